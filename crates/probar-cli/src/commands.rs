@@ -59,7 +59,7 @@ pub enum Commands {
     /// Run state machine playbooks
     Playbook(PlaybookArgs),
 
-    /// Run WASM compliance checks (C001-C010)
+    /// Run WASM compliance checks (C001-C011)
     ///
     /// Validates WASM application against Probar's compliance checklist:
     /// - C001: Code execution verified (not just mocked HTML)
@@ -72,6 +72,7 @@ pub enum Commands {
     /// - C008: Proper cache handling
     /// - C009: WASM under size limit
     /// - C010: No panic paths in WASM
+    /// - C011: CORP header on WASM/JS assets when cross-origin isolated
     Comply(ComplyArgs),
 
     /// Verify audio-visual synchronization against EDL ground truth
@@ -278,6 +279,14 @@ pub struct VideoCheckArgs {
     #[arg(long)]
     pub require_audio: bool,
 
+    /// Fail on variable frame rate (decodes per-frame timestamps)
+    #[arg(long)]
+    pub constant_frame_rate: bool,
+
+    /// Maximum run of consecutive duplicated frames (decodes frame hashes)
+    #[arg(long)]
+    pub max_duplicate_frame_run: Option<usize>,
+
     /// Output format
     #[arg(long, default_value = "text")]
     pub format: OutputFormat,
@@ -365,6 +374,15 @@ pub struct StressArgs {
     /// Run full system stress test
     #[arg(long)]
     pub full: bool,
+
+    /// Ramp concurrency in steps of --concurrency until the latency knee
+    /// is found, then report the saturation point
+    #[arg(long)]
+    pub find_knee: bool,
+
+    /// Highest concurrency to try with --find-knee
+    #[arg(long, default_value = "64")]
+    pub max_concurrency: u32,
 }
 
 impl StressArgs {
@@ -1164,6 +1182,20 @@ pub struct TreeArgs {
     /// Show MIME types
     #[arg(long, default_value = "true")]
     pub mime_types: bool,
+
+    /// Output format
+    #[arg(long, default_value = "ascii")]
+    pub format: TreeOutputFormat,
+}
+
+/// Output format for tree command
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TreeOutputFormat {
+    /// ASCII tree
+    #[default]
+    Ascii,
+    /// JSON array of entries
+    Json,
 }
 
 /// Arguments for the viz subcommand
@@ -1364,7 +1396,7 @@ pub struct PlaybookArgs {
     #[arg(long)]
     pub export_output: Option<PathBuf>,
 
-    /// Run mutation testing (M1-M5)
+    /// Run mutation testing (M1-M6)
     #[arg(long)]
     pub mutate: bool,
 
@@ -2582,6 +2614,8 @@ mod tests {
                 render,
                 trace,
                 full,
+                find_knee: false,
+                max_concurrency: 64,
             }
         }
 
@@ -2664,5 +2698,17 @@ mod tests {
                 panic!("expected Stress command");
             }
         }
+
+        #[test]
+        fn test_parse_stress_find_knee() {
+            let cli =
+                Cli::parse_from(["probar", "stress", "--find-knee", "--max-concurrency", "32"]);
+            if let Commands::Stress(args) = cli.command {
+                assert!(args.find_knee);
+                assert_eq!(args.max_concurrency, 32);
+            } else {
+                panic!("expected Stress command");
+            }
+        }
     }
 }
@@ -6,7 +6,9 @@
 //! ## Features
 //!
 //! - HTTP server with correct MIME types for WASM
+//! - HTTP Range requests for streaming media and large `.wasm` files
 //! - WebSocket server for hot reload notifications
+//! - In-place CSS/asset swapping without a full page reload
 //! - File watcher with debouncing
 //! - `wasm-pack` build integration
 //!
@@ -41,7 +43,7 @@
 
 use axum::{
     extract::ws::{Message, WebSocket, WebSocketUpgrade},
-    http::{header, StatusCode},
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
     routing::get,
     Router,
@@ -103,8 +105,101 @@ pub enum HotReloadMessage {
         /// Number of connected clients
         client_count: usize,
     },
+    /// Stylesheet changed; client swaps the `<link>` href in place
+    CssUpdated {
+        /// Path to the changed stylesheet
+        path: String,
+    },
+    /// Static asset changed; client re-fetches matching elements in place
+    AssetUpdated {
+        /// Path to the changed asset
+        path: String,
+    },
+}
+
+/// How a changed file is applied in the browser
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Rust sources and manifests: rebuild, then full reload
+    Source,
+    /// Stylesheets: swapped in place
+    Stylesheet,
+    /// Images, media, fonts and data files: re-fetched in place
+    Asset,
+    /// `.wasm`, `.js` and HTML: full reload
+    Code,
 }
 
+impl ChangeKind {
+    /// Extensions applied in place without a reload
+    pub const HOT_SWAP_EXTENSIONS: &'static [&'static str] = &[
+        "css", "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "ico", "json", "mp4", "webm",
+        "mp3", "wav", "ogg", "woff", "woff2", "ttf", "otf",
+    ];
+
+    /// Classify a changed file by extension
+    #[must_use]
+    pub fn classify(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("rs") | Some("toml") => Self::Source,
+            Some("css") => Self::Stylesheet,
+            Some(ext) if Self::HOT_SWAP_EXTENSIONS.contains(&ext) => Self::Asset,
+            _ => Self::Code,
+        }
+    }
+
+    /// Whether the change is applied without reloading the page
+    #[must_use]
+    pub const fn is_hot_swap(&self) -> bool {
+        matches!(self, Self::Stylesheet | Self::Asset)
+    }
+}
+
+/// Client script injected into served HTML pages by [`DevServer::run`] and
+/// [`DevServer::run_split`]
+///
+/// Swaps stylesheets and re-fetches assets in place on `CssUpdated` /
+/// `AssetUpdated`, and reloads the page after `RebuildComplete`. Connects to
+/// `/ws` on the page's host, or to the port in the script tag's
+/// `data-ws-port` attribute when the WebSocket server runs separately.
+pub const HOT_RELOAD_CLIENT_JS: &str = r#"(() => {
+  const proto = location.protocol === 'https:' ? 'wss:' : 'ws:';
+  const wsPort = document.currentScript && document.currentScript.dataset.wsPort;
+  const ws = new WebSocket(wsPort
+    ? `${proto}//${location.hostname}:${wsPort}/`
+    : `${proto}//${location.host}/ws`);
+  const bust = (url) => {
+    const u = new URL(url, location.href);
+    u.searchParams.set('probar-reload', Date.now());
+    return u.toString();
+  };
+  const matches = (url, path) => path.endsWith(new URL(url, location.href).pathname);
+  const refresh = (elements, attr, path) => {
+    const hits = elements.filter((el) => matches(el[attr], path));
+    (hits.length > 0 ? hits : elements).forEach((el) => { el[attr] = bust(el[attr]); });
+  };
+  ws.onmessage = (event) => {
+    const msg = JSON.parse(event.data);
+    switch (msg.type) {
+      case 'CssUpdated':
+        refresh([...document.querySelectorAll('link[rel="stylesheet"]')], 'href', msg.data.path);
+        break;
+      case 'AssetUpdated':
+        [...document.querySelectorAll('img[src], video[src], audio[src], source[src]')]
+          .filter((el) => matches(el.src, msg.data.path))
+          .forEach((el) => { el.src = bust(el.src); });
+        break;
+      case 'RebuildComplete':
+        location.reload();
+        break;
+    }
+  };
+})();
+"#;
+
+/// Path the hot reload client script is served from
+pub const HOT_RELOAD_CLIENT_PATH: &str = "/__probar__/hot-reload.js";
+
 /// File change event types
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -126,6 +221,19 @@ impl HotReloadMessage {
         serde_json::to_string(self).unwrap_or_else(|_| r#"{"type":"Error"}"#.to_string())
     }
 
+    /// Message that applies a changed file in place, if its type allows it
+    ///
+    /// Returns `None` for changes that need a rebuild or full reload.
+    #[must_use]
+    pub fn hot_swap(path: impl Into<String>) -> Option<Self> {
+        let path = path.into();
+        match ChangeKind::classify(std::path::Path::new(&path)) {
+            ChangeKind::Stylesheet => Some(Self::CssUpdated { path }),
+            ChangeKind::Asset => Some(Self::AssetUpdated { path }),
+            ChangeKind::Source | ChangeKind::Code => None,
+        }
+    }
+
     /// Create a file modified message with size tracking
     #[must_use]
     pub fn file_modified(
@@ -333,18 +441,28 @@ impl DevServer {
                     move |ws: WebSocketUpgrade| handle_websocket(ws, tx.clone())
                 }),
             )
+            // Hot reload client script
+            .route(HOT_RELOAD_CLIENT_PATH, get(serve_hot_reload_client))
             // Index route
             .route(
                 "/",
                 get({
                     let dir = directory.clone();
-                    move || serve_index(dir.clone())
+                    move || {
+                        let dir = dir.clone();
+                        async move { with_hot_reload_client(serve_index(dir).await, None).await }
+                    }
                 }),
             )
             // Static file fallback
             .fallback({
                 let dir = directory.clone();
-                move |uri: axum::http::Uri| serve_static(dir.clone(), uri)
+                move |uri: axum::http::Uri, headers: HeaderMap| {
+                    let dir = dir.clone();
+                    async move {
+                        with_hot_reload_client(serve_static(dir, uri, headers).await, None).await
+                    }
+                }
             });
 
         // Add CORS if enabled
@@ -434,18 +552,29 @@ impl DevServer {
         let directory = Arc::new(self.config.directory.clone());
         let reload_tx = self.reload_tx.clone();
 
+        let ws_port = Some(self.config.ws_port);
+
         // HTTP server
         let http_app = Router::new()
+            .route(HOT_RELOAD_CLIENT_PATH, get(serve_hot_reload_client))
             .route(
                 "/",
                 get({
                     let dir = directory.clone();
-                    move || serve_index(dir.clone())
+                    move || {
+                        let dir = dir.clone();
+                        async move { with_hot_reload_client(serve_index(dir).await, ws_port).await }
+                    }
                 }),
             )
             .fallback({
                 let dir = directory.clone();
-                move |uri: axum::http::Uri| serve_static(dir.clone(), uri)
+                move |uri: axum::http::Uri, headers: HeaderMap| {
+                    let dir = dir.clone();
+                    async move {
+                        with_hot_reload_client(serve_static(dir, uri, headers).await, ws_port).await
+                    }
+                }
             });
 
         let http_app = if self.config.cors {
@@ -557,14 +686,19 @@ async fn websocket_handler(socket: WebSocket, reload_tx: broadcast::Sender<HotRe
 /// Serve index.html
 async fn serve_index(directory: Arc<PathBuf>) -> Response {
     let index_path = directory.join("index.html");
-    serve_file(&index_path).await
+    serve_file(&index_path, None).await
 }
 
 /// Serve static file based on URI
 ///
 /// Handles directory requests by serving index.html if it exists.
 /// Properly handles both `/dir/` and `/dir` paths.
-async fn serve_static(directory: Arc<PathBuf>, uri: axum::http::Uri) -> Response {
+async fn serve_static(
+    directory: Arc<PathBuf>,
+    uri: axum::http::Uri,
+    headers: HeaderMap,
+) -> Response {
+    let range = headers.get(header::RANGE).and_then(|v| v.to_str().ok());
     // Trim both leading and trailing slashes to normalize path
     let path = uri.path().trim_start_matches('/').trim_end_matches('/');
     let file_path = if path.is_empty() {
@@ -577,7 +711,7 @@ async fn serve_static(directory: Arc<PathBuf>, uri: axum::http::Uri) -> Response
     if file_path.is_dir() {
         let index_path = file_path.join("index.html");
         if index_path.exists() {
-            return serve_file(&index_path).await;
+            return serve_file(&index_path, range).await;
         }
         // Return 404 for directories without index.html
         return (
@@ -587,7 +721,129 @@ async fn serve_static(directory: Arc<PathBuf>, uri: axum::http::Uri) -> Response
             .into_response();
     }
 
-    serve_file(&file_path).await
+    serve_file(&file_path, range).await
+}
+
+/// Serve the hot reload client script
+async fn serve_hot_reload_client() -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, "text/javascript"),
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        HOT_RELOAD_CLIENT_JS,
+    )
+        .into_response()
+}
+
+/// Insert the hot reload client `<script>` tag before `</body>`
+///
+/// `ws_port` is set when the WebSocket server listens on its own port
+/// (see [`DevServer::run_split`]). Appends the tag when the document has no
+/// closing body tag.
+#[must_use]
+pub fn inject_hot_reload_client(html: &str, ws_port: Option<u16>) -> String {
+    let tag = match ws_port {
+        Some(port) => {
+            format!(r#"<script src="{HOT_RELOAD_CLIENT_PATH}" data-ws-port="{port}"></script>"#)
+        }
+        None => format!(r#"<script src="{HOT_RELOAD_CLIENT_PATH}"></script>"#),
+    };
+    match html.to_ascii_lowercase().rfind("</body>") {
+        Some(idx) => format!("{}{tag}{}", &html[..idx], &html[idx..]),
+        None => format!("{html}{tag}"),
+    }
+}
+
+/// Inject the hot reload client into full (200) HTML responses
+async fn with_hot_reload_client(response: Response, ws_port: Option<u16>) -> Response {
+    let is_html = response.status() == StatusCode::OK
+        && response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|ct| ct.starts_with("text/html"));
+    if !is_html {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let html = inject_hot_reload_client(&String::from_utf8_lossy(&bytes), ws_port);
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, axum::body::Body::from(html))
+}
+
+/// Outcome of evaluating a `Range` request header against a file length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeRequest {
+    /// No usable range (absent, malformed, or not `bytes`): serve the whole file
+    Full,
+    /// A single satisfiable range with inclusive bounds: serve 206
+    Partial {
+        /// First byte offset
+        start: u64,
+        /// Last byte offset (inclusive)
+        end: u64,
+    },
+    /// Range outside the file, or multiple ranges (unsupported): serve 416
+    Unsatisfiable,
+}
+
+impl RangeRequest {
+    /// Parse a `Range` header value (e.g. `bytes=0-1023`, `bytes=512-`,
+    /// `bytes=-256`) for a file of `len` bytes
+    ///
+    /// Malformed headers are ignored per RFC 9110 and yield `Full`.
+    /// Multi-range requests are answered with 416 rather than multipart.
+    #[must_use]
+    pub fn parse(value: &str, len: u64) -> Self {
+        let Some(spec) = value.trim().strip_prefix("bytes=") else {
+            return Self::Full;
+        };
+        if spec.contains(',') {
+            return Self::Unsatisfiable;
+        }
+        let Some((first, last)) = spec.trim().split_once('-') else {
+            return Self::Full;
+        };
+        let (first, last) = (first.trim(), last.trim());
+
+        if first.is_empty() {
+            // Suffix range: last N bytes
+            let Ok(suffix) = last.parse::<u64>() else {
+                return Self::Full;
+            };
+            if suffix == 0 || len == 0 {
+                return Self::Unsatisfiable;
+            }
+            return Self::Partial {
+                start: len.saturating_sub(suffix),
+                end: len - 1,
+            };
+        }
+
+        let Ok(start) = first.parse::<u64>() else {
+            return Self::Full;
+        };
+        let end = if last.is_empty() {
+            u64::MAX
+        } else {
+            match last.parse::<u64>() {
+                Ok(end) if end >= start => end,
+                _ => return Self::Full,
+            }
+        };
+        if start >= len {
+            return Self::Unsatisfiable;
+        }
+        Self::Partial {
+            start,
+            end: end.min(len - 1),
+        }
+    }
 }
 
 /// Serve a file with correct MIME type
@@ -595,16 +851,32 @@ async fn serve_static(directory: Arc<PathBuf>, uri: axum::http::Uri) -> Response
 /// MIME types are critical for WASM to work in browsers:
 /// - `.wasm` files MUST be `application/wasm`
 /// - `.js` files MUST be `text/javascript` or `application/javascript`
-async fn serve_file(path: &std::path::Path) -> Response {
-    match tokio::fs::read(path).await {
-        Ok(contents) => {
+///
+/// When `range` holds a `Range` header value, only the requested bytes are
+/// read and returned with `206 Partial Content`.
+async fn serve_file(path: &std::path::Path, range: Option<&str>) -> Response {
+    match read_file_range(path, range).await {
+        Ok((RangeRequest::Unsatisfiable, len, _)) => Response::builder()
+            .status(StatusCode::RANGE_NOT_SATISFIABLE)
+            .header(header::CONTENT_RANGE, format!("bytes */{len}"))
+            .header(header::ACCEPT_RANGES, "bytes")
+            .body(axum::body::Body::empty())
+            .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response()),
+        Ok((served, len, contents)) => {
             // Determine MIME type from extension
             let mime_type = get_mime_type(path);
 
-            Response::builder()
-                .status(StatusCode::OK)
+            let builder = Response::builder()
                 .header(header::CONTENT_TYPE, mime_type)
                 .header(header::CACHE_CONTROL, "no-cache")
+                .header(header::ACCEPT_RANGES, "bytes");
+            let builder = match served {
+                RangeRequest::Partial { start, end } => builder
+                    .status(StatusCode::PARTIAL_CONTENT)
+                    .header(header::CONTENT_RANGE, format!("bytes {start}-{end}/{len}")),
+                _ => builder.status(StatusCode::OK),
+            };
+            builder
                 .body(axum::body::Body::from(contents))
                 .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response())
         }
@@ -621,6 +893,37 @@ async fn serve_file(path: &std::path::Path) -> Response {
     }
 }
 
+/// Read the bytes selected by a `Range` header value
+///
+/// Returns the evaluated range, the full file length and the bytes to send
+/// (empty for unsatisfiable ranges). Partial reads seek instead of loading
+/// the whole file.
+async fn read_file_range(
+    path: &std::path::Path,
+    range: Option<&str>,
+) -> Result<(RangeRequest, u64, Vec<u8>), std::io::Error> {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    let mut file = tokio::fs::File::open(path).await?;
+    let len = file.metadata().await?.len();
+    let request = range.map_or(RangeRequest::Full, |value| RangeRequest::parse(value, len));
+
+    match request {
+        RangeRequest::Full => {
+            let mut contents = Vec::with_capacity(len as usize);
+            file.read_to_end(&mut contents).await?;
+            Ok((request, len, contents))
+        }
+        RangeRequest::Partial { start, end } => {
+            let mut contents = vec![0; (end - start + 1) as usize];
+            file.seek(std::io::SeekFrom::Start(start)).await?;
+            file.read_exact(&mut contents).await?;
+            Ok((request, len, contents))
+        }
+        RangeRequest::Unsatisfiable => Ok((request, len, Vec::new())),
+    }
+}
+
 /// Get MIME type for a file path
 ///
 /// Ensures WASM files get the correct `application/wasm` type
@@ -753,6 +1056,23 @@ impl FileWatcher {
         FileWatcherBuilder::default()
     }
 
+    /// Also watch stylesheets and static assets so they can be hot-swapped
+    #[must_use]
+    pub fn with_asset_patterns(mut self) -> Self {
+        for ext in ChangeKind::HOT_SWAP_EXTENSIONS {
+            if !self.patterns.iter().any(|p| p == ext) {
+                self.patterns.push((*ext).to_string());
+            }
+        }
+        self
+    }
+
+    /// Classify a changed file to decide how the browser applies it
+    #[must_use]
+    pub fn classify(path: &std::path::Path) -> ChangeKind {
+        ChangeKind::classify(path)
+    }
+
     /// Check if a path matches watch patterns
     #[must_use]
     pub fn matches_pattern(&self, path: &std::path::Path) -> bool {
@@ -917,6 +1237,37 @@ pub struct ImportValidationError {
     pub message: String,
 }
 
+/// Circular import chain between modules
+///
+/// Cycles can deadlock WASM module initialization even when every import
+/// resolves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportCycle {
+    /// Files in import order, relative to the serve root; the first file is
+    /// repeated at the end to close the cycle
+    pub files: Vec<PathBuf>,
+}
+
+impl ImportCycle {
+    /// Check if this is a module importing itself
+    #[must_use]
+    pub fn is_self_import(&self) -> bool {
+        self.files.len() == 2
+    }
+}
+
+impl std::fmt::Display for ImportCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = self
+            .files
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join(" → ");
+        write!(f, "{path}")
+    }
+}
+
 /// Result of validating all imports
 #[derive(Debug, Default)]
 pub struct ModuleValidationResult {
@@ -926,13 +1277,21 @@ pub struct ModuleValidationResult {
     pub passed: usize,
     /// Imports that failed validation
     pub errors: Vec<ImportValidationError>,
+    /// Circular import chains
+    pub cycles: Vec<ImportCycle>,
 }
 
 impl ModuleValidationResult {
     /// Check if all validations passed
     #[must_use]
     pub fn is_ok(&self) -> bool {
-        self.errors.is_empty()
+        self.errors.is_empty() && self.cycles.is_empty()
+    }
+
+    /// Number of problems found (broken imports plus import cycles)
+    #[must_use]
+    pub fn problem_count(&self) -> usize {
+        self.errors.len() + self.cycles.len()
     }
 }
 
@@ -970,7 +1329,8 @@ impl ModuleValidator {
         })
     }
 
-    /// Scan all HTML files and extract module imports
+    /// Scan all HTML files and the JS modules reachable from them, and
+    /// extract module imports
     #[must_use]
     pub fn scan_imports(&self) -> Vec<ImportRef> {
         let mut imports = Vec::new();
@@ -989,9 +1349,57 @@ impl ModuleValidator {
             }
         }
 
+        // Follow JS imports transitively from the HTML entry points
+        let mut scanned = std::collections::HashSet::new();
+        let mut next = 0;
+        while next < imports.len() {
+            let import = &imports[next];
+            next += 1;
+            if import.import_type == ImportType::Wasm {
+                continue;
+            }
+            let Some(module) = self
+                .resolve_path(import)
+                .and_then(|p| p.canonicalize().ok())
+            else {
+                continue;
+            };
+            let is_js = module
+                .extension()
+                .is_some_and(|ext| ext == "js" || ext == "mjs");
+            if !is_js || self.is_excluded(&module) || !scanned.insert(module.clone()) {
+                continue;
+            }
+            if let Ok(content) = std::fs::read_to_string(&module) {
+                imports.extend(Self::extract_imports_from_js(&module, &content));
+            }
+        }
+
         imports
     }
 
+    /// Extract ES module imports from JS content
+    fn extract_imports_from_js(file: &std::path::Path, content: &str) -> Vec<ImportRef> {
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(line_num, line)| {
+                let path = Self::extract_es_import(line)?;
+                let import_type = if path.to_ascii_lowercase().ends_with(".wasm") {
+                    ImportType::Wasm
+                } else {
+                    ImportType::EsModule
+                };
+                Some(ImportRef {
+                    source_file: file.to_path_buf(),
+                    import_path: path,
+                    import_type,
+                    line_number: (line_num + 1) as u32,
+                })
+            })
+            .collect()
+    }
+
     /// Extract imports from HTML content
     fn extract_imports_from_html(file: &std::path::Path, content: &str) -> Vec<ImportRef> {
         let mut imports = Vec::new();
@@ -1144,11 +1552,12 @@ impl ModuleValidator {
         let imports = self.scan_imports();
         let mut result = ModuleValidationResult {
             total_imports: imports.len(),
+            cycles: self.find_cycles(&imports),
             ..Default::default()
         };
 
-        for import in imports {
-            if let Some(resolved) = self.resolve_path(&import) {
+        for import in &imports {
+            if let Some(resolved) = self.resolve_path(import) {
                 // Check if file exists
                 let canonical = resolved.canonicalize();
 
@@ -1193,12 +1602,93 @@ impl ModuleValidator {
         result
     }
 
+    /// Find circular import chains in the graph of resolvable imports
+    ///
+    /// Reports one cycle per back edge found by a depth-first search, so a
+    /// cycle is listed once regardless of where it is entered.
+    #[must_use]
+    pub fn find_cycles(&self, imports: &[ImportRef]) -> Vec<ImportCycle> {
+        use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+        let mut graph: BTreeMap<PathBuf, BTreeSet<PathBuf>> = BTreeMap::new();
+        for import in imports {
+            let Some(target) = self
+                .resolve_path(import)
+                .and_then(|p| p.canonicalize().ok())
+            else {
+                continue;
+            };
+            let Ok(source) = import.source_file.canonicalize() else {
+                continue;
+            };
+            graph.entry(source).or_default().insert(target);
+        }
+
+        let mut done: HashMap<PathBuf, bool> = HashMap::new();
+        let mut stack = Vec::new();
+        let mut cycles = Vec::new();
+        for node in graph.keys() {
+            if !done.contains_key(node) {
+                Self::visit_imports(node, &graph, &mut done, &mut stack, &mut cycles);
+            }
+        }
+
+        let root = self
+            .serve_root
+            .canonicalize()
+            .unwrap_or_else(|_| self.serve_root.clone());
+        cycles
+            .into_iter()
+            .map(|files| ImportCycle {
+                files: files
+                    .iter()
+                    .map(|p| p.strip_prefix(&root).unwrap_or(p).to_path_buf())
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Depth-first search step for cycle detection
+    ///
+    /// `done` maps visited files to whether they are finished (`true`) or
+    /// still on the DFS stack (`false`).
+    fn visit_imports(
+        node: &std::path::Path,
+        graph: &std::collections::BTreeMap<PathBuf, std::collections::BTreeSet<PathBuf>>,
+        done: &mut std::collections::HashMap<PathBuf, bool>,
+        stack: &mut Vec<PathBuf>,
+        cycles: &mut Vec<Vec<PathBuf>>,
+    ) {
+        done.insert(node.to_path_buf(), false);
+        stack.push(node.to_path_buf());
+
+        for next in graph.get(node).into_iter().flatten() {
+            match done.get(next) {
+                Some(false) => {
+                    if let Some(start) = stack.iter().position(|p| p == next) {
+                        let mut files = stack[start..].to_vec();
+                        files.push(next.clone());
+                        cycles.push(files);
+                    }
+                }
+                Some(true) => {}
+                None => Self::visit_imports(next, graph, done, stack, cycles),
+            }
+        }
+
+        stack.pop();
+        done.insert(node.to_path_buf(), true);
+    }
+
     /// Print validation results to stderr
     pub fn print_results(&self, result: &ModuleValidationResult) {
         eprintln!("\nValidating module imports...");
         eprintln!("  Scanned: {} imports", result.total_imports);
         eprintln!("  Passed:  {}", result.passed);
         eprintln!("  Failed:  {}", result.errors.len());
+        if !result.cycles.is_empty() {
+            eprintln!("  Cycles:  {}", result.cycles.len());
+        }
 
         if !result.errors.is_empty() {
             eprintln!("\nErrors:");
@@ -1213,6 +1703,13 @@ impl ModuleValidator {
                 eprintln!("    {}", error.message);
             }
         }
+
+        if !result.cycles.is_empty() {
+            eprintln!("\nCircular imports:");
+            for cycle in &result.cycles {
+                eprintln!("  ↻ {cycle}");
+            }
+        }
     }
 }
 
@@ -1479,7 +1976,7 @@ mod tests {
         let directory = Arc::new(temp_dir.path().to_path_buf());
         let uri: axum::http::Uri = "/subdir/".parse().unwrap();
 
-        let response = serve_static(directory, uri).await;
+        let response = serve_static(directory, uri, HeaderMap::new()).await;
         assert_eq!(response.status(), StatusCode::OK);
     }
 
@@ -1496,7 +1993,7 @@ mod tests {
         let directory = Arc::new(temp_dir.path().to_path_buf());
         let uri: axum::http::Uri = "/mydir".parse().unwrap();
 
-        let response = serve_static(directory, uri).await;
+        let response = serve_static(directory, uri, HeaderMap::new()).await;
         assert_eq!(response.status(), StatusCode::OK);
     }
 
@@ -1513,11 +2010,246 @@ mod tests {
         let directory = Arc::new(temp_dir.path().to_path_buf());
         let uri: axum::http::Uri = "/empty/".parse().unwrap();
 
-        let response = serve_static(directory, uri).await;
+        let response = serve_static(directory, uri, HeaderMap::new()).await;
         // Should return error since directory has no index.html
         assert!(response.status().is_client_error() || response.status().is_server_error());
     }
 
+    // =========================================================================
+    // Range Request Tests
+    // =========================================================================
+
+    #[test]
+    fn test_range_request_parse() {
+        assert_eq!(
+            RangeRequest::parse("bytes=0-9", 100),
+            RangeRequest::Partial { start: 0, end: 9 }
+        );
+        assert_eq!(
+            RangeRequest::parse("bytes=90-", 100),
+            RangeRequest::Partial { start: 90, end: 99 }
+        );
+        assert_eq!(
+            RangeRequest::parse("bytes=-10", 100),
+            RangeRequest::Partial { start: 90, end: 99 }
+        );
+        // End past EOF is clamped, oversized suffix covers the whole file
+        assert_eq!(
+            RangeRequest::parse("bytes=50-500", 100),
+            RangeRequest::Partial { start: 50, end: 99 }
+        );
+        assert_eq!(
+            RangeRequest::parse("bytes=-500", 100),
+            RangeRequest::Partial { start: 0, end: 99 }
+        );
+    }
+
+    #[test]
+    fn test_range_request_parse_unsatisfiable_and_malformed() {
+        assert_eq!(
+            RangeRequest::parse("bytes=100-", 100),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(
+            RangeRequest::parse("bytes=0-1,5-9", 100),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(
+            RangeRequest::parse("bytes=-0", 100),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(RangeRequest::parse("items=0-9", 100), RangeRequest::Full);
+        assert_eq!(RangeRequest::parse("bytes=9-0", 100), RangeRequest::Full);
+        assert_eq!(RangeRequest::parse("bytes=abc", 100), RangeRequest::Full);
+    }
+
+    fn range_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::RANGE, value.parse().unwrap());
+        headers
+    }
+
+    #[tokio::test]
+    async fn test_serve_static_range_returns_partial_content() {
+        use std::sync::Arc;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let data: Vec<u8> = (0..=255).collect();
+        std::fs::write(temp_dir.path().join("app_bg.wasm"), &data).unwrap();
+
+        let directory = Arc::new(temp_dir.path().to_path_buf());
+        let uri: axum::http::Uri = "/app_bg.wasm".parse().unwrap();
+
+        let response = serve_static(directory, uri, range_headers("bytes=16-31")).await;
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        let headers = response.headers();
+        assert_eq!(headers[header::CONTENT_RANGE], "bytes 16-31/256");
+        assert_eq!(headers[header::ACCEPT_RANGES], "bytes");
+        assert_eq!(headers[header::CONTENT_TYPE], "application/wasm");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body.as_ref(), &data[16..32]);
+    }
+
+    #[tokio::test]
+    async fn test_serve_static_range_unsatisfiable() {
+        use std::sync::Arc;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("clip.mp4"), [0u8; 64]).unwrap();
+        let directory = Arc::new(temp_dir.path().to_path_buf());
+
+        let uri: axum::http::Uri = "/clip.mp4".parse().unwrap();
+        let response = serve_static(directory.clone(), uri, range_headers("bytes=64-")).await;
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes */64");
+
+        let uri: axum::http::Uri = "/clip.mp4".parse().unwrap();
+        let response = serve_static(directory, uri, range_headers("bytes=0-1,4-8")).await;
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    }
+
+    #[tokio::test]
+    async fn test_serve_static_without_range_serves_full_file() {
+        use std::sync::Arc;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("data.bin"), b"hello world").unwrap();
+        let directory = Arc::new(temp_dir.path().to_path_buf());
+        let uri: axum::http::Uri = "/data.bin".parse().unwrap();
+
+        let response = serve_static(directory, uri, HeaderMap::new()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::ACCEPT_RANGES], "bytes");
+        assert!(!response.headers().contains_key(header::CONTENT_RANGE));
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body.as_ref(), b"hello world");
+    }
+
+    // =========================================================================
+    // Hot Swap Tests (CSS/asset injection without full reload)
+    // =========================================================================
+
+    #[test]
+    fn test_css_change_emits_css_update_not_reload() {
+        assert_eq!(
+            FileWatcher::classify(std::path::Path::new("www/styles/app.css")),
+            ChangeKind::Stylesheet
+        );
+        let msg = HotReloadMessage::hot_swap("www/styles/app.css").unwrap();
+        match &msg {
+            HotReloadMessage::CssUpdated { path } => assert_eq!(path, "www/styles/app.css"),
+            other => panic!("expected CssUpdated, got {other:?}"),
+        }
+        let json = msg.to_json();
+        assert!(json.contains(r#""type":"CssUpdated""#));
+        assert!(!json.contains("RebuildComplete"));
+    }
+
+    #[test]
+    fn test_change_kind_classification() {
+        let classify = |p: &str| FileWatcher::classify(std::path::Path::new(p));
+        assert_eq!(classify("assets/logo.png"), ChangeKind::Asset);
+        assert_eq!(classify("fonts/inter.woff2"), ChangeKind::Asset);
+        assert_eq!(classify("pkg/app_bg.wasm"), ChangeKind::Code);
+        assert_eq!(classify("pkg/app.js"), ChangeKind::Code);
+        assert_eq!(classify("src/lib.rs"), ChangeKind::Source);
+        assert_eq!(classify("Cargo.toml"), ChangeKind::Source);
+        assert!(ChangeKind::Asset.is_hot_swap());
+        assert!(!ChangeKind::Code.is_hot_swap());
+
+        assert!(matches!(
+            HotReloadMessage::hot_swap("assets/logo.png"),
+            Some(HotReloadMessage::AssetUpdated { .. })
+        ));
+        assert!(HotReloadMessage::hot_swap("pkg/app_bg.wasm").is_none());
+        assert!(HotReloadMessage::hot_swap("pkg/app.js").is_none());
+        assert!(HotReloadMessage::hot_swap("src/lib.rs").is_none());
+    }
+
+    #[test]
+    fn test_file_watcher_with_asset_patterns() {
+        let watcher = FileWatcher::new(PathBuf::from("."), 100).with_asset_patterns();
+        assert!(watcher.matches_pattern(std::path::Path::new("a.rs")));
+        assert!(watcher.matches_pattern(std::path::Path::new("style.css")));
+        assert!(watcher.matches_pattern(std::path::Path::new("logo.svg")));
+        assert!(!watcher.matches_pattern(std::path::Path::new("app.js")));
+
+        let twice = watcher.with_asset_patterns();
+        assert_eq!(twice.patterns.iter().filter(|p| *p == "css").count(), 1);
+    }
+
+    #[test]
+    fn test_inject_hot_reload_client() {
+        let html = "<html><BODY><p>hi</p></BODY></html>";
+        let injected = inject_hot_reload_client(html, None);
+        assert_eq!(
+            injected,
+            format!(
+                r#"<html><BODY><p>hi</p><script src="{HOT_RELOAD_CLIENT_PATH}"></script></BODY></html>"#
+            )
+        );
+
+        let fragment = inject_hot_reload_client("<p>no body</p>", None);
+        assert!(fragment.ends_with(r#"hot-reload.js"></script>"#));
+
+        let split = inject_hot_reload_client("<body></body>", Some(3001));
+        assert_eq!(
+            split,
+            format!(
+                r#"<body><script src="{HOT_RELOAD_CLIENT_PATH}" data-ws-port="3001"></script></body>"#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_hot_reload_client_only_touches_html() {
+        use std::sync::Arc;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("index.html"),
+            "<html><body></body></html>",
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("app.css"), "body {}").unwrap();
+        let directory = Arc::new(temp_dir.path().to_path_buf());
+
+        let response = with_hot_reload_client(serve_index(directory.clone()).await, None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(String::from_utf8_lossy(&body).contains(HOT_RELOAD_CLIENT_PATH));
+
+        let uri: axum::http::Uri = "/app.css".parse().unwrap();
+        let response =
+            with_hot_reload_client(serve_static(directory, uri, HeaderMap::new()).await, None)
+                .await;
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body.as_ref(), b"body {}");
+    }
+
+    #[tokio::test]
+    async fn test_hot_reload_client_script_handles_swaps() {
+        let response = serve_hot_reload_client().await;
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/javascript");
+        assert!(HOT_RELOAD_CLIENT_JS.contains("'CssUpdated'"));
+        assert!(HOT_RELOAD_CLIENT_JS.contains("'AssetUpdated'"));
+        assert!(HOT_RELOAD_CLIENT_JS.contains("location.reload()"));
+        assert!(HOT_RELOAD_CLIENT_JS.contains("dataset.wsPort"));
+    }
+
     // =========================================================================
     // Integration-style Tests (no actual I/O)
     // =========================================================================
@@ -1916,6 +2648,106 @@ mod tests {
         assert_eq!(result.errors[0].status, 404);
     }
 
+    #[test]
+    fn test_module_validator_detects_three_file_cycle() {
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("index.html"),
+            r#"<script type="module">import init from './a.js';</script>"#,
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("a.js"), "import { b } from './b.js';").unwrap();
+        std::fs::write(temp.path().join("b.js"), "import { c } from './c.js';").unwrap();
+        std::fs::write(temp.path().join("c.js"), "import { a } from './a.js';").unwrap();
+
+        let validator = ModuleValidator::new(temp.path());
+        let result = validator.validate();
+
+        assert!(result.errors.is_empty());
+        assert_eq!(result.cycles.len(), 1);
+        assert_eq!(result.cycles[0].to_string(), "a.js → b.js → c.js → a.js");
+        assert!(!result.cycles[0].is_self_import());
+        assert!(!result.is_ok());
+        assert_eq!(result.problem_count(), 1);
+    }
+
+    #[test]
+    fn test_module_validator_detects_self_import() {
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("index.html"),
+            r#"<script type="module">import init from './s.js';</script>"#,
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("s.js"), "import { s } from './s.js';").unwrap();
+
+        let validator = ModuleValidator::new(temp.path());
+        let result = validator.validate();
+
+        assert_eq!(result.cycles.len(), 1);
+        assert!(result.cycles[0].is_self_import());
+        assert_eq!(result.cycles[0].to_string(), "s.js → s.js");
+    }
+
+    #[test]
+    fn test_module_validator_acyclic_imports_pass() {
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("index.html"),
+            r#"<script type="module">import init from './a.js';</script>"#,
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("a.js"),
+            "import { b } from './b.js';\nimport { c } from './c.js';",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("b.js"), "import { c } from './c.js';").unwrap();
+        std::fs::write(temp.path().join("c.js"), "export const c = 1;").unwrap();
+
+        let validator = ModuleValidator::new(temp.path());
+        let result = validator.validate();
+
+        assert_eq!(result.total_imports, 4);
+        assert!(result.cycles.is_empty());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_module_validator_ignores_unreachable_modules() {
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("index.html"),
+            r#"<script type="module">import init from './a.js';</script>"#,
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("a.js"), "export const a = 1;").unwrap();
+        std::fs::write(
+            temp.path().join("stale.js"),
+            "import { x } from './missing.js';",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("loop.js"),
+            "import { l } from './loop.js';",
+        )
+        .unwrap();
+
+        let validator = ModuleValidator::new(temp.path());
+        let result = validator.validate();
+
+        assert_eq!(result.total_imports, 1);
+        assert!(result.is_ok());
+    }
+
     // =========================================================================
     // format_bytes Tests
     // =========================================================================
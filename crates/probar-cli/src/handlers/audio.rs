@@ -63,6 +63,10 @@ fn render_text_report(report: &jugar_probar::audio_quality::AudioQualityReport)
         if report.levels.passed { "PASS" } else { "FAIL" }
     );
     println!("    RMS:  {:.1} dBFS", report.levels.rms_dbfs);
+    println!(
+        "    DC offset: {:+.4} ({:.1} dBFS)",
+        report.levels.dc_offset, report.levels.dc_offset_dbfs
+    );
     println!(
         "    Dynamic range: {:.1} dB",
         report.levels.dynamic_range_db
@@ -91,8 +95,8 @@ fn render_text_report(report: &jugar_probar::audio_quality::AudioQualityReport)
     );
     for region in &report.silence.regions {
         println!(
-            "    Region: {:.1}s - {:.1}s ({:.1}s)",
-            region.start_secs, region.end_secs, region.duration_secs
+            "    Region: {:.1}s - {:.1}s ({:.1}s, {})",
+            region.start_secs, region.end_secs, region.duration_secs, region.kind
         );
     }
     println!("Verdict: {}", report.verdict);
@@ -103,7 +107,8 @@ fn render_text_report(report: &jugar_probar::audio_quality::AudioQualityReport)
 mod tests {
     use super::*;
     use jugar_probar::audio_quality::{
-        AudioLevels, AudioQualityReport, AudioVerdict, ClippingReport, SilenceRegion, SilenceReport,
+        AudioLevels, AudioQualityReport, AudioVerdict, ClippingReport, SilenceKind, SilenceRegion,
+        SilenceReport,
     };
 
     fn sample_report() -> AudioQualityReport {
@@ -115,6 +120,8 @@ mod tests {
                 peak_dbfs: -6.0,
                 rms: 0.2,
                 rms_dbfs: -14.0,
+                dc_offset: 0.0,
+                dc_offset_dbfs: -120.0,
                 dynamic_range_db: 40.0,
                 passed: true,
             },
@@ -149,6 +156,7 @@ mod tests {
             start_secs: 2.0,
             end_secs: 3.5,
             duration_secs: 1.5,
+            kind: SilenceKind::Internal,
         }];
         report.silence.total_silence_secs = 1.5;
         report.silence.silence_pct = 15.0;
@@ -165,6 +173,16 @@ mod tests {
         render_text_report(&report);
     }
 
+    #[test]
+    fn test_render_text_report_dc_offset() {
+        let mut report = sample_report();
+        report.verdict = AudioVerdict::DcOffset;
+        report.levels.passed = false;
+        report.levels.dc_offset = 0.1;
+        report.levels.dc_offset_dbfs = -20.0;
+        render_text_report(&report);
+    }
+
     #[test]
     fn test_execute_check_missing_file() {
         let config = CliConfig::new();
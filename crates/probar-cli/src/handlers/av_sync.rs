@@ -7,7 +7,7 @@ use crate::config::CliConfig;
 use crate::error::{CliError, CliResult};
 use jugar_probar::av_sync::{
     compare_edl_to_onsets, default_edl_path, detect_onsets, extract_audio, DetectionConfig,
-    EditDecisionList, SyncVerdict, DEFAULT_SAMPLE_RATE,
+    EditDecisionList, DEFAULT_SAMPLE_RATE,
 };
 use std::path::Path;
 
@@ -51,10 +51,10 @@ pub fn execute_check(config: &CliConfig, args: &AvSyncCheckArgs) -> CliResult<()
         }
     }
 
-    if report.verdict == SyncVerdict::Fail {
+    if report.verdict.is_failure() {
         Err(CliError::test_execution(format!(
-            "AV sync check failed: {}/{} ticks passed (max drift: {:.1}ms)",
-            report.matched_ticks, report.total_ticks, report.max_delta_ms
+            "AV sync check failed: {} ({}/{} ticks passed, max drift: {:.1}ms)",
+            report.verdict, report.matched_ticks, report.total_ticks, report.max_delta_ms
         )))
     } else {
         Ok(())
@@ -105,7 +105,7 @@ fn check_all_edls(
         let edl = load_edl(edl_path)?;
         if let Some(vp) = find_video_for_edl(edl_path) {
             let report = run_av_sync_check(&vp, &edl, tolerance_ms, config)?;
-            if report.verdict == SyncVerdict::Fail {
+            if report.verdict.is_failure() {
                 all_passed = false;
             }
             reports.push(report);
@@ -272,6 +272,10 @@ pub fn render_text_report(
         }
     }
 
+    if let Some(slope) = report.drift_slope {
+        out.push_str(&format!("Drift trend: {slope:+.2}ms/s\n"));
+    }
+
     out.push_str(&format!(
         "Verdict: {} ({}/{} ticks passed, max drift: {:.1}ms)\n",
         report.verdict, report.matched_ticks, report.total_ticks, report.max_delta_ms
@@ -284,7 +288,7 @@ pub fn render_text_report(
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
-    use jugar_probar::av_sync::{AvSyncReport, SegmentSyncResult, TickDelta};
+    use jugar_probar::av_sync::{AvSyncReport, SegmentSyncResult, SyncVerdict, TickDelta};
 
     fn sample_pass_report() -> AvSyncReport {
         AvSyncReport {
@@ -301,12 +305,15 @@ mod tests {
                     passed: true,
                 }],
                 all_passed: true,
+                drift_slope: None,
             }],
             total_ticks: 1,
             matched_ticks: 1,
             coverage_pct: 100.0,
             max_delta_ms: 10.0,
             mean_delta_ms: 10.0,
+            drift_slope: None,
+            drift_r_squared: None,
         }
     }
 
@@ -325,12 +332,15 @@ mod tests {
                     passed: false,
                 }],
                 all_passed: false,
+                drift_slope: None,
             }],
             total_ticks: 1,
             matched_ticks: 0,
             coverage_pct: 0.0,
             max_delta_ms: 292.0,
             mean_delta_ms: 292.0,
+            drift_slope: None,
+            drift_r_squared: None,
         }
     }
 
@@ -361,6 +371,16 @@ mod tests {
         assert!(text.contains("Verdict:"));
     }
 
+    #[test]
+    fn test_render_text_report_drift_trend() {
+        let mut report = sample_pass_report();
+        report.verdict = SyncVerdict::AccumulatingDrift;
+        report.drift_slope = Some(3.0);
+        let text = render_text_report(&report, 20.0, false);
+        assert!(text.contains("Drift trend: +3.00ms/s"));
+        assert!(text.contains("Verdict: ACCUMULATING DRIFT"));
+    }
+
     #[test]
     fn test_render_text_report_no_match() {
         let report = AvSyncReport {
@@ -377,12 +397,15 @@ mod tests {
                     passed: false,
                 }],
                 all_passed: false,
+                drift_slope: None,
             }],
             total_ticks: 1,
             matched_ticks: 0,
             coverage_pct: 0.0,
             max_delta_ms: 0.0,
             mean_delta_ms: 0.0,
+            drift_slope: None,
+            drift_r_squared: None,
         };
         let text = render_text_report(&report, 20.0, true);
         assert!(text.contains("actual=NONE"));
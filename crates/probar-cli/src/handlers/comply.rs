@@ -113,6 +113,11 @@ pub fn run_compliance_checks(config: &CliConfig, args: &ComplyArgs) -> CliResult
             "No panic paths",
             Box::new(|path, _| check_c010_panic_paths(path)),
         ),
+        (
+            "C011",
+            "CORP headers",
+            Box::new(|path, _| check_c011_corp_header(path)),
+        ),
     ];
 
     let filtered_checks: Vec<(&str, &str, CheckFn)> = if let Some(ref requested) = args.checks {
@@ -339,10 +344,9 @@ pub fn check_c005_low_memory() -> ComplianceResult {
 /// C006: COOP/COEP headers present for `SharedArrayBuffer`
 #[must_use]
 pub fn check_c006_headers(path: &Path) -> ComplianceResult {
-    let has_server_config = path.join(".htaccess").exists()
-        || path.join("vercel.json").exists()
-        || path.join("netlify.toml").exists()
-        || path.join("_headers").exists();
+    let has_server_config = SERVER_HEADER_CONFIGS
+        .iter()
+        .any(|name| path.join(name).exists());
 
     let has_probar_config = check_probar_cross_origin_config(path);
     let has_makefile_config = check_makefile_cross_origin(path);
@@ -412,10 +416,59 @@ pub fn check_c010_panic_paths(path: &Path) -> ComplianceResult {
     ComplianceResult::pass("C010").with_detail("Verify panic-free via clippy::panic lint")
 }
 
+/// C011: Cross-Origin-Resource-Policy set on WASM/JS assets when cross-origin isolated
+///
+/// Under `Cross-Origin-Embedder-Policy: require-corp`, browsers refuse
+/// cross-origin WASM/JS assets (e.g. from a CDN) that lack CORP.
+#[must_use]
+pub fn check_c011_corp_header(path: &Path) -> ComplianceResult {
+    // Header names and values are case-insensitive
+    let server_configs: Vec<String> = read_server_header_configs(path)
+        .iter()
+        .map(|content| content.to_ascii_lowercase())
+        .collect();
+    let threading_enabled = server_configs
+        .iter()
+        .any(|content| content.contains("require-corp"))
+        || check_probar_cross_origin_config(path)
+        || check_makefile_cross_origin(path);
+
+    if !threading_enabled {
+        return ComplianceResult::pass("C011")
+            .with_detail("Cross-origin isolation not enabled (CORP not required)");
+    }
+
+    let has_corp = server_configs
+        .iter()
+        .any(|content| content.contains("cross-origin-resource-policy"));
+
+    if has_corp {
+        ComplianceResult::pass("C011").with_detail("Cross-Origin-Resource-Policy configured")
+    } else {
+        ComplianceResult::fail(
+            "C011",
+            "Threading enabled but no Cross-Origin-Resource-Policy header configured",
+        )
+        .with_detail("Add Cross-Origin-Resource-Policy: cross-origin to .wasm and .js responses")
+        .with_detail("Use same-site instead if assets are only served from your own subdomains")
+    }
+}
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
 
+/// Static hosting config files that can declare response headers
+const SERVER_HEADER_CONFIGS: &[&str] = &[".htaccess", "vercel.json", "netlify.toml", "_headers"];
+
+/// Read the contents of every server header config present in `path`
+fn read_server_header_configs(path: &Path) -> Vec<String> {
+    SERVER_HEADER_CONFIGS
+        .iter()
+        .filter_map(|name| std::fs::read_to_string(path.join(name)).ok())
+        .collect()
+}
+
 /// Check probar.toml for `cross_origin_isolated` setting
 #[must_use]
 pub fn check_probar_cross_origin_config(path: &Path) -> bool {
@@ -695,6 +748,58 @@ panic = "abort"
             .any(|d| d.contains("panic = \"abort\"")));
     }
 
+    #[test]
+    fn test_check_c011_not_isolated() {
+        let temp = TempDir::new().unwrap();
+        let result = check_c011_corp_header(temp.path());
+        assert!(result.passed);
+    }
+
+    #[test]
+    fn test_check_c011_isolated_without_corp() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("_headers"),
+            "/*\n  Cross-Origin-Opener-Policy: same-origin\n  Cross-Origin-Embedder-Policy: require-corp\n",
+        )
+        .unwrap();
+        let result = check_c011_corp_header(temp.path());
+        assert!(!result.passed);
+        assert!(result
+            .details
+            .iter()
+            .any(|d| d.contains("Cross-Origin-Resource-Policy: cross-origin")));
+    }
+
+    #[test]
+    fn test_check_c011_mixed_case_coep() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("_headers"),
+            "/*\n  cross-origin-embedder-policy: Require-Corp\n",
+        )
+        .unwrap();
+        let result = check_c011_corp_header(temp.path());
+        assert!(!result.passed);
+    }
+
+    #[test]
+    fn test_check_c011_isolated_with_corp() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("probar.toml"),
+            "[server]\ncross_origin_isolated = true",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("_headers"),
+            "/*.wasm\n  Cross-Origin-Resource-Policy: cross-origin\n",
+        )
+        .unwrap();
+        let result = check_c011_corp_header(temp.path());
+        assert!(result.passed);
+    }
+
     #[test]
     fn test_check_probar_cross_origin_config_false() {
         let temp = TempDir::new().unwrap();
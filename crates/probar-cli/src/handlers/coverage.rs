@@ -168,6 +168,7 @@ pub fn generate_coverage_report(cells: &[Vec<CoverageCell>]) -> PixelCoverageRep
         total_interactions: 0,
         meets_threshold: overall_coverage >= 0.8,
         uncovered_regions: Vec::new(),
+        weighted_coverage: None,
     }
 }
 
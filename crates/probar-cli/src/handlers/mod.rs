@@ -23,8 +23,9 @@ pub mod video;
 pub use comply::{
     check_c001_code_execution, check_c002_console_errors, check_c003_custom_elements,
     check_c004_threading_modes, check_c005_low_memory, check_c006_headers, check_c007_replay_hash,
-    check_c008_cache, check_c009_wasm_size, check_c010_panic_paths, check_makefile_cross_origin,
-    check_probar_cross_origin_config, run_compliance_checks, ComplianceResult,
+    check_c008_cache, check_c009_wasm_size, check_c010_panic_paths, check_c011_corp_header,
+    check_makefile_cross_origin, check_probar_cross_origin_config, run_compliance_checks,
+    ComplianceResult,
 };
 pub use config::execute_config;
 pub use coverage::{
@@ -134,6 +134,9 @@ pub fn generate_junit_report() -> String {
 }
 
 /// Generate Cobertura XML report
+///
+/// Branch coverage is not collected here, so branch metrics are reported as
+/// zero rather than as a vacuous 100%.
 #[must_use]
 pub fn generate_cobertura_report() -> String {
     let timestamp = chrono::Utc::now().timestamp();
@@ -198,6 +201,9 @@ mod tests {
         assert!(cobertura.contains("<?xml"));
         assert!(cobertura.contains("<coverage"));
         assert!(cobertura.contains("line-rate=\"0\""));
+        assert!(cobertura.contains("branches-valid=\"0\""));
+        assert!(cobertura.contains("branches-covered=\"0\""));
+        assert!(cobertura.contains("branch-rate=\"0\""));
     }
 
     #[test]
@@ -18,7 +18,7 @@ pub fn validate_imports(directory: &Path, exclude: &[String]) -> CliResult<()> {
     if !result.is_ok() {
         return Err(crate::CliError::test_execution(format!(
             "Module validation failed: {} error(s) found. Fix imports before serving.",
-            result.errors.len()
+            result.problem_count()
         )));
     }
     eprintln!("\n✓ All module imports validated successfully\n");
@@ -44,6 +44,10 @@ pub fn build_tree_config(args: &TreeArgs) -> crate::TreeConfig {
         .with_filter(args.filter.as_deref())
         .with_sizes(args.sizes)
         .with_mime_types(args.mime_types)
+        .with_format(match args.format {
+            crate::TreeOutputFormat::Ascii => crate::TreeFormat::Ascii,
+            crate::TreeOutputFormat::Json => crate::TreeFormat::Json,
+        })
 }
 
 /// Format a server URL from port
@@ -72,6 +76,7 @@ mod tests {
             filter: None,
             sizes: false,
             mime_types: false,
+            format: crate::TreeOutputFormat::Ascii,
         };
         let config = build_tree_config(&args);
         // Config should be created without panic
@@ -86,9 +91,11 @@ mod tests {
             filter: Some("*.rs".to_string()),
             sizes: true,
             mime_types: true,
+            format: crate::TreeOutputFormat::Json,
         };
         let config = build_tree_config(&args);
         assert_eq!(config.max_depth, Some(3));
+        assert_eq!(config.format, crate::TreeFormat::Json);
     }
 
     #[test]
@@ -1,11 +1,14 @@
 //! Video quality command handler.
 //!
-//! Orchestrates: probe video -> validate against expectations -> render report.
+//! Orchestrates: probe video (and frame timing, if needed) -> validate against
+//! expectations -> render report.
 
 use crate::commands::{OutputFormat, VideoCheckArgs};
 use crate::config::CliConfig;
 use crate::error::{CliError, CliResult};
-use jugar_probar::video_quality::{probe_video, validate_video, VideoExpectations, VideoVerdict};
+use jugar_probar::video_quality::{
+    probe_frame_timing, probe_video, validate_video, VideoExpectations, VideoVerdict,
+};
 
 /// Execute the video check command for a single file.
 pub fn execute_check(config: &CliConfig, args: &VideoCheckArgs) -> CliResult<()> {
@@ -21,7 +24,7 @@ pub fn execute_check(config: &CliConfig, args: &VideoCheckArgs) -> CliResult<()>
         println!("Probing video: {}", video_path.display());
     }
 
-    let probe = probe_video(video_path)
+    let mut probe = probe_video(video_path)
         .map_err(|e| CliError::test_execution(format!("Video probe failed: {e}")))?;
 
     let mut expectations = VideoExpectations::default();
@@ -47,6 +50,22 @@ pub fn execute_check(config: &CliConfig, args: &VideoCheckArgs) -> CliResult<()>
     if args.require_audio {
         expectations = expectations.with_require_audio(true);
     }
+    if args.constant_frame_rate {
+        expectations = expectations.require_constant_frame_rate(true);
+    }
+    if let Some(max_run) = args.max_duplicate_frame_run {
+        expectations = expectations.with_max_duplicate_frame_run(max_run);
+    }
+
+    if expectations.needs_frame_timing() {
+        if config.verbosity.is_verbose() {
+            println!("Probing frame timing: {}", video_path.display());
+        }
+        probe.frame_timing =
+            Some(probe_frame_timing(video_path).map_err(|e| {
+                CliError::test_execution(format!("Frame timing probe failed: {e}"))
+            })?);
+    }
 
     let report = validate_video(&probe, &expectations, &video_path.display().to_string());
 
@@ -128,6 +147,7 @@ mod tests {
             audio_codec: Some("aac".to_string()),
             audio_sample_rate: Some(48000),
             audio_channels: Some(2),
+            frame_timing: None,
         }
     }
 
@@ -189,6 +209,8 @@ mod tests {
             min_duration: None,
             max_duration: None,
             require_audio: false,
+            constant_frame_rate: false,
+            max_duplicate_frame_run: None,
             format: OutputFormat::Text,
         };
         let result = execute_check(&config, &args);
@@ -64,25 +64,29 @@ pub use commands::{
     InitArgs, LlmArgs, LlmBenchArgs, LlmGenDatasetArgs, LlmLoadArgs, LlmReportArgs, LlmScoreArgs,
     LlmSubcommand, LlmSweepArgs, LlmTestArgs, OutputFormat, PaletteArg, PlaybookArgs,
     PlaybookOutputFormat, RecordArgs, RecordFormat, ReportArgs, ReportFormat, ScoreArgs,
-    ScoreOutputFormat, ServeArgs, ServeSubcommand, StressArgs, TestArgs, TreeArgs, VideoArgs,
-    VideoCheckArgs, VideoSubcommand, VizArgs, WasmTarget, WatchArgs,
+    ScoreOutputFormat, ServeArgs, ServeSubcommand, StressArgs, TestArgs, TreeArgs,
+    TreeOutputFormat, VideoArgs, VideoCheckArgs, VideoSubcommand, VizArgs, WasmTarget, WatchArgs,
 };
 pub use config::{CliConfig, ColorChoice, Verbosity};
 pub use debug::{create_tracer, DebugCategory, DebugTracer, DebugVerbosity, ResolutionRule};
 pub use dev_server::{
-    get_mime_type, DevServer, DevServerConfig, DevServerConfigBuilder, FileChangeEvent,
-    FileWatcher, FileWatcherBuilder, HotReloadMessage, ImportRef, ImportType,
-    ImportValidationError, ModuleValidationResult, ModuleValidator,
+    get_mime_type, inject_hot_reload_client, ChangeKind, DevServer, DevServerConfig,
+    DevServerConfigBuilder, FileChangeEvent, FileWatcher, FileWatcherBuilder, HotReloadMessage,
+    ImportCycle, ImportRef, ImportType, ImportValidationError, ModuleValidationResult,
+    ModuleValidator, RangeRequest,
 };
 pub use error::{CliError, CliResult};
 pub use lint::{
-    render_lint_json, render_lint_report, ContentLinter, LintReport, LintResult, LintSeverity,
+    render_lint_json, render_lint_report, ContentLinter, ContentRule, LintReport, LintResult,
+    LintSeverity,
 };
 pub use load_testing::{
-    render_load_test_json, render_load_test_report, AssertionResult as LoadAssertionResult,
+    render_load_test_json, render_load_test_report, render_saturation_json,
+    render_saturation_report, ArrivalDistribution, AssertionResult as LoadAssertionResult,
     EndpointStats, HttpMethod, LatencyHistogram, LoadTestAssertion, LoadTestConfig, LoadTestError,
     LoadTestErrorKind, LoadTestOutputFormat, LoadTestRequest, LoadTestResult, LoadTestScenario,
-    LoadTestStage, ResourceUsage, UserConfig,
+    LoadTestStage, ResourceUsage, SaturationProbe, SaturationReport, SaturationSearch,
+    ScheduledRequest, UserConfig,
 };
 pub use output::{OutputFormat as CliOutputFormat, ProgressReporter};
 pub use runner::TestRunner;
@@ -90,7 +94,7 @@ pub use score::{
     CategoryScore, CategoryStatus, CriterionResult, Effort, Grade, ProjectScore, Recommendation,
     ScoreCalculator,
 };
-pub use tree::{build_tree, display_tree, render_tree, FileNode, TreeConfig};
+pub use tree::{build_tree, display_tree, render_tree, FileNode, TreeConfig, TreeFormat};
 pub use wasm_testing::{
     compare_performance, render_performance_report, Browser, BrowserMatrix, BrowserTestResult,
     ComparisonStatus, KeyModifiers, MemoryGrowthEvent, MemoryProfile, MemorySnapshot,
@@ -105,22 +109,23 @@ pub use visualization::{
 };
 // PROBAR-SPEC-006 Section I: Statistical Analysis
 pub use statistics::{
-    render_statistical_json, render_statistical_report, ApdexCalculator, ApdexRating, KneeDetector,
-    LatencySample, QuantileRegression, StatisticalAnalysis, TailAttribution, VarianceComponent,
-    VarianceTree,
+    render_statistical_json, render_statistical_report, ApdexCalculator, ApdexRating, ApdexSummary,
+    KneeDetector, LatencySample, QuantileRegression, StatisticalAnalysis, TailAttribution,
+    VarianceComponent, VarianceTree,
 };
 // PROBAR-SPEC-006 Section J: Deep Tracing
 pub use tracing::{
-    render_trace_json, render_trace_report, Flamegraph, FlamegraphNode, OptimizationSuggestion,
-    SourceHotspot, SourceLocation, SyscallStats, TraceAnalysis, TraceCategory, TraceConfig,
-    TraceSpan, WasmEvent, WasmEventType,
+    render_trace_json, render_trace_report, DiffStatus, Flamegraph, FlamegraphDelta,
+    FlamegraphNode, OptimizationSuggestion, SourceHotspot, SourceLocation, SyscallStats,
+    TraceAnalysis, TraceCategory, TraceConfig, TraceSpan, WasmEvent, WasmEventType,
 };
 // PROBAR-SPEC-006 Section K: Simulation Playback
 pub use simulation::{
-    render_chaos_report, render_monte_carlo_json, render_monte_carlo_report, ChaosObservation,
-    ChaosResult, Distribution, FailureInjection, ImpactLevel, InjectionType, LatencyDistribution,
-    MonteCarloResult, ObservationSeverity, ParameterVariation, RiskLevel, SensitivityFactor,
-    SimulationConfig, SimulationMode, SimulationOutput, SlaProbability,
+    pearson_correlation, render_chaos_report, render_monte_carlo_json, render_monte_carlo_report,
+    ChaosObservation, ChaosResult, CorrelatedSampler, DegradeCurve, DegradeMetric, Distribution,
+    FailureInjection, ImpactLevel, InjectionType, LatencyDistribution, MetricValue,
+    MonteCarloResult, ObservationSeverity, ParameterCorrelation, ParameterVariation, RiskLevel,
+    SensitivityFactor, SimulationConfig, SimulationMode, SimulationOutput, SlaProbability,
 };
 // PROBAR-SPEC-WASM-001 Section H: Browser/WASM Stress Testing
 pub use stress::{
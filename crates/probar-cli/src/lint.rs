@@ -11,6 +11,9 @@
 //! | JavaScript | Syntax errors, module resolution |
 //! | WASM | Valid module structure |
 //! | JSON | Parse validity |
+//!
+//! Project-specific checks (forbidden words, required meta tags) plug in via
+//! the [`ContentRule`] trait and [`ContentLinter::register_rule`].
 
 #![allow(clippy::must_use_candidate)]
 #![allow(clippy::missing_panics_doc)]
@@ -207,8 +210,25 @@ impl LintReport {
     }
 }
 
+/// A custom content rule registered with [`ContentLinter::register_rule`]
+///
+/// Rules see the file content as text and return findings with whatever
+/// [`LintSeverity`] they choose; findings are merged into the [`LintReport`].
+pub trait ContentRule: Send + Sync {
+    /// Rule name (for diagnostics)
+    fn name(&self) -> &str;
+
+    /// Whether the rule should run on `path` (default: all text content types)
+    fn applies_to(&self, path: &Path) -> bool {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        matches!(extension, "html" | "htm" | "css" | "js" | "mjs" | "json")
+    }
+
+    /// Check file content and return findings
+    fn check(&self, content: &str, path: &Path) -> Vec<LintResult>;
+}
+
 /// Content linter
-#[derive(Debug)]
 pub struct ContentLinter {
     /// Root directory to lint
     root: PathBuf,
@@ -222,6 +242,25 @@ pub struct ContentLinter {
     pub lint_wasm: bool,
     /// Lint JSON files
     pub lint_json: bool,
+    /// Custom rules run after the built-in checks
+    rules: Vec<Box<dyn ContentRule>>,
+}
+
+impl std::fmt::Debug for ContentLinter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContentLinter")
+            .field("root", &self.root)
+            .field("lint_html", &self.lint_html)
+            .field("lint_css", &self.lint_css)
+            .field("lint_js", &self.lint_js)
+            .field("lint_wasm", &self.lint_wasm)
+            .field("lint_json", &self.lint_json)
+            .field(
+                "rules",
+                &self.rules.iter().map(|r| r.name()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
 }
 
 impl ContentLinter {
@@ -234,9 +273,15 @@ impl ContentLinter {
             lint_js: true,
             lint_wasm: true,
             lint_json: true,
+            rules: Vec::new(),
         }
     }
 
+    /// Register a custom content rule
+    pub fn register_rule(&mut self, rule: Box<dyn ContentRule>) {
+        self.rules.push(rule);
+    }
+
     /// Lint all files in the directory
     pub fn lint(&self) -> LintReport {
         let mut report = LintReport::new(&self.root);
@@ -248,14 +293,33 @@ impl ContentLinter {
     pub fn lint_file(&self, path: &Path) -> Vec<LintResult> {
         let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
-        match extension {
+        let mut results = match extension {
             "html" | "htm" if self.lint_html => self.lint_html_file(path),
             "css" if self.lint_css => self.lint_css_file(path),
             "js" | "mjs" if self.lint_js => self.lint_js_file(path),
             "wasm" if self.lint_wasm => self.lint_wasm_file(path),
             "json" if self.lint_json => self.lint_json_file(path),
             _ => Vec::new(),
+        };
+        results.extend(self.run_custom_rules(path));
+        results
+    }
+
+    fn run_custom_rules(&self, path: &Path) -> Vec<LintResult> {
+        let rules: Vec<_> = self.rules.iter().filter(|r| r.applies_to(path)).collect();
+        if rules.is_empty() {
+            return Vec::new();
         }
+
+        // Binary or unreadable files are left to the built-in checks
+        let content = match std::fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(_) => return Vec::new(),
+        };
+        rules
+            .iter()
+            .flat_map(|rule| rule.check(&content, path))
+            .collect()
     }
 
     fn lint_directory(&self, dir: &Path, report: &mut LintReport) {
@@ -715,6 +779,59 @@ mod tests {
         assert!(results.iter().all(|r| r.severity != LintSeverity::Error));
     }
 
+    struct NoTodoInHtml;
+
+    impl ContentRule for NoTodoInHtml {
+        fn name(&self) -> &'static str {
+            "no-todo-in-html"
+        }
+
+        fn applies_to(&self, path: &Path) -> bool {
+            path.extension().is_some_and(|e| e == "html")
+        }
+
+        fn check(&self, content: &str, path: &Path) -> Vec<LintResult> {
+            content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.contains("TODO"))
+                .map(|(i, _)| {
+                    LintResult::error(path, "CUSTOM001", "TODO left in shipped HTML")
+                        .at_line(i as u32 + 1)
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_register_custom_rule() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("index.html"),
+            "<!DOCTYPE html>\n<html><head></head>\n<body><!-- TODO: hero --></body></html>",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("app.js"), "// TODO: not HTML\n").unwrap();
+
+        let mut linter = ContentLinter::new(temp.path());
+        linter.register_rule(Box::new(NoTodoInHtml));
+        assert!(format!("{linter:?}").contains("no-todo-in-html"));
+
+        let report = linter.lint();
+        let custom: Vec<_> = report
+            .results
+            .iter()
+            .filter(|r| r.code == "CUSTOM001")
+            .collect();
+
+        assert_eq!(custom.len(), 1);
+        assert_eq!(custom[0].severity, LintSeverity::Error);
+        assert_eq!(custom[0].line, Some(3));
+        assert!(custom[0].file.ends_with("index.html"));
+        assert_eq!(report.errors, 1);
+        assert!(!report.passed());
+    }
+
     #[test]
     fn test_lint_html_missing_alt() {
         let temp = TempDir::new().unwrap();
@@ -23,6 +23,8 @@
 #![allow(clippy::return_self_not_must_use)]
 #![allow(clippy::unwrap_used)]
 
+use crate::statistics::{ApdexSummary, KneeDetector};
+use jugar_probar::brick::DeterministicRng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -44,6 +46,9 @@ pub struct LoadTestConfig {
     pub scenario: Option<PathBuf>,
     /// Output format
     pub output: LoadTestOutputFormat,
+    /// Apdex target T in ms (satisfied <= T, tolerating <= 4T)
+    #[serde(default)]
+    pub apdex_threshold_ms: Option<u64>,
 }
 
 impl LoadTestConfig {
@@ -55,6 +60,7 @@ impl LoadTestConfig {
             duration_secs,
             scenario: None,
             output: LoadTestOutputFormat::Text,
+            apdex_threshold_ms: None,
         }
     }
 
@@ -76,9 +82,16 @@ impl LoadTestConfig {
             duration_secs,
             scenario: None,
             output: LoadTestOutputFormat::Text,
+            apdex_threshold_ms: None,
         }
     }
 
+    /// Compute Apdex with target T in ms (satisfied <= T, tolerating <= 4T)
+    pub fn apdex_threshold_ms(mut self, threshold_ms: u64) -> Self {
+        self.apdex_threshold_ms = Some(threshold_ms);
+        self
+    }
+
     /// Create from scenario file
     pub fn from_scenario(scenario_path: PathBuf) -> Self {
         Self {
@@ -87,6 +100,7 @@ impl LoadTestConfig {
             duration_secs: 0,
             scenario: Some(scenario_path),
             output: LoadTestOutputFormat::Text,
+            apdex_threshold_ms: None,
         }
     }
 }
@@ -160,6 +174,9 @@ pub struct LoadTestScenario {
     pub stages: Vec<LoadTestStage>,
     /// Request definitions
     pub requests: Vec<LoadTestRequest>,
+    /// Seed for arrival scheduling and request selection
+    #[serde(default)]
+    pub seed: u64,
 }
 
 impl LoadTestScenario {
@@ -171,9 +188,16 @@ impl LoadTestScenario {
             citation: None,
             stages: Vec::new(),
             requests: Vec::new(),
+            seed: 0,
         }
     }
 
+    /// Set the scheduling seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
     /// Add a stage
     pub fn add_stage(&mut self, stage: LoadTestStage) {
         self.stages.push(stage);
@@ -189,6 +213,40 @@ impl LoadTestScenario {
         self.stages.iter().map(|s| s.duration_secs).sum()
     }
 
+    /// Schedule every request of the test
+    ///
+    /// Arrival times follow each stage's [`ArrivalDistribution`] and requests
+    /// are picked by weight. The same seed always yields the same schedule.
+    /// Returns an empty schedule when the scenario has no requests.
+    pub fn schedule(&self) -> Vec<ScheduledRequest> {
+        if self.requests.is_empty() {
+            return Vec::new();
+        }
+        let total_weight: f64 = self.requests.iter().map(|r| r.weight.max(0.0)).sum();
+
+        let mut rng = DeterministicRng::new(self.seed ^ 0x9E37_79B9_7F4A_7C15);
+        let mut schedule = Vec::new();
+        let mut stage_start = 0.0;
+
+        for (stage_idx, stage) in self.stages.iter().enumerate() {
+            for offset in stage.arrival_times(&mut rng) {
+                let request = if total_weight > 0.0 {
+                    pick_weighted(&self.requests, rng.next_f64() * total_weight)
+                } else {
+                    0
+                };
+                schedule.push(ScheduledRequest {
+                    at_secs: stage_start + offset,
+                    stage: stage_idx,
+                    request,
+                });
+            }
+            stage_start += stage.duration_secs as f64;
+        }
+
+        schedule
+    }
+
     /// Load from YAML string
     pub fn from_yaml(yaml: &str) -> Result<Self, String> {
         serde_yaml_ng::from_str(yaml).map_err(|e| format!("Failed to parse YAML: {}", e))
@@ -220,6 +278,9 @@ pub struct LoadTestStage {
     pub users_start: u32,
     /// Ending users (for ramp stages, same as start for steady)
     pub users_end: u32,
+    /// How request arrivals are spaced within the stage
+    #[serde(default)]
+    pub arrivals: ArrivalDistribution,
 }
 
 impl LoadTestStage {
@@ -230,6 +291,7 @@ impl LoadTestStage {
             duration_secs,
             users_start: users,
             users_end: users,
+            arrivals: ArrivalDistribution::Constant,
         }
     }
 
@@ -240,9 +302,16 @@ impl LoadTestStage {
             duration_secs,
             users_start: start_users,
             users_end: end_users,
+            arrivals: ArrivalDistribution::Constant,
         }
     }
 
+    /// Set the arrival distribution
+    pub fn with_arrivals(mut self, arrivals: ArrivalDistribution) -> Self {
+        self.arrivals = arrivals;
+        self
+    }
+
     /// Check if this is a ramp stage
     pub fn is_ramp(&self) -> bool {
         self.users_start != self.users_end
@@ -257,6 +326,109 @@ impl LoadTestStage {
         let range = (self.users_end as i64 - self.users_start as i64) as f64;
         (self.users_start as f64 + range * progress) as u32
     }
+
+    /// Arrival times in seconds from the start of the stage
+    ///
+    /// Only `Poisson` stages draw from `rng`; the others are fully
+    /// deterministic.
+    pub fn arrival_times(&self, rng: &mut DeterministicRng) -> Vec<f64> {
+        let duration = self.duration_secs as f64;
+        match self.arrivals {
+            ArrivalDistribution::Constant => {
+                linear_rate_arrivals(self.users_start as f64, self.users_end as f64, duration)
+            }
+            ArrivalDistribution::Ramp { from, to } => {
+                linear_rate_arrivals(from.max(0.0), to.max(0.0), duration)
+            }
+            ArrivalDistribution::Poisson(lambda) => {
+                let mut times = Vec::new();
+                if lambda <= 0.0 {
+                    return times;
+                }
+                let mut t = 0.0;
+                loop {
+                    // Exponential inter-arrival gap; 1 - u keeps ln() finite
+                    t += -(1.0 - rng.next_f64()).ln() / lambda;
+                    if t >= duration {
+                        break;
+                    }
+                    times.push(t);
+                }
+                times
+            }
+        }
+    }
+}
+
+/// Arrival distribution within a load test stage
+///
+/// Rates are in requests per second. Scenario YAML selects a variant with a
+/// tag, e.g. `arrivals: !poisson 12.5`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ArrivalDistribution {
+    /// Evenly spaced arrivals, one request per user per second
+    #[default]
+    Constant,
+    /// Bursty Poisson process with `lambda` arrivals per second on average
+    Poisson(f64),
+    /// Evenly spaced arrivals whose rate moves linearly over the stage
+    Ramp {
+        /// Rate at the start of the stage
+        from: f64,
+        /// Rate at the end of the stage
+        to: f64,
+    },
+}
+
+/// A request scheduled by [`LoadTestScenario::schedule`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledRequest {
+    /// Seconds since the start of the test
+    pub at_secs: f64,
+    /// Index into the scenario's stages
+    pub stage: usize,
+    /// Index into the scenario's requests
+    pub request: usize,
+}
+
+/// Evenly spaced arrivals for a rate moving linearly from `from` to `to`
+///
+/// Arrival `k` is placed where the cumulative arrival count
+/// `from * t + (to - from) * t^2 / (2 * duration)` reaches `k`.
+fn linear_rate_arrivals(from: f64, to: f64, duration: f64) -> Vec<f64> {
+    if duration <= 0.0 {
+        return Vec::new();
+    }
+    let curve = (to - from) / (2.0 * duration);
+    let total = (from + to) * duration / 2.0;
+    let count = (total + 1e-9).floor() as u64;
+
+    (0..count)
+        .map(|k| {
+            let k = k as f64;
+            // Smaller root of curve*t^2 + from*t - k, stable when curve == 0
+            let denom = from + (from * from + 4.0 * curve * k).max(0.0).sqrt();
+            if denom > 0.0 {
+                2.0 * k / denom
+            } else {
+                0.0
+            }
+        })
+        .filter(|&t| t < duration)
+        .collect()
+}
+
+/// Index of the request selected by a point in `[0, total_weight)`
+fn pick_weighted(requests: &[LoadTestRequest], mut point: f64) -> usize {
+    for (idx, request) in requests.iter().enumerate() {
+        let weight = request.weight.max(0.0);
+        if point < weight {
+            return idx;
+        }
+        point -= weight;
+    }
+    requests.len() - 1
 }
 
 /// A request definition in the scenario
@@ -468,6 +640,9 @@ pub struct LoadTestResult {
     pub assertion_results: Vec<AssertionResult>,
     /// Errors encountered
     pub errors: Vec<LoadTestError>,
+    /// Overall Apdex, when an Apdex threshold is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apdex: Option<ApdexSummary>,
 }
 
 impl LoadTestResult {
@@ -486,9 +661,29 @@ impl LoadTestResult {
             resource_usage: ResourceUsage::default(),
             assertion_results: Vec::new(),
             errors: Vec::new(),
+            apdex: None,
         }
     }
 
+    /// Compute Apdex per endpoint and overall from latency samples
+    ///
+    /// `samples` maps endpoint names (as in `endpoint_stats`) to latencies in
+    /// ms. Does nothing unless `config` sets an Apdex threshold.
+    pub fn apply_apdex(&mut self, config: &LoadTestConfig, samples: &HashMap<String, Vec<u64>>) {
+        let Some(threshold_ms) = config.apdex_threshold_ms else {
+            return;
+        };
+
+        for stat in &mut self.endpoint_stats {
+            if let Some(latencies) = samples.get(&stat.name) {
+                stat.apdex = Some(ApdexSummary::from_samples(threshold_ms, latencies));
+            }
+        }
+
+        let all: Vec<u64> = samples.values().flatten().copied().collect();
+        self.apdex = Some(ApdexSummary::from_samples(threshold_ms, &all));
+    }
+
     /// Calculate error rate as percentage
     pub fn error_rate(&self) -> f64 {
         if self.total_requests == 0 {
@@ -535,6 +730,9 @@ pub struct EndpointStats {
     pub max_ms: u64,
     /// Avg latency in ms
     pub avg_ms: u64,
+    /// Apdex for this endpoint, when an Apdex threshold is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apdex: Option<ApdexSummary>,
 }
 
 impl EndpointStats {
@@ -550,6 +748,7 @@ impl EndpointStats {
             min_ms: u64::MAX,
             max_ms: 0,
             avg_ms: 0,
+            apdex: None,
         }
     }
 
@@ -575,6 +774,7 @@ impl EndpointStats {
             min_ms: *sorted.first().unwrap_or(&0),
             max_ms: *sorted.last().unwrap_or(&0),
             avg_ms: sum / count,
+            apdex: None,
         }
     }
 }
@@ -798,6 +998,156 @@ impl Default for LatencyHistogram {
     }
 }
 
+// =============================================================================
+// Saturation Search (latency knee detection)
+// =============================================================================
+
+/// Measurement taken at one concurrency level during a saturation search
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct SaturationProbe {
+    /// Concurrent users/workers
+    pub concurrency: u32,
+    /// Latency at this level in ms
+    pub latency_ms: f64,
+    /// Throughput at this level (req/s or ops/s)
+    pub throughput: f64,
+}
+
+/// Ramp concurrency until the latency knee is found
+///
+/// Concurrency grows linearly from `start` by `step`. After each probe the
+/// [`KneeDetector`] runs over all probes so far; the search stops once a knee
+/// is found and latency has climbed to `growth_factor` times the latency at
+/// the knee, or when `max_concurrency` is reached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaturationSearch {
+    /// First concurrency level
+    pub start: u32,
+    /// Concurrency increment per probe
+    pub step: u32,
+    /// Highest concurrency level to probe
+    pub max_concurrency: u32,
+    /// Latency growth past the knee required to stop
+    pub growth_factor: f64,
+}
+
+impl SaturationSearch {
+    /// Create a search ramping from `start` by `step` up to `max_concurrency`
+    pub fn new(start: u32, step: u32, max_concurrency: u32) -> Self {
+        Self {
+            start: start.max(1),
+            step: step.max(1),
+            max_concurrency,
+            growth_factor: 1.5,
+        }
+    }
+
+    /// Set the latency growth past the knee required to stop
+    pub fn with_growth_factor(mut self, factor: f64) -> Self {
+        self.growth_factor = factor.max(1.0);
+        self
+    }
+
+    /// Run the search, calling `probe` once per concurrency level
+    ///
+    /// `probe` returns `(latency_ms, throughput)` for a concurrency level.
+    pub fn run<F>(&self, mut probe: F) -> SaturationReport
+    where
+        F: FnMut(u32) -> (f64, f64),
+    {
+        let mut detector = KneeDetector::new();
+        let mut probes = Vec::new();
+        let mut concurrency = self.start;
+
+        while concurrency <= self.max_concurrency {
+            let (latency_ms, throughput) = probe(concurrency);
+            probes.push(SaturationProbe {
+                concurrency,
+                latency_ms,
+                throughput,
+            });
+            detector.add_point(f64::from(concurrency), latency_ms);
+            detector.detect();
+
+            if let Some((_, knee_latency)) = detector.knee_point {
+                if latency_ms >= knee_latency * self.growth_factor {
+                    break;
+                }
+            }
+            concurrency = concurrency.saturating_add(self.step);
+        }
+
+        let knee = detector.knee_point.and_then(|(load, _)| {
+            probes
+                .iter()
+                .find(|p| f64::from(p.concurrency) == load)
+                .copied()
+        });
+        let stopped_early = probes
+            .last()
+            .is_some_and(|p| p.concurrency.saturating_add(self.step) <= self.max_concurrency);
+
+        SaturationReport {
+            knee,
+            recommended_concurrency: detector.recommended_capacity.map(|c| c as u32),
+            stopped_early,
+            probes,
+        }
+    }
+}
+
+/// Outcome of a [`SaturationSearch`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaturationReport {
+    /// Probe at the latency knee (the saturation point), if one was found
+    pub knee: Option<SaturationProbe>,
+    /// Recommended operating concurrency (80% of the knee)
+    pub recommended_concurrency: Option<u32>,
+    /// Whether the search stopped before reaching the maximum concurrency
+    pub stopped_early: bool,
+    /// All probes in the order they were taken
+    pub probes: Vec<SaturationProbe>,
+}
+
+/// Render a saturation search report as text
+pub fn render_saturation_report(report: &SaturationReport) -> String {
+    let mut output = String::new();
+
+    output.push_str("SATURATION SEARCH\n");
+    output.push_str("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n");
+
+    output.push_str("┌─────────────┬──────────────┬──────────────┐\n");
+    output.push_str("│ Concurrency │ Latency      │ Throughput   │\n");
+    output.push_str("├─────────────┼──────────────┼──────────────┤\n");
+    for probe in &report.probes {
+        output.push_str(&format!(
+            "│ {:>11} │ {:>10.2}ms │ {:>10.0}/s │\n",
+            probe.concurrency, probe.latency_ms, probe.throughput
+        ));
+    }
+    output.push_str("└─────────────┴──────────────┴──────────────┘\n\n");
+
+    match report.knee {
+        Some(knee) => {
+            output.push_str(&format!(
+                "Saturation: {} concurrent at {:.0}/s ({:.2}ms latency)\n",
+                knee.concurrency, knee.throughput, knee.latency_ms
+            ));
+            if let Some(recommended) = report.recommended_concurrency {
+                output.push_str(&format!("Recommended: {} concurrent\n", recommended));
+            }
+        }
+        None => output.push_str("Saturation: no latency knee found in the probed range\n"),
+    }
+
+    output
+}
+
+/// Render a saturation search report as JSON
+pub fn render_saturation_json(report: &SaturationReport) -> String {
+    serde_json::to_string_pretty(report).unwrap_or_else(|_| "{}".to_string())
+}
+
 // =============================================================================
 // Rendering
 // =============================================================================
@@ -836,6 +1186,27 @@ pub fn render_load_test_report(result: &LoadTestResult) -> String {
     }
     output.push_str("└─────────────────┴─────────┴─────────┴─────────┴─────────┴─────────┘\n\n");
 
+    // Apdex
+    if let Some(ref apdex) = result.apdex {
+        output.push_str(&format!("Apdex (T={}ms):\n", apdex.threshold_ms));
+        output.push_str(&format!(
+            "  Overall: {:.2} ({})\n",
+            apdex.score,
+            apdex.rating.as_str()
+        ));
+        for stat in &result.endpoint_stats {
+            if let Some(ref endpoint_apdex) = stat.apdex {
+                output.push_str(&format!(
+                    "  {:<15} {:.2} ({})\n",
+                    truncate(&stat.name, 15),
+                    endpoint_apdex.score,
+                    endpoint_apdex.rating.as_str()
+                ));
+            }
+        }
+        output.push('\n');
+    }
+
     // Throughput
     output.push_str("Throughput:\n");
     output.push_str(&format!(
@@ -890,6 +1261,7 @@ fn truncate(s: &str, max_len: usize) -> String {
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::float_cmp)]
 mod tests {
     use super::*;
+    use crate::statistics::ApdexRating;
 
     #[test]
     fn test_load_test_config_new() {
@@ -951,6 +1323,167 @@ mod tests {
         assert_eq!(stage.users_at(30), 50);
     }
 
+    #[test]
+    fn test_constant_arrivals_one_per_user_per_second() {
+        let stage = LoadTestStage::steady("steady", 10, 5);
+        let times = stage.arrival_times(&mut DeterministicRng::new(1));
+
+        assert_eq!(times.len(), 50);
+        assert_eq!(times[0], 0.0);
+        assert!((times[1] - 0.2).abs() < 1e-9);
+        assert!(times.iter().all(|&t| t < 10.0));
+    }
+
+    #[test]
+    fn test_ramp_arrivals_get_denser() {
+        let stage = LoadTestStage::steady("ramp", 10, 1).with_arrivals(ArrivalDistribution::Ramp {
+            from: 0.0,
+            to: 20.0,
+        });
+        let times = stage.arrival_times(&mut DeterministicRng::new(1));
+
+        // Average rate of 10/s over 10s
+        assert_eq!(times.len(), 100);
+        let first_half = times.iter().filter(|&&t| t < 5.0).count();
+        assert_eq!(first_half, 25);
+        assert!(times.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_poisson_inter_arrival_mean_matches_lambda() {
+        let lambda = 20.0;
+        let stage = LoadTestStage::steady("burst", 500, 1)
+            .with_arrivals(ArrivalDistribution::Poisson(lambda));
+        let times = stage.arrival_times(&mut DeterministicRng::new(12345));
+
+        let gaps: Vec<f64> = std::iter::once(times[0])
+            .chain(times.windows(2).map(|w| w[1] - w[0]))
+            .collect();
+        let mean = gaps.iter().sum::<f64>() / gaps.len() as f64;
+
+        assert!(
+            (mean - 1.0 / lambda).abs() < 0.05 / lambda,
+            "mean gap {mean}"
+        );
+        // Bursty: gaps vary, unlike constant arrivals
+        assert!(gaps.iter().any(|&g| g < 0.2 / lambda));
+        assert!(gaps.iter().any(|&g| g > 3.0 / lambda));
+    }
+
+    #[test]
+    fn test_poisson_zero_lambda_has_no_arrivals() {
+        let stage =
+            LoadTestStage::steady("idle", 10, 1).with_arrivals(ArrivalDistribution::Poisson(0.0));
+        assert!(stage
+            .arrival_times(&mut DeterministicRng::new(1))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_schedule_is_reproducible_for_seed() {
+        let mut scenario = LoadTestScenario::new("Bursty", "Poisson traffic").with_seed(7);
+        scenario.add_stage(LoadTestStage::steady("warmup", 5, 2));
+        scenario.add_stage(
+            LoadTestStage::steady("burst", 5, 1).with_arrivals(ArrivalDistribution::Poisson(8.0)),
+        );
+        scenario.add_request(LoadTestRequest::get("home", "/"));
+        scenario.add_request(LoadTestRequest::get("api", "/api").with_weight(3.0));
+
+        let first = scenario.schedule();
+        assert_eq!(first, scenario.schedule());
+        assert_ne!(first, scenario.clone().with_seed(8).schedule());
+
+        // Warmup arrivals come first, offset by stage start for the burst
+        assert_eq!(first.iter().filter(|r| r.stage == 0).count(), 10);
+        assert!(first
+            .iter()
+            .filter(|r| r.stage == 1)
+            .all(|r| (5.0..10.0).contains(&r.at_secs)));
+        assert!(first.iter().any(|r| r.request == 1));
+    }
+
+    #[test]
+    fn test_schedule_without_requests_is_empty() {
+        let mut scenario = LoadTestScenario::new("Empty", "No requests");
+        scenario.add_stage(LoadTestStage::steady("steady", 5, 2));
+        assert!(scenario.schedule().is_empty());
+    }
+
+    #[test]
+    fn test_scheduled_requests_aggregate_into_stats() {
+        let mut scenario = LoadTestScenario::new("Mixed", "Weighted").with_seed(3);
+        scenario.add_stage(
+            LoadTestStage::steady("burst", 30, 1).with_arrivals(ArrivalDistribution::Poisson(10.0)),
+        );
+        scenario.add_request(LoadTestRequest::get("home", "/"));
+        scenario.add_request(LoadTestRequest::get("api", "/api"));
+
+        let schedule = scenario.schedule();
+        let mut histogram = LatencyHistogram::default();
+        let mut samples: Vec<Vec<u64>> = vec![Vec::new(); 2];
+        for (i, scheduled) in schedule.iter().enumerate() {
+            let latency = 10 + (i as u64 % 50);
+            histogram.record(latency);
+            samples[scheduled.request].push(latency);
+        }
+
+        let stats: Vec<EndpointStats> = scenario
+            .requests
+            .iter()
+            .zip(&samples)
+            .map(|(r, s)| EndpointStats::from_samples(&r.name, s, 0))
+            .collect();
+
+        assert_eq!(histogram.count(), schedule.len() as u64);
+        assert_eq!(
+            stats.iter().map(|s| s.count).sum::<u64>(),
+            schedule.len() as u64
+        );
+        assert_eq!(histogram.min(), 10);
+        assert!(stats.iter().all(|s| s.count > 0 && s.min_ms >= 10));
+    }
+
+    #[test]
+    fn test_stage_arrivals_yaml_roundtrip() {
+        let yaml = r"
+name: Bursty
+description: Poisson stage
+stages:
+  - name: steady
+    duration_secs: 10
+    users_start: 5
+    users_end: 5
+  - name: burst
+    duration_secs: 10
+    users_start: 5
+    users_end: 5
+    arrivals: !poisson 12.5
+  - name: ramp
+    duration_secs: 10
+    users_start: 5
+    users_end: 5
+    arrivals: !ramp
+      from: 1.0
+      to: 4.0
+requests: []
+";
+        let scenario = LoadTestScenario::from_yaml(yaml).unwrap();
+        assert_eq!(scenario.seed, 0);
+        assert_eq!(scenario.stages[0].arrivals, ArrivalDistribution::Constant);
+        assert_eq!(
+            scenario.stages[1].arrivals,
+            ArrivalDistribution::Poisson(12.5)
+        );
+        assert_eq!(
+            scenario.stages[2].arrivals,
+            ArrivalDistribution::Ramp { from: 1.0, to: 4.0 }
+        );
+
+        let encoded = serde_yaml_ng::to_string(&scenario.stages[1].arrivals).unwrap();
+        let decoded: ArrivalDistribution = serde_yaml_ng::from_str(&encoded).unwrap();
+        assert_eq!(decoded, ArrivalDistribution::Poisson(12.5));
+    }
+
     #[test]
     fn test_load_test_stage_ramp() {
         let stage = LoadTestStage::ramp("ramp", 60, 10, 100);
@@ -1088,6 +1621,118 @@ mod tests {
         assert_eq!(LoadTestErrorKind::HttpError.to_string(), "HTTP Error");
     }
 
+    #[test]
+    fn test_apdex_per_endpoint_and_overall() {
+        let config = LoadTestConfig::new("http://localhost:8080", 10, 30).apdex_threshold_ms(100);
+        let samples = HashMap::from([
+            // 2 satisfied, 1 tolerating, 1 frustrated: (2 + 0.5) / 4
+            ("home".to_string(), vec![40, 100, 300, 900]),
+            // All satisfied
+            ("api".to_string(), vec![10, 20, 30, 40]),
+        ]);
+
+        let mut result = LoadTestResult::new("Apdex");
+        result.endpoint_stats = vec![
+            EndpointStats::from_samples("home", &samples["home"], 0),
+            EndpointStats::from_samples("api", &samples["api"], 0),
+        ];
+        result.apply_apdex(&config, &samples);
+
+        let home = result.endpoint_stats[0].apdex.as_ref().unwrap();
+        assert!((home.score - 0.625).abs() < 1e-9);
+        assert_eq!(home.rating, ApdexRating::Poor);
+        let api = result.endpoint_stats[1].apdex.as_ref().unwrap();
+        assert_eq!(api.score, 1.0);
+        assert_eq!(api.rating, ApdexRating::Excellent);
+
+        // Overall: 6 satisfied, 1 tolerating, 1 frustrated → 6.5 / 8
+        let overall = result.apdex.as_ref().unwrap();
+        assert!((overall.score - 0.8125).abs() < 1e-9);
+        assert_eq!(overall.rating, ApdexRating::Fair);
+
+        let report = render_load_test_report(&result);
+        assert!(report.contains("Apdex (T=100ms):"));
+        assert!(report.contains("Overall: 0.81 (Fair)"));
+        assert!(report.contains("0.62 (Poor)") || report.contains("0.63 (Poor)"));
+
+        let json = render_load_test_json(&result);
+        assert!(json.contains("\"apdex\""));
+        assert!(json.contains("\"rating\": \"Fair\""));
+        assert!(json.contains("\"score\": 0.8125"));
+    }
+
+    #[test]
+    fn test_apdex_requires_threshold() {
+        let config = LoadTestConfig::new("http://localhost:8080", 10, 30);
+        let samples = HashMap::from([("home".to_string(), vec![40, 100])]);
+
+        let mut result = LoadTestResult::new("No threshold");
+        result.endpoint_stats = vec![EndpointStats::from_samples("home", &samples["home"], 0)];
+        result.apply_apdex(&config, &samples);
+
+        assert!(result.apdex.is_none());
+        assert!(result.endpoint_stats[0].apdex.is_none());
+        assert!(!render_load_test_report(&result).contains("Apdex"));
+        assert!(!render_load_test_json(&result).contains("apdex"));
+    }
+
+    /// Hockey-stick curve: flat 10ms up to 50 users, then +5ms per user
+    fn hockey_stick(concurrency: u32) -> (f64, f64) {
+        let c = f64::from(concurrency);
+        let latency = if c <= 50.0 {
+            10.0
+        } else {
+            10.0 + 5.0 * (c - 50.0)
+        };
+        // Throughput follows Little's law: users / latency
+        (latency, c * 1000.0 / latency)
+    }
+
+    #[test]
+    fn test_saturation_search_finds_knee() {
+        let report = SaturationSearch::new(10, 10, 200).run(hockey_stick);
+
+        let knee = report.knee.unwrap();
+        assert_eq!(knee.concurrency, 50);
+        assert_eq!(knee.latency_ms, 10.0);
+        assert_eq!(knee.throughput, 5000.0);
+        assert_eq!(report.recommended_concurrency, Some(40));
+
+        // Stops as soon as latency climbs past the knee
+        assert!(report.stopped_early);
+        assert_eq!(report.probes.last().unwrap().concurrency, 60);
+        assert_eq!(report.probes.len(), 6);
+
+        let text = render_saturation_report(&report);
+        assert!(text.contains("Saturation: 50 concurrent at 5000/s"));
+        assert!(render_saturation_json(&report).contains("\"concurrency\": 50"));
+    }
+
+    #[test]
+    fn test_saturation_search_without_knee() {
+        let mut calls = 0;
+        let report = SaturationSearch::new(1, 1, 8).run(|c| {
+            calls += 1;
+            (10.0, f64::from(c) * 100.0)
+        });
+
+        assert!(report.knee.is_none());
+        assert!(!report.stopped_early);
+        assert_eq!(calls, 8);
+        assert!(render_saturation_report(&report).contains("no latency knee"));
+    }
+
+    #[test]
+    fn test_saturation_search_growth_factor_delays_stop() {
+        let report = SaturationSearch::new(10, 10, 200)
+            .with_growth_factor(10.0)
+            .run(hockey_stick);
+
+        // 10 * 10ms = 100ms is first reached at 68 users, probed at 70
+        assert_eq!(report.knee.unwrap().concurrency, 50);
+        assert_eq!(report.probes.last().unwrap().concurrency, 70);
+    }
+
     #[test]
     fn test_render_load_test_report() {
         let mut result = LoadTestResult::new("Test Scenario");
@@ -1109,6 +1754,7 @@ mod tests {
             min_ms: 5,
             max_ms: 120,
             avg_ms: 25,
+            apdex: None,
         });
 
         result
@@ -19,7 +19,7 @@ use probador::{
             check_c001_code_execution, check_c002_console_errors, check_c003_custom_elements,
             check_c004_threading_modes, check_c005_low_memory, check_c006_headers,
             check_c007_replay_hash, check_c008_cache, check_c009_wasm_size, check_c010_panic_paths,
-            generate_comply_report, ComplianceResult,
+            check_c011_corp_header, generate_comply_report, ComplianceResult,
         },
     },
     Cli, CliConfig, CliResult, ColorChoice, Commands, TestRunner, Verbosity,
@@ -218,7 +218,7 @@ fn run_serve(args: &probador::ServeArgs) -> CliResult<()> {
         if !result.is_ok() {
             return Err(probador::CliError::test_execution(format!(
                 "Module validation failed: {} error(s) found. Fix imports before serving.",
-                result.errors.len()
+                result.problem_count()
             )));
         }
         eprintln!("\n✓ All module imports validated successfully\n");
@@ -260,13 +260,9 @@ fn run_serve(args: &probador::ServeArgs) -> CliResult<()> {
 }
 
 fn run_serve_tree(args: &probador::TreeArgs, _default_dir: &std::path::Path) -> CliResult<()> {
-    use probador::{build_tree, render_tree, TreeConfig};
+    use probador::{build_tree, handlers::serve::build_tree_config, render_tree};
 
-    let config = TreeConfig::default()
-        .with_depth(args.depth)
-        .with_filter(args.filter.as_deref())
-        .with_sizes(args.sizes)
-        .with_mime_types(args.mime_types);
+    let config = build_tree_config(args);
 
     let tree = build_tree(&args.path, &config)
         .map_err(|e| probador::CliError::test_execution(format!("Failed to build tree: {e}")))?;
@@ -300,7 +296,7 @@ fn run_serve_score(args: &probador::ScoreArgs, _default_dir: &std::path::Path) -
         return run_live_browser_validation(args);
     }
 
-    let calculator = ScoreCalculator::new(args.path.clone());
+    let calculator = ScoreCalculator::new(args.path.clone()).with_weights_file();
     let project_score = calculator.calculate();
 
     match args.format {
@@ -327,7 +323,8 @@ fn run_serve_score(args: &probador::ScoreArgs, _default_dir: &std::path::Path) -
 /// Checks performed:
 /// 1. Module resolution: Can all JS/WASM imports be fetched? (404 = FAIL)
 /// 2. MIME types: Are JS/WASM served with correct MIME types? (wrong MIME = FAIL)
-/// 3. Console errors: Any console.error or uncaught exceptions? (errors = FAIL)
+/// 3. Console errors: Any console.error, uncaught exceptions or unhandled
+///    promise rejections? (errors = FAIL)
 /// 4. WASM initialization: Does the WASM module load? (load failure = FAIL)
 fn run_live_browser_validation(args: &probador::ScoreArgs) -> CliResult<()> {
     use probador::DevServerConfig;
@@ -410,8 +407,9 @@ fn validate_module_imports(
     if !validation_result.is_ok() {
         print_module_import_errors(&validation_result);
         return Err(probador::CliError::test_execution(format!(
-            "Module resolution failed: {} broken import(s)",
-            validation_result.errors.len()
+            "Module resolution failed: {} broken import(s), {} import cycle(s)",
+            validation_result.errors.len(),
+            validation_result.cycles.len()
         )));
     }
     eprintln!(
@@ -424,7 +422,9 @@ fn validate_module_imports(
 
 /// Print detailed module import errors.
 fn print_module_import_errors(result: &probador::ModuleValidationResult) {
-    eprintln!("  ✗ FAIL: {} broken import(s) found\n", result.errors.len());
+    if !result.errors.is_empty() {
+        eprintln!("  ✗ FAIL: {} broken import(s) found\n", result.errors.len());
+    }
     for error in &result.errors {
         eprintln!(
             "    • {} (from {}:{})",
@@ -440,11 +440,20 @@ fn print_module_import_errors(result: &probador::ModuleValidationResult) {
         eprintln!("      Status: {status_str} - {}", error.message);
         eprintln!("      MIME type: {}", error.actual_mime);
     }
+    if !result.cycles.is_empty() {
+        eprintln!(
+            "  ✗ FAIL: {} circular import chain(s) found\n",
+            result.cycles.len()
+        );
+        for cycle in &result.cycles {
+            eprintln!("    • {cycle}");
+        }
+    }
     eprintln!();
     eprintln!("══════════════════════════════════════════════════════════════");
     eprintln!("  RESULT: FAIL (Grade: F)");
     eprintln!("══════════════════════════════════════════════════════════════");
-    eprintln!("\n  Fix the broken imports above and run again.\n");
+    eprintln!("\n  Fix the import problems above and run again.\n");
 }
 
 /// Resolve the server port: use specified port or find an available one.
@@ -639,31 +648,50 @@ async fn validate_browser_page(
 /// Check for console errors on a loaded page.
 #[cfg(feature = "browser")]
 async fn check_page_console_errors(page: &jugar_probar::Page, url_path: &str) -> Vec<String> {
-    use jugar_probar::BrowserConsoleLevel;
-
     let mut errors = Vec::new();
     if let Ok(messages) = page.fetch_console_messages().await {
-        let error_messages: Vec<_> = messages
-            .iter()
-            .filter(|m| m.level == BrowserConsoleLevel::Error)
-            .collect();
+        errors = console_failures(&messages, url_path);
 
-        if error_messages.is_empty() {
+        if errors.is_empty() {
             eprintln!("    ✓ No console errors");
         } else {
             eprintln!(
                 "    ✗ {} console error(s) found - APP IS BROKEN",
-                error_messages.len()
+                errors.len()
             );
-            for msg in &error_messages {
-                eprintln!("      └─ {}", msg.text);
-                errors.push(format!("[{url_path}] Console error: {}", msg.text));
+            for error in &errors {
+                eprintln!("      └─ {error}");
             }
         }
     }
     errors
 }
 
+/// Describe captured console messages that prove the page is broken.
+///
+/// `console.error` calls, uncaught exceptions and unhandled promise
+/// rejections all count as failures.
+#[cfg(any(feature = "browser", test))]
+fn console_failures(
+    messages: &[jugar_probar::BrowserConsoleMessage],
+    url_path: &str,
+) -> Vec<String> {
+    use jugar_probar::BrowserConsoleLevel;
+
+    messages
+        .iter()
+        .filter_map(|m| {
+            let kind = match m.level {
+                BrowserConsoleLevel::Error => "Console error",
+                BrowserConsoleLevel::UncaughtException => "Uncaught exception",
+                BrowserConsoleLevel::UnhandledRejection => "Unhandled promise rejection",
+                _ => return None,
+            };
+            Some(format!("[{url_path}] {kind}: {}", m.text))
+        })
+        .collect()
+}
+
 /// Check WASM initialization on a loaded page.
 #[cfg(feature = "browser")]
 async fn check_page_wasm_init(
@@ -853,7 +881,7 @@ fn run_watch(args: &probador::WatchArgs) -> CliResult<()> {
     println!("\nWatching for changes in {}...", args.path.display());
     println!("Press Ctrl+C to stop\n");
 
-    let watcher = FileWatcher::new(args.path.clone(), args.debounce);
+    let watcher = FileWatcher::new(args.path.clone(), args.debounce).with_asset_patterns();
     let path_for_rebuild = args.path.clone();
     let target_for_rebuild = args.target.as_str().to_string();
     let release_for_rebuild = args.release;
@@ -869,6 +897,21 @@ fn run_watch(args: &probador::WatchArgs) -> CliResult<()> {
                 let target = target_for_rebuild.clone();
                 let reload_tx = reload_tx.clone();
 
+                // Stylesheets and static assets are swapped in place, no rebuild
+                if let Some(message) =
+                    probador::dev_server::HotReloadMessage::hot_swap(changed_file.as_str())
+                {
+                    println!(
+                        "[{}] Hot-swapped: {}",
+                        chrono::Local::now().format("%H:%M:%S"),
+                        changed_file
+                    );
+                    if let Some(ref tx) = reload_tx {
+                        let _ = tx.send(message);
+                    }
+                    return;
+                }
+
                 // Use a separate runtime for the rebuild since we're in a sync callback
                 let rt = tokio::runtime::Handle::current();
                 rt.spawn(async move {
@@ -1144,6 +1187,7 @@ fn parse_mutation_classes(
                 "M3" => Some(MutationClass::EventSwap),
                 "M4" => Some(MutationClass::TargetSwap),
                 "M5" => Some(MutationClass::GuardNegation),
+                "M6" => Some(MutationClass::GuardBoundary),
                 _ => {
                     eprintln!("Unknown mutation class: {name}");
                     None
@@ -1225,15 +1269,19 @@ fn run_stress(_config: &CliConfig, args: &probador::StressArgs) -> CliResult<()>
         .parse()
         .map_err(|e: String| probador::CliError::invalid_argument(e))?;
 
-    let config = match mode {
-        StressMode::Atomics => StressConfig::atomics(args.duration, args.concurrency),
-        StressMode::WorkerMsg => StressConfig::worker_msg(args.duration, args.concurrency),
+    let make_config = |concurrency: u32| match mode {
+        StressMode::Atomics => StressConfig::atomics(args.duration, concurrency),
+        StressMode::WorkerMsg => StressConfig::worker_msg(args.duration, concurrency),
         StressMode::Render => StressConfig::render(args.duration),
         StressMode::Trace => StressConfig::trace(args.duration),
-        StressMode::Full => StressConfig::full(args.duration, args.concurrency),
+        StressMode::Full => StressConfig::full(args.duration, concurrency),
     };
 
-    let runner = StressRunner::new(config);
+    if args.find_knee {
+        return run_stress_knee_search(args, make_config);
+    }
+
+    let runner = StressRunner::new(make_config(args.concurrency));
     let result = runner.run();
 
     // Output result
@@ -1255,6 +1303,32 @@ fn run_stress(_config: &CliConfig, args: &probador::StressArgs) -> CliResult<()>
     }
 }
 
+/// Ramp stress concurrency until the latency knee, then report saturation.
+fn run_stress_knee_search(
+    args: &probador::StressArgs,
+    make_config: impl Fn(u32) -> probador::StressConfig,
+) -> CliResult<()> {
+    use probador::{
+        render_saturation_json, render_saturation_report, SaturationSearch, StressRunner,
+    };
+
+    let search = SaturationSearch::new(args.concurrency, args.concurrency, args.max_concurrency);
+    let report = search.run(|concurrency| {
+        eprintln!("  Probing concurrency {concurrency}...");
+        let result = StressRunner::new(make_config(concurrency)).run();
+        (result.latency.p95_us as f64 / 1000.0, result.ops_per_sec)
+    });
+
+    let output = if args.output == "json" {
+        render_saturation_json(&report)
+    } else {
+        render_saturation_report(&report)
+    };
+    println!("{output}");
+
+    Ok(())
+}
+
 // =============================================================================
 // LLM Testing
 // =============================================================================
@@ -1296,7 +1370,7 @@ fn run_llm(args: &probador::LlmArgs) -> CliResult<()> {
 }
 
 // =============================================================================
-// WASM Compliance Checks (C001-C010)
+// WASM Compliance Checks (C001-C011)
 // =============================================================================
 
 /// Run WASM compliance checks per PROBAR-SPEC-011
@@ -1398,7 +1472,7 @@ fn run_comply_checks_internal(config: &CliConfig, args: &probador::ComplyArgs) -
     )
 }
 
-/// Build the vector of all compliance checks (C001-C010).
+/// Build the vector of all compliance checks (C001-C011).
 fn build_compliance_checks() -> Vec<(
     &'static str,
     &'static str,
@@ -1455,6 +1529,11 @@ fn build_compliance_checks() -> Vec<(
             "No panic paths",
             Box::new(|path, _| check_c010_panic_paths(path)),
         ),
+        (
+            "C011",
+            "CORP headers",
+            Box::new(|path, _| check_c011_corp_header(path)),
+        ),
     ]
 }
 
@@ -1864,6 +1943,7 @@ fn run_comply_report(config: &CliConfig, args: &probador::ComplyReportArgs) -> C
             check_c009_wasm_size(p, 5_242_880)
         }),
         ("C010", "No panic paths", |p| check_c010_panic_paths(p)),
+        ("C011", "CORP headers", |p| check_c011_corp_header(p)),
     ];
 
     for (_, _, check_fn) in &checks {
@@ -2761,5 +2841,67 @@ panic = "abort""#;
         }
     }
 
+    mod live_validation_tests {
+        use super::*;
+        use jugar_probar::{BrowserConsoleLevel, BrowserConsoleMessage};
+
+        fn message(level: BrowserConsoleLevel, text: &str) -> BrowserConsoleMessage {
+            BrowserConsoleMessage {
+                level,
+                text: text.to_string(),
+                timestamp: 0,
+                source: None,
+                line: None,
+            }
+        }
+
+        #[test]
+        fn test_console_failures_clean_page() {
+            let messages = vec![
+                message(BrowserConsoleLevel::Log, "ready"),
+                message(BrowserConsoleLevel::Warning, "deprecated API"),
+            ];
+            assert!(console_failures(&messages, "index.html").is_empty());
+        }
+
+        #[test]
+        fn test_console_failures_unhandled_rejection() {
+            // What the capture script records for a page running
+            // `<script>Promise.reject(new Error("wasm fetch failed"));</script>`
+            let messages = vec![
+                message(BrowserConsoleLevel::Log, "booting"),
+                message(
+                    BrowserConsoleLevel::UnhandledRejection,
+                    "Error: wasm fetch failed",
+                ),
+            ];
+
+            let failures = console_failures(&messages, "index.html");
+            assert_eq!(
+                failures,
+                vec!["[index.html] Unhandled promise rejection: Error: wasm fetch failed"]
+            );
+        }
+
+        #[test]
+        fn test_console_failures_uncaught_and_console_error() {
+            let messages = vec![
+                message(BrowserConsoleLevel::Error, "boom"),
+                message(
+                    BrowserConsoleLevel::UncaughtException,
+                    "TypeError: x is undefined",
+                ),
+            ];
+
+            let failures = console_failures(&messages, "app.html");
+            assert_eq!(failures.len(), 2);
+            assert_eq!(failures[0], "[app.html] Console error: boom");
+            assert_eq!(
+                failures[1],
+                "[app.html] Uncaught exception: TypeError: x is undefined"
+            );
+        }
+    }
+
     // NOTE: compliance_result_tests moved to handlers/comply.rs
 }
@@ -16,6 +16,20 @@
 //! | Cross-Browser | 10 |
 //! | Accessibility | 10 |
 //! | Documentation | 5 |
+//!
+//! ## Weighting Profiles
+//!
+//! By default each category counts in proportion to its points. Teams can
+//! override this with a `.probar-score.toml` in the project root:
+//!
+//! ```toml
+//! [weights]
+//! accessibility = 0.3
+//! performance_benchmarks = 0.05
+//! ```
+//!
+//! Keys are category names in snake case. Categories not listed keep their
+//! default weight, and all weights are renormalized to sum to 1.0.
 
 #![allow(clippy::must_use_candidate)]
 #![allow(clippy::missing_panics_doc)]
@@ -32,8 +46,12 @@
 
 use glob::glob;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
+/// File name of the optional weighting profile in the project root
+pub const SCORE_WEIGHTS_FILE: &str = ".probar-score.toml";
+
 /// Project testing score result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectScore {
@@ -41,8 +59,16 @@ pub struct ProjectScore {
     pub total: u32,
     /// Maximum possible score
     pub max: u32,
+    /// Percentage the grade is based on (weighted when a profile is set)
+    #[serde(default)]
+    pub percentage: u32,
     /// Letter grade
     pub grade: Grade,
+    /// Normalized category weights, by category name
+    ///
+    /// Empty when the default point-based weighting is used.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub weights: BTreeMap<String, f32>,
     /// Scores by category
     pub categories: Vec<CategoryScore>,
     /// Top recommendations for improvement
@@ -197,17 +223,140 @@ impl Effort {
     }
 }
 
+/// Normalize a category name into a weighting profile key
+///
+/// `"Performance Benchmarks"` and `"Cross-Browser"` become
+/// `performance_benchmarks` and `cross_browser`.
+#[must_use]
+pub fn category_key(name: &str) -> String {
+    name.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+/// Contents of a `.probar-score.toml` profile
+#[derive(Debug, Default, Deserialize)]
+struct ScoreProfile {
+    #[serde(default)]
+    weights: HashMap<String, toml::Value>,
+}
+
+/// Parse the `[weights]` table of a `.probar-score.toml` profile
+///
+/// Entries that are not numbers are ignored, as are negative weights.
+///
+/// # Errors
+///
+/// Returns an error if `content` is not valid TOML.
+pub fn parse_score_weights(content: &str) -> Result<HashMap<String, f32>, toml::de::Error> {
+    let profile: ScoreProfile = toml::from_str(content)?;
+    Ok(profile
+        .weights
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let weight = match value {
+                toml::Value::Float(f) => f as f32,
+                toml::Value::Integer(i) => i as f32,
+                _ => return None,
+            };
+            (weight >= 0.0).then(|| (category_key(&key), weight))
+        })
+        .collect())
+}
+
 /// Score calculator
 #[derive(Debug)]
 pub struct ScoreCalculator {
     root: PathBuf,
+    weights: HashMap<String, f32>,
 }
 
 impl ScoreCalculator {
     /// Create a new score calculator
     #[must_use]
     pub fn new(root: impl Into<PathBuf>) -> Self {
-        Self { root: root.into() }
+        Self {
+            root: root.into(),
+            weights: HashMap::new(),
+        }
+    }
+
+    /// Override category weights
+    ///
+    /// Keys are category names (see [`category_key`]). Categories not listed
+    /// keep their default point-based weight; the result is renormalized.
+    #[must_use]
+    pub fn with_weights(mut self, weights: HashMap<String, f32>) -> Self {
+        self.weights = weights
+            .into_iter()
+            .map(|(name, weight)| (category_key(&name), weight.max(0.0)))
+            .collect();
+        self
+    }
+
+    /// Load category weights from `.probar-score.toml` in the project root
+    ///
+    /// Leaves the default weighting in place when the file does not exist
+    /// or is not valid TOML.
+    #[must_use]
+    pub fn with_weights_file(self) -> Self {
+        let weights = std::fs::read_to_string(self.root.join(SCORE_WEIGHTS_FILE))
+            .ok()
+            .and_then(|content| parse_score_weights(&content).ok());
+        match weights {
+            Some(weights) => self.with_weights(weights),
+            None => self,
+        }
+    }
+
+    /// Effective normalized weight of each category
+    ///
+    /// Returns an empty map when no custom weights are configured.
+    fn normalized_weights(&self, categories: &[CategoryScore]) -> BTreeMap<String, f32> {
+        if self.weights.is_empty() {
+            return BTreeMap::new();
+        }
+
+        let max: u32 = categories.iter().map(|c| c.max).sum();
+        let raw: Vec<(String, f32)> = categories
+            .iter()
+            .map(|c| {
+                let default = if max > 0 {
+                    c.max as f32 / max as f32
+                } else {
+                    0.0
+                };
+                let weight = self
+                    .weights
+                    .get(&category_key(&c.name))
+                    .copied()
+                    .unwrap_or(default);
+                (c.name.clone(), weight)
+            })
+            .collect();
+
+        let sum: f32 = raw.iter().map(|(_, w)| w).sum();
+        if sum <= 0.0 {
+            return BTreeMap::new();
+        }
+        raw.into_iter().map(|(name, w)| (name, w / sum)).collect()
+    }
+
+    /// Percentage used for grading, honoring custom weights if any
+    fn score_percentage(
+        categories: &[CategoryScore],
+        weights: &BTreeMap<String, f32>,
+        total: u32,
+        max: u32,
+    ) -> u32 {
+        if weights.is_empty() {
+            return (total * 100).checked_div(max).unwrap_or(0);
+        }
+
+        let weighted: f32 = categories
+            .iter()
+            .filter(|c| c.max > 0)
+            .map(|c| weights.get(&c.name).copied().unwrap_or(0.0) * c.score as f32 / c.max as f32)
+            .sum();
+        (weighted * 100.0).round().clamp(0.0, 100.0) as u32
     }
 
     /// Calculate the project score
@@ -232,14 +381,20 @@ impl ScoreCalculator {
 
         let total: u32 = categories.iter().map(|c| c.score).sum();
         let max: u32 = categories.iter().map(|c| c.max).sum();
+        let weights = self.normalized_weights(&categories);
+        let percentage = Self::score_percentage(&categories, &weights, total, max);
 
         // Apply grade caps based on runtime health (PROBAR-SPEC-007)
         let grade = if runtime_passed {
-            Grade::from_score(total, max)
+            Grade::from_score(percentage, 100)
         } else {
             // Runtime failures cap the grade at C (max 79%)
-            let capped_percentage = std::cmp::min((total * 100) / max, 79);
-            Grade::from_score(capped_percentage, 100)
+            Grade::from_score(std::cmp::min(percentage, 79), 100)
+        };
+        let percentage_text = if weights.is_empty() {
+            format_percentage(total, max)
+        } else {
+            format!("{percentage}% weighted")
         };
 
         let recommendations = self.generate_recommendations(&categories);
@@ -248,7 +403,7 @@ impl ScoreCalculator {
             format!(
                 "Project has {} testing coverage with {} in {} categories",
                 grade.as_str(),
-                format_percentage(total, max),
+                percentage_text,
                 categories
                     .iter()
                     .filter(|c| c.status == CategoryStatus::Complete)
@@ -258,14 +413,16 @@ impl ScoreCalculator {
             format!(
                 "Project has {} testing coverage ({}) - GRADE CAPPED: Runtime validation failed",
                 grade.as_str(),
-                format_percentage(total, max)
+                percentage_text
             )
         };
 
         ProjectScore {
             total,
             max,
+            percentage,
             grade,
+            weights,
             categories,
             recommendations,
             summary,
@@ -1245,6 +1402,13 @@ pub fn render_score_text(score: &ProjectScore, verbose: bool) -> String {
         score.grade.as_str()
     ));
 
+    if !score.weights.is_empty() {
+        output.push_str(&format!(
+            "Weighted Score: {}% (custom weighting profile)\n\n",
+            score.percentage
+        ));
+    }
+
     // Category table
     output
         .push_str("┌─────────────────────┬────────┬────────┬─────────────────────────────────┐\n");
@@ -1480,12 +1644,124 @@ mod tests {
         assert_eq!(score.max, 115);
     }
 
+    fn accessibility_only_project() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("a11y_checks.yaml"), "rules: []").unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_default_weighting_has_no_profile() {
+        let temp = accessibility_only_project();
+        let score = ScoreCalculator::new(temp.path()).calculate();
+
+        assert!(score.weights.is_empty());
+        assert_eq!(score.percentage, (score.total * 100) / score.max);
+        assert_eq!(score.grade, Grade::F);
+    }
+
+    #[test]
+    fn test_weighting_accessibility_up_shifts_grade() {
+        let temp = accessibility_only_project();
+        let baseline = ScoreCalculator::new(temp.path()).calculate();
+
+        let weights = HashMap::from([("Accessibility".to_string(), 9.0)]);
+        let weighted = ScoreCalculator::new(temp.path())
+            .with_weights(weights)
+            .calculate();
+
+        assert_eq!(weighted.total, baseline.total);
+        assert!(weighted.percentage > 80);
+        // Runtime health still fails, so the grade is capped at C
+        assert_eq!(weighted.grade, Grade::C);
+        assert_ne!(weighted.grade, baseline.grade);
+        assert!(weighted.summary.contains("weighted"));
+    }
+
+    #[test]
+    fn test_weights_are_normalized() {
+        let temp = TempDir::new().unwrap();
+        let names = ScoreCalculator::new(temp.path())
+            .calculate()
+            .categories
+            .into_iter()
+            .map(|c| (c.name, 2.0))
+            .collect::<HashMap<_, _>>();
+
+        let score = ScoreCalculator::new(temp.path())
+            .with_weights(names)
+            .calculate();
+
+        assert_eq!(score.weights.len(), 10);
+        let sum: f32 = score.weights.values().sum();
+        assert!((sum - 1.0).abs() < 1e-5);
+        assert!(score.weights.values().all(|w| (w - 0.1).abs() < 1e-5));
+    }
+
+    #[test]
+    fn test_missing_categories_keep_default_weight() {
+        let temp = TempDir::new().unwrap();
+        let weights = HashMap::from([("documentation".to_string(), 0.0)]);
+        let score = ScoreCalculator::new(temp.path())
+            .with_weights(weights)
+            .calculate();
+
+        assert_eq!(score.weights["Documentation"], 0.0);
+        // Remaining 110 points keep their relative weighting
+        assert!((score.weights["Accessibility"] - 10.0 / 110.0).abs() < 1e-5);
+        assert!((score.weights["Runtime Health"] - 15.0 / 110.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_weights_file_is_loaded() {
+        let temp = accessibility_only_project();
+        std::fs::write(
+            temp.path().join(SCORE_WEIGHTS_FILE),
+            "# team profile\n[weights]\naccessibility = 9.0 # a11y first\n\n[other]\nload_testing = 5\n",
+        )
+        .unwrap();
+
+        let score = ScoreCalculator::new(temp.path())
+            .with_weights_file()
+            .calculate();
+
+        assert!(!score.weights.is_empty());
+        assert_eq!(score.grade, Grade::C);
+    }
+
+    #[test]
+    fn test_parse_score_weights() {
+        let weights = parse_score_weights(
+            "[weights]\nCross-Browser = 0.2\n\"pixel_testing\" = 1\nbad = -1\njunk = \"x\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(weights.len(), 2);
+        assert_eq!(weights["cross_browser"], 0.2);
+        assert_eq!(weights["pixel_testing"], 1.0);
+
+        assert!(parse_score_weights("[weights]\njunk\n").is_err());
+        assert!(parse_score_weights("[other]\nx = 1\n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_category_key() {
+        assert_eq!(
+            category_key("Performance Benchmarks"),
+            "performance_benchmarks"
+        );
+        assert_eq!(category_key("Cross-Browser"), "cross_browser");
+        assert_eq!(category_key("accessibility"), "accessibility");
+    }
+
     #[test]
     fn test_render_score_text() {
         let score = ProjectScore {
             total: 50,
             max: 100,
+            percentage: 50,
             grade: Grade::F,
+            weights: BTreeMap::new(),
             categories: vec![],
             recommendations: vec![],
             summary: "Test".to_string(),
@@ -1501,7 +1777,9 @@ mod tests {
         let score = ProjectScore {
             total: 75,
             max: 100,
+            percentage: 75,
             grade: Grade::C,
+            weights: BTreeMap::new(),
             categories: vec![],
             recommendations: vec![],
             summary: "Test".to_string(),
@@ -1591,7 +1869,9 @@ mod tests {
         let score = ProjectScore {
             total: 60,
             max: 100,
+            percentage: 60,
             grade: Grade::D,
+            weights: BTreeMap::new(),
             categories: vec![],
             recommendations: vec![
                 Recommendation {
@@ -1681,7 +1961,9 @@ mod tests {
         let score = ProjectScore {
             total: 75,
             max: 100,
+            percentage: 75,
             grade: Grade::C,
+            weights: BTreeMap::new(),
             categories: vec![
                 CategoryScore {
                     name: "Test A".to_string(),
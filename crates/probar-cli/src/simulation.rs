@@ -24,6 +24,7 @@
 #![allow(clippy::single_char_add_str)]
 #![allow(clippy::useless_vec)]
 
+use jugar_probar::brick::DeterministicRng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -99,6 +100,30 @@ impl FailureInjection {
             duration_ms: None,
         }
     }
+
+    /// Create a gradual degradation that worsens `metric` from `from` to
+    /// `to` over `over_duration_ms`
+    pub fn degrade(
+        target: &str,
+        metric: DegradeMetric,
+        from: f64,
+        to: f64,
+        over_duration_ms: u64,
+        curve: DegradeCurve,
+    ) -> Self {
+        Self {
+            injection_type: InjectionType::Degrade {
+                metric,
+                from: MetricValue::from_f64(from),
+                to: MetricValue::from_f64(to),
+                over_duration_ms,
+                curve,
+            },
+            probability: 1.0,
+            target: target.to_string(),
+            duration_ms: Some(over_duration_ms),
+        }
+    }
 }
 
 /// Types of failure injection
@@ -116,6 +141,19 @@ pub enum InjectionType {
     CpuThrottle,
     /// Memory pressure
     MemoryPressure,
+    /// Gradual degradation of a metric (memory leak, pool exhaustion)
+    Degrade {
+        /// Metric that worsens
+        metric: DegradeMetric,
+        /// Value at the start of the injection
+        from: MetricValue,
+        /// Value once fully degraded
+        to: MetricValue,
+        /// Time to go from `from` to `to` in milliseconds
+        over_duration_ms: u64,
+        /// Shape of the degradation
+        curve: DegradeCurve,
+    },
 }
 
 impl InjectionType {
@@ -128,10 +166,110 @@ impl InjectionType {
             Self::Timeout => "timeout",
             Self::CpuThrottle => "cpu_throttle",
             Self::MemoryPressure => "memory_pressure",
+            Self::Degrade { .. } => "degrade",
+        }
+    }
+
+    /// Injected metric value `elapsed_ms` after a degradation started
+    ///
+    /// Returns `None` for discrete injection types. Values hold at `to`
+    /// once `over_duration_ms` has passed.
+    pub fn value_at(&self, elapsed_ms: u64) -> Option<f64> {
+        let Self::Degrade {
+            from,
+            to,
+            over_duration_ms,
+            curve,
+            ..
+        } = *self
+        else {
+            return None;
+        };
+        let (from, to) = (from.as_f64(), to.as_f64());
+
+        let progress = if over_duration_ms == 0 {
+            1.0
+        } else {
+            (elapsed_ms as f64 / over_duration_ms as f64).min(1.0)
+        };
+        let value = match curve {
+            // Geometric interpolation needs two positive endpoints
+            DegradeCurve::Exponential if from > 0.0 && to > 0.0 => {
+                from * (to / from).powf(progress)
+            }
+            _ => from + (to - from) * progress,
+        };
+        Some(value)
+    }
+}
+
+/// Fixed-point metric value in basis points (1/10 000) of the metric's unit
+///
+/// Keeps [`InjectionType`] `Eq` while still describing fractional values
+/// such as error rates.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MetricValue(i64);
+
+impl MetricValue {
+    /// Create a value from basis points of the metric's unit
+    pub const fn from_basis_points(basis_points: i64) -> Self {
+        Self(basis_points)
+    }
+
+    /// Convert from a floating-point value, rounding to the nearest basis point
+    ///
+    /// NaN maps to zero and out-of-range values saturate.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_f64(value: f64) -> Self {
+        Self((value * 10_000.0).round() as i64)
+    }
+
+    /// Value in basis points
+    pub const fn basis_points(self) -> i64 {
+        self.0
+    }
+
+    /// Value in the metric's unit
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_f64(self) -> f64 {
+        self.0 as f64 / 10_000.0
+    }
+}
+
+/// Metric worsened by a [`InjectionType::Degrade`] injection
+///
+/// Higher values are worse for every metric.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DegradeMetric {
+    /// Response latency in milliseconds
+    LatencyMs,
+    /// Error rate (0.0 - 1.0)
+    ErrorRate,
+    /// Memory usage in megabytes
+    MemoryMb,
+}
+
+impl DegradeMetric {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::LatencyMs => "latency_ms",
+            Self::ErrorRate => "error_rate",
+            Self::MemoryMb => "memory_mb",
         }
     }
 }
 
+/// Shape of a gradual degradation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum DegradeCurve {
+    /// Constant rate of change
+    #[default]
+    Linear,
+    /// Constant growth ratio (slow start, fast finish)
+    Exponential,
+}
+
 // =============================================================================
 // K.1 Simulation Configuration
 // =============================================================================
@@ -145,6 +283,9 @@ pub struct SimulationConfig {
     pub mode: SimulationMode,
     /// Parameter variations for Monte Carlo
     pub parameter_variations: HashMap<String, ParameterVariation>,
+    /// Pairwise correlations between varied parameters
+    #[serde(default)]
+    pub correlations: Vec<ParameterCorrelation>,
     /// Output configuration
     pub output: SimulationOutput,
 }
@@ -156,6 +297,7 @@ impl SimulationConfig {
             base_session: session_path,
             mode: SimulationMode::DeterministicReplay,
             parameter_variations: HashMap::new(),
+            correlations: Vec::new(),
             output: SimulationOutput::default(),
         }
     }
@@ -169,6 +311,7 @@ impl SimulationConfig {
                 seed: None,
             },
             parameter_variations: HashMap::new(),
+            correlations: Vec::new(),
             output: SimulationOutput::default(),
         }
     }
@@ -179,6 +322,190 @@ impl SimulationConfig {
             .insert(name.to_string(), variation);
         self
     }
+
+    /// Correlate two varied parameters (`coefficient` in -1.0..=1.0)
+    pub fn with_correlation(mut self, a: &str, b: &str, coefficient: f64) -> Self {
+        self.correlations.push(ParameterCorrelation {
+            a: a.to_string(),
+            b: b.to_string(),
+            coefficient: coefficient.clamp(-1.0, 1.0),
+        });
+        self
+    }
+
+    /// Build a sampler that draws all varied parameters jointly
+    ///
+    /// Fails if a correlation names an unknown parameter or the correlation
+    /// matrix is not positive definite.
+    pub fn sampler(&self) -> Result<CorrelatedSampler, String> {
+        CorrelatedSampler::new(&self.parameter_variations, &self.correlations)
+    }
+}
+
+/// Correlation between two varied parameters
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ParameterCorrelation {
+    /// First parameter name
+    pub a: String,
+    /// Second parameter name
+    pub b: String,
+    /// Correlation coefficient (-1.0 to 1.0)
+    pub coefficient: f64,
+}
+
+/// Joint sampler for correlated parameter variations
+///
+/// Uses a Gaussian copula: independent standard normals are mixed through
+/// the Cholesky factor of the correlation matrix, mapped to uniforms with
+/// the normal CDF, then passed to each parameter's
+/// [`ParameterVariation::sample`]. Marginals are unchanged; the configured
+/// coefficients apply to the underlying normals, so sampled values show
+/// slightly weaker (rank) correlation for non-normal marginals.
+#[derive(Debug, Clone)]
+pub struct CorrelatedSampler {
+    /// Parameter names, sorted for reproducible draws
+    names: Vec<String>,
+    /// Variations in `names` order
+    variations: Vec<ParameterVariation>,
+    /// Lower-triangular Cholesky factor of the correlation matrix
+    cholesky: Vec<Vec<f64>>,
+}
+
+impl CorrelatedSampler {
+    /// Create a sampler for the given variations and correlations
+    pub fn new(
+        variations: &HashMap<String, ParameterVariation>,
+        correlations: &[ParameterCorrelation],
+    ) -> Result<Self, String> {
+        let mut names: Vec<String> = variations.keys().cloned().collect();
+        names.sort();
+        let n = names.len();
+
+        let mut matrix = vec![vec![0.0; n]; n];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+        let index_of = |name: &str| {
+            names
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| format!("Correlation references unknown parameter: {}", name))
+        };
+        for corr in correlations {
+            let (i, j) = (index_of(&corr.a)?, index_of(&corr.b)?);
+            if i == j {
+                return Err(format!(
+                    "Parameter {} cannot be correlated with itself",
+                    corr.a
+                ));
+            }
+            matrix[i][j] = corr.coefficient;
+            matrix[j][i] = corr.coefficient;
+        }
+
+        let cholesky = cholesky(&matrix)
+            .ok_or_else(|| "Correlation matrix is not positive definite".to_string())?;
+        let variations = names.iter().map(|n| variations[n].clone()).collect();
+
+        Ok(Self {
+            names,
+            variations,
+            cholesky,
+        })
+    }
+
+    /// Draw one joint sample of every parameter
+    pub fn sample(&self, rng: &mut DeterministicRng) -> HashMap<String, f64> {
+        let z: Vec<f64> = (0..self.names.len())
+            .map(|_| standard_normal(rng))
+            .collect();
+
+        self.names
+            .iter()
+            .zip(&self.variations)
+            .zip(&self.cholesky)
+            .map(|((name, variation), row)| {
+                let correlated: f64 = row.iter().zip(&z).map(|(l, z)| l * z).sum();
+                // Keep away from 0 and 1 so log-based distributions stay finite
+                let uniform = normal_cdf(correlated).clamp(1e-12, 1.0 - 1e-12);
+                (name.clone(), variation.sample(uniform))
+            })
+            .collect()
+    }
+}
+
+/// Cholesky decomposition of a symmetric matrix (None if not positive definite)
+#[allow(clippy::needless_range_loop)]
+fn cholesky(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut lower = vec![vec![0.0; n]; n];
+
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+            if i == j {
+                let diag = matrix[i][i] - sum;
+                if diag <= 0.0 {
+                    return None;
+                }
+                lower[i][j] = diag.sqrt();
+            } else {
+                lower[i][j] = (matrix[i][j] - sum) / lower[j][j];
+            }
+        }
+    }
+
+    Some(lower)
+}
+
+/// Standard normal draw via Box-Muller
+fn standard_normal(rng: &mut DeterministicRng) -> f64 {
+    // 1 - u keeps the log argument in (0, 1]
+    let u1 = 1.0 - rng.next_f64();
+    let u2 = rng.next_f64();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Standard normal CDF (Abramowitz-Stegun 7.1.26 erf, error < 1.5e-7)
+fn normal_cdf(x: f64) -> f64 {
+    let t = x.abs() / std::f64::consts::SQRT_2;
+    let k = 1.0 / (1.0 + 0.327_591_1 * t);
+    let poly = k
+        * (0.254_829_592
+            + k * (-0.284_496_736
+                + k * (1.421_413_741 + k * (-1.453_152_027 + k * 1.061_405_429))));
+    let erf = 1.0 - poly * (-t * t).exp();
+    if x >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+/// Pearson correlation coefficient of two equally long series
+///
+/// Returns 0.0 when either series is constant or shorter than two values.
+pub fn pearson_correlation(xs: &[f64], ys: &[f64]) -> f64 {
+    let n = xs.len().min(ys.len());
+    if n < 2 {
+        return 0.0;
+    }
+    let mean_x = xs[..n].iter().sum::<f64>() / n as f64;
+    let mean_y = ys[..n].iter().sum::<f64>() / n as f64;
+
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in xs[..n].iter().zip(&ys[..n]) {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        cov += dx * dy;
+        var_x += dx * dx;
+        var_y += dy * dy;
+    }
+
+    if var_x == 0.0 || var_y == 0.0 {
+        0.0
+    } else {
+        cov / (var_x * var_y).sqrt()
+    }
 }
 
 /// Parameter variation definition
@@ -309,6 +636,35 @@ impl MonteCarloResult {
         self.sensitivity_analysis.push(factor);
     }
 
+    /// Derive sensitivity factors from jointly sampled parameters
+    ///
+    /// Each parameter is correlated with `metric` (one value per iteration)
+    /// over the same draws, so correlated inputs share credit for their joint
+    /// effect. Factors are added strongest first.
+    pub fn add_sensitivity_from_samples(
+        &mut self,
+        samples: &[HashMap<String, f64>],
+        metric: &[f64],
+    ) {
+        let mut names: Vec<&String> = samples.iter().flat_map(HashMap::keys).collect();
+        names.sort();
+        names.dedup();
+
+        let mut factors: Vec<SensitivityFactor> = names
+            .into_iter()
+            .map(|name| {
+                let values: Vec<f64> = samples
+                    .iter()
+                    .map(|s| s.get(name).copied().unwrap_or(0.0))
+                    .collect();
+                SensitivityFactor::new(name, pearson_correlation(&values, metric))
+            })
+            .collect();
+        factors.sort_by(|a, b| b.correlation.abs().total_cmp(&a.correlation.abs()));
+
+        self.sensitivity_analysis.extend(factors);
+    }
+
     /// Add recommendation
     pub fn add_recommendation(&mut self, rec: &str) {
         self.recommendations.push(rec.to_string());
@@ -518,6 +874,9 @@ pub struct ChaosResult {
     pub graceful_degradation: bool,
     /// Recovery time in milliseconds
     pub recovery_time_ms: Option<u64>,
+    /// Time of the earliest SLA breach in milliseconds
+    #[serde(default)]
+    pub sla_breached_at_ms: Option<u64>,
 }
 
 impl ChaosResult {
@@ -529,6 +888,7 @@ impl ChaosResult {
             observations: Vec::new(),
             graceful_degradation: true,
             recovery_time_ms: None,
+            sla_breached_at_ms: None,
         }
     }
 
@@ -538,8 +898,59 @@ impl ChaosResult {
         if matches!(obs.severity, ObservationSeverity::Critical) {
             self.graceful_degradation = false;
         }
+        if obs.sla_breach {
+            self.sla_breached_at_ms = Some(
+                self.sla_breached_at_ms
+                    .map_or(obs.timestamp_ms, |t| t.min(obs.timestamp_ms)),
+            );
+        }
         self.observations.push(obs);
     }
+
+    /// Apply a degradation injection and record when it breaches the SLA
+    ///
+    /// Samples the degradation curve every `step_ms` and records an SLA breach
+    /// observation at the first sample whose value exceeds `sla_limit`.
+    /// Returns the breach time, or `None` for discrete injections and
+    /// degradations that stay within the SLA.
+    pub fn observe_degradation(
+        &mut self,
+        injection: &FailureInjection,
+        sla_limit: f64,
+        step_ms: u64,
+    ) -> Option<u64> {
+        let InjectionType::Degrade {
+            metric,
+            over_duration_ms,
+            ..
+        } = injection.injection_type
+        else {
+            return None;
+        };
+        self.injections.push(injection.clone());
+
+        let step_ms = step_ms.max(1);
+        let breach = (0..=over_duration_ms)
+            .step_by(step_ms as usize)
+            .chain(std::iter::once(over_duration_ms))
+            .find_map(|t| {
+                let value = injection.injection_type.value_at(t)?;
+                (value > sla_limit).then_some((t, value))
+            });
+
+        let (breached_at, value) = breach?;
+        self.add_observation(ChaosObservation::sla_breach(
+            breached_at,
+            &injection.target,
+            &format!(
+                "{} {:.2} breached SLA limit {:.2}",
+                metric.name(),
+                value,
+                sla_limit
+            ),
+        ));
+        Some(breached_at)
+    }
 }
 
 /// Observation during chaos experiment
@@ -553,6 +964,9 @@ pub struct ChaosObservation {
     pub description: String,
     /// Severity
     pub severity: ObservationSeverity,
+    /// Whether this observation marks an SLA breach
+    #[serde(default)]
+    pub sla_breach: bool,
 }
 
 impl ChaosObservation {
@@ -568,6 +982,20 @@ impl ChaosObservation {
             component: component.to_string(),
             description: description.to_string(),
             severity,
+            sla_breach: false,
+        }
+    }
+
+    /// Create an SLA breach observation
+    pub fn sla_breach(timestamp_ms: u64, component: &str, description: &str) -> Self {
+        Self {
+            sla_breach: true,
+            ..Self::new(
+                timestamp_ms,
+                component,
+                description,
+                ObservationSeverity::Error,
+            )
         }
     }
 }
@@ -756,6 +1184,10 @@ pub fn render_chaos_report(result: &ChaosResult) -> String {
     };
     out.push_str(&format!("VERDICT: {}\n", verdict));
 
+    if let Some(breached_at) = result.sla_breached_at_ms {
+        out.push_str(&format!("SLA Breached At: {}ms\n", breached_at));
+    }
+
     if let Some(recovery) = result.recovery_time_ms {
         out.push_str(&format!("Recovery Time: {}ms\n", recovery));
     }
@@ -950,6 +1382,138 @@ mod tests {
         assert_eq!(InjectionType::Timeout.name(), "timeout");
         assert_eq!(InjectionType::CpuThrottle.name(), "cpu_throttle");
         assert_eq!(InjectionType::MemoryPressure.name(), "memory_pressure");
+        assert_eq!(
+            FailureInjection::degrade(
+                "db",
+                DegradeMetric::ErrorRate,
+                0.0,
+                1.0,
+                10,
+                DegradeCurve::Linear
+            )
+            .injection_type
+            .name(),
+            "degrade"
+        );
+    }
+
+    #[test]
+    fn test_degrade_linear_latency_curve() {
+        let injection = FailureInjection::degrade(
+            "api",
+            DegradeMetric::LatencyMs,
+            50.0,
+            500.0,
+            10_000,
+            DegradeCurve::Linear,
+        );
+        let curve = injection.injection_type;
+
+        for (t, expected) in [
+            (0, 50.0),
+            (2_500, 162.5),
+            (5_000, 275.0),
+            (10_000, 500.0),
+            (20_000, 500.0),
+        ] {
+            let value = curve.value_at(t).unwrap();
+            assert!((value - expected).abs() < 1e-9, "t={t}: {value}");
+        }
+        assert_eq!(InjectionType::Latency.value_at(100), None);
+    }
+
+    #[test]
+    fn test_degrade_injection_type_is_eq() {
+        let injection = FailureInjection::degrade(
+            "db",
+            DegradeMetric::ErrorRate,
+            0.0,
+            0.01,
+            1_000,
+            DegradeCurve::Linear,
+        );
+        let InjectionType::Degrade { to, .. } = injection.injection_type else {
+            panic!("expected a degradation");
+        };
+        assert_eq!(to, MetricValue::from_basis_points(100));
+
+        fn requires_eq<T: Eq>(value: T) -> T {
+            value
+        }
+        let rebuilt = FailureInjection::degrade(
+            "db",
+            DegradeMetric::ErrorRate,
+            0.0,
+            0.01,
+            1_000,
+            DegradeCurve::Linear,
+        );
+        assert_eq!(
+            requires_eq(injection.injection_type),
+            rebuilt.injection_type
+        );
+    }
+
+    #[test]
+    fn test_degrade_exponential_curve() {
+        let curve = FailureInjection::degrade(
+            "heap",
+            DegradeMetric::MemoryMb,
+            10.0,
+            1000.0,
+            1_000,
+            DegradeCurve::Exponential,
+        )
+        .injection_type;
+
+        assert!((curve.value_at(0).unwrap() - 10.0).abs() < 1e-9);
+        assert!((curve.value_at(500).unwrap() - 100.0).abs() < 1e-9);
+        assert!((curve.value_at(1_000).unwrap() - 1000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_chaos_result_captures_sla_breach() {
+        let injection = FailureInjection::degrade(
+            "api",
+            DegradeMetric::LatencyMs,
+            50.0,
+            500.0,
+            10_000,
+            DegradeCurve::Linear,
+        );
+
+        let mut result = ChaosResult::new("Connection pool exhaustion");
+        // 50 + 450 * t / 10000 > 300 first at t = 5600 with 100ms steps
+        let breach = result.observe_degradation(&injection, 300.0, 100);
+
+        assert_eq!(breach, Some(5_600));
+        assert_eq!(result.sla_breached_at_ms, Some(5_600));
+        assert_eq!(result.injections.len(), 1);
+        let obs = result.observations.last().unwrap();
+        assert!(obs.sla_breach);
+        assert_eq!(obs.severity, ObservationSeverity::Error);
+        assert!(obs.description.contains("latency_ms"));
+        assert!(render_chaos_report(&result).contains("SLA Breached At: 5600ms"));
+    }
+
+    #[test]
+    fn test_degradation_within_sla_has_no_breach() {
+        let injection = FailureInjection::degrade(
+            "api",
+            DegradeMetric::ErrorRate,
+            0.0,
+            0.01,
+            1_000,
+            DegradeCurve::Linear,
+        );
+
+        let mut result = ChaosResult::new("Slow drift");
+        assert_eq!(result.observe_degradation(&injection, 0.05, 10), None);
+        assert!(result.sla_breached_at_ms.is_none());
+        assert!(result.observations.is_empty());
+
+        let discrete = FailureInjection::error("api", 0.1);
+        assert_eq!(result.observe_degradation(&discrete, 0.05, 10), None);
     }
 
     #[test]
@@ -1143,6 +1707,102 @@ mod tests {
         }
     }
 
+    fn correlated_config(coefficient: f64) -> SimulationConfig {
+        SimulationConfig::monte_carlo(PathBuf::from("session.simular"), 2000)
+            .with_variation("latency_ms", ParameterVariation::normal(100.0, 20.0))
+            .with_variation("error_rate", ParameterVariation::uniform(0.0, 0.1))
+            .with_correlation("latency_ms", "error_rate", coefficient)
+    }
+
+    fn draw(config: &SimulationConfig, seed: u64, n: usize) -> Vec<HashMap<String, f64>> {
+        let sampler = config.sampler().unwrap();
+        let mut rng = DeterministicRng::new(seed);
+        (0..n).map(|_| sampler.sample(&mut rng)).collect()
+    }
+
+    fn column(samples: &[HashMap<String, f64>], name: &str) -> Vec<f64> {
+        samples.iter().map(|s| s[name]).collect()
+    }
+
+    #[test]
+    fn test_correlated_sampling_respects_correlation() {
+        let samples = draw(&correlated_config(0.9), 42, 4000);
+        let latency = column(&samples, "latency_ms");
+        let errors = column(&samples, "error_rate");
+
+        let corr = pearson_correlation(&latency, &errors);
+        assert!((corr - 0.9).abs() < 0.05, "correlation {corr}");
+        // Marginals are unchanged
+        assert!(errors.iter().all(|e| (0.0..=0.1).contains(e)));
+        let mean = latency.iter().sum::<f64>() / latency.len() as f64;
+        assert!((mean - 100.0).abs() < 2.0, "mean {mean}");
+    }
+
+    #[test]
+    fn test_correlated_sampling_is_reproducible() {
+        let config = correlated_config(0.9);
+        assert_eq!(draw(&config, 7, 10), draw(&config, 7, 10));
+    }
+
+    #[test]
+    fn test_uncorrelated_sampling_is_independent() {
+        let config = SimulationConfig::monte_carlo(PathBuf::from("session.simular"), 2000)
+            .with_variation("latency_ms", ParameterVariation::normal(100.0, 20.0))
+            .with_variation("error_rate", ParameterVariation::uniform(0.0, 0.1));
+        let samples = draw(&config, 42, 4000);
+
+        let corr = pearson_correlation(
+            &column(&samples, "latency_ms"),
+            &column(&samples, "error_rate"),
+        );
+        assert!(corr.abs() < 0.05, "correlation {corr}");
+    }
+
+    #[test]
+    fn test_sampler_rejects_invalid_correlations() {
+        let unknown = correlated_config(0.5).with_correlation("latency_ms", "cpu", 0.3);
+        assert!(unknown.sampler().unwrap_err().contains("cpu"));
+
+        // Pairwise 0.9 / 0.9 / -0.9 between three parameters is impossible
+        let impossible = SimulationConfig::monte_carlo(PathBuf::from("s.simular"), 10)
+            .with_variation("a", ParameterVariation::uniform(0.0, 1.0))
+            .with_variation("b", ParameterVariation::uniform(0.0, 1.0))
+            .with_variation("c", ParameterVariation::uniform(0.0, 1.0))
+            .with_correlation("a", "b", 0.9)
+            .with_correlation("b", "c", 0.9)
+            .with_correlation("a", "c", -0.9);
+        assert!(impossible
+            .sampler()
+            .unwrap_err()
+            .contains("positive definite"));
+    }
+
+    #[test]
+    fn test_sensitivity_reflects_joint_effects() {
+        let samples = draw(&correlated_config(0.9), 3, 2000);
+        // Metric depends only on latency; error_rate gets credit via correlation
+        let metric: Vec<f64> = column(&samples, "latency_ms")
+            .iter()
+            .map(|l| 2.0 * l + 5.0)
+            .collect();
+
+        let mut result = MonteCarloResult::new(2000);
+        result.add_sensitivity_from_samples(&samples, &metric);
+
+        assert_eq!(result.sensitivity_analysis.len(), 2);
+        assert_eq!(result.sensitivity_analysis[0].parameter, "latency_ms");
+        assert!((result.sensitivity_analysis[0].correlation - 1.0).abs() < 1e-9);
+        assert_eq!(result.sensitivity_analysis[1].parameter, "error_rate");
+        assert_eq!(result.sensitivity_analysis[1].impact, ImpactLevel::High);
+    }
+
+    #[test]
+    fn test_pearson_correlation_edge_cases() {
+        assert_eq!(pearson_correlation(&[1.0], &[2.0]), 0.0);
+        assert_eq!(pearson_correlation(&[1.0, 1.0], &[2.0, 3.0]), 0.0);
+        assert!((pearson_correlation(&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]) + 1.0).abs() < 1e-12);
+    }
+
     #[test]
     fn test_simulation_output_default() {
         let output = SimulationOutput::default();
@@ -254,6 +254,47 @@ impl Default for ApdexCalculator {
     }
 }
 
+/// Snapshot of an Apdex calculation, suitable for reports
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApdexSummary {
+    /// Satisfied threshold T in ms
+    pub threshold_ms: u64,
+    /// Apdex score (0.0 to 1.0)
+    pub score: f64,
+    /// Rating for the score
+    pub rating: ApdexRating,
+    /// Satisfied request count
+    pub satisfied: u64,
+    /// Tolerating request count
+    pub tolerating: u64,
+    /// Frustrated request count
+    pub frustrated: u64,
+}
+
+impl ApdexSummary {
+    /// Compute Apdex for latency samples with target T (tolerating up to 4T)
+    pub fn from_samples(threshold_ms: u64, samples: &[u64]) -> Self {
+        let mut calculator = ApdexCalculator::new(threshold_ms, threshold_ms.saturating_mul(4));
+        for &latency in samples {
+            calculator.record(latency);
+        }
+        Self::from(&calculator)
+    }
+}
+
+impl From<&ApdexCalculator> for ApdexSummary {
+    fn from(calculator: &ApdexCalculator) -> Self {
+        Self {
+            threshold_ms: calculator.satisfied_threshold_ms,
+            score: calculator.score(),
+            rating: calculator.rating(),
+            satisfied: calculator.satisfied(),
+            tolerating: calculator.tolerating(),
+            frustrated: calculator.frustrated(),
+        }
+    }
+}
+
 /// Apdex rating levels
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ApdexRating {
@@ -817,6 +858,19 @@ mod tests {
         assert_eq!(sample.components.len(), 2);
     }
 
+    #[test]
+    fn test_apdex_summary_from_samples() {
+        // T = 100: 3 satisfied, 2 tolerating (<= 400), 1 frustrated
+        let summary = ApdexSummary::from_samples(100, &[50, 100, 80, 250, 400, 401]);
+
+        assert_eq!(summary.threshold_ms, 100);
+        assert_eq!(summary.satisfied, 3);
+        assert_eq!(summary.tolerating, 2);
+        assert_eq!(summary.frustrated, 1);
+        assert!((summary.score - 4.0 / 6.0).abs() < 1e-9);
+        assert_eq!(summary.rating, ApdexRating::Poor);
+    }
+
     #[test]
     fn test_apdex_reset() {
         let mut apdex = ApdexCalculator::new(100, 400);
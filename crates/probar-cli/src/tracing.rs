@@ -24,7 +24,7 @@
 #![allow(clippy::redundant_closure_for_method_calls)]
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 // =============================================================================
@@ -148,6 +148,9 @@ pub struct TraceSpan {
     pub thread_id: u64,
     /// Additional metadata
     pub metadata: HashMap<String, String>,
+    /// Source location that issued the span (if known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_location: Option<SourceLocation>,
 }
 
 impl TraceSpan {
@@ -160,6 +163,7 @@ impl TraceSpan {
             duration_us,
             thread_id: 0,
             metadata: HashMap::new(),
+            source_location: None,
         }
     }
 
@@ -178,6 +182,12 @@ impl TraceSpan {
         self.metadata.insert(key.to_string(), value.to_string());
         self
     }
+
+    /// Set source location
+    pub fn with_source_location(mut self, location: SourceLocation) -> Self {
+        self.source_location = Some(location);
+        self
+    }
 }
 
 /// Syscall statistics
@@ -353,6 +363,15 @@ pub enum OptimizationSuggestion {
         /// Blocking time in microseconds
         blocking_us: u64,
     },
+    /// Same cheap call repeated from one location (N+1 pattern)
+    NPlusOne {
+        /// Number of repeated calls
+        repeat_count: u64,
+        /// Average cost per call in microseconds
+        per_call_us: u64,
+        /// Aggregate cost of all calls in microseconds
+        aggregate_us: u64,
+    },
 }
 
 impl OptimizationSuggestion {
@@ -363,10 +382,21 @@ impl OptimizationSuggestion {
             Self::UsePool { .. } => "⚠ Pool",
             Self::BatchOperations { .. } => "⚠ Batch",
             Self::AsyncIO { .. } => "⚠ Async",
+            Self::NPlusOne { .. } => "⚠ N+1",
         }
     }
 }
 
+/// Minimum repeats from one location before flagging an N+1 pattern
+pub const N_PLUS_ONE_MIN_REPEATS: u64 = 50;
+
+/// Maximum average per-call cost (μs) for an N+1 pattern; slower calls are
+/// ordinary hotspots rather than batching opportunities
+pub const N_PLUS_ONE_MAX_PER_CALL_US: u64 = 1_000;
+
+/// Minimum aggregate cost (μs) for an N+1 pattern to be worth reporting
+pub const N_PLUS_ONE_MIN_AGGREGATE_US: u64 = 10_000;
+
 // =============================================================================
 // J.3 Trace Analysis
 // =============================================================================
@@ -388,6 +418,9 @@ pub struct TraceAnalysis {
     pub source_hotspots: Vec<SourceHotspot>,
     /// Critical path components
     pub critical_path: Vec<String>,
+    /// Differential flamegraph against a baseline profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flamegraph_diff: Option<Flamegraph>,
 }
 
 impl TraceAnalysis {
@@ -401,6 +434,7 @@ impl TraceAnalysis {
             wasm_events: Vec::new(),
             source_hotspots: Vec::new(),
             critical_path: Vec::new(),
+            flamegraph_diff: None,
         }
     }
 
@@ -428,6 +462,65 @@ impl TraceAnalysis {
         self.source_hotspots.push(hotspot);
     }
 
+    /// Attach a differential flamegraph (see [`Flamegraph::diff`])
+    pub fn set_flamegraph_diff(&mut self, diff: Flamegraph) {
+        self.flamegraph_diff = Some(diff);
+    }
+
+    /// Flag N+1 / batching opportunities
+    ///
+    /// Groups timeline spans by source location and span name. Groups with
+    /// many cheap calls but a high aggregate cost become hotspots carrying an
+    /// [`OptimizationSuggestion::NPlusOne`]; hotspots are then re-sorted by
+    /// total time. Returns the number of patterns found.
+    pub fn detect_repeated_calls(&mut self) -> usize {
+        let mut groups: BTreeMap<(PathBuf, u32, &str), (&SourceLocation, u64, u64)> =
+            BTreeMap::new();
+        for span in &self.timeline {
+            let Some(loc) = &span.source_location else {
+                continue;
+            };
+            let entry = groups
+                .entry((loc.file.clone(), loc.line, span.name.as_str()))
+                .or_insert((loc, 0, 0));
+            entry.1 += 1;
+            entry.2 += span.duration_us;
+        }
+
+        let mut found = Vec::new();
+        for ((_, _, name), (loc, count, total_us)) in groups {
+            let per_call_us = total_us / count;
+            if count < N_PLUS_ONE_MIN_REPEATS
+                || per_call_us > N_PLUS_ONE_MAX_PER_CALL_US
+                || total_us < N_PLUS_ONE_MIN_AGGREGATE_US
+            {
+                continue;
+            }
+
+            let function = loc.function.as_deref().unwrap_or(name);
+            let mut hotspot = SourceHotspot::new(loc.file.clone(), loc.line, function);
+            hotspot.total_us = total_us;
+            hotspot.call_count = count;
+            hotspot.suggestion = Some(OptimizationSuggestion::NPlusOne {
+                repeat_count: count,
+                per_call_us,
+                aggregate_us: total_us,
+            });
+            found.push(hotspot);
+        }
+
+        let detected = found.len();
+        for hotspot in found {
+            // Replace any existing hotspot for the same line
+            self.source_hotspots
+                .retain(|h| !(h.file == hotspot.file && h.line == hotspot.line));
+            self.source_hotspots.push(hotspot);
+        }
+        self.source_hotspots
+            .sort_by_key(|h| std::cmp::Reverse(h.total_us));
+        detected
+    }
+
     /// Calculate critical path
     pub fn calculate_critical_path(&mut self) {
         // Sort spans by duration descending
@@ -479,6 +572,9 @@ pub struct FlamegraphNode {
     pub total_time_us: u64,
     /// Children nodes
     pub children: Vec<FlamegraphNode>,
+    /// Before/after comparison (only set on differential flamegraphs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta: Option<FlamegraphDelta>,
 }
 
 impl FlamegraphNode {
@@ -489,6 +585,7 @@ impl FlamegraphNode {
             self_time_us: 0,
             total_time_us: 0,
             children: Vec::new(),
+            delta: None,
         }
     }
 
@@ -503,6 +600,101 @@ impl FlamegraphNode {
         self.total_time_us += child.total_time_us;
         self.children.push(child);
     }
+
+    /// Diff two nodes sharing the same stack path
+    ///
+    /// The result is sized by the current profile, or by the baseline for
+    /// stacks that disappeared so they stay visible. Returns `None` when
+    /// neither profile has the stack.
+    fn diff(baseline: Option<&Self>, current: Option<&Self>) -> Option<Self> {
+        let sized = current.or(baseline)?;
+        let base_total = baseline.map_or(0, |n| n.total_time_us);
+        let curr_total = current.map_or(0, |n| n.total_time_us);
+        let status = match (baseline, current) {
+            (Some(_), Some(_)) => DiffStatus::Both,
+            (None, _) => DiffStatus::Added,
+            (Some(_), None) => DiffStatus::Removed,
+        };
+
+        let mut node = Self::new(&sized.name);
+        node.self_time_us = sized.self_time_us;
+        node.total_time_us = sized.total_time_us;
+        node.delta = Some(FlamegraphDelta {
+            baseline_us: base_total,
+            current_us: curr_total,
+            delta_us: curr_total as i64 - base_total as i64,
+            status,
+        });
+        node.children = diff_children(
+            baseline.map(|n| n.children.as_slice()).unwrap_or_default(),
+            current.map(|n| n.children.as_slice()).unwrap_or_default(),
+        );
+        Some(node)
+    }
+}
+
+/// Match sibling nodes by name and diff each pair
+///
+/// Current-profile order is kept; stacks only in the baseline follow.
+fn diff_children(baseline: &[FlamegraphNode], current: &[FlamegraphNode]) -> Vec<FlamegraphNode> {
+    let mut matched = vec![false; baseline.len()];
+    let mut out = Vec::with_capacity(current.len());
+
+    for node in current {
+        let base = baseline
+            .iter()
+            .enumerate()
+            .find(|(i, b)| !matched[*i] && b.name == node.name)
+            .map(|(i, b)| {
+                matched[i] = true;
+                b
+            });
+        out.extend(FlamegraphNode::diff(base, Some(node)));
+    }
+    for (node, _) in baseline.iter().zip(&matched).filter(|(_, m)| !**m) {
+        out.extend(FlamegraphNode::diff(Some(node), None));
+    }
+    out
+}
+
+/// Presence of a stack in a differential flamegraph
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DiffStatus {
+    /// Stack present in both profiles
+    Both,
+    /// Stack only present in the current profile
+    Added,
+    /// Stack only present in the baseline profile
+    Removed,
+}
+
+/// Before/after timing of a differential flamegraph node
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FlamegraphDelta {
+    /// Total time in the baseline profile
+    pub baseline_us: u64,
+    /// Total time in the current profile
+    pub current_us: u64,
+    /// Change in total time (positive = slower)
+    pub delta_us: i64,
+    /// Which profiles contain this stack
+    pub status: DiffStatus,
+}
+
+impl FlamegraphDelta {
+    /// Change relative to the baseline in percent (`None` for new stacks)
+    pub fn percent(&self) -> Option<f64> {
+        (self.baseline_us > 0).then(|| self.delta_us as f64 / self.baseline_us as f64 * 100.0)
+    }
+
+    /// Flamegraph color: red = got slower, blue = got faster
+    pub fn color(&self) -> &'static str {
+        match self.delta_us {
+            d if d > 0 => "red",
+            d if d < 0 => "blue",
+            _ => "gray",
+        }
+    }
 }
 
 /// Flamegraph data structure
@@ -529,6 +721,28 @@ impl Flamegraph {
         self.roots.push(node);
     }
 
+    /// Build a differential flamegraph from a baseline and a current profile
+    ///
+    /// Nodes are matched by stack path and carry a [`FlamegraphDelta`].
+    pub fn diff(baseline: &Flamegraph, current: &Flamegraph) -> Flamegraph {
+        let mut fg = Flamegraph::new();
+        for root in diff_children(&baseline.roots, &current.roots) {
+            fg.add_root(root);
+        }
+        fg
+    }
+
+    /// Find a node by its `;`-separated stack path
+    pub fn find(&self, path: &str) -> Option<&FlamegraphNode> {
+        let mut parts = path.split(';');
+        let first = parts.next()?;
+        let mut node = self.roots.iter().find(|n| n.name == first)?;
+        for part in parts {
+            node = node.children.iter().find(|n| n.name == part)?;
+        }
+        Some(node)
+    }
+
     /// Render as folded stack format (for external tools)
     pub fn to_folded(&self) -> String {
         let mut out = String::new();
@@ -704,9 +918,47 @@ pub fn render_trace_report(analysis: &TraceAnalysis) -> String {
         );
     }
 
+    // Differential flamegraph
+    if let Some(diff) = &analysis.flamegraph_diff {
+        out.push_str("\nDIFFERENTIAL FLAMEGRAPH (red = slower, blue = faster)\n");
+        for root in &diff.roots {
+            render_diff_node(&mut out, root, 0);
+        }
+    }
+
     out
 }
 
+/// Render one differential flamegraph node and its children
+fn render_diff_node(out: &mut String, node: &FlamegraphNode, depth: usize) {
+    if let Some(delta) = &node.delta {
+        let marker = match delta.status {
+            DiffStatus::Added => " [NEW]",
+            DiffStatus::Removed => " [REMOVED]",
+            DiffStatus::Both => "",
+        };
+        let percent = delta
+            .percent()
+            .map(|p| format!(" ({:+.1}%)", p))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "  {}{:<width$} {:>8.1}ms → {:>8.1}ms  {:+.1}ms{} [{}]{}\n",
+            "  ".repeat(depth),
+            truncate(&node.name, 30),
+            delta.baseline_us as f64 / 1000.0,
+            delta.current_us as f64 / 1000.0,
+            delta.delta_us as f64 / 1000.0,
+            percent,
+            delta.color(),
+            marker,
+            width = 30usize.saturating_sub(depth * 2),
+        ));
+    }
+    for child in &node.children {
+        render_diff_node(out, child, depth + 1);
+    }
+}
+
 /// Render as JSON
 pub fn render_trace_json(analysis: &TraceAnalysis) -> String {
     serde_json::to_string_pretty(analysis).unwrap_or_else(|_| "{}".to_string())
@@ -920,6 +1172,69 @@ mod tests {
 
         let async_io = OptimizationSuggestion::AsyncIO { blocking_us: 1000 };
         assert_eq!(async_io.hint(), "⚠ Async");
+
+        let n_plus_one = OptimizationSuggestion::NPlusOne {
+            repeat_count: 500,
+            per_call_us: 40,
+            aggregate_us: 20_000,
+        };
+        assert_eq!(n_plus_one.hint(), "⚠ N+1");
+    }
+
+    #[test]
+    fn test_detect_repeated_calls_n_plus_one() {
+        let mut analysis = TraceAnalysis::new("n-plus-one");
+        let mut loc = SourceLocation::new(PathBuf::from("src/db.rs"), 88);
+        loc.function = Some("load_user".to_string());
+        for i in 0..500 {
+            analysis.add_span(
+                TraceSpan::new("fetch", TraceCategory::Network, i * 50, 40)
+                    .with_source_location(loc.clone()),
+            );
+        }
+        // A single expensive call is not a batching opportunity
+        analysis.add_span(
+            TraceSpan::new("compile", TraceCategory::Wasm, 0, 30_000)
+                .with_source_location(SourceLocation::new(PathBuf::from("src/boot.rs"), 3)),
+        );
+
+        assert_eq!(analysis.detect_repeated_calls(), 1);
+        assert_eq!(analysis.source_hotspots.len(), 1);
+
+        let hotspot = &analysis.source_hotspots[0];
+        assert_eq!(hotspot.line, 88);
+        assert_eq!(hotspot.function, "load_user");
+        assert_eq!(hotspot.call_count, 500);
+        assert_eq!(hotspot.total_us, 20_000);
+        match hotspot.suggestion {
+            Some(OptimizationSuggestion::NPlusOne {
+                repeat_count,
+                per_call_us,
+                aggregate_us,
+            }) => {
+                assert_eq!(repeat_count, 500);
+                assert_eq!(per_call_us, 40);
+                assert_eq!(aggregate_us, 20_000);
+            }
+            ref other => panic!("expected N+1 suggestion, got {other:?}"),
+        }
+        assert!(render_trace_report(&analysis).contains("⚠ N+1"));
+    }
+
+    #[test]
+    fn test_detect_repeated_calls_ignores_few_repeats() {
+        let mut analysis = TraceAnalysis::new("few");
+        let loc = SourceLocation::new(PathBuf::from("src/db.rs"), 10);
+        for i in 0..10 {
+            analysis.add_span(
+                TraceSpan::new("fetch", TraceCategory::Network, i * 2000, 1500)
+                    .with_source_location(loc.clone()),
+            );
+        }
+        analysis.add_span(TraceSpan::new("untracked", TraceCategory::Wasm, 0, 10));
+
+        assert_eq!(analysis.detect_repeated_calls(), 0);
+        assert!(analysis.source_hotspots.is_empty());
     }
 
     #[test]
@@ -999,6 +1314,79 @@ mod tests {
         assert!(folded.contains("root;child1;grandchild 25"));
     }
 
+    fn profile(process_us: u64, extra: Option<(&str, u64)>) -> Flamegraph {
+        let mut root = FlamegraphNode::new("main");
+        root.add_time(1000);
+        let mut process = FlamegraphNode::new("process");
+        process.add_time(process_us);
+        root.add_child(process);
+        if let Some((name, us)) = extra {
+            let mut node = FlamegraphNode::new(name);
+            node.add_time(us);
+            root.add_child(node);
+        }
+        let mut fg = Flamegraph::new();
+        fg.add_root(root);
+        fg
+    }
+
+    #[test]
+    fn test_flamegraph_node_diff_needs_a_side() {
+        let node = FlamegraphNode::new("main");
+        assert!(FlamegraphNode::diff(None, None).is_none());
+        let added = FlamegraphNode::diff(None, Some(&node)).unwrap();
+        assert_eq!(added.delta.unwrap().status, DiffStatus::Added);
+    }
+
+    #[test]
+    fn test_flamegraph_diff_grown_function() {
+        let baseline = profile(500, Some(("parse", 200)));
+        let current = profile(1500, Some(("render", 300)));
+
+        let diff = Flamegraph::diff(&baseline, &current);
+
+        let process = diff.find("main;process").unwrap().delta.unwrap();
+        assert_eq!(process.baseline_us, 500);
+        assert_eq!(process.current_us, 1500);
+        assert_eq!(process.delta_us, 1000);
+        assert_eq!(process.status, DiffStatus::Both);
+        assert_eq!(process.color(), "red");
+        assert_eq!(process.percent(), Some(200.0));
+
+        let main = diff.find("main").unwrap().delta.unwrap();
+        assert_eq!(main.delta_us, 2800 - 1700);
+
+        let render = diff.find("main;render").unwrap().delta.unwrap();
+        assert_eq!(render.status, DiffStatus::Added);
+        assert_eq!(render.delta_us, 300);
+        assert_eq!(render.percent(), None);
+
+        // Removed stacks keep their baseline size so they stay visible
+        let parse = diff.find("main;parse").unwrap();
+        assert_eq!(parse.total_time_us, 200);
+        let parse = parse.delta.unwrap();
+        assert_eq!(parse.status, DiffStatus::Removed);
+        assert_eq!(parse.delta_us, -200);
+        assert_eq!(parse.color(), "blue");
+    }
+
+    #[test]
+    fn test_render_trace_report_with_flamegraph_diff() {
+        let mut analysis = TraceAnalysis::new("diff-test");
+        analysis.add_span(TraceSpan::new("main", TraceCategory::Wasm, 0, 3000));
+        analysis.set_flamegraph_diff(Flamegraph::diff(
+            &profile(500, Some(("parse", 200))),
+            &profile(1500, Some(("render", 300))),
+        ));
+
+        let report = render_trace_report(&analysis);
+        assert!(report.contains("DIFFERENTIAL FLAMEGRAPH"));
+        assert!(report.contains("+1.0ms (+200.0%) [red]"));
+        assert!(report.contains("[NEW]"));
+        assert!(report.contains("[REMOVED]"));
+        assert!(!render_trace_report(&TraceAnalysis::new("x")).contains("DIFFERENTIAL"));
+    }
+
     #[test]
     fn test_render_trace_report_full() {
         let mut analysis = TraceAnalysis::new("full-test");
@@ -15,6 +15,9 @@
 //!
 //! Total: 5 files, 1.3 MB
 //! ```
+//!
+//! With [`TreeFormat::Json`] the same tree is emitted as a flat JSON array of
+//! entries (pre-order, with `depth`) for dashboards and other tooling.
 
 #![allow(clippy::must_use_candidate)]
 #![allow(clippy::missing_panics_doc)]
@@ -28,6 +31,7 @@
 
 use crate::dev_server::get_mime_type;
 use glob::Pattern;
+use serde::Serialize;
 use std::path::{Path, PathBuf};
 
 /// File node in the tree
@@ -43,6 +47,8 @@ pub struct FileNode {
     pub mime_type: String,
     /// Whether this is a directory
     pub is_dir: bool,
+    /// Link target if this entry is a symlink (symlinked directories are not followed)
+    pub symlink_target: Option<PathBuf>,
     /// Child nodes
     pub children: Vec<FileNode>,
 }
@@ -63,6 +69,7 @@ impl FileNode {
             size,
             mime_type,
             is_dir: false,
+            symlink_target: None,
             children: Vec::new(),
         }
     }
@@ -81,6 +88,7 @@ impl FileNode {
             size: 0,
             mime_type: String::new(),
             is_dir: true,
+            symlink_target: None,
             children: Vec::new(),
         }
     }
@@ -95,6 +103,12 @@ impl FileNode {
         }
     }
 
+    /// Whether this entry is a symlink
+    #[must_use]
+    pub fn is_symlink(&self) -> bool {
+        self.symlink_target.is_some()
+    }
+
     /// Count total files (excluding directories)
     #[must_use]
     pub fn file_count(&self) -> usize {
@@ -106,6 +120,16 @@ impl FileNode {
     }
 }
 
+/// Output format for [`render_tree`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TreeFormat {
+    /// ASCII tree for terminals
+    #[default]
+    Ascii,
+    /// JSON array of entries for programmatic consumption
+    Json,
+}
+
 /// Configuration for tree display
 #[derive(Debug, Clone)]
 pub struct TreeConfig {
@@ -119,6 +143,8 @@ pub struct TreeConfig {
     pub show_mime_types: bool,
     /// Use colors
     pub use_colors: bool,
+    /// Output format
+    pub format: TreeFormat,
 }
 
 impl Default for TreeConfig {
@@ -129,6 +155,7 @@ impl Default for TreeConfig {
             show_sizes: true,
             show_mime_types: true,
             use_colors: atty::is(atty::Stream::Stdout),
+            format: TreeFormat::Ascii,
         }
     }
 }
@@ -161,6 +188,13 @@ impl TreeConfig {
         self.show_mime_types = show;
         self
     }
+
+    /// Set output format
+    #[must_use]
+    pub const fn with_format(mut self, format: TreeFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 /// Build a file tree from a directory
@@ -177,6 +211,16 @@ fn build_tree_recursive(
     config: &TreeConfig,
     current_depth: usize,
 ) -> Result<FileNode, std::io::Error> {
+    // The root is always followed; nested symlinks are marked instead
+    let symlink_target = if current_depth > 0 {
+        std::fs::symlink_metadata(path)?
+            .file_type()
+            .is_symlink()
+            .then(|| std::fs::read_link(path))
+            .transpose()?
+    } else {
+        None
+    };
     let metadata = std::fs::metadata(path)?;
 
     if metadata.is_file() {
@@ -194,17 +238,26 @@ fn build_tree_recursive(
                     size: 0,
                     mime_type: String::new(),
                     is_dir: false,
+                    symlink_target: None,
                     children: Vec::new(),
                 });
             }
         }
 
-        return Ok(FileNode::new_file(path.to_path_buf(), metadata.len()));
+        let mut node = FileNode::new_file(path.to_path_buf(), metadata.len());
+        node.symlink_target = symlink_target;
+        return Ok(node);
     }
 
     // Directory
     let mut node = FileNode::new_dir(path.to_path_buf());
 
+    // Never follow symlinked directories: they can form cycles
+    if symlink_target.is_some() {
+        node.symlink_target = symlink_target;
+        return Ok(node);
+    }
+
     // Check depth limit
     if let Some(max_depth) = config.max_depth {
         if current_depth >= max_depth {
@@ -271,9 +324,63 @@ pub fn format_size(bytes: u64) -> String {
     }
 }
 
-/// Render the tree to a string
+/// Render the tree to a string in the configured [`TreeFormat`]
 #[must_use]
 pub fn render_tree(root: &FileNode, config: &TreeConfig) -> String {
+    match config.format {
+        TreeFormat::Ascii => render_tree_ascii(root, config),
+        TreeFormat::Json => render_tree_json(root, config),
+    }
+}
+
+/// One entry of the JSON tree output
+#[derive(Debug, Serialize)]
+struct TreeEntry<'a> {
+    name: &'a str,
+    path: String,
+    depth: usize,
+    is_dir: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mime_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symlink: Option<String>,
+}
+
+fn render_tree_json(root: &FileNode, config: &TreeConfig) -> String {
+    let mut entries = Vec::new();
+    collect_entries(root, config, 0, &mut entries);
+    let mut output = serde_json::to_string_pretty(&entries).unwrap_or_else(|_| "[]".to_string());
+    output.push('\n');
+    output
+}
+
+fn collect_entries<'a>(
+    node: &'a FileNode,
+    config: &TreeConfig,
+    depth: usize,
+    entries: &mut Vec<TreeEntry<'a>>,
+) {
+    entries.push(TreeEntry {
+        name: &node.name,
+        path: node.path.to_string_lossy().to_string(),
+        depth,
+        is_dir: node.is_dir,
+        size: (config.show_sizes && !node.is_dir).then_some(node.size),
+        mime_type: (config.show_mime_types && !node.mime_type.is_empty())
+            .then_some(node.mime_type.as_str()),
+        symlink: node
+            .symlink_target
+            .as_ref()
+            .map(|t| t.to_string_lossy().to_string()),
+    });
+    for child in &node.children {
+        collect_entries(child, config, depth + 1, entries);
+    }
+}
+
+fn render_tree_ascii(root: &FileNode, config: &TreeConfig) -> String {
     let mut output = String::new();
 
     // Root directory name
@@ -316,7 +423,10 @@ fn render_node_children(
 
         if child.is_dir {
             output.push('/');
-        } else {
+        }
+        if let Some(ref target) = child.symlink_target {
+            output.push_str(&format!(" -> {}", target.display()));
+        } else if !child.is_dir {
             // File info
             if config.show_sizes {
                 output.push_str(&format!(" ({})", format_size(child.size)));
@@ -722,6 +832,97 @@ mod tests {
         assert!(!tree.children[1].is_dir);
     }
 
+    #[test]
+    fn test_render_tree_json_shape() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("index.html"), "<html></html>").unwrap();
+        let pkg = temp.path().join("pkg");
+        std::fs::create_dir(&pkg).unwrap();
+        std::fs::write(pkg.join("app.wasm"), [0u8; 8]).unwrap();
+
+        let config = TreeConfig::default()
+            .with_format(TreeFormat::Json)
+            .with_sizes(true)
+            .with_mime_types(true);
+        let tree = build_tree(temp.path(), &config).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&render_tree(&tree, &config)).unwrap();
+
+        let entries = json.as_array().unwrap();
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[0]["depth"], 0);
+        assert_eq!(entries[0]["is_dir"], true);
+
+        assert_eq!(entries[1]["name"], "pkg");
+        assert_eq!(entries[1]["depth"], 1);
+        assert!(entries[1].get("size").is_none());
+
+        assert_eq!(entries[2]["name"], "app.wasm");
+        assert_eq!(entries[2]["depth"], 2);
+        assert_eq!(entries[2]["size"], 8);
+        assert_eq!(entries[2]["mime_type"], "application/wasm");
+
+        assert_eq!(entries[3]["name"], "index.html");
+        assert_eq!(entries[3]["size"], 13);
+        assert_eq!(entries[3]["mime_type"], "text/html");
+        assert!(entries[3].get("symlink").is_none());
+    }
+
+    #[test]
+    fn test_render_tree_json_respects_flags() {
+        let mut root = FileNode::new_dir(PathBuf::from("project"));
+        root.children.push(FileNode::new_file(
+            PathBuf::from("project/index.html"),
+            1024,
+        ));
+
+        let config = TreeConfig::default()
+            .with_format(TreeFormat::Json)
+            .with_sizes(false)
+            .with_mime_types(false);
+        let json: serde_json::Value = serde_json::from_str(&render_tree(&root, &config)).unwrap();
+
+        let file = &json[1];
+        assert_eq!(file["name"], "index.html");
+        assert_eq!(file["depth"], 1);
+        assert!(file.get("size").is_none());
+        assert!(file.get("mime_type").is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_build_tree_does_not_follow_symlink_cycles() {
+        let temp = TempDir::new().unwrap();
+        let sub = temp.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(sub.join("file.txt"), "content").unwrap();
+        std::os::unix::fs::symlink(temp.path(), sub.join("loop")).unwrap();
+
+        let config = TreeConfig::default().with_format(TreeFormat::Json);
+        let tree = build_tree(temp.path(), &config).unwrap();
+
+        let link = tree.children[0]
+            .children
+            .iter()
+            .find(|c| c.name == "loop")
+            .unwrap();
+        assert!(link.is_symlink());
+        assert!(link.is_dir);
+        assert!(link.children.is_empty());
+        assert_eq!(tree.file_count(), 1);
+
+        let json: serde_json::Value = serde_json::from_str(&render_tree(&tree, &config)).unwrap();
+        let entry = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|e| e["name"] == "loop")
+            .unwrap();
+        assert_eq!(entry["symlink"], temp.path().to_string_lossy().as_ref());
+
+        let ascii = render_tree(&tree, &config.with_format(TreeFormat::Ascii));
+        assert!(ascii.contains("loop/ -> "));
+    }
+
     #[test]
     fn test_render_tree_multiple_files_last_item() {
         let mut root = FileNode::new_dir(PathBuf::from("project"));
@@ -174,7 +174,7 @@ performance:
         }
     }
 
-    // 4. Mutation Testing (M1-M5 Falsification)
+    // 4. Mutation Testing (M1-M6 Falsification)
     println!("\n4. Mutation Testing (Falsification Protocol)");
     println!("   ─────────────────────────────────────────────────────────");
     println!("   Reference: Fabbri et al., ISSRE 1999\n");
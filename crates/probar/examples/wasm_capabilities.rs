@@ -154,6 +154,7 @@ fn demo_threading_assertion() {
         shared_array_buffer: true,
         atomics: false, // Missing atomics
         hardware_concurrency: 4,
+        max_workers: Some(4),
         coop_header: Some("same-origin".to_string()),
         coep_header: Some("require-corp".to_string()),
         is_secure_context: true,
@@ -179,6 +180,7 @@ fn demo_threading_assertion() {
         shared_array_buffer: false,
         atomics: false,
         hardware_concurrency: 4,
+        max_workers: Some(4),
         coop_header: None,
         coep_header: None,
         is_secure_context: true,
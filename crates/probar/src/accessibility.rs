@@ -6,8 +6,10 @@
 //! - Color contrast analysis (WCAG 2.1 AA)
 //! - Focus indicator detection
 //! - Reduced motion preference handling
+//! - Target size (WCAG 2.2 AA, 2.5.8)
 //! - Screen reader compatibility
 
+use crate::locator::BoundingBox;
 use crate::result::{ProbarError, ProbarResult};
 
 /// Minimum contrast ratio for normal text (WCAG 2.1 AA)
@@ -19,6 +21,9 @@ pub const MIN_CONTRAST_LARGE: f32 = 3.0;
 /// Minimum contrast ratio for UI components (WCAG 2.1 AA)
 pub const MIN_CONTRAST_UI: f32 = 3.0;
 
+/// Minimum target size for pointer inputs in CSS pixels (WCAG 2.2 AA, 2.5.8)
+pub const MIN_TARGET_SIZE: f32 = 24.0;
+
 /// Color represented as RGB values
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Color {
@@ -183,6 +188,10 @@ pub struct AccessibilityConfig {
     pub min_contrast_text: f32,
     /// Minimum contrast ratio for UI
     pub min_contrast_ui: f32,
+    /// Check interactive target sizes
+    pub check_target_size: bool,
+    /// Minimum target width/height in CSS pixels
+    pub min_target_size: f32,
 }
 
 impl Default for AccessibilityConfig {
@@ -194,6 +203,8 @@ impl Default for AccessibilityConfig {
             check_keyboard: true,
             min_contrast_text: MIN_CONTRAST_NORMAL,
             min_contrast_ui: MIN_CONTRAST_UI,
+            check_target_size: true,
+            min_target_size: MIN_TARGET_SIZE,
         }
     }
 }
@@ -268,6 +279,8 @@ pub struct AccessibilityIssue {
     pub context: Option<String>,
     /// Suggested fix
     pub fix_suggestion: Option<String>,
+    /// Bounding box of the offending element, if known
+    pub rect: Option<BoundingBox>,
 }
 
 impl AccessibilityIssue {
@@ -284,6 +297,7 @@ impl AccessibilityIssue {
             severity,
             context: None,
             fix_suggestion: None,
+            rect: None,
         }
     }
 
@@ -300,6 +314,13 @@ impl AccessibilityIssue {
         self.fix_suggestion = Some(fix.into());
         self
     }
+
+    /// Add the element's bounding box
+    #[must_use]
+    pub fn with_rect(mut self, rect: BoundingBox) -> Self {
+        self.rect = Some(rect);
+        self
+    }
 }
 
 /// Severity level of an accessibility issue
@@ -404,6 +425,68 @@ impl AccessibilityValidator {
         }
     }
 
+    /// Check interactive target sizes (WCAG 2.5.8)
+    ///
+    /// Flags targets narrower or shorter than `min_target_size` unless the
+    /// spacing exception applies: a circle of that diameter centered on the
+    /// undersized target must not intersect any other target, nor the circle
+    /// of another undersized target.
+    #[must_use]
+    pub fn check_target_size(&self, targets: &[(BoundingBox, &str)]) -> Vec<AccessibilityIssue> {
+        if !self.config.check_target_size {
+            return Vec::new();
+        }
+
+        let min = self.config.min_target_size;
+        let is_undersized = |rect: &BoundingBox| rect.width < min || rect.height < min;
+        let mut issues = Vec::new();
+
+        for (i, (rect, context)) in targets.iter().enumerate() {
+            if !is_undersized(rect) {
+                continue;
+            }
+
+            let center = rect.center();
+            let radius = min / 2.0;
+            let well_spaced =
+                targets
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .all(|(_, (other, _))| {
+                        if is_undersized(other) {
+                            // Two circles of diameter `min` must not overlap
+                            let other_center = other.center();
+                            (center.x - other_center.x).hypot(center.y - other_center.y) >= min
+                        } else {
+                            let nearest_x = center.x.clamp(other.x, other.x + other.width);
+                            let nearest_y = center.y.clamp(other.y, other.y + other.height);
+                            (center.x - nearest_x).hypot(center.y - nearest_y) >= radius
+                        }
+                    });
+
+            if !well_spaced {
+                issues.push(
+                    AccessibilityIssue::new(
+                        "2.5.8",
+                        format!(
+                            "Target size {:.0}x{:.0}px is below the {min:.0}x{min:.0}px minimum",
+                            rect.width, rect.height
+                        ),
+                        Severity::Major,
+                    )
+                    .with_context(*context)
+                    .with_rect(*rect)
+                    .with_fix(format!(
+                        "Enlarge the target to at least {min:.0}x{min:.0}px or add spacing around it"
+                    )),
+                );
+            }
+        }
+
+        issues
+    }
+
     /// Run a full accessibility audit
     #[must_use]
     pub fn audit(
@@ -470,6 +553,8 @@ pub struct FlashDetector {
     pub max_red_intensity: f32,
     /// Maximum flash area (percentage of screen)
     pub max_flash_area: f32,
+    /// Edge length of the square regions used by [`Self::analyze_regions`] (pixels)
+    pub region_size: usize,
 }
 
 impl Default for FlashDetector {
@@ -478,6 +563,7 @@ impl Default for FlashDetector {
             max_flash_rate: 3.0, // WCAG 2.3.1: < 3 flashes per second
             max_red_intensity: 0.8,
             max_flash_area: 0.25, // 25% of screen max
+            region_size: 64,      // Small enough that a flashing widget dominates its tile
         }
     }
 }
@@ -495,6 +581,15 @@ pub struct FlashResult {
     pub is_safe: bool,
     /// Warning message if applicable
     pub warning: Option<String>,
+    /// Region with the highest flash rate, when it exceeds the threshold
+    pub region: Option<BoundingBox>,
+}
+
+/// Per-region flash counts over a frame sequence
+#[derive(Debug, Clone, Copy, Default)]
+struct RegionFlashes {
+    general: usize,
+    red: usize,
 }
 
 impl FlashDetector {
@@ -540,8 +635,174 @@ impl FlashDetector {
             flash_area,
             is_safe,
             warning,
+            region: None,
         }
     }
+
+    /// Analyze a frame sequence for flashing per region (WCAG 2.3.1)
+    ///
+    /// The frame is tiled into `region_size` squares and each tile is
+    /// evaluated on its own, so a small flashing element is not diluted by a
+    /// static background. A flash is a pair of opposing luminance changes of
+    /// more than 10%; it counts as a red flash when either state is saturated
+    /// red above `max_red_intensity`. Content is unsafe if any tile exceeds
+    /// `max_flash_rate` general or red flashes per second.
+    ///
+    /// `frames` are row-major pixel buffers of `width * height` colors
+    /// captured at `fps`.
+    #[must_use]
+    pub fn analyze_regions(
+        &self,
+        frames: &[&[Color]],
+        width: usize,
+        height: usize,
+        fps: f32,
+    ) -> FlashResult {
+        let tile = self.region_size.max(1);
+        let cols = width.div_ceil(tile);
+        let rows = height.div_ceil(tile);
+        let duration_secs = frames.len().saturating_sub(1) as f32 / fps;
+        if duration_secs <= 0.0 || !duration_secs.is_finite() {
+            return FlashResult {
+                flash_rate: 0.0,
+                red_flash_exceeded: false,
+                flash_area: 0.0,
+                is_safe: true,
+                warning: None,
+                region: None,
+            };
+        }
+
+        let mut worst: Option<(f32, BoundingBox)> = None;
+        let mut red_flash_exceeded = false;
+        let mut unsafe_pixels = 0.0;
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let rect = BoundingBox::new(
+                    (col * tile) as f32,
+                    (row * tile) as f32,
+                    tile.min(width - col * tile) as f32,
+                    tile.min(height - row * tile) as f32,
+                );
+                let flashes = self.count_region_flashes(frames, width, &rect);
+
+                let rate = flashes.general as f32 / duration_secs;
+                let red_rate = flashes.red as f32 / duration_secs;
+                let red_exceeded = red_rate > self.max_flash_rate;
+                if rate <= self.max_flash_rate && !red_exceeded {
+                    continue;
+                }
+
+                red_flash_exceeded |= red_exceeded;
+                unsafe_pixels += rect.width * rect.height;
+                match worst {
+                    Some((worst_rate, _)) if worst_rate >= rate => {}
+                    _ => worst = Some((rate, rect)),
+                }
+            }
+        }
+
+        let frame_pixels = (width * height) as f32;
+        let flash_area = if frame_pixels > 0.0 {
+            unsafe_pixels / frame_pixels
+        } else {
+            0.0
+        };
+
+        match worst {
+            None => FlashResult {
+                flash_rate: 0.0,
+                red_flash_exceeded: false,
+                flash_area,
+                is_safe: true,
+                warning: None,
+                region: None,
+            },
+            Some((flash_rate, rect)) => FlashResult {
+                flash_rate,
+                red_flash_exceeded,
+                flash_area,
+                is_safe: false,
+                warning: Some(format!(
+                    "Region at ({:.0}, {:.0}) {:.0}x{:.0} flashes {flash_rate:.1} times per second",
+                    rect.x, rect.y, rect.width, rect.height
+                )),
+                region: Some(rect),
+            },
+        }
+    }
+
+    /// Count flashes within one region across the frame sequence
+    fn count_region_flashes(
+        &self,
+        frames: &[&[Color]],
+        width: usize,
+        rect: &BoundingBox,
+    ) -> RegionFlashes {
+        let samples: Vec<(f32, f32)> = frames
+            .iter()
+            .map(|frame| Self::region_luminance_and_red(frame, width, rect))
+            .collect();
+
+        let mut transitions = 0;
+        let mut red_transitions = 0;
+        let mut last_direction = 0.0_f32;
+        for pair in samples.windows(2) {
+            let (prev_lum, prev_red) = pair[0];
+            let (lum, red) = pair[1];
+            let delta = lum - prev_lum;
+            if delta.abs() <= 0.1 {
+                continue;
+            }
+            // Only opposing changes form a flash
+            if delta.signum() != last_direction {
+                transitions += 1;
+                if prev_red.max(red) > self.max_red_intensity {
+                    red_transitions += 1;
+                }
+                last_direction = delta.signum();
+            }
+        }
+
+        RegionFlashes {
+            general: transitions / 2,
+            red: red_transitions / 2,
+        }
+    }
+
+    /// Mean relative luminance of a region and mean red level of its
+    /// saturated-red pixels (0.0 when there are none)
+    fn region_luminance_and_red(frame: &[Color], width: usize, rect: &BoundingBox) -> (f32, f32) {
+        let x0 = rect.x as usize;
+        let y0 = rect.y as usize;
+        let x1 = x0 + rect.width as usize;
+        let y1 = y0 + rect.height as usize;
+
+        let mut luminance = 0.0;
+        let mut red = 0.0;
+        let mut red_count = 0.0;
+        let mut count = 0.0;
+        for y in y0..y1 {
+            for pixel in frame.iter().skip(y * width + x0).take(x1 - x0) {
+                luminance += pixel.relative_luminance();
+                let total = f32::from(pixel.r) + f32::from(pixel.g) + f32::from(pixel.b);
+                if total > 0.0 && f32::from(pixel.r) / total >= 0.8 {
+                    red += f32::from(pixel.r) / 255.0;
+                    red_count += 1.0;
+                }
+                count += 1.0;
+            }
+        }
+
+        let luminance = if count > 0.0 { luminance / count } else { 0.0 };
+        let red = if red_count > 0.0 {
+            red / red_count
+        } else {
+            0.0
+        };
+        (luminance, red)
+    }
 }
 
 #[cfg(test)]
@@ -803,6 +1064,65 @@ mod tests {
         }
     }
 
+    mod target_size_tests {
+        use super::*;
+
+        #[test]
+        fn test_undersized_button_flagged() {
+            let validator = AccessibilityValidator::new();
+            let small = BoundingBox::new(0.0, 0.0, 16.0, 16.0);
+            let neighbor = BoundingBox::new(18.0, 0.0, 40.0, 40.0);
+
+            let issues = validator.check_target_size(&[(small, "close"), (neighbor, "ok")]);
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].wcag_code, "2.5.8");
+            assert_eq!(issues[0].severity, Severity::Major);
+            assert_eq!(issues[0].rect, Some(small));
+            assert_eq!(issues[0].context.as_deref(), Some("close"));
+        }
+
+        #[test]
+        fn test_undersized_but_well_spaced_passes() {
+            let validator = AccessibilityValidator::new();
+            let small = BoundingBox::new(0.0, 0.0, 16.0, 16.0);
+            // Circle around (8, 8) with radius 12 stays clear of x >= 20
+            let neighbor = BoundingBox::new(20.0, 0.0, 40.0, 40.0);
+            let other_small = BoundingBox::new(0.0, 40.0, 16.0, 16.0);
+
+            let issues = validator.check_target_size(&[
+                (small, "close"),
+                (neighbor, "ok"),
+                (other_small, "help"),
+            ]);
+            assert!(issues.is_empty());
+        }
+
+        #[test]
+        fn test_compliant_target_passes() {
+            let validator = AccessibilityValidator::new();
+            let button = BoundingBox::new(0.0, 0.0, 24.0, 24.0);
+            let adjacent = BoundingBox::new(24.0, 0.0, 44.0, 44.0);
+
+            assert!(validator
+                .check_target_size(&[(button, "play"), (adjacent, "pause")])
+                .is_empty());
+        }
+
+        #[test]
+        fn test_custom_min_target_size() {
+            let validator = AccessibilityValidator::with_config(AccessibilityConfig {
+                min_target_size: 44.0,
+                ..Default::default()
+            });
+            let button = BoundingBox::new(0.0, 0.0, 24.0, 24.0);
+            let adjacent = BoundingBox::new(24.0, 0.0, 44.0, 44.0);
+
+            let issues = validator.check_target_size(&[(button, "play"), (adjacent, "pause")]);
+            assert_eq!(issues.len(), 1);
+            assert!(issues[0].description.contains("44x44px"));
+        }
+    }
+
     mod flash_detector_tests {
         use super::*;
 
@@ -843,6 +1163,74 @@ mod tests {
             let result = detector.analyze(0.1, 0.2, 0.5, 1.0);
             assert!(!result.is_safe);
         }
+
+        /// 256x256 gray frames with a 48x48 square toggling between a color and black every frame
+        fn flashing_square_frames(count: usize, square_color: Color) -> Vec<Vec<Color>> {
+            (0..count)
+                .map(|i| {
+                    let mut frame = vec![Color::new(128, 128, 128); 256 * 256];
+                    let color = if i % 2 == 0 {
+                        square_color
+                    } else {
+                        Color::new(0, 0, 0)
+                    };
+                    for y in 200..248 {
+                        for x in 136..184 {
+                            frame[y * 256 + x] = color;
+                        }
+                    }
+                    frame
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_analyze_regions_small_flashing_square() {
+            let detector = FlashDetector::new();
+            // 1 second at 30 fps: 15 flashes/sec from a square covering 3.5% of the frame
+            let frames = flashing_square_frames(31, Color::new(255, 255, 255));
+            let refs: Vec<&[Color]> = frames.iter().map(Vec::as_slice).collect();
+
+            let result = detector.analyze_regions(&refs, 256, 256, 30.0);
+            assert!(!result.is_safe);
+            assert!(result.flash_rate > 3.0);
+            assert!(!result.red_flash_exceeded);
+            assert_eq!(
+                result.region,
+                Some(BoundingBox::new(128.0, 192.0, 64.0, 64.0))
+            );
+            assert!(result.warning.is_some());
+
+            // The same frames averaged over the whole screen stay below 10% change
+            let full_frame = FlashDetector {
+                region_size: 256,
+                ..FlashDetector::default()
+            };
+            assert!(full_frame.analyze_regions(&refs, 256, 256, 30.0).is_safe);
+        }
+
+        #[test]
+        fn test_analyze_regions_red_flash() {
+            let detector = FlashDetector::new();
+            let frames = flashing_square_frames(31, Color::new(255, 0, 0));
+            let refs: Vec<&[Color]> = frames.iter().map(Vec::as_slice).collect();
+
+            let result = detector.analyze_regions(&refs, 256, 256, 30.0);
+            assert!(!result.is_safe);
+            assert!(result.red_flash_exceeded);
+        }
+
+        #[test]
+        fn test_analyze_regions_static_frames_safe() {
+            let detector = FlashDetector::new();
+            let frame = vec![Color::new(128, 128, 128); 64 * 64];
+            let refs: Vec<&[Color]> = vec![frame.as_slice(); 10];
+
+            let result = detector.analyze_regions(&refs, 64, 64, 30.0);
+            assert!(result.is_safe);
+            assert!(result.region.is_none());
+            assert!(result.flash_rate.abs() < f32::EPSILON);
+        }
     }
 
     mod config_tests {
@@ -13,6 +13,8 @@
 //!                               AnimationReport
 //!
 //! Keyframes ──→ easing::verify_easing ──→ EasingVerification
+//!
+//! Frame times ──→ pacing::verify_frame_pacing ──→ FramePacingReport
 //! ```
 //!
 //! # Integration with rmedia
@@ -21,12 +23,14 @@
 //! probar reads these timelines and verifies actual timing matches intent.
 
 pub mod easing;
+pub mod pacing;
 pub mod timing;
 pub mod types;
 
 pub use easing::{sample_easing, verify_easing, EasingVerification, Keyframe};
+pub use pacing::verify_frame_pacing;
 pub use timing::{verify_events, verify_timeline, ObservedEvent};
 pub use types::{
     AnimationEvent, AnimationEventType, AnimationReport, AnimationTimeline, AnimationVerdict,
-    EasingFunction, EventResult,
+    EasingFunction, EventResult, FramePacingReport, JankFrame,
 };
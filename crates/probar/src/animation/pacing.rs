@@ -0,0 +1,124 @@
+//! Frame pacing verification.
+//!
+//! Checks that an animation rendered at a steady frame rate by comparing
+//! observed frame presentation times against the target frame budget.
+//! Frames that overrun the budget by more than the allowed jank (e.g. a
+//! GC pause mid-animation) are reported individually.
+
+use super::types::{AnimationVerdict, FramePacingReport, JankFrame};
+
+/// Verify frame pacing against a target FPS.
+///
+/// `frame_times_secs` are the presentation timestamps of consecutive
+/// frames. A frame is jank when the interval since the previous frame
+/// exceeds `1000 / target_fps + max_jank_ms`.
+#[must_use]
+pub fn verify_frame_pacing(
+    frame_times_secs: &[f64],
+    target_fps: f64,
+    max_jank_ms: f64,
+) -> FramePacingReport {
+    let frame_budget_ms = if target_fps > 0.0 {
+        1000.0 / target_fps
+    } else {
+        0.0
+    };
+    let threshold_ms = frame_budget_ms + max_jank_ms;
+
+    let mut jank_frames = Vec::new();
+    let mut max_frame_time_ms: f64 = 0.0;
+
+    for (i, pair) in frame_times_secs.windows(2).enumerate() {
+        let frame_time_ms = (pair[1] - pair[0]) * 1000.0;
+        max_frame_time_ms = max_frame_time_ms.max(frame_time_ms);
+        if frame_time_ms > threshold_ms {
+            jank_frames.push(JankFrame {
+                index: i + 1,
+                time_secs: pair[1],
+                frame_time_ms,
+            });
+        }
+    }
+
+    let verdict = if frame_times_secs.len() < 2 {
+        AnimationVerdict::NoEvents
+    } else if jank_frames.is_empty() {
+        AnimationVerdict::Pass
+    } else {
+        AnimationVerdict::Fail
+    };
+
+    FramePacingReport {
+        verdict,
+        target_fps,
+        frame_budget_ms,
+        max_jank_ms,
+        frame_count: frame_times_secs.len(),
+        jank_count: jank_frames.len(),
+        jank_frames,
+        max_frame_time_ms,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    /// Frame timestamps at a steady 60 FPS.
+    fn steady_60fps(frames: usize) -> Vec<f64> {
+        (0..frames).map(|i| i as f64 / 60.0).collect()
+    }
+
+    #[test]
+    fn test_steady_frames_pass() {
+        let report = verify_frame_pacing(&steady_60fps(120), 60.0, 4.0);
+        assert_eq!(report.verdict, AnimationVerdict::Pass);
+        assert_eq!(report.jank_count, 0);
+        assert_eq!(report.frame_count, 120);
+        assert!((report.frame_budget_ms - 16.667).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_single_stall_reported() {
+        // 30 steady frames, one 50ms stall, then 30 more steady frames
+        let mut times = steady_60fps(30);
+        let stall_at = times.last().unwrap() + 0.050;
+        times.extend((0..30).map(|i| stall_at + i as f64 / 60.0));
+
+        let report = verify_frame_pacing(&times, 60.0, 4.0);
+        assert_eq!(report.verdict, AnimationVerdict::Fail);
+        assert_eq!(report.jank_count, 1);
+        let jank = &report.jank_frames[0];
+        assert_eq!(jank.index, 30);
+        assert!((jank.time_secs - stall_at).abs() < f64::EPSILON);
+        assert!((jank.frame_time_ms - 50.0).abs() < 0.001);
+        assert!((report.max_frame_time_ms - 50.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_jank_within_allowance() {
+        // 20ms frame at 60 FPS is within a 4ms allowance (16.67 + 4 = 20.67)
+        let times = vec![0.0, 0.016_667, 0.036_667, 0.053_333];
+        let report = verify_frame_pacing(&times, 60.0, 4.0);
+        assert_eq!(report.verdict, AnimationVerdict::Pass);
+    }
+
+    #[test]
+    fn test_jank_fails_animation_report() {
+        use crate::animation::timing::verify_events;
+
+        let mut report = verify_events(&[], &[], 20.0, "test");
+        report.verdict = AnimationVerdict::Pass;
+        let pacing = verify_frame_pacing(&[0.0, 0.016_667, 0.1], 60.0, 4.0);
+        report.apply_frame_pacing(&pacing);
+        assert_eq!(report.verdict, AnimationVerdict::Fail);
+    }
+
+    #[test]
+    fn test_too_few_frames() {
+        let report = verify_frame_pacing(&[0.0], 60.0, 4.0);
+        assert_eq!(report.verdict, AnimationVerdict::NoEvents);
+        assert_eq!(report.jank_count, 0);
+    }
+}
@@ -95,6 +95,49 @@ pub struct AnimationReport {
     pub mean_delta_ms: f64,
 }
 
+impl AnimationReport {
+    /// Fold a frame pacing result into the overall verdict.
+    ///
+    /// Jank fails the animation even if every event landed on time.
+    pub fn apply_frame_pacing(&mut self, pacing: &FramePacingReport) {
+        if pacing.verdict == AnimationVerdict::Fail {
+            self.verdict = AnimationVerdict::Fail;
+        }
+    }
+}
+
+/// Frame pacing verification report.
+#[derive(Clone, Debug, Serialize)]
+pub struct FramePacingReport {
+    /// Overall verdict (`NoEvents` if fewer than two frames were observed)
+    pub verdict: AnimationVerdict,
+    /// Target frame rate
+    pub target_fps: f64,
+    /// Frame budget in milliseconds (1000 / target_fps)
+    pub frame_budget_ms: f64,
+    /// Allowed overrun beyond the frame budget in milliseconds
+    pub max_jank_ms: f64,
+    /// Number of frames observed
+    pub frame_count: usize,
+    /// Number of frames that exceeded budget + allowance
+    pub jank_count: usize,
+    /// Frames that exceeded budget + allowance
+    pub jank_frames: Vec<JankFrame>,
+    /// Longest observed frame time in milliseconds
+    pub max_frame_time_ms: f64,
+}
+
+/// A frame that took longer than the frame budget plus allowed jank.
+#[derive(Clone, Debug, Serialize)]
+pub struct JankFrame {
+    /// Index of the late frame in the observed series
+    pub index: usize,
+    /// Presentation time of the late frame in seconds
+    pub time_secs: f64,
+    /// Time since the previous frame in milliseconds
+    pub frame_time_ms: f64,
+}
+
 /// Per-event verification result.
 #[derive(Clone, Debug, Serialize)]
 pub struct EventResult {
@@ -1,10 +1,10 @@
-//! Audio level analysis: peak, RMS, dynamic range.
+//! Audio level analysis: peak, RMS, DC offset, dynamic range.
 
 use super::types::AudioLevels;
 
 /// Compute audio levels from PCM samples.
 ///
-/// Calculates peak amplitude, RMS level, and dynamic range.
+/// Calculates peak amplitude, RMS level, DC offset, and dynamic range.
 pub fn analyze_levels(samples: &[f32]) -> AudioLevels {
     if samples.is_empty() {
         return AudioLevels {
@@ -12,6 +12,8 @@ pub fn analyze_levels(samples: &[f32]) -> AudioLevels {
             peak_dbfs: -120.0,
             rms: 0.0,
             rms_dbfs: -120.0,
+            dc_offset: 0.0,
+            dc_offset_dbfs: -120.0,
             dynamic_range_db: 0.0,
             passed: false,
         };
@@ -22,8 +24,11 @@ pub fn analyze_levels(samples: &[f32]) -> AudioLevels {
     let sum_sq: f64 = samples.iter().map(|&s| f64::from(s) * f64::from(s)).sum();
     let rms = (sum_sq / samples.len() as f64).sqrt();
 
+    let dc_offset = samples.iter().map(|&s| f64::from(s)).sum::<f64>() / samples.len() as f64;
+
     let peak_dbfs = amplitude_to_dbfs(f64::from(peak));
     let rms_dbfs = amplitude_to_dbfs(rms);
+    let dc_offset_dbfs = amplitude_to_dbfs(dc_offset.abs());
 
     // Estimate noise floor from quietest 10% of windowed RMS values
     let noise_floor_db = estimate_noise_floor(samples);
@@ -34,6 +39,8 @@ pub fn analyze_levels(samples: &[f32]) -> AudioLevels {
         peak_dbfs,
         rms,
         rms_dbfs,
+        dc_offset,
+        dc_offset_dbfs,
         dynamic_range_db,
         passed: true, // caller sets based on config
     }
@@ -45,6 +52,12 @@ pub fn check_levels(levels: &AudioLevels, min_rms_dbfs: f64, max_peak_dbfs: f64)
     levels.rms_dbfs >= min_rms_dbfs && levels.peak_dbfs <= max_peak_dbfs
 }
 
+/// Check if the DC offset magnitude is within the configured limit.
+#[must_use]
+pub fn check_dc_offset(levels: &AudioLevels, max_dc_offset: f64) -> bool {
+    levels.dc_offset.abs() <= max_dc_offset
+}
+
 /// Convert linear amplitude to dBFS.
 fn amplitude_to_dbfs(amplitude: f64) -> f64 {
     if amplitude <= 0.0 {
@@ -146,6 +159,8 @@ mod tests {
             peak_dbfs: -6.0,
             rms: 0.2,
             rms_dbfs: -14.0,
+            dc_offset: 0.0,
+            dc_offset_dbfs: -120.0,
             dynamic_range_db: 40.0,
             passed: true,
         };
@@ -159,6 +174,8 @@ mod tests {
             peak_dbfs: -40.0,
             rms: 0.001,
             rms_dbfs: -60.0,
+            dc_offset: 0.0,
+            dc_offset_dbfs: -120.0,
             dynamic_range_db: 20.0,
             passed: true,
         };
@@ -172,12 +189,36 @@ mod tests {
             peak_dbfs: 0.0,
             rms: 0.5,
             rms_dbfs: -6.0,
+            dc_offset: 0.0,
+            dc_offset_dbfs: -120.0,
             dynamic_range_db: 40.0,
             passed: true,
         };
         assert!(!check_levels(&levels, -40.0, -0.1));
     }
 
+    #[test]
+    fn test_dc_offset_zero_mean() {
+        let signal: Vec<f32> = (0..48000)
+            .map(|i| if i % 2 == 0 { 0.5 } else { -0.5 })
+            .collect();
+        let levels = analyze_levels(&signal);
+        assert!(levels.dc_offset.abs() < 1e-9);
+        assert_eq!(levels.dc_offset_dbfs, -120.0);
+        assert!(check_dc_offset(&levels, 0.01));
+    }
+
+    #[test]
+    fn test_dc_offset_biased() {
+        let signal: Vec<f32> = (0..48000)
+            .map(|i| if i % 2 == 0 { 0.6 } else { -0.4 })
+            .collect();
+        let levels = analyze_levels(&signal);
+        assert!((levels.dc_offset - 0.1).abs() < 1e-6);
+        assert!((levels.dc_offset_dbfs - (-20.0)).abs() < 0.01);
+        assert!(!check_dc_offset(&levels, 0.01));
+    }
+
     #[test]
     fn test_dynamic_range_with_signal() {
         // Signal with both loud and quiet portions
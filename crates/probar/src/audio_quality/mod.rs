@@ -2,8 +2,10 @@
 //!
 //! Analyzes extracted audio to verify:
 //! - Peak and RMS levels are within acceptable range
+//! - No DC offset (mean sample value near zero)
 //! - No digital clipping (samples at +/- 1.0)
-//! - Silence regions are identified and within acceptable limits
+//! - Silence regions are identified, classified as leading/trailing/internal,
+//!   and within acceptable limits
 //!
 //! # Usage
 //!
@@ -19,10 +21,10 @@ pub mod silence;
 pub mod types;
 
 pub use clipping::detect_clipping;
-pub use levels::{analyze_levels, check_levels};
+pub use levels::{analyze_levels, check_dc_offset, check_levels};
 pub use silence::{check_silence, detect_silence};
 pub use types::{
-    AudioLevels, AudioQualityConfig, AudioQualityReport, AudioVerdict, ClippingReport,
+    AudioLevels, AudioQualityConfig, AudioQualityReport, AudioVerdict, ClippingReport, SilenceKind,
     SilenceRegion, SilenceReport,
 };
 
@@ -63,6 +65,8 @@ pub fn analyze_samples(
                 peak_dbfs: -120.0,
                 rms: 0.0,
                 rms_dbfs: -120.0,
+                dc_offset: 0.0,
+                dc_offset_dbfs: -120.0,
                 dynamic_range_db: 0.0,
                 passed: false,
             },
@@ -84,7 +88,9 @@ pub fn analyze_samples(
     }
 
     let mut audio_levels = analyze_levels(samples);
-    audio_levels.passed = check_levels(&audio_levels, config.min_rms_dbfs, config.max_peak_dbfs);
+    let levels_ok = check_levels(&audio_levels, config.min_rms_dbfs, config.max_peak_dbfs);
+    let dc_ok = check_dc_offset(&audio_levels, config.max_dc_offset);
+    audio_levels.passed = levels_ok && dc_ok;
 
     let mut clip_report = detect_clipping(samples);
     if !config.no_clipping {
@@ -97,15 +103,16 @@ pub fn analyze_samples(
         config.silence_threshold_dbfs,
         config.min_silence_duration_secs,
     );
-    silence_report.passed = check_silence(&silence_report, config.max_silence_pct);
+    silence_report.passed = check_silence(&silence_report, config);
 
     #[allow(clippy::cast_precision_loss)]
     let duration_secs = samples.len() as f64 / f64::from(sample_rate);
 
-    let verdict = if audio_levels.passed && clip_report.passed && silence_report.passed {
-        AudioVerdict::Pass
-    } else {
-        AudioVerdict::Fail
+    let others_ok = levels_ok && clip_report.passed && silence_report.passed;
+    let verdict = match (others_ok, dc_ok) {
+        (true, true) => AudioVerdict::Pass,
+        (true, false) => AudioVerdict::DcOffset,
+        (false, _) => AudioVerdict::Fail,
     };
 
     AudioQualityReport {
@@ -132,10 +139,17 @@ mod tests {
         assert_eq!(report.verdict, AudioVerdict::NoAudio);
     }
 
+    /// Zero-mean square wave at +/- `amplitude`.
+    fn square_wave(amplitude: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| if i % 2 == 0 { amplitude } else { -amplitude })
+            .collect()
+    }
+
     #[test]
     fn test_analyze_samples_clean_signal() {
         let config = AudioQualityConfig::default();
-        let samples = vec![0.3f32; 48000]; // 1s of clean signal
+        let samples = square_wave(0.3, 48000); // 1s of clean signal
         let report = analyze_samples(&samples, Path::new("test.mp4"), &config, 48000);
         assert_eq!(report.verdict, AudioVerdict::Pass);
         assert!(report.levels.passed);
@@ -172,6 +186,27 @@ mod tests {
         assert!(!report.levels.passed);
     }
 
+    #[test]
+    fn test_analyze_samples_dc_offset() {
+        let config = AudioQualityConfig::default();
+        let samples: Vec<f32> = square_wave(0.3, 48000).iter().map(|s| s + 0.1).collect();
+        let report = analyze_samples(&samples, Path::new("test.mp4"), &config, 48000);
+        assert!((report.levels.dc_offset - 0.1).abs() < 1e-6);
+        assert!((report.levels.dc_offset_dbfs - (-20.0)).abs() < 0.01);
+        assert!(!report.levels.passed);
+        assert!(report.clipping.passed);
+        assert_eq!(report.verdict, AudioVerdict::DcOffset);
+    }
+
+    #[test]
+    fn test_analyze_samples_dc_offset_within_limit() {
+        let config = AudioQualityConfig::default().with_max_dc_offset(0.2);
+        let samples: Vec<f32> = square_wave(0.3, 48000).iter().map(|s| s + 0.1).collect();
+        let report = analyze_samples(&samples, Path::new("test.mp4"), &config, 48000);
+        assert!(report.levels.passed);
+        assert_eq!(report.verdict, AudioVerdict::Pass);
+    }
+
     #[test]
     fn test_analyze_samples_source_path() {
         let config = AudioQualityConfig::default();
@@ -1,9 +1,11 @@
 //! Silence detection in audio streams.
 //!
 //! Identifies regions of silence (energy below threshold) and reports
-//! their positions and durations.
+//! their positions and durations. Regions are classified as leading,
+//! trailing, or internal so head/tail padding can be tolerated while
+//! mid-clip dropouts fail.
 
-use super::types::{SilenceRegion, SilenceReport};
+use super::types::{AudioQualityConfig, SilenceKind, SilenceRegion, SilenceReport};
 
 /// Detect silence regions in PCM samples.
 ///
@@ -41,14 +43,9 @@ pub fn detect_silence(
                 silence_start = Some(pos);
             }
         } else if let Some(start) = silence_start.take() {
-            let duration_samples = pos - start;
-            let duration_secs = duration_samples as f64 / f64::from(sample_rate);
-            if duration_secs >= min_duration_secs {
-                regions.push(SilenceRegion {
-                    start_secs: start as f64 / f64::from(sample_rate),
-                    end_secs: pos as f64 / f64::from(sample_rate),
-                    duration_secs,
-                });
+            let region = silence_region(start, pos, samples.len(), sample_rate);
+            if region.duration_secs >= min_duration_secs {
+                regions.push(region);
             }
         }
 
@@ -57,14 +54,9 @@ pub fn detect_silence(
 
     // Handle trailing silence
     if let Some(start) = silence_start {
-        let duration_samples = samples.len() - start;
-        let duration_secs = duration_samples as f64 / f64::from(sample_rate);
-        if duration_secs >= min_duration_secs {
-            regions.push(SilenceRegion {
-                start_secs: start as f64 / f64::from(sample_rate),
-                end_secs: samples.len() as f64 / f64::from(sample_rate),
-                duration_secs,
-            });
+        let region = silence_region(start, samples.len(), samples.len(), sample_rate);
+        if region.duration_secs >= min_duration_secs {
+            regions.push(region);
         }
     }
 
@@ -84,10 +76,40 @@ pub fn detect_silence(
     }
 }
 
-/// Check if silence percentage exceeds the maximum.
+/// Check silence against the configured limits.
+///
+/// Fails when the overall silence percentage exceeds `max_silence_pct` or
+/// when any region is longer than the limit for its kind. Leading and
+/// trailing silence are unlimited by default, so only internal gaps fail
+/// unless the caller opts in to stricter padding limits.
 #[must_use]
-pub fn check_silence(report: &SilenceReport, max_silence_pct: f64) -> bool {
-    report.silence_pct <= max_silence_pct
+pub fn check_silence(report: &SilenceReport, config: &AudioQualityConfig) -> bool {
+    report.silence_pct <= config.max_silence_pct
+        && report.regions.iter().all(|region| {
+            config
+                .max_silence_secs(region.kind)
+                .map_or(true, |max| region.duration_secs <= max)
+        })
+}
+
+/// Build a silence region spanning samples `[start, end)` and classify it.
+///
+/// A region touching the start of the clip is leading (this includes a
+/// clip that is silent throughout); one running to the end is trailing.
+fn silence_region(start: usize, end: usize, total: usize, sample_rate: u32) -> SilenceRegion {
+    let kind = if start == 0 {
+        SilenceKind::Leading
+    } else if end >= total {
+        SilenceKind::Trailing
+    } else {
+        SilenceKind::Internal
+    };
+    SilenceRegion {
+        start_secs: start as f64 / f64::from(sample_rate),
+        end_secs: end as f64 / f64::from(sample_rate),
+        duration_secs: (end - start) as f64 / f64::from(sample_rate),
+        kind,
+    }
 }
 
 fn window_rms(samples: &[f32]) -> f64 {
@@ -131,6 +153,7 @@ mod tests {
         let report = detect_silence(&samples, 48000, -60.0, 0.5);
         assert_eq!(report.regions.len(), 1);
         assert!((report.regions[0].duration_secs - 1.0).abs() < 0.02);
+        assert_eq!(report.regions[0].kind, SilenceKind::Internal);
     }
 
     #[test]
@@ -155,6 +178,41 @@ mod tests {
         samples.extend(vec![0.0f32; 48000]); // 1.0s trailing silence
         let report = detect_silence(&samples, 48000, -60.0, 0.5);
         assert_eq!(report.regions.len(), 1);
+        assert_eq!(report.regions[0].kind, SilenceKind::Trailing);
+    }
+
+    #[test]
+    fn test_detect_silence_classifies_leading_and_internal() {
+        let mut samples = vec![0.0f32; 96000]; // 2.0s leading silence
+        samples.extend(vec![0.5f32; 48000]); // 1.0s signal
+        samples.extend(vec![0.0f32; 96000]); // 2.0s dropout
+        samples.extend(vec![0.5f32; 48000]); // 1.0s signal
+        let report = detect_silence(&samples, 48000, -60.0, 0.5);
+        assert_eq!(report.regions.len(), 2);
+        assert_eq!(report.regions[0].kind, SilenceKind::Leading);
+        assert!(report.regions[0].start_secs < f64::EPSILON);
+        assert_eq!(report.regions[1].kind, SilenceKind::Internal);
+        assert!((report.regions[1].start_secs - 3.0).abs() < 0.02);
+        assert!((report.regions[1].duration_secs - 2.0).abs() < 0.02);
+
+        // The 2s internal gap fails the default 1s limit
+        let config = AudioQualityConfig::default();
+        assert!(!check_silence(&report, &config));
+    }
+
+    #[test]
+    fn test_check_silence_tolerates_leading_padding() {
+        let mut samples = vec![0.0f32; 96000]; // 2.0s leading silence
+        samples.extend(vec![0.5f32; 144_000]); // 3.0s signal
+        let report = detect_silence(&samples, 48000, -60.0, 0.5);
+        assert_eq!(report.regions.len(), 1);
+        assert_eq!(report.regions[0].kind, SilenceKind::Leading);
+
+        let config = AudioQualityConfig::default();
+        assert!(check_silence(&report, &config));
+
+        let strict = AudioQualityConfig::default().with_max_leading_silence_secs(1.0);
+        assert!(!check_silence(&report, &strict));
     }
 
     #[test]
@@ -173,7 +231,7 @@ mod tests {
             silence_pct: 10.0,
             passed: true,
         };
-        assert!(check_silence(&report, 80.0));
+        assert!(check_silence(&report, &AudioQualityConfig::default()));
     }
 
     #[test]
@@ -184,7 +242,7 @@ mod tests {
             silence_pct: 90.0,
             passed: true,
         };
-        assert!(!check_silence(&report, 80.0));
+        assert!(!check_silence(&report, &AudioQualityConfig::default()));
     }
 
     #[test]
@@ -37,6 +37,10 @@ pub struct AudioLevels {
     pub rms: f64,
     /// RMS amplitude in dBFS
     pub rms_dbfs: f64,
+    /// DC offset (mean sample value, signed)
+    pub dc_offset: f64,
+    /// Magnitude of the DC offset in dBFS
+    pub dc_offset_dbfs: f64,
     /// Dynamic range in dB (peak - noise floor)
     pub dynamic_range_db: f64,
     /// Whether levels are within acceptable range
@@ -76,6 +80,29 @@ pub struct SilenceRegion {
     pub end_secs: f64,
     /// Duration in seconds
     pub duration_secs: f64,
+    /// Where the region sits in the clip
+    pub kind: SilenceKind,
+}
+
+/// Position of a silence region within the clip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SilenceKind {
+    /// Silence at the start of the clip (head padding)
+    Leading,
+    /// Silence running to the end of the clip (tail padding)
+    Trailing,
+    /// Gap between two non-silent sections (likely a dropout)
+    Internal,
+}
+
+impl std::fmt::Display for SilenceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Leading => write!(f, "leading"),
+            Self::Trailing => write!(f, "trailing"),
+            Self::Internal => write!(f, "internal"),
+        }
+    }
 }
 
 /// Overall audio quality verdict.
@@ -85,6 +112,8 @@ pub enum AudioVerdict {
     Pass,
     /// One or more checks failed
     Fail,
+    /// Levels are otherwise fine but the signal carries a DC offset
+    DcOffset,
     /// No audio found
     NoAudio,
 }
@@ -94,6 +123,7 @@ impl std::fmt::Display for AudioVerdict {
         match self {
             Self::Pass => write!(f, "PASS"),
             Self::Fail => write!(f, "FAIL"),
+            Self::DcOffset => write!(f, "DC OFFSET"),
             Self::NoAudio => write!(f, "NO AUDIO"),
         }
     }
@@ -114,6 +144,14 @@ pub struct AudioQualityConfig {
     pub min_silence_duration_secs: f64,
     /// Maximum acceptable silence percentage (default: 80.0)
     pub max_silence_pct: f64,
+    /// Maximum leading silence in seconds (default: None, unlimited)
+    pub max_leading_silence_secs: Option<f64>,
+    /// Maximum trailing silence in seconds (default: None, unlimited)
+    pub max_trailing_silence_secs: Option<f64>,
+    /// Maximum internal silence gap in seconds (default: 1.0)
+    pub max_internal_silence_secs: f64,
+    /// Maximum acceptable absolute DC offset, linear (default: 0.01, -40 dBFS)
+    pub max_dc_offset: f64,
 }
 
 impl Default for AudioQualityConfig {
@@ -125,6 +163,10 @@ impl Default for AudioQualityConfig {
             silence_threshold_dbfs: -60.0,
             min_silence_duration_secs: 0.5,
             max_silence_pct: 80.0,
+            max_leading_silence_secs: None,
+            max_trailing_silence_secs: None,
+            max_internal_silence_secs: 1.0,
+            max_dc_offset: 0.01,
         }
     }
 }
@@ -157,6 +199,44 @@ impl AudioQualityConfig {
         self.silence_threshold_dbfs = dbfs;
         self
     }
+
+    /// Set maximum leading silence.
+    #[must_use]
+    pub fn with_max_leading_silence_secs(mut self, secs: f64) -> Self {
+        self.max_leading_silence_secs = Some(secs);
+        self
+    }
+
+    /// Set maximum trailing silence.
+    #[must_use]
+    pub fn with_max_trailing_silence_secs(mut self, secs: f64) -> Self {
+        self.max_trailing_silence_secs = Some(secs);
+        self
+    }
+
+    /// Set maximum internal silence gap.
+    #[must_use]
+    pub fn with_max_internal_silence_secs(mut self, secs: f64) -> Self {
+        self.max_internal_silence_secs = secs;
+        self
+    }
+
+    /// Maximum allowed duration for a silence region of the given kind.
+    #[must_use]
+    pub fn max_silence_secs(&self, kind: SilenceKind) -> Option<f64> {
+        match kind {
+            SilenceKind::Leading => self.max_leading_silence_secs,
+            SilenceKind::Trailing => self.max_trailing_silence_secs,
+            SilenceKind::Internal => Some(self.max_internal_silence_secs),
+        }
+    }
+
+    /// Set maximum absolute DC offset (linear).
+    #[must_use]
+    pub fn with_max_dc_offset(mut self, offset: f64) -> Self {
+        self.max_dc_offset = offset;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -169,6 +249,7 @@ mod tests {
         assert_eq!(AudioVerdict::Pass.to_string(), "PASS");
         assert_eq!(AudioVerdict::Fail.to_string(), "FAIL");
         assert_eq!(AudioVerdict::NoAudio.to_string(), "NO AUDIO");
+        assert_eq!(AudioVerdict::DcOffset.to_string(), "DC OFFSET");
     }
 
     #[test]
@@ -184,6 +265,7 @@ mod tests {
         assert!((config.max_peak_dbfs - (-0.1)).abs() < f64::EPSILON);
         assert!(config.no_clipping);
         assert!((config.silence_threshold_dbfs - (-60.0)).abs() < f64::EPSILON);
+        assert!((config.max_dc_offset - 0.01).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -192,11 +274,13 @@ mod tests {
             .with_min_rms_dbfs(-30.0)
             .with_max_peak_dbfs(-1.0)
             .with_no_clipping(false)
-            .with_silence_threshold_dbfs(-50.0);
+            .with_silence_threshold_dbfs(-50.0)
+            .with_max_dc_offset(0.05);
         assert!((config.min_rms_dbfs - (-30.0)).abs() < f64::EPSILON);
         assert!((config.max_peak_dbfs - (-1.0)).abs() < f64::EPSILON);
         assert!(!config.no_clipping);
         assert!((config.silence_threshold_dbfs - (-50.0)).abs() < f64::EPSILON);
+        assert!((config.max_dc_offset - 0.05).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -205,8 +289,26 @@ mod tests {
             start_secs: 1.0,
             end_secs: 2.5,
             duration_secs: 1.5,
+            kind: SilenceKind::Internal,
         };
         assert!((region.duration_secs - 1.5).abs() < f64::EPSILON);
+        assert_eq!(region.kind.to_string(), "internal");
+    }
+
+    #[test]
+    fn test_config_silence_limits() {
+        let config = AudioQualityConfig::default();
+        assert_eq!(config.max_silence_secs(SilenceKind::Leading), None);
+        assert_eq!(config.max_silence_secs(SilenceKind::Trailing), None);
+        assert_eq!(config.max_silence_secs(SilenceKind::Internal), Some(1.0));
+
+        let config = config
+            .with_max_leading_silence_secs(3.0)
+            .with_max_trailing_silence_secs(4.0)
+            .with_max_internal_silence_secs(0.25);
+        assert_eq!(config.max_silence_secs(SilenceKind::Leading), Some(3.0));
+        assert_eq!(config.max_silence_secs(SilenceKind::Trailing), Some(4.0));
+        assert_eq!(config.max_silence_secs(SilenceKind::Internal), Some(0.25));
     }
 
     #[test]
@@ -216,6 +318,8 @@ mod tests {
             peak_dbfs: -0.45,
             rms: 0.3,
             rms_dbfs: -10.46,
+            dc_offset: 0.0,
+            dc_offset_dbfs: -120.0,
             dynamic_range_db: 50.0,
             passed: true,
         };
@@ -255,6 +359,8 @@ mod tests {
                 peak_dbfs: -6.0,
                 rms: 0.2,
                 rms_dbfs: -14.0,
+                dc_offset: 0.0,
+                dc_offset_dbfs: -120.0,
                 dynamic_range_db: 40.0,
                 passed: true,
             },
@@ -2,7 +2,8 @@
 //!
 //! For each declared tick in the EDL, finds the nearest detected onset
 //! within a search window and computes the delta. Aggregates results
-//! into an `AvSyncReport`.
+//! into an `AvSyncReport`, including a linear drift trend that separates
+//! accumulating clock skew from random jitter.
 
 use super::types::{
     AudioOnset, AvSyncReport, EditDecisionList, SegmentSyncResult, SyncVerdict, TickDelta,
@@ -11,6 +12,16 @@ use super::types::{
 /// Maximum search window in seconds for matching an onset to a declared tick.
 const MATCH_WINDOW_SECS: f64 = 0.5;
 
+/// Default drift slope (ms per second of video) above which a steady trend
+/// is reported as accumulating drift.
+pub const DEFAULT_MAX_DRIFT_SLOPE_MS_PER_SEC: f64 = 1.0;
+
+/// Minimum matched ticks before a drift trend is trusted.
+const MIN_DRIFT_SAMPLES: usize = 4;
+
+/// Minimum R² for a trend to count as accumulating rather than jitter.
+const MIN_DRIFT_R_SQUARED: f64 = 0.8;
+
 /// Compare EDL declarations against detected onsets.
 ///
 /// For each EDL tick, finds the nearest detected onset within 500ms.
@@ -19,6 +30,26 @@ pub fn compare_edl_to_onsets(
     edl: &EditDecisionList,
     onsets: &[AudioOnset],
     tolerance_ms: f64,
+) -> AvSyncReport {
+    compare_edl_to_onsets_with_drift(
+        edl,
+        onsets,
+        tolerance_ms,
+        DEFAULT_MAX_DRIFT_SLOPE_MS_PER_SEC,
+    )
+}
+
+/// Compare EDL declarations against detected onsets with a drift-slope limit.
+///
+/// Like [`compare_edl_to_onsets`], but when every tick is within tolerance
+/// and the deltas trend steadily (R² ≥ 0.8 over at least four ticks) with a
+/// slope above `max_drift_slope_ms_per_sec`, the verdict is
+/// `SyncVerdict::AccumulatingDrift`.
+pub fn compare_edl_to_onsets_with_drift(
+    edl: &EditDecisionList,
+    onsets: &[AudioOnset],
+    tolerance_ms: f64,
+    max_drift_slope_ms_per_sec: f64,
 ) -> AvSyncReport {
     if !edl.has_ticks() {
         return AvSyncReport {
@@ -30,6 +61,8 @@ pub fn compare_edl_to_onsets(
             coverage_pct: 0.0,
             max_delta_ms: 0.0,
             mean_delta_ms: 0.0,
+            drift_slope: None,
+            drift_r_squared: None,
         };
     }
 
@@ -42,7 +75,7 @@ pub fn compare_edl_to_onsets(
         .map(|decision| compare_segment(decision, onsets, tolerance_ms, &mut acc))
         .collect();
 
-    acc.into_report(edl.video_id.clone(), segments)
+    acc.into_report(edl.video_id.clone(), segments, max_drift_slope_ms_per_sec)
 }
 
 /// Accumulated statistics across all segments.
@@ -51,13 +84,16 @@ struct ComparisonAccumulator {
     total_ticks: usize,
     matched_ticks: usize,
     all_deltas: Vec<f64>,
+    timed_deltas: Vec<(f64, f64)>,
     max_delta_ms: f64,
 }
 
 impl ComparisonAccumulator {
-    fn record_match(&mut self, abs_delta: f64, passed: bool) {
+    fn record_match(&mut self, declared_secs: f64, delta_ms: f64, passed: bool) {
+        let abs_delta = delta_ms.abs();
         self.total_ticks += 1;
         self.all_deltas.push(abs_delta);
+        self.timed_deltas.push((declared_secs, delta_ms));
         if abs_delta > self.max_delta_ms {
             self.max_delta_ms = abs_delta;
         }
@@ -71,7 +107,12 @@ impl ComparisonAccumulator {
     }
 
     #[allow(clippy::cast_precision_loss)]
-    fn into_report(self, video_id: String, segments: Vec<SegmentSyncResult>) -> AvSyncReport {
+    fn into_report(
+        self,
+        video_id: String,
+        segments: Vec<SegmentSyncResult>,
+        max_drift_slope_ms_per_sec: f64,
+    ) -> AvSyncReport {
         let mean_delta_ms = if self.all_deltas.is_empty() {
             0.0
         } else {
@@ -84,10 +125,19 @@ impl ComparisonAccumulator {
             0.0
         };
 
-        let verdict = if self.matched_ticks == self.total_ticks {
-            SyncVerdict::Pass
-        } else {
+        let drift = fit_drift(&self.timed_deltas);
+        let accumulating = drift.is_some_and(|d| {
+            self.timed_deltas.len() >= MIN_DRIFT_SAMPLES
+                && d.r_squared >= MIN_DRIFT_R_SQUARED
+                && d.slope.abs() > max_drift_slope_ms_per_sec
+        });
+
+        let verdict = if self.matched_ticks != self.total_ticks {
             SyncVerdict::Fail
+        } else if accumulating {
+            SyncVerdict::AccumulatingDrift
+        } else {
+            SyncVerdict::Pass
         };
 
         AvSyncReport {
@@ -99,10 +149,48 @@ impl ComparisonAccumulator {
             coverage_pct,
             max_delta_ms: self.max_delta_ms,
             mean_delta_ms,
+            drift_slope: drift.map(|d| d.slope),
+            drift_r_squared: drift.map(|d| d.r_squared),
         }
     }
 }
 
+/// Least-squares fit of tick delta (ms) against declared time (s).
+#[derive(Clone, Copy)]
+struct DriftFit {
+    slope: f64,
+    r_squared: f64,
+}
+
+/// Fit a line through `(time_secs, delta_ms)` points.
+///
+/// Returns None with fewer than two points or when all points share the
+/// same time. A perfectly flat series has R² of 0.0 (no trend).
+#[allow(clippy::cast_precision_loss)]
+fn fit_drift(points: &[(f64, f64)]) -> Option<DriftFit> {
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+
+    let ss_xx: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    let ss_xy: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let ss_yy: f64 = points.iter().map(|p| (p.1 - mean_y).powi(2)).sum();
+    if ss_xx <= f64::EPSILON {
+        return None;
+    }
+
+    let slope = ss_xy / ss_xx;
+    let r_squared = if ss_yy <= f64::EPSILON {
+        0.0
+    } else {
+        (ss_xy * ss_xy) / (ss_xx * ss_yy)
+    };
+    Some(DriftFit { slope, r_squared })
+}
+
 /// Compare a single segment's ticks against detected onsets.
 fn compare_segment(
     decision: &super::types::EditDecision,
@@ -111,6 +199,7 @@ fn compare_segment(
     acc: &mut ComparisonAccumulator,
 ) -> SegmentSyncResult {
     let mut segment_all_passed = true;
+    let mut segment_deltas = Vec::new();
 
     let tick_deltas: Vec<TickDelta> = decision
         .ticks
@@ -120,9 +209,9 @@ fn compare_segment(
             let nearest = find_nearest_onset(onsets, declared, MATCH_WINDOW_SECS);
             let (actual_secs, delta_ms, passed) = if let Some(onset) = nearest {
                 let delta = (onset.time_secs - declared) * 1000.0;
-                let abs_delta = delta.abs();
-                let tick_passed = abs_delta <= tolerance_ms;
-                acc.record_match(abs_delta, tick_passed);
+                let tick_passed = delta.abs() <= tolerance_ms;
+                acc.record_match(declared, delta, tick_passed);
+                segment_deltas.push((declared, delta));
                 (Some(onset.time_secs), Some(delta), tick_passed)
             } else {
                 acc.record_miss();
@@ -146,6 +235,7 @@ fn compare_segment(
         segment: decision.segment.clone(),
         ticks: tick_deltas,
         all_passed: segment_all_passed,
+        drift_slope: fit_drift(&segment_deltas).map(|d| d.slope),
     }
 }
 
@@ -169,7 +259,7 @@ fn find_nearest_onset(
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
-    use crate::av_sync::types::{AudioTickPlacement, EditDecision};
+    use crate::av_sync::types::{AudioTickPlacement, EditDecision, OnsetMethod};
 
     fn make_edl(ticks: Vec<(usize, f64, f64)>) -> EditDecisionList {
         EditDecisionList {
@@ -200,6 +290,7 @@ mod tests {
                 time_secs: t,
                 energy_db: -20.0,
                 sample_index: (t * 48000.0) as usize,
+                method: OnsetMethod::Energy,
             })
             .collect()
     }
@@ -381,6 +472,69 @@ mod tests {
         assert_eq!(report.segments[0].ticks[0].segment, "P2-key_terms");
     }
 
+    #[test]
+    fn test_accumulating_drift_within_tolerance() {
+        // Deltas grow 3ms per second: every tick passes a 20ms tolerance,
+        // but audio is steadily falling behind video
+        let declared: Vec<f64> = (0..6).map(|i| 1.0 + f64::from(i)).collect();
+        let ticks = declared
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| (i, t, t))
+            .collect();
+        let edl = make_edl(ticks);
+        let actual: Vec<f64> = declared
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| t + 0.003 * i as f64)
+            .collect();
+        let onsets = make_onsets(&actual);
+        let report = compare_edl_to_onsets(&edl, &onsets, 20.0);
+
+        assert_eq!(report.matched_ticks, 6);
+        assert_eq!(report.verdict, SyncVerdict::AccumulatingDrift);
+        assert!(report.verdict.is_failure());
+        assert!((report.drift_slope.unwrap() - 3.0).abs() < 0.01);
+        assert!(report.drift_r_squared.unwrap() > 0.99);
+        assert!((report.segments[0].drift_slope.unwrap() - 3.0).abs() < 0.01);
+
+        // A looser slope limit accepts the same trend
+        let report = compare_edl_to_onsets_with_drift(&edl, &onsets, 20.0, 5.0);
+        assert_eq!(report.verdict, SyncVerdict::Pass);
+    }
+
+    #[test]
+    fn test_random_jitter_is_not_drift() {
+        let declared = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let ticks = declared
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| (i, t, t))
+            .collect();
+        let edl = make_edl(ticks);
+        let jitter = [0.008, -0.008, 0.006, -0.009, 0.007, -0.006];
+        let actual: Vec<f64> = declared.iter().zip(jitter).map(|(t, j)| t + j).collect();
+        let report = compare_edl_to_onsets(&edl, &make_onsets(&actual), 20.0);
+
+        assert_eq!(report.verdict, SyncVerdict::Pass);
+        assert!(report.drift_r_squared.unwrap() < MIN_DRIFT_R_SQUARED);
+    }
+
+    #[test]
+    fn test_constant_offset_has_no_drift() {
+        let edl = make_edl(vec![
+            (0, 1.0, 1.0),
+            (1, 2.0, 2.0),
+            (2, 3.0, 3.0),
+            (3, 4.0, 4.0),
+        ]);
+        let onsets = make_onsets(&[1.01, 2.01, 3.01, 4.01]);
+        let report = compare_edl_to_onsets(&edl, &onsets, 20.0);
+
+        assert_eq!(report.verdict, SyncVerdict::Pass);
+        assert!(report.drift_slope.unwrap().abs() < 1e-6);
+    }
+
     #[test]
     fn test_empty_decisions() {
         let edl = EditDecisionList {
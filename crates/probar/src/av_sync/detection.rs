@@ -1,9 +1,18 @@
-//! Audio onset detection via RMS energy analysis.
+//! Audio onset detection via RMS energy analysis or spectral flux.
 //!
 //! Detects percussive audio events (ticks/clicks) in PCM audio streams
-//! using sliding-window RMS energy thresholding.
+//! using sliding-window RMS energy thresholding. For sustained or noisy
+//! audio, where the signal never drops below the energy threshold between
+//! events, spectral flux detects onsets from frame-to-frame spectral change.
 
-use super::types::AudioOnset;
+use super::types::{AudioOnset, OnsetMethod};
+
+/// Frames on each side of a spectral-flux frame used for its adaptive threshold.
+const FLUX_NEIGHBORHOOD_FRAMES: usize = 25;
+
+/// Absolute spectral-flux floor, so near-silent or perfectly stationary
+/// audio does not produce onsets from numerical noise.
+const MIN_SPECTRAL_FLUX: f64 = 1.0;
 
 /// Configuration for onset detection.
 #[derive(Clone, Debug)]
@@ -18,6 +27,13 @@ pub struct DetectionConfig {
     pub min_gap_ms: f64,
     /// Look-back for onset refinement in milliseconds (default: 5ms)
     pub refine_lookback_ms: f64,
+    /// Onset detection algorithm (default: energy)
+    pub method: OnsetMethod,
+    /// STFT frame size in samples for spectral flux, a power of two (default: 1024)
+    pub fft_size: usize,
+    /// Spectral-flux peaks must exceed the local mean by this many local
+    /// standard deviations (default: 3.0)
+    pub flux_threshold: f64,
 }
 
 impl Default for DetectionConfig {
@@ -28,6 +44,9 @@ impl Default for DetectionConfig {
             threshold_db: -40.0,
             min_gap_ms: 200.0,
             refine_lookback_ms: 5.0,
+            method: OnsetMethod::Energy,
+            fft_size: 1024,
+            flux_threshold: 3.0,
         }
     }
 }
@@ -54,6 +73,13 @@ impl DetectionConfig {
         self
     }
 
+    /// Set the onset detection algorithm.
+    #[must_use]
+    pub fn with_method(mut self, method: OnsetMethod) -> Self {
+        self.method = method;
+        self
+    }
+
     /// Window size in samples.
     fn window_samples(&self) -> usize {
         ((self.window_ms / 1000.0) * f64::from(self.sample_rate)) as usize
@@ -89,9 +115,17 @@ fn rms_to_db(rms: f64) -> f64 {
 
 /// Detect audio onsets in PCM samples.
 ///
-/// Uses RMS energy windowing with threshold crossing detection.
-/// Returns onsets sorted by time.
+/// Dispatches on `config.method`: RMS energy windowing with threshold
+/// crossing detection, or spectral flux. Returns onsets sorted by time.
 pub fn detect_onsets(samples: &[f32], config: &DetectionConfig) -> Vec<AudioOnset> {
+    match config.method {
+        OnsetMethod::Energy => detect_energy_onsets(samples, config),
+        OnsetMethod::SpectralFlux => detect_spectral_flux_onsets(samples, config),
+    }
+}
+
+/// Energy onset detection: RMS crossing the threshold from below.
+fn detect_energy_onsets(samples: &[f32], config: &DetectionConfig) -> Vec<AudioOnset> {
     let window_size = config.window_samples();
     let min_gap = config.min_gap_samples();
     let lookback = config.lookback_samples();
@@ -140,6 +174,7 @@ pub fn detect_onsets(samples: &[f32], config: &DetectionConfig) -> Vec<AudioOnse
                     time_secs,
                     energy_db: db,
                     sample_index: onset_sample,
+                    method: OnsetMethod::Energy,
                 });
                 last_onset_sample = Some(onset_sample);
             }
@@ -154,6 +189,145 @@ pub fn detect_onsets(samples: &[f32], config: &DetectionConfig) -> Vec<AudioOnse
     onsets
 }
 
+/// Spectral-flux onset detection.
+///
+/// Computes a Hann-windowed STFT (hop = `window_ms`), sums the half-wave
+/// rectified increase in log magnitude per bin between consecutive frames,
+/// and picks local maxima that exceed the neighborhood mean by
+/// `flux_threshold` standard deviations.
+fn detect_spectral_flux_onsets(samples: &[f32], config: &DetectionConfig) -> Vec<AudioOnset> {
+    let frame_size = config.fft_size;
+    let hop = config.window_samples().max(1);
+    let min_gap = config.min_gap_samples();
+
+    if !frame_size.is_power_of_two() || frame_size < 2 || samples.len() < frame_size {
+        return Vec::new();
+    }
+
+    let window: Vec<f64> = (0..frame_size)
+        .map(|n| {
+            let phase = std::f64::consts::TAU * n as f64 / frame_size as f64;
+            0.5 - 0.5 * phase.cos()
+        })
+        .collect();
+
+    // Flux per frame; the first frame is compared against silence
+    let mut positions = Vec::new();
+    let mut flux = Vec::new();
+    let mut previous = vec![0.0; frame_size / 2];
+    let mut pos = 0;
+    while pos + frame_size <= samples.len() {
+        let spectrum = log_magnitude_spectrum(&samples[pos..pos + frame_size], &window);
+        let rise: f64 = spectrum
+            .iter()
+            .zip(&previous)
+            .map(|(current, prev)| (current - prev).max(0.0))
+            .sum();
+        positions.push(pos);
+        flux.push(rise);
+        previous = spectrum;
+        pos += hop;
+    }
+
+    let mut onsets = Vec::new();
+    let mut last_onset_sample: Option<usize> = None;
+    for (frame, &value) in flux.iter().enumerate() {
+        let lo = frame.saturating_sub(FLUX_NEIGHBORHOOD_FRAMES);
+        let hi = (frame + FLUX_NEIGHBORHOOD_FRAMES + 1).min(flux.len());
+        let local = &flux[lo..hi];
+        let mean = local.iter().sum::<f64>() / local.len() as f64;
+        let variance = local.iter().map(|f| (f - mean).powi(2)).sum::<f64>() / local.len() as f64;
+        let threshold = (mean + config.flux_threshold * variance.sqrt()).max(MIN_SPECTRAL_FLUX);
+
+        let is_peak = frame.checked_sub(1).map_or(true, |p| value > flux[p])
+            && flux.get(frame + 1).map_or(true, |&next| value >= next);
+        if !is_peak || value < threshold {
+            continue;
+        }
+
+        // New sound entered the tail of the frame; the first frame has no
+        // predecessor, so its onset is placed at the start of the signal
+        let onset_sample = if frame == 0 {
+            0
+        } else {
+            positions[frame] + frame_size - hop / 2
+        };
+
+        let gap_ok = match last_onset_sample {
+            Some(last) => onset_sample.saturating_sub(last) >= min_gap,
+            None => true,
+        };
+        if gap_ok {
+            let end = (onset_sample + hop).min(samples.len());
+            onsets.push(AudioOnset {
+                time_secs: onset_sample as f64 / f64::from(config.sample_rate),
+                energy_db: rms_to_db(rms_energy(&samples[onset_sample..end])),
+                sample_index: onset_sample,
+                method: OnsetMethod::SpectralFlux,
+            });
+            last_onset_sample = Some(onset_sample);
+        }
+    }
+
+    onsets
+}
+
+/// Log-compressed magnitude spectrum (`ln(1 + |X|)`) of a windowed frame,
+/// bins `0..n/2`.
+fn log_magnitude_spectrum(frame: &[f32], window: &[f64]) -> Vec<f64> {
+    let mut re: Vec<f64> = frame
+        .iter()
+        .zip(window)
+        .map(|(&s, &w)| f64::from(s) * w)
+        .collect();
+    let mut im = vec![0.0; frame.len()];
+    fft_in_place(&mut re, &mut im);
+    re.iter()
+        .zip(&im)
+        .take(frame.len() / 2)
+        .map(|(r, i)| r.hypot(*i).ln_1p())
+        .collect()
+}
+
+/// Iterative radix-2 Cooley-Tukey FFT. Length must be a power of two.
+fn fft_in_place(re: &mut [f64], im: &mut [f64]) {
+    let n = re.len();
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -std::f64::consts::TAU / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let a = start + k;
+                let b = a + len / 2;
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len <<= 1;
+    }
+}
+
 /// Refine onset position by looking back to find true start.
 fn refine_onset(
     samples: &[f32],
@@ -367,6 +541,83 @@ mod tests {
         assert_eq!(config.min_gap_samples(), 9600);
     }
 
+    /// A sustained tone throughout, with a second tone entering at `entry_secs`.
+    fn sustained_signal(sample_rate: u32, duration_secs: f64, entry_secs: f64) -> Vec<f32> {
+        let rate = f64::from(sample_rate);
+        let total_samples = (duration_secs * rate) as usize;
+        (0..total_samples)
+            .map(|i| {
+                let t = i as f64 / rate;
+                let bed = 0.3 * (std::f64::consts::TAU * 330.0 * t).sin();
+                let entry = if t >= entry_secs {
+                    0.3 * (std::f64::consts::TAU * 2500.0 * t).sin()
+                } else {
+                    0.0
+                };
+                (bed + entry) as f32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_spectral_flux_finds_onset_in_sustained_audio() {
+        let signal = sustained_signal(48000, 2.0, 1.0);
+
+        // Energy never falls below threshold after the bed starts, so the
+        // entering tone is missed
+        let energy = detect_onsets(&signal, &DetectionConfig::default());
+        assert_eq!(energy.len(), 1);
+        assert!(energy[0].time_secs < 0.015);
+        assert_eq!(energy[0].method, OnsetMethod::Energy);
+
+        let config = DetectionConfig::default().with_method(OnsetMethod::SpectralFlux);
+        let flux = detect_onsets(&signal, &config);
+        assert_eq!(flux.len(), 2, "onsets: {flux:?}");
+        assert!(flux[0].time_secs < 0.015);
+        assert!(
+            (flux[1].time_secs - 1.0).abs() < 0.015,
+            "onset at {:.3}s, expected ~1.0s",
+            flux[1].time_secs
+        );
+        assert!(flux.iter().all(|o| o.method == OnsetMethod::SpectralFlux));
+    }
+
+    #[test]
+    fn test_spectral_flux_detects_ticks() {
+        let config = DetectionConfig::default().with_method(OnsetMethod::SpectralFlux);
+        let signal = synthetic_signal(48000, 4.0, &[1.0, 2.0, 3.0]);
+        let onsets = detect_onsets(&signal, &config);
+        assert_eq!(onsets.len(), 3, "onsets: {onsets:?}");
+        for (onset, expected) in onsets.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((onset.time_secs - expected).abs() < 0.015);
+        }
+    }
+
+    #[test]
+    fn test_spectral_flux_silence_and_invalid_fft_size() {
+        let config = DetectionConfig::default().with_method(OnsetMethod::SpectralFlux);
+        let silence = vec![0.0f32; 48000];
+        assert!(detect_onsets(&silence, &config).is_empty());
+
+        let mut config = config;
+        config.fft_size = 1000;
+        let signal = synthetic_signal(48000, 2.0, &[1.0]);
+        assert!(detect_onsets(&signal, &config).is_empty());
+    }
+
+    #[test]
+    fn test_fft_single_bin() {
+        let n = 64;
+        let mut re: Vec<f64> = (0..n)
+            .map(|i| (std::f64::consts::TAU * 4.0 * i as f64 / n as f64).cos())
+            .collect();
+        let mut im = vec![0.0; n];
+        fft_in_place(&mut re, &mut im);
+        let magnitude = |k: usize| re[k].hypot(im[k]);
+        assert!((magnitude(4) - 32.0).abs() < 1e-9);
+        assert!(magnitude(5) < 1e-9);
+    }
+
     #[test]
     fn test_onset_ordering() {
         let config = DetectionConfig::default();
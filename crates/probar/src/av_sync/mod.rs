@@ -20,10 +20,12 @@ pub mod detection;
 pub mod extraction;
 pub mod types;
 
-pub use comparison::compare_edl_to_onsets;
+pub use comparison::{
+    compare_edl_to_onsets, compare_edl_to_onsets_with_drift, DEFAULT_MAX_DRIFT_SLOPE_MS_PER_SEC,
+};
 pub use detection::{detect_onsets, DetectionConfig};
 pub use extraction::{build_ffmpeg_args, default_edl_path, extract_audio, DEFAULT_SAMPLE_RATE};
 pub use types::{
-    AudioOnset, AudioTickPlacement, AvSyncReport, EditDecision, EditDecisionList,
+    AudioOnset, AudioTickPlacement, AvSyncReport, EditDecision, EditDecisionList, OnsetMethod,
     SegmentSyncResult, SyncVerdict, TickDelta,
 };
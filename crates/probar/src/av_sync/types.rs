@@ -67,6 +67,18 @@ pub struct AudioOnset {
     pub energy_db: f64,
     /// Sample index in the PCM stream
     pub sample_index: usize,
+    /// Detection method that produced this onset
+    pub method: OnsetMethod,
+}
+
+/// Onset detection algorithm.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OnsetMethod {
+    /// RMS energy threshold crossing (rising edge from silence)
+    #[default]
+    Energy,
+    /// Half-wave rectified STFT magnitude difference with adaptive peak picking
+    SpectralFlux,
 }
 
 /// Result of comparing EDL declarations against actual audio.
@@ -88,6 +100,12 @@ pub struct AvSyncReport {
     pub max_delta_ms: f64,
     /// Mean absolute delta in milliseconds
     pub mean_delta_ms: f64,
+    /// Linear trend of tick delta over video time (ms of drift per second),
+    /// None if fewer than two ticks were matched
+    pub drift_slope: Option<f64>,
+    /// Goodness of fit (R²) of the drift trend: near 1.0 for steadily
+    /// accumulating drift, near 0.0 for random jitter
+    pub drift_r_squared: Option<f64>,
 }
 
 /// Per-segment sync verification results.
@@ -99,6 +117,9 @@ pub struct SegmentSyncResult {
     pub ticks: Vec<TickDelta>,
     /// Whether all ticks in this segment passed
     pub all_passed: bool,
+    /// Linear trend of tick delta over segment time (ms of drift per second),
+    /// None if fewer than two ticks were matched
+    pub drift_slope: Option<f64>,
 }
 
 /// Delta between declared and actual tick timing.
@@ -127,6 +148,17 @@ pub enum SyncVerdict {
     Fail,
     /// No ticks found in EDL
     NoTicks,
+    /// Every tick is within tolerance, but the deltas trend steadily in one
+    /// direction (clock skew) and will eventually exceed it
+    AccumulatingDrift,
+}
+
+impl SyncVerdict {
+    /// Whether this verdict should fail a sync check.
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Fail | Self::AccumulatingDrift)
+    }
 }
 
 impl std::fmt::Display for SyncVerdict {
@@ -135,6 +167,7 @@ impl std::fmt::Display for SyncVerdict {
             Self::Pass => write!(f, "PASS"),
             Self::Fail => write!(f, "FAIL"),
             Self::NoTicks => write!(f, "NO TICKS"),
+            Self::AccumulatingDrift => write!(f, "ACCUMULATING DRIFT"),
         }
     }
 }
@@ -252,6 +285,18 @@ mod tests {
         assert_eq!(SyncVerdict::Pass.to_string(), "PASS");
         assert_eq!(SyncVerdict::Fail.to_string(), "FAIL");
         assert_eq!(SyncVerdict::NoTicks.to_string(), "NO TICKS");
+        assert_eq!(
+            SyncVerdict::AccumulatingDrift.to_string(),
+            "ACCUMULATING DRIFT"
+        );
+    }
+
+    #[test]
+    fn test_sync_verdict_is_failure() {
+        assert!(!SyncVerdict::Pass.is_failure());
+        assert!(!SyncVerdict::NoTicks.is_failure());
+        assert!(SyncVerdict::Fail.is_failure());
+        assert!(SyncVerdict::AccumulatingDrift.is_failure());
     }
 
     #[test]
@@ -266,6 +311,7 @@ mod tests {
             time_secs: 1.5,
             energy_db: -20.0,
             sample_index: 72000,
+            method: OnsetMethod::Energy,
         };
         assert!((onset.time_secs - 1.5).abs() < f64::EPSILON);
         assert_eq!(onset.sample_index, 72000);
@@ -310,6 +356,8 @@ mod tests {
             coverage_pct: 100.0,
             max_delta_ms: 5.0,
             mean_delta_ms: 3.0,
+            drift_slope: Some(0.0),
+            drift_r_squared: Some(0.0),
         };
         let json = serde_json::to_string(&report).unwrap();
         assert!(json.contains("\"verdict\":\"Pass\""));
@@ -322,6 +370,7 @@ mod tests {
             segment: "P2-key_terms".to_string(),
             ticks: vec![],
             all_passed: true,
+            drift_slope: None,
         };
         assert!(result.all_passed);
         assert_eq!(result.segment, "P2-key_terms");
@@ -97,6 +97,35 @@ pub struct RingBufferConfig {
     pub use_atomics: bool,
 }
 
+/// What a playback processor outputs when the ring buffer runs dry
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnderrunPolicy {
+    /// Fill the missing samples with zeros
+    #[default]
+    Silence,
+    /// Repeat the samples from the previous block
+    Repeat,
+    /// Ramp linearly from the last sample down to zero (avoids clicks)
+    Interpolate,
+}
+
+impl UnderrunPolicy {
+    /// Generate the JS that fills `out[got..]` for this policy
+    fn to_fill_js(self, pad: &str) -> String {
+        match self {
+            Self::Silence => format!("{pad}out.fill(0, got);\n"),
+            Self::Repeat => format!(
+                "{pad}for (let i = got; i < out.length; i++) out[i] = this.lastBlock[i] || 0;\n"
+            ),
+            Self::Interpolate => format!(
+                "{pad}const from = got > 0 ? out[got - 1] : this.lastSample;\n\
+                 {pad}const span = out.length - got;\n\
+                 {pad}for (let i = got; i < out.length; i++) out[i] = from * (1 - (i - got + 1) / span);\n"
+            ),
+        }
+    }
+}
+
 impl Default for RingBufferConfig {
     fn default() -> Self {
         Self {
@@ -147,6 +176,8 @@ pub struct AudioBrick {
     ring_buffer: Option<RingBufferConfig>,
     /// Sample rate (for calculations)
     sample_rate: u32,
+    /// Fallback when a playback processor finds the buffer short
+    underrun_policy: UnderrunPolicy,
 }
 
 impl AudioBrick {
@@ -160,6 +191,7 @@ impl AudioBrick {
             params: Vec::new(),
             ring_buffer: None,
             sample_rate: 48000,
+            underrun_policy: UnderrunPolicy::Silence,
         }
     }
 
@@ -198,6 +230,22 @@ impl AudioBrick {
         self
     }
 
+    /// Set the underrun fallback for playback processors
+    #[must_use]
+    pub fn underrun_policy(mut self, policy: UnderrunPolicy) -> Self {
+        self.underrun_policy = policy;
+        self
+    }
+
+    /// Whether this processor plays back from the ring buffer
+    ///
+    /// A brick with no inputs, at least one output, and a ring buffer reads
+    /// the buffer into its output instead of capturing into it.
+    #[must_use]
+    pub fn is_playback(&self) -> bool {
+        self.inputs == 0 && self.outputs > 0 && self.ring_buffer.is_some()
+    }
+
     /// Get the processor class name
     #[must_use]
     pub fn class_name(&self) -> String {
@@ -267,10 +315,27 @@ impl AudioBrick {
             js.push_str("        };\n");
         }
 
+        if self.is_playback() {
+            js.push_str("        this.underruns = 0;\n");
+            js.push_str("        this.starved = false;\n");
+            js.push_str("        this.lastBlock = new Float32Array(128);\n");
+            js.push_str("        this.lastSample = 0;\n");
+        }
+
         js.push_str("    }\n\n");
 
         // Process method
         js.push_str("    process(inputs, outputs, parameters) {\n");
+        if self.is_playback() {
+            js.push_str(&Self::generate_playback_body(self.underrun_policy));
+            js.push_str("    }\n");
+            js.push_str("}\n\n");
+            js.push_str(&format!(
+                "registerProcessor('{}', {});\n",
+                self.name, class_name
+            ));
+            return js;
+        }
         js.push_str("        const input = inputs[0];\n");
         js.push_str("        if (!input || !input[0]) return true;\n\n");
 
@@ -305,6 +370,36 @@ impl AudioBrick {
         js
     }
 
+    /// Generate the `process` body for a playback processor
+    fn generate_playback_body(policy: UnderrunPolicy) -> String {
+        let mut js = String::new();
+        js.push_str("        const output = outputs[0];\n");
+        js.push_str("        const out = output[0];\n");
+        js.push_str("        const got = this.ringBuffer ? this.ringBuffer.read(out) : 0;\n\n");
+        js.push_str("        // Underrun: buffer had fewer samples than the block needs\n");
+        js.push_str("        if (got < out.length) {\n");
+        js.push_str(&format!("            // Underrun policy: {policy:?}\n"));
+        js.push_str(&policy.to_fill_js("            "));
+        js.push_str("            // Report once per starvation episode, not every quantum\n");
+        js.push_str("            if (!this.starved) {\n");
+        js.push_str("                this.starved = true;\n");
+        js.push_str("                this.underruns++;\n");
+        js.push_str(
+            "                this.port.postMessage({ type: 'underrun', count: this.underruns });\n",
+        );
+        js.push_str("            }\n");
+        js.push_str("        } else {\n");
+        js.push_str("            this.starved = false;\n");
+        js.push_str("        }\n\n");
+        js.push_str("        this.lastBlock.set(out.subarray(0, this.lastBlock.length));\n");
+        js.push_str("        this.lastSample = out[out.length - 1];\n");
+        js.push_str("        for (let channel = 1; channel < output.length; channel++) {\n");
+        js.push_str("            output[channel].set(out);\n");
+        js.push_str("        }\n\n");
+        js.push_str("        return true; // Keep processor alive\n");
+        js
+    }
+
     /// Generate ring buffer class for SharedArrayBuffer
     fn generate_ring_buffer_class(&self, config: &RingBufferConfig) -> String {
         format!(
@@ -397,10 +492,23 @@ class RingBuffer {{
             );
         }
 
+        // Track underruns reported by a playback worklet
+        if self.is_playback() {
+            js.push_str("    const underruns = { count: 0 };\n");
+            js.push_str("    workletNode.port.onmessage = (e) => {\n");
+            js.push_str(
+                "        if (e.data.type === 'underrun') underruns.count = e.data.count;\n",
+            );
+            js.push_str("    };\n");
+        }
+
         js.push_str("\n    return { audioContext, workletNode");
         if self.ring_buffer.is_some() {
             js.push_str(", ringBufferSab");
         }
+        if self.is_playback() {
+            js.push_str(", underruns");
+        }
         js.push_str(" };\n");
         js.push_str("}\n");
 
@@ -1133,4 +1241,71 @@ mod tests {
         assert!(js.contains("class MinimalProcessor"));
         assert!(js.contains("registerProcessor('minimal'"));
     }
+
+    // ============================================================
+    // Underrun policy tests
+    // ============================================================
+
+    fn playback(policy: UnderrunPolicy) -> AudioBrick {
+        AudioBrick::new("tts-playback")
+            .inputs(0)
+            .with_ring_buffer(RingBufferConfig::new(48000))
+            .underrun_policy(policy)
+    }
+
+    #[test]
+    fn test_default_underrun_policy() {
+        let default = AudioBrick::new("tts-playback")
+            .inputs(0)
+            .with_ring_buffer(RingBufferConfig::new(48000))
+            .to_worklet_js();
+        assert_eq!(default, playback(UnderrunPolicy::Silence).to_worklet_js());
+    }
+
+    #[test]
+    fn test_playback_worklet_handles_underrun() {
+        let brick = playback(UnderrunPolicy::Silence);
+        assert!(brick.is_playback());
+
+        let js = brick.to_worklet_js();
+        assert!(js.contains("this.underruns = 0;"));
+        assert!(js.contains("this.ringBuffer.read(out)"));
+        assert!(js.contains("if (got < out.length) {"));
+        assert!(js.contains("this.underruns++;"));
+        assert!(js.contains("type: 'underrun', count: this.underruns"));
+        // Posted only when starvation starts; cleared on a full block
+        assert!(js.contains("if (!this.starved) {"));
+        assert!(js.contains("this.starved = false;"));
+        // Playback processors don't require an input
+        assert!(!js.contains("inputs[0]"));
+    }
+
+    #[test]
+    fn test_underrun_policy_output_differs() {
+        let silence = playback(UnderrunPolicy::Silence).to_worklet_js();
+        let repeat = playback(UnderrunPolicy::Repeat).to_worklet_js();
+        let interpolate = playback(UnderrunPolicy::Interpolate).to_worklet_js();
+
+        assert!(silence.contains("out.fill(0, got);"));
+        assert!(repeat.contains("out[i] = this.lastBlock[i]"));
+        assert!(interpolate.contains("from * (1 - (i - got + 1) / span)"));
+
+        assert!(!repeat.contains("out.fill(0, got)"));
+        assert!(!interpolate.contains("this.lastBlock[i]"));
+        assert_ne!(silence, repeat);
+        assert_ne!(repeat, interpolate);
+    }
+
+    #[test]
+    fn test_playback_init_exposes_underrun_counter() {
+        let js = playback(UnderrunPolicy::Repeat).to_audio_init_js();
+        assert!(js.contains("const underruns = { count: 0 };"));
+        assert!(js.contains("underruns.count = e.data.count"));
+        assert!(js.contains(", underruns };"));
+
+        let capture = AudioBrick::new("capture").with_ring_buffer(RingBufferConfig::default());
+        assert!(!capture.is_playback());
+        assert!(!capture.to_audio_init_js().contains("underruns"));
+        assert!(!capture.to_worklet_js().contains("underruns"));
+    }
 }
@@ -33,7 +33,8 @@
 //! let wgsl = mel_brick.to_wgsl();
 //! ```
 
-use super::{Brick, BrickAssertion, BrickBudget, BrickVerification};
+use super::{Brick, BrickAssertion, BrickBudget, BrickError, BrickResult, BrickVerification};
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Tensor element type for GPU compute
@@ -284,6 +285,17 @@ pub enum TileOp {
         /// Output scalar or reduced tensor
         output: String,
     },
+    /// Reduction along a single axis
+    ReduceAxis {
+        /// Reduction type
+        kind: ReduceKind,
+        /// Input tensor
+        input: String,
+        /// Output reduced tensor
+        output: String,
+        /// Axis to reduce along
+        axis: usize,
+    },
 }
 
 /// Reduction operation type
@@ -511,6 +523,17 @@ impl ComputeBrick {
                         input, output, kind
                     ));
                 }
+                TileOp::ReduceAxis {
+                    kind,
+                    input,
+                    output,
+                    axis,
+                } => {
+                    wgsl.push_str(&format!(
+                        "    // Reduce {} -> {} ({:?}, axis {})\n",
+                        input, output, kind, axis
+                    ));
+                }
             }
         }
 
@@ -662,6 +685,161 @@ impl ComputeBrick {
     pub fn outputs(&self) -> &[TensorBinding] {
         &self.outputs
     }
+
+    /// Validate tensor shapes across all operations
+    ///
+    /// Elementwise operands must be broadcast-compatible (NumPy rules:
+    /// trailing-aligned, each extent equal or 1), and the broadcast result
+    /// must fit the output binding without growing it. Reductions must name
+    /// an axis within the input's rank. Unbound output names become
+    /// intermediates whose inferred shape is used by later operations;
+    /// names with no known shape are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrickError::ShapeMismatch`] or [`BrickError::InvalidAxis`]
+    /// for the first incompatible operation.
+    pub fn validate_shapes(&self) -> BrickResult<()> {
+        let mut shapes: HashMap<String, Vec<u32>> = self
+            .inputs
+            .iter()
+            .chain(&self.outputs)
+            .map(|t| (t.name.clone(), t.shape.clone()))
+            .chain(
+                self.shared_memory
+                    .iter()
+                    .map(|(name, _, size)| (name.clone(), vec![*size])),
+            )
+            .collect();
+
+        for op in &self.operations {
+            match op {
+                TileOp::Elementwise {
+                    op: elem_op,
+                    operands,
+                    output,
+                } => {
+                    let op_name = format!("{elem_op:?}");
+                    let mut result: Option<(String, Vec<u32>)> = None;
+                    for name in operands {
+                        let Some(shape) = shapes.get(name) else {
+                            continue;
+                        };
+                        result = Some(match result {
+                            None => (name.clone(), shape.clone()),
+                            Some((lhs, lhs_shape)) => {
+                                let merged =
+                                    broadcast_shapes(&op_name, (&lhs, &lhs_shape), (name, shape))?;
+                                (lhs, merged)
+                            }
+                        });
+                    }
+
+                    let Some((lhs, result_shape)) = result else {
+                        continue;
+                    };
+                    let out_name = output.as_ref().unwrap_or(&operands[0]);
+                    match shapes.get(out_name) {
+                        Some(out_shape) => {
+                            let merged = broadcast_shapes(
+                                &op_name,
+                                (&lhs, &result_shape),
+                                (out_name, out_shape),
+                            )?;
+                            if &merged != out_shape {
+                                let (axis, lhs_extent, rhs_extent) =
+                                    first_differing_axis(&merged, out_shape, &result_shape);
+                                return Err(BrickError::ShapeMismatch {
+                                    op: op_name,
+                                    lhs,
+                                    rhs: out_name.clone(),
+                                    axis,
+                                    lhs_extent,
+                                    rhs_extent,
+                                });
+                            }
+                        }
+                        None => {
+                            shapes.insert(out_name.clone(), result_shape);
+                        }
+                    }
+                }
+                TileOp::Reduce { input, output, .. } if shapes.contains_key(input) => {
+                    shapes.entry(output.clone()).or_default();
+                }
+                TileOp::ReduceAxis {
+                    kind,
+                    input,
+                    output,
+                    axis,
+                } => {
+                    let Some(shape) = shapes.get(input) else {
+                        continue;
+                    };
+                    if *axis >= shape.len() {
+                        return Err(BrickError::InvalidAxis {
+                            op: format!("Reduce({kind:?})"),
+                            tensor: input.clone(),
+                            axis: *axis,
+                            rank: shape.len(),
+                        });
+                    }
+                    let mut reduced = shape.clone();
+                    reduced.remove(*axis);
+                    shapes.entry(output.clone()).or_insert(reduced);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Broadcast two shapes (NumPy rules), naming the offending axis on failure
+fn broadcast_shapes(
+    op: &str,
+    (lhs, lhs_shape): (&str, &[u32]),
+    (rhs, rhs_shape): (&str, &[u32]),
+) -> BrickResult<Vec<u32>> {
+    let rank = lhs_shape.len().max(rhs_shape.len());
+    let extent = |shape: &[u32], axis: usize| {
+        (axis + shape.len())
+            .checked_sub(rank)
+            .map_or(1, |i| shape[i])
+    };
+
+    (0..rank)
+        .map(|axis| {
+            let (l, r) = (extent(lhs_shape, axis), extent(rhs_shape, axis));
+            match (l, r) {
+                _ if l == r => Ok(l),
+                (1, _) => Ok(r),
+                (_, 1) => Ok(l),
+                _ => Err(BrickError::ShapeMismatch {
+                    op: op.to_string(),
+                    lhs: lhs.to_string(),
+                    rhs: rhs.to_string(),
+                    axis,
+                    lhs_extent: l,
+                    rhs_extent: r,
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Find where a broadcast grew `target`, returning `(axis, source extent, target extent)`
+fn first_differing_axis(merged: &[u32], target: &[u32], source: &[u32]) -> (usize, u32, u32) {
+    let offset = merged.len() - target.len();
+    let source_offset = merged.len() - source.len();
+    (0..merged.len())
+        .find(|&axis| axis < offset || merged[axis] != target[axis - offset])
+        .map_or((0, 0, 0), |axis| {
+            let source_extent = axis.checked_sub(source_offset).map_or(1, |i| source[i]);
+            let target_extent = axis.checked_sub(offset).map_or(1, |i| target[i]);
+            (axis, source_extent, target_extent)
+        })
 }
 
 impl Brick for ComputeBrick {
@@ -772,6 +950,20 @@ impl Brick for ComputeBrick {
             }
         }
 
+        match self.validate_shapes() {
+            Ok(()) => passed.push(BrickAssertion::Custom {
+                name: "shapes_compatible".into(),
+                validator_id: 6,
+            }),
+            Err(e) => failed.push((
+                BrickAssertion::Custom {
+                    name: "shapes_compatible".into(),
+                    validator_id: 6,
+                },
+                e.to_string(),
+            )),
+        }
+
         if failed.is_empty() {
             passed.push(BrickAssertion::Custom {
                 name: "compute_brick_valid".into(),
@@ -1417,4 +1609,137 @@ mod tests {
         assert_eq!(binding.element_count(), 1); // Product of empty vec is 1
         assert_eq!(binding.byte_size(), 4);
     }
+
+    mod shape_tests {
+        use super::*;
+
+        fn elementwise(operands: &[&str], output: &str) -> TileOp {
+            TileOp::Elementwise {
+                op: ElementwiseOp::Relu,
+                operands: operands.iter().map(|&s| s.into()).collect(),
+                output: Some(output.into()),
+            }
+        }
+
+        #[test]
+        fn test_valid_broadcast() {
+            let brick = ComputeBrick::new("bias-add")
+                .input("x", TensorType::F32, &[8, 4, 16])
+                .input("bias", TensorType::F32, &[16])
+                .input("scale", TensorType::F32, &[4, 1])
+                .output("y", TensorType::F32, &[8, 4, 16])
+                .op(elementwise(&["x", "bias", "scale"], "y"));
+
+            assert!(brick.validate_shapes().is_ok());
+            assert!(brick.verify().is_valid());
+        }
+
+        #[test]
+        fn test_incompatible_operands() {
+            let brick = ComputeBrick::new("bad-add")
+                .input("a", TensorType::F32, &[4, 3])
+                .input("b", TensorType::F32, &[4, 5])
+                .output("c", TensorType::F32, &[4, 5])
+                .op(elementwise(&["a", "b"], "c"));
+
+            let err = brick.validate_shapes().unwrap_err();
+            match &err {
+                BrickError::ShapeMismatch {
+                    lhs,
+                    rhs,
+                    axis,
+                    lhs_extent,
+                    rhs_extent,
+                    ..
+                } => {
+                    assert_eq!((lhs.as_str(), rhs.as_str()), ("a", "b"));
+                    assert_eq!((*axis, *lhs_extent, *rhs_extent), (1, 3, 5));
+                }
+                other => panic!("Expected ShapeMismatch, got {other:?}"),
+            }
+            assert!(err.to_string().contains("axis 1"));
+
+            let verification = brick.verify();
+            assert!(!verification.is_valid());
+            assert!(verification
+                .failed
+                .iter()
+                .any(|(_, reason)| reason.contains("Shape mismatch")));
+        }
+
+        #[test]
+        fn test_broadcast_must_not_grow_output() {
+            let brick = ComputeBrick::new("grow")
+                .input("a", TensorType::F32, &[2, 8])
+                .output("out", TensorType::F32, &[8])
+                .op(elementwise(&["a"], "out"));
+
+            let err = brick.validate_shapes().unwrap_err();
+            assert!(matches!(
+                err,
+                BrickError::ShapeMismatch {
+                    axis: 0,
+                    lhs_extent: 2,
+                    rhs_extent: 1,
+                    ..
+                }
+            ));
+        }
+
+        #[test]
+        fn test_intermediate_shape_is_inferred() {
+            let brick = ComputeBrick::new("chain")
+                .input("a", TensorType::F32, &[4, 4])
+                .input("b", TensorType::F32, &[3])
+                .output("out", TensorType::F32, &[4, 4])
+                .op(elementwise(&["a"], "tmp"))
+                .op(elementwise(&["tmp", "b"], "out"));
+
+            assert!(matches!(
+                brick.validate_shapes(),
+                Err(BrickError::ShapeMismatch { ref lhs, ref rhs, .. }) if lhs == "tmp" && rhs == "b"
+            ));
+        }
+
+        #[test]
+        fn test_reduce_axis_out_of_range() {
+            let brick = ComputeBrick::new("reduce")
+                .input("values", TensorType::F32, &[16, 32])
+                .output("result", TensorType::F32, &[16])
+                .op(TileOp::ReduceAxis {
+                    kind: ReduceKind::Sum,
+                    input: "values".into(),
+                    output: "result".into(),
+                    axis: 2,
+                });
+
+            let err = brick.validate_shapes().unwrap_err();
+            assert!(matches!(
+                err,
+                BrickError::InvalidAxis {
+                    axis: 2,
+                    rank: 2,
+                    ..
+                }
+            ));
+            assert!(err.to_string().contains("rank 2"));
+        }
+
+        #[test]
+        fn test_reduce_axis_in_range() {
+            let brick = ComputeBrick::new("reduce")
+                .input("values", TensorType::F32, &[16, 32])
+                .output("result", TensorType::F32, &[16])
+                .op(TileOp::ReduceAxis {
+                    kind: ReduceKind::Max,
+                    input: "values".into(),
+                    output: "row_max".into(),
+                    axis: 1,
+                })
+                .op(elementwise(&["row_max"], "result"));
+
+            assert!(brick.validate_shapes().is_ok());
+            assert!(brick.to_wgsl().contains("(Max, axis 1)"));
+        }
+    }
 }
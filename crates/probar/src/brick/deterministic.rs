@@ -33,7 +33,8 @@
 //! ```
 
 use super::{Brick, BrickError};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, PoisonError};
 use std::time::Duration;
 
 /// Trait for bricks with deterministic, pure functional execution
@@ -136,6 +137,62 @@ impl BrickState {
     pub fn get_metadata(&self, key: &str) -> Option<&StateValue> {
         self.metadata.get(key)
     }
+
+    /// First tensor, shape, or metadata difference from another state
+    ///
+    /// Keys are compared in sorted order; tensors compare bitwise so that
+    /// NaN payloads replay as equal. `version` is ignored.
+    fn first_divergence(&self, replayed: &Self) -> Option<StateDivergence> {
+        let tensors_equal = |a: &[f32], b: &[f32]| {
+            a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.to_bits() == y.to_bits())
+        };
+
+        let names: BTreeSet<&String> = self.tensors.keys().chain(replayed.tensors.keys()).collect();
+        for name in names {
+            let (a, b) = (self.tensors.get(name), replayed.tensors.get(name));
+            let equal = match (a, b) {
+                (Some(a), Some(b)) => tensors_equal(a, b),
+                _ => false,
+            };
+            if !equal {
+                return Some(StateDivergence::Tensor {
+                    name: name.clone(),
+                    recorded: a.cloned(),
+                    replayed: b.cloned(),
+                });
+            }
+        }
+
+        let names: BTreeSet<&String> = self.shapes.keys().chain(replayed.shapes.keys()).collect();
+        for name in names {
+            let (a, b) = (self.shapes.get(name), replayed.shapes.get(name));
+            if a != b {
+                return Some(StateDivergence::Shape {
+                    name: name.clone(),
+                    recorded: a.cloned(),
+                    replayed: b.cloned(),
+                });
+            }
+        }
+
+        let keys: BTreeSet<&String> = self
+            .metadata
+            .keys()
+            .chain(replayed.metadata.keys())
+            .collect();
+        for key in keys {
+            let (a, b) = (self.metadata.get(key), replayed.metadata.get(key));
+            if a != b {
+                return Some(StateDivergence::Metadata {
+                    key: key.clone(),
+                    recorded: a.cloned(),
+                    replayed: b.cloned(),
+                });
+            }
+        }
+
+        None
+    }
 }
 
 impl Default for BrickState {
@@ -172,6 +229,8 @@ pub struct BrickHistory {
     position: usize,
     /// Maximum history size
     max_size: usize,
+    /// Steps evicted from the front to respect `max_size`
+    evicted: usize,
 }
 
 impl BrickHistory {
@@ -183,6 +242,7 @@ impl BrickHistory {
             traces: Vec::with_capacity(max_size),
             position: 0,
             max_size,
+            evicted: 0,
         }
     }
 
@@ -198,6 +258,7 @@ impl BrickHistory {
         if self.snapshots.len() >= self.max_size {
             self.snapshots.remove(0);
             self.traces.remove(0);
+            self.evicted += 1;
         }
 
         self.snapshots.push(state);
@@ -272,8 +333,170 @@ impl BrickHistory {
     pub fn traces(&self) -> &[ExecutionTrace] {
         &self.traces
     }
+
+    /// Number of oldest steps evicted to respect the maximum history size
+    #[must_use]
+    pub fn evicted(&self) -> usize {
+        self.evicted
+    }
+
+    /// Replay inputs through `B::execute_pure` and compare against recorded snapshots
+    ///
+    /// Replay starts from `B::initial_state()` with every input since
+    /// recording began. The state after input `i` is compared with the
+    /// snapshot recorded for step `i` (ignoring `version`); steps evicted to
+    /// respect the maximum history size are replayed but not compared.
+    ///
+    /// # Errors
+    ///
+    /// Returns a report for the first step whose replayed state diverged,
+    /// whose execution failed, or if the input count differs from the
+    /// number of recorded snapshots.
+    pub fn verify_determinism<B>(
+        &self,
+        inputs: impl IntoIterator<Item = B::Input>,
+    ) -> Result<(), NonDeterminismReport>
+    where
+        B: DeterministicBrick,
+        BrickState: From<B::State>,
+    {
+        let recorded_steps = self.evicted + self.snapshots.len();
+        let mut state = B::initial_state();
+        let mut steps = 0;
+
+        for (step, input) in inputs.into_iter().enumerate() {
+            if step >= recorded_steps {
+                return Err(NonDeterminismReport {
+                    step,
+                    divergence: StateDivergence::LengthMismatch {
+                        recorded: recorded_steps,
+                        replayed: step + 1,
+                    },
+                });
+            }
+
+            let (next, _) = B::execute_pure(state, input).map_err(|e| NonDeterminismReport {
+                step,
+                divergence: StateDivergence::ExecutionFailed(e.to_string()),
+            })?;
+
+            if let Some(recorded) = step
+                .checked_sub(self.evicted)
+                .and_then(|i| self.snapshots.get(i))
+            {
+                if let Some(divergence) = recorded.first_divergence(&BrickState::from(next.clone()))
+                {
+                    return Err(NonDeterminismReport { step, divergence });
+                }
+            }
+
+            state = next;
+            steps = step + 1;
+        }
+
+        if steps == recorded_steps {
+            Ok(())
+        } else {
+            Err(NonDeterminismReport {
+                step: steps,
+                divergence: StateDivergence::LengthMismatch {
+                    recorded: recorded_steps,
+                    replayed: steps,
+                },
+            })
+        }
+    }
 }
 
+/// How a replayed state differed from its recorded snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateDivergence {
+    /// Tensor data differs (or is missing on one side)
+    Tensor {
+        /// Tensor name
+        name: String,
+        /// Recorded tensor data
+        recorded: Option<Vec<f32>>,
+        /// Replayed tensor data
+        replayed: Option<Vec<f32>>,
+    },
+    /// Tensor shape differs (or is missing on one side)
+    Shape {
+        /// Tensor name
+        name: String,
+        /// Recorded shape
+        recorded: Option<Vec<usize>>,
+        /// Replayed shape
+        replayed: Option<Vec<usize>>,
+    },
+    /// Metadata value differs (or is missing on one side)
+    Metadata {
+        /// Metadata key
+        key: String,
+        /// Recorded value
+        recorded: Option<StateValue>,
+        /// Replayed value
+        replayed: Option<StateValue>,
+    },
+    /// Replaying the step returned an error
+    ExecutionFailed(String),
+    /// Replayed input count differs from recorded snapshot count
+    LengthMismatch {
+        /// Number of recorded snapshots
+        recorded: usize,
+        /// Number of replayed steps
+        replayed: usize,
+    },
+}
+
+/// First step at which a replay diverged from recorded history
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonDeterminismReport {
+    /// Zero-based step index
+    pub step: usize,
+    /// What differed
+    pub divergence: StateDivergence,
+}
+
+impl std::fmt::Display for NonDeterminismReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Non-deterministic at step {}: ", self.step)?;
+        match &self.divergence {
+            StateDivergence::Tensor {
+                name,
+                recorded,
+                replayed,
+            } => write!(
+                f,
+                "tensor '{name}' recorded {recorded:?}, replayed {replayed:?}"
+            ),
+            StateDivergence::Shape {
+                name,
+                recorded,
+                replayed,
+            } => write!(
+                f,
+                "shape of '{name}' recorded {recorded:?}, replayed {replayed:?}"
+            ),
+            StateDivergence::Metadata {
+                key,
+                recorded,
+                replayed,
+            } => write!(
+                f,
+                "metadata '{key}' recorded {recorded:?}, replayed {replayed:?}"
+            ),
+            StateDivergence::ExecutionFailed(msg) => write!(f, "replay failed: {msg}"),
+            StateDivergence::LengthMismatch { recorded, replayed } => write!(
+                f,
+                "{recorded} snapshots recorded, {replayed} steps replayed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NonDeterminismReport {}
+
 impl Default for BrickHistory {
     fn default() -> Self {
         Self::new(1000)
@@ -332,6 +555,50 @@ impl InvariantGuard {
     }
 }
 
+/// Transition guard: an invariant over consecutive states
+///
+/// Used for properties like "`frame_count` only increases" that cannot be
+/// expressed against a single state.
+pub struct TransitionGuard {
+    /// Guard name
+    pub name: &'static str,
+    /// Check function, called with `(prev, next)`
+    pub check: fn(&BrickState, &BrickState) -> bool,
+    /// Severity on violation
+    pub severity: GuardSeverity,
+}
+
+impl std::fmt::Debug for TransitionGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransitionGuard")
+            .field("name", &self.name)
+            .field("check", &"<fn>")
+            .field("severity", &self.severity)
+            .finish()
+    }
+}
+
+impl TransitionGuard {
+    /// Create a new transition guard
+    #[must_use]
+    pub const fn new(
+        name: &'static str,
+        check: fn(&BrickState, &BrickState) -> bool,
+        severity: GuardSeverity,
+    ) -> Self {
+        Self {
+            name,
+            check,
+            severity,
+        }
+    }
+
+    /// Check the transition from `prev` to `next`
+    pub fn check(&self, prev: &BrickState, next: &BrickState) -> bool {
+        (self.check)(prev, next)
+    }
+}
+
 /// Wrapper that adds invariant checking to a brick
 #[derive(Debug)]
 pub struct GuardedBrick<B: Brick> {
@@ -339,6 +606,10 @@ pub struct GuardedBrick<B: Brick> {
     inner: B,
     /// Invariant guards
     guards: Vec<InvariantGuard>,
+    /// Transition guards
+    transition_guards: Vec<TransitionGuard>,
+    /// Last state that passed all guards
+    previous: Mutex<Option<BrickState>>,
 }
 
 impl<B: Brick> GuardedBrick<B> {
@@ -348,6 +619,8 @@ impl<B: Brick> GuardedBrick<B> {
         Self {
             inner: brick,
             guards: Vec::new(),
+            transition_guards: Vec::new(),
+            previous: Mutex::new(None),
         }
     }
 
@@ -358,19 +631,51 @@ impl<B: Brick> GuardedBrick<B> {
         self
     }
 
+    /// Add a transition guard
+    #[must_use]
+    pub fn transition_guard(mut self, guard: TransitionGuard) -> Self {
+        self.transition_guards.push(guard);
+        self
+    }
+
     /// Check all guards against state
+    ///
+    /// State guards run first, then transition guards against the last
+    /// state that passed. The first call has no previous state, so only
+    /// state guards apply. A state that fails is not stored, so the next
+    /// call is still compared against the last good state.
     pub fn check_guards(&self, state: &BrickState) -> Result<(), GuardViolation> {
         for guard in &self.guards {
             if !guard.check(state) {
                 return Err(GuardViolation {
                     guard_name: guard.name,
                     severity: guard.severity,
+                    kind: GuardKind::State,
                 });
             }
         }
+
+        let mut previous = self.previous.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(prev) = previous.as_ref() {
+            for guard in &self.transition_guards {
+                if !guard.check(prev, state) {
+                    return Err(GuardViolation {
+                        guard_name: guard.name,
+                        severity: guard.severity,
+                        kind: GuardKind::Transition,
+                    });
+                }
+            }
+        }
+        *previous = Some(state.clone());
         Ok(())
     }
 
+    /// Forget the stored previous state (e.g. after a deliberate reset)
+    pub fn reset_transitions(&self) {
+        *self.previous.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
     /// Get the inner brick
     pub fn inner(&self) -> &B {
         &self.inner
@@ -380,6 +685,20 @@ impl<B: Brick> GuardedBrick<B> {
     pub fn guards(&self) -> &[InvariantGuard] {
         &self.guards
     }
+
+    /// Get transition guards
+    pub fn transition_guards(&self) -> &[TransitionGuard] {
+        &self.transition_guards
+    }
+}
+
+/// Which kind of guard produced a violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuardKind {
+    /// [`InvariantGuard`] over a single state
+    State,
+    /// [`TransitionGuard`] over consecutive states
+    Transition,
 }
 
 /// Guard violation error
@@ -389,13 +708,19 @@ pub struct GuardViolation {
     pub guard_name: &'static str,
     /// Severity
     pub severity: GuardSeverity,
+    /// Whether a state or transition guard was violated
+    pub kind: GuardKind,
 }
 
 impl std::fmt::Display for GuardViolation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self.kind {
+            GuardKind::State => "Invariant",
+            GuardKind::Transition => "Transition",
+        };
         write!(
             f,
-            "Invariant guard '{}' violated (severity: {:?})",
+            "{label} guard '{}' violated (severity: {:?})",
             self.guard_name, self.severity
         )
     }
@@ -445,6 +770,86 @@ impl DeterministicRng {
     pub fn restore(&mut self, state: u64) {
         self.state = state;
     }
+
+    /// Split into `n` decorrelated substreams for parallel bricks
+    ///
+    /// Each substream is seeded by a splitmix64 derivation of this RNG's
+    /// state and the substream index, so the result is reproducible for a
+    /// given seed. Substreams are not guaranteed disjoint: they are
+    /// independent seeds in the same 2^64 - 1 cycle, so a test must keep
+    /// each stream's draw count well below the cycle length divided by `n`
+    /// for overlap to be negligible. `self` is left untouched.
+    #[must_use]
+    pub fn split(&self, n: usize) -> Vec<Self> {
+        (0..n as u64)
+            .map(|i| {
+                let seed = splitmix64(self.state ^ splitmix64(i.wrapping_add(1)));
+                // xorshift64 is stuck at zero; nudge it onto the cycle
+                Self::new(if seed == 0 {
+                    0x9E37_79B9_7F4A_7C15
+                } else {
+                    seed
+                })
+            })
+            .collect()
+    }
+
+    /// Advance the stream by `steps` draws in O(log steps)
+    ///
+    /// Equivalent to calling [`next_u64`](Self::next_u64) `steps` times.
+    /// xorshift64 is linear over GF(2), so the step is applied as a
+    /// 64x64 bit matrix raised to the `steps`-th power.
+    pub fn jump(&mut self, mut steps: u64) {
+        let mut step = XorshiftMatrix::step();
+        while steps > 0 {
+            if steps & 1 == 1 {
+                self.state = step.apply(self.state);
+            }
+            step = step.square();
+            steps >>= 1;
+        }
+    }
+}
+
+/// splitmix64 finaliser, used for seed derivation
+const fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Linear map over GF(2)^64, stored column-wise (`cols[i]` is the image of bit `i`)
+struct XorshiftMatrix {
+    cols: [u64; 64],
+}
+
+impl XorshiftMatrix {
+    /// One xorshift64 step
+    fn step() -> Self {
+        let mut cols = [0u64; 64];
+        for (i, col) in cols.iter_mut().enumerate() {
+            let mut rng = DeterministicRng::new(1 << i);
+            *col = rng.next_u64();
+        }
+        Self { cols }
+    }
+
+    fn apply(&self, v: u64) -> u64 {
+        self.cols
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (v >> i) & 1 == 1)
+            .fold(0, |acc, (_, col)| acc ^ col)
+    }
+
+    fn square(&self) -> Self {
+        let mut cols = [0u64; 64];
+        for (out, col) in cols.iter_mut().zip(&self.cols) {
+            *out = self.apply(*col);
+        }
+        Self { cols }
+    }
 }
 
 impl Default for DeterministicRng {
@@ -1184,6 +1589,7 @@ mod tests {
         let violation = GuardViolation {
             guard_name: "test_guard",
             severity: GuardSeverity::Error,
+            kind: GuardKind::State,
         };
         let display = format!("{}", violation);
         assert!(display.contains("test_guard"));
@@ -1195,6 +1601,7 @@ mod tests {
         let violation = GuardViolation {
             guard_name: "test",
             severity: GuardSeverity::Warning,
+            kind: GuardKind::State,
         };
         let _: &dyn std::error::Error = &violation;
     }
@@ -1339,6 +1746,7 @@ mod tests {
         let violation = GuardViolation {
             guard_name: "test_guard",
             severity: GuardSeverity::Critical,
+            kind: GuardKind::State,
         };
         let cloned = violation.clone();
         assert_eq!(violation.guard_name, cloned.guard_name);
@@ -1350,6 +1758,7 @@ mod tests {
         let violation = GuardViolation {
             guard_name: "my_guard",
             severity: GuardSeverity::Warning,
+            kind: GuardKind::State,
         };
         let debug_str = format!("{:?}", violation);
         assert!(debug_str.contains("GuardViolation"));
@@ -1881,14 +2290,17 @@ mod tests {
         let warning = GuardViolation {
             guard_name: "w",
             severity: GuardSeverity::Warning,
+            kind: GuardKind::State,
         };
         let error = GuardViolation {
             guard_name: "e",
             severity: GuardSeverity::Error,
+            kind: GuardKind::State,
         };
         let critical = GuardViolation {
             guard_name: "c",
             severity: GuardSeverity::Critical,
+            kind: GuardKind::State,
         };
 
         assert!(format!("{}", warning).contains("Warning"));
@@ -2446,14 +2858,17 @@ mod tests {
         let warning = GuardViolation {
             guard_name: "warn_guard",
             severity: GuardSeverity::Warning,
+            kind: GuardKind::State,
         };
         let error = GuardViolation {
             guard_name: "err_guard",
             severity: GuardSeverity::Error,
+            kind: GuardKind::State,
         };
         let critical = GuardViolation {
             guard_name: "crit_guard",
             severity: GuardSeverity::Critical,
+            kind: GuardKind::State,
         };
 
         let warning_str = format!("{}", warning);
@@ -2748,6 +3163,7 @@ mod tests {
         let violation = GuardViolation {
             guard_name: "test",
             severity: GuardSeverity::Warning,
+            kind: GuardKind::State,
         };
 
         // Test std::error::Error trait
@@ -3560,6 +3976,7 @@ mod tests {
         let violation = GuardViolation {
             guard_name: "test",
             severity: GuardSeverity::Error,
+            kind: GuardKind::State,
         };
 
         // GuardViolation has no source error
@@ -3639,4 +4056,416 @@ mod tests {
         state.shapes.insert("both".into(), vec![2]);
         assert!(state.get_tensor("both").is_some());
     }
+
+    mod replay_tests {
+        use super::super::super::{BrickAssertion, BrickBudget, BrickVerification};
+        use super::*;
+
+        #[derive(Clone, Default)]
+        struct ReplayState {
+            total: i64,
+            samples: Vec<f32>,
+            clock_ns: Option<i64>,
+        }
+
+        impl From<ReplayState> for BrickState {
+            fn from(state: ReplayState) -> Self {
+                let mut out = Self::new();
+                let len = state.samples.len();
+                out.set_tensor("samples", state.samples, vec![len]);
+                out.set_metadata("total", StateValue::Int(state.total));
+                if let Some(ns) = state.clock_ns {
+                    out.set_metadata("clock_ns", StateValue::Int(ns));
+                }
+                out
+            }
+        }
+
+        macro_rules! replay_brick {
+            ($name:ident) => {
+                struct $name;
+
+                impl Brick for $name {
+                    fn brick_name(&self) -> &'static str {
+                        stringify!($name)
+                    }
+                    fn assertions(&self) -> &[BrickAssertion] {
+                        &[]
+                    }
+                    fn budget(&self) -> BrickBudget {
+                        BrickBudget::uniform(16)
+                    }
+                    fn verify(&self) -> BrickVerification {
+                        BrickVerification {
+                            passed: vec![],
+                            failed: vec![],
+                            verification_time: Duration::ZERO,
+                        }
+                    }
+                    fn to_html(&self) -> String {
+                        String::new()
+                    }
+                    fn to_css(&self) -> String {
+                        String::new()
+                    }
+                }
+            };
+        }
+
+        replay_brick!(AccumulatorBrick);
+        replay_brick!(WallClockBrick);
+
+        impl DeterministicBrick for AccumulatorBrick {
+            type State = ReplayState;
+            type Input = i64;
+            type Output = i64;
+
+            fn execute_pure(
+                mut state: Self::State,
+                input: Self::Input,
+            ) -> Result<(Self::State, Self::Output), BrickError> {
+                state.total += input;
+                state.samples.push(input as f32);
+                Ok((state.clone(), state.total))
+            }
+        }
+
+        impl DeterministicBrick for WallClockBrick {
+            type State = ReplayState;
+            type Input = i64;
+            type Output = ();
+
+            fn execute_pure(
+                mut state: Self::State,
+                input: Self::Input,
+            ) -> Result<(Self::State, Self::Output), BrickError> {
+                state.total += input;
+                // Deliberately impure: reads the wall clock
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap();
+                state.clock_ns = Some(now.as_nanos() as i64);
+                Ok((state, ()))
+            }
+        }
+
+        fn record<B>(inputs: &[i64]) -> BrickHistory
+        where
+            B: DeterministicBrick<State = ReplayState, Input = i64>,
+        {
+            record_into::<B>(BrickHistory::new(100), inputs)
+        }
+
+        fn record_into<B>(mut history: BrickHistory, inputs: &[i64]) -> BrickHistory
+        where
+            B: DeterministicBrick<State = ReplayState, Input = i64>,
+        {
+            let mut state = B::initial_state();
+            for &input in inputs {
+                let (next, _) = B::execute_pure(state, input).unwrap();
+                history.record(
+                    BrickState::from(next.clone()),
+                    ExecutionTrace {
+                        operation: "step".into(),
+                        input_summary: input.to_string(),
+                        output_summary: String::new(),
+                        duration: Duration::ZERO,
+                        state_version_before: 0,
+                        state_version_after: 0,
+                    },
+                );
+                state = next;
+            }
+            history
+        }
+
+        #[test]
+        fn test_verify_determinism_passes_for_pure_brick() {
+            let inputs = [3, 1, 4, 1, 5];
+            let history = record::<AccumulatorBrick>(&inputs);
+            assert!(history
+                .verify_determinism::<AccumulatorBrick>(inputs)
+                .is_ok());
+        }
+
+        #[test]
+        fn test_verify_determinism_detects_wall_clock() {
+            let inputs = [1, 2, 3];
+            let history = record::<WallClockBrick>(&inputs);
+            std::thread::sleep(Duration::from_millis(2));
+
+            let report = history
+                .verify_determinism::<WallClockBrick>(inputs)
+                .unwrap_err();
+            assert_eq!(report.step, 0);
+            assert!(matches!(
+                &report.divergence,
+                StateDivergence::Metadata { key, .. } if key == "clock_ns"
+            ));
+            assert!(report.to_string().contains("clock_ns"));
+        }
+
+        #[test]
+        fn test_verify_determinism_reports_tensor_divergence() {
+            let history = record::<AccumulatorBrick>(&[1, 2, 3]);
+
+            let report = history
+                .verify_determinism::<AccumulatorBrick>([1, 5, 3])
+                .unwrap_err();
+            assert_eq!(report.step, 1);
+            assert_eq!(
+                report.divergence,
+                StateDivergence::Tensor {
+                    name: "samples".into(),
+                    recorded: Some(vec![1.0, 2.0]),
+                    replayed: Some(vec![1.0, 5.0]),
+                }
+            );
+        }
+
+        #[test]
+        fn test_verify_determinism_length_mismatch() {
+            let history = record::<AccumulatorBrick>(&[1, 2, 3]);
+
+            let short = history
+                .verify_determinism::<AccumulatorBrick>([1, 2])
+                .unwrap_err();
+            assert_eq!(short.step, 2);
+            assert_eq!(
+                short.divergence,
+                StateDivergence::LengthMismatch {
+                    recorded: 3,
+                    replayed: 2
+                }
+            );
+
+            let long = history
+                .verify_determinism::<AccumulatorBrick>([1, 2, 3, 4])
+                .unwrap_err();
+            assert_eq!(long.step, 3);
+        }
+
+        #[test]
+        fn test_verify_determinism_after_eviction() {
+            let inputs = [3, 1, 4, 1, 5];
+            let history = record_into::<AccumulatorBrick>(BrickHistory::new(3), &inputs);
+            assert_eq!(history.len(), 3);
+            assert_eq!(history.evicted(), 2);
+
+            assert!(history
+                .verify_determinism::<AccumulatorBrick>(inputs)
+                .is_ok());
+
+            let report = history
+                .verify_determinism::<AccumulatorBrick>([3, 1, 4, 1, 6])
+                .unwrap_err();
+            assert_eq!(report.step, 4);
+            assert!(matches!(report.divergence, StateDivergence::Tensor { .. }));
+
+            let short = history
+                .verify_determinism::<AccumulatorBrick>([3, 1, 4])
+                .unwrap_err();
+            assert_eq!(
+                short.divergence,
+                StateDivergence::LengthMismatch {
+                    recorded: 5,
+                    replayed: 3
+                }
+            );
+        }
+
+        #[test]
+        fn test_verify_determinism_after_step_back_branch() {
+            let mut history = record::<AccumulatorBrick>(&[1, 2, 3]);
+            let _ = history.step_back();
+            let _ = history.step_back();
+            // Re-record from step 1 on a new branch
+            let mut state = AccumulatorBrick::initial_state();
+            for input in [1, 7] {
+                let (next, _) = AccumulatorBrick::execute_pure(state, input).unwrap();
+                state = next;
+            }
+            history.record(
+                BrickState::from(state),
+                ExecutionTrace {
+                    operation: "step".into(),
+                    input_summary: "7".into(),
+                    output_summary: String::new(),
+                    duration: Duration::ZERO,
+                    state_version_before: 0,
+                    state_version_after: 0,
+                },
+            );
+
+            assert_eq!(history.len(), 2);
+            assert!(history
+                .verify_determinism::<AccumulatorBrick>([1, 7])
+                .is_ok());
+        }
+    }
+
+    mod rng_stream_tests {
+        use super::*;
+
+        fn correlation(a: &[f64], b: &[f64]) -> f64 {
+            let n = a.len() as f64;
+            let mean_a = a.iter().sum::<f64>() / n;
+            let mean_b = b.iter().sum::<f64>() / n;
+            let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+            for (x, y) in a.iter().zip(b) {
+                cov += (x - mean_a) * (y - mean_b);
+                var_a += (x - mean_a).powi(2);
+                var_b += (y - mean_b).powi(2);
+            }
+            cov / (var_a.sqrt() * var_b.sqrt())
+        }
+
+        #[test]
+        fn test_split_is_reproducible() {
+            let rng = DeterministicRng::new(7);
+            let a: Vec<u64> = rng.split(4).iter().map(DeterministicRng::state).collect();
+            let b: Vec<u64> = rng.split(4).iter().map(DeterministicRng::state).collect();
+            assert_eq!(a, b);
+            assert_eq!(rng.state(), 7);
+
+            let unique: std::collections::HashSet<_> = a.iter().collect();
+            assert_eq!(unique.len(), 4);
+            assert!(a.iter().all(|&s| s != 0));
+        }
+
+        #[test]
+        fn test_split_streams_low_cross_correlation() {
+            let mut streams = DeterministicRng::new(42).split(2);
+            let a: Vec<f64> = (0..10_000).map(|_| streams[0].next_f64()).collect();
+            let b: Vec<f64> = (0..10_000).map(|_| streams[1].next_f64()).collect();
+
+            let r = correlation(&a, &b);
+            assert!(r.abs() < 0.05, "cross-correlation too high: {r}");
+        }
+
+        #[test]
+        fn test_jump_matches_stepping() {
+            for steps in [0, 1, 2, 63, 64, 1000, 12_345] {
+                let mut stepped = DeterministicRng::new(0xDEAD_BEEF);
+                for _ in 0..steps {
+                    stepped.next_u64();
+                }
+
+                let mut jumped = DeterministicRng::new(0xDEAD_BEEF);
+                jumped.jump(steps);
+                assert_eq!(jumped.state(), stepped.state(), "steps = {steps}");
+            }
+        }
+
+        #[test]
+        fn test_jump_composes() {
+            let mut once = DeterministicRng::new(3);
+            once.jump(1 << 40);
+
+            let mut twice = DeterministicRng::new(3);
+            twice.jump(1 << 39);
+            twice.jump(1 << 39);
+            assert_eq!(once.state(), twice.state());
+        }
+    }
+
+    mod transition_guard_tests {
+        use super::super::super::{BrickAssertion, BrickBudget, BrickVerification};
+        use super::*;
+
+        struct CounterBrick;
+
+        impl Brick for CounterBrick {
+            fn brick_name(&self) -> &'static str {
+                "Counter"
+            }
+            fn assertions(&self) -> &[BrickAssertion] {
+                &[]
+            }
+            fn budget(&self) -> BrickBudget {
+                BrickBudget::uniform(16)
+            }
+            fn verify(&self) -> BrickVerification {
+                BrickVerification {
+                    passed: vec![],
+                    failed: vec![],
+                    verification_time: Duration::ZERO,
+                }
+            }
+            fn to_html(&self) -> String {
+                String::new()
+            }
+            fn to_css(&self) -> String {
+                String::new()
+            }
+        }
+
+        fn frame(n: i64) -> BrickState {
+            let mut state = BrickState::new();
+            state.set_metadata("frame_count", StateValue::Int(n));
+            state
+        }
+
+        fn frame_count(state: &BrickState) -> i64 {
+            match state.get_metadata("frame_count") {
+                Some(StateValue::Int(n)) => *n,
+                _ => 0,
+            }
+        }
+
+        fn guarded() -> GuardedBrick<CounterBrick> {
+            GuardedBrick::new(CounterBrick)
+                .guard(InvariantGuard::new(
+                    "non_negative",
+                    |s| frame_count(s) >= 0,
+                    GuardSeverity::Error,
+                ))
+                .transition_guard(TransitionGuard::new(
+                    "monotonic_frame_count",
+                    |prev, next| frame_count(next) >= frame_count(prev),
+                    GuardSeverity::Critical,
+                ))
+        }
+
+        #[test]
+        fn test_monotonic_guard_catches_regression() {
+            let guarded = guarded();
+            assert!(guarded.check_guards(&frame(1)).is_ok());
+            assert!(guarded.check_guards(&frame(2)).is_ok());
+
+            let violation = guarded.check_guards(&frame(1)).unwrap_err();
+            assert_eq!(violation.guard_name, "monotonic_frame_count");
+            assert_eq!(violation.kind, GuardKind::Transition);
+            assert_eq!(violation.severity, GuardSeverity::Critical);
+            assert!(violation.to_string().starts_with("Transition guard"));
+
+            // Rejected state is not stored; still compared against frame 2
+            assert!(guarded.check_guards(&frame(1)).is_err());
+            assert!(guarded.check_guards(&frame(3)).is_ok());
+        }
+
+        #[test]
+        fn test_state_guard_reports_state_kind() {
+            let guarded = guarded();
+            let violation = guarded.check_guards(&frame(-1)).unwrap_err();
+            assert_eq!(violation.guard_name, "non_negative");
+            assert_eq!(violation.kind, GuardKind::State);
+            assert!(violation.to_string().starts_with("Invariant guard"));
+        }
+
+        #[test]
+        fn test_first_state_skips_transition_guards() {
+            let guarded = guarded();
+            assert!(guarded.check_guards(&frame(100)).is_ok());
+            assert_eq!(guarded.transition_guards().len(), 1);
+        }
+
+        #[test]
+        fn test_reset_transitions() {
+            let guarded = guarded();
+            assert!(guarded.check_guards(&frame(10)).is_ok());
+            guarded.reset_transitions();
+            assert!(guarded.check_guards(&frame(0)).is_ok());
+        }
+    }
 }
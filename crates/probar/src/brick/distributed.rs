@@ -27,7 +27,7 @@
 // Allow expect for RwLock - lock poisoning is truly exceptional
 #![allow(clippy::expect_used)]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
@@ -183,6 +183,8 @@ pub struct ExecutionMetrics {
     pub worker_id: Option<WorkerId>,
     /// Data transfer time (if remote)
     pub transfer_time: Option<Duration>,
+    /// Why the backend was chosen (cost-based selection only)
+    pub decision: Option<BackendDecision>,
 }
 
 impl ExecutionMetrics {
@@ -194,6 +196,7 @@ impl ExecutionMetrics {
             backend,
             worker_id: None,
             transfer_time: None,
+            decision: None,
         }
     }
 }
@@ -210,6 +213,7 @@ pub struct DistributedBrick<B: Brick> {
     backend: Backend,
     data_dependencies: Vec<String>,
     preferred_worker: Option<WorkerId>,
+    priority: u32,
 }
 
 impl<B: Brick> DistributedBrick<B> {
@@ -221,6 +225,7 @@ impl<B: Brick> DistributedBrick<B> {
             backend: Backend::default(),
             data_dependencies: Vec::new(),
             preferred_worker: None,
+            priority: 0,
         }
     }
 
@@ -245,6 +250,13 @@ impl<B: Brick> DistributedBrick<B> {
         self
     }
 
+    /// Set scheduling priority (higher = more urgent)
+    #[must_use]
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Get the inner brick
     #[must_use]
     pub fn inner(&self) -> &B {
@@ -282,6 +294,7 @@ impl<B: Brick> DistributedBrick<B> {
             backend: self.backend,
             data_dependencies: self.data_dependencies.clone(),
             preferred_worker: self.preferred_worker,
+            priority: self.priority,
         }
     }
 }
@@ -323,6 +336,8 @@ pub struct TaskSpec {
     pub data_dependencies: Vec<String>,
     /// Preferred worker
     pub preferred_worker: Option<WorkerId>,
+    /// Scheduling priority (higher = more urgent)
+    pub priority: u32,
 }
 
 /// Data location entry for a specific piece of data
@@ -459,6 +474,13 @@ impl BrickDataTracker {
             .map(|(worker, _)| worker)
     }
 
+    /// Get the location entry for a data key
+    #[must_use]
+    pub fn location(&self, key: &str) -> Option<DataLocation> {
+        let locations = self.locations.read().expect("lock poisoned");
+        locations.get(key).cloned()
+    }
+
     /// Get total data size tracked
     #[must_use]
     pub fn total_data_size(&self) -> usize {
@@ -467,6 +489,129 @@ impl BrickDataTracker {
     }
 }
 
+/// Estimated cost characteristics of one backend
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackendCost {
+    /// Fixed dispatch overhead per task
+    pub setup: Duration,
+    /// Compute time per element-operation, in nanoseconds
+    pub ns_per_op: f64,
+    /// Transfer bandwidth for moving data to/from the backend (`None` = shares host memory)
+    pub transfer_bytes_per_sec: Option<f64>,
+}
+
+impl BackendCost {
+    /// Create a backend cost entry
+    #[must_use]
+    pub const fn new(setup: Duration, ns_per_op: f64, transfer_bytes_per_sec: Option<f64>) -> Self {
+        Self {
+            setup,
+            ns_per_op,
+            transfer_bytes_per_sec,
+        }
+    }
+}
+
+/// Cost model used by [`BackendSelector::auto`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostModel {
+    /// Plain CPU
+    pub cpu: BackendCost,
+    /// SIMD-accelerated CPU
+    pub simd: BackendCost,
+    /// GPU (host-to-device transfers over the bus)
+    pub gpu: BackendCost,
+    /// Remote worker (transfers over the network)
+    pub remote: BackendCost,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self {
+            cpu: BackendCost::new(Duration::ZERO, 1.0, None),
+            simd: BackendCost::new(Duration::from_micros(1), 0.25, None),
+            gpu: BackendCost::new(Duration::from_micros(20), 0.002, Some(16e9)),
+            remote: BackendCost::new(Duration::from_millis(1), 0.01, Some(1e8)),
+        }
+    }
+}
+
+impl CostModel {
+    /// Get the cost entry for a backend
+    #[must_use]
+    pub const fn cost(&self, backend: Backend) -> &BackendCost {
+        match backend {
+            Backend::Cpu => &self.cpu,
+            Backend::Simd => &self.simd,
+            Backend::Gpu => &self.gpu,
+            Backend::Remote => &self.remote,
+        }
+    }
+
+    /// Estimate `(compute, transfer)` time for a workload on a backend
+    ///
+    /// Transfers cover the input and an equal-sized output, plus any
+    /// dependency data. A remote backend skips dependency bytes that are
+    /// already resident on some worker. A negative or NaN `ns_per_op`, or a
+    /// zero or negative bandwidth, makes the estimate [`Duration::MAX`].
+    #[must_use]
+    pub fn estimate(
+        &self,
+        backend: Backend,
+        element_count: usize,
+        ops_per_element: u32,
+        dependencies: &[DataLocation],
+    ) -> (Duration, Duration) {
+        let cost = self.cost(backend);
+        let ops = element_count as f64 * f64::from(ops_per_element.max(1));
+        let compute = cost
+            .setup
+            .saturating_add(secs_or_max(ops * cost.ns_per_op / 1e9));
+
+        let transfer = cost
+            .transfer_bytes_per_sec
+            .map_or(Duration::ZERO, |bandwidth| {
+                let io_bytes = 2 * element_count * std::mem::size_of::<f32>();
+                let dep_bytes: usize = dependencies
+                    .iter()
+                    .filter(|d| backend != Backend::Remote || d.workers.is_empty())
+                    .map(|d| d.size_bytes)
+                    .sum();
+                secs_or_max((io_bytes + dep_bytes) as f64 / bandwidth)
+            });
+
+        (compute, transfer)
+    }
+}
+
+/// Convert an estimate in seconds, treating invalid values as infinitely expensive
+fn secs_or_max(secs: f64) -> Duration {
+    Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+}
+
+/// Outcome of a cost-based backend selection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendDecision {
+    /// Chosen backend
+    pub backend: Backend,
+    /// Estimated compute time on the chosen backend
+    pub compute: Duration,
+    /// Estimated transfer time on the chosen backend
+    pub transfer: Duration,
+    /// Estimated total for every backend considered, cheapest first
+    pub candidates: Vec<(Backend, Duration)>,
+    /// Human-readable explanation
+    pub rationale: String,
+}
+
+impl BackendDecision {
+    /// Estimated total time on the chosen backend
+    #[must_use]
+    pub fn estimated(&self) -> Duration {
+        self.compute.saturating_add(self.transfer)
+    }
+}
+
 /// Backend selector for choosing optimal execution backend
 #[derive(Debug)]
 pub struct BackendSelector {
@@ -476,6 +621,8 @@ pub struct BackendSelector {
     simd_threshold: usize,
     /// Maximum element count for CPU (else remote)
     cpu_max_threshold: usize,
+    /// Cost model for automatic selection (`None` = threshold-based)
+    cost_model: Option<CostModel>,
 }
 
 impl Default for BackendSelector {
@@ -492,9 +639,108 @@ impl BackendSelector {
             gpu_threshold: 1_000_000,       // 1M elements for GPU
             simd_threshold: 10_000,         // 10K elements for SIMD
             cpu_max_threshold: 100_000_000, // 100M elements max for local
+            cost_model: None,
         }
     }
 
+    /// Create a selector that minimizes estimated transfer + compute cost
+    #[must_use]
+    pub fn auto() -> Self {
+        Self::new().with_cost_model(CostModel::default())
+    }
+
+    /// Use a cost model for selection instead of fixed thresholds
+    #[must_use]
+    pub fn with_cost_model(mut self, model: CostModel) -> Self {
+        self.cost_model = Some(model);
+        self
+    }
+
+    /// Get the cost model, if cost-based selection is enabled
+    #[must_use]
+    pub fn cost_model(&self) -> Option<&CostModel> {
+        self.cost_model.as_ref()
+    }
+
+    /// Choose the backend with the lowest estimated cost
+    ///
+    /// Unavailable backends (GPU without `gpu_available`, Remote until
+    /// supported) are not considered. Uses the default cost model if this
+    /// selector was built without one.
+    #[must_use]
+    pub fn choose(
+        &self,
+        element_count: usize,
+        ops_per_element: u32,
+        dependencies: &[DataLocation],
+        gpu_available: bool,
+    ) -> BackendDecision {
+        let default_model;
+        let model = match &self.cost_model {
+            Some(model) => model,
+            None => {
+                default_model = CostModel::default();
+                &default_model
+            }
+        };
+
+        let mut estimates: Vec<_> = [Backend::Cpu, Backend::Simd, Backend::Gpu, Backend::Remote]
+            .into_iter()
+            .filter(|b| match b {
+                Backend::Gpu => gpu_available,
+                Backend::Remote => b.is_available(),
+                _ => true,
+            })
+            .map(|b| {
+                let (compute, transfer) =
+                    model.estimate(b, element_count, ops_per_element, dependencies);
+                (b, compute, transfer)
+            })
+            .collect();
+        // Stable sort keeps the cheaper-to-dispatch backend first on ties
+        estimates.sort_by_key(|(_, compute, transfer)| compute.saturating_add(*transfer));
+
+        let (backend, compute, transfer) = estimates[0];
+        let candidates: Vec<_> = estimates
+            .iter()
+            .map(|(b, c, t)| (*b, c.saturating_add(*t)))
+            .collect();
+        let runner_up = candidates
+            .get(1)
+            .map(|(b, total)| format!(", beating {b:?} ({total:?})"))
+            .unwrap_or_default();
+        let rationale = format!(
+            "{backend:?} for {element_count} elements x {ops_per_element} ops: \
+             compute {compute:?} + transfer {transfer:?} = {:?}{runner_up}",
+            compute.saturating_add(transfer)
+        );
+
+        BackendDecision {
+            backend,
+            compute,
+            transfer,
+            candidates,
+            rationale,
+        }
+    }
+
+    /// Choose a backend for a task, looking up its dependencies' locations
+    #[must_use]
+    pub fn choose_for_task(
+        &self,
+        spec: &TaskSpec,
+        element_count: usize,
+        tracker: &BrickDataTracker,
+        gpu_available: bool,
+    ) -> BackendDecision {
+        let dependencies: Vec<_> = spec
+            .data_dependencies
+            .iter()
+            .filter_map(|key| tracker.location(key))
+            .collect();
+        self.choose(element_count, 1, &dependencies, gpu_available)
+    }
+
     /// Configure GPU threshold
     #[must_use]
     pub fn with_gpu_threshold(mut self, threshold: usize) -> Self {
@@ -591,9 +837,17 @@ impl MultiBrickExecutor {
         let start = Instant::now();
 
         // Select backend
-        let backend = self
-            .selector
-            .select(input.element_count(), self.gpu_available);
+        let decision = self.selector.cost_model().map(|_| {
+            self.selector
+                .choose(input.element_count(), 1, &[], self.gpu_available)
+        });
+        let backend = decision.as_ref().map_or_else(
+            || {
+                self.selector
+                    .select(input.element_count(), self.gpu_available)
+            },
+            |d| d.backend,
+        );
 
         // Execute on selected backend
         let (output_data, output_shape) = match backend {
@@ -608,6 +862,7 @@ impl MultiBrickExecutor {
         // Build output with metrics
         let mut output = BrickOutput::new(output_data, output_shape);
         output.metrics = ExecutionMetrics::new(execution_time, backend);
+        output.metrics.decision = decision;
 
         Ok(output)
     }
@@ -643,6 +898,7 @@ impl MultiBrickExecutor {
             backend,
             worker_id,
             transfer_time: None,
+            decision: None,
         };
 
         Ok(output)
@@ -794,9 +1050,9 @@ impl WorkStealingTask {
     pub fn new(id: u64, spec: TaskSpec, input_key: String) -> Self {
         Self {
             id,
+            priority: spec.priority,
             spec,
             input_key,
-            priority: 0,
             created_at: Instant::now(),
         }
     }
@@ -813,6 +1069,17 @@ impl WorkStealingTask {
     pub fn age(&self) -> Duration {
         self.created_at.elapsed()
     }
+
+    /// Priority after aging: +1 for every `aging` interval spent waiting
+    #[must_use]
+    pub fn effective_priority(&self, aging: Option<Duration>) -> u64 {
+        let bump = aging
+            .filter(|interval| !interval.is_zero())
+            .map_or(0, |interval| {
+                (self.age().as_nanos() / interval.as_nanos()) as u64
+            });
+        u64::from(self.priority).saturating_add(bump)
+    }
 }
 
 /// Per-worker task queue supporting work-stealing
@@ -826,6 +1093,8 @@ pub struct WorkerQueue {
     completed_count: AtomicU64,
     /// Number of tasks stolen from this queue
     stolen_count: AtomicU64,
+    /// Aging interval for starvation prevention (`None` disables aging)
+    aging: Option<Duration>,
 }
 
 impl WorkerQueue {
@@ -837,9 +1106,37 @@ impl WorkerQueue {
             local_queue: RwLock::new(Vec::new()),
             completed_count: AtomicU64::new(0),
             stolen_count: AtomicU64::new(0),
+            aging: None,
         }
     }
 
+    /// Enable aging: waiting tasks gain +1 priority per `interval`
+    #[must_use]
+    pub fn with_aging(mut self, interval: Duration) -> Self {
+        self.aging = Some(interval);
+        self
+    }
+
+    /// Index of the most urgent task (highest effective priority, oldest first on ties)
+    fn most_urgent(&self, queue: &[WorkStealingTask]) -> Option<usize> {
+        queue
+            .iter()
+            .enumerate()
+            .max_by(|(ia, a), (ib, b)| {
+                a.effective_priority(self.aging)
+                    .cmp(&b.effective_priority(self.aging))
+                    .then(ib.cmp(ia))
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// Highest effective priority currently queued
+    #[must_use]
+    pub fn peek_priority(&self) -> Option<u64> {
+        let queue = self.local_queue.read().expect("lock poisoned");
+        queue.iter().map(|t| t.effective_priority(self.aging)).max()
+    }
+
     /// Push a task to the local queue
     pub fn push(&self, task: WorkStealingTask) {
         let mut queue = self.local_queue.write().expect("lock poisoned");
@@ -848,13 +1145,11 @@ impl WorkerQueue {
         queue.sort_by(|a, b| b.priority.cmp(&a.priority));
     }
 
-    /// Pop a task from the local queue (highest priority first)
+    /// Pop a task from the local queue (highest effective priority first)
     pub fn pop(&self) -> Option<WorkStealingTask> {
         let mut queue = self.local_queue.write().expect("lock poisoned");
-        if queue.is_empty() {
-            return None;
-        }
-        Some(queue.remove(0)) // Get highest priority (front after sort)
+        let index = self.most_urgent(&queue)?;
+        Some(queue.remove(index))
     }
 
     /// Steal a task from this queue (lowest priority - be nice to owner)
@@ -867,6 +1162,17 @@ impl WorkerQueue {
         queue.pop() // Steal lowest priority (back after sort)
     }
 
+    /// Steal the most urgent task from this queue
+    ///
+    /// Used by the scheduler so an idle worker picks up latency-critical
+    /// work rather than leaving it behind the owner's current task.
+    pub fn steal_urgent(&self) -> Option<WorkStealingTask> {
+        let mut queue = self.local_queue.write().expect("lock poisoned");
+        let index = self.most_urgent(&queue)?;
+        self.stolen_count.fetch_add(1, Ordering::Relaxed);
+        Some(queue.remove(index))
+    }
+
     /// Check if queue is empty
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -914,8 +1220,11 @@ impl WorkerQueue {
 ///
 /// 1. Each worker has a local deque (double-ended queue)
 /// 2. Workers push/pop from their own queue (LIFO - good for cache locality)
-/// 3. When idle, workers steal from other queues (FIFO - steal oldest tasks)
+/// 3. When idle, workers steal the most urgent task from the queue whose
+///    head has the highest priority (busiest queue breaks ties)
 /// 4. Stealing considers data locality via `BrickDataTracker`
+/// 5. With [`WorkStealingScheduler::with_aging`], waiting tasks age
+///    (+1 priority per aging interval) so low-priority work is not starved
 #[derive(Debug)]
 pub struct WorkStealingScheduler {
     /// Worker queues indexed by worker ID
@@ -926,9 +1235,16 @@ pub struct WorkStealingScheduler {
     task_counter: AtomicU64,
     /// Total tasks submitted
     submitted_count: AtomicU64,
+    /// Aging interval applied to worker queues
+    aging: Option<Duration>,
+    /// Wait times of dequeued tasks, by original priority
+    wait_times: RwLock<BTreeMap<u32, PriorityWaitStats>>,
 }
 
 impl WorkStealingScheduler {
+    /// Suggested aging interval for [`Self::with_aging`]: +1 priority per 100ms waited
+    pub const DEFAULT_AGING: Duration = Duration::from_millis(100);
+
     /// Create a new work-stealing scheduler
     #[must_use]
     pub fn new(data_tracker: Arc<BrickDataTracker>) -> Self {
@@ -937,12 +1253,25 @@ impl WorkStealingScheduler {
             data_tracker,
             task_counter: AtomicU64::new(0),
             submitted_count: AtomicU64::new(0),
+            aging: None,
+            wait_times: RwLock::new(BTreeMap::new()),
         }
     }
 
+    /// Set the aging interval for workers registered afterwards
+    #[must_use]
+    pub fn with_aging(mut self, interval: Duration) -> Self {
+        self.aging = Some(interval);
+        self
+    }
+
     /// Register a worker with the scheduler
     pub fn register_worker(&self, worker_id: WorkerId) -> Arc<WorkerQueue> {
-        let queue = Arc::new(WorkerQueue::new(worker_id));
+        let queue = WorkerQueue::new(worker_id);
+        let queue = Arc::new(match self.aging {
+            Some(interval) => queue.with_aging(interval),
+            None => queue,
+        });
         let mut queues = self.queues.write().expect("lock poisoned");
         queues.insert(worker_id, Arc::clone(&queue));
         queue
@@ -996,15 +1325,29 @@ impl WorkStealingScheduler {
     pub fn get_work(&self, worker_id: WorkerId) -> Option<WorkStealingTask> {
         let queues = self.queues.read().expect("lock poisoned");
 
-        // First try local queue
-        if let Some(queue) = queues.get(&worker_id) {
-            if let Some(task) = queue.pop() {
-                return Some(task);
-            }
-        }
-
-        // Try to steal from other workers
-        self.try_steal(worker_id, &queues)
+        // First try local queue, then steal from other workers
+        let task = queues
+            .get(&worker_id)
+            .and_then(|queue| queue.pop())
+            .or_else(|| self.try_steal(worker_id, &queues))?;
+
+        self.record_wait(&task);
+        Some(task)
+    }
+
+    /// Record how long a dequeued task waited
+    fn record_wait(&self, task: &WorkStealingTask) {
+        let wait = task.age();
+        let mut wait_times = self.wait_times.write().expect("lock poisoned");
+        let entry = wait_times
+            .entry(task.priority)
+            .or_insert_with(|| PriorityWaitStats {
+                priority: task.priority,
+                ..PriorityWaitStats::default()
+            });
+        entry.dequeued += 1;
+        entry.total_wait += wait;
+        entry.max_wait = entry.max_wait.max(wait);
     }
 
     /// Try to steal work from another worker's queue
@@ -1014,7 +1357,7 @@ impl WorkStealingScheduler {
         queues: &HashMap<WorkerId, Arc<WorkerQueue>>,
     ) -> Option<WorkStealingTask> {
         // Find queues with work, preferring those with data locality
-        let mut candidates: Vec<_> = queues
+        let candidates: Vec<_> = queues
             .iter()
             .filter(|(id, q)| **id != stealer_id && !q.is_empty())
             .collect();
@@ -1023,12 +1366,15 @@ impl WorkStealingScheduler {
             return None;
         }
 
-        // Sort by queue length (steal from busiest)
-        candidates.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+        // Steal from the queue with the most urgent task, busiest on ties
+        let mut candidates: Vec<_> = candidates
+            .into_iter()
+            .map(|(_, q)| (q.peek_priority(), q.len(), q))
+            .collect();
+        candidates.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
 
-        // Try to steal from the busiest queue
-        for (_, queue) in candidates {
-            if let Some(task) = queue.steal() {
+        for (_, _, queue) in candidates {
+            if let Some(task) = queue.steal_urgent() {
                 return Some(task);
             }
         }
@@ -1072,6 +1418,8 @@ impl WorkStealingScheduler {
         let total_completed: u64 = worker_stats.iter().map(|s| s.completed).sum();
         let total_stolen: u64 = worker_stats.iter().map(|s| s.stolen_from).sum();
 
+        let wait_times = self.wait_times.read().expect("lock poisoned");
+
         SchedulerStats {
             worker_count: queues.len(),
             total_submitted: self.submitted_count.load(Ordering::Relaxed),
@@ -1079,6 +1427,7 @@ impl WorkStealingScheduler {
             total_completed,
             total_stolen,
             workers: worker_stats,
+            priority_waits: wait_times.values().rev().cloned().collect(),
         }
     }
 
@@ -1117,6 +1466,41 @@ pub struct SchedulerStats {
     pub total_stolen: u64,
     /// Per-worker statistics
     pub workers: Vec<WorkerStats>,
+    /// Wait times per priority level, highest priority first
+    pub priority_waits: Vec<PriorityWaitStats>,
+}
+
+impl SchedulerStats {
+    /// Wait statistics for one priority level
+    #[must_use]
+    pub fn wait_for_priority(&self, priority: u32) -> Option<&PriorityWaitStats> {
+        self.priority_waits.iter().find(|w| w.priority == priority)
+    }
+}
+
+/// Queue wait statistics for one priority level
+#[derive(Debug, Clone, Default)]
+pub struct PriorityWaitStats {
+    /// Task priority (as submitted, before aging)
+    pub priority: u32,
+    /// Tasks dequeued at this priority
+    pub dequeued: u64,
+    /// Total time those tasks spent queued
+    pub total_wait: Duration,
+    /// Longest single wait
+    pub max_wait: Duration,
+}
+
+impl PriorityWaitStats {
+    /// Mean time spent queued
+    #[must_use]
+    pub fn mean_wait(&self) -> Duration {
+        if self.dequeued == 0 {
+            Duration::ZERO
+        } else {
+            self.total_wait / self.dequeued as u32
+        }
+    }
 }
 
 // ============================================================================
@@ -1453,6 +1837,7 @@ mod tests {
             backend: Backend::Cpu,
             data_dependencies: vec![],
             preferred_worker: None,
+            priority: 0,
         };
         let task = WorkStealingTask::new(1, spec, "input_key".into()).with_priority(10);
 
@@ -1474,6 +1859,7 @@ mod tests {
             backend: Backend::Cpu,
             data_dependencies: vec![],
             preferred_worker: None,
+            priority: 0,
         };
         let task = WorkStealingTask::new(1, spec, "key".into());
         queue.push(task);
@@ -1497,6 +1883,7 @@ mod tests {
                 backend: Backend::Cpu,
                 data_dependencies: vec![],
                 preferred_worker: None,
+                priority: 0,
             };
             let task = WorkStealingTask::new(i as u64, spec, "key".into()).with_priority(i);
             queue.push(task);
@@ -1521,6 +1908,7 @@ mod tests {
                 backend: Backend::Cpu,
                 data_dependencies: vec![],
                 preferred_worker: None,
+                priority: 0,
             };
             let task = WorkStealingTask::new(i as u64, spec, "key".into()).with_priority(i);
             queue.push(task);
@@ -1561,6 +1949,7 @@ mod tests {
             backend: Backend::Cpu,
             data_dependencies: vec![],
             preferred_worker: None,
+            priority: 0,
         };
 
         let task_id = scheduler.submit(spec, "input".into());
@@ -1585,6 +1974,7 @@ mod tests {
             backend: Backend::Cpu,
             data_dependencies: vec![],
             preferred_worker: Some(WorkerId::new(1)),
+            priority: 0,
         };
         scheduler.submit(spec, "input".into());
 
@@ -1612,6 +2002,7 @@ mod tests {
                 backend: Backend::Cpu,
                 data_dependencies: vec![],
                 preferred_worker: Some(WorkerId::new(1)),
+                priority: 0,
             };
             scheduler.submit(spec, format!("input{}", i));
         }
@@ -1642,6 +2033,7 @@ mod tests {
             backend: Backend::Cpu,
             data_dependencies: vec!["model_weights".into()],
             preferred_worker: None,
+            priority: 0,
         };
         scheduler.submit(spec, "audio_input".into());
 
@@ -1670,6 +2062,7 @@ mod tests {
                 } else {
                     Some(WorkerId::new(2))
                 },
+                priority: 0,
             };
             scheduler.submit(spec, format!("input{}", i));
         }
@@ -1796,6 +2189,7 @@ mod tests {
             backend: Backend::Gpu,
             data_dependencies: vec!["dep1".into()],
             preferred_worker: Some(WorkerId::new(5)),
+            priority: 0,
         };
         let cloned = spec.clone();
         assert_eq!(spec.brick_name, cloned.brick_name);
@@ -2068,6 +2462,7 @@ mod tests {
             backend: Backend::Cpu,
             data_dependencies: vec![],
             preferred_worker: None,
+            priority: 0,
         };
         let task = WorkStealingTask::new(1, spec, "key".into());
         let cloned = task.clone();
@@ -2124,6 +2519,7 @@ mod tests {
             backend: Backend::Cpu,
             data_dependencies: vec![],
             preferred_worker: None,
+            priority: 0,
         };
 
         let task_id = scheduler.submit(spec, "input".into());
@@ -2144,6 +2540,7 @@ mod tests {
             backend: Backend::Cpu,
             data_dependencies: vec![],
             preferred_worker: None,
+            priority: 0,
         };
 
         let task_id = scheduler.submit_priority(spec, "input".into(), 100);
@@ -2195,6 +2592,7 @@ mod tests {
             total_completed: 4,
             total_stolen: 1,
             workers: vec![],
+            priority_waits: vec![],
         };
         assert_eq!(stats.worker_count, 2);
         assert_eq!(stats.total_submitted, 10);
@@ -2224,4 +2622,246 @@ mod tests {
         let workers = tracker.get_workers_for_data("key");
         assert_eq!(workers.len(), 1); // Should not duplicate
     }
+
+    // ========================================================================
+    // Priority-aware scheduling tests
+    // ========================================================================
+
+    fn prioritized_spec(name: &str, priority: u32) -> TaskSpec {
+        TaskSpec {
+            brick_name: name.into(),
+            backend: Backend::Cpu,
+            data_dependencies: vec![],
+            preferred_worker: Some(WorkerId::new(1)),
+            priority,
+        }
+    }
+
+    #[test]
+    fn test_mixed_priority_high_completes_first() {
+        let scheduler = WorkStealingScheduler::new(Arc::new(BrickDataTracker::new()));
+        scheduler.register_worker(WorkerId::new(1));
+        scheduler.register_worker(WorkerId::new(2));
+
+        // Interleave batch and latency-critical tasks on worker 1
+        for i in 0..10 {
+            scheduler.submit(prioritized_spec(&format!("batch{i}"), 0), "k".into());
+            scheduler.submit(prioritized_spec(&format!("ui{i}"), 10), "k".into());
+        }
+
+        // Both workers drain; worker 2 only ever steals
+        let mut order = Vec::new();
+        for turn in 0.. {
+            let worker = WorkerId::new(1 + turn % 2);
+            match scheduler.get_work(worker) {
+                Some(task) => order.push(task.priority),
+                None if scheduler.stats().total_pending == 0 => break,
+                None => {}
+            }
+        }
+        assert_eq!(order.len(), 20);
+
+        let mean_position = |priority: u32| {
+            let positions: Vec<_> = order
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| **p == priority)
+                .map(|(i, _)| i as f64)
+                .collect();
+            positions.iter().sum::<f64>() / positions.len() as f64
+        };
+        assert!(mean_position(10) < mean_position(0));
+        // Stealing takes the urgent task, so all high-priority work goes first
+        assert!(order[..10].iter().all(|&p| p == 10));
+
+        let stats = scheduler.stats();
+        let high = stats.wait_for_priority(10).unwrap();
+        let low = stats.wait_for_priority(0).unwrap();
+        assert_eq!(high.dequeued, 10);
+        assert_eq!(low.dequeued, 10);
+        assert!(high.mean_wait() <= low.mean_wait());
+        assert_eq!(stats.priority_waits[0].priority, 10);
+    }
+
+    #[test]
+    fn test_steal_from_highest_priority_victim() {
+        let scheduler = WorkStealingScheduler::new(Arc::new(BrickDataTracker::new()));
+        for id in 1..=3 {
+            scheduler.register_worker(WorkerId::new(id));
+        }
+
+        // Worker 1 is busiest but only has batch work; worker 2 has one urgent task
+        for _ in 0..5 {
+            scheduler.submit(prioritized_spec("batch", 0), "k".into());
+        }
+        let mut urgent = prioritized_spec("urgent", 50);
+        urgent.preferred_worker = Some(WorkerId::new(2));
+        scheduler.submit(urgent, "k".into());
+
+        let stolen = scheduler.get_work(WorkerId::new(3)).unwrap();
+        assert_eq!(stolen.spec.brick_name, "urgent");
+    }
+
+    #[test]
+    fn test_aging_prevents_starvation() {
+        let queue = WorkerQueue::new(WorkerId::new(1)).with_aging(Duration::from_millis(1));
+        queue.push(WorkStealingTask::new(
+            0,
+            prioritized_spec("old", 0),
+            "k".into(),
+        ));
+        std::thread::sleep(Duration::from_millis(20));
+        queue.push(WorkStealingTask::new(
+            1,
+            prioritized_spec("fresh", 5),
+            "k".into(),
+        ));
+
+        assert_eq!(queue.pop().unwrap().spec.brick_name, "old");
+
+        let strict = WorkerQueue::new(WorkerId::new(1));
+        strict.push(WorkStealingTask::new(
+            0,
+            prioritized_spec("old", 0),
+            "k".into(),
+        ));
+        strict.push(WorkStealingTask::new(
+            1,
+            prioritized_spec("fresh", 5),
+            "k".into(),
+        ));
+        assert_eq!(strict.pop().unwrap().spec.brick_name, "fresh");
+    }
+
+    #[test]
+    fn test_scheduler_aging_is_opt_in() {
+        let first = |scheduler: WorkStealingScheduler| {
+            scheduler.register_worker(WorkerId::new(1));
+            scheduler.submit(prioritized_spec("old", 0), "k".into());
+            std::thread::sleep(Duration::from_millis(20));
+            scheduler.submit(prioritized_spec("fresh", 5), "k".into());
+            scheduler
+                .get_work(WorkerId::new(1))
+                .unwrap()
+                .spec
+                .brick_name
+        };
+
+        let tracker = || Arc::new(BrickDataTracker::new());
+        assert_eq!(first(WorkStealingScheduler::new(tracker())), "fresh");
+        assert_eq!(
+            first(WorkStealingScheduler::new(tracker()).with_aging(Duration::from_millis(1))),
+            "old"
+        );
+    }
+
+    #[test]
+    fn test_task_spec_priority_propagates() {
+        let brick = DistributedBrick::new(TestBrick { name: "Urgent" }).with_priority(7);
+        let spec = brick.to_task_spec();
+        assert_eq!(spec.priority, 7);
+        assert_eq!(WorkStealingTask::new(0, spec, "k".into()).priority, 7);
+    }
+
+    // ========================================================================
+    // Cost-based backend selection tests
+    // ========================================================================
+
+    fn test_cost_model() -> CostModel {
+        CostModel {
+            cpu: BackendCost::new(Duration::ZERO, 1.0, None),
+            simd: BackendCost::new(Duration::from_micros(2), 0.5, None),
+            gpu: BackendCost::new(Duration::from_micros(50), 0.001, Some(20e9)),
+            remote: BackendCost::new(Duration::from_millis(5), 0.001, Some(1e8)),
+        }
+    }
+
+    #[test]
+    fn test_invalid_cost_is_infinitely_expensive() {
+        let mut model = test_cost_model();
+        model.gpu.transfer_bytes_per_sec = Some(0.0);
+        model.simd.ns_per_op = f64::NAN;
+        model.remote.ns_per_op = -1.0;
+
+        assert_eq!(model.estimate(Backend::Gpu, 1024, 1, &[]).1, Duration::MAX);
+        assert_eq!(model.estimate(Backend::Simd, 1024, 1, &[]).0, Duration::MAX);
+        assert_eq!(
+            model.estimate(Backend::Remote, 1024, 1, &[]).0,
+            Duration::MAX
+        );
+
+        let decision =
+            BackendSelector::new()
+                .with_cost_model(model)
+                .choose(10_000_000, 1, &[], true);
+        assert_eq!(decision.backend, Backend::Cpu);
+        assert_eq!(decision.candidates.last().unwrap().1, Duration::MAX);
+    }
+
+    #[test]
+    fn test_auto_selects_cpu_for_tiny_task() {
+        let selector = BackendSelector::new().with_cost_model(test_cost_model());
+        let decision = selector.choose(64, 1, &[], true);
+
+        assert_eq!(decision.backend, Backend::Cpu);
+        assert_eq!(decision.transfer, Duration::ZERO);
+        assert!(decision.rationale.starts_with("Cpu for 64 elements"));
+        assert!(decision.rationale.contains("beating Simd"));
+        assert_eq!(decision.candidates.len(), 3);
+        assert_eq!(decision.candidates.last().unwrap().0, Backend::Gpu);
+    }
+
+    #[test]
+    fn test_auto_selects_gpu_for_large_task() {
+        let selector = BackendSelector::new().with_cost_model(test_cost_model());
+        let decision = selector.choose(4_000_000, 1, &[], true);
+
+        assert_eq!(decision.backend, Backend::Gpu);
+        assert!(decision.transfer > Duration::ZERO);
+        assert!(decision.rationale.starts_with("Gpu for 4000000 elements"));
+        assert!(decision.rationale.contains("transfer"));
+        assert!(decision.rationale.contains("beating Simd"));
+
+        // Without a GPU the same task falls back to SIMD
+        let fallback = selector.choose(4_000_000, 1, &[], false);
+        assert_eq!(fallback.backend, Backend::Simd);
+    }
+
+    #[test]
+    fn test_auto_dependency_transfer_penalizes_gpu() {
+        let tracker = BrickDataTracker::new();
+        tracker.track_data("weights", WorkerId::new(1), 1_000_000_000);
+        let spec = TaskSpec {
+            brick_name: "Big".into(),
+            backend: Backend::Cpu,
+            data_dependencies: vec!["weights".into()],
+            preferred_worker: None,
+            priority: 0,
+        };
+
+        let selector = BackendSelector::new().with_cost_model(test_cost_model());
+        let decision = selector.choose_for_task(&spec, 4_000_000, &tracker, true);
+        // 1 GB of weights over the bus outweighs the GPU's compute advantage
+        assert_eq!(decision.backend, Backend::Simd);
+    }
+
+    #[test]
+    fn test_executor_exposes_decision_in_metrics() {
+        let executor = MultiBrickExecutor::new(Arc::new(BrickDataTracker::new()))
+            .with_selector(BackendSelector::auto())
+            .with_gpu_available(false);
+        let brick = TestBrick { name: "Tiny" };
+
+        let output = executor
+            .execute(&brick, BrickInput::new(vec![1.0; 8], vec![8]))
+            .unwrap();
+        let decision = output.metrics.decision.unwrap();
+        assert_eq!(decision.backend, Backend::Cpu);
+        assert_eq!(output.metrics.backend, Backend::Cpu);
+
+        let manual = MultiBrickExecutor::new(Arc::new(BrickDataTracker::new()))
+            .execute(&brick, BrickInput::new(vec![1.0; 8], vec![8]))
+            .unwrap();
+        assert!(manual.metrics.decision.is_none());
+    }
 }
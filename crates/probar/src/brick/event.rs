@@ -57,6 +57,8 @@ pub enum EventType {
     TouchEnd,
     /// Touch move
     TouchMove,
+    /// Mouse wheel
+    Wheel,
     /// Custom event
     Custom(&'static str),
 }
@@ -84,9 +86,21 @@ impl EventType {
             Self::TouchStart => "touchstart",
             Self::TouchEnd => "touchend",
             Self::TouchMove => "touchmove",
+            Self::Wheel => "wheel",
             Self::Custom(name) => name,
         }
     }
+
+    /// Whether listeners for this event can block scrolling
+    ///
+    /// Bindings for these events default to `{ passive: true }`.
+    #[must_use]
+    pub const fn is_scroll_blocking(&self) -> bool {
+        matches!(
+            self,
+            Self::Scroll | Self::TouchStart | Self::TouchMove | Self::Wheel
+        )
+    }
 }
 
 /// Event handler action
@@ -226,6 +240,24 @@ impl EventHandler {
         }
     }
 
+    /// Whether this handler (or any nested handler) calls `preventDefault`
+    #[must_use]
+    pub fn calls_prevent_default(&self) -> bool {
+        match self {
+            Self::PreventDefault => true,
+            Self::Chain(handlers) => handlers.iter().any(Self::calls_prevent_default),
+            Self::If {
+                then, otherwise, ..
+            } => {
+                then.calls_prevent_default()
+                    || otherwise
+                        .as_ref()
+                        .is_some_and(|h| h.calls_prevent_default())
+            }
+            _ => false,
+        }
+    }
+
     /// Generate JavaScript code for this handler
     #[must_use]
     pub fn to_js(&self, indent: usize) -> String {
@@ -331,15 +363,20 @@ pub struct EventBinding {
 
 impl EventBinding {
     /// Create a new event binding
+    ///
+    /// Scroll-blocking events (scroll, touchstart, touchmove, wheel) default
+    /// to passive unless the handler calls `preventDefault`, which a passive
+    /// listener would silently ignore.
     #[must_use]
     pub fn new(selector: impl Into<String>, event_type: EventType, handler: EventHandler) -> Self {
+        let passive = event_type.is_scroll_blocking() && !handler.calls_prevent_default();
         Self {
             selector: selector.into(),
             event_type,
             handler,
             capture: false,
             once: false,
-            passive: false,
+            passive,
         }
     }
 
@@ -364,12 +401,28 @@ impl EventBinding {
         self
     }
 
+    /// Mark as non-passive (required for handlers that call `preventDefault`)
+    #[must_use]
+    pub fn non_passive(mut self) -> Self {
+        self.passive = false;
+        self
+    }
+
+    /// Whether this binding is passive but its handler calls `preventDefault`
+    #[must_use]
+    pub fn has_passive_conflict(&self) -> bool {
+        self.passive && self.handler.calls_prevent_default()
+    }
+
     /// Generate JavaScript for this binding
     #[must_use]
     pub fn to_js(&self) -> String {
         let handler_js = self.handler.to_js(2);
+        // Browsers may treat scroll-blocking listeners as passive by default,
+        // so opting out must be explicit
+        let explicit_non_passive = !self.passive && self.event_type.is_scroll_blocking();
 
-        let options = if self.capture || self.once || self.passive {
+        let options = if self.capture || self.once || self.passive || explicit_non_passive {
             let mut opts = Vec::new();
             if self.capture {
                 opts.push("capture: true");
@@ -379,6 +432,8 @@ impl EventBinding {
             }
             if self.passive {
                 opts.push("passive: true");
+            } else if explicit_non_passive {
+                opts.push("passive: false");
             }
             format!(", {{ {} }}", opts.join(", "))
         } else {
@@ -455,10 +510,16 @@ impl EventBrick {
         // Window handlers
         for (event_type, handler) in &self.window_handlers {
             let handler_js = handler.to_js(1);
+            let options = if event_type.is_scroll_blocking() {
+                format!(", {{ passive: {} }}", !handler.calls_prevent_default())
+            } else {
+                String::new()
+            };
             js.push_str(&format!(
-                "window.addEventListener('{}', (e) => {{\n{}\n}});\n",
+                "window.addEventListener('{}', (e) => {{\n{}\n}}{});\n",
                 event_type.js_name(),
-                handler_js
+                handler_js,
+                options
             ));
         }
 
@@ -486,14 +547,41 @@ impl Brick for EventBrick {
     }
 
     fn verify(&self) -> BrickVerification {
-        let passed = vec![BrickAssertion::Custom {
+        let mut passed = vec![BrickAssertion::Custom {
             name: "event_bindings_valid".into(),
             validator_id: 10,
         }];
+        let mut failed = Vec::new();
+
+        let assertion = BrickAssertion::Custom {
+            name: "passive_listeners_valid".into(),
+            validator_id: 11,
+        };
+        let conflicts: Vec<_> = self
+            .bindings
+            .iter()
+            .filter(|b| b.has_passive_conflict())
+            .collect();
+        if conflicts.is_empty() {
+            if !self.bindings.is_empty() {
+                passed.push(assertion);
+            }
+        } else {
+            for binding in conflicts {
+                failed.push((
+                    assertion.clone(),
+                    format!(
+                        "Passive '{}' listener on {} calls preventDefault; mark it non_passive()",
+                        binding.event_type.js_name(),
+                        binding.selector
+                    ),
+                ));
+            }
+        }
 
         BrickVerification {
             passed,
-            failed: Vec::new(),
+            failed,
             verification_time: Duration::from_micros(50),
         }
     }
@@ -1109,4 +1197,69 @@ mod tests {
         assert!(js.contains("onlyATrue"));
         assert!(js.contains("aFalse"));
     }
+
+    // ============================================================
+    // Passive listener tests
+    // ============================================================
+
+    #[test]
+    fn test_scroll_binding_is_passive_by_default() {
+        let binding = EventBinding::new(
+            "#feed",
+            EventType::Scroll,
+            EventHandler::call_wasm("on_scroll"),
+        );
+
+        assert!(binding.passive);
+        assert!(binding.to_js().contains("{ passive: true }"));
+        for event_type in [
+            EventType::TouchStart,
+            EventType::TouchMove,
+            EventType::Wheel,
+        ] {
+            assert!(EventBinding::new("#x", event_type, EventHandler::call_wasm("f")).passive);
+        }
+        assert!(!EventBinding::new("#x", EventType::Click, EventHandler::call_wasm("f")).passive);
+    }
+
+    #[test]
+    fn test_prevent_default_binding_is_non_passive() {
+        let handler = EventHandler::chain(vec![
+            EventHandler::PreventDefault,
+            EventHandler::call_wasm("drag"),
+        ]);
+        let binding = EventBinding::new("#canvas", EventType::TouchMove, handler);
+
+        assert!(!binding.passive);
+        assert!(binding.to_js().contains("{ passive: false }"));
+        assert!(EventBrick::new().on_with(binding).verify().is_valid());
+    }
+
+    #[test]
+    fn test_passive_prevent_default_is_flagged() {
+        let binding = EventBinding::new(
+            "#canvas",
+            EventType::Wheel,
+            EventHandler::when("e.ctrlKey", EventHandler::PreventDefault, None),
+        )
+        .passive();
+        assert!(binding.has_passive_conflict());
+
+        let verification = EventBrick::new().on_with(binding).verify();
+        assert!(!verification.is_valid());
+        let (_, reason) = &verification.failed[0];
+        assert!(reason.contains("wheel"));
+        assert!(reason.contains("#canvas"));
+    }
+
+    #[test]
+    fn test_window_scroll_handler_is_passive() {
+        let js = EventBrick::new()
+            .on_window(EventType::Scroll, EventHandler::call_wasm("onScroll"))
+            .on_window(EventType::Wheel, EventHandler::PreventDefault)
+            .to_event_js();
+
+        assert!(js.contains("}, { passive: true });"));
+        assert!(js.contains("}, { passive: false });"));
+    }
 }
@@ -66,46 +66,48 @@ pub mod widget;
 pub mod worker;
 
 // Re-export submodule types
-pub use audio::{AudioBrick, AudioParam, RingBufferConfig};
+pub use audio::{AudioBrick, AudioParam, RingBufferConfig, UnderrunPolicy};
 pub use compute::{
     ComputeBrick, ElementwiseOp, ReduceKind, TensorBinding, TensorType, TileOp, TileStrategy,
 };
 pub use deterministic::{
     BrickHistory, BrickState, DeterministicBrick, DeterministicClock, DeterministicRng,
-    ExecutionTrace, GuardSeverity, GuardViolation, GuardedBrick, InvariantGuard, StateValue,
+    ExecutionTrace, GuardKind, GuardSeverity, GuardViolation, GuardedBrick, InvariantGuard,
+    NonDeterminismReport, StateDivergence, StateValue, TransitionGuard,
 };
 pub use distributed::{
-    Backend, BackendSelector, BrickCoordinator, BrickDataTracker, BrickInput, BrickMessage,
-    BrickOutput, DataLocation, DistributedBrick, ExecutionMetrics, MultiBrickExecutor,
-    SchedulerStats, Subscription, TaskSpec, WorkStealingScheduler, WorkStealingTask, WorkerId,
-    WorkerQueue, WorkerStats,
+    Backend, BackendCost, BackendDecision, BackendSelector, BrickCoordinator, BrickDataTracker,
+    BrickInput, BrickMessage, BrickOutput, CostModel, DataLocation, DistributedBrick,
+    ExecutionMetrics, MultiBrickExecutor, PriorityWaitStats, SchedulerStats, Subscription,
+    TaskSpec, WorkStealingScheduler, WorkStealingTask, WorkerId, WorkerQueue, WorkerStats,
 };
 pub use event::{EventBinding, EventBrick, EventHandler, EventType};
 pub use pipeline::{
-    AuditEntry, BrickPipeline, BrickStage, Checkpoint, PipelineAuditCollector, PipelineContext,
-    PipelineData, PipelineError, PipelineMetadata, PipelineResult, PrivacyTier, StageTrace,
-    ValidationLevel, ValidationMessage, ValidationResult,
+    AuditEntry, BrickPipeline, BrickStage, Checkpoint, Declassification, PipelineAuditCollector,
+    PipelineContext, PipelineData, PipelineError, PipelineMetadata, PipelineResult, PrivacyTier,
+    StageTrace, ValidationLevel, ValidationMessage, ValidationResult,
 };
 pub use tui::{
     AnalyzerBrick, CielabColor, CollectorBrick, CollectorError, PanelBrick, PanelId, PanelState,
     RingBuffer,
 };
 pub use web_sys_gen::{
-    get_base_url, BlobUrl, CustomEventDispatcher, EventDetail, FetchClient, GeneratedWebSys,
-    GenerationMetadata, PerformanceTiming, WebSysError, GENERATION_METADATA,
+    get_base_url, BlobUrl, CustomEventDispatcher, EventDetail, FetchClient, FetchOptions,
+    GeneratedWebSys, GenerationMetadata, PerformanceTiming, WebSysError, GENERATION_METADATA,
 };
 pub use widget::{
-    commands_to_gpu_instances, Canvas, Constraints, CornerRadius, DrawCommand, Event, GpuInstance,
-    LayoutResult, LineCap, LineJoin, Modifiers, RecordingCanvas, Rect, RenderMetrics, Size,
-    StrokeStyle, TextStyle, Transform2D, Widget, WidgetColor, WidgetExt, WidgetMouseButton,
-    WidgetPoint,
+    batch_draw_commands, commands_to_gpu_instances, AlignItems, BlendMode, Canvas, ChildLayout,
+    Constraints, CornerRadius, DrawCommand, Event, FlexDirection, GpuBatch, GpuInstance,
+    JustifyContent, LayoutMode, LayoutResult, LineCap, LineJoin, Material, Modifiers,
+    RecordingCanvas, Rect, RenderMetrics, ShaderKind, Size, StrokeStyle, TextStyle, Transform2D,
+    Widget, WidgetColor, WidgetExt, WidgetMouseButton, WidgetPoint,
 };
 pub use worker::{
     BrickWorkerMessage, BrickWorkerMessageDirection, FieldType, MessageField, WorkerBrick,
-    WorkerTransition,
+    WorkerMessageError, WorkerTransition,
 };
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Brick assertion that must be verified at runtime.
 ///
@@ -271,6 +273,99 @@ pub enum BrickPhase {
     Paint,
 }
 
+/// Measured time spent in each rendering phase
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseTimings {
+    /// Time spent in the measure phase
+    pub measure: Duration,
+    /// Time spent in the layout phase
+    pub layout: Duration,
+    /// Time spent in the paint phase
+    pub paint: Duration,
+}
+
+impl PhaseTimings {
+    /// Run a brick's measure, layout, and paint phases, timing each
+    ///
+    /// Returns the painted HTML and the phase timings.
+    pub fn record<B: Brick + ?Sized>(brick: &B) -> (String, Self) {
+        let start = Instant::now();
+        brick.measure_phase();
+        let measure = start.elapsed();
+
+        let start = Instant::now();
+        brick.layout_phase();
+        let layout = start.elapsed();
+
+        let start = Instant::now();
+        let html = brick.paint_phase();
+        let paint = start.elapsed();
+
+        (
+            html,
+            Self {
+                measure,
+                layout,
+                paint,
+            },
+        )
+    }
+
+    /// Total time across all phases
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.measure + self.layout + self.paint
+    }
+
+    /// Time spent in a specific phase
+    #[must_use]
+    pub const fn get(&self, phase: BrickPhase) -> Duration {
+        match phase {
+            BrickPhase::Measure => self.measure,
+            BrickPhase::Layout => self.layout,
+            BrickPhase::Paint => self.paint,
+        }
+    }
+
+    /// Phase responsible for exceeding the budget, if any
+    ///
+    /// Picks the phase furthest over its own limit. If every phase is within
+    /// its limit but the total is not, the slowest phase is blamed.
+    #[must_use]
+    pub fn overrun(&self, budget: &BrickBudget) -> Option<BrickPhase> {
+        let phases = [
+            (BrickPhase::Measure, budget.measure_ms),
+            (BrickPhase::Layout, budget.layout_ms),
+            (BrickPhase::Paint, budget.paint_ms),
+        ];
+
+        let worst = phases
+            .iter()
+            .map(|&(phase, limit_ms)| {
+                let excess = self
+                    .get(phase)
+                    .saturating_sub(Duration::from_millis(u64::from(limit_ms)));
+                (phase, excess)
+            })
+            .filter(|(_, excess)| !excess.is_zero())
+            .max_by_key(|(_, excess)| *excess)
+            .map(|(phase, _)| phase);
+
+        if worst.is_some() {
+            return worst;
+        }
+
+        if self.total() > budget.as_duration() {
+            phases
+                .iter()
+                .map(|&(phase, _)| phase)
+                .max_by_key(|&phase| self.get(phase))
+        } else {
+            None
+        }
+    }
+}
+
 /// Core Brick trait - the foundation of the Brick Architecture.
 ///
 /// All UI components implement this trait. The trait defines:
@@ -322,6 +417,41 @@ pub trait Brick: Send + Sync {
     fn can_render(&self) -> bool {
         self.verify().is_valid()
     }
+
+    /// Measure phase hook (compute intrinsic size)
+    fn measure_phase(&self) {}
+
+    /// Layout phase hook (position children)
+    fn layout_phase(&self) {}
+
+    /// Paint phase hook (generate output), defaults to [`Brick::to_html`]
+    fn paint_phase(&self) -> String {
+        self.to_html()
+    }
+
+    /// Verify assertions and run the render phases against the budget
+    ///
+    /// On success returns the verification with the measured phase timings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrickError::BudgetExceeded`] naming the phase that overran.
+    fn verify_timed(&self) -> BrickResult<(BrickVerification, PhaseTimings)> {
+        let verification = self.verify();
+        let (_, timings) = PhaseTimings::record(self);
+        let budget = self.budget();
+
+        if let Some(phase) = timings.overrun(&budget) {
+            return Err(BrickError::BudgetExceeded(BudgetViolation {
+                brick_name: self.brick_name().to_string(),
+                budget,
+                actual: timings.total(),
+                phase: Some(phase),
+            }));
+        }
+
+        Ok((verification, timings))
+    }
 }
 
 /// Yuan Gate: Zero-swallow error handling for bricks
@@ -362,6 +492,34 @@ pub enum BrickError {
         /// Reason for failure
         reason: String,
     },
+
+    /// Tensor shapes are not broadcast-compatible
+    ShapeMismatch {
+        /// Operation being validated
+        op: String,
+        /// Left-hand tensor name
+        lhs: String,
+        /// Right-hand tensor name
+        rhs: String,
+        /// Axis of the broadcast result where extents disagree
+        axis: usize,
+        /// Left-hand extent at `axis`
+        lhs_extent: u32,
+        /// Right-hand extent at `axis`
+        rhs_extent: u32,
+    },
+
+    /// Reduction axis is out of range for the tensor's rank
+    InvalidAxis {
+        /// Operation being validated
+        op: String,
+        /// Tensor being reduced
+        tensor: String,
+        /// Requested axis
+        axis: usize,
+        /// Tensor rank
+        rank: usize,
+    },
 }
 
 impl std::fmt::Display for BrickError {
@@ -386,6 +544,30 @@ impl std::fmt::Display for BrickError {
             Self::HtmlGenerationFailed { reason } => {
                 write!(f, "HTML generation failed: {reason}")
             }
+            Self::ShapeMismatch {
+                op,
+                lhs,
+                rhs,
+                axis,
+                lhs_extent,
+                rhs_extent,
+            } => {
+                write!(
+                    f,
+                    "Shape mismatch in {op} at axis {axis}: {lhs} has {lhs_extent}, {rhs} has {rhs_extent}"
+                )
+            }
+            Self::InvalidAxis {
+                op,
+                tensor,
+                axis,
+                rank,
+            } => {
+                write!(
+                    f,
+                    "Invalid axis in {op}: axis {axis} out of range for {tensor} (rank {rank})"
+                )
+            }
         }
     }
 }
@@ -481,6 +663,101 @@ mod tests {
         assert!(result.score() < 1.0);
     }
 
+    struct SlowPaintBrick {
+        paint_delay: Duration,
+    }
+
+    impl Brick for SlowPaintBrick {
+        fn brick_name(&self) -> &'static str {
+            "SlowPaintBrick"
+        }
+
+        fn assertions(&self) -> &[BrickAssertion] {
+            &[]
+        }
+
+        fn budget(&self) -> BrickBudget {
+            BrickBudget::new(50, 50, 5)
+        }
+
+        fn verify(&self) -> BrickVerification {
+            BrickVerification {
+                passed: Vec::new(),
+                failed: Vec::new(),
+                verification_time: Duration::ZERO,
+            }
+        }
+
+        fn paint_phase(&self) -> String {
+            std::thread::sleep(self.paint_delay);
+            self.to_html()
+        }
+
+        fn to_html(&self) -> String {
+            "<div></div>".into()
+        }
+
+        fn to_css(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_verify_timed_attributes_paint_overrun() {
+        let brick = SlowPaintBrick {
+            paint_delay: Duration::from_millis(20),
+        };
+
+        match brick.verify_timed() {
+            Err(BrickError::BudgetExceeded(violation)) => {
+                assert_eq!(violation.brick_name, "SlowPaintBrick");
+                assert_eq!(violation.phase, Some(BrickPhase::Paint));
+                assert!(violation.actual >= Duration::from_millis(20));
+            }
+            other => panic!("expected paint budget violation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verify_timed_records_phase_timings() {
+        let brick = SlowPaintBrick {
+            paint_delay: Duration::ZERO,
+        };
+
+        let (verification, timings) = brick.verify_timed().unwrap();
+        assert!(verification.is_valid());
+        assert!(timings.total() < brick.budget().as_duration());
+    }
+
+    #[test]
+    fn test_phase_overrun_picks_largest_excess() {
+        let timings = PhaseTimings {
+            measure: Duration::from_millis(7),
+            layout: Duration::from_millis(1),
+            paint: Duration::from_millis(15),
+        };
+        let budget = BrickBudget::new(5, 5, 5);
+        assert_eq!(timings.overrun(&budget), Some(BrickPhase::Paint));
+        assert_eq!(timings.get(BrickPhase::Measure), Duration::from_millis(7));
+    }
+
+    #[test]
+    fn test_phase_overrun_total_only_blames_slowest() {
+        let timings = PhaseTimings {
+            measure: Duration::from_millis(4),
+            layout: Duration::from_millis(6),
+            paint: Duration::from_millis(3),
+        };
+        let budget = BrickBudget {
+            measure_ms: 10,
+            layout_ms: 10,
+            paint_ms: 10,
+            total_ms: 12,
+        };
+        assert_eq!(timings.overrun(&budget), Some(BrickPhase::Layout));
+        assert_eq!(timings.overrun(&BrickBudget::new(10, 10, 10)), None);
+    }
+
     #[test]
     fn test_budget_uniform() {
         let budget = BrickBudget::uniform(30);
@@ -140,6 +140,15 @@ pub struct PipelineMetadata {
     pub started_at: Option<Instant>,
     /// Custom tags
     pub tags: HashMap<String, String>,
+    /// Definition of the pipeline that produced this context
+    ///
+    /// Stamped by [`BrickPipeline::run`] and checked by
+    /// [`BrickPipeline::resume_from`].
+    pub definition: Option<String>,
+    /// Privacy tier of the data currently in the context
+    ///
+    /// Initialized from the pipeline tier and updated after each stage.
+    pub privacy_tier: Option<PrivacyTier>,
 }
 
 impl PipelineMetadata {
@@ -150,6 +159,8 @@ impl PipelineMetadata {
             run_id: format!("run-{}", uuid_v4()),
             started_at: None,
             tags: HashMap::new(),
+            definition: None,
+            privacy_tier: None,
         }
     }
 
@@ -189,6 +200,23 @@ pub enum PrivacyTier {
     Standard,
 }
 
+impl PrivacyTier {
+    /// Strictness rank (higher is more private)
+    const fn rank(self) -> u8 {
+        match self {
+            Self::Sovereign => 2,
+            Self::Private => 1,
+            Self::Standard => 0,
+        }
+    }
+
+    /// Whether this tier is strictly more private than `other`
+    #[must_use]
+    pub const fn is_more_private_than(self, other: Self) -> bool {
+        self.rank() > other.rank()
+    }
+}
+
 impl Default for PrivacyTier {
     fn default() -> Self {
         Self::Standard
@@ -320,6 +348,14 @@ pub trait BrickStage: Brick + Send + Sync {
     fn output_names(&self) -> &[&str] {
         &[]
     }
+
+    /// Privacy tier of the data this stage produces from `input`
+    ///
+    /// Defaults to preserving the input tier. Returning a less private tier
+    /// requires a declassification (see [`BrickPipeline::declassify`]).
+    fn output_privacy(&self, input: PrivacyTier) -> PrivacyTier {
+        input
+    }
 }
 
 /// Audit entry for pipeline execution
@@ -339,10 +375,24 @@ pub struct AuditEntry {
     pub outputs: Vec<String>,
 }
 
+/// Explicit permission for a stage to lower the privacy tier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Declassification {
+    /// Stage name
+    pub stage: String,
+    /// Tier of the stage input
+    pub from: PrivacyTier,
+    /// Tier the stage output may be released at
+    pub to: PrivacyTier,
+    /// Why the downgrade is acceptable
+    pub justification: String,
+}
+
 /// Audit trail collector
 #[derive(Debug, Default)]
 pub struct PipelineAuditCollector {
     entries: Vec<AuditEntry>,
+    declassifications: Vec<Declassification>,
 }
 
 impl PipelineAuditCollector {
@@ -351,6 +401,7 @@ impl PipelineAuditCollector {
     pub fn new() -> Self {
         Self {
             entries: Vec::new(),
+            declassifications: Vec::new(),
         }
     }
 
@@ -366,11 +417,63 @@ impl PipelineAuditCollector {
         });
     }
 
+    /// Record a declassification allowing `stage` to lower `from` to `to`
+    pub fn declassify(
+        &mut self,
+        stage: &str,
+        from: PrivacyTier,
+        to: PrivacyTier,
+        justification: impl Into<String>,
+    ) {
+        self.declassifications.push(Declassification {
+            stage: stage.to_string(),
+            from,
+            to,
+            justification: justification.into(),
+        });
+    }
+
+    /// Check that `stage` may move data from `input` to `output` tier
+    ///
+    /// Downgrades (output less private than input) are forbidden unless a
+    /// matching [`Declassification`] has been recorded.
+    pub fn check_flow(
+        &self,
+        stage: &str,
+        input: PrivacyTier,
+        output: PrivacyTier,
+    ) -> PipelineResult<()> {
+        if !input.is_more_private_than(output) {
+            return Ok(());
+        }
+
+        let declassified = self
+            .declassifications
+            .iter()
+            .any(|d| d.stage == stage && d.from == input && d.to == output);
+        if declassified {
+            Ok(())
+        } else {
+            Err(PipelineError::PrivacyViolation {
+                tier: input,
+                reason: format!(
+                    "stage '{}' downgrades {:?} data to {:?} without declassification",
+                    stage, input, output
+                ),
+            })
+        }
+    }
+
     /// Get all entries
     pub fn entries(&self) -> &[AuditEntry] {
         &self.entries
     }
 
+    /// Get recorded declassifications
+    pub fn declassifications(&self) -> &[Declassification] {
+        &self.declassifications
+    }
+
     /// Get total execution time
     pub fn total_duration(&self) -> Duration {
         self.entries.iter().map(|e| e.duration).sum()
@@ -432,6 +535,22 @@ impl BrickPipeline {
         self
     }
 
+    /// Allow `stage` to lower the data privacy tier from `from` to `to`
+    ///
+    /// Records a [`Declassification`] alongside the audit trail.
+    #[must_use]
+    pub fn declassify(
+        mut self,
+        stage: &str,
+        from: PrivacyTier,
+        to: PrivacyTier,
+        justification: impl Into<String>,
+    ) -> Self {
+        self.audit_collector
+            .declassify(stage, from, to, justification);
+        self
+    }
+
     /// Enable checkpointing
     #[must_use]
     pub fn with_checkpointing(mut self, interval: Duration) -> Self {
@@ -440,19 +559,24 @@ impl BrickPipeline {
     }
 
     /// Run the pipeline
+    ///
+    /// Always starts at the first stage with `input`; a stored checkpoint is
+    /// only used by [`Self::resume_from`].
     pub fn run(&mut self, input: PipelineContext) -> PipelineResult<PipelineContext> {
         let mut ctx = input;
         ctx.metadata.started_at = Some(Instant::now());
+        self.execute_from(ctx, 0)
+    }
 
-        let start_index = self
-            .last_checkpoint
-            .as_ref()
-            .map(|c| c.stage_index)
-            .unwrap_or(0);
-
-        // Restore from checkpoint if available
-        if let Some(checkpoint) = &self.last_checkpoint {
-            ctx = checkpoint.context.clone();
+    /// Execute stages from `start_index` onwards with the given context
+    fn execute_from(
+        &mut self,
+        mut ctx: PipelineContext,
+        start_index: usize,
+    ) -> PipelineResult<PipelineContext> {
+        ctx.metadata.definition = Some(self.definition());
+        if ctx.metadata.privacy_tier.is_none() {
+            ctx.metadata.privacy_tier = Some(self.privacy_tier);
         }
 
         let mut last_checkpoint_time = Instant::now();
@@ -478,9 +602,20 @@ impl BrickPipeline {
             }
 
             // Execute stage (clone ctx for error recovery)
+            let input_tier = ctx.metadata.privacy_tier.unwrap_or(self.privacy_tier);
+            let output_tier = stage.output_privacy(input_tier);
             let start = Instant::now();
             let ctx_for_error = ctx.clone();
-            let result = stage.execute(ctx);
+            // Privacy tier may only be lowered by an explicit declassification,
+            // checked before the stage sees any data
+            let result = self
+                .audit_collector
+                .check_flow(stage_name, input_tier, output_tier)
+                .and_then(|()| stage.execute(ctx))
+                .map(|mut new_ctx| {
+                    new_ctx.metadata.privacy_tier = Some(output_tier);
+                    new_ctx
+                });
             let duration = start.elapsed();
 
             match result {
@@ -520,6 +655,18 @@ impl BrickPipeline {
                         error: Some(e.to_string()),
                     });
 
+                    // Checkpoint the failed stage so it can be resumed
+                    if self.checkpoint_interval.is_some() {
+                        self.last_checkpoint = Some(Checkpoint {
+                            stage_index: i,
+                            context: error_ctx,
+                            created_at: Instant::now(),
+                        });
+                    }
+
+                    if let PipelineError::PrivacyViolation { .. } = e {
+                        return Err(e);
+                    }
                     return Err(PipelineError::ExecutionFailed {
                         stage: stage_name.to_string(),
                         reason: e.to_string(),
@@ -534,6 +681,61 @@ impl BrickPipeline {
         Ok(ctx)
     }
 
+    /// Resume execution from a checkpoint
+    ///
+    /// Restores the checkpoint context and executes only the stages from
+    /// `checkpoint.stage_index` onwards. The checkpoint must have been taken
+    /// by a pipeline with the same [`definition`](Self::definition).
+    pub fn resume_from(&mut self, checkpoint: Checkpoint) -> PipelineResult<PipelineContext> {
+        let expected = self.definition();
+        match checkpoint.context.metadata.definition.as_deref() {
+            Some(definition) if definition == expected => {}
+            Some(definition) => {
+                return Err(PipelineError::CheckpointFailed {
+                    reason: format!(
+                        "checkpoint taken by '{}', pipeline is '{}'",
+                        definition, expected
+                    ),
+                });
+            }
+            None => {
+                return Err(PipelineError::CheckpointFailed {
+                    reason: "checkpoint has no pipeline definition".into(),
+                });
+            }
+        }
+
+        if checkpoint.stage_index > self.stages.len() {
+            return Err(PipelineError::CheckpointFailed {
+                reason: format!(
+                    "stage index {} out of range for {} stages",
+                    checkpoint.stage_index,
+                    self.stages.len()
+                ),
+            });
+        }
+
+        self.execute_from(checkpoint.context, checkpoint.stage_index)
+    }
+
+    /// Get the most recent checkpoint, if any
+    #[must_use]
+    pub fn last_checkpoint(&self) -> Option<&Checkpoint> {
+        self.last_checkpoint.as_ref()
+    }
+
+    /// Get the pipeline definition (name and ordered stage names)
+    #[must_use]
+    pub fn definition(&self) -> String {
+        let stages = self
+            .stages
+            .iter()
+            .map(|s| s.brick_name())
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("{}[{}]", self.name, stages)
+    }
+
     /// Get the pipeline name
     #[must_use]
     pub fn name(&self) -> &str {
@@ -551,6 +753,11 @@ impl BrickPipeline {
         self.audit_collector.entries()
     }
 
+    /// Get declassifications granted to stages
+    pub fn declassifications(&self) -> &[Declassification] {
+        self.audit_collector.declassifications()
+    }
+
     /// Get privacy tier
     #[must_use]
     pub fn privacy_tier(&self) -> PrivacyTier {
@@ -2163,17 +2370,19 @@ mod tests {
                 should_fail: false,
             });
 
-        // Manually set a checkpoint at stage index 1 (skip first stage)
-        let checkpoint_ctx = PipelineContext::from_input("checkpoint_data", PipelineData::Int(42));
-        pipeline.last_checkpoint = Some(Checkpoint {
-            stage_index: 1,
-            context: checkpoint_ctx,
-            created_at: Instant::now(),
-        });
-
-        // Run with fresh context - should restore from checkpoint
-        let fresh_ctx = PipelineContext::new();
-        let result = pipeline.run(fresh_ctx).unwrap();
+        // Manually build a checkpoint at stage index 1 (skip first stage)
+        let mut checkpoint_ctx =
+            PipelineContext::from_input("checkpoint_data", PipelineData::Int(42));
+        checkpoint_ctx.metadata.definition = Some(pipeline.definition());
+
+        // Resume - should restore from checkpoint
+        let result = pipeline
+            .resume_from(Checkpoint {
+                stage_index: 1,
+                context: checkpoint_ctx,
+                created_at: Instant::now(),
+            })
+            .unwrap();
 
         // Should have stage2 and stage3 outputs (stage1 skipped)
         assert!(result.get("stage2_output").is_some());
@@ -2201,20 +2410,34 @@ mod tests {
         let mut checkpoint_ctx = PipelineContext::new();
         checkpoint_ctx.set("restored_key", PipelineData::Text("restored_value".into()));
 
+        checkpoint_ctx.metadata.definition = Some(pipeline.definition());
         pipeline.last_checkpoint = Some(Checkpoint {
             stage_index: 0,
             context: checkpoint_ctx,
             created_at: Instant::now(),
         });
 
-        // Run should use checkpoint context
+        // Run always starts from its input, never from a stored checkpoint
         let input_ctx =
             PipelineContext::from_input("input_key", PipelineData::Text("input_value".into()));
         let result = pipeline.run(input_ctx).unwrap();
+        assert!(result.get("input_key").is_some());
+        assert!(result.get("restored_key").is_none());
+
+        // Resume should use the checkpoint context
+        let mut checkpoint_ctx = PipelineContext::new();
+        checkpoint_ctx.set("restored_key", PipelineData::Text("restored_value".into()));
+        checkpoint_ctx.metadata.definition = Some(pipeline.definition());
+        let result = pipeline
+            .resume_from(Checkpoint {
+                stage_index: 0,
+                context: checkpoint_ctx,
+                created_at: Instant::now(),
+            })
+            .unwrap();
 
         // Restored context should have the checkpoint data
         assert!(result.get("restored_key").is_some());
-        // Input context's data should NOT be present (checkpoint overwrites)
         assert!(result.get("input_key").is_none());
     }
 
@@ -2806,15 +3029,16 @@ mod tests {
                 should_fail: false,
             });
 
-        // Set checkpoint to skip first stage
-        pipeline.last_checkpoint = Some(Checkpoint {
-            stage_index: 1,
-            context: PipelineContext::from_input("from_checkpoint", PipelineData::Bool(true)),
-            created_at: Instant::now(),
-        });
-
-        let ctx = PipelineContext::new();
-        let result = pipeline.run(ctx).unwrap();
+        // Resume from a checkpoint that skips the first stage
+        let mut context = PipelineContext::from_input("from_checkpoint", PipelineData::Bool(true));
+        context.metadata.definition = Some(pipeline.definition());
+        let result = pipeline
+            .resume_from(Checkpoint {
+                stage_index: 1,
+                context,
+                created_at: Instant::now(),
+            })
+            .unwrap();
 
         // skipped_output should NOT be present
         assert!(result.get("skipped_output").is_none());
@@ -2836,15 +3060,16 @@ mod tests {
                 should_fail: false,
             });
 
-        // Set checkpoint to skip all stages
-        pipeline.last_checkpoint = Some(Checkpoint {
-            stage_index: 2, // Skip all
-            context: PipelineContext::from_input("final_data", PipelineData::Int(999)),
-            created_at: Instant::now(),
-        });
-
-        let ctx = PipelineContext::new();
-        let result = pipeline.run(ctx).unwrap();
+        // Resume from a checkpoint that skips all stages
+        let mut context = PipelineContext::from_input("final_data", PipelineData::Int(999));
+        context.metadata.definition = Some(pipeline.definition());
+        let result = pipeline
+            .resume_from(Checkpoint {
+                stage_index: 2, // Skip all
+                context,
+                created_at: Instant::now(),
+            })
+            .unwrap();
 
         // No stage outputs should be present
         assert!(result.get("s1_output").is_none());
@@ -3158,4 +3383,396 @@ mod tests {
 
         assert_eq!(collector.total_duration(), Duration::from_secs(5));
     }
+
+    // ============================================================
+    // Checkpoint Resume Tests
+    // ============================================================
+
+    mod resume_tests {
+        use super::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        /// A stage that counts its executions
+        struct CountingStage {
+            name: &'static str,
+            should_fail: bool,
+            runs: Arc<AtomicUsize>,
+        }
+
+        impl Brick for CountingStage {
+            fn brick_name(&self) -> &'static str {
+                self.name
+            }
+
+            fn assertions(&self) -> &[BrickAssertion] {
+                &[]
+            }
+
+            fn budget(&self) -> BrickBudget {
+                BrickBudget::uniform(100)
+            }
+
+            fn verify(&self) -> BrickVerification {
+                BrickVerification {
+                    passed: vec![],
+                    failed: vec![],
+                    verification_time: Duration::from_micros(10),
+                }
+            }
+
+            fn to_html(&self) -> String {
+                String::new()
+            }
+
+            fn to_css(&self) -> String {
+                String::new()
+            }
+        }
+
+        impl BrickStage for CountingStage {
+            fn execute(&self, mut ctx: PipelineContext) -> PipelineResult<PipelineContext> {
+                self.runs.fetch_add(1, Ordering::SeqCst);
+                if self.should_fail {
+                    return Err(PipelineError::ExecutionFailed {
+                        stage: self.name.to_string(),
+                        reason: "Test failure".into(),
+                    });
+                }
+                ctx.set(
+                    format!("{}_output", self.name),
+                    PipelineData::Text("done".into()),
+                );
+                Ok(ctx)
+            }
+
+            fn validate(&self, _ctx: &PipelineContext) -> ValidationResult {
+                ValidationResult::ok()
+            }
+        }
+
+        const NAMES: [&str; 6] = ["s0", "s1", "s2", "s3", "s4", "s5"];
+
+        fn build(failing: Option<usize>, counters: &[Arc<AtomicUsize>]) -> BrickPipeline {
+            let mut pipeline = BrickPipeline::new("resume").with_checkpointing(Duration::ZERO);
+            for (i, &name) in NAMES.iter().enumerate() {
+                pipeline = pipeline.stage(CountingStage {
+                    name,
+                    should_fail: failing == Some(i),
+                    runs: Arc::clone(&counters[i]),
+                });
+            }
+            pipeline
+        }
+
+        fn counters() -> Vec<Arc<AtomicUsize>> {
+            (0..NAMES.len())
+                .map(|_| Arc::new(AtomicUsize::new(0)))
+                .collect()
+        }
+
+        #[test]
+        fn test_definition_lists_stages() {
+            let pipeline = build(None, &counters());
+            assert_eq!(pipeline.definition(), "resume[s0,s1,s2,s3,s4,s5]");
+        }
+
+        #[test]
+        fn test_failure_saves_checkpoint_at_failed_stage() {
+            let runs = counters();
+            let mut pipeline = build(Some(3), &runs);
+
+            let result = pipeline.run(PipelineContext::new());
+            assert!(matches!(
+                result,
+                Err(PipelineError::ExecutionFailed { ref stage, .. }) if stage == "s3"
+            ));
+
+            let checkpoint = pipeline.last_checkpoint().unwrap();
+            assert_eq!(checkpoint.stage_index, 3);
+            assert!(checkpoint.context.get("s2_output").is_some());
+            assert!(checkpoint.context.get("s3_output").is_none());
+        }
+
+        #[test]
+        fn test_failure_without_checkpointing_saves_nothing() {
+            let mut pipeline = BrickPipeline::new("no-checkpoint").stage(TestStage {
+                name: "s0",
+                should_fail: true,
+            });
+
+            assert!(pipeline.run(PipelineContext::new()).is_err());
+            assert!(pipeline.last_checkpoint().is_none());
+        }
+
+        #[test]
+        fn test_resume_runs_only_downstream_stages() {
+            let runs = counters();
+            let mut failing = build(Some(3), &runs);
+            assert!(failing.run(PipelineContext::new()).is_err());
+            let checkpoint = failing.last_checkpoint().cloned().unwrap();
+
+            // Fix the stage and resume with the same definition
+            let mut fixed = build(None, &runs);
+            let result = fixed.resume_from(checkpoint).unwrap();
+
+            let counts: Vec<_> = runs.iter().map(|r| r.load(Ordering::SeqCst)).collect();
+            assert_eq!(counts, vec![1, 1, 1, 2, 1, 1]);
+            for name in NAMES {
+                assert!(result.get(&format!("{}_output", name)).is_some());
+            }
+            assert!(fixed.last_checkpoint().is_none());
+        }
+
+        #[test]
+        fn test_failure_checkpoint_does_not_hijack_next_run() {
+            let runs = counters();
+            let mut pipeline = build(Some(3), &runs);
+            assert!(pipeline.run(PipelineContext::new()).is_err());
+            assert_eq!(pipeline.last_checkpoint().unwrap().stage_index, 3);
+
+            let input = PipelineContext::from_input("fresh", PipelineData::Bool(true));
+            assert!(pipeline.run(input).is_err());
+
+            // The second run started over from s0 with its own input
+            let counts: Vec<_> = runs.iter().map(|r| r.load(Ordering::SeqCst)).collect();
+            assert_eq!(counts, vec![2, 2, 2, 2, 0, 0]);
+            let checkpoint = pipeline.last_checkpoint().unwrap();
+            assert!(checkpoint.context.get("fresh").is_some());
+        }
+
+        #[test]
+        fn test_resume_rejects_mismatched_definition() {
+            let runs = counters();
+            let mut failing = build(Some(3), &runs);
+            assert!(failing.run(PipelineContext::new()).is_err());
+            let checkpoint = failing.last_checkpoint().cloned().unwrap();
+
+            let mut other = BrickPipeline::new("resume").stage(TestStage {
+                name: "s0",
+                should_fail: false,
+            });
+            let result = other.resume_from(checkpoint);
+
+            assert!(matches!(
+                result,
+                Err(PipelineError::CheckpointFailed { .. })
+            ));
+            assert!(other.last_checkpoint().is_none());
+        }
+
+        #[test]
+        fn test_resume_rejects_checkpoint_without_definition() {
+            let mut pipeline = build(None, &counters());
+            let checkpoint = Checkpoint {
+                stage_index: 1,
+                context: PipelineContext::new(),
+                created_at: Instant::now(),
+            };
+
+            assert!(matches!(
+                pipeline.resume_from(checkpoint),
+                Err(PipelineError::CheckpointFailed { .. })
+            ));
+        }
+
+        #[test]
+        fn test_resume_rejects_out_of_range_stage_index() {
+            let mut pipeline = build(None, &counters());
+            let mut context = PipelineContext::new();
+            context.metadata.definition = Some(pipeline.definition());
+            let checkpoint = Checkpoint {
+                stage_index: NAMES.len() + 1,
+                context,
+                created_at: Instant::now(),
+            };
+
+            assert!(matches!(
+                pipeline.resume_from(checkpoint),
+                Err(PipelineError::CheckpointFailed { .. })
+            ));
+        }
+    }
+
+    // ============================================================
+    // Privacy Flow Tests
+    // ============================================================
+
+    mod privacy_flow_tests {
+        use super::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        /// A stage that releases its output at a fixed tier
+        struct ReleaseStage {
+            name: &'static str,
+            output: PrivacyTier,
+            runs: Arc<AtomicUsize>,
+        }
+
+        fn release(name: &'static str, output: PrivacyTier) -> ReleaseStage {
+            ReleaseStage {
+                name,
+                output,
+                runs: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+
+        impl Brick for ReleaseStage {
+            fn brick_name(&self) -> &'static str {
+                self.name
+            }
+
+            fn assertions(&self) -> &[BrickAssertion] {
+                &[]
+            }
+
+            fn budget(&self) -> BrickBudget {
+                BrickBudget::uniform(100)
+            }
+
+            fn verify(&self) -> BrickVerification {
+                BrickVerification {
+                    passed: vec![],
+                    failed: vec![],
+                    verification_time: Duration::from_micros(10),
+                }
+            }
+
+            fn to_html(&self) -> String {
+                String::new()
+            }
+
+            fn to_css(&self) -> String {
+                String::new()
+            }
+        }
+
+        impl BrickStage for ReleaseStage {
+            fn execute(&self, mut ctx: PipelineContext) -> PipelineResult<PipelineContext> {
+                self.runs.fetch_add(1, Ordering::SeqCst);
+                ctx.set(
+                    format!("{}_output", self.name),
+                    PipelineData::Text("released".into()),
+                );
+                Ok(ctx)
+            }
+
+            fn validate(&self, _ctx: &PipelineContext) -> ValidationResult {
+                ValidationResult::ok()
+            }
+
+            fn output_privacy(&self, _input: PrivacyTier) -> PrivacyTier {
+                self.output
+            }
+        }
+
+        #[test]
+        fn test_tier_ordering() {
+            assert!(PrivacyTier::Sovereign.is_more_private_than(PrivacyTier::Private));
+            assert!(PrivacyTier::Private.is_more_private_than(PrivacyTier::Standard));
+            assert!(!PrivacyTier::Standard.is_more_private_than(PrivacyTier::Private));
+            assert!(!PrivacyTier::Private.is_more_private_than(PrivacyTier::Private));
+        }
+
+        #[test]
+        fn test_check_flow_allows_same_or_stricter_tier() {
+            let collector = PipelineAuditCollector::new();
+            assert!(collector
+                .check_flow("s", PrivacyTier::Private, PrivacyTier::Private)
+                .is_ok());
+            assert!(collector
+                .check_flow("s", PrivacyTier::Standard, PrivacyTier::Sovereign)
+                .is_ok());
+        }
+
+        #[test]
+        fn test_default_stage_preserves_tier() {
+            let mut pipeline = BrickPipeline::new("preserve")
+                .with_privacy(PrivacyTier::Sovereign)
+                .stage(TestStage {
+                    name: "s1",
+                    should_fail: false,
+                });
+
+            let result = pipeline.run(PipelineContext::new()).unwrap();
+            assert_eq!(result.metadata.privacy_tier, Some(PrivacyTier::Sovereign));
+        }
+
+        #[test]
+        fn test_undeclassified_downgrade_fails() {
+            let stage = release("export", PrivacyTier::Standard);
+            let runs = Arc::clone(&stage.runs);
+            let mut pipeline = BrickPipeline::new("leak")
+                .with_privacy(PrivacyTier::Sovereign)
+                .stage(stage);
+
+            let result = pipeline.run(PipelineContext::new());
+
+            match result {
+                Err(PipelineError::PrivacyViolation { tier, reason }) => {
+                    assert_eq!(tier, PrivacyTier::Sovereign);
+                    assert!(reason.contains("export"));
+                }
+                other => panic!("expected privacy violation, got {:?}", other),
+            }
+            let trail = pipeline.audit_trail();
+            assert_eq!(trail.len(), 1);
+            assert!(!trail[0].success);
+            // Checked before execution: the stage never saw the data
+            assert_eq!(runs.load(Ordering::SeqCst), 0);
+        }
+
+        #[test]
+        fn test_declassified_downgrade_passes() {
+            let mut pipeline = BrickPipeline::new("release")
+                .with_privacy(PrivacyTier::Sovereign)
+                .declassify(
+                    "export",
+                    PrivacyTier::Sovereign,
+                    PrivacyTier::Standard,
+                    "aggregated statistics only",
+                )
+                .stage(release("export", PrivacyTier::Standard));
+
+            let result = pipeline.run(PipelineContext::new()).unwrap();
+
+            assert!(result.get("export_output").is_some());
+            assert_eq!(result.metadata.privacy_tier, Some(PrivacyTier::Standard));
+
+            assert_eq!(
+                pipeline.declassifications(),
+                &[Declassification {
+                    stage: "export".into(),
+                    from: PrivacyTier::Sovereign,
+                    to: PrivacyTier::Standard,
+                    justification: "aggregated statistics only".into(),
+                }]
+            );
+            let trail = pipeline.audit_trail();
+            assert_eq!(trail.len(), 1);
+            assert!(trail[0].stage == "export" && trail[0].success);
+        }
+
+        #[test]
+        fn test_declassification_is_scoped_to_stage_and_tiers() {
+            let mut collector = PipelineAuditCollector::new();
+            collector.declassify(
+                "export",
+                PrivacyTier::Private,
+                PrivacyTier::Standard,
+                "reviewed",
+            );
+
+            assert!(collector
+                .check_flow("export", PrivacyTier::Private, PrivacyTier::Standard)
+                .is_ok());
+            assert!(collector
+                .check_flow("other", PrivacyTier::Private, PrivacyTier::Standard)
+                .is_err());
+            assert!(collector
+                .check_flow("export", PrivacyTier::Sovereign, PrivacyTier::Standard)
+                .is_err());
+        }
+    }
 }
@@ -22,6 +22,7 @@
 //! 3. No hand-written web_sys in application code
 
 use std::fmt;
+use std::time::Duration;
 
 // ============================================================================
 // Performance Timing (replaces web_sys::window().performance())
@@ -207,45 +208,190 @@ pub struct FetchResult {
     pub body: Vec<u8>,
 }
 
+/// Timeout and retry settings for generated fetch calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FetchOptions {
+    /// Per-attempt timeout enforced with an `AbortController` (None = no timeout)
+    pub timeout: Option<Duration>,
+    /// Number of retries after the first attempt
+    pub retries: u32,
+    /// Base backoff delay, doubled after each failed attempt
+    pub backoff: Duration,
+}
+
+impl FetchOptions {
+    /// Default base backoff delay
+    pub const DEFAULT_BACKOFF: Duration = Duration::from_millis(100);
+
+    /// Single attempt, no timeout
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            timeout: None,
+            retries: 0,
+            backoff: Self::DEFAULT_BACKOFF,
+        }
+    }
+
+    /// Backoff delay before retry number `attempt` (0-based)
+    #[must_use]
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        self.backoff.saturating_mul(1u32 << attempt.min(16))
+    }
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Generated fetch client
 ///
 /// Replaces hand-written fetch calls
 #[derive(Debug, Clone, Default)]
-pub struct FetchClient;
+pub struct FetchClient {
+    options: FetchOptions,
+}
 
 impl FetchClient {
     /// Create a new fetch client
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            options: FetchOptions::new(),
+        }
+    }
+
+    /// Abort each attempt after `timeout`
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry failed requests up to `retries` times
+    #[must_use]
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.options.retries = retries;
+        self
+    }
+
+    /// Set the base backoff delay between retries
+    #[must_use]
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.options.backoff = backoff;
+        self
+    }
+
+    /// Get the fetch options
+    #[must_use]
+    pub fn options(&self) -> &FetchOptions {
+        &self.options
+    }
+
+    /// Generation metadata including the fetch settings
+    #[must_use]
+    pub fn metadata(&self) -> GenerationMetadata {
+        GenerationMetadata {
+            fetch: Some(self.options),
+            ..GENERATION_METADATA
+        }
+    }
+
+    /// Generate the `probarFetch(url, init)` JavaScript wrapper
+    ///
+    /// Each attempt gets its own `AbortController` when a timeout is set.
+    /// Network errors, timeouts and 5xx responses are retried with
+    /// exponential backoff; the last response or error is surfaced.
+    #[must_use]
+    pub fn to_fetch_js(&self) -> String {
+        let opts = &self.options;
+        let mut js = String::new();
+
+        js.push_str(&format!(
+            "// Generated by {} ({})\n",
+            GENERATION_METADATA.method, GENERATION_METADATA.ticket
+        ));
+        js.push_str(&format!(
+            "// timeout_ms: {}, retries: {}, backoff_ms: {}\n",
+            opts.timeout
+                .map_or_else(|| "none".to_string(), |t| t.as_millis().to_string()),
+            opts.retries,
+            opts.backoff.as_millis()
+        ));
+        js.push_str("async function probarFetch(url, init = {}) {\n");
+        if let Some(timeout) = opts.timeout {
+            js.push_str(&format!("  const TIMEOUT_MS = {};\n", timeout.as_millis()));
+        }
+        js.push_str(&format!("  const RETRIES = {};\n", opts.retries));
+        js.push_str(&format!(
+            "  const BACKOFF_MS = {};\n",
+            opts.backoff.as_millis()
+        ));
+        js.push_str("  let lastError;\n");
+        js.push_str("  for (let attempt = 0; attempt <= RETRIES; attempt++) {\n");
+        if opts.timeout.is_some() {
+            js.push_str("    const controller = new AbortController();\n");
+            js.push_str("    const timer = setTimeout(() => controller.abort(), TIMEOUT_MS);\n");
+        }
+        js.push_str("    try {\n");
+        if opts.timeout.is_some() {
+            js.push_str(
+                "      const response = await fetch(url, { ...init, signal: controller.signal });\n",
+            );
+        } else {
+            js.push_str("      const response = await fetch(url, init);\n");
+        }
+        js.push_str("      if (response.status < 500 || attempt === RETRIES) return response;\n");
+        js.push_str("      lastError = new Error(`HTTP ${response.status}`);\n");
+        js.push_str("    } catch (e) {\n");
+        js.push_str("      lastError = e;\n");
+        if opts.timeout.is_some() {
+            js.push_str("    } finally {\n");
+            js.push_str("      clearTimeout(timer);\n");
+        }
+        js.push_str("    }\n");
+        js.push_str("    if (attempt < RETRIES) {\n");
+        js.push_str(
+            "      await new Promise((resolve) => setTimeout(resolve, BACKOFF_MS * 2 ** attempt));\n",
+        );
+        js.push_str("    }\n");
+        js.push_str("  }\n");
+        js.push_str("  throw lastError;\n");
+        js.push_str("}\n");
+
+        js
     }
 
     /// Fetch bytes from a URL (WASM)
     /// Works in both main thread (window) and Web Worker (self) contexts
+    ///
+    /// Each attempt is aborted through an `AbortController` once the
+    /// configured timeout elapses. Network errors, timeouts and 5xx responses
+    /// are retried with exponential backoff.
     #[cfg(target_arch = "wasm32")]
     pub async fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>, WebSysError> {
-        use wasm_bindgen::JsCast;
         use wasm_bindgen_futures::JsFuture;
 
-        // Use global fetch which works in both Window and Worker contexts
+        // Use global fetch/timers which work in both Window and Worker contexts
         let global = js_sys::global();
-        let fetch_fn = js_sys::Reflect::get(&global, &wasm_bindgen::JsValue::from_str("fetch"))
-            .map_err(|_| WebSysError::NoWindow)?;
-        let fetch_fn: js_sys::Function = fetch_fn.dyn_into().map_err(|_| WebSysError::NoWindow)?;
-
-        let promise = fetch_fn
-            .call1(
-                &wasm_bindgen::JsValue::UNDEFINED,
-                &wasm_bindgen::JsValue::from_str(url),
-            )
-            .map_err(|_| WebSysError::FetchFailed)?;
-
-        let response = JsFuture::from(js_sys::Promise::from(promise))
-            .await
-            .map_err(|_| WebSysError::FetchFailed)?;
-
-        let response: web_sys::Response =
-            response.dyn_into().map_err(|_| WebSysError::FetchFailed)?;
+        let timers = Timers::from_global(&global)?;
+        let fetch_fn = global_function(&global, "fetch")?;
+
+        let mut attempt = 0;
+        let response = loop {
+            let last = attempt >= self.options.retries;
+            match self.fetch_attempt(&global, &fetch_fn, &timers, url).await {
+                Ok(response) if response.status() < 500 || last => break response,
+                Err(e) if last => return Err(e),
+                _ => {}
+            }
+            timers
+                .sleep(&global, self.options.backoff_for(attempt))
+                .await?;
+            attempt += 1;
+        };
 
         let array_buffer = JsFuture::from(
             response
@@ -259,6 +405,45 @@ impl FetchClient {
         Ok(uint8_array.to_vec())
     }
 
+    /// Run a single fetch attempt, aborting it after the configured timeout
+    #[cfg(target_arch = "wasm32")]
+    async fn fetch_attempt(
+        &self,
+        global: &js_sys::Object,
+        fetch_fn: &js_sys::Function,
+        timers: &Timers,
+        url: &str,
+    ) -> Result<web_sys::Response, WebSysError> {
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen_futures::JsFuture;
+
+        let init = web_sys::RequestInit::new();
+        // Keep the abort closure alive until the timer is cleared
+        let mut abort_timer = None;
+        if let Some(timeout) = self.options.timeout {
+            let controller =
+                web_sys::AbortController::new().map_err(|_| WebSysError::FetchFailed)?;
+            init.set_signal(Some(&controller.signal()));
+            let abort = Closure::<dyn FnMut()>::once(move || controller.abort());
+            let id = timers.set(global, abort.as_ref(), timeout)?;
+            abort_timer = Some((id, abort));
+        }
+
+        let result = match fetch_fn.call2(global, &wasm_bindgen::JsValue::from_str(url), &init) {
+            Ok(promise) => JsFuture::from(js_sys::Promise::from(promise))
+                .await
+                .map_err(|_| WebSysError::FetchFailed),
+            Err(_) => Err(WebSysError::FetchFailed),
+        };
+
+        if let Some((id, _abort)) = abort_timer {
+            timers.clear(global, &id);
+        }
+
+        result?.dyn_into().map_err(|_| WebSysError::FetchFailed)
+    }
+
     /// Fetch bytes from a URL (native fallback - returns error)
     #[cfg(not(target_arch = "wasm32"))]
     #[allow(clippy::unused_async)] // Must be async for API compatibility with WASM target
@@ -267,6 +452,65 @@ impl FetchClient {
     }
 }
 
+/// Global `setTimeout`/`clearTimeout`, available in Window and Worker contexts
+#[cfg(target_arch = "wasm32")]
+struct Timers {
+    set_timeout: js_sys::Function,
+    clear_timeout: js_sys::Function,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Timers {
+    fn from_global(global: &js_sys::Object) -> Result<Self, WebSysError> {
+        Ok(Self {
+            set_timeout: global_function(global, "setTimeout")?,
+            clear_timeout: global_function(global, "clearTimeout")?,
+        })
+    }
+
+    /// Schedule `callback` after `delay`, returning the timer id
+    fn set(
+        &self,
+        global: &js_sys::Object,
+        callback: &wasm_bindgen::JsValue,
+        delay: Duration,
+    ) -> Result<wasm_bindgen::JsValue, WebSysError> {
+        let millis = wasm_bindgen::JsValue::from_f64(delay.as_secs_f64() * 1000.0);
+        self.set_timeout
+            .call2(global, callback, &millis)
+            .map_err(|_| WebSysError::FetchFailed)
+    }
+
+    /// Cancel a timer returned by [`Self::set`]
+    fn clear(&self, global: &js_sys::Object, id: &wasm_bindgen::JsValue) {
+        let _ = self.clear_timeout.call1(global, id);
+    }
+
+    /// Resolve after `delay`
+    async fn sleep(&self, global: &js_sys::Object, delay: Duration) -> Result<(), WebSysError> {
+        let mut scheduled = Ok(wasm_bindgen::JsValue::UNDEFINED);
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            scheduled = self.set(global, &resolve, delay);
+        });
+        scheduled?;
+        wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map(drop)
+            .map_err(|_| WebSysError::FetchFailed)
+    }
+}
+
+/// Look up a function on the global object
+#[cfg(target_arch = "wasm32")]
+fn global_function(global: &js_sys::Object, name: &str) -> Result<js_sys::Function, WebSysError> {
+    use wasm_bindgen::JsCast;
+
+    js_sys::Reflect::get(global, &wasm_bindgen::JsValue::from_str(name))
+        .map_err(|_| WebSysError::NoWindow)?
+        .dyn_into()
+        .map_err(|_| WebSysError::NoWindow)
+}
+
 // ============================================================================
 // Blob URL Generation (replaces web_sys::Blob, web_sys::Url)
 // ============================================================================
@@ -480,6 +724,8 @@ pub struct GenerationMetadata {
     pub ticket: &'static str,
     /// Generation method
     pub method: &'static str,
+    /// Fetch timeout/retry settings (None when no fetch client is involved)
+    pub fetch: Option<FetchOptions>,
 }
 
 /// Standard generation metadata for this module
@@ -487,6 +733,7 @@ pub const GENERATION_METADATA: GenerationMetadata = GenerationMetadata {
     spec: "PROBAR-SPEC-009-P7",
     ticket: "PROBAR-WEBSYS-001",
     method: "probar::brick::web_sys_gen",
+    fetch: None,
 };
 
 // ============================================================================
@@ -717,7 +964,7 @@ mod tests {
 
     #[test]
     fn test_fetch_client_default() {
-        let client = FetchClient;
+        let client = FetchClient::default();
         let debug_str = format!("{:?}", client);
         assert!(debug_str.contains("FetchClient"));
     }
@@ -983,4 +1230,86 @@ mod tests {
             };
         }
     }
+
+    // ========================================================================
+    // Fetch timeout/retry generation tests
+    // ========================================================================
+
+    mod fetch_generation_tests {
+        use super::*;
+
+        #[test]
+        fn test_fetch_options_default() {
+            assert_eq!(FetchClient::new().options(), FetchClient::default().options());
+            let opts = *FetchClient::new().options();
+            assert_eq!(opts, FetchOptions::default());
+            assert_eq!(opts.timeout, None);
+            assert_eq!(opts.retries, 0);
+            assert_eq!(opts.backoff, FetchOptions::DEFAULT_BACKOFF);
+        }
+
+        #[test]
+        fn test_backoff_doubles() {
+            let client = FetchClient::new().with_backoff(Duration::from_millis(50));
+            let opts = client.options();
+            assert_eq!(opts.backoff_for(0), Duration::from_millis(50));
+            assert_eq!(opts.backoff_for(1), Duration::from_millis(100));
+            assert_eq!(opts.backoff_for(3), Duration::from_millis(400));
+        }
+
+        #[test]
+        fn test_generated_js_has_abort_controller_and_retry_loop() {
+            let client = FetchClient::new()
+                .with_timeout(Duration::from_millis(2500))
+                .with_retries(3)
+                .with_backoff(Duration::from_millis(200));
+            let js = client.to_fetch_js();
+
+            assert!(js.contains("async function probarFetch(url, init = {})"));
+            assert!(js.contains("const TIMEOUT_MS = 2500;"));
+            assert!(js.contains("new AbortController()"));
+            assert!(js.contains("setTimeout(() => controller.abort(), TIMEOUT_MS)"));
+            assert!(js.contains("signal: controller.signal"));
+            assert!(js.contains("clearTimeout(timer)"));
+            assert!(js.contains("const RETRIES = 3;"));
+            assert!(js.contains("for (let attempt = 0; attempt <= RETRIES; attempt++)"));
+            assert!(js.contains("BACKOFF_MS * 2 ** attempt"));
+            assert!(js.contains("const BACKOFF_MS = 200;"));
+        }
+
+        #[test]
+        fn test_generated_js_without_timeout_omits_abort_controller() {
+            let js = FetchClient::new().to_fetch_js();
+
+            assert!(!js.contains("AbortController"));
+            assert!(js.contains("const RETRIES = 0;"));
+            assert!(js.contains("await fetch(url, init)"));
+        }
+
+        #[test]
+        fn test_metadata_records_fetch_settings() {
+            let client = FetchClient::new()
+                .with_timeout(Duration::from_secs(5))
+                .with_retries(2);
+            let meta = client.metadata();
+
+            assert_eq!(meta.spec, GENERATION_METADATA.spec);
+            assert_eq!(meta.ticket, GENERATION_METADATA.ticket);
+            let fetch = meta.fetch.unwrap();
+            assert_eq!(fetch.timeout, Some(Duration::from_secs(5)));
+            assert_eq!(fetch.retries, 2);
+            assert!(GENERATION_METADATA.fetch.is_none());
+        }
+
+        #[test]
+        fn test_generated_js_header_records_settings() {
+            let js = FetchClient::new()
+                .with_timeout(Duration::from_millis(750))
+                .with_retries(1)
+                .to_fetch_js();
+
+            assert!(js.contains("// timeout_ms: 750, retries: 1, backoff_ms: 100"));
+            assert!(js.contains("PROBAR-WEBSYS-001"));
+        }
+    }
 }
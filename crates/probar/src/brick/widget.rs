@@ -433,79 +433,174 @@ pub struct GpuInstance {
     pub params: [f32; 4],
 }
 
-/// Convert draw commands to GPU instances for batched rendering
-#[must_use]
-pub fn commands_to_gpu_instances(commands: &[DrawCommand]) -> Vec<GpuInstance> {
-    let mut instances = Vec::with_capacity(commands.len());
+/// Shader program used to draw a command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShaderKind {
+    /// Instanced SDF shapes (rects, circles, gradients)
+    Shape,
+    /// Glyph rendering
+    Text,
+    /// Stroked/filled paths
+    Path,
+    /// Textured quads
+    Image,
+}
 
-    for cmd in commands {
-        match cmd {
-            DrawCommand::Rect {
+/// Framebuffer blend mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// Source-over alpha blending
+    Alpha,
+    /// Overwrite destination pixels
+    Replace,
+}
+
+/// GPU state a draw command needs; commands sharing a material can be
+/// drawn in one instanced call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Material {
+    /// Shader program
+    pub shader: ShaderKind,
+    /// Blend mode
+    pub blend: BlendMode,
+    /// Texture identity (content hash for images)
+    pub texture: Option<u64>,
+}
+
+impl DrawCommand {
+    /// Material required to draw this command (None for groups)
+    #[must_use]
+    pub fn material(&self) -> Option<Material> {
+        let (shader, blend, texture) = match self {
+            Self::Rect { .. } | Self::Circle { .. } | Self::Gradient { .. } => {
+                (ShaderKind::Shape, BlendMode::Alpha, None)
+            }
+            Self::Clear { .. } => (ShaderKind::Shape, BlendMode::Replace, None),
+            Self::Text { .. } => (ShaderKind::Text, BlendMode::Alpha, None),
+            Self::Path { .. } => (ShaderKind::Path, BlendMode::Alpha, None),
+            Self::Image { data, .. } => {
+                use std::hash::{Hash, Hasher};
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                data.hash(&mut hasher);
+                (ShaderKind::Image, BlendMode::Alpha, Some(hasher.finish()))
+            }
+            Self::Group { .. } => return None,
+        };
+        Some(Material {
+            shader,
+            blend,
+            texture,
+        })
+    }
+
+    /// Instance data for commands drawn by the shape shader
+    fn gpu_instance(&self) -> Option<GpuInstance> {
+        match self {
+            Self::Rect {
                 bounds,
                 color,
                 radius,
-            } => {
-                instances.push(GpuInstance {
-                    bounds: bounds.to_array(),
-                    color: color.to_array(),
-                    shape_type: 0,
-                    corner_radius: radius.top_left,
-                    params: [0.0; 4],
-                });
-            }
-            DrawCommand::Circle {
+            } => Some(GpuInstance {
+                bounds: bounds.to_array(),
+                color: color.to_array(),
+                shape_type: 0,
+                corner_radius: radius.top_left,
+                params: [0.0; 4],
+            }),
+            Self::Circle {
                 center,
                 radius,
                 color,
-            } => {
-                instances.push(GpuInstance {
-                    bounds: [
-                        center.x - radius,
-                        center.y - radius,
-                        radius * 2.0,
-                        radius * 2.0,
-                    ],
-                    color: color.to_array(),
-                    shape_type: 1,
-                    corner_radius: *radius,
-                    params: [0.0; 4],
-                });
-            }
-            DrawCommand::Clear { bounds, color } => {
-                instances.push(GpuInstance {
-                    bounds: bounds.to_array(),
-                    color: color.to_array(),
-                    shape_type: 3,
-                    corner_radius: 0.0,
-                    params: [0.0; 4],
-                });
-            }
-            DrawCommand::Gradient {
+            } => Some(GpuInstance {
+                bounds: [
+                    center.x - radius,
+                    center.y - radius,
+                    radius * 2.0,
+                    radius * 2.0,
+                ],
+                color: color.to_array(),
+                shape_type: 1,
+                corner_radius: *radius,
+                params: [0.0; 4],
+            }),
+            Self::Clear { bounds, color } => Some(GpuInstance {
+                bounds: bounds.to_array(),
+                color: color.to_array(),
+                shape_type: 3,
+                corner_radius: 0.0,
+                params: [0.0; 4],
+            }),
+            Self::Gradient {
                 bounds,
                 start_color,
                 end_color,
                 angle,
-            } => {
-                instances.push(GpuInstance {
-                    bounds: bounds.to_array(),
-                    color: start_color.to_array(),
-                    shape_type: 4,
-                    corner_radius: 0.0,
-                    params: [end_color.r, end_color.g, end_color.b, *angle],
-                });
+            } => Some(GpuInstance {
+                bounds: bounds.to_array(),
+                color: start_color.to_array(),
+                shape_type: 4,
+                corner_radius: 0.0,
+                params: [end_color.r, end_color.g, end_color.b, *angle],
+            }),
+            // Text, paths and images need separate render passes
+            Self::Text { .. } | Self::Path { .. } | Self::Image { .. } | Self::Group { .. } => None,
+        }
+    }
+}
+
+/// Run of consecutive draw commands sharing a material (one draw call)
+#[derive(Debug, Clone)]
+pub struct GpuBatch {
+    /// Material shared by every command in the batch
+    pub material: Material,
+    /// Instances for the shape shader (empty for text/path/image passes)
+    pub instances: Vec<GpuInstance>,
+    /// Number of draw commands merged into this batch
+    pub command_count: usize,
+}
+
+/// Group draw commands into instanced batches
+///
+/// Groups are flattened and only *consecutive* commands with the same
+/// [`Material`] are merged, so painter's order is preserved exactly:
+/// overlapping commands are never reordered across a material change.
+#[must_use]
+pub fn batch_draw_commands(commands: &[DrawCommand]) -> Vec<GpuBatch> {
+    fn push(batches: &mut Vec<GpuBatch>, commands: &[DrawCommand]) {
+        for cmd in commands {
+            if let DrawCommand::Group { children, .. } = cmd {
+                push(batches, children);
+                continue;
             }
-            // Text and Path require more complex handling
-            DrawCommand::Text { .. } | DrawCommand::Path { .. } | DrawCommand::Image { .. } => {
-                // These need separate render passes
+            let Some(material) = cmd.material() else {
+                continue;
+            };
+            if batches.last().map(|b| b.material) != Some(material) {
+                batches.push(GpuBatch {
+                    material,
+                    instances: Vec::new(),
+                    command_count: 0,
+                });
             }
-            DrawCommand::Group { children, .. } => {
-                // Recursively process children
-                instances.extend(commands_to_gpu_instances(children));
+            if let Some(batch) = batches.last_mut() {
+                batch.instances.extend(cmd.gpu_instance());
+                batch.command_count += 1;
             }
         }
     }
 
-    instances
+    let mut batches = Vec::new();
+    push(&mut batches, commands);
+    batches
+}
+
+/// Convert draw commands to GPU instances for batched rendering
+#[must_use]
+pub fn commands_to_gpu_instances(commands: &[DrawCommand]) -> Vec<GpuInstance> {
+    batch_draw_commands(commands)
+        .into_iter()
+        .flat_map(|batch| batch.instances)
+        .collect()
 }
 
 /// Layout constraints from parent
@@ -607,6 +702,165 @@ impl LayoutResult {
     }
 }
 
+/// Main axis of a flex layout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FlexDirection {
+    /// Children laid out left to right
+    #[default]
+    Row,
+    /// Children laid out top to bottom
+    Column,
+}
+
+/// Distribution of free space along the main axis
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JustifyContent {
+    /// Pack children at the start
+    #[default]
+    Start,
+    /// Pack children at the end
+    End,
+    /// Pack children in the center
+    Center,
+    /// First and last child flush with the edges, equal gaps between
+    SpaceBetween,
+    /// Equal space around each child (half-size gaps at the edges)
+    SpaceAround,
+    /// Equal gaps between children and at the edges
+    SpaceEvenly,
+}
+
+/// Placement of children along the cross axis
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AlignItems {
+    /// Align to the cross-axis start
+    #[default]
+    Start,
+    /// Align to the cross-axis end
+    End,
+    /// Center on the cross axis
+    Center,
+    /// Stretch to fill the cross axis
+    Stretch,
+}
+
+/// How a container positions its children
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// All children placed at the container origin
+    #[default]
+    Stack,
+    /// Flexbox-style distribution along a main axis
+    Flex {
+        direction: FlexDirection,
+        justify: JustifyContent,
+        align: AlignItems,
+    },
+}
+
+/// Child positions computed by [`LayoutMode::layout_children`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChildLayout {
+    /// Child bounds relative to the container origin, in child order
+    pub children: Vec<Rect>,
+    /// Main-axis extent by which children exceed the available space
+    pub overflow: f32,
+}
+
+impl ChildLayout {
+    /// Check if children overflow the container
+    #[must_use]
+    pub fn overflows(&self) -> bool {
+        self.overflow > 0.0
+    }
+}
+
+impl LayoutMode {
+    /// Position children of the given sizes within `constraints`
+    ///
+    /// Available space is the maximum constraint on each axis (or the
+    /// children's extent when unbounded). Children never wrap or shrink:
+    /// when they exceed the main axis they are packed from the start with
+    /// no gaps, and the excess is reported in [`ChildLayout::overflow`].
+    #[must_use]
+    pub fn layout_children(&self, constraints: &Constraints, children: &[Size]) -> ChildLayout {
+        let Self::Flex {
+            direction,
+            justify,
+            align,
+        } = *self
+        else {
+            return ChildLayout {
+                children: children.iter().map(|&size| Rect::from_size(size)).collect(),
+                overflow: 0.0,
+            };
+        };
+
+        let (max_main, max_cross) = match direction {
+            FlexDirection::Row => (constraints.max_width, constraints.max_height),
+            FlexDirection::Column => (constraints.max_height, constraints.max_width),
+        };
+        let split = |size: Size| match direction {
+            FlexDirection::Row => (size.width, size.height),
+            FlexDirection::Column => (size.height, size.width),
+        };
+
+        let total_main: f32 = children.iter().map(|&c| split(c).0).sum();
+        let largest_cross = children.iter().map(|&c| split(c).1).fold(0.0, f32::max);
+        let available_main = if max_main.is_finite() {
+            max_main
+        } else {
+            total_main
+        };
+        let available_cross = if max_cross.is_finite() {
+            max_cross
+        } else {
+            largest_cross
+        };
+
+        let free = available_main - total_main;
+        let count = children.len() as f32;
+        let (start, gap) = if free < 0.0 {
+            (0.0, 0.0)
+        } else {
+            match justify {
+                JustifyContent::Start => (0.0, 0.0),
+                JustifyContent::End => (free, 0.0),
+                JustifyContent::Center => (free / 2.0, 0.0),
+                JustifyContent::SpaceBetween if children.len() > 1 => (0.0, free / (count - 1.0)),
+                JustifyContent::SpaceBetween => (0.0, 0.0),
+                JustifyContent::SpaceAround => (free / count / 2.0, free / count),
+                JustifyContent::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+            }
+        };
+
+        let mut cursor = start;
+        let rects = children
+            .iter()
+            .map(|&child| {
+                let (main, cross) = split(child);
+                let (cross_offset, cross_size) = match align {
+                    AlignItems::Start => (0.0, cross),
+                    AlignItems::End => (available_cross - cross, cross),
+                    AlignItems::Center => ((available_cross - cross) / 2.0, cross),
+                    AlignItems::Stretch => (0.0, available_cross),
+                };
+                let offset = cursor;
+                cursor += main + gap;
+                match direction {
+                    FlexDirection::Row => Rect::new(offset, cross_offset, main, cross_size),
+                    FlexDirection::Column => Rect::new(cross_offset, offset, cross_size, main),
+                }
+            })
+            .collect();
+
+        ChildLayout {
+            children: rects,
+            overflow: (-free).max(0.0),
+        }
+    }
+}
+
 /// UI event for widget interaction
 #[derive(Debug, Clone)]
 pub enum Event {
@@ -818,6 +1072,7 @@ pub trait WidgetExt: Widget {
                 total_time: verify_time,
                 valid: false,
                 command_count: 0,
+                draw_batches: 0,
             };
         }
 
@@ -831,6 +1086,7 @@ pub trait WidgetExt: Widget {
             total_time: start.elapsed(),
             valid: true,
             command_count: canvas.commands().len(),
+            draw_batches: batch_draw_commands(canvas.commands()).len(),
         }
     }
 
@@ -873,6 +1129,8 @@ pub struct RenderMetrics {
     pub valid: bool,
     /// Number of draw commands generated
     pub command_count: usize,
+    /// Number of GPU draw calls after batching
+    pub draw_batches: usize,
 }
 
 impl RenderMetrics {
@@ -2093,6 +2351,7 @@ mod tests {
             total_time: Duration::from_millis(6),
             valid: true,
             command_count: 10,
+            draw_batches: 1,
         };
 
         assert!(metrics.within_budget(BrickBudget::uniform(16)));
@@ -2115,9 +2374,336 @@ mod tests {
             total_time: Duration::from_millis(3),
             valid: true,
             command_count: 5,
+            draw_batches: 1,
         };
 
         let cloned = metrics;
         assert!(format!("{:?}", cloned).contains("RenderMetrics"));
     }
+
+    // ============================================================
+    // Flex Layout Tests
+    // ============================================================
+
+    mod flex_tests {
+        use super::*;
+
+        fn flex(
+            direction: FlexDirection,
+            justify: JustifyContent,
+            align: AlignItems,
+        ) -> LayoutMode {
+            LayoutMode::Flex {
+                direction,
+                justify,
+                align,
+            }
+        }
+
+        fn three_children() -> Vec<Size> {
+            vec![
+                Size::new(20.0, 10.0),
+                Size::new(30.0, 20.0),
+                Size::new(10.0, 30.0),
+            ]
+        }
+
+        fn xs(layout: &ChildLayout) -> Vec<f32> {
+            layout.children.iter().map(|r| r.x).collect()
+        }
+
+        #[test]
+        fn test_stack_places_children_at_origin() {
+            let layout = LayoutMode::Stack.layout_children(
+                &Constraints::loose(Size::new(100.0, 100.0)),
+                &three_children(),
+            );
+
+            assert!(layout.children.iter().all(|r| r.x == 0.0 && r.y == 0.0));
+            assert_eq!(layout.children[1].size(), Size::new(30.0, 20.0));
+            assert!(!layout.overflows());
+        }
+
+        #[test]
+        fn test_space_between_three_children() {
+            let mode = flex(
+                FlexDirection::Row,
+                JustifyContent::SpaceBetween,
+                AlignItems::Start,
+            );
+            let layout = mode.layout_children(
+                &Constraints::loose(Size::new(100.0, 40.0)),
+                &three_children(),
+            );
+
+            // 40px free space split into two 20px gaps
+            assert_eq!(xs(&layout), vec![0.0, 40.0, 90.0]);
+            assert_eq!(layout.children[2].x + layout.children[2].width, 100.0);
+            assert!(layout.children.iter().all(|r| r.y == 0.0));
+            assert!(!layout.overflows());
+        }
+
+        #[test]
+        fn test_space_between_single_child_at_start() {
+            let mode = flex(
+                FlexDirection::Row,
+                JustifyContent::SpaceBetween,
+                AlignItems::Start,
+            );
+            let layout = mode.layout_children(
+                &Constraints::loose(Size::new(100.0, 40.0)),
+                &[Size::new(20.0, 10.0)],
+            );
+
+            assert_eq!(xs(&layout), vec![0.0]);
+        }
+
+        #[test]
+        fn test_justify_variants() {
+            let constraints = Constraints::loose(Size::new(100.0, 40.0));
+            let cases = [
+                (JustifyContent::Start, vec![0.0, 20.0, 50.0]),
+                (JustifyContent::End, vec![40.0, 60.0, 90.0]),
+                (JustifyContent::Center, vec![20.0, 40.0, 70.0]),
+                (JustifyContent::SpaceEvenly, vec![10.0, 40.0, 80.0]),
+            ];
+
+            for (justify, expected) in cases {
+                let layout = flex(FlexDirection::Row, justify, AlignItems::Start)
+                    .layout_children(&constraints, &three_children());
+                assert_eq!(xs(&layout), expected, "{:?}", justify);
+            }
+        }
+
+        #[test]
+        fn test_space_around() {
+            let layout = flex(
+                FlexDirection::Row,
+                JustifyContent::SpaceAround,
+                AlignItems::Start,
+            )
+            .layout_children(
+                &Constraints::loose(Size::new(120.0, 40.0)),
+                &three_children(),
+            );
+
+            // 60px free: 20px per child, 10px at each edge
+            let expected = [10.0, 50.0, 100.0];
+            for (actual, expected) in xs(&layout).iter().zip(expected) {
+                assert!((actual - expected).abs() < 1e-4);
+            }
+        }
+
+        #[test]
+        fn test_align_items() {
+            let constraints = Constraints::loose(Size::new(100.0, 40.0));
+            let children = [Size::new(10.0, 10.0)];
+
+            let end = flex(FlexDirection::Row, JustifyContent::Start, AlignItems::End)
+                .layout_children(&constraints, &children);
+            assert_eq!(end.children[0].y, 30.0);
+
+            let center = flex(
+                FlexDirection::Row,
+                JustifyContent::Start,
+                AlignItems::Center,
+            )
+            .layout_children(&constraints, &children);
+            assert_eq!(center.children[0].y, 15.0);
+
+            let stretch = flex(
+                FlexDirection::Row,
+                JustifyContent::Start,
+                AlignItems::Stretch,
+            )
+            .layout_children(&constraints, &children);
+            assert_eq!(stretch.children[0], Rect::new(0.0, 0.0, 10.0, 40.0));
+        }
+
+        #[test]
+        fn test_column_direction() {
+            let layout = flex(
+                FlexDirection::Column,
+                JustifyContent::SpaceBetween,
+                AlignItems::Center,
+            )
+            .layout_children(
+                &Constraints::loose(Size::new(40.0, 100.0)),
+                &[Size::new(20.0, 20.0), Size::new(10.0, 30.0)],
+            );
+
+            assert_eq!(layout.children[0], Rect::new(10.0, 0.0, 20.0, 20.0));
+            assert_eq!(layout.children[1], Rect::new(15.0, 70.0, 10.0, 30.0));
+        }
+
+        #[test]
+        fn test_overflow_packs_from_start_without_wrapping() {
+            let children = three_children(); // 60px on the main axis
+            for justify in [
+                JustifyContent::Start,
+                JustifyContent::End,
+                JustifyContent::Center,
+                JustifyContent::SpaceBetween,
+                JustifyContent::SpaceAround,
+                JustifyContent::SpaceEvenly,
+            ] {
+                let layout = flex(FlexDirection::Row, justify, AlignItems::Start)
+                    .layout_children(&Constraints::loose(Size::new(45.0, 40.0)), &children);
+
+                assert!(layout.overflows());
+                assert_eq!(layout.overflow, 15.0);
+                // Sizes preserved, all on one line, in order
+                assert_eq!(xs(&layout), vec![0.0, 20.0, 50.0], "{:?}", justify);
+                assert!(layout.children.iter().all(|r| r.y == 0.0));
+                assert_eq!(layout.children[1].width, 30.0);
+            }
+        }
+
+        #[test]
+        fn test_unbounded_constraints_have_no_free_space() {
+            let layout = flex(FlexDirection::Row, JustifyContent::End, AlignItems::End)
+                .layout_children(&Constraints::unbounded(), &three_children());
+
+            assert_eq!(xs(&layout), vec![0.0, 20.0, 50.0]);
+            assert_eq!(layout.children[0].y, 20.0);
+            assert!(!layout.overflows());
+        }
+
+        #[test]
+        fn test_no_children() {
+            let layout = flex(
+                FlexDirection::Row,
+                JustifyContent::SpaceAround,
+                AlignItems::Center,
+            )
+            .layout_children(&Constraints::loose(Size::new(100.0, 40.0)), &[]);
+
+            assert!(layout.children.is_empty());
+            assert!(!layout.overflows());
+        }
+    }
+
+    // ============================================================
+    // Draw Command Batching Tests
+    // ============================================================
+
+    mod batching_tests {
+        use super::*;
+
+        fn quad(x: f32) -> DrawCommand {
+            DrawCommand::Rect {
+                bounds: Rect::new(x, 0.0, 10.0, 10.0),
+                color: WidgetColor::WHITE,
+                radius: CornerRadius::ZERO,
+            }
+        }
+
+        #[test]
+        fn test_same_material_quads_collapse_into_one_batch() {
+            let commands: Vec<_> = (0..5).map(|i| quad(i as f32 * 10.0)).collect();
+
+            let batches = batch_draw_commands(&commands);
+
+            assert_eq!(batches.len(), 1);
+            assert_eq!(batches[0].command_count, 5);
+            assert_eq!(batches[0].instances.len(), 5);
+            assert_eq!(batches[0].material.shader, ShaderKind::Shape);
+            // Instances keep draw order
+            let xs: Vec<_> = batches[0].instances.iter().map(|i| i.bounds[0]).collect();
+            assert_eq!(xs, vec![0.0, 10.0, 20.0, 30.0, 40.0]);
+        }
+
+        #[test]
+        fn test_material_change_forces_new_batch() {
+            let commands = vec![
+                quad(0.0),
+                quad(10.0),
+                DrawCommand::Clear {
+                    bounds: Rect::new(0.0, 0.0, 5.0, 5.0),
+                    color: WidgetColor::BLACK,
+                },
+                quad(20.0),
+            ];
+
+            let batches = batch_draw_commands(&commands);
+
+            // Shapes after the clear are not merged into the first batch
+            assert_eq!(batches.len(), 3);
+            assert_eq!(batches[0].command_count, 2);
+            assert_eq!(batches[1].material.blend, BlendMode::Replace);
+            assert_eq!(batches[2].command_count, 1);
+        }
+
+        #[test]
+        fn test_shapes_share_shader() {
+            let commands = vec![
+                quad(0.0),
+                DrawCommand::Circle {
+                    center: WidgetPoint::new(5.0, 5.0),
+                    radius: 5.0,
+                    color: WidgetColor::BLACK,
+                },
+                DrawCommand::Gradient {
+                    bounds: Rect::new(0.0, 0.0, 10.0, 10.0),
+                    start_color: WidgetColor::WHITE,
+                    end_color: WidgetColor::BLACK,
+                    angle: 0.0,
+                },
+            ];
+
+            let batches = batch_draw_commands(&commands);
+            assert_eq!(batches.len(), 1);
+            assert_eq!(batches[0].instances.len(), 3);
+        }
+
+        #[test]
+        fn test_images_batch_by_texture() {
+            let image = |data: Vec<u8>| DrawCommand::Image {
+                data,
+                bounds: Rect::new(0.0, 0.0, 4.0, 4.0),
+            };
+            let commands = vec![image(vec![1, 2]), image(vec![1, 2]), image(vec![3])];
+
+            let batches = batch_draw_commands(&commands);
+
+            assert_eq!(batches.len(), 2);
+            assert_eq!(batches[0].command_count, 2);
+            assert!(batches[0].instances.is_empty());
+            assert_ne!(batches[0].material.texture, batches[1].material.texture);
+        }
+
+        #[test]
+        fn test_groups_are_flattened_into_batches() {
+            let commands = vec![
+                quad(0.0),
+                DrawCommand::Group {
+                    children: vec![quad(10.0), quad(20.0)],
+                    transform: Transform2D::identity(),
+                },
+            ];
+
+            let batches = batch_draw_commands(&commands);
+            assert_eq!(batches.len(), 1);
+            assert_eq!(batches[0].command_count, 3);
+            assert!(DrawCommand::Group {
+                children: vec![],
+                transform: Transform2D::identity(),
+            }
+            .material()
+            .is_none());
+        }
+
+        #[test]
+        fn test_render_metrics_draw_batches() {
+            let mut canvas = RecordingCanvas::new(Size::new(100.0, 100.0));
+            let widget = TestWidget::new("Hello");
+
+            let metrics = widget.render_timed(&mut canvas);
+
+            // Background rect and text need separate draw calls
+            assert!(metrics.valid);
+            assert_eq!(metrics.command_count, 2);
+            assert_eq!(metrics.draw_batches, 2);
+        }
+    }
 }
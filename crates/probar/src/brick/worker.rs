@@ -24,6 +24,7 @@
 //! ```
 
 use super::{Brick, BrickAssertion, BrickBudget, BrickVerification};
+use serde_json::Value;
 use std::time::Duration;
 
 /// Direction of worker message
@@ -90,6 +91,27 @@ impl FieldType {
             Self::Optional(inner) => format!("Option<{}>", inner.to_rust()),
         }
     }
+
+    /// Check whether a decoded JSON value has this type
+    ///
+    /// Transferables (`SharedArrayBuffer`, `Float32Array`) have no JSON
+    /// form, so any non-null value is accepted for them.
+    #[must_use]
+    pub fn matches_json(&self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::Boolean => value.is_boolean(),
+            Self::SharedArrayBuffer | Self::Float32Array => !value.is_null(),
+            Self::Object(fields) => value.as_object().is_some_and(|obj| {
+                fields.iter().all(|f| match obj.get(&f.name) {
+                    Some(v) => f.field_type.matches_json(v),
+                    None => !f.required,
+                })
+            }),
+            Self::Optional(inner) => value.is_null() || inner.matches_json(value),
+        }
+    }
 }
 
 /// A field in a worker message
@@ -230,11 +252,94 @@ impl WorkerTransition {
     }
 }
 
+/// Error receiving a worker message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkerMessageError {
+    /// Sender was built against a different schema version
+    VersionMismatch {
+        /// Receiver's schema version
+        expected: u32,
+        /// Sender's schema version (`None` if the message had no `_v`)
+        received: Option<u64>,
+    },
+    /// Message is not a JSON object with a string `type`
+    Malformed,
+    /// Message type is not defined in the schema
+    UnknownType(String),
+    /// Required field is absent
+    MissingField {
+        /// Message type
+        message: String,
+        /// Field name
+        field: String,
+    },
+    /// Field is present with the wrong type
+    WrongFieldType {
+        /// Message type
+        message: String,
+        /// Field name
+        field: String,
+        /// Expected TypeScript type
+        expected: String,
+    },
+    /// Message is not accepted in the current state
+    InvalidTransition {
+        /// Current state
+        state: String,
+        /// Message type
+        message: String,
+    },
+}
+
+impl std::fmt::Display for WorkerMessageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::VersionMismatch { expected, received } => match received {
+                Some(v) => write!(
+                    f,
+                    "Schema version mismatch: expected v{}, received v{}",
+                    expected, v
+                ),
+                None => write!(
+                    f,
+                    "Schema version mismatch: expected v{}, message has no version",
+                    expected
+                ),
+            },
+            Self::Malformed => write!(f, "Malformed worker message"),
+            Self::UnknownType(t) => write!(f, "Unknown message type: {}", t),
+            Self::MissingField { message, field } => {
+                write!(
+                    f,
+                    "Message '{}' missing required field '{}'",
+                    message, field
+                )
+            }
+            Self::WrongFieldType {
+                message,
+                field,
+                expected,
+            } => write!(
+                f,
+                "Message '{}' field '{}' is not {}",
+                message, field, expected
+            ),
+            Self::InvalidTransition { state, message } => {
+                write!(f, "Message '{}' not accepted in state '{}'", message, state)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WorkerMessageError {}
+
 /// WorkerBrick: Generates Web Worker code from brick definition
 #[derive(Debug, Clone)]
 pub struct WorkerBrick {
     /// Worker name
     name: String,
+    /// Message schema version, embedded as `_v` in every message
+    schema_version: u32,
     /// Message definitions
     messages: Vec<BrickWorkerMessage>,
     /// State machine transitions
@@ -251,6 +356,7 @@ impl WorkerBrick {
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             name: name.into(),
+            schema_version: 1,
             messages: Vec::new(),
             transitions: Vec::new(),
             initial_state: "uninitialized".into(),
@@ -258,6 +364,23 @@ impl WorkerBrick {
         }
     }
 
+    /// Set the message schema version
+    ///
+    /// Bump this whenever a message's fields change so that a worker and
+    /// main thread built at different times reject each other's messages
+    /// instead of misparsing them.
+    #[must_use]
+    pub fn schema_version(mut self, version: u32) -> Self {
+        self.schema_version = version;
+        self
+    }
+
+    /// Get the message schema version
+    #[must_use]
+    pub fn get_schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
     /// Add a message definition
     #[must_use]
     pub fn message(mut self, msg: BrickWorkerMessage) -> Self {
@@ -360,6 +483,100 @@ impl WorkerBrick {
             .collect()
     }
 
+    /// Build a message envelope stamped with this schema's version
+    #[must_use]
+    pub fn encode(&self, message: &str, fields: serde_json::Map<String, Value>) -> Value {
+        let mut obj = fields;
+        obj.insert("type".into(), Value::from(message.to_lowercase()));
+        obj.insert("_v".into(), Value::from(self.schema_version));
+        Value::Object(obj)
+    }
+
+    /// Validate a received message against this schema
+    ///
+    /// The version is checked first, so a message from a different schema
+    /// is never field-checked against the wrong definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on version mismatch, unknown type, or a missing or
+    /// mistyped required field.
+    pub fn decode(&self, value: &Value) -> Result<&BrickWorkerMessage, WorkerMessageError> {
+        let obj = value.as_object().ok_or(WorkerMessageError::Malformed)?;
+
+        let received = obj.get("_v").and_then(Value::as_u64);
+        if received != Some(u64::from(self.schema_version)) {
+            return Err(WorkerMessageError::VersionMismatch {
+                expected: self.schema_version,
+                received,
+            });
+        }
+
+        let msg_type = obj
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or(WorkerMessageError::Malformed)?;
+        let msg = self
+            .messages
+            .iter()
+            .find(|m| m.js_type_name() == msg_type)
+            .ok_or_else(|| WorkerMessageError::UnknownType(msg_type.to_string()))?;
+
+        for field in &msg.fields {
+            match obj.get(&field.name) {
+                None if field.required => {
+                    return Err(WorkerMessageError::MissingField {
+                        message: msg_type.to_string(),
+                        field: field.name.clone(),
+                    });
+                }
+                Some(v) if !field.field_type.matches_json(v) => {
+                    return Err(WorkerMessageError::WrongFieldType {
+                        message: msg_type.to_string(),
+                        field: field.name.clone(),
+                        expected: field.field_type.to_typescript(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(msg)
+    }
+
+    /// Decode a message and apply its state transition
+    ///
+    /// Returns the next state. Transitions only fire once the message has
+    /// decoded successfully; messages with no transitions leave the state
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns any [`decode`](Self::decode) error, or
+    /// [`WorkerMessageError::InvalidTransition`] if the message is not
+    /// accepted in `state`.
+    pub fn receive(&self, state: &str, value: &Value) -> Result<String, WorkerMessageError> {
+        let msg = self.decode(value)?;
+        let js_type = msg.js_type_name();
+
+        let mut transitions = self
+            .transitions
+            .iter()
+            .filter(|t| t.message.to_lowercase() == js_type)
+            .peekable();
+        if transitions.peek().is_none() {
+            return Ok(state.to_string());
+        }
+
+        transitions
+            .find(|t| t.from == state)
+            .map(|t| t.to.clone())
+            .ok_or_else(|| WorkerMessageError::InvalidTransition {
+                state: state.to_string(),
+                message: js_type,
+            })
+    }
+
     /// Generate JavaScript Worker code
     #[must_use]
     pub fn to_worker_js(&self) -> String {
@@ -372,13 +589,22 @@ impl WorkerBrick {
         ));
         js.push_str("// Generated by probar - DO NOT EDIT MANUALLY\n\n");
 
-        // State variable
+        // Schema version and state variable
+        js.push_str(&format!(
+            "const SCHEMA_VERSION = {};\n",
+            self.schema_version
+        ));
         js.push_str(&format!("let workerState = '{}';\n\n", self.initial_state));
 
         // Message handler
         js.push_str("self.onmessage = async (e) => {\n");
         js.push_str("    const msg = e.data;\n");
         js.push_str("    const _trace = msg._trace; // Dapper trace context\n\n");
+        js.push_str("    // Reject messages from a different schema before parsing fields\n");
+        js.push_str("    if (msg._v !== SCHEMA_VERSION) {\n");
+        js.push_str("        rejectMessage(msg, 'schema_version_mismatch', 'expected v' + SCHEMA_VERSION + ', received v' + msg._v);\n");
+        js.push_str("        return;\n");
+        js.push_str("    }\n\n");
         js.push_str("    switch (msg.type) {\n");
 
         // Generate case for each to-worker message
@@ -387,6 +613,20 @@ impl WorkerBrick {
 
             js.push_str(&format!("        case '{}':\n", js_type));
 
+            // Required fields must deserialize before any transition fires
+            for field in msg.fields.iter().filter(|f| f.required) {
+                js.push_str(&format!(
+                    "            if (msg.{} === undefined) {{\n",
+                    field.name
+                ));
+                js.push_str(&format!(
+                    "                rejectMessage(msg, 'missing_field', '{}');\n",
+                    field.name
+                ));
+                js.push_str("                return;\n");
+                js.push_str("            }\n");
+            }
+
             // Find transitions triggered by this message
             let transitions: Vec<_> = self
                 .transitions
@@ -437,7 +677,17 @@ impl WorkerBrick {
 
         // Helper to post message back
         js.push_str("function postResult(type, data, trace) {\n");
-        js.push_str("    self.postMessage({ type, ...data, _trace: trace });\n");
+        js.push_str(
+            "    self.postMessage({ type, ...data, _v: SCHEMA_VERSION, _trace: trace });\n",
+        );
+        js.push_str("}\n\n");
+
+        // Helper to report a message that failed validation
+        js.push_str("function rejectMessage(msg, reason, detail) {\n");
+        js.push_str("    console.error('[Worker] Rejected ' + msg.type + ': ' + reason + ' (' + detail + ')');\n");
+        js.push_str(
+            "    postResult('error', { reason, detail, messageType: msg.type }, msg._trace);\n",
+        );
         js.push_str("}\n\n");
 
         // Log module loaded
@@ -461,6 +711,27 @@ impl WorkerBrick {
         ));
         rust.push_str("//! Generated by probar - DO NOT EDIT MANUALLY\n\n");
         rust.push_str("use serde::{Deserialize, Serialize};\n\n");
+        rust.push_str("/// Message schema version; must match the worker's `SCHEMA_VERSION`\n");
+        rust.push_str(&format!(
+            "pub const SCHEMA_VERSION: u32 = {};\n\n",
+            self.schema_version
+        ));
+
+        // Versioned envelope: the worker rejects messages without a matching `_v`
+        rust.push_str("/// Message wrapper carrying the schema version as `_v`\n");
+        rust.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+        rust.push_str("pub struct Envelope<M> {\n");
+        rust.push_str("    #[serde(rename = \"_v\")]\n");
+        rust.push_str("    pub v: u32,\n");
+        rust.push_str("    #[serde(flatten)]\n");
+        rust.push_str("    pub message: M,\n");
+        rust.push_str("}\n\n");
+        rust.push_str("impl<M> Envelope<M> {\n");
+        rust.push_str("    /// Wrap a message with the current `SCHEMA_VERSION`\n");
+        rust.push_str("    pub fn new(message: M) -> Self {\n");
+        rust.push_str("        Self { v: SCHEMA_VERSION, message }\n");
+        rust.push_str("    }\n");
+        rust.push_str("}\n\n");
 
         // ToWorker enum
         rust.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
@@ -475,11 +746,11 @@ impl WorkerBrick {
                 rust.push_str(&format!("    {} {{\n", name));
                 for field in &msg.fields {
                     let rust_type = field.field_type.to_rust();
-                    rust.push_str(&format!(
-                        "        {}: {},\n",
-                        to_snake_case(&field.name),
-                        rust_type
-                    ));
+                    let rust_name = to_snake_case(&field.name);
+                    if rust_name != field.name {
+                        rust.push_str(&format!("        #[serde(rename = \"{}\")]\n", field.name));
+                    }
+                    rust.push_str(&format!("        {}: {},\n", rust_name, rust_type));
                 }
                 rust.push_str("    },\n");
             }
@@ -499,11 +770,11 @@ impl WorkerBrick {
                 rust.push_str(&format!("    {} {{\n", name));
                 for field in &msg.fields {
                     let rust_type = field.field_type.to_rust();
-                    rust.push_str(&format!(
-                        "        {}: {},\n",
-                        to_snake_case(&field.name),
-                        rust_type
-                    ));
+                    let rust_name = to_snake_case(&field.name);
+                    if rust_name != field.name {
+                        rust.push_str(&format!("        #[serde(rename = \"{}\")]\n", field.name));
+                    }
+                    rust.push_str(&format!("        {}: {},\n", rust_name, rust_type));
                 }
                 rust.push_str("    },\n");
             }
@@ -545,6 +816,7 @@ impl WorkerBrick {
         for msg in &self.messages {
             ts.push_str(&format!("interface {}Message {{\n", msg.rust_type_name()));
             ts.push_str(&format!("    type: '{}';\n", msg.js_type_name()));
+            ts.push_str(&format!("    _v: {};\n", self.schema_version));
             for field in &msg.fields {
                 let ts_type = field.field_type.to_typescript();
                 if field.required {
@@ -1202,4 +1474,162 @@ mod tests {
         assert_eq!(worker.name, cloned.name);
         assert_eq!(worker.messages.len(), cloned.messages.len());
     }
+
+    // ============================================================
+    // Schema version tests
+    // ============================================================
+
+    fn schema(version: u32) -> WorkerBrick {
+        WorkerBrick::new("transcription")
+            .schema_version(version)
+            .message(
+                BrickWorkerMessage::new("init", BrickWorkerMessageDirection::ToWorker)
+                    .field("modelUrl", FieldType::String),
+            )
+            .transition("uninitialized", "init", "loading")
+    }
+
+    fn init_fields() -> serde_json::Map<String, Value> {
+        let mut fields = serde_json::Map::new();
+        fields.insert("modelUrl".into(), Value::from("/model.bin"));
+        fields
+    }
+
+    #[test]
+    fn test_schema_version_mismatch_rejected() {
+        let sender = schema(1);
+        let receiver = schema(2);
+
+        let msg = sender.encode("init", init_fields());
+        assert_eq!(msg["_v"], 1);
+
+        let err = receiver.receive("uninitialized", &msg).unwrap_err();
+        assert_eq!(
+            err,
+            WorkerMessageError::VersionMismatch {
+                expected: 2,
+                received: Some(1),
+            }
+        );
+        assert!(err.to_string().contains("expected v2, received v1"));
+    }
+
+    #[test]
+    fn test_matching_schema_transitions() {
+        let worker = schema(2);
+        let msg = worker.encode("init", init_fields());
+        assert_eq!(worker.receive("uninitialized", &msg).unwrap(), "loading");
+    }
+
+    #[test]
+    fn test_transition_gated_on_deserialization() {
+        let worker = schema(1);
+
+        let missing = worker.encode("init", serde_json::Map::new());
+        assert!(matches!(
+            worker.receive("uninitialized", &missing),
+            Err(WorkerMessageError::MissingField { ref field, .. }) if field == "modelUrl"
+        ));
+
+        let mut bad = serde_json::Map::new();
+        bad.insert("modelUrl".into(), Value::from(42));
+        let bad = worker.encode("init", bad);
+        assert!(matches!(
+            worker.receive("uninitialized", &bad),
+            Err(WorkerMessageError::WrongFieldType { .. })
+        ));
+
+        let unversioned = serde_json::json!({ "type": "init", "modelUrl": "/m" });
+        assert!(matches!(
+            worker.decode(&unversioned),
+            Err(WorkerMessageError::VersionMismatch { received: None, .. })
+        ));
+
+        let msg = worker.encode("init", init_fields());
+        assert!(matches!(
+            worker.receive("loading", &msg),
+            Err(WorkerMessageError::InvalidTransition { .. })
+        ));
+    }
+
+    #[test]
+    fn test_generated_code_embeds_schema_version() {
+        let worker = schema(3);
+
+        let js = worker.to_worker_js();
+        assert!(js.contains("const SCHEMA_VERSION = 3;"));
+        assert!(js.contains("if (msg._v !== SCHEMA_VERSION)"));
+        assert!(js.contains("_v: SCHEMA_VERSION"));
+        // Field validation precedes the state transition
+        let check = js.find("if (msg.modelUrl === undefined)").unwrap();
+        let transition = js.find("workerState = 'loading'").unwrap();
+        assert!(check < transition);
+
+        assert!(worker
+            .to_rust_bindings()
+            .contains("pub const SCHEMA_VERSION: u32 = 3;"));
+        assert!(worker.to_typescript_defs().contains("_v: 3;"));
+    }
+
+    /// Hand copy of `schema(1).to_rust_bindings()`, checked against the
+    /// generator below so the serialized form stays what the worker accepts
+    mod generated {
+        use serde::{Deserialize, Serialize};
+
+        pub const SCHEMA_VERSION: u32 = 1;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct Envelope<M> {
+            #[serde(rename = "_v")]
+            pub v: u32,
+            #[serde(flatten)]
+            pub message: M,
+        }
+
+        impl<M> Envelope<M> {
+            pub fn new(message: M) -> Self {
+                Self {
+                    v: SCHEMA_VERSION,
+                    message,
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        #[serde(tag = "type", rename_all = "lowercase")]
+        pub enum ToWorker {
+            Init {
+                #[serde(rename = "modelUrl")]
+                model_url: String,
+            },
+        }
+    }
+
+    #[test]
+    fn test_generated_rust_message_carries_schema_version() {
+        let worker = schema(1);
+        let bindings = worker.to_rust_bindings();
+        for line in [
+            "pub const SCHEMA_VERSION: u32 = 1;",
+            "pub struct Envelope<M> {",
+            "    #[serde(rename = \"_v\")]\n    pub v: u32,",
+            "    #[serde(flatten)]\n    pub message: M,",
+            "        Self { v: SCHEMA_VERSION, message }",
+            "#[serde(tag = \"type\", rename_all = \"lowercase\")]\npub enum ToWorker {",
+            "    Init {\n        #[serde(rename = \"modelUrl\")]\n        model_url: String,\n    },",
+        ] {
+            assert!(bindings.contains(line), "missing {line:?} in:\n{bindings}");
+        }
+
+        let msg = serde_json::to_value(generated::Envelope::new(generated::ToWorker::Init {
+            model_url: "/model.bin".into(),
+        }))
+        .unwrap();
+        assert_eq!(msg["_v"], 1);
+        assert_eq!(msg, worker.encode("init", init_fields()));
+        assert_eq!(worker.receive("uninitialized", &msg).unwrap(), "loading");
+
+        let back: generated::Envelope<generated::ToWorker> = serde_json::from_value(msg).unwrap();
+        assert_eq!(back.v, generated::SCHEMA_VERSION);
+    }
 }
@@ -20,6 +20,10 @@
 //! If any brick exceeds its budget, the BrickHouse triggers a Jidoka alert
 //! and halts rendering. This prevents cascading performance failures.
 //!
+//! Under [`BudgetPolicy::PooledTotal`], bricks that finish under their
+//! allocation donate the slack to the rest of the house: individual overruns
+//! are recorded but only a breach of the house total stops the line.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -44,8 +48,19 @@ use std::time::{Duration, Instant};
 
 use crate::brick::{
     Brick, BrickBudget, BrickError, BrickPhase, BrickResult, BrickVerification, BudgetViolation,
+    PhaseTimings,
 };
 
+/// How a house enforces its brick budgets at render time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BudgetPolicy {
+    /// Any brick exceeding its own allocation stops the line
+    #[default]
+    Strict,
+    /// Individual overruns are tolerated while the house total holds
+    PooledTotal,
+}
+
 /// A composed house of bricks with a total performance budget.
 ///
 /// The BrickHouse ensures:
@@ -62,6 +77,8 @@ pub struct BrickHouse {
     bricks: Vec<BrickEntry>,
     /// Budget report from last render
     last_report: Option<BudgetReport>,
+    /// Render-time budget enforcement policy
+    policy: BudgetPolicy,
 }
 
 /// Entry for a brick in the house
@@ -121,6 +138,43 @@ impl BudgetReport {
         self.violations.is_empty() && self.total_used_ms <= self.total_budget_ms
     }
 
+    /// Check if the house stayed within budget under a given policy
+    ///
+    /// Under [`BudgetPolicy::PooledTotal`] only the house total counts.
+    #[must_use]
+    pub fn within_policy(&self, policy: BudgetPolicy) -> bool {
+        match policy {
+            BudgetPolicy::Strict => self.within_budget(),
+            BudgetPolicy::PooledTotal => self.total_used_ms <= self.total_budget_ms,
+        }
+    }
+
+    /// Jidoka alert for this render, if the policy calls for one
+    ///
+    /// Under [`BudgetPolicy::PooledTotal`] per-brick overruns are
+    /// informational and only a breach of the house total raises an alert.
+    #[must_use]
+    pub fn jidoka_alert(&self, policy: BudgetPolicy) -> Option<JidokaAlert> {
+        match policy {
+            BudgetPolicy::Strict => self
+                .violations
+                .first()
+                .map(|v| JidokaAlert::from_violation(&self.house_name, v)),
+            BudgetPolicy::PooledTotal => (self.total_used_ms > self.total_budget_ms)
+                .then(|| JidokaAlert::from_violation(&self.house_name, &self.total_violation())),
+        }
+    }
+
+    /// Violation describing the house total as a whole
+    fn total_violation(&self) -> BudgetViolation {
+        BudgetViolation {
+            brick_name: self.house_name.clone(),
+            budget: BrickBudget::uniform(self.total_budget_ms),
+            actual: Duration::from_millis(u64::from(self.total_used_ms)),
+            phase: None,
+        }
+    }
+
     /// Get budget utilization as percentage
     #[must_use]
     pub fn utilization(&self) -> f32 {
@@ -147,9 +201,23 @@ impl BrickHouse {
             budget: BrickBudget::uniform(budget_ms),
             bricks: Vec::new(),
             last_report: None,
+            policy: BudgetPolicy::Strict,
         }
     }
 
+    /// Set the render-time budget policy
+    #[must_use]
+    pub fn with_policy(mut self, policy: BudgetPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Get the render-time budget policy
+    #[must_use]
+    pub fn policy(&self) -> BudgetPolicy {
+        self.policy
+    }
+
     /// Add a brick with a specific budget allocation
     ///
     /// # Errors
@@ -228,7 +296,8 @@ impl BrickHouse {
     ///
     /// # Errors
     ///
-    /// Returns an error if any brick exceeds its budget (Jidoka).
+    /// Returns an error if any brick exceeds its budget (Jidoka), or under
+    /// [`BudgetPolicy::PooledTotal`] only if the house total is exceeded.
     pub fn render(&mut self) -> BrickResult<String> {
         let mut html_parts = Vec::new();
         let mut timings = HashMap::new();
@@ -251,8 +320,8 @@ impl BrickHouse {
                 return Err(BrickError::AssertionFailed { assertion, reason });
             }
 
-            // Generate HTML
-            let html = entry.brick.to_html();
+            // Run measure/layout/paint, timing each phase
+            let (html, phase_timings) = PhaseTimings::record(entry.brick.as_ref());
             html_parts.push(html);
 
             let elapsed = start.elapsed();
@@ -274,30 +343,37 @@ impl BrickHouse {
             );
 
             if exceeded {
+                let budget = BrickBudget::uniform(entry.allocated_ms);
                 violations.push(BudgetViolation {
                     brick_name,
-                    budget: BrickBudget::uniform(entry.allocated_ms),
+                    budget,
                     actual: elapsed,
-                    phase: Some(BrickPhase::Paint),
+                    phase: phase_timings.overrun(&budget),
                 });
             }
         }
 
         // Store report
-        self.last_report = Some(BudgetReport {
+        let report = BudgetReport {
             house_name: self.name.clone(),
             total_budget_ms: self.budget.total_ms,
             total_used_ms,
             brick_timings: timings,
-            violations: violations.clone(),
+            violations,
             timestamp: std::time::SystemTime::now(),
-        });
+        };
 
         // Jidoka: stop-the-line on violations
-        if !violations.is_empty() {
-            return Err(BrickError::BudgetExceeded(
-                violations.into_iter().next().expect("violations not empty"),
-            ));
+        let stop = match self.policy {
+            BudgetPolicy::Strict => report.violations.first().cloned(),
+            BudgetPolicy::PooledTotal => {
+                (!report.within_policy(self.policy)).then(|| report.total_violation())
+            }
+        };
+        self.last_report = Some(report);
+
+        if let Some(violation) = stop {
+            return Err(BrickError::BudgetExceeded(violation));
         }
 
         Ok(html_parts.join("\n"))
@@ -325,6 +401,7 @@ pub struct BrickHouseBuilder {
     name: String,
     budget_ms: u32,
     bricks: Vec<(Arc<dyn Brick>, u32)>,
+    policy: BudgetPolicy,
 }
 
 impl std::fmt::Debug for BrickHouseBuilder {
@@ -333,6 +410,7 @@ impl std::fmt::Debug for BrickHouseBuilder {
             .field("name", &self.name)
             .field("budget_ms", &self.budget_ms)
             .field("brick_count", &self.bricks.len())
+            .field("policy", &self.policy)
             .finish()
     }
 }
@@ -345,9 +423,17 @@ impl BrickHouseBuilder {
             name: name.into(),
             budget_ms: 1000, // Default 1 second
             bricks: Vec::new(),
+            policy: BudgetPolicy::Strict,
         }
     }
 
+    /// Set the render-time budget policy
+    #[must_use]
+    pub fn policy(mut self, policy: BudgetPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
     /// Set the total budget in milliseconds
     #[must_use]
     pub fn budget_ms(mut self, ms: u32) -> Self {
@@ -379,7 +465,7 @@ impl BrickHouseBuilder {
             }));
         }
 
-        let mut house = BrickHouse::new(self.name, self.budget_ms);
+        let mut house = BrickHouse::new(self.name, self.budget_ms).with_policy(self.policy);
         for (brick, budget) in self.bricks {
             house.add_brick(brick, budget)?;
         }
@@ -476,6 +562,109 @@ mod tests {
         }
     }
 
+    /// Brick that sleeps during paint to simulate a slow render
+    struct SleepyBrick {
+        name: &'static str,
+        delay: Duration,
+    }
+
+    impl Brick for SleepyBrick {
+        fn brick_name(&self) -> &'static str {
+            self.name
+        }
+
+        fn assertions(&self) -> &[BrickAssertion] {
+            &[]
+        }
+
+        fn budget(&self) -> BrickBudget {
+            BrickBudget::uniform(16)
+        }
+
+        fn verify(&self) -> BrickVerification {
+            BrickVerification {
+                passed: vec![],
+                failed: vec![],
+                verification_time: Duration::from_micros(1),
+            }
+        }
+
+        fn paint_phase(&self) -> String {
+            std::thread::sleep(self.delay);
+            self.to_html()
+        }
+
+        fn to_html(&self) -> String {
+            format!("<div class=\"{}\"></div>", self.name)
+        }
+
+        fn to_css(&self) -> String {
+            String::new()
+        }
+    }
+
+    fn over_and_under_house(policy: BudgetPolicy, budget_ms: u32) -> BrickHouse {
+        BrickHouseBuilder::new("pooled")
+            .budget_ms(budget_ms)
+            .policy(policy)
+            .brick(Arc::new(SimpleBrick { name: "a" }), 10)
+            .brick(
+                Arc::new(SleepyBrick {
+                    name: "b",
+                    delay: Duration::from_millis(15),
+                }),
+                5,
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_pooled_total_tolerates_individual_overrun() {
+        let mut house = over_and_under_house(BudgetPolicy::PooledTotal, 200);
+        assert_eq!(house.policy(), BudgetPolicy::PooledTotal);
+
+        let html = house.render().expect("pooled budget should absorb overrun");
+        assert!(html.contains("class=\"b\""));
+
+        let report = house.last_report().unwrap();
+        assert!(report.within_policy(BudgetPolicy::PooledTotal));
+        assert!(!report.within_budget());
+        assert!(report.jidoka_alert(BudgetPolicy::PooledTotal).is_none());
+        assert_eq!(report.violations().len(), 1);
+        assert_eq!(report.violations()[0].brick_name, "b");
+        assert!(report.brick_timings["b"].exceeded);
+        assert!(!report.brick_timings["a"].exceeded);
+    }
+
+    #[test]
+    fn test_strict_policy_stops_on_individual_overrun() {
+        let mut house = over_and_under_house(BudgetPolicy::Strict, 200);
+
+        let result = house.render();
+        assert!(matches!(result, Err(BrickError::BudgetExceeded(v)) if v.brick_name == "b"));
+        let report = house.last_report().unwrap();
+        assert!(report.jidoka_alert(BudgetPolicy::Strict).is_some());
+    }
+
+    #[test]
+    fn test_pooled_total_stops_on_house_breach() {
+        let mut house = over_and_under_house(BudgetPolicy::PooledTotal, 15);
+        house.bricks[1].brick = Arc::new(SleepyBrick {
+            name: "b",
+            delay: Duration::from_millis(30),
+        });
+
+        let result = house.render();
+        assert!(matches!(result, Err(BrickError::BudgetExceeded(v)) if v.brick_name == "pooled"));
+
+        let report = house.last_report().unwrap();
+        let alert = report.jidoka_alert(BudgetPolicy::PooledTotal).unwrap();
+        assert_eq!(alert.brick_name, "pooled");
+        assert_eq!(alert.budget_ms, 15);
+        assert!(alert.actual_ms >= 30);
+    }
+
     #[test]
     fn test_brick_house_creation() {
         let house = BrickHouse::new("test-house", 1000);
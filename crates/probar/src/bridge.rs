@@ -30,7 +30,8 @@
 use crate::result::ProbarResult;
 use crate::runtime::{EntityId, MemoryView, StateDelta};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
 
 /// Game state snapshot with delta encoding
 ///
@@ -373,6 +374,17 @@ impl SnapshotCache {
     }
 }
 
+/// Snapshot returned by [`StateBridge`] scrubbing
+#[derive(Debug, Clone)]
+pub struct ScrubPosition {
+    /// Snapshot at the scrubber position
+    pub snapshot: GameStateSnapshot,
+    /// Frame that was asked for
+    pub requested: u64,
+    /// Whether the request fell outside the recorded range and was clamped
+    pub clamped: bool,
+}
+
 /// State bridge for game state inspection
 ///
 /// Provides unified access to game state whether using WasmRuntime (zero-copy)
@@ -387,6 +399,10 @@ pub struct StateBridge {
     snapshot_cache: SnapshotCache,
     /// Delta history for replay
     delta_history: Vec<StateDelta>,
+    /// Recorded snapshots for scrubbing, ordered by frame
+    timeline: BTreeMap<u64, GameStateSnapshot>,
+    /// Scrubber position (None = live, past the last recorded frame)
+    scrub_frame: Option<u64>,
 }
 
 impl StateBridge {
@@ -398,6 +414,8 @@ impl StateBridge {
             memory_view: Some(memory_view),
             snapshot_cache: SnapshotCache::new(100),
             delta_history: Vec::new(),
+            timeline: BTreeMap::new(),
+            scrub_frame: None,
         }
     }
 
@@ -411,6 +429,8 @@ impl StateBridge {
             memory_view: None,
             snapshot_cache: SnapshotCache::new(100),
             delta_history: Vec::new(),
+            timeline: BTreeMap::new(),
+            scrub_frame: None,
         }
     }
 
@@ -473,6 +493,96 @@ impl StateBridge {
         Ok(snapshot)
     }
 
+    /// Record a snapshot for later scrubbing
+    pub fn record_snapshot(&mut self, snapshot: GameStateSnapshot) {
+        self.timeline.insert(snapshot.frame, snapshot);
+    }
+
+    /// Move the scrubber to `frame` and return the snapshot there
+    ///
+    /// Requests outside the recorded range clamp to the first/last frame
+    /// and set [`ScrubPosition::clamped`]. Inside the range, a frame with no
+    /// snapshot resolves to the closest earlier recorded frame. Returns
+    /// `None` if nothing has been recorded.
+    pub fn scrub_to(&mut self, frame: u64) -> Option<ScrubPosition> {
+        let first = *self.timeline.keys().next()?;
+        let last = *self.timeline.keys().next_back()?;
+
+        let (target, clamped) = if frame < first {
+            (first, true)
+        } else if frame > last {
+            (last, true)
+        } else {
+            let (&at_or_before, _) = self.timeline.range(..=frame).next_back()?;
+            (at_or_before, false)
+        };
+
+        self.position_at(target, frame, clamped)
+    }
+
+    /// Step the scrubber to the previous recorded frame
+    ///
+    /// From the live position this returns the last recorded frame. At the
+    /// first frame the scrubber stays put and the result is clamped.
+    pub fn step_back(&mut self) -> Option<ScrubPosition> {
+        let first = *self.timeline.keys().next()?;
+
+        let (target, requested, clamped) = match self.scrub_frame {
+            None => {
+                let last = *self.timeline.keys().next_back()?;
+                (last, last, false)
+            }
+            Some(current) => match self.timeline.range(..current).next_back() {
+                Some((&prev, _)) => (prev, prev, false),
+                None => (first, current.saturating_sub(1), true),
+            },
+        };
+
+        self.position_at(target, requested, clamped)
+    }
+
+    /// Step the scrubber to the next recorded frame
+    ///
+    /// At (or past) the last frame the scrubber stays on the last frame and
+    /// the result is clamped.
+    pub fn step_forward(&mut self) -> Option<ScrubPosition> {
+        let last = *self.timeline.keys().next_back()?;
+
+        let current = self.scrub_frame.unwrap_or(last);
+        let next = self
+            .timeline
+            .range((Bound::Excluded(current), Bound::Unbounded))
+            .next();
+        let (target, requested, clamped) = match next {
+            Some((&next, _)) => (next, next, false),
+            None => (last, current.saturating_add(1), true),
+        };
+
+        self.position_at(target, requested, clamped)
+    }
+
+    /// Current scrubber frame (None when live)
+    #[must_use]
+    pub const fn scrub_frame(&self) -> Option<u64> {
+        self.scrub_frame
+    }
+
+    /// Return the scrubber to the live position
+    pub fn resume_live(&mut self) {
+        self.scrub_frame = None;
+    }
+
+    /// Set the scrubber to a recorded frame and fetch its snapshot
+    fn position_at(&mut self, target: u64, requested: u64, clamped: bool) -> Option<ScrubPosition> {
+        let snapshot = self.timeline.get(&target)?.clone();
+        self.scrub_frame = Some(target);
+        Some(ScrubPosition {
+            snapshot,
+            requested,
+            clamped,
+        })
+    }
+
     /// Record a delta from current state
     pub fn record_delta(&mut self, delta: StateDelta) {
         self.delta_history.push(delta);
@@ -837,4 +947,124 @@ mod tests {
             assert!(diff.perceptual_similarity < 1.0);
         }
     }
+
+    mod scrubber_tests {
+        use super::*;
+
+        fn recorded_bridge(frames: u64) -> StateBridge {
+            let mut bridge = StateBridge::rpc("scrub-session");
+            for frame in 0..frames {
+                let mut state = GameStateData::new();
+                state.set_score("frame", frame as i32);
+                state.add_position(1, frame as f32 * 10.0, 0.0);
+                bridge.record_snapshot(GameStateSnapshot::new(frame, state));
+            }
+            bridge
+        }
+
+        fn score(position: &ScrubPosition) -> Option<i32> {
+            position.snapshot.state.get_score("frame")
+        }
+
+        #[test]
+        fn test_scrub_to_and_step_forward() {
+            let mut bridge = recorded_bridge(10);
+
+            let at3 = bridge.scrub_to(3).unwrap();
+            assert_eq!(at3.snapshot.frame, 3);
+            assert_eq!(score(&at3), Some(3));
+            assert_eq!(at3.snapshot.state.get_position(1), Some((30.0, 0.0)));
+            assert!(!at3.clamped);
+            assert_eq!(bridge.scrub_frame(), Some(3));
+
+            let at4 = bridge.step_forward().unwrap();
+            assert_eq!(at4.snapshot.frame, 4);
+            assert_eq!(score(&at4), Some(4));
+            assert!(!at4.clamped);
+
+            let back = bridge.step_back().unwrap();
+            assert_eq!(back.snapshot.frame, 3);
+            assert_eq!(back.snapshot.state_hash, at3.snapshot.state_hash);
+        }
+
+        #[test]
+        fn test_scrub_beyond_range_clamps() {
+            let mut bridge = recorded_bridge(10);
+
+            let past_end = bridge.scrub_to(50).unwrap();
+            assert!(past_end.clamped);
+            assert_eq!(past_end.requested, 50);
+            assert_eq!(past_end.snapshot.frame, 9);
+
+            let forward = bridge.step_forward().unwrap();
+            assert!(forward.clamped);
+            assert_eq!(forward.snapshot.frame, 9);
+
+            let start = bridge.scrub_to(0).unwrap();
+            assert!(!start.clamped);
+            let before_start = bridge.step_back().unwrap();
+            assert!(before_start.clamped);
+            assert_eq!(before_start.snapshot.frame, 0);
+        }
+
+        #[test]
+        fn test_scrub_below_first_recorded_frame_clamps() {
+            let mut bridge = StateBridge::rpc("late-start");
+            for frame in 5..8 {
+                bridge.record_snapshot(GameStateSnapshot::new(frame, GameStateData::new()));
+            }
+
+            let early = bridge.scrub_to(2).unwrap();
+            assert!(early.clamped);
+            assert_eq!(early.snapshot.frame, 5);
+        }
+
+        #[test]
+        fn test_step_back_from_live_returns_last_frame() {
+            let mut bridge = recorded_bridge(10);
+            assert_eq!(bridge.scrub_frame(), None);
+
+            let last = bridge.step_back().unwrap();
+            assert_eq!(last.snapshot.frame, 9);
+            assert!(!last.clamped);
+
+            let prev = bridge.step_back().unwrap();
+            assert_eq!(prev.snapshot.frame, 8);
+
+            bridge.resume_live();
+            assert_eq!(bridge.scrub_frame(), None);
+        }
+
+        #[test]
+        fn test_scrub_gap_resolves_to_earlier_frame() {
+            let mut bridge = StateBridge::rpc("sparse");
+            for frame in [0, 10, 20] {
+                bridge.record_snapshot(GameStateSnapshot::new(frame, GameStateData::new()));
+            }
+
+            let mid = bridge.scrub_to(15).unwrap();
+            assert!(!mid.clamped);
+            assert_eq!(mid.snapshot.frame, 10);
+            assert_eq!(bridge.step_forward().unwrap().snapshot.frame, 20);
+        }
+
+        #[test]
+        fn test_scrub_empty_bridge() {
+            let mut bridge = StateBridge::rpc("empty");
+            assert!(bridge.scrub_to(3).is_none());
+            assert!(bridge.step_back().is_none());
+            assert!(bridge.step_forward().is_none());
+            assert_eq!(bridge.scrub_frame(), None);
+        }
+
+        #[test]
+        fn test_scrub_reaches_frames_beyond_cache_capacity() {
+            let mut bridge = recorded_bridge(150);
+
+            let early = bridge.scrub_to(3).unwrap();
+            assert!(!early.clamped);
+            assert_eq!(early.snapshot.frame, 3);
+            assert_eq!(bridge.step_back().unwrap().snapshot.frame, 2);
+        }
+    }
 }
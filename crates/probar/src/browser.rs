@@ -42,6 +42,37 @@ pub enum BrowserConsoleLevel {
     Error,
     /// console.debug
     Debug,
+    /// Uncaught exception (`window.onerror`)
+    UncaughtException,
+    /// Unhandled promise rejection (`window.onunhandledrejection`)
+    UnhandledRejection,
+}
+
+impl BrowserConsoleLevel {
+    /// Parse a level name recorded by the injected capture script
+    ///
+    /// Unknown names fall back to [`Self::Log`].
+    #[must_use]
+    pub fn from_capture(level: &str) -> Self {
+        match level {
+            "info" => Self::Info,
+            "warn" => Self::Warning,
+            "error" => Self::Error,
+            "debug" => Self::Debug,
+            "uncaught" => Self::UncaughtException,
+            "unhandledrejection" => Self::UnhandledRejection,
+            _ => Self::Log,
+        }
+    }
+
+    /// Check if this message is evidence that the app is broken
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::Error | Self::UncaughtException | Self::UnhandledRejection
+        )
+    }
 }
 
 impl std::fmt::Display for BrowserConsoleLevel {
@@ -52,10 +83,57 @@ impl std::fmt::Display for BrowserConsoleLevel {
             Self::Warning => write!(f, "warn"),
             Self::Error => write!(f, "error"),
             Self::Debug => write!(f, "debug"),
+            Self::UncaughtException => write!(f, "uncaught"),
+            Self::UnhandledRejection => write!(f, "unhandledrejection"),
         }
     }
 }
 
+/// JavaScript injected by `Page::inject_console_capture`
+///
+/// Wraps the console methods and listens for uncaught exceptions and
+/// unhandled promise rejections, recording everything into
+/// `window.__probar_console_messages`.
+#[cfg(any(feature = "browser", test))]
+const CONSOLE_CAPTURE_SCRIPT: &str = r#"
+(function() {
+    if (window.__probar_console_hooked) return;
+    window.__probar_console_hooked = true;
+    window.__probar_console_messages = [];
+
+    const levels = ['log', 'info', 'warn', 'error', 'debug'];
+    levels.forEach(level => {
+        const original = console[level];
+        console[level] = function(...args) {
+            window.__probar_console_messages.push({
+                level: level,
+                text: args.map(a => String(a)).join(' '),
+                timestamp: Date.now()
+            });
+            original.apply(console, args);
+        };
+    });
+
+    window.addEventListener('error', event => {
+        window.__probar_console_messages.push({
+            level: 'uncaught',
+            text: String(event.error || event.message),
+            timestamp: Date.now(),
+            source: event.filename || null,
+            line: event.lineno || null
+        });
+    });
+
+    window.addEventListener('unhandledrejection', event => {
+        window.__probar_console_messages.push({
+            level: 'unhandledrejection',
+            text: String(event.reason),
+            timestamp: Date.now()
+        });
+    });
+})();
+"#;
+
 /// A captured browser console message (from CDP)
 #[derive(Debug, Clone)]
 pub struct BrowserConsoleMessage {
@@ -182,8 +260,12 @@ mod cdp {
     use crate::cdp_coverage::{
         CoverageConfig, CoverageRange, CoverageReport, FunctionCoverage, ScriptCoverage,
     };
+    use crate::emulation::MediaFeature;
     use crate::renacer_integration::TraceSpan;
     use chromiumoxide::browser::{Browser as CdpBrowser, BrowserConfig as CdpConfig};
+    use chromiumoxide::cdp::browser_protocol::emulation::{
+        MediaFeature as CdpMediaFeature, SetDeviceMetricsOverrideParams, SetEmulatedMediaParams,
+    };
     use chromiumoxide::cdp::browser_protocol::input::{
         DispatchTouchEventParams, DispatchTouchEventType, TouchPoint,
     };
@@ -630,6 +712,60 @@ mod cdp {
             }
         }
 
+        /// Override viewport size, device pixel ratio, and mobile mode
+        /// (CDP `Emulation.setDeviceMetricsOverride`)
+        ///
+        /// # Errors
+        ///
+        /// Returns error if the CDP command fails
+        pub async fn set_device_metrics(
+            &mut self,
+            width: u32,
+            height: u32,
+            device_scale_factor: f64,
+            mobile: bool,
+        ) -> ProbarResult<()> {
+            if let Some(ref inner) = self.inner {
+                let page = inner.lock().await;
+                let params =
+                    SetDeviceMetricsOverrideParams::new(width, height, device_scale_factor, mobile);
+                let _ = page
+                    .execute(params)
+                    .await
+                    .map_err(|e| ProbarError::PageError {
+                        message: format!("Emulation.setDeviceMetricsOverride failed: {e}"),
+                    })?;
+            }
+            self.width = width;
+            self.height = height;
+            Ok(())
+        }
+
+        /// Override CSS media features (CDP `Emulation.setEmulatedMedia`)
+        ///
+        /// # Errors
+        ///
+        /// Returns error if the CDP command fails
+        pub async fn set_emulated_media(&self, features: &[MediaFeature]) -> ProbarResult<()> {
+            if let Some(ref inner) = self.inner {
+                let page = inner.lock().await;
+                let params = SetEmulatedMediaParams::builder()
+                    .features(
+                        features
+                            .iter()
+                            .map(|f| CdpMediaFeature::new(f.name.clone(), f.value.clone())),
+                    )
+                    .build();
+                let _ = page
+                    .execute(params)
+                    .await
+                    .map_err(|e| ProbarError::PageError {
+                        message: format!("Emulation.setEmulatedMedia failed: {e}"),
+                    })?;
+            }
+            Ok(())
+        }
+
         /// Get current URL
         #[must_use]
         pub fn current_url(&self) -> &str {
@@ -826,32 +962,11 @@ mod cdp {
             if let Some(ref inner) = self.inner {
                 let page = inner.lock().await;
 
-                // Inject console interceptor
-                page.evaluate(
-                    r#"
-                    (function() {
-                        if (window.__probar_console_hooked) return;
-                        window.__probar_console_hooked = true;
-                        window.__probar_console_messages = [];
-
-                        const levels = ['log', 'info', 'warn', 'error', 'debug'];
-                        levels.forEach(level => {
-                            const original = console[level];
-                            console[level] = function(...args) {
-                                window.__probar_console_messages.push({
-                                    level: level,
-                                    text: args.map(a => String(a)).join(' '),
-                                    timestamp: Date.now()
-                                });
-                                original.apply(console, args);
-                            };
-                        });
-                    })();
-                    "#,
-                )
-                .await
-                .map_err(|e| ProbarError::WasmError {
-                    message: format!("Failed to inject console capture: {e}"),
+                // Inject console interceptor and error listeners
+                page.evaluate(CONSOLE_CAPTURE_SCRIPT).await.map_err(|e| {
+                    ProbarError::WasmError {
+                        message: format!("Failed to inject console capture: {e}"),
+                    }
                 })?;
 
                 self.console_capture_enabled = true;
@@ -884,21 +999,20 @@ mod cdp {
                     .map(|arr| {
                         arr.iter()
                             .filter_map(|v| {
-                                let level_str = v.get("level")?.as_str()?;
-                                let level = match level_str {
-                                    "log" => BrowserConsoleLevel::Log,
-                                    "info" => BrowserConsoleLevel::Info,
-                                    "warn" => BrowserConsoleLevel::Warning,
-                                    "error" => BrowserConsoleLevel::Error,
-                                    "debug" => BrowserConsoleLevel::Debug,
-                                    _ => BrowserConsoleLevel::Log,
-                                };
+                                let level =
+                                    BrowserConsoleLevel::from_capture(v.get("level")?.as_str()?);
                                 Some(BrowserConsoleMessage {
                                     level,
                                     text: v.get("text")?.as_str()?.to_string(),
                                     timestamp: v.get("timestamp")?.as_u64().unwrap_or(0),
-                                    source: None,
-                                    line: None,
+                                    source: v
+                                        .get("source")
+                                        .and_then(serde_json::Value::as_str)
+                                        .map(String::from),
+                                    line: v
+                                        .get("line")
+                                        .and_then(serde_json::Value::as_u64)
+                                        .map(|l| l as u32),
                                 })
                             })
                             .collect()
@@ -1401,6 +1515,35 @@ mod mock {
             Ok(vec![])
         }
 
+        /// Override viewport size (mock ignores pixel ratio and mobile mode)
+        ///
+        /// # Errors
+        ///
+        /// Returns Ok in mock mode
+        pub fn set_device_metrics(
+            &mut self,
+            width: u32,
+            height: u32,
+            _device_scale_factor: f64,
+            _mobile: bool,
+        ) -> ProbarResult<()> {
+            self.width = width;
+            self.height = height;
+            Ok(())
+        }
+
+        /// Override CSS media features (mock does nothing)
+        ///
+        /// # Errors
+        ///
+        /// Returns Ok in mock mode
+        pub fn set_emulated_media(
+            &self,
+            _features: &[crate::emulation::MediaFeature],
+        ) -> ProbarResult<()> {
+            Ok(())
+        }
+
         /// Get current URL
         #[must_use]
         pub fn current_url(&self) -> &str {
@@ -1832,6 +1975,16 @@ mod tests {
             assert!(screenshot.is_empty()); // Mock returns empty
         }
 
+        #[test]
+        fn test_page_set_device_metrics_resizes() {
+            let mut page = Page::new(800, 600);
+            page.set_device_metrics(393, 852, 3.0, true).unwrap();
+            assert_eq!((page.width, page.height), (393, 852));
+
+            let reduce = crate::emulation::MediaFeature::new("prefers-reduced-motion", "reduce");
+            page.set_emulated_media(&[reduce]).unwrap();
+        }
+
         #[test]
         fn test_page_debug() {
             let page = Page::new(800, 600);
@@ -2239,6 +2392,47 @@ mod tests {
             assert_eq!(format!("{}", BrowserConsoleLevel::Debug), "debug");
         }
 
+        #[test]
+        fn test_browser_console_level_from_capture() {
+            assert_eq!(
+                BrowserConsoleLevel::from_capture("warn"),
+                BrowserConsoleLevel::Warning
+            );
+            assert_eq!(
+                BrowserConsoleLevel::from_capture("uncaught"),
+                BrowserConsoleLevel::UncaughtException
+            );
+            assert_eq!(
+                BrowserConsoleLevel::from_capture("unhandledrejection"),
+                BrowserConsoleLevel::UnhandledRejection
+            );
+            assert_eq!(
+                BrowserConsoleLevel::from_capture("trace"),
+                BrowserConsoleLevel::Log
+            );
+        }
+
+        #[test]
+        fn test_browser_console_level_is_failure() {
+            assert!(BrowserConsoleLevel::Error.is_failure());
+            assert!(BrowserConsoleLevel::UncaughtException.is_failure());
+            assert!(BrowserConsoleLevel::UnhandledRejection.is_failure());
+            assert!(!BrowserConsoleLevel::Warning.is_failure());
+            assert!(!BrowserConsoleLevel::Log.is_failure());
+        }
+
+        #[test]
+        fn test_console_capture_script_listens_for_errors() {
+            assert!(CONSOLE_CAPTURE_SCRIPT.contains("'unhandledrejection'"));
+            assert!(CONSOLE_CAPTURE_SCRIPT.contains("'error'"));
+            for level in [
+                BrowserConsoleLevel::UncaughtException,
+                BrowserConsoleLevel::UnhandledRejection,
+            ] {
+                assert!(CONSOLE_CAPTURE_SCRIPT.contains(&format!("level: '{level}'")));
+            }
+        }
+
         #[test]
         fn test_browser_console_level_eq() {
             assert_eq!(BrowserConsoleLevel::Log, BrowserConsoleLevel::Log);
@@ -24,6 +24,9 @@ impl RequiredHeaders {
     pub const COEP: &'static str = "require-corp";
 }
 
+/// Worker count assumed when `navigator.hardwareConcurrency` is unavailable
+pub const DEFAULT_MAX_WORKERS: u32 = 4;
+
 /// WASM threading capabilities detected from browser context
 #[derive(Debug, Clone, Default)]
 pub struct WasmThreadCapabilities {
@@ -39,6 +42,10 @@ pub struct WasmThreadCapabilities {
     /// `navigator.hardwareConcurrency` value
     pub hardware_concurrency: u32,
 
+    /// Maximum useful worker count from `hardwareConcurrency` (`None` when
+    /// it could not be read)
+    pub max_workers: Option<u32>,
+
     /// COOP header value (if present)
     pub coop_header: Option<String>,
 
@@ -72,6 +79,7 @@ impl WasmThreadCapabilities {
             shared_array_buffer: true,
             atomics: true,
             hardware_concurrency: 8,
+            max_workers: Some(8),
             coop_header: Some(RequiredHeaders::COOP.to_string()),
             coep_header: Some(RequiredHeaders::COEP.to_string()),
             is_secure_context: true,
@@ -87,6 +95,7 @@ impl WasmThreadCapabilities {
             shared_array_buffer: false,
             atomics: false,
             hardware_concurrency: 1,
+            max_workers: Some(1),
             coop_header: None,
             coep_header: None,
             is_secure_context: false,
@@ -186,6 +195,22 @@ impl WasmThreadCapabilities {
         self.hardware_concurrency.saturating_sub(1).clamp(1, 8)
     }
 
+    /// Maximum worker count, falling back to [`DEFAULT_MAX_WORKERS`] when
+    /// `hardwareConcurrency` could not be read
+    #[must_use]
+    pub fn max_workers_or_default(&self) -> u32 {
+        self.max_workers.unwrap_or(DEFAULT_MAX_WORKERS)
+    }
+
+    /// Worker count read from the browser
+    ///
+    /// # Errors
+    /// Returns [`CapabilityError::ConcurrencyUnknown`] if `hardwareConcurrency`
+    /// could not be read
+    pub fn detected_max_workers(&self) -> Result<u32, CapabilityError> {
+        self.max_workers.ok_or(CapabilityError::ConcurrencyUnknown)
+    }
+
     /// Check if threading is available (non-asserting)
     #[must_use]
     pub fn is_threading_available(&self) -> bool {
@@ -218,7 +243,7 @@ impl WasmThreadCapabilities {
         crossOriginIsolated: !!self.crossOriginIsolated,
         sharedArrayBuffer: typeof SharedArrayBuffer !== 'undefined',
         atomics: typeof Atomics !== 'undefined',
-        hardwareConcurrency: navigator.hardwareConcurrency || 1,
+        hardwareConcurrency: navigator.hardwareConcurrency || null,
         isSecureContext: !!self.isSecureContext,
         coopHeader: null,
         coepHeader: null
@@ -238,12 +263,17 @@ impl WasmThreadCapabilities {
     pub fn from_json(json: &str) -> Result<Self, CapabilityError> {
         let parsed: serde_json::Value =
             serde_json::from_str(json).map_err(|e| CapabilityError::ParseError(e.to_string()))?;
+        let concurrency = parsed["hardwareConcurrency"]
+            .as_u64()
+            .filter(|&n| n > 0)
+            .map(|n| n.min(u64::from(u32::MAX)) as u32);
 
         Ok(Self {
             cross_origin_isolated: parsed["crossOriginIsolated"].as_bool().unwrap_or(false),
             shared_array_buffer: parsed["sharedArrayBuffer"].as_bool().unwrap_or(false),
             atomics: parsed["atomics"].as_bool().unwrap_or(false),
             hardware_concurrency: parsed["hardwareConcurrency"].as_u64().unwrap_or(1) as u32,
+            max_workers: concurrency,
             is_secure_context: parsed["isSecureContext"].as_bool().unwrap_or(false),
             coop_header: parsed["coopHeader"].as_str().map(String::from),
             coep_header: parsed["coepHeader"].as_str().map(String::from),
@@ -275,6 +305,26 @@ impl WasmThreadCapabilities {
         Self::from_json(&result)
     }
 
+    /// Detect capabilities through any [`ProbarDriver`](crate::driver::ProbarDriver)
+    ///
+    /// # Errors
+    /// Returns error if script execution or parsing fails
+    #[cfg(feature = "browser")]
+    pub async fn detect_with_driver<D>(driver: &D) -> Result<Self, CapabilityError>
+    where
+        D: crate::driver::ProbarDriver + ?Sized,
+    {
+        let value = driver
+            .execute_js(Self::detection_js())
+            .await
+            .map_err(|e| CapabilityError::ParseError(format!("Driver evaluation failed: {e}")))?;
+
+        match value {
+            serde_json::Value::String(json) => Self::from_json(&json),
+            other => Self::from_json(&other.to_string()),
+        }
+    }
+
     /// Detect capabilities and assert threading is ready
     ///
     /// Convenience method that detects and validates in one call.
@@ -298,6 +348,8 @@ pub enum CapabilityError {
     InsufficientResources(String),
     /// Parse error
     ParseError(String),
+    /// `navigator.hardwareConcurrency` could not be read
+    ConcurrencyUnknown,
     /// Worker state mismatch
     WorkerState {
         /// Expected state
@@ -319,6 +371,10 @@ impl fmt::Display for CapabilityError {
             }
             Self::InsufficientResources(msg) => write!(f, "Insufficient resources: {msg}"),
             Self::ParseError(msg) => write!(f, "Parse error: {msg}"),
+            Self::ConcurrencyUnknown => write!(
+                f,
+                "navigator.hardwareConcurrency unavailable; assuming {DEFAULT_MAX_WORKERS} workers"
+            ),
             Self::WorkerState { expected, actual } => {
                 write!(
                     f,
@@ -342,6 +398,8 @@ pub enum WorkerState {
     Ready,
     /// Worker processing a task
     Processing,
+    /// Worker message queue is full (backpressure)
+    Saturated,
     /// Worker encountered an error
     Error,
     /// Worker terminated
@@ -361,6 +419,7 @@ impl fmt::Display for WorkerState {
             Self::Loading => write!(f, "Loading"),
             Self::Ready => write!(f, "Ready"),
             Self::Processing => write!(f, "Processing"),
+            Self::Saturated => write!(f, "Saturated"),
             Self::Error => write!(f, "Error"),
             Self::Terminated => write!(f, "Terminated"),
         }
@@ -423,6 +482,10 @@ pub struct WorkerEmulator {
     name: String,
     /// Message queue (ordered)
     message_queue: Vec<WorkerMessage>,
+    /// Maximum queued messages (None = unbounded)
+    queue_capacity: Option<usize>,
+    /// Messages refused by a full queue, kept for inspection
+    rejected_messages: Vec<WorkerMessage>,
     /// Response queue
     response_queue: Vec<WorkerMessage>,
     /// Lamport timestamp for ordering
@@ -447,6 +510,8 @@ impl WorkerEmulator {
             state: WorkerState::Uninitialized,
             name: String::new(),
             message_queue: Vec::new(),
+            queue_capacity: None,
+            rejected_messages: Vec::new(),
             response_queue: Vec::new(),
             lamport_clock: 0,
             simulate_delays: false,
@@ -476,7 +541,34 @@ impl WorkerEmulator {
     }
 
     /// Send a message to the worker
+    ///
+    /// If the queue is bounded and full the message is not queued: it is kept
+    /// in [`Self::rejected_messages`] and the worker reports
+    /// [`WorkerState::Saturated`]. Use [`Self::try_send`] to handle
+    /// backpressure in the producer.
     pub fn send(&mut self, message: WorkerMessage) {
+        if let Err(message) = self.try_send(message) {
+            self.rejected_messages.push(message);
+        }
+    }
+
+    /// Send a message, handing it back if the queue is full
+    ///
+    /// # Errors
+    /// Returns the message unchanged when the bounded queue is at capacity;
+    /// the worker state becomes [`WorkerState::Saturated`].
+    pub fn try_send(&mut self, message: WorkerMessage) -> Result<(), WorkerMessage> {
+        if self.is_queue_full() {
+            self.lamport_clock += 1;
+            self.history.push((
+                self.lamport_clock,
+                "saturated".to_string(),
+                message.type_.clone(),
+            ));
+            self.state = WorkerState::Saturated;
+            return Err(message);
+        }
+
         self.lamport_clock += 1;
         self.history.push((
             self.lamport_clock,
@@ -495,6 +587,54 @@ impl WorkerEmulator {
             }
             _ => {}
         }
+        if self.is_queue_full() {
+            self.state = WorkerState::Saturated;
+        }
+        Ok(())
+    }
+
+    /// Worker takes the next queued message, relieving backpressure
+    pub fn process_next(&mut self) -> Option<WorkerMessage> {
+        if self.message_queue.is_empty() {
+            return None;
+        }
+        let message = self.message_queue.remove(0);
+        self.lamport_clock += 1;
+        self.history.push((
+            self.lamport_clock,
+            "process".to_string(),
+            message.type_.clone(),
+        ));
+        if self.state == WorkerState::Saturated {
+            self.state = WorkerState::Processing;
+        }
+        Some(message)
+    }
+
+    /// Bound the message queue to `capacity` messages
+    #[must_use]
+    pub fn with_queue_capacity(mut self, capacity: usize) -> Self {
+        self.queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Queue capacity (None = unbounded)
+    #[must_use]
+    pub fn queue_capacity(&self) -> Option<usize> {
+        self.queue_capacity
+    }
+
+    /// Whether the bounded queue is at capacity
+    #[must_use]
+    pub fn is_queue_full(&self) -> bool {
+        self.queue_capacity
+            .is_some_and(|capacity| self.message_queue.len() >= capacity)
+    }
+
+    /// Messages refused by [`Self::send`] because the queue was full
+    #[must_use]
+    pub fn rejected_messages(&self) -> &[WorkerMessage] {
+        &self.rejected_messages
     }
 
     /// Receive a response from the worker
@@ -559,7 +699,11 @@ impl WorkerEmulator {
     /// Clear all queues
     pub fn clear(&mut self) {
         self.message_queue.clear();
+        self.rejected_messages.clear();
         self.response_queue.clear();
+        if self.state == WorkerState::Saturated {
+            self.state = WorkerState::Processing;
+        }
     }
 
     /// Verify message ordering (Lamport guarantee)
@@ -1145,6 +1289,44 @@ mod tests {
         assert!(caps.is_secure_context);
     }
 
+    #[test]
+    fn test_from_json_max_workers() {
+        let caps = WasmThreadCapabilities::from_json(r#"{"hardwareConcurrency": 12}"#).unwrap();
+        assert_eq!(caps.max_workers, Some(12));
+        assert_eq!(caps.detected_max_workers().unwrap(), 12);
+
+        let caps = WasmThreadCapabilities::from_json(r#"{"hardwareConcurrency": null}"#).unwrap();
+        assert_eq!(caps.max_workers, None);
+        assert_eq!(caps.max_workers_or_default(), DEFAULT_MAX_WORKERS);
+        let err = caps.detected_max_workers().unwrap_err();
+        assert!(matches!(err, CapabilityError::ConcurrencyUnknown));
+        assert!(err.to_string().contains("hardwareConcurrency"));
+
+        assert_eq!(WasmThreadCapabilities::full_support().max_workers, Some(8));
+    }
+
+    #[cfg(feature = "browser")]
+    #[tokio::test]
+    async fn test_detect_with_driver_max_workers() {
+        use crate::driver::MockDriver;
+
+        let mut driver = MockDriver::new();
+        driver.set_js_result(serde_json::json!({
+            "crossOriginIsolated": true,
+            "sharedArrayBuffer": true,
+            "atomics": true,
+            "hardwareConcurrency": 16,
+            "isSecureContext": true
+        }));
+
+        let caps = WasmThreadCapabilities::detect_with_driver(&driver)
+            .await
+            .unwrap();
+        assert_eq!(caps.max_workers, Some(16));
+        assert_eq!(caps.hardware_concurrency, 16);
+        assert!(caps.is_threading_available());
+    }
+
     #[test]
     fn test_from_json_invalid() {
         let result = WasmThreadCapabilities::from_json("not json");
@@ -1259,6 +1441,71 @@ mod tests {
         assert!(emulator.pending_messages().is_empty());
     }
 
+    #[test]
+    fn test_worker_queue_backpressure() {
+        let mut emulator = WorkerEmulator::ready("slow").with_queue_capacity(3);
+        assert_eq!(emulator.queue_capacity(), Some(3));
+
+        // Worker is busy: nothing is processed while the producer floods it
+        let mut refused = Vec::new();
+        for i in 0..5 {
+            let msg = WorkerMessage::new("Chunk", serde_json::json!({ "seq": i }));
+            if let Err(msg) = emulator.try_send(msg) {
+                refused.push(msg);
+            }
+        }
+
+        assert_eq!(emulator.state(), WorkerState::Saturated);
+        assert!(emulator.is_queue_full());
+        assert_eq!(emulator.pending_messages().len(), 3);
+        // Nothing silently dropped: every message is queued or handed back
+        let seqs: Vec<_> = emulator
+            .pending_messages()
+            .iter()
+            .chain(&refused)
+            .map(|m| m.data["seq"].as_i64().unwrap())
+            .collect();
+        assert_eq!(seqs, vec![0, 1, 2, 3, 4]);
+
+        // Draining one message relieves backpressure
+        assert_eq!(emulator.process_next().unwrap().data["seq"], 0);
+        assert_eq!(emulator.state(), WorkerState::Processing);
+        assert!(emulator.try_send(refused.remove(0)).is_ok());
+        assert_eq!(emulator.state(), WorkerState::Saturated);
+        assert!(emulator.verify_ordering());
+    }
+
+    #[test]
+    fn test_worker_send_keeps_rejected_messages() {
+        let mut emulator = WorkerEmulator::ready("slow").with_queue_capacity(1);
+        emulator.send(WorkerMessage::new("A", serde_json::json!({})));
+        emulator.send(WorkerMessage::new("B", serde_json::json!({})));
+
+        assert_eq!(emulator.state(), WorkerState::Saturated);
+        assert_eq!(emulator.pending_messages()[0].type_, "A");
+        assert_eq!(emulator.rejected_messages()[0].type_, "B");
+        assert!(emulator
+            .history()
+            .iter()
+            .any(|(_, dir, _)| dir == "saturated"));
+
+        emulator.clear();
+        assert!(emulator.rejected_messages().is_empty());
+        assert_eq!(emulator.state(), WorkerState::Processing);
+        assert_eq!(format!("{}", WorkerState::Saturated), "Saturated");
+    }
+
+    #[test]
+    fn test_worker_unbounded_queue_never_saturates() {
+        let mut emulator = WorkerEmulator::ready("fast");
+        for _ in 0..100 {
+            emulator.send(WorkerMessage::new("Chunk", serde_json::json!({})));
+        }
+        assert_eq!(emulator.state(), WorkerState::Processing);
+        assert!(emulator.rejected_messages().is_empty());
+        assert!(emulator.process_next().is_some());
+    }
+
     // ========================================================================
     // Additional coverage tests for CapabilityError Display
     // ========================================================================
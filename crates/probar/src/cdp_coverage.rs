@@ -19,7 +19,8 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
 
 /// Coverage configuration
 #[derive(Debug, Clone)]
@@ -64,6 +65,105 @@ impl CoverageConfig {
     }
 }
 
+/// Minimum coverage requirements for a report
+///
+/// The overall floor alone can hide an untested script behind well-tested
+/// ones, so optional floors apply to every script and every named function.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CoverageThreshold {
+    /// Minimum overall function coverage percentage
+    pub overall: f64,
+    /// Minimum function coverage percentage for each script
+    pub per_file: Option<f64>,
+    /// Minimum block coverage percentage for each named function
+    pub per_function: Option<f64>,
+}
+
+impl CoverageThreshold {
+    /// Create a threshold with an overall floor only
+    #[must_use]
+    pub const fn new(overall: f64) -> Self {
+        Self {
+            overall,
+            per_file: None,
+            per_function: None,
+        }
+    }
+
+    /// Require every script to reach this coverage percentage
+    #[must_use]
+    pub const fn with_file_floor(mut self, percent: f64) -> Self {
+        self.per_file = Some(percent);
+        self
+    }
+
+    /// Require every named function to reach this block coverage percentage
+    #[must_use]
+    pub const fn with_function_floor(mut self, percent: f64) -> Self {
+        self.per_function = Some(percent);
+        self
+    }
+}
+
+/// A script or function whose coverage fell below its floor
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThresholdViolation {
+    /// Script URL
+    pub script_url: String,
+    /// Function name, or `None` for a whole-script violation
+    pub function_name: Option<String>,
+    /// Measured coverage percentage
+    pub percent: f64,
+    /// Required coverage percentage
+    pub floor: f64,
+}
+
+impl std::fmt::Display for ThresholdViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.function_name {
+            Some(name) => write!(
+                f,
+                "{}::{} at {:.1}% (floor {:.1}%)",
+                self.script_url, name, self.percent, self.floor
+            ),
+            None => write!(
+                f,
+                "{} at {:.1}% (floor {:.1}%)",
+                self.script_url, self.percent, self.floor
+            ),
+        }
+    }
+}
+
+/// Outcome of checking a report against a [`CoverageThreshold`]
+#[derive(Debug, Clone)]
+pub struct ThresholdCheck {
+    /// Overall coverage percentage
+    pub overall_percent: f64,
+    /// Whether the overall floor was met
+    pub overall_passed: bool,
+    /// Scripts and functions below their floors
+    pub violations: Vec<ThresholdViolation>,
+}
+
+impl ThresholdCheck {
+    /// Check if every floor was met
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.overall_passed && self.violations.is_empty()
+    }
+
+    /// URLs of scripts that fell below the per-file floor
+    #[must_use]
+    pub fn failing_files(&self) -> Vec<&str> {
+        self.violations
+            .iter()
+            .filter(|v| v.function_name.is_none())
+            .map(|v| v.script_url.as_str())
+            .collect()
+    }
+}
+
 /// A range of bytes/characters in a script that was covered
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoverageRange {
@@ -99,6 +199,16 @@ impl FunctionCoverage {
         self.ranges.iter().map(|r| r.count).sum()
     }
 
+    /// Percentage of this function's ranges that were executed
+    #[must_use]
+    pub fn block_coverage_percent(&self) -> f64 {
+        if self.ranges.is_empty() {
+            return 100.0;
+        }
+        let executed = self.ranges.iter().filter(|r| r.count > 0).count();
+        (executed as f64 / self.ranges.len() as f64) * 100.0
+    }
+
     /// Get the byte range covered
     #[must_use]
     pub fn byte_range(&self) -> Option<(u32, u32)> {
@@ -197,6 +307,57 @@ impl CoverageReport {
         (self.functions_covered() as f64 / total as f64) * 100.0
     }
 
+    /// Check overall, per-script, and per-function floors
+    #[must_use]
+    pub fn check_threshold(&self, threshold: &CoverageThreshold) -> ThresholdCheck {
+        let overall_percent = self.coverage_percent();
+        let mut violations = Vec::new();
+
+        for script in &self.scripts {
+            if let Some(floor) = threshold.per_file {
+                let percent = script.coverage_percent();
+                if percent < floor {
+                    violations.push(ThresholdViolation {
+                        script_url: script.url.clone(),
+                        function_name: None,
+                        percent,
+                        floor,
+                    });
+                }
+            }
+
+            if let Some(floor) = threshold.per_function {
+                for func in script
+                    .functions
+                    .iter()
+                    .filter(|f| !f.function_name.is_empty())
+                {
+                    let percent = func.block_coverage_percent();
+                    if percent < floor {
+                        violations.push(ThresholdViolation {
+                            script_url: script.url.clone(),
+                            function_name: Some(func.function_name.clone()),
+                            percent,
+                            floor,
+                        });
+                    }
+                }
+            }
+        }
+
+        ThresholdCheck {
+            overall_percent,
+            overall_passed: overall_percent >= threshold.overall,
+            violations,
+        }
+    }
+
+    /// Check if the report meets every floor in the threshold
+    #[must_use]
+    pub fn meets_threshold(&self, threshold: &CoverageThreshold) -> bool {
+        self.check_threshold(threshold).passed()
+    }
+
     /// Get WASM-only coverage
     #[must_use]
     pub fn wasm_coverage(&self) -> WasmCoverage {
@@ -331,6 +492,69 @@ impl WasmCoverage {
         }
         (self.functions_covered as f64 / self.functions_total as f64) * 100.0
     }
+
+    /// Resolve covered byte ranges to Rust source lines
+    ///
+    /// Within each function, nested ranges are applied innermost-last so a
+    /// block that never ran (`count: 0`) overrides the count of the range
+    /// enclosing it. Each function then adds its count once to every source
+    /// line it reaches. Covered bytes preceding the first mapping in a range
+    /// are kept in [`LineCoverage::unmapped`] rather than dropped.
+    #[must_use]
+    pub fn to_line_coverage(&self, source_map: &WasmSourceMap) -> LineCoverage {
+        let mut line_coverage = LineCoverage::new();
+
+        for function in self.scripts.iter().flat_map(|s| &s.functions) {
+            let mut ranges: Vec<&CoverageRange> = function.ranges.iter().collect();
+            ranges.sort_by_key(|r| (r.start_offset, Reverse(r.end_offset)));
+
+            // Count at each mapped offset, keyed by the line it belongs to
+            let mut points: BTreeMap<(&str, u32, u32), u32> = BTreeMap::new();
+            for range in ranges {
+                let interior: Vec<&SourceMapEntry> = source_map
+                    .entries
+                    .iter()
+                    .filter(|e| {
+                        e.wasm_offset > range.start_offset && e.wasm_offset < range.end_offset
+                    })
+                    .collect();
+
+                for entry in &interior {
+                    let key = (entry.source_file.as_str(), entry.line, entry.wasm_offset);
+                    let _ = points.insert(key, range.count);
+                }
+
+                if let Some(entry) = source_map.lookup(range.start_offset) {
+                    let key = (entry.source_file.as_str(), entry.line, range.start_offset);
+                    let _ = points.insert(key, range.count);
+                } else if range.count > 0 {
+                    let mapped_from = interior
+                        .iter()
+                        .map(|e| e.wasm_offset)
+                        .min()
+                        .unwrap_or(range.end_offset);
+                    line_coverage.unmapped.push(CoverageRange {
+                        start_offset: range.start_offset,
+                        end_offset: mapped_from,
+                        count: range.count,
+                    });
+                }
+            }
+
+            let mut lines: BTreeMap<(&str, u32), u32> = BTreeMap::new();
+            for ((file, line, _), count) in points {
+                let max = lines.entry((file, line)).or_insert(0);
+                *max = (*max).max(count);
+            }
+            for ((file, line), count) in lines {
+                if count > 0 {
+                    line_coverage.mark_covered(file, line, count);
+                }
+            }
+        }
+
+        line_coverage
+    }
 }
 
 /// JS-specific coverage data
@@ -433,6 +657,8 @@ impl WasmSourceMap {
 pub struct LineCoverage {
     /// Coverage per file: file -> (line -> count)
     pub files: HashMap<String, HashMap<u32, u32>>,
+    /// Covered byte ranges with no source mapping
+    pub unmapped: Vec<CoverageRange>,
 }
 
 impl LineCoverage {
@@ -544,6 +770,94 @@ mod tests {
         assert_eq!(func.total_count(), 0);
     }
 
+    mod threshold_tests {
+        use super::*;
+
+        fn script(url: &str, executed: &[bool]) -> ScriptCoverage {
+            ScriptCoverage {
+                script_id: url.to_string(),
+                url: url.to_string(),
+                functions: executed
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &run)| FunctionCoverage {
+                        function_name: format!("f{i}"),
+                        ranges: vec![CoverageRange {
+                            start_offset: 0,
+                            end_offset: 10,
+                            count: u32::from(run),
+                        }],
+                        is_block_coverage: false,
+                    })
+                    .collect(),
+            }
+        }
+
+        fn report() -> CoverageReport {
+            let mut report = CoverageReport::new();
+            report.add_script(script("http://localhost/app.js", &[true; 9]));
+            report.add_script(script("http://localhost/untested.js", &[false]));
+            report
+        }
+
+        #[test]
+        fn test_overall_only() {
+            let report = report();
+            assert!(report.meets_threshold(&CoverageThreshold::new(85.0)));
+            assert!(!report.meets_threshold(&CoverageThreshold::new(95.0)));
+        }
+
+        #[test]
+        fn test_file_floor_fails_despite_overall() {
+            let report = report();
+            let threshold = CoverageThreshold::new(85.0).with_file_floor(50.0);
+            let check = report.check_threshold(&threshold);
+
+            assert!(check.overall_passed);
+            assert!(!check.passed());
+            assert!(!report.meets_threshold(&threshold));
+            assert_eq!(check.failing_files(), vec!["http://localhost/untested.js"]);
+            assert_eq!(
+                check.violations[0].to_string(),
+                "http://localhost/untested.js at 0.0% (floor 50.0%)"
+            );
+        }
+
+        #[test]
+        fn test_function_floor() {
+            let mut report = CoverageReport::new();
+            report.add_script(ScriptCoverage {
+                script_id: "1".to_string(),
+                url: "app.js".to_string(),
+                functions: vec![FunctionCoverage {
+                    function_name: "update".to_string(),
+                    ranges: vec![
+                        CoverageRange {
+                            start_offset: 0,
+                            end_offset: 100,
+                            count: 1,
+                        },
+                        CoverageRange {
+                            start_offset: 40,
+                            end_offset: 60,
+                            count: 0,
+                        },
+                    ],
+                    is_block_coverage: true,
+                }],
+            });
+
+            let check =
+                report.check_threshold(&CoverageThreshold::new(0.0).with_function_floor(75.0));
+            assert!(!check.passed());
+            assert!(check.failing_files().is_empty());
+            assert_eq!(check.violations[0].function_name.as_deref(), Some("update"));
+            assert!((check.violations[0].percent - 50.0).abs() < f64::EPSILON);
+
+            assert!(report.meets_threshold(&CoverageThreshold::new(0.0).with_function_floor(50.0)));
+        }
+    }
+
     #[test]
     fn test_function_byte_range() {
         let func = FunctionCoverage {
@@ -978,6 +1292,59 @@ mod tests {
         assert!(line_coverage.is_covered("src/lib.rs", 10));
     }
 
+    #[test]
+    fn test_wasm_coverage_to_line_coverage() {
+        let mut sm = WasmSourceMap::new();
+        for (offset, line) in [(100, 3), (120, 4), (140, 4), (200, 9)] {
+            sm.entries.push(SourceMapEntry {
+                wasm_offset: offset,
+                source_file: "src/lib.rs".to_string(),
+                line,
+                column: 0,
+            });
+        }
+
+        let range = |start_offset, end_offset, count| CoverageRange {
+            start_offset,
+            end_offset,
+            count,
+        };
+        let mut report = CoverageReport::new();
+        report.add_script(ScriptCoverage {
+            script_id: "1".to_string(),
+            url: "http://localhost/app.wasm".to_string(),
+            functions: vec![
+                FunctionCoverage {
+                    function_name: "update".to_string(),
+                    // Starts before any mapping: 40..100 is unmapped
+                    ranges: vec![range(40, 150, 2)],
+                    is_block_coverage: false,
+                },
+                FunctionCoverage {
+                    function_name: "render".to_string(),
+                    ranges: vec![range(145, 210, 5), range(10, 30, 1), range(200, 210, 0)],
+                    is_block_coverage: true,
+                },
+            ],
+        });
+
+        let lines = report.wasm_coverage().to_line_coverage(&sm);
+
+        assert_eq!(lines.get_count("src/lib.rs", 3), 2);
+        // Line 4 spans two entries but each range counts once: 2 + 5
+        assert_eq!(lines.get_count("src/lib.rs", 4), 7);
+        // Offset 200 falls in the nested never-executed block of "render"
+        assert!(!lines.is_covered("src/lib.rs", 9));
+        assert!(!lines.is_covered("src/lib.rs", 5));
+
+        assert_eq!(lines.unmapped.len(), 2);
+        assert_eq!(lines.unmapped[0].start_offset, 40);
+        assert_eq!(lines.unmapped[0].end_offset, 100);
+        assert_eq!(lines.unmapped[0].count, 2);
+        assert_eq!(lines.unmapped[1].start_offset, 10);
+        assert_eq!(lines.unmapped[1].end_offset, 30);
+    }
+
     #[test]
     fn test_wasm_source_map_map_coverage_skips_js() {
         let sm = WasmSourceMap::new();
@@ -21,7 +21,7 @@ pub mod tarantula;
 pub mod wasm_threading;
 
 pub use pmat_bridge::{PmatBridge, PmatResult};
-pub use tarantula::{TarantulaEngine, TarantulaReport};
+pub use tarantula::{SuspiciousnessFormula, TarantulaEngine, TarantulaReport};
 pub use wasm_threading::{
     ComplianceCheck, ComplianceResult, ComplianceStatus, WasmThreadingCompliance,
 };
@@ -14,6 +14,20 @@
 //!
 //! Lines with higher suspiciousness scores are more likely to contain bugs.
 //!
+//! ## Alternative Formulas
+//!
+//! [`SuspiciousnessFormula`] selects the metric computed from the same
+//! pass/fail spectra (`ef`/`ep` = failing/passing tests covering the line,
+//! `F` = total failing tests):
+//!
+//! ```text
+//! Ochiai = ef / sqrt(F * (ef + ep))
+//! D*     = ef^n / (ep + (F - ef))
+//! ```
+//!
+//! Ochiai and D* often rank the faulty statement higher than Tarantula,
+//! which only compares coverage ratios. D* scores are unbounded.
+//!
 //! ## Integration
 //!
 //! This module consumes `.lcov` or `profraw` coverage artifacts to calculate
@@ -22,6 +36,80 @@
 use std::collections::HashMap;
 use std::path::Path;
 
+/// Suspiciousness metric used to rank lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SuspiciousnessFormula {
+    /// Tarantula (Jones & Harrold), bounded to `[0, 1]`
+    #[default]
+    Tarantula,
+    /// Ochiai (Abreu et al.), bounded to `[0, 1]`
+    Ochiai,
+    /// D* (Wong et al.) with exponent `n` (commonly 2), unbounded
+    DStar(u32),
+}
+
+impl std::fmt::Display for SuspiciousnessFormula {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tarantula => write!(f, "Tarantula"),
+            Self::Ochiai => write!(f, "Ochiai"),
+            Self::DStar(n) => write!(f, "D*{n}"),
+        }
+    }
+}
+
+impl SuspiciousnessFormula {
+    /// Whether scores stay within `[0, 1]`
+    #[must_use]
+    pub fn is_bounded(self) -> bool {
+        !matches!(self, Self::DStar(_))
+    }
+
+    /// Score a line from its spectrum
+    ///
+    /// # Arguments
+    /// * `failed` - Failing tests that executed the line (`ef`)
+    /// * `passed` - Passing tests that executed the line (`ep`)
+    /// * `total_failed` - Total failing tests (`F`)
+    /// * `total_passed` - Total passing tests (`P`)
+    #[must_use]
+    pub fn score(
+        self,
+        failed: usize,
+        passed: usize,
+        total_failed: usize,
+        total_passed: usize,
+    ) -> f64 {
+        if total_failed == 0 || failed == 0 {
+            return 0.0;
+        }
+        let ef = failed as f64;
+        let ep = passed as f64;
+
+        match self {
+            Self::Tarantula => {
+                if total_passed == 0 {
+                    return 0.0;
+                }
+                let failed_ratio = ef / total_failed as f64;
+                let passed_ratio = ep / total_passed as f64;
+                failed_ratio / (failed_ratio + passed_ratio)
+            }
+            Self::Ochiai => ef / (total_failed as f64 * (ef + ep)).sqrt(),
+            Self::DStar(n) => {
+                let not_executed_failed = total_failed.saturating_sub(failed) as f64;
+                let denominator = ep + not_executed_failed;
+                if denominator == 0.0 {
+                    // Covered by every failing test and no passing test
+                    f64::INFINITY
+                } else {
+                    ef.powi(n as i32) / denominator
+                }
+            }
+        }
+    }
+}
+
 /// Coverage data for a single line
 #[derive(Debug, Default, Clone)]
 pub struct LineCoverage {
@@ -42,35 +130,68 @@ pub struct TarantulaReport {
     pub total_passed: usize,
     /// Total failing tests
     pub total_failed: usize,
+    /// Formula used to compute `line_scores`
+    pub formula: SuspiciousnessFormula,
 }
 
 impl TarantulaReport {
+    /// All scored lines, most suspicious first (ties ordered by line number)
+    #[must_use]
+    pub fn ranking(&self) -> Vec<(usize, f64)> {
+        let mut scores: Vec<_> = self.line_scores.iter().map(|(&l, &s)| (l, s)).collect();
+        scores.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        scores
+    }
+
+    /// Competition rank of a line (1 = most suspicious; tied lines share a rank)
+    #[must_use]
+    pub fn rank_of(&self, line: usize) -> Option<usize> {
+        let score = *self.line_scores.get(&line)?;
+        Some(1 + self.line_scores.values().filter(|&&s| s > score).count())
+    }
+
     /// Get the top N most suspicious lines
     #[must_use]
     pub fn top_suspicious(&self, n: usize) -> Vec<(usize, f64)> {
-        let mut scores: Vec<_> = self.line_scores.iter().map(|(&l, &s)| (l, s)).collect();
-        scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        let mut scores = self.ranking();
         scores.truncate(n);
         scores
     }
 
+    /// Map a score onto `[0, 1]` for status levels (D* scores are unbounded)
+    fn status_level(&self, score: f64) -> f64 {
+        if self.formula.is_bounded() {
+            score
+        } else if score.is_infinite() {
+            1.0
+        } else {
+            score / (score + 1.0)
+        }
+    }
+
     /// Format as a hotspot report
     #[must_use]
     pub fn format_hotspot_report(&self) -> String {
         let mut output = String::new();
         output.push_str(&format!("🎯 Tarantula Hotspot Report: {}\n", self.file));
         output.push_str(&format!(
-            "   Tests: {} passed, {} failed\n\n",
+            "   Tests: {} passed, {} failed\n",
             self.total_passed, self.total_failed
         ));
+        output.push_str(&format!("   Formula: {}\n\n", self.formula));
 
         output.push_str("   Line  | Suspiciousness | Status\n");
         output.push_str("   ------|----------------|--------\n");
 
         for (line, score) in self.top_suspicious(10) {
-            let status = if score > 0.8 {
+            let level = self.status_level(score);
+            let status = if level > 0.8 {
                 "🔴 HIGH"
-            } else if score > 0.5 {
+            } else if level > 0.5 {
                 "🟡 MEDIUM"
             } else {
                 "🟢 LOW"
@@ -91,6 +212,8 @@ pub struct TarantulaEngine {
     total_passed: usize,
     /// Total failing tests recorded
     total_failed: usize,
+    /// Suspiciousness formula used for reports
+    formula: SuspiciousnessFormula,
 }
 
 impl TarantulaEngine {
@@ -100,6 +223,19 @@ impl TarantulaEngine {
         Self::default()
     }
 
+    /// Select the suspiciousness formula (default: Tarantula)
+    #[must_use]
+    pub fn with_formula(mut self, formula: SuspiciousnessFormula) -> Self {
+        self.formula = formula;
+        self
+    }
+
+    /// Get the selected suspiciousness formula
+    #[must_use]
+    pub fn formula(&self) -> SuspiciousnessFormula {
+        self.formula
+    }
+
     /// Record a test execution
     ///
     /// # Arguments
@@ -126,22 +262,17 @@ impl TarantulaEngine {
         }
     }
 
-    /// Calculate suspiciousness score for a line
+    /// Calculate suspiciousness score for a line with the selected formula
     ///
-    /// Returns a value between 0.0 (not suspicious) and 1.0 (highly suspicious).
+    /// Returns 0.0 for lines never executed by a failing test. Tarantula and
+    /// Ochiai scores are at most 1.0.
     fn calculate_suspiciousness(&self, line: &LineCoverage) -> f64 {
-        if self.total_failed == 0 || self.total_passed == 0 {
-            return 0.0;
-        }
-
-        let failed_ratio = line.failed_executions as f64 / self.total_failed as f64;
-        let passed_ratio = line.passed_executions as f64 / self.total_passed as f64;
-
-        if failed_ratio + passed_ratio == 0.0 {
-            return 0.0;
-        }
-
-        failed_ratio / (failed_ratio + passed_ratio)
+        self.formula.score(
+            line.failed_executions,
+            line.passed_executions,
+            self.total_failed,
+            self.total_passed,
+        )
     }
 
     /// Generate report for a specific file
@@ -162,6 +293,7 @@ impl TarantulaEngine {
             line_scores,
             total_passed: self.total_passed,
             total_failed: self.total_failed,
+            formula: self.formula,
         })
     }
 
@@ -274,6 +406,7 @@ mod tests {
             line_scores: HashMap::new(),
             total_passed: 10,
             total_failed: 5,
+            formula: SuspiciousnessFormula::Tarantula,
         };
 
         report.line_scores.insert(10, 0.3);
@@ -312,6 +445,7 @@ println!("hello");
             line_scores: HashMap::new(),
             total_passed: 5,
             total_failed: 3,
+            formula: SuspiciousnessFormula::Tarantula,
         };
 
         // Add HIGH suspiciousness lines (> 0.8)
@@ -332,6 +466,7 @@ println!("hello");
             line_scores: HashMap::new(),
             total_passed: 10,
             total_failed: 2,
+            formula: SuspiciousnessFormula::Tarantula,
         };
 
         // Add MEDIUM suspiciousness lines (> 0.5 but <= 0.8)
@@ -350,6 +485,7 @@ println!("hello");
             line_scores: HashMap::new(),
             total_passed: 20,
             total_failed: 1,
+            formula: SuspiciousnessFormula::Tarantula,
         };
 
         // Add LOW suspiciousness lines (<= 0.5)
@@ -368,6 +504,7 @@ println!("hello");
             line_scores: HashMap::new(),
             total_passed: 8,
             total_failed: 4,
+            formula: SuspiciousnessFormula::Tarantula,
         };
 
         // Mix of all suspiciousness levels
@@ -561,6 +698,7 @@ end_of_record
             line_scores: HashMap::new(),
             total_passed: 5,
             total_failed: 2,
+            formula: SuspiciousnessFormula::Tarantula,
         };
 
         report.line_scores.insert(1, 0.5);
@@ -584,6 +722,7 @@ end_of_record
             line_scores: HashMap::new(),
             total_passed: 3,
             total_failed: 2,
+            formula: SuspiciousnessFormula::Tarantula,
         };
 
         // Line number 12345 to test formatting width
@@ -597,4 +736,77 @@ end_of_record
         // Verify score is formatted with 3 decimal places
         assert!(output.contains("0.567"));
     }
+
+    /// F=2, P=2: line 10 covered by 2 fails + 2 passes, line 20 by 1 fail + 1 pass
+    fn engine_with_tied_tarantula_scores(formula: SuspiciousnessFormula) -> TarantulaEngine {
+        let mut engine = TarantulaEngine::new().with_formula(formula);
+        for _ in 0..2 {
+            engine.record_execution("tie.rs", 10, false);
+            engine.record_execution("tie.rs", 10, true);
+        }
+        engine.record_execution("tie.rs", 20, false);
+        engine.record_execution("tie.rs", 20, true);
+        for _ in 0..2 {
+            engine.record_test_run(true);
+            engine.record_test_run(false);
+        }
+        engine
+    }
+
+    #[test]
+    fn test_default_formula_is_tarantula() {
+        let engine = TarantulaEngine::new();
+        assert_eq!(engine.formula(), SuspiciousnessFormula::Tarantula);
+        assert_eq!(
+            TarantulaReport::default().formula,
+            SuspiciousnessFormula::Tarantula
+        );
+    }
+
+    #[test]
+    fn test_ochiai_breaks_tarantula_tie() {
+        let tarantula = engine_with_tied_tarantula_scores(SuspiciousnessFormula::Tarantula)
+            .report_for_file("tie.rs")
+            .unwrap();
+        assert!((tarantula.line_scores[&10] - 0.5).abs() < 1e-9);
+        assert!((tarantula.line_scores[&20] - 0.5).abs() < 1e-9);
+        assert_eq!(tarantula.rank_of(10), Some(1));
+        assert_eq!(tarantula.rank_of(20), Some(1));
+
+        let ochiai = engine_with_tied_tarantula_scores(SuspiciousnessFormula::Ochiai)
+            .report_for_file("tie.rs")
+            .unwrap();
+        assert_eq!(ochiai.formula, SuspiciousnessFormula::Ochiai);
+        // 2 / sqrt(2 * 4) and 1 / sqrt(2 * 2)
+        assert!((ochiai.line_scores[&10] - 0.5_f64.sqrt()).abs() < 1e-9);
+        assert!((ochiai.line_scores[&20] - 0.5).abs() < 1e-9);
+        assert_eq!(ochiai.rank_of(10), Some(1));
+        assert_eq!(ochiai.rank_of(20), Some(2));
+        assert_eq!(ochiai.rank_of(99), None);
+        assert_eq!(ochiai.ranking()[0].0, 10);
+    }
+
+    #[test]
+    fn test_dstar_scores() {
+        let formula = SuspiciousnessFormula::DStar(2);
+        // ef=2, ep=2, F=2: 2^2 / (2 + 0)
+        assert!((formula.score(2, 2, 2, 2) - 2.0).abs() < 1e-9);
+        // ef=1, ep=1, F=2: 1^2 / (1 + 1)
+        assert!((formula.score(1, 1, 2, 2) - 0.5).abs() < 1e-9);
+        // Covered by every failing test and no passing test
+        assert!(formula.score(2, 0, 2, 2).is_infinite());
+        assert_eq!(formula.score(0, 3, 2, 2), 0.0);
+        assert!(!formula.is_bounded());
+    }
+
+    #[test]
+    fn test_format_hotspot_report_shows_formula() {
+        let report = engine_with_tied_tarantula_scores(SuspiciousnessFormula::DStar(2))
+            .report_for_file("tie.rs")
+            .unwrap();
+        let output = report.format_hotspot_report();
+        assert!(output.contains("Formula: D*2"));
+        // 2.0 maps to level 0.667
+        assert!(output.contains("🟡 MEDIUM"));
+    }
 }
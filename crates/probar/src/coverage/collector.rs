@@ -14,8 +14,10 @@ pub enum Granularity {
     Function,
     /// Basic block coverage
     BasicBlock,
-    /// Edge/branch coverage
+    /// Edge coverage (every CFG edge traversed)
     Edge,
+    /// Branch coverage (every outgoing edge of each conditional block taken)
+    Branch,
     /// Path coverage (finest)
     Path,
 }
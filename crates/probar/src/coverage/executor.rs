@@ -4,7 +4,7 @@
 //!
 //! Uses work-stealing scheduler for parallel coverage collection.
 
-use super::{CoverageReport, Superblock, SuperblockId};
+use super::{CoverageReport, EdgeId, Granularity, Superblock, SuperblockId};
 
 /// Result of executing a superblock
 #[derive(Debug, Clone)]
@@ -26,6 +26,10 @@ pub struct CoverageExecutor {
     worker_count: usize,
     /// Enable work stealing
     work_stealing: bool,
+    /// Coverage granularity
+    granularity: Granularity,
+    /// Static CFG edges for edge/branch coverage
+    cfg_edges: Vec<EdgeId>,
 }
 
 impl CoverageExecutor {
@@ -36,6 +40,8 @@ impl CoverageExecutor {
             superblocks,
             worker_count: num_cpus(),
             work_stealing: true,
+            granularity: Granularity::BasicBlock,
+            cfg_edges: Vec::new(),
         }
     }
 
@@ -53,6 +59,20 @@ impl CoverageExecutor {
         self
     }
 
+    /// Set the coverage granularity
+    #[must_use]
+    pub fn with_granularity(mut self, granularity: Granularity) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    /// Set the static CFG edges used for edge and branch coverage
+    #[must_use]
+    pub fn with_cfg_edges(mut self, edges: Vec<EdgeId>) -> Self {
+        self.cfg_edges = edges;
+        self
+    }
+
     /// Execute coverage collection for all superblocks
     ///
     /// In a full implementation, this would use Simular's WorkStealingMonteCarlo
@@ -60,21 +80,49 @@ impl CoverageExecutor {
     pub fn execute<F>(&self, test_fn: F) -> CoverageReport
     where
         F: Fn(&Superblock) -> SuperblockResult + Send + Sync,
+    {
+        self.execute_with_edges(|superblock| (test_fn(superblock), Vec::new()))
+    }
+
+    /// Execute coverage collection, also recording the CFG edges each
+    /// superblock traversed
+    ///
+    /// Edges are only recorded under `Granularity::Edge` and
+    /// `Granularity::Branch`.
+    pub fn execute_with_edges<F>(&self, test_fn: F) -> CoverageReport
+    where
+        F: Fn(&Superblock) -> (SuperblockResult, Vec<EdgeId>) + Send + Sync,
     {
         // Calculate total blocks
         let total_blocks = self.superblocks.iter().map(|sb| sb.block_count()).sum();
 
         let mut report = CoverageReport::new(total_blocks);
+        for superblock in &self.superblocks {
+            for block in superblock.iter() {
+                report.set_function_id(*block, superblock.function());
+            }
+        }
+        let track_edges = matches!(self.granularity, Granularity::Edge | Granularity::Branch);
+        if track_edges {
+            for edge in &self.cfg_edges {
+                report.register_edge(*edge);
+            }
+        }
 
         // Execute each superblock (sequentially for now)
         // In production, this would use work-stealing parallel execution
         for superblock in &self.superblocks {
-            let result = test_fn(superblock);
+            let (result, taken_edges) = test_fn(superblock);
             if result.success {
                 // Record hits for all blocks in the superblock
                 for block in superblock.iter() {
                     report.record_hit(*block);
                 }
+                if track_edges {
+                    for edge in &taken_edges {
+                        report.record_edge_hit(*edge);
+                    }
+                }
             }
         }
 
@@ -93,6 +141,12 @@ impl CoverageExecutor {
         self.superblocks.iter().map(|sb| sb.block_count()).sum()
     }
 
+    /// Get the coverage granularity
+    #[must_use]
+    pub fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
     /// Get the worker count
     #[must_use]
     pub fn worker_count(&self) -> usize {
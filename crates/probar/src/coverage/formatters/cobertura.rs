@@ -7,13 +7,18 @@
 //! ```xml
 //! <?xml version="1.0" ?>
 //! <!DOCTYPE coverage SYSTEM "http://cobertura.sourceforge.net/xml/coverage-04.dtd">
-//! <coverage line-rate="0.8" branch-rate="0.7" version="1.0">
+//! <coverage line-rate="0.8" branch-rate="0.5" branches-covered="1" branches-valid="2" version="1.0">
 //!   <packages>
-//!     <package name="src" line-rate="0.8" branch-rate="0.7" complexity="0">
+//!     <package name="src" line-rate="0.8" branch-rate="0.5" complexity="0">
 //!       <classes>
-//!         <class name="Game" filename="src/game.rs" line-rate="0.9">
+//!         <class name="Game" filename="src/game.rs" line-rate="0.9" branch-rate="0.5">
 //!           <lines>
 //!             <line number="10" hits="5"/>
+//!             <line number="12" hits="5" branch="true" condition-coverage="50% (1/2)">
+//!               <conditions>
+//!                 <condition number="0" type="jump" coverage="50%"/>
+//!               </conditions>
+//!             </line>
 //!           </lines>
 //!         </class>
 //!       </classes>
@@ -37,6 +42,12 @@ type FileMap = BTreeMap<String, BlockCoverageData>;
 /// Packages grouped by directory
 type PackageMap = BTreeMap<String, FileMap>;
 
+/// Branch outcomes per line: one (taken, total) edge count per branching block
+type LineBranches = BTreeMap<u32, Vec<(usize, usize)>>;
+
+/// Branch outcomes grouped by file
+type BranchMap = BTreeMap<String, LineBranches>;
+
 /// Cobertura XML format report generator
 #[derive(Debug)]
 pub struct CoberturaFormatter<'a> {
@@ -67,8 +78,19 @@ impl<'a> CoberturaFormatter<'a> {
         let summary = self.report.summary();
         let files = self.group_by_file();
         let packages = Self::group_by_package(&files);
+        let branches = self.group_branches();
 
         let line_rate = summary.coverage_percent / 100.0;
+        let (branches_covered, branches_valid) =
+            self.report
+                .branch_coverages()
+                .iter()
+                .fold((0, 0), |(covered, valid), b| {
+                    (
+                        covered + b.taken_edges.len(),
+                        valid + b.taken_edges.len() + b.untaken_edges.len(),
+                    )
+                });
 
         let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
         xml.push('\n');
@@ -78,8 +100,14 @@ impl<'a> CoberturaFormatter<'a> {
         xml.push('\n');
         let _ = write!(
             xml,
-            r#"<coverage line-rate="{:.4}" branch-rate="0" lines-covered="{}" lines-valid="{}" version="{}">"#,
-            line_rate, summary.covered_blocks, summary.total_blocks, self.version,
+            r#"<coverage line-rate="{:.4}" branch-rate="{:.4}" lines-covered="{}" lines-valid="{}" branches-covered="{}" branches-valid="{}" version="{}">"#,
+            line_rate,
+            Self::rate(branches_covered, branches_valid),
+            summary.covered_blocks,
+            summary.total_blocks,
+            branches_covered,
+            branches_valid,
+            self.version,
         );
         xml.push('\n');
 
@@ -87,16 +115,19 @@ impl<'a> CoberturaFormatter<'a> {
 
         for (package_name, package_files) in &packages {
             let (pkg_covered, pkg_total) = Self::calculate_package_coverage(package_files);
-            let pkg_rate = if pkg_total > 0 {
-                pkg_covered as f64 / pkg_total as f64
-            } else {
-                1.0
-            };
+            let pkg_rate = Self::rate(pkg_covered, pkg_total);
+            let (pkg_br_covered, pkg_br_total) = package_files
+                .keys()
+                .filter_map(|file| branches.get(file))
+                .map(Self::calculate_branch_coverage)
+                .fold((0, 0), |(c, t), (fc, ft)| (c + fc, t + ft));
 
             let _ = write!(
                 xml,
-                r#"    <package name="{}" line-rate="{:.4}" branch-rate="0" complexity="0">"#,
-                package_name, pkg_rate
+                r#"    <package name="{}" line-rate="{:.4}" branch-rate="{:.4}" complexity="0">"#,
+                package_name,
+                pkg_rate,
+                Self::rate(pkg_br_covered, pkg_br_total)
             );
             xml.push('\n');
             xml.push_str("      <classes>\n");
@@ -104,28 +135,37 @@ impl<'a> CoberturaFormatter<'a> {
             for (file_path, blocks) in package_files {
                 let class_name = Self::extract_class_name(file_path);
                 let (file_covered, file_total) = Self::calculate_file_coverage(blocks);
-                let file_rate = if file_total > 0 {
-                    file_covered as f64 / file_total as f64
-                } else {
-                    1.0
-                };
+                let file_rate = Self::rate(file_covered, file_total);
+                let file_branches = branches.get(file_path);
+                let (file_br_covered, file_br_total) =
+                    file_branches.map_or((0, 0), Self::calculate_branch_coverage);
 
                 let _ = write!(
                     xml,
-                    r#"        <class name="{}" filename="{}" line-rate="{:.4}" branch-rate="0" complexity="0">"#,
-                    class_name, file_path, file_rate
+                    r#"        <class name="{}" filename="{}" line-rate="{:.4}" branch-rate="{:.4}" complexity="0">"#,
+                    class_name,
+                    file_path,
+                    file_rate,
+                    Self::rate(file_br_covered, file_br_total)
                 );
                 xml.push('\n');
                 xml.push_str("          <lines>\n");
 
                 let lines = Self::extract_lines(blocks);
                 for (line, count) in &lines {
-                    let _ = write!(
-                        xml,
-                        r#"            <line number="{}" hits="{}"/>"#,
-                        line, count
-                    );
-                    xml.push('\n');
+                    match file_branches.and_then(|b| b.get(line)) {
+                        Some(conditions) => {
+                            Self::write_branch_line(&mut xml, *line, *count, conditions);
+                        }
+                        None => {
+                            let _ = write!(
+                                xml,
+                                r#"            <line number="{}" hits="{}"/>"#,
+                                line, count
+                            );
+                            xml.push('\n');
+                        }
+                    }
                 }
 
                 xml.push_str("          </lines>\n");
@@ -179,6 +219,66 @@ impl<'a> CoberturaFormatter<'a> {
         files
     }
 
+    /// Group branch outcomes by source file and line
+    fn group_branches(&self) -> BranchMap {
+        let locations: BTreeMap<_, _> = self
+            .report
+            .block_coverages()
+            .into_iter()
+            .filter_map(|block| {
+                let loc = block.source_location?;
+                let mut parts = loc.split(':');
+                let file = parts.next()?.to_string();
+                let line: u32 = parts.next()?.parse().ok()?;
+                Some((block.block_id, (file, line)))
+            })
+            .collect();
+
+        let mut branches: BranchMap = BTreeMap::new();
+        for branch in self.report.branch_coverages() {
+            let Some((file, line)) = locations.get(&branch.block_id) else {
+                continue;
+            };
+            let taken = branch.taken_edges.len();
+            branches
+                .entry(file.clone())
+                .or_default()
+                .entry(*line)
+                .or_default()
+                .push((taken, taken + branch.untaken_edges.len()));
+        }
+
+        branches
+    }
+
+    /// Write a branching line with its condition coverage
+    fn write_branch_line(xml: &mut String, line: u32, hits: u64, conditions: &[(usize, usize)]) {
+        let (taken, total) = conditions
+            .iter()
+            .fold((0, 0), |(c, t), (bc, bt)| (c + bc, t + bt));
+
+        let _ = writeln!(
+            xml,
+            r#"            <line number="{}" hits="{}" branch="true" condition-coverage="{}% ({}/{})">"#,
+            line,
+            hits,
+            Self::percent(taken, total),
+            taken,
+            total
+        );
+        xml.push_str("              <conditions>\n");
+        for (number, (taken, total)) in conditions.iter().enumerate() {
+            let _ = writeln!(
+                xml,
+                r#"                <condition number="{}" type="jump" coverage="{}%"/>"#,
+                number,
+                Self::percent(*taken, *total)
+            );
+        }
+        xml.push_str("              </conditions>\n");
+        xml.push_str("            </line>\n");
+    }
+
     /// Group files by package (directory)
     fn group_by_package(files: &FileMap) -> PackageMap {
         let mut packages: PackageMap = BTreeMap::new();
@@ -230,6 +330,28 @@ impl<'a> CoberturaFormatter<'a> {
         (covered, total)
     }
 
+    /// Calculate branch coverage (taken edges, total edges) for a file
+    fn calculate_branch_coverage(lines: &LineBranches) -> (usize, usize) {
+        lines
+            .values()
+            .flatten()
+            .fold((0, 0), |(c, t), (bc, bt)| (c + bc, t + bt))
+    }
+
+    /// Ratio of covered to total, treating an empty total as fully covered
+    fn rate(covered: usize, total: usize) -> f64 {
+        if total > 0 {
+            covered as f64 / total as f64
+        } else {
+            1.0
+        }
+    }
+
+    /// Integer percentage of covered to total, as used in condition coverage
+    fn percent(covered: usize, total: usize) -> usize {
+        (covered * 100).checked_div(total).unwrap_or(100)
+    }
+
     /// Extract line coverage from blocks
     fn extract_lines(blocks: &[(u32, u64, Option<String>)]) -> BTreeMap<u32, u64> {
         let mut lines = BTreeMap::new();
@@ -402,6 +524,72 @@ mod tests {
         assert!(packages.contains_key("src"));
     }
 
+    mod branch_tests {
+        use super::*;
+        use crate::coverage::EdgeId;
+
+        /// Two branching blocks in src/game.rs: line 10 takes 1 of 2 edges,
+        /// line 20 takes both, giving 3 of 4 branches covered.
+        fn create_branch_report() -> CoverageReport {
+            let mut report = CoverageReport::new(6);
+            for (i, line) in [10, 11, 12, 20, 21, 22].iter().enumerate() {
+                report.set_source_location(BlockId::new(i as u32), &format!("src/game.rs:{line}"));
+            }
+            report.record_hits(BlockId::new(0), 4);
+            report.record_hits(BlockId::new(1), 4);
+            report.record_hits(BlockId::new(3), 2);
+            report.record_hits(BlockId::new(4), 1);
+            report.record_hits(BlockId::new(5), 1);
+
+            for (source, target) in [(0, 1), (0, 2), (3, 4), (3, 5)] {
+                report.register_edge(EdgeId::new(BlockId::new(source), BlockId::new(target)));
+            }
+            report.record_edge_hits(EdgeId::new(BlockId::new(0), BlockId::new(1)), 4);
+            report.record_edge_hits(EdgeId::new(BlockId::new(3), BlockId::new(4)), 1);
+            report.record_edge_hits(EdgeId::new(BlockId::new(3), BlockId::new(5)), 1);
+            report
+        }
+
+        #[test]
+        fn test_branch_rate_matches_computed_ratio() {
+            let report = create_branch_report();
+            let output = CoberturaFormatter::new(&report).generate();
+
+            let summary = report.summary();
+            assert_eq!(summary.total_branches, 2);
+            assert!(output.contains(r#"branch-rate="0.7500" lines-covered="5" lines-valid="6" branches-covered="3" branches-valid="4""#));
+            assert!(
+                output.contains(r#"<package name="src" line-rate="0.8333" branch-rate="0.7500""#)
+            );
+            assert!(output
+                .contains(r#"filename="src/game.rs" line-rate="0.8333" branch-rate="0.7500""#));
+        }
+
+        #[test]
+        fn test_branching_lines_emit_conditions() {
+            let report = create_branch_report();
+            let output = CoberturaFormatter::new(&report).generate();
+
+            assert!(output.contains(
+                r#"<line number="10" hits="4" branch="true" condition-coverage="50% (1/2)">"#
+            ));
+            assert!(output.contains(r#"<condition number="0" type="jump" coverage="50%"/>"#));
+            assert!(output.contains(
+                r#"<line number="20" hits="2" branch="true" condition-coverage="100% (2/2)">"#
+            ));
+            assert!(output.contains(r#"<line number="11" hits="4"/>"#));
+        }
+
+        #[test]
+        fn test_no_branches_reports_full_branch_rate() {
+            let report = create_test_report();
+            let output = CoberturaFormatter::new(&report).generate();
+
+            assert!(output.contains(r#"branches-covered="0" branches-valid="0""#));
+            assert!(!output.contains("<conditions>"));
+        }
+    }
+
     #[test]
     fn test_calculate_file_coverage() {
         // 2 blocks covered out of 3 in game.rs
@@ -5,7 +5,7 @@
 use crate::coverage::CoverageReport;
 use crate::result::ProbarResult;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
 /// Block coverage data: (line, hit_count, function_name)
@@ -14,6 +14,31 @@ type BlockCoverageData = Vec<(u32, u64, Option<String>)>;
 /// Files grouped by path
 type FileMap = BTreeMap<String, BlockCoverageData>;
 
+/// Per-line coverage for one file, keyed by line number
+type LineMap = BTreeMap<u32, LineCoverage>;
+
+/// Aggregated coverage of all blocks starting on one source line
+#[derive(Debug, Clone, Copy, Default)]
+struct LineCoverage {
+    hits: u64,
+    covered_blocks: usize,
+    uncovered_blocks: usize,
+    partial_branch: bool,
+}
+
+impl LineCoverage {
+    /// CSS class for the line: `covered`, `uncovered`, or `partial`
+    fn css_class(&self) -> &'static str {
+        if self.covered_blocks == 0 {
+            "uncovered"
+        } else if self.uncovered_blocks > 0 || self.partial_branch {
+            "partial"
+        } else {
+            "covered"
+        }
+    }
+}
+
 /// Color theme for HTML report
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Theme {
@@ -39,6 +64,16 @@ pub struct HtmlReportConfig {
     pub theme: Theme,
     /// Show line numbers
     pub show_line_numbers: bool,
+    /// Render annotated source listings for files registered with `with_source`
+    #[serde(default)]
+    pub include_source: bool,
+    /// Listings longer than this many lines start collapsed
+    #[serde(default = "default_collapse_after_lines")]
+    pub collapse_after_lines: usize,
+}
+
+const fn default_collapse_after_lines() -> usize {
+    200
 }
 
 impl Default for HtmlReportConfig {
@@ -49,6 +84,8 @@ impl Default for HtmlReportConfig {
             include_branch_coverage: false,
             theme: Theme::Light,
             show_line_numbers: true,
+            include_source: false,
+            collapse_after_lines: default_collapse_after_lines(),
         }
     }
 }
@@ -94,6 +131,20 @@ impl HtmlReportConfig {
         self.show_line_numbers = show;
         self
     }
+
+    /// Set source listing option
+    #[must_use]
+    pub fn with_source_listing(mut self, include: bool) -> Self {
+        self.include_source = include;
+        self
+    }
+
+    /// Set the line count above which source listings start collapsed
+    #[must_use]
+    pub fn with_collapse_after_lines(mut self, lines: usize) -> Self {
+        self.collapse_after_lines = lines;
+        self
+    }
 }
 
 /// HTML format report generator
@@ -101,6 +152,7 @@ impl HtmlReportConfig {
 pub struct HtmlFormatter<'a> {
     report: &'a CoverageReport,
     config: HtmlReportConfig,
+    sources: BTreeMap<String, String>,
 }
 
 impl<'a> HtmlFormatter<'a> {
@@ -110,13 +162,25 @@ impl<'a> HtmlFormatter<'a> {
         Self {
             report,
             config: HtmlReportConfig::default(),
+            sources: BTreeMap::new(),
         }
     }
 
     /// Create with custom configuration
     #[must_use]
     pub fn with_config(report: &'a CoverageReport, config: HtmlReportConfig) -> Self {
-        Self { report, config }
+        Self {
+            report,
+            config,
+            sources: BTreeMap::new(),
+        }
+    }
+
+    /// Register source text for a file path as it appears in block source locations
+    #[must_use]
+    pub fn with_source(mut self, path: impl Into<String>, contents: impl Into<String>) -> Self {
+        let _ = self.sources.insert(path.into(), contents.into());
+        self
     }
 
     /// Generate the HTML report as a string
@@ -128,6 +192,11 @@ impl<'a> HtmlFormatter<'a> {
         let css = Self::generate_css();
         let summary_html = Self::generate_summary_section(&summary);
         let files_html = Self::generate_files_section(&files);
+        let source_html = if self.config.include_source {
+            self.generate_source_section()
+        } else {
+            String::new()
+        };
 
         format!(
             r#"<!DOCTYPE html>
@@ -146,6 +215,7 @@ impl<'a> HtmlFormatter<'a> {
     <main>
         {summary_html}
         {files_html}
+        {source_html}
     </main>
     <footer>
         <p>Probar Coverage Report</p>
@@ -157,6 +227,7 @@ impl<'a> HtmlFormatter<'a> {
             theme_class = self.theme_class(),
             summary_html = summary_html,
             files_html = files_html,
+            source_html = source_html,
         )
     }
 
@@ -211,6 +282,19 @@ impl<'a> HtmlFormatter<'a> {
         .file-coverage { font-weight: bold; }
         .covered { color: #4caf50; }
         .uncovered { color: #f44336; }
+        .source-file { margin: 10px 0; }
+        .source-file summary { cursor: pointer; font-family: monospace; padding: 5px 0; }
+        .source { border-collapse: collapse; width: 100%; font-family: monospace; font-size: 13px; }
+        .source td { padding: 0 8px; white-space: pre; vertical-align: top; }
+        .source .gutter, .source .line-number { text-align: right; user-select: none; color: #999; }
+        .source tr.covered { background: #e6ffed; }
+        .source tr.uncovered { background: #ffeef0; }
+        .source tr.partial { background: #fff8c5; }
+        .source tr.covered, .source tr.uncovered, .source tr.partial { color: inherit; }
+        .theme-dark .source tr.covered { background: #1b3a24; }
+        .theme-dark .source tr.uncovered { background: #4b1c20; }
+        .theme-dark .source tr.partial { background: #4a3f12; }
+        .theme-dark .source .gutter, .theme-dark .source .line-number { color: #777; }
         footer { margin-top: 40px; padding-top: 10px; border-top: 1px solid #ccc; color: #666; font-size: 12px; }
         "#
     }
@@ -283,6 +367,87 @@ impl<'a> HtmlFormatter<'a> {
         html
     }
 
+    /// Generate annotated source listings with per-line hit count gutters
+    fn generate_source_section(&self) -> String {
+        use std::fmt::Write;
+
+        let lines_by_file = self.group_lines_by_file();
+        let mut html = String::from("<section class=\"sources\"><h2>Source</h2>");
+
+        for (file, source) in &self.sources {
+            let coverage = lines_by_file.get(file);
+            let line_count = source.lines().count();
+            let open = if line_count > self.config.collapse_after_lines {
+                ""
+            } else {
+                " open"
+            };
+
+            let _ = write!(
+                html,
+                r#"<details class="source-file"{open}><summary>{} ({line_count} lines)</summary><table class="source">"#,
+                escape_html(file)
+            );
+
+            for (idx, text) in source.lines().enumerate() {
+                let line_no = idx as u32 + 1;
+                let line = coverage.and_then(|lines| lines.get(&line_no));
+                let class = match line.map(LineCoverage::css_class) {
+                    Some("uncovered") if !self.config.highlight_uncovered => "",
+                    Some(class) => class,
+                    None => "",
+                };
+                let hits = line.map(|l| l.hits.to_string()).unwrap_or_default();
+
+                let _ = write!(
+                    html,
+                    r#"<tr class="{class}"><td class="gutter">{hits}</td>"#
+                );
+                if self.config.show_line_numbers {
+                    let _ = write!(html, r#"<td class="line-number">{line_no}</td>"#);
+                }
+                let _ = write!(html, r#"<td class="code">{}</td></tr>"#, escape_html(text));
+            }
+
+            html.push_str("</table></details>");
+        }
+
+        html.push_str("</section>");
+        html
+    }
+
+    /// Aggregate block and branch coverage per source line
+    fn group_lines_by_file(&self) -> BTreeMap<String, LineMap> {
+        let partial: HashSet<_> = self
+            .report
+            .branch_coverages()
+            .into_iter()
+            .filter(|b| b.is_partial())
+            .map(|b| b.block_id)
+            .collect();
+        let mut files: BTreeMap<String, LineMap> = BTreeMap::new();
+
+        for block in self.report.block_coverages() {
+            let Some((file, line)) = block.source_location.as_deref().and_then(|loc| {
+                let (file, line) = loc.rsplit_once(':')?;
+                Some((file.to_string(), line.parse::<u32>().ok()?))
+            }) else {
+                continue;
+            };
+
+            let entry = files.entry(file).or_default().entry(line).or_default();
+            entry.hits += block.hit_count;
+            if block.hit_count > 0 {
+                entry.covered_blocks += 1;
+            } else {
+                entry.uncovered_blocks += 1;
+            }
+            entry.partial_branch |= partial.contains(&block.block_id);
+        }
+
+        files
+    }
+
     /// Group coverage data by source file
     fn group_by_file(&self) -> FileMap {
         let mut files: FileMap = BTreeMap::new();
@@ -310,6 +475,14 @@ impl<'a> HtmlFormatter<'a> {
     }
 }
 
+/// Escape text for inclusion in HTML
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -478,6 +651,101 @@ mod tests {
         }
     }
 
+    mod source_listing_tests {
+        use super::*;
+
+        const SOURCE: &str = "fn main() {\n    let x = 1;\n    if x > 0 { a() } else { b() }\n}";
+
+        fn create_source_report() -> CoverageReport {
+            let mut report = CoverageReport::new(4);
+            report.record_hits(BlockId::new(0), 7);
+            report.record_hits(BlockId::new(1), 0);
+            report.record_hits(BlockId::new(2), 2);
+            report.record_hits(BlockId::new(3), 0);
+
+            report.set_source_location(BlockId::new(0), "src/main.rs:1");
+            report.set_source_location(BlockId::new(1), "src/main.rs:2");
+            report.set_source_location(BlockId::new(2), "src/main.rs:3");
+            report.set_source_location(BlockId::new(3), "src/main.rs:3");
+            report
+        }
+
+        fn listing(report: &CoverageReport, config: HtmlReportConfig) -> String {
+            HtmlFormatter::with_config(report, config.with_source_listing(true))
+                .with_source("src/main.rs", SOURCE)
+                .generate()
+        }
+
+        #[test]
+        fn test_source_listing_disabled_by_default() {
+            let report = create_source_report();
+            let html = HtmlFormatter::new(&report)
+                .with_source("src/main.rs", SOURCE)
+                .generate();
+            assert!(!html.contains(r#"<table class="source">"#));
+        }
+
+        #[test]
+        fn test_source_listing_gutter_and_classes() {
+            let report = create_source_report();
+            let html = listing(&report, HtmlReportConfig::default());
+
+            assert!(html.contains(
+                r#"<tr class="covered"><td class="gutter">7</td><td class="line-number">1</td>"#
+            ));
+            assert!(html.contains(
+                r#"<tr class="uncovered"><td class="gutter">0</td><td class="line-number">2</td>"#
+            ));
+            assert!(html.contains(
+                r#"<tr class="partial"><td class="gutter">2</td><td class="line-number">3</td>"#
+            ));
+            assert!(html.contains(
+                r#"<tr class=""><td class="gutter"></td><td class="line-number">4</td>"#
+            ));
+        }
+
+        #[test]
+        fn test_source_listing_escapes_code() {
+            let report = create_source_report();
+            let html = listing(&report, HtmlReportConfig::default());
+            assert!(html.contains("if x &gt; 0"));
+            assert!(!html.contains("if x > 0"));
+        }
+
+        #[test]
+        fn test_source_listing_collapses_long_files() {
+            let report = create_source_report();
+            let html = listing(&report, HtmlReportConfig::default());
+            assert!(html.contains(r#"<details class="source-file" open>"#));
+
+            let html = listing(
+                &report,
+                HtmlReportConfig::default().with_collapse_after_lines(2),
+            );
+            assert!(html.contains(r#"<details class="source-file"><summary>"#));
+        }
+
+        #[test]
+        fn test_source_listing_dark_theme() {
+            let report = create_source_report();
+            let html = listing(&report, HtmlReportConfig::default().with_theme(Theme::Dark));
+            assert!(html.contains(r#"<body class="theme-dark">"#));
+            assert!(html.contains(".theme-dark .source tr.uncovered"));
+        }
+
+        #[test]
+        fn test_source_listing_without_line_numbers() {
+            let report = create_source_report();
+            let html = listing(
+                &report,
+                HtmlReportConfig::default().with_line_numbers(false),
+            );
+            assert!(html
+                .contains(r#"<tr class="uncovered"><td class="gutter">0</td><td class="code">"#));
+            assert!(!html.contains(r#"<td class="line-number">"#));
+        }
+    }
+
     mod theme_tests {
         use super::*;
 
@@ -42,7 +42,9 @@ pub use formatters::{CoberturaFormatter, HtmlFormatter, HtmlReportConfig, LcovFo
 pub use hypotheses::{CoverageHypothesis, NullificationConfig, NullificationResult};
 pub use jidoka::{CoverageViolation, JidokaAction, TaintedBlocks};
 pub use memory::CoverageMemoryView;
-pub use report::{BlockCoverage, CoverageReport, CoverageSummary};
+pub use report::{
+    BlockCoverage, BranchCoverage, CoverageMergeError, CoverageReport, CoverageSummary,
+};
 pub use superblock::{Superblock, SuperblockBuilder, SuperblockId};
 pub use thread_local::ThreadLocalCounters;
 
@@ -8,8 +8,37 @@
 //! - Source location mapping
 //! - Nullification test results
 
-use super::{BlockId, CoverageViolation, TaintedBlocks};
-use std::collections::HashMap;
+use super::{BlockId, CoverageViolation, EdgeId, FunctionId, TaintedBlocks};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use thiserror::Error;
+
+/// Error when combining partial coverage reports
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CoverageMergeError {
+    /// No reports were supplied
+    #[error("no coverage reports to merge")]
+    NoReports,
+    /// Reports were collected against modules with different block counts
+    #[error("report {index} has {found} blocks, expected {expected}")]
+    BlockCountMismatch {
+        /// Index of the offending report
+        index: usize,
+        /// Block count of the first report
+        expected: usize,
+        /// Block count of the offending report
+        found: usize,
+    },
+    /// Reports describe different sets of functions
+    #[error("report {index} function set differs: missing {missing:?}, unexpected {unexpected:?}")]
+    FunctionMismatch {
+        /// Index of the offending report
+        index: usize,
+        /// Functions in the first report but not this one
+        missing: Vec<FunctionId>,
+        /// Functions in this report but not the first
+        unexpected: Vec<FunctionId>,
+    },
+}
 
 /// Coverage summary statistics
 #[derive(Debug, Clone)]
@@ -24,6 +53,12 @@ pub struct CoverageSummary {
     pub confidence_interval: Option<(f64, f64)>,
     /// Effect size (Cohen's d)
     pub effect_size: Option<f64>,
+    /// Number of branching blocks (two or more outgoing CFG edges)
+    pub total_branches: usize,
+    /// Number of branching blocks with every outgoing edge taken
+    pub covered_branches: usize,
+    /// Branching blocks reached but with at least one outgoing edge never taken
+    pub partial_branches: Vec<BlockId>,
 }
 
 /// Per-block coverage information
@@ -39,6 +74,33 @@ pub struct BlockCoverage {
     pub function_name: Option<String>,
 }
 
+/// Per-branch coverage for a block with two or more outgoing CFG edges
+#[derive(Debug, Clone)]
+pub struct BranchCoverage {
+    /// Branching block
+    pub block_id: BlockId,
+    /// Whether the block itself was executed
+    pub block_hit: bool,
+    /// Outgoing edges traversed at least once
+    pub taken_edges: Vec<EdgeId>,
+    /// Outgoing edges never traversed
+    pub untaken_edges: Vec<EdgeId>,
+}
+
+impl BranchCoverage {
+    /// Every outgoing edge was taken
+    #[must_use]
+    pub fn is_fully_covered(&self) -> bool {
+        self.untaken_edges.is_empty()
+    }
+
+    /// The branch was reached but at least one outgoing edge was never taken
+    #[must_use]
+    pub fn is_partial(&self) -> bool {
+        (self.block_hit || !self.taken_edges.is_empty()) && !self.untaken_edges.is_empty()
+    }
+}
+
 /// Coverage report containing all coverage data
 #[derive(Debug)]
 pub struct CoverageReport {
@@ -50,6 +112,12 @@ pub struct CoverageReport {
     source_locations: HashMap<BlockId, String>,
     /// Function names per block
     function_names: HashMap<BlockId, String>,
+    /// Owning function per block
+    function_ids: HashMap<BlockId, FunctionId>,
+    /// Hit counts per CFG edge
+    edge_hits: HashMap<EdgeId, u64>,
+    /// Static CFG edges (used to find branches)
+    cfg_edges: BTreeSet<EdgeId>,
     /// Tainted blocks tracker
     tainted: TaintedBlocks,
     /// Session name
@@ -67,6 +135,9 @@ impl CoverageReport {
             hit_counts: HashMap::new(),
             source_locations: HashMap::new(),
             function_names: HashMap::new(),
+            function_ids: HashMap::new(),
+            edge_hits: HashMap::new(),
+            cfg_edges: BTreeSet::new(),
             tainted: TaintedBlocks::new(),
             session_name: None,
             tests: Vec::new(),
@@ -93,6 +164,16 @@ impl CoverageReport {
         *self.hit_counts.entry(block).or_insert(0) += count;
     }
 
+    /// Record a traversal of a CFG edge
+    pub fn record_edge_hit(&mut self, edge: EdgeId) {
+        *self.edge_hits.entry(edge).or_insert(0) += 1;
+    }
+
+    /// Record multiple traversals of a CFG edge
+    pub fn record_edge_hits(&mut self, edge: EdgeId, count: u64) {
+        *self.edge_hits.entry(edge).or_insert(0) += count;
+    }
+
     /// Record a violation
     pub fn record_violation(&mut self, violation: CoverageViolation) {
         self.tainted.record_violation(violation);
@@ -108,6 +189,72 @@ impl CoverageReport {
         let _ = self.function_names.insert(block, name.to_string());
     }
 
+    /// Set the owning function for a block
+    pub fn set_function_id(&mut self, block: BlockId, function: FunctionId) {
+        let _ = self.function_ids.insert(block, function);
+    }
+
+    /// Get the owning function for a block
+    #[must_use]
+    pub fn function_id(&self, block: BlockId) -> Option<FunctionId> {
+        self.function_ids.get(&block).copied()
+    }
+
+    /// Get the set of functions described by this report
+    #[must_use]
+    pub fn function_ids(&self) -> BTreeSet<FunctionId> {
+        self.function_ids.values().copied().collect()
+    }
+
+    /// Get the traversal count for an edge
+    #[must_use]
+    pub fn get_edge_hit_count(&self, edge: EdgeId) -> u64 {
+        self.edge_hits.get(&edge).copied().unwrap_or(0)
+    }
+
+    /// Register a static CFG edge so untaken branch sides can be reported
+    pub fn register_edge(&mut self, edge: EdgeId) {
+        let _ = self.cfg_edges.insert(edge);
+    }
+
+    /// Branch coverage for every block with two or more registered outgoing edges
+    #[must_use]
+    pub fn branch_coverages(&self) -> Vec<BranchCoverage> {
+        let mut outgoing: BTreeMap<BlockId, Vec<EdgeId>> = BTreeMap::new();
+        for edge in &self.cfg_edges {
+            outgoing.entry(edge.source()).or_default().push(*edge);
+        }
+
+        outgoing
+            .into_iter()
+            .filter(|(_, edges)| edges.len() >= 2)
+            .map(|(block_id, edges)| {
+                let (taken_edges, untaken_edges): (Vec<_>, Vec<_>) = edges
+                    .into_iter()
+                    .partition(|edge| self.get_edge_hit_count(*edge) > 0);
+                BranchCoverage {
+                    block_id,
+                    block_hit: self.is_covered(block_id),
+                    taken_edges,
+                    untaken_edges,
+                }
+            })
+            .collect()
+    }
+
+    /// Get all edges that were traversed at least once, in ascending order
+    #[must_use]
+    pub fn covered_edges(&self) -> Vec<EdgeId> {
+        let mut edges: Vec<EdgeId> = self
+            .edge_hits
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(edge, _)| *edge)
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
     /// Get the hit count for a block
     #[must_use]
     pub fn get_hit_count(&self, block: BlockId) -> u64 {
@@ -159,12 +306,20 @@ impl CoverageReport {
     /// Get coverage summary
     #[must_use]
     pub fn summary(&self) -> CoverageSummary {
+        let branches = self.branch_coverages();
         CoverageSummary {
             total_blocks: self.total_blocks,
             covered_blocks: self.covered_count(),
             coverage_percent: self.coverage_percent(),
             confidence_interval: None,
             effect_size: None,
+            total_branches: branches.len(),
+            covered_branches: branches.iter().filter(|b| b.is_fully_covered()).count(),
+            partial_branches: branches
+                .iter()
+                .filter(|b| b.is_partial())
+                .map(|b| b.block_id)
+                .collect(),
         }
     }
 
@@ -235,12 +390,61 @@ impl CoverageReport {
                 let _ = self.function_names.insert(*block, name.clone());
             }
         }
+        for (block, function) in &other.function_ids {
+            let _ = self.function_ids.entry(*block).or_insert(*function);
+        }
+        for (edge, count) in &other.edge_hits {
+            self.record_edge_hits(*edge, *count);
+        }
+        self.cfg_edges.extend(other.cfg_edges.iter().copied());
         for test in &other.tests {
             if !self.tests.contains(test) {
                 self.tests.push(test.clone());
             }
         }
     }
+
+    /// Combine partial reports from sharded test processes into one
+    ///
+    /// Block and edge hit counts are summed, so a block is covered in the result
+    /// if any shard covered it. All reports must describe the same module: the
+    /// same block count and the same set of `FunctionId`s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `reports` is empty or the reports describe different modules.
+    pub fn merge_all(reports: &[CoverageReport]) -> Result<Self, CoverageMergeError> {
+        let first = reports.first().ok_or(CoverageMergeError::NoReports)?;
+        let functions = first.function_ids();
+
+        for (index, report) in reports.iter().enumerate().skip(1) {
+            if report.total_blocks != first.total_blocks {
+                return Err(CoverageMergeError::BlockCountMismatch {
+                    index,
+                    expected: first.total_blocks,
+                    found: report.total_blocks,
+                });
+            }
+            let other = report.function_ids();
+            if other != functions {
+                return Err(CoverageMergeError::FunctionMismatch {
+                    index,
+                    missing: functions.difference(&other).copied().collect(),
+                    unexpected: other.difference(&functions).copied().collect(),
+                });
+            }
+        }
+
+        let mut merged = Self::new(first.total_blocks);
+        merged.session_name.clone_from(&first.session_name);
+        for report in reports {
+            merged.merge(report);
+            for violation in report.violations() {
+                merged.record_violation(violation.clone());
+            }
+        }
+        Ok(merged)
+    }
 }
 
 impl Default for CoverageReport {
@@ -582,6 +786,85 @@ mod tests {
         assert_eq!(coverages[1].function_name, Some("new_fn".to_string()));
     }
 
+    fn shard_report(function_ids: &[(u32, u32)]) -> CoverageReport {
+        let mut report = CoverageReport::new(4);
+        for &(block, function) in function_ids {
+            report.set_function_id(BlockId::new(block), FunctionId::new(function));
+        }
+        report
+    }
+
+    /// Test merge_all unions partial shard reports
+    #[test]
+    fn test_merge_all_unions_partial_reports() {
+        let layout = [(0, 0), (1, 0), (2, 1), (3, 1)];
+        let edge = EdgeId::new(BlockId::new(0), BlockId::new(1));
+
+        let mut shard_a = shard_report(&layout);
+        shard_a.record_hits(BlockId::new(0), 3);
+        shard_a.record_hit(BlockId::new(1));
+        shard_a.record_edge_hits(edge, 2);
+        shard_a.add_test("test_a");
+
+        let mut shard_b = shard_report(&layout);
+        shard_b.record_hits(BlockId::new(0), 2);
+        shard_b.record_hit(BlockId::new(2));
+        shard_b.record_edge_hit(edge);
+        shard_b.add_test("test_b");
+
+        let merged = CoverageReport::merge_all(&[shard_a, shard_b]).unwrap();
+
+        assert_eq!(merged.total_blocks(), 4);
+        assert_eq!(merged.get_hit_count(BlockId::new(0)), 5);
+        assert_eq!(merged.get_hit_count(BlockId::new(1)), 1);
+        assert_eq!(merged.get_hit_count(BlockId::new(2)), 1);
+        assert_eq!(merged.get_hit_count(BlockId::new(3)), 0);
+        assert_eq!(merged.covered_count(), 3);
+        assert!((merged.coverage_percent() - 75.0).abs() < 0.001);
+        assert_eq!(merged.get_edge_hit_count(edge), 3);
+        assert_eq!(merged.covered_edges(), vec![edge]);
+        assert_eq!(merged.tests(), ["test_a", "test_b"]);
+        assert_eq!(
+            merged.function_id(BlockId::new(2)),
+            Some(FunctionId::new(1))
+        );
+    }
+
+    /// Test merge_all rejects reports for different modules
+    #[test]
+    fn test_merge_all_rejects_mismatched_modules() {
+        assert_eq!(
+            CoverageReport::merge_all(&[]).unwrap_err(),
+            CoverageMergeError::NoReports
+        );
+
+        let err = CoverageReport::merge_all(&[CoverageReport::new(4), CoverageReport::new(5)])
+            .unwrap_err();
+        assert_eq!(
+            err,
+            CoverageMergeError::BlockCountMismatch {
+                index: 1,
+                expected: 4,
+                found: 5
+            }
+        );
+
+        let err = CoverageReport::merge_all(&[
+            shard_report(&[(0, 0), (1, 1)]),
+            shard_report(&[(0, 0), (1, 2)]),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err,
+            CoverageMergeError::FunctionMismatch {
+                index: 1,
+                missing: vec![FunctionId::new(1)],
+                unexpected: vec![FunctionId::new(2)],
+            }
+        );
+        assert!(err.to_string().contains("report 1"));
+    }
+
     /// Test merge does NOT add duplicate test names
     #[test]
     fn test_merge_tests_no_duplicates() {
@@ -664,6 +947,9 @@ mod tests {
             coverage_percent: 80.0,
             confidence_interval: Some((78.0, 82.0)),
             effect_size: Some(0.5),
+            total_branches: 0,
+            covered_branches: 0,
+            partial_branches: Vec::new(),
         };
 
         let summary2 = summary1;
@@ -684,6 +970,9 @@ mod tests {
             coverage_percent: 50.0,
             confidence_interval: None,
             effect_size: None,
+            total_branches: 0,
+            covered_branches: 0,
+            partial_branches: Vec::new(),
         };
 
         let debug = format!("{:?}", summary);
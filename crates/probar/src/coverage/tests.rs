@@ -765,6 +765,41 @@ mod executor_tests {
         assert!((summary.coverage_percent - 50.0).abs() < 0.01);
     }
 
+    /// H₀-EXEC-05b: CoverageExecutor reports record owning functions so
+    /// shards built from different modules refuse to merge
+    #[test]
+    fn test_executor_reports_with_different_functions_do_not_merge() {
+        let blocks = vec![BlockId::new(0), BlockId::new(1)];
+        let run = |function: u32| {
+            let sb = Superblock::new(
+                SuperblockId::new(0),
+                blocks.clone(),
+                FunctionId::new(function),
+            );
+            CoverageExecutor::new(vec![sb]).execute(|sb| SuperblockResult {
+                id: sb.id(),
+                success: true,
+                error: None,
+            })
+        };
+
+        let shard_a = run(0);
+        let shard_b = run(1);
+        assert_eq!(
+            shard_a.function_id(BlockId::new(1)),
+            Some(FunctionId::new(0))
+        );
+
+        let err = CoverageReport::merge_all(&[run(0), shard_b]).unwrap_err();
+        assert!(matches!(
+            err,
+            CoverageMergeError::FunctionMismatch { index: 1, .. }
+        ));
+
+        let merged = CoverageReport::merge_all(&[shard_a, run(0)]).unwrap();
+        assert_eq!(merged.get_hit_count(BlockId::new(0)), 2);
+    }
+
     /// H₀-EXEC-06: CoverageExecutor empty superblocks
     #[test]
     fn test_executor_empty() {
@@ -809,6 +844,80 @@ mod executor_tests {
         assert!(!result.success);
         assert_eq!(result.error, Some("Something went wrong".to_string()));
     }
+
+    /// H₀-EXEC-09: Branch granularity flags a conditional with only the true edge taken
+    #[test]
+    fn test_executor_branch_coverage_partial() {
+        // CFG: 0 -(true)-> 1 -> 3, 0 -(false)-> 2 -> 3
+        let b = BlockId::new;
+        let true_edge = EdgeId::new(b(0), b(1));
+        let false_edge = EdgeId::new(b(0), b(2));
+        let cfg = vec![
+            true_edge,
+            false_edge,
+            EdgeId::new(b(1), b(3)),
+            EdgeId::new(b(2), b(3)),
+        ];
+        let superblock = Superblock::new(
+            SuperblockId::new(0),
+            vec![b(0), b(1), b(2), b(3)],
+            FunctionId::new(0),
+        );
+
+        let executor = CoverageExecutor::new(vec![superblock])
+            .with_granularity(Granularity::Branch)
+            .with_cfg_edges(cfg);
+        assert_eq!(executor.granularity(), Granularity::Branch);
+
+        let report = executor.execute_with_edges(|sb| {
+            let result = SuperblockResult {
+                id: sb.id(),
+                success: true,
+                error: None,
+            };
+            (result, vec![true_edge, EdgeId::new(b(1), b(3))])
+        });
+
+        let branches = report.branch_coverages();
+        assert_eq!(branches.len(), 1);
+        let branch = &branches[0];
+        assert_eq!(branch.block_id, b(0));
+        assert!(branch.block_hit);
+        assert!(branch.is_partial());
+        assert!(!branch.is_fully_covered());
+        assert_eq!(branch.taken_edges, vec![true_edge]);
+        assert_eq!(branch.untaken_edges, vec![false_edge]);
+
+        let summary = report.summary();
+        assert_eq!(summary.coverage_percent, 100.0);
+        assert_eq!(summary.total_branches, 1);
+        assert_eq!(summary.covered_branches, 0);
+        assert_eq!(summary.partial_branches, vec![b(0)]);
+    }
+
+    /// H₀-EXEC-10: Block granularity ignores edges
+    #[test]
+    fn test_executor_block_granularity_ignores_edges() {
+        let edge = EdgeId::new(BlockId::new(0), BlockId::new(1));
+        let superblock = Superblock::new(
+            SuperblockId::new(0),
+            vec![BlockId::new(0), BlockId::new(1)],
+            FunctionId::new(0),
+        );
+        let executor = CoverageExecutor::new(vec![superblock]).with_cfg_edges(vec![edge]);
+
+        let report = executor.execute_with_edges(|sb| {
+            let result = SuperblockResult {
+                id: sb.id(),
+                success: true,
+                error: None,
+            };
+            (result, vec![edge])
+        });
+
+        assert_eq!(report.get_edge_hit_count(edge), 0);
+        assert!(report.branch_coverages().is_empty());
+    }
 }
 
 // ============================================================================
@@ -52,7 +52,8 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::path::PathBuf;
-use std::time::Duration;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -88,6 +89,15 @@ pub enum DockerError {
     #[error("Timeout waiting for container: {0}")]
     Timeout(String),
 
+    /// Test timed out; carries the container's most recent log lines.
+    #[error("Test timed out: {message}{}", format_log_tail(.recent_logs))]
+    TestTimeout {
+        /// What timed out.
+        message: String,
+        /// Last log lines captured before the timeout.
+        recent_logs: Vec<LogLine>,
+    },
+
     /// Health check failed.
     #[error("Health check failed: {0}")]
     HealthCheckFailed(String),
@@ -105,9 +115,44 @@ pub enum DockerError {
     NetworkError(String),
 }
 
+fn format_log_tail(lines: &[LogLine]) -> String {
+    use fmt::Write as _;
+
+    lines.iter().fold(String::new(), |mut acc, l| {
+        let _ = write!(acc, "\n  {l}");
+        acc
+    })
+}
+
 /// Result type for Docker operations.
 pub type DockerResult<T> = Result<T, DockerError>;
 
+/// A single line of container output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// Container that produced the line.
+    pub container_id: String,
+    /// Monotonic time since the container started.
+    ///
+    /// Relative to this container only; lines from different containers are
+    /// not ordered by comparing `elapsed`.
+    pub elapsed: Duration,
+    /// Line content without trailing newline.
+    pub line: String,
+}
+
+impl fmt::Display for LogLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{:>10.3}s {}] {}",
+            self.elapsed.as_secs_f64(),
+            self.container_id,
+            self.line
+        )
+    }
+}
+
 /// Supported browsers for Docker testing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -141,6 +186,14 @@ impl Browser {
         }
     }
 
+    /// Returns the Docker image tag pinned to a specific browser version.
+    ///
+    /// `"latest"` resolves to [`Browser::image_name`].
+    #[must_use]
+    pub fn image_for_version(&self, version: &str) -> String {
+        format!("{}:{version}", self.container_prefix())
+    }
+
     /// Returns the container name prefix for this browser.
     #[must_use]
     pub fn container_prefix(&self) -> &'static str {
@@ -336,8 +389,27 @@ impl ContainerConfig {
             ..Self::default()
         }
     }
+
+    /// Creates a container config pinned to a specific browser version.
+    #[must_use]
+    pub fn for_browser_version(browser: Browser, version: &str) -> Self {
+        let mut config = Self::for_browser(browser);
+        config.image = browser.image_for_version(version);
+        config.name = format!(
+            "{}-{version}-{}",
+            browser.container_prefix(),
+            uuid::Uuid::new_v4()
+        );
+        config
+            .environment
+            .insert("PROBAR_BROWSER_VERSION".to_string(), version.to_string());
+        config
+    }
 }
 
+/// First host port published for browser matrix entries.
+pub const MATRIX_HOST_PORT_BASE: u16 = 19222;
+
 /// Docker test runner configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DockerConfig {
@@ -359,6 +431,26 @@ pub struct DockerConfig {
     pub cleanup: bool,
     /// Whether to capture container logs.
     pub capture_logs: bool,
+    /// Number of trailing log lines attached to timeout errors.
+    #[serde(default = "default_log_tail_lines")]
+    pub log_tail_lines: usize,
+    /// Browser version matrix as (browser, version) pairs.
+    #[serde(default)]
+    pub matrix: Vec<(Browser, String)>,
+    /// Image tags known to be available locally or in the registry.
+    #[serde(default = "default_available_images")]
+    pub available_images: Vec<String>,
+}
+
+const fn default_log_tail_lines() -> usize {
+    20
+}
+
+fn default_available_images() -> Vec<String> {
+    Browser::all()
+        .iter()
+        .map(|b| b.image_name().to_string())
+        .collect()
 }
 
 impl Default for DockerConfig {
@@ -374,14 +466,74 @@ impl Default for DockerConfig {
             pull_images: true,
             cleanup: true,
             capture_logs: true,
+            log_tail_lines: default_log_tail_lines(),
+            matrix: Vec::new(),
+            available_images: default_available_images(),
         }
     }
 }
 
+impl DockerConfig {
+    /// Creates a config that runs every (browser, version) pair in the matrix.
+    #[must_use]
+    pub fn browser_matrix<V: Into<String>>(entries: Vec<(Browser, V)>) -> Self {
+        Self {
+            matrix: entries
+                .into_iter()
+                .map(|(browser, version)| (browser, version.into()))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Marks an image tag (e.g. `probar-firefox:115`) as available.
+    #[must_use]
+    pub fn with_available_image(mut self, image: impl Into<String>) -> Self {
+        let image = image.into();
+        if !self.available_images.contains(&image) {
+            self.available_images.push(image);
+        }
+        self
+    }
+
+    /// Expands the browser matrix into one container spec per entry.
+    ///
+    /// Each entry's CDP port is published on its own host port, starting at
+    /// [`MATRIX_HOST_PORT_BASE`], so entries can run in parallel. Fails fast
+    /// with [`DockerError::ImageNotFound`] on the first entry whose pinned
+    /// image tag is not available.
+    pub fn matrix_container_specs(&self) -> DockerResult<Vec<ContainerConfig>> {
+        self.matrix
+            .iter()
+            .enumerate()
+            .map(|(index, (browser, version))| {
+                let image = browser.image_for_version(version);
+                if !self.available_images.contains(&image) {
+                    return Err(DockerError::ImageNotFound(format!(
+                        "no image for {browser} {version} (expected tag {image})"
+                    )));
+                }
+                let host_port = u16::try_from(index)
+                    .ok()
+                    .and_then(|i| MATRIX_HOST_PORT_BASE.checked_add(i))
+                    .ok_or_else(|| {
+                        DockerError::ConfigError(format!(
+                            "browser matrix too large: no host port for entry {index}"
+                        ))
+                    })?;
+                let mut spec = ContainerConfig::for_browser_version(*browser, version);
+                spec.ports = vec![(host_port, browser.default_cdp_port())];
+                Ok(spec)
+            })
+            .collect()
+    }
+}
+
 /// Builder for DockerTestRunner.
 #[derive(Debug, Default)]
 pub struct DockerTestRunnerBuilder {
     config: DockerConfig,
+    log_sender: Option<mpsc::Sender<LogLine>>,
 }
 
 impl DockerTestRunnerBuilder {
@@ -448,6 +600,29 @@ impl DockerTestRunnerBuilder {
         self
     }
 
+    /// Sets how many trailing log lines are attached to timeout errors.
+    #[must_use]
+    pub fn log_tail_lines(mut self, lines: usize) -> Self {
+        self.config.log_tail_lines = lines;
+        self
+    }
+
+    /// Streams every container log line to `sender` as it is produced.
+    ///
+    /// The sender may be shared across runners; lines carry their container id.
+    #[must_use]
+    pub fn log_sender(mut self, sender: mpsc::Sender<LogLine>) -> Self {
+        self.log_sender = Some(sender);
+        self
+    }
+
+    /// Creates a log channel, returning the builder and its receiving end.
+    #[must_use]
+    pub fn stream_logs(self) -> (Self, mpsc::Receiver<LogLine>) {
+        let (tx, rx) = mpsc::channel();
+        (self.log_sender(tx), rx)
+    }
+
     /// Adds a volume mount.
     #[must_use]
     pub fn volume(mut self, host: PathBuf, container: String) -> Self {
@@ -469,6 +644,9 @@ impl DockerTestRunnerBuilder {
             state: ContainerState::NotCreated,
             container_id: None,
             logs: Vec::new(),
+            log_lines: Vec::new(),
+            log_sender: self.log_sender,
+            started_at: Instant::now(),
         })
     }
 }
@@ -480,6 +658,9 @@ pub struct DockerTestRunner {
     state: ContainerState,
     container_id: Option<String>,
     logs: Vec<String>,
+    log_lines: Vec<LogLine>,
+    log_sender: Option<mpsc::Sender<LogLine>>,
+    started_at: Instant,
 }
 
 impl DockerTestRunner {
@@ -513,6 +694,46 @@ impl DockerTestRunner {
         &self.logs
     }
 
+    /// Returns captured log lines with container id and timestamp.
+    #[must_use]
+    pub fn log_lines(&self) -> &[LogLine] {
+        &self.log_lines
+    }
+
+    /// Records one line of container output and forwards it to the log stream.
+    pub fn record_log(&mut self, line: impl Into<String>) {
+        let line = line.into();
+        let log_line = LogLine {
+            container_id: self
+                .container_id
+                .clone()
+                .unwrap_or_else(|| self.config.container.name.clone()),
+            elapsed: self.started_at.elapsed(),
+            line: line.clone(),
+        };
+        if let Some(sender) = &self.log_sender {
+            // A dropped receiver just means nobody is listening any more.
+            let _ = sender.send(log_line.clone());
+        }
+        if self.config.capture_logs {
+            self.log_lines.push(log_line);
+        }
+        self.logs.push(line);
+    }
+
+    /// Builds a timeout error carrying the last `log_tail_lines` log lines.
+    #[must_use]
+    pub fn timeout_error(&self, message: impl Into<String>) -> DockerError {
+        let start = self
+            .log_lines
+            .len()
+            .saturating_sub(self.config.log_tail_lines);
+        DockerError::TestTimeout {
+            message: message.into(),
+            recent_logs: self.log_lines[start..].to_vec(),
+        }
+    }
+
     /// Returns the CDP URL for connecting to the browser.
     #[must_use]
     pub fn cdp_url(&self) -> String {
@@ -558,8 +779,9 @@ impl DockerTestRunner {
         self.state = ContainerState::Creating;
         self.state = ContainerState::Starting;
         self.container_id = Some(format!("sim-{}", uuid::Uuid::new_v4()));
+        self.started_at = Instant::now();
         self.state = ContainerState::Running;
-        self.logs.push("Container started successfully".to_string());
+        self.record_log("Container started successfully");
         Ok(())
     }
 
@@ -571,14 +793,18 @@ impl DockerTestRunner {
             ));
         }
         self.state = ContainerState::Stopping;
-        self.logs.push("Container stopping".to_string());
+        self.record_log("Container stopping");
         self.state = ContainerState::Stopped;
-        self.logs.push("Container stopped".to_string());
+        self.record_log("Container stopped");
         self.container_id = None;
         Ok(())
     }
 
     /// Simulates running tests in the container.
+    ///
+    /// Each simulated test takes 100ms; once the run exceeds the configured
+    /// timeout a [`DockerError::TestTimeout`] carrying the recent logs is
+    /// returned.
     pub fn simulate_run_tests(&mut self, tests: &[&str]) -> DockerResult<TestResults> {
         if self.state != ContainerState::Running {
             return Err(DockerError::ContainerNotFound(
@@ -587,12 +813,18 @@ impl DockerTestRunner {
         }
 
         let mut results = TestResults::new(self.config.browser);
+        let mut run_time = Duration::ZERO;
         for test in tests {
-            self.logs.push(format!("Running test: {test}"));
+            self.record_log(format!("Running test: {test}"));
+            let duration = Duration::from_millis(100);
+            run_time += duration;
+            if run_time > self.config.timeout {
+                return Err(self.timeout_error(format!("{test} after {:?}", self.config.timeout)));
+            }
             results.add_result(TestResult {
                 name: (*test).to_string(),
                 passed: true,
-                duration: Duration::from_millis(100),
+                duration,
                 error: None,
             });
         }
@@ -652,6 +884,9 @@ impl TestResult {
 pub struct TestResults {
     /// Browser that ran the tests.
     pub browser: Browser,
+    /// Pinned browser version, if run as part of a version matrix.
+    #[serde(default)]
+    pub version: Option<String>,
     /// Individual test results.
     pub results: Vec<TestResult>,
     /// Total duration.
@@ -668,6 +903,7 @@ impl TestResults {
     pub fn new(browser: Browser) -> Self {
         Self {
             browser,
+            version: None,
             results: Vec::new(),
             total_duration: Duration::ZERO,
             passed: 0,
@@ -675,6 +911,13 @@ impl TestResults {
         }
     }
 
+    /// Sets the pinned browser version.
+    #[must_use]
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
     /// Adds a test result.
     pub fn add_result(&mut self, result: TestResult) {
         self.total_duration += result.duration;
@@ -711,10 +954,13 @@ impl TestResults {
 
 impl fmt::Display for TestResults {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.browser)?;
+        if let Some(version) = &self.version {
+            write!(f, " {version}")?;
+        }
         write!(
             f,
-            "{}: {} passed, {} failed ({:.1}%)",
-            self.browser,
+            ": {} passed, {} failed ({:.1}%)",
             self.passed,
             self.failed,
             self.pass_rate()
@@ -758,9 +1004,18 @@ impl ParallelRunnerBuilder {
         self
     }
 
+    /// Sets the runner configuration, including any browser version matrix.
+    #[must_use]
+    pub fn config(mut self, config: DockerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
     /// Builds the parallel runner.
+    ///
+    /// Every matrix entry must resolve to an available image.
     pub fn build(self) -> DockerResult<ParallelRunner> {
-        if self.browsers.is_empty() {
+        if self.browsers.is_empty() && self.config.matrix.is_empty() {
             return Err(DockerError::ConfigError(
                 "No browsers specified".to_string(),
             ));
@@ -768,11 +1023,13 @@ impl ParallelRunnerBuilder {
         if self.tests.is_empty() {
             return Err(DockerError::ConfigError("No tests specified".to_string()));
         }
+        self.config.matrix_container_specs()?;
         Ok(ParallelRunner {
             browsers: self.browsers,
             tests: self.tests,
             config: self.config,
             results: HashMap::new(),
+            matrix_results: HashMap::new(),
         })
     }
 }
@@ -784,6 +1041,7 @@ pub struct ParallelRunner {
     tests: Vec<String>,
     config: DockerConfig,
     results: HashMap<Browser, TestResults>,
+    matrix_results: HashMap<(Browser, String), TestResults>,
 }
 
 impl ParallelRunner {
@@ -811,8 +1069,15 @@ impl ParallelRunner {
         &self.results
     }
 
-    /// Simulates parallel test execution across browsers.
+    /// Returns matrix results keyed by (browser, version).
+    #[must_use]
+    pub fn results_by_version(&self) -> &HashMap<(Browser, String), TestResults> {
+        &self.matrix_results
+    }
+
+    /// Simulates parallel test execution across browsers and matrix entries.
     pub fn simulate_run(&mut self) -> DockerResult<()> {
+        self.simulate_run_matrix()?;
         for browser in &self.browsers.clone() {
             let mut runner = DockerTestRunner::builder()
                 .browser(*browser)
@@ -829,10 +1094,61 @@ impl ParallelRunner {
         Ok(())
     }
 
+    /// Runs matrix entries concurrently, at most `parallel` containers at a time.
+    fn simulate_run_matrix(&mut self) -> DockerResult<()> {
+        let specs = self.config.matrix_container_specs()?;
+        let entries: Vec<_> = self.config.matrix.iter().cloned().zip(specs).collect();
+        let chunk_size = self.config.parallel.max(1) as usize;
+        let test_refs: Vec<&str> = self.tests.iter().map(String::as_str).collect();
+
+        for chunk in entries.chunks(chunk_size) {
+            let outcomes: Vec<DockerResult<TestResults>> = std::thread::scope(|scope| {
+                let handles: Vec<_> = chunk
+                    .iter()
+                    .map(|((browser, version), spec)| {
+                        let test_refs = &test_refs;
+                        let timeout = self.config.timeout;
+                        scope.spawn(move || -> DockerResult<TestResults> {
+                            let mut runner = DockerTestRunner::builder()
+                                .browser(*browser)
+                                .timeout(timeout)
+                                .build()?;
+                            runner.config.container = spec.clone();
+                            runner.simulate_start()?;
+                            let results = runner.simulate_run_tests(test_refs)?;
+                            runner.simulate_stop()?;
+                            Ok(results.with_version(version.clone()))
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|h| {
+                        h.join().unwrap_or_else(|_| {
+                            Err(DockerError::TestExecutionFailed(
+                                "matrix worker panicked".to_string(),
+                            ))
+                        })
+                    })
+                    .collect()
+            });
+
+            for (((browser, version), _), outcome) in chunk.iter().zip(outcomes) {
+                self.matrix_results
+                    .insert((*browser, version.clone()), outcome?);
+            }
+        }
+        Ok(())
+    }
+
+    fn all_results(&self) -> impl Iterator<Item = &TestResults> {
+        self.results.values().chain(self.matrix_results.values())
+    }
+
     /// Returns whether all browsers passed all tests.
     #[must_use]
     pub fn all_passed(&self) -> bool {
-        !self.results.is_empty() && self.results.values().all(TestResults::all_passed)
+        self.all_results().next().is_some() && self.all_results().all(TestResults::all_passed)
     }
 
     /// Returns aggregated stats across all browsers.
@@ -841,7 +1157,7 @@ impl ParallelRunner {
         let mut passed = 0;
         let mut failed = 0;
         let mut duration = Duration::ZERO;
-        for result in self.results.values() {
+        for result in self.all_results() {
             passed += result.passed;
             failed += result.failed;
             duration += result.total_duration;
@@ -857,6 +1173,7 @@ impl Default for ParallelRunner {
             tests: Vec::new(),
             config: DockerConfig::default(),
             results: HashMap::new(),
+            matrix_results: HashMap::new(),
         }
     }
 }
@@ -1488,6 +1805,95 @@ mod tests {
         assert!(!runner.all_passed());
     }
 
+    // =========================================================================
+    // Browser Version Matrix Tests
+    // =========================================================================
+
+    fn matrix_config() -> DockerConfig {
+        DockerConfig::browser_matrix(vec![
+            (Browser::Chrome, "120"),
+            (Browser::Firefox, "115"),
+            (Browser::Firefox, "latest"),
+        ])
+        .with_available_image("probar-chrome:120")
+        .with_available_image("probar-firefox:115")
+    }
+
+    #[test]
+    fn test_browser_matrix_expands_to_container_specs() {
+        let specs = matrix_config()
+            .matrix_container_specs()
+            .expect("all images available");
+
+        let images: Vec<&str> = specs.iter().map(|s| s.image.as_str()).collect();
+        assert_eq!(
+            images,
+            vec![
+                "probar-chrome:120",
+                "probar-firefox:115",
+                "probar-firefox:latest"
+            ]
+        );
+        assert!(specs[0].name.starts_with("probar-chrome-120-"));
+        assert_eq!(
+            specs[1].environment.get("PROBAR_BROWSER_VERSION"),
+            Some(&"115".to_string())
+        );
+        let ports: Vec<(u16, u16)> = specs.iter().flat_map(|s| s.ports.clone()).collect();
+        assert_eq!(
+            ports,
+            vec![
+                (MATRIX_HOST_PORT_BASE, 9222),
+                (MATRIX_HOST_PORT_BASE + 1, 9223),
+                (MATRIX_HOST_PORT_BASE + 2, 9223),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_browser_matrix_missing_image_fails_fast() {
+        let config = DockerConfig::browser_matrix(vec![(Browser::WebKit, "17.4")]);
+        match config.matrix_container_specs() {
+            Err(DockerError::ImageNotFound(msg)) => {
+                assert!(msg.contains("webkit 17.4"));
+                assert!(msg.contains("probar-webkit:17.4"));
+            }
+            other => panic!("Expected ImageNotFound, got {other:?}"),
+        }
+
+        let result = ParallelRunner::builder()
+            .config(DockerConfig::browser_matrix(vec![(
+                Browser::WebKit,
+                "17.4",
+            )]))
+            .tests(&["test.rs"])
+            .build();
+        assert!(matches!(result, Err(DockerError::ImageNotFound(_))));
+    }
+
+    #[test]
+    fn test_parallel_runner_matrix_results_keyed_by_version() {
+        let mut runner = ParallelRunner::builder()
+            .config(matrix_config())
+            .tests(&["test1.rs", "test2.rs"])
+            .build()
+            .expect("Should build");
+
+        runner.simulate_run().expect("Should run");
+
+        let results = runner.results_by_version();
+        assert_eq!(results.len(), 3);
+        let firefox_115 = &results[&(Browser::Firefox, "115".to_string())];
+        assert_eq!(firefox_115.version.as_deref(), Some("115"));
+        assert_eq!(
+            firefox_115.to_string(),
+            "firefox 115: 2 passed, 0 failed (100.0%)"
+        );
+        assert!(runner.results_by_browser().is_empty());
+        assert!(runner.all_passed());
+        assert_eq!(runner.aggregate_stats().0, 6);
+    }
+
     // =========================================================================
     // Header Validation Tests
     // =========================================================================
@@ -1965,6 +2371,10 @@ mod tests {
             DockerError::CdpConnectionFailed("test".to_string()),
             DockerError::TestExecutionFailed("test".to_string()),
             DockerError::Timeout("test".to_string()),
+            DockerError::TestTimeout {
+                message: "test".to_string(),
+                recent_logs: vec![],
+            },
             DockerError::HealthCheckFailed("test".to_string()),
             DockerError::ConfigError("test".to_string()),
             DockerError::IoError("test".to_string()),
@@ -2003,6 +2413,93 @@ mod tests {
         assert!(runner.logs().len() > initial_logs + 1);
     }
 
+    #[test]
+    fn test_log_stream_attributes_interleaved_container_output() {
+        let (tx, rx) = mpsc::channel();
+        let mut chrome = DockerTestRunner::builder()
+            .log_sender(tx.clone())
+            .build()
+            .expect("Should build");
+        let mut firefox = DockerTestRunner::builder()
+            .browser(Browser::Firefox)
+            .log_tail_lines(2)
+            .log_sender(tx)
+            .build()
+            .expect("Should build");
+
+        chrome.simulate_start().expect("Should start");
+        firefox.simulate_start().expect("Should start");
+        for i in 0..3 {
+            chrome.record_log(format!("chrome line {i}"));
+            firefox.record_log(format!("firefox line {i}"));
+        }
+
+        let chrome_id = chrome.container_id().expect("running").to_string();
+        let firefox_id = firefox.container_id().expect("running").to_string();
+        assert_ne!(chrome_id, firefox_id);
+
+        let lines: Vec<LogLine> = rx.try_iter().collect();
+        assert_eq!(lines.len(), 8);
+        for line in &lines {
+            if line.line.starts_with("chrome") {
+                assert_eq!(line.container_id, chrome_id);
+            } else if line.line.starts_with("firefox") {
+                assert_eq!(line.container_id, firefox_id);
+            }
+        }
+        let interleaved: Vec<&str> = lines[2..].iter().map(|l| l.line.as_str()).collect();
+        assert_eq!(
+            interleaved,
+            vec![
+                "chrome line 0",
+                "firefox line 0",
+                "chrome line 1",
+                "firefox line 1",
+                "chrome line 2",
+                "firefox line 2",
+            ]
+        );
+        assert_eq!(chrome.log_lines().len(), 4);
+        assert!(chrome
+            .log_lines()
+            .windows(2)
+            .all(|w| w[0].elapsed <= w[1].elapsed));
+
+        match firefox.timeout_error("worker_tests.rs after 60s") {
+            DockerError::TestTimeout {
+                message,
+                recent_logs,
+            } => {
+                assert_eq!(message, "worker_tests.rs after 60s");
+                let lines: Vec<&str> = recent_logs.iter().map(|l| l.line.as_str()).collect();
+                assert_eq!(lines, vec!["firefox line 1", "firefox line 2"]);
+            }
+            other => panic!("Expected TestTimeout, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_run_tests_raises_timeout_with_recent_logs() {
+        let mut runner = DockerTestRunner::builder()
+            .timeout(Duration::from_millis(250))
+            .log_tail_lines(2)
+            .build()
+            .expect("Should build");
+        runner.simulate_start().expect("Should start");
+
+        match runner.simulate_run_tests(&["a.rs", "b.rs", "c.rs", "d.rs"]) {
+            Err(DockerError::TestTimeout {
+                message,
+                recent_logs,
+            }) => {
+                assert!(message.starts_with("c.rs after"), "{message}");
+                let lines: Vec<&str> = recent_logs.iter().map(|l| l.line.as_str()).collect();
+                assert_eq!(lines, vec!["Running test: b.rs", "Running test: c.rs"]);
+            }
+            other => panic!("Expected TestTimeout, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_test_result_duration() {
         let result = TestResult::passed("test".to_string(), Duration::from_millis(42));
@@ -27,6 +27,7 @@
 //! - **Genchi Genbutsu**: Abstract trait allows "going and seeing" with different browsers
 //! - **Risk Mitigation**: If chromiumoxide becomes unmaintained, swap to PlaywrightBridge
 
+use crate::emulation::MediaFeature;
 #[cfg(feature = "browser")]
 use crate::event::InputEvent;
 use crate::locator::BoundingBox;
@@ -406,6 +407,33 @@ pub trait ProbarDriver: Send + Sync {
     /// Reload page
     async fn reload(&mut self) -> ProbarResult<()>;
 
+    /// Override viewport size, device pixel ratio, and mobile mode
+    /// (CDP `Emulation.setDeviceMetricsOverride`)
+    ///
+    /// Drivers without device emulation return
+    /// [`ProbarError::UnsupportedOperation`].
+    async fn set_device_metrics(
+        &mut self,
+        _width: u32,
+        _height: u32,
+        _device_scale_factor: f64,
+        _mobile: bool,
+    ) -> ProbarResult<()> {
+        Err(ProbarError::UnsupportedOperation {
+            operation: "Emulation.setDeviceMetricsOverride".to_string(),
+        })
+    }
+
+    /// Override CSS media features (CDP `Emulation.setEmulatedMedia`)
+    ///
+    /// Drivers without media emulation return
+    /// [`ProbarError::UnsupportedOperation`].
+    async fn set_emulated_media(&mut self, _features: &[MediaFeature]) -> ProbarResult<()> {
+        Err(ProbarError::UnsupportedOperation {
+            operation: "Emulation.setEmulatedMedia".to_string(),
+        })
+    }
+
     /// Close the browser
     async fn close(&mut self) -> ProbarResult<()>;
 }
@@ -423,6 +451,10 @@ pub struct MockDriver {
     pub screenshot_data: Option<Screenshot>,
     /// Call history for verification
     pub call_history: Vec<String>,
+    /// Emulated device pixel ratio
+    pub device_scale_factor: Option<f64>,
+    /// Emulated CSS media features
+    pub emulated_media: Vec<MediaFeature>,
 }
 
 impl MockDriver {
@@ -458,6 +490,15 @@ impl MockDriver {
     pub fn was_called(&self, method: &str) -> bool {
         self.call_history.iter().any(|c| c.starts_with(method))
     }
+
+    /// Get the emulated value of a media feature
+    #[must_use]
+    pub fn media_feature(&self, name: &str) -> Option<&str> {
+        self.emulated_media
+            .iter()
+            .find(|f| f.name == name)
+            .map(|f| f.value.as_str())
+    }
 }
 
 #[cfg(feature = "browser")]
@@ -552,6 +593,29 @@ impl ProbarDriver for MockDriver {
         Ok(())
     }
 
+    async fn set_device_metrics(
+        &mut self,
+        width: u32,
+        height: u32,
+        device_scale_factor: f64,
+        mobile: bool,
+    ) -> ProbarResult<()> {
+        self.call_history.push(format!(
+            "set_device_metrics:{width}x{height}@{device_scale_factor}:mobile={mobile}"
+        ));
+        self.device_scale_factor = Some(device_scale_factor);
+        Ok(())
+    }
+
+    async fn set_emulated_media(&mut self, features: &[MediaFeature]) -> ProbarResult<()> {
+        self.call_history.push("set_emulated_media".to_string());
+        for feature in features {
+            self.emulated_media.retain(|f| f.name != feature.name);
+            self.emulated_media.push(feature.clone());
+        }
+        Ok(())
+    }
+
     async fn close(&mut self) -> ProbarResult<()> {
         self.call_history.push("close".to_string());
         Ok(())
@@ -12,6 +12,7 @@
 //! - [12] Sohn et al. (2015) VAD state machine testing
 
 use std::f32::consts::PI;
+use std::path::Path;
 
 /// Audio source types for injection (H4-H6 falsification)
 #[derive(Debug, Clone)]
@@ -66,6 +67,112 @@ impl Default for AudioSource {
     }
 }
 
+impl AudioSource {
+    /// Pure tone at the given frequency (Hz) and amplitude
+    #[must_use]
+    pub const fn tone(frequency: f32, amplitude: f32) -> Self {
+        Self::SineWave {
+            frequency,
+            amplitude,
+        }
+    }
+
+    /// Looping pre-recorded samples at the given sample rate
+    #[must_use]
+    pub const fn samples(data: Vec<f32>, sample_rate: u32) -> Self {
+        Self::Samples {
+            data,
+            sample_rate,
+            loop_playback: true,
+        }
+    }
+
+    /// Load a PCM WAV file (16-bit integer or 32-bit float) as looping samples
+    ///
+    /// Multi-channel files are downmixed to mono.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or is not a supported WAV file
+    pub fn from_wav_file(path: impl AsRef<Path>) -> Result<Self, AudioEmulatorError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|e| {
+            AudioEmulatorError::InvalidConfig(format!("cannot read {}: {e}", path.display()))
+        })?;
+        let (data, sample_rate) = decode_wav(&bytes)?;
+        Ok(Self::samples(data, sample_rate))
+    }
+}
+
+/// Decode a RIFF/WAVE byte buffer into mono f32 samples and its sample rate
+fn decode_wav(bytes: &[u8]) -> Result<(Vec<f32>, u32), AudioEmulatorError> {
+    let invalid = |msg: &str| AudioEmulatorError::InvalidConfig(format!("invalid WAV: {msg}"));
+    let u16_at = |i: usize| {
+        bytes
+            .get(i..i + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    };
+    let u32_at = |i: usize| {
+        bytes
+            .get(i..i + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+
+    let riff = bytes.get(0..4) == Some(b"RIFF".as_slice());
+    let wave = bytes.get(8..12) == Some(b"WAVE".as_slice());
+    if !riff || !wave {
+        return Err(invalid("missing RIFF/WAVE header"));
+    }
+
+    let mut format = None;
+    let mut pos = 12;
+    while let (Some(id), Some(len)) = (bytes.get(pos..pos + 4), u32_at(pos + 4)) {
+        let body = pos + 8;
+        let end = body + len as usize;
+        match id {
+            b"fmt " => {
+                let tag = u16_at(body).ok_or_else(|| invalid("truncated fmt chunk"))?;
+                let channels = u16_at(body + 2).ok_or_else(|| invalid("truncated fmt chunk"))?;
+                let rate = u32_at(body + 4).ok_or_else(|| invalid("truncated fmt chunk"))?;
+                let bits = u16_at(body + 14).ok_or_else(|| invalid("truncated fmt chunk"))?;
+                format = Some((tag, channels.max(1) as usize, rate, bits));
+            }
+            b"data" => {
+                let (tag, channels, rate, bits) =
+                    format.ok_or_else(|| invalid("data chunk before fmt chunk"))?;
+                let data = bytes
+                    .get(body..end.min(bytes.len()))
+                    .ok_or_else(|| invalid("truncated data chunk"))?;
+                let samples: Vec<f32> = match (tag, bits) {
+                    (1, 16) => data
+                        .chunks_exact(2)
+                        .map(|b| f32::from(i16::from_le_bytes([b[0], b[1]])) / 32768.0)
+                        .collect(),
+                    (3, 32) => data
+                        .chunks_exact(4)
+                        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                        .collect(),
+                    _ => {
+                        return Err(invalid(&format!(
+                            "unsupported encoding (format {tag}, {bits} bits)"
+                        )))
+                    }
+                };
+                let mono = samples
+                    .chunks_exact(channels)
+                    .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+                    .collect();
+                return Ok((mono, rate));
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even length
+        pos = end + (len as usize & 1);
+    }
+
+    Err(invalid("missing data chunk"))
+}
+
 /// Audio emulator configuration
 #[derive(Debug, Clone)]
 pub struct AudioEmulatorConfig {
@@ -148,6 +255,12 @@ impl AudioEmulator {
         self.generate_n_samples(num_samples)
     }
 
+    /// Generate the next callback buffer of `buffer_size` samples
+    #[must_use]
+    pub fn next_buffer(&mut self) -> Vec<f32> {
+        self.generate_n_samples(self.config.buffer_size)
+    }
+
     /// Generate exactly N samples
     #[must_use]
     pub fn generate_n_samples(&mut self, num_samples: usize) -> Vec<f32> {
@@ -234,20 +347,36 @@ impl AudioEmulator {
 
             AudioSource::Samples {
                 data,
-                sample_rate: _src_rate,
+                sample_rate: src_rate,
                 loop_playback,
             } => {
                 if data.is_empty() {
                     return 0.0;
                 }
-                let idx = self.sample_count as usize;
-                if idx < data.len() {
-                    data[idx].clamp(-1.0, 1.0)
-                } else if *loop_playback {
-                    data[idx % data.len()].clamp(-1.0, 1.0)
+                // Resample to the output rate by linear interpolation
+                let src_rate = if *src_rate == 0 {
+                    f64::from(sample_rate)
                 } else {
-                    0.0
+                    f64::from(*src_rate)
+                };
+                let position = self.sample_count as f64 * src_rate / f64::from(sample_rate);
+                let idx = position as usize;
+                let frac = (position - idx as f64) as f32;
+
+                let at = |i: usize| {
+                    if i < data.len() {
+                        data[i]
+                    } else if *loop_playback {
+                        data[i % data.len()]
+                    } else {
+                        0.0
+                    }
+                };
+                if idx >= data.len() && !*loop_playback {
+                    return 0.0;
                 }
+                let (a, b) = (at(idx), at(idx + 1));
+                (a + (b - a) * frac).clamp(-1.0, 1.0)
             }
         }
     }
@@ -287,7 +416,7 @@ impl AudioEmulator {
 
     // Create mock MediaStream
     const audioContext = new AudioContext({{ sampleRate: sampleRate }});
-    const bufferSize = 1024;
+    const bufferSize = {buffer_size};
     const scriptNode = audioContext.createScriptProcessor(bufferSize, 1, 1);
 
     scriptNode.onaudioprocess = function(e) {{
@@ -322,7 +451,8 @@ impl AudioEmulator {
 }})();
 "#,
             samples_json = samples_json,
-            sample_rate = self.config.sample_rate
+            sample_rate = self.config.sample_rate,
+            buffer_size = self.config.buffer_size
         )
     }
 
@@ -733,6 +863,95 @@ mod tests {
         assert!(samples[3].abs() < f32::EPSILON); // Silence after exhausted
     }
 
+    #[test]
+    fn test_tone_first_buffer_matches_sine() {
+        let mut emulator = AudioEmulator::with_config(
+            AudioSource::tone(440.0, 0.5),
+            AudioEmulatorConfig {
+                sample_rate: 16000,
+                buffer_size: 256,
+                ..Default::default()
+            },
+        );
+
+        let buffer = emulator.next_buffer();
+        assert_eq!(buffer.len(), 256);
+        for (n, &sample) in buffer.iter().enumerate() {
+            let expected = 0.5 * (2.0 * PI * 440.0 * n as f32 / 16000.0).sin();
+            assert!(
+                (sample - expected).abs() < 1e-3,
+                "sample {n}: {sample} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_samples_resampled_to_output_rate() {
+        // 8kHz source played at 16kHz: every other output sample is interpolated
+        let mut emulator = AudioEmulator::new(AudioSource::samples(vec![0.0, 0.4, 0.8], 8000));
+
+        let samples = emulator.generate_n_samples(8);
+        let expected = [0.0, 0.2, 0.4, 0.6, 0.8, 0.4, 0.0, 0.2];
+        for (got, want) in samples.iter().zip(expected) {
+            assert!((got - want).abs() < 1e-6, "{samples:?}");
+        }
+    }
+
+    #[test]
+    fn test_from_wav_file_16bit_stereo() {
+        let frames: [[i16; 2]; 3] = [[0, 0], [16384, 16384], [-32768, 0]];
+        let data: Vec<u8> = frames
+            .iter()
+            .flatten()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&2u16.to_le_bytes()); // stereo
+        wav.extend_from_slice(&22050u32.to_le_bytes());
+        wav.extend_from_slice(&(22050u32 * 4).to_le_bytes());
+        wav.extend_from_slice(&4u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(&data);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mic.wav");
+        std::fs::write(&path, wav).unwrap();
+
+        match AudioSource::from_wav_file(&path).unwrap() {
+            AudioSource::Samples {
+                data,
+                sample_rate,
+                loop_playback,
+            } => {
+                assert_eq!(sample_rate, 22050);
+                assert!(loop_playback);
+                assert_eq!(data, vec![0.0, 0.5, -0.5]);
+            }
+            other => panic!("expected samples, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_wav_file_rejects_non_wav() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("not.wav");
+        std::fs::write(&path, b"hello").unwrap();
+
+        assert!(matches!(
+            AudioSource::from_wav_file(&path),
+            Err(AudioEmulatorError::InvalidConfig(_))
+        ));
+        assert!(AudioSource::from_wav_file(dir.path().join("missing.wav")).is_err());
+    }
+
     #[test]
     fn test_mock_js_generation() {
         let emulator = AudioEmulator::new(AudioSource::Silence {
@@ -4,6 +4,10 @@
 //!
 //! ## EXTREME TDD: Tests written FIRST per spec
 
+#[cfg(feature = "browser")]
+use crate::driver::ProbarDriver;
+#[cfg(feature = "browser")]
+use crate::result::ProbarResult;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -91,6 +95,46 @@ impl TouchMode {
     }
 }
 
+/// Preferred color scheme for `prefers-color-scheme` emulation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorScheme {
+    /// Light color scheme
+    Light,
+    /// Dark color scheme
+    Dark,
+}
+
+impl ColorScheme {
+    /// CSS media feature value
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+/// A CSS media feature override (e.g., `prefers-reduced-motion: reduce`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaFeature {
+    /// Feature name
+    pub name: String,
+    /// Feature value (empty clears the override)
+    pub value: String,
+}
+
+impl MediaFeature {
+    /// Create a new media feature override
+    #[must_use]
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
 /// Device descriptor with all emulation parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeviceDescriptor {
@@ -230,6 +274,64 @@ impl DeviceEmulator {
             .with_user_agent(user_agent)
     }
 
+    /// Media feature overrides for reduced motion and color scheme
+    ///
+    /// `None` for the color scheme clears any previous override.
+    #[must_use]
+    pub fn media_features(
+        prefers_reduced_motion: bool,
+        color_scheme: Option<ColorScheme>,
+    ) -> Vec<MediaFeature> {
+        let motion = if prefers_reduced_motion {
+            "reduce"
+        } else {
+            "no-preference"
+        };
+        vec![
+            MediaFeature::new("prefers-reduced-motion", motion),
+            MediaFeature::new(
+                "prefers-color-scheme",
+                color_scheme.map_or("", |scheme| scheme.as_str()),
+            ),
+        ]
+    }
+
+    /// Apply a device's viewport, pixel ratio, and mobile flag to a driver
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the driver rejects the metrics override
+    #[cfg(feature = "browser")]
+    pub async fn apply_device<D: ProbarDriver + ?Sized>(
+        driver: &mut D,
+        device: &DeviceDescriptor,
+    ) -> ProbarResult<()> {
+        driver
+            .set_device_metrics(
+                device.viewport.width,
+                device.viewport.height,
+                device.device_scale_factor,
+                device.is_mobile,
+            )
+            .await
+    }
+
+    /// Emulate `prefers-reduced-motion` and `prefers-color-scheme` on a driver
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the driver rejects the media override
+    #[cfg(feature = "browser")]
+    pub async fn emulate_media<D: ProbarDriver + ?Sized>(
+        driver: &mut D,
+        prefers_reduced_motion: bool,
+        color_scheme: Option<ColorScheme>,
+    ) -> ProbarResult<()> {
+        driver
+            .set_emulated_media(&Self::media_features(prefers_reduced_motion, color_scheme))
+            .await
+    }
+
     // ========================================================================
     // iPhone Presets
     // ========================================================================
@@ -580,6 +682,71 @@ mod tests {
         }
     }
 
+    mod media_tests {
+        use super::*;
+
+        #[test]
+        fn test_color_scheme_as_str() {
+            assert_eq!(ColorScheme::Light.as_str(), "light");
+            assert_eq!(ColorScheme::Dark.as_str(), "dark");
+        }
+
+        #[test]
+        fn test_media_features_reduced_motion() {
+            let features = DeviceEmulator::media_features(true, None);
+            assert!(features.contains(&MediaFeature::new("prefers-reduced-motion", "reduce")));
+            assert!(features.contains(&MediaFeature::new("prefers-color-scheme", "")));
+        }
+
+        #[test]
+        fn test_media_features_no_preference() {
+            let features = DeviceEmulator::media_features(false, Some(ColorScheme::Light));
+            assert!(features.contains(&MediaFeature::new(
+                "prefers-reduced-motion",
+                "no-preference"
+            )));
+            assert!(features.contains(&MediaFeature::new("prefers-color-scheme", "light")));
+        }
+    }
+
+    #[cfg(feature = "browser")]
+    mod driver_emulation_tests {
+        use super::*;
+        use crate::driver::MockDriver;
+
+        #[tokio::test]
+        async fn test_apply_device_sets_pixel_ratio() {
+            let mut driver = MockDriver::new();
+            let device = DeviceDescriptor::new("Hi-DPI")
+                .with_viewport_size(393, 852)
+                .with_device_scale_factor(3.0)
+                .with_mobile(true);
+
+            DeviceEmulator::apply_device(&mut driver, &device)
+                .await
+                .unwrap();
+
+            assert_eq!(driver.device_scale_factor, Some(3.0));
+            assert!(driver.was_called("set_device_metrics:393x852@3"));
+        }
+
+        #[tokio::test]
+        async fn test_emulate_media_reduced_motion_and_dark() {
+            let mut driver = MockDriver::new();
+
+            DeviceEmulator::emulate_media(&mut driver, true, Some(ColorScheme::Dark))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                driver.media_feature("prefers-reduced-motion"),
+                Some("reduce")
+            );
+            assert_eq!(driver.media_feature("prefers-color-scheme"), Some("dark"));
+            assert!(driver.was_called("set_emulated_media"));
+        }
+    }
+
     mod preset_tests {
         use super::*;
 
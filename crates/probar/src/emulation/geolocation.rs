@@ -11,6 +11,11 @@
 #![allow(clippy::unreadable_literal)]
 
 use std::collections::HashMap;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Mean Earth radius in meters (haversine distance)
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
 
 /// Geographic position with coordinates and accuracy
 #[derive(Debug, Clone, PartialEq)]
@@ -91,6 +96,26 @@ impl GeolocationPosition {
         self
     }
 
+    /// Great-circle distance to another position in meters
+    #[must_use]
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_M * a.sqrt().asin()
+    }
+
+    /// Initial bearing towards another position in degrees (0-360, clockwise from north)
+    #[must_use]
+    pub fn bearing_to(&self, other: &Self) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlon = (other.longitude - self.longitude).to_radians();
+        let y = dlon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+
     // === Preset Locations ===
 
     /// New York City, USA (Times Square)
@@ -167,6 +192,12 @@ pub struct GeolocationMock {
     permission_granted: bool,
     /// Error simulation mode
     error_mode: Option<GeolocationError>,
+    /// Scripted movement path of (position, time offset) waypoints
+    path: Vec<(GeolocationPosition, Duration)>,
+    /// Injected time elapsed along the path
+    elapsed: Duration,
+    /// `watchPosition` subscribers
+    watchers: Vec<mpsc::Sender<GeolocationPosition>>,
 }
 
 /// Simulated geolocation errors
@@ -213,12 +244,121 @@ impl GeolocationMock {
             enabled: true,
             permission_granted: true,
             error_mode: None,
+            path: Vec::new(),
+            elapsed: Duration::ZERO,
+            watchers: Vec::new(),
         }
     }
 
     /// Set current position directly
     pub fn set_position(&mut self, position: GeolocationPosition) {
         self.current_position = Some(position);
+        self.notify_watchers();
+    }
+
+    /// Play a scripted movement path
+    ///
+    /// Waypoints are `(position, time offset)` pairs. The reported position
+    /// is interpolated between waypoints as time is advanced with
+    /// [`advance`](Self::advance), with `speed` and `heading` derived from
+    /// the current segment.
+    ///
+    /// # Panics
+    /// Panics if time offsets are not in non-decreasing order
+    pub fn play_path(&mut self, path: &[(GeolocationPosition, Duration)]) {
+        assert!(
+            path.windows(2).all(|w| w[0].1 <= w[1].1),
+            "Path timestamps must be non-decreasing"
+        );
+        self.path = path.to_vec();
+        self.elapsed = Duration::ZERO;
+        self.update_from_path();
+    }
+
+    /// Advance injected time along the current path
+    pub fn advance(&mut self, dt: Duration) {
+        self.elapsed += dt;
+        self.update_from_path();
+    }
+
+    /// Get injected time elapsed along the path
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Check if the path has reached its final waypoint
+    #[must_use]
+    pub fn is_path_complete(&self) -> bool {
+        self.path.last().is_some_and(|(_, t)| self.elapsed >= *t)
+    }
+
+    /// Subscribe to position updates (`watchPosition`)
+    ///
+    /// Dropping the receiver unsubscribes.
+    pub fn watch_position(&mut self) -> mpsc::Receiver<GeolocationPosition> {
+        let (tx, rx) = mpsc::channel();
+        if let Ok(position) = self.get_current_position() {
+            let _ = tx.send(position);
+        }
+        self.watchers.push(tx);
+        rx
+    }
+
+    /// Recompute the current position from the path and notify watchers
+    fn update_from_path(&mut self) {
+        if let Some(position) = self.position_at(self.elapsed) {
+            self.set_position(position);
+        }
+    }
+
+    /// Position along the path at a given time
+    fn position_at(&self, at: Duration) -> Option<GeolocationPosition> {
+        let (first, first_time) = self.path.first()?;
+        if at <= *first_time || self.path.len() == 1 {
+            return Some(first.clone());
+        }
+
+        let segment = self
+            .path
+            .windows(2)
+            .find(|w| at < w[1].1 && w[0].1 < w[1].1);
+        let Some([(from, t0), (to, t1)]) = segment else {
+            // Past the end: hold the final waypoint, stationary
+            let (last, _) = self.path.last()?;
+            let mut position = last.clone();
+            position.speed = Some(0.0);
+            return Some(position);
+        };
+
+        let span = t1.saturating_sub(*t0).as_secs_f64();
+        let frac = at.saturating_sub(*t0).as_secs_f64() / span;
+        let lerp = |a: f64, b: f64| a + (b - a) * frac;
+
+        let mut position = GeolocationPosition::new(
+            lerp(from.latitude, to.latitude),
+            lerp(from.longitude, to.longitude),
+            lerp(from.accuracy, to.accuracy),
+        )
+        .with_speed(from.distance_to(to) / span)
+        .with_heading(from.bearing_to(to));
+        if let (Some(a0), Some(a1)) = (from.altitude, to.altitude) {
+            position = position.with_altitude(
+                lerp(a0, a1),
+                from.altitude_accuracy
+                    .or(to.altitude_accuracy)
+                    .unwrap_or(0.0),
+            );
+        }
+        Some(position)
+    }
+
+    /// Send the current position to subscribers, pruning closed receivers
+    fn notify_watchers(&mut self) {
+        let Ok(position) = self.get_current_position() else {
+            return;
+        };
+        self.watchers.retain(|tx| tx.send(position.clone()).is_ok());
     }
 
     /// Set position from preset name
@@ -318,6 +458,9 @@ impl GeolocationMock {
         self.enabled = true;
         self.permission_granted = true;
         self.error_mode = None;
+        self.path.clear();
+        self.elapsed = Duration::ZERO;
+        self.watchers.clear();
     }
 }
 
@@ -679,6 +822,94 @@ mod tests {
     // H₀ EXTREME TDD: Geolocation Tests (G.3 P1)
     // =========================================================================
 
+    mod path_tests {
+        use super::*;
+
+        fn two_point_path() -> Vec<(GeolocationPosition, Duration)> {
+            vec![
+                (GeolocationPosition::new(0.0, 0.0, 10.0), Duration::ZERO),
+                (
+                    GeolocationPosition::new(0.0, 0.001, 10.0),
+                    Duration::from_secs(10),
+                ),
+            ]
+        }
+
+        #[test]
+        fn test_play_path_starts_at_first_waypoint() {
+            let mut mock = GeolocationMock::new();
+            mock.play_path(&two_point_path());
+
+            let pos = mock.get_current_position().unwrap();
+            assert!(pos.longitude.abs() < 1e-9);
+            assert!(!mock.is_path_complete());
+        }
+
+        #[test]
+        fn test_path_interpolates_with_speed_and_heading() {
+            let mut mock = GeolocationMock::new();
+            mock.play_path(&two_point_path());
+            mock.advance(Duration::from_secs(5));
+
+            let pos = mock.get_current_position().unwrap();
+            assert!((pos.longitude - 0.0005).abs() < 1e-9);
+            assert!(pos.latitude.abs() < 1e-9);
+
+            // Due east along the equator
+            let heading = pos.heading.unwrap();
+            assert!(heading > 0.0);
+            assert!((heading - 90.0).abs() < 0.01);
+
+            // 0.001 degrees of longitude at the equator is ~111.2 m over 10 s
+            let speed = pos.speed.unwrap();
+            assert!((speed - 11.12).abs() < 0.01, "speed was {speed}");
+        }
+
+        #[test]
+        fn test_path_holds_final_waypoint() {
+            let mut mock = GeolocationMock::new();
+            mock.play_path(&two_point_path());
+            mock.advance(Duration::from_secs(30));
+
+            let pos = mock.get_current_position().unwrap();
+            assert!((pos.longitude - 0.001).abs() < 1e-9);
+            assert!(pos.speed.is_some_and(|s| s.abs() < 1e-9));
+            assert!(mock.is_path_complete());
+        }
+
+        #[test]
+        fn test_watch_position_receives_updates() {
+            let mut mock = GeolocationMock::new();
+            let rx = mock.watch_position();
+
+            mock.play_path(&two_point_path());
+            mock.advance(Duration::from_secs(5));
+
+            let updates: Vec<_> = rx.try_iter().collect();
+            assert_eq!(updates.len(), 2);
+            assert!(updates[0].longitude.abs() < 1e-9);
+            assert!((updates[1].longitude - 0.0005).abs() < 1e-9);
+        }
+
+        #[test]
+        fn test_watch_position_skips_when_permission_denied() {
+            let mut mock = GeolocationMock::new();
+            let rx = mock.watch_position();
+            mock.set_permission(false);
+
+            mock.play_path(&two_point_path());
+            assert!(rx.try_recv().is_err());
+        }
+
+        #[test]
+        #[should_panic(expected = "Path timestamps must be non-decreasing")]
+        fn test_play_path_rejects_unordered_timestamps() {
+            let mut path = two_point_path();
+            path.reverse();
+            GeolocationMock::new().play_path(&path);
+        }
+    }
+
     mod h0_position_tests {
         use super::*;
 
@@ -13,5 +13,7 @@ mod device;
 mod geolocation;
 
 pub use audio::{AudioEmulator, AudioEmulatorConfig, AudioEmulatorError, AudioSource};
-pub use device::{DeviceDescriptor, DeviceEmulator, TouchMode, Viewport};
+pub use device::{
+    ColorScheme, DeviceDescriptor, DeviceEmulator, MediaFeature, TouchMode, Viewport,
+};
 pub use geolocation::{GeolocationMock, GeolocationPosition};
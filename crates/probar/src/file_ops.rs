@@ -2,6 +2,7 @@
 //!
 //! Provides support for file input/output operations in E2E tests.
 
+use crate::result::{ProbarError, ProbarResult};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
@@ -128,6 +129,25 @@ impl FileInput {
         String::from_utf8(self.contents.clone()).ok()
     }
 
+    /// Get the lowercase file extension with a leading dot (e.g. `.csv`)
+    #[must_use]
+    pub fn extension(&self) -> Option<String> {
+        let (stem, ext) = self.name.rsplit_once('.')?;
+        if stem.is_empty() || ext.is_empty() {
+            return None;
+        }
+        Some(format!(".{}", ext.to_lowercase()))
+    }
+
+    /// Check whether this file satisfies an `accept` attribute value
+    ///
+    /// The attribute is parsed with [`parse_accept`]; an empty attribute
+    /// accepts every file, mirroring browser behavior.
+    #[must_use]
+    pub fn accept(&self, accept: &str) -> bool {
+        accept_matches(&parse_accept(accept), self)
+    }
+
     /// Check if file is empty
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -303,9 +323,29 @@ impl FileChooser {
         self
     }
 
-    /// Set a single file
-    pub fn set_files(&mut self, files: impl IntoIterator<Item = FileInput>) {
+    /// Set accepted file types from a raw `accept` attribute value
+    /// (e.g. `"image/*,.csv"`)
+    #[must_use]
+    pub fn accept_attribute(mut self, accept: &str) -> Self {
+        self.accept = parse_accept(accept);
+        self
+    }
+
+    /// Set the selected files
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProbarError::FileRejected` naming the first file that does
+    /// not match the chooser's accept list. No files are selected in that case.
+    pub fn set_files(&mut self, files: impl IntoIterator<Item = FileInput>) -> ProbarResult<()> {
         let files: Vec<FileInput> = files.into_iter().collect();
+        if let Some(rejected) = files.iter().find(|f| !self.is_accepted(f)) {
+            return Err(ProbarError::FileRejected {
+                name: rejected.name.clone(),
+                mime_type: guess_mime_type(&rejected.name),
+                accept: self.accept.join(","),
+            });
+        }
         if !self.multiple && files.len() > 1 {
             // Safe: we checked files.len() > 1, so there's at least one item
             if let Some(first) = files.into_iter().next() {
@@ -314,49 +354,24 @@ impl FileChooser {
         } else {
             self.files = files;
         }
+        Ok(())
     }
 
     /// Set files from paths
-    pub fn set_input_files(&mut self, paths: &[impl AsRef<Path>]) {
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProbarError::FileRejected` if any path does not match the
+    /// accept list (see [`Self::set_files`]).
+    pub fn set_input_files(&mut self, paths: &[impl AsRef<Path>]) -> ProbarResult<()> {
         let files: Vec<FileInput> = paths.iter().map(FileInput::from_path).collect();
-        self.set_files(files);
+        self.set_files(files)
     }
 
     /// Check if file type is accepted
     #[must_use]
     pub fn is_accepted(&self, file: &FileInput) -> bool {
-        if self.accept.is_empty() {
-            return true;
-        }
-
-        let ext = file
-            .name
-            .rsplit('.')
-            .next()
-            .map(|e| format!(".{}", e.to_lowercase()));
-
-        for accept in &self.accept {
-            if accept == &file.mime_type {
-                return true;
-            }
-            if let Some(ref extension) = ext {
-                if accept == extension {
-                    return true;
-                }
-            }
-            if accept == "*/*" {
-                return true;
-            }
-            // Check MIME type patterns like "image/*"
-            if accept.ends_with("/*") {
-                let prefix = &accept[..accept.len() - 1];
-                if file.mime_type.starts_with(prefix) {
-                    return true;
-                }
-            }
-        }
-
-        false
+        accept_matches(&self.accept, file)
     }
 
     /// Get selected files
@@ -454,6 +469,48 @@ impl DownloadManager {
     }
 }
 
+/// Parse an HTML `accept` attribute into its individual tokens
+///
+/// Tokens are comma-separated, trimmed and lowercased. Empty tokens are
+/// dropped, so `""` yields an empty list (accept everything).
+#[must_use]
+pub fn parse_accept(accept: &str) -> Vec<String> {
+    accept
+        .split(',')
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Check a file against parsed accept tokens
+///
+/// Both the declared MIME type and the type guessed from the file name are
+/// considered, so a file built with a generic type still matches by name.
+fn accept_matches(accept: &[String], file: &FileInput) -> bool {
+    if accept.is_empty() {
+        return true;
+    }
+
+    let declared = file.mime_type.to_lowercase();
+    let guessed = guess_mime_type(&file.name);
+    let ext = file.extension();
+
+    accept.iter().any(|token| {
+        let token = token.trim().to_lowercase();
+        if token.starts_with('.') {
+            return ext.as_deref() == Some(token.as_str());
+        }
+        if token == "*/*" {
+            return true;
+        }
+        // MIME type patterns like "image/*"
+        if let Some(prefix) = token.strip_suffix('*').filter(|p| p.ends_with('/')) {
+            return declared.starts_with(prefix) || guessed.starts_with(prefix);
+        }
+        token == declared || token == guessed
+    })
+}
+
 /// Guess MIME type from filename
 #[must_use]
 pub fn guess_mime_type(filename: &str) -> String {
@@ -669,7 +726,9 @@ mod tests {
     #[test]
     fn h0_file_21_set_files() {
         let mut chooser = FileChooser::new();
-        chooser.set_files(vec![FileInput::text("test.txt", "content")]);
+        chooser
+            .set_files(vec![FileInput::text("test.txt", "content")])
+            .unwrap();
 
         assert_eq!(chooser.file_count(), 1);
     }
@@ -677,10 +736,12 @@ mod tests {
     #[test]
     fn h0_file_22_set_files_single_mode() {
         let mut chooser = FileChooser::single();
-        chooser.set_files(vec![
-            FileInput::text("a.txt", "a"),
-            FileInput::text("b.txt", "b"),
-        ]);
+        chooser
+            .set_files(vec![
+                FileInput::text("a.txt", "a"),
+                FileInput::text("b.txt", "b"),
+            ])
+            .unwrap();
 
         // Should only keep first file
         assert_eq!(chooser.file_count(), 1);
@@ -690,10 +751,12 @@ mod tests {
     #[test]
     fn h0_file_23_set_files_multiple_mode() {
         let mut chooser = FileChooser::multiple();
-        chooser.set_files(vec![
-            FileInput::text("a.txt", "a"),
-            FileInput::text("b.txt", "b"),
-        ]);
+        chooser
+            .set_files(vec![
+                FileInput::text("a.txt", "a"),
+                FileInput::text("b.txt", "b"),
+            ])
+            .unwrap();
 
         assert_eq!(chooser.file_count(), 2);
     }
@@ -882,7 +945,9 @@ mod tests {
     #[test]
     fn h0_file_45_file_chooser_set_input_files() {
         let mut chooser = FileChooser::multiple();
-        chooser.set_input_files(&["file1.txt", "file2.pdf"]);
+        chooser
+            .set_input_files(&["file1.txt", "file2.pdf"])
+            .unwrap();
         assert_eq!(chooser.file_count(), 2);
         assert_eq!(chooser.files()[0].name(), "file1.txt");
         assert_eq!(chooser.files()[1].name(), "file2.pdf");
@@ -891,7 +956,9 @@ mod tests {
     #[test]
     fn h0_file_46_file_chooser_clear() {
         let mut chooser = FileChooser::new();
-        chooser.set_files(vec![FileInput::text("test.txt", "content")]);
+        chooser
+            .set_files(vec![FileInput::text("test.txt", "content")])
+            .unwrap();
         assert!(chooser.has_files());
         chooser.clear();
         assert!(!chooser.has_files());
@@ -1093,14 +1160,16 @@ mod tests {
     #[test]
     fn h0_file_71_set_files_single_mode_empty() {
         let mut chooser = FileChooser::single();
-        chooser.set_files(Vec::<FileInput>::new());
+        chooser.set_files(Vec::<FileInput>::new()).unwrap();
         assert_eq!(chooser.file_count(), 0);
     }
 
     #[test]
     fn h0_file_72_set_files_single_mode_exactly_one() {
         let mut chooser = FileChooser::single();
-        chooser.set_files(vec![FileInput::text("single.txt", "content")]);
+        chooser
+            .set_files(vec![FileInput::text("single.txt", "content")])
+            .unwrap();
         assert_eq!(chooser.file_count(), 1);
         assert_eq!(chooser.files()[0].name(), "single.txt");
     }
@@ -1143,7 +1212,9 @@ mod tests {
     #[test]
     fn h0_file_77_set_input_files_single_mode() {
         let mut chooser = FileChooser::single();
-        chooser.set_input_files(&["file1.txt", "file2.txt"]);
+        chooser
+            .set_input_files(&["file1.txt", "file2.txt"])
+            .unwrap();
         // Single mode should only keep first file
         assert_eq!(chooser.file_count(), 1);
         assert_eq!(chooser.files()[0].name(), "file1.txt");
@@ -1183,4 +1254,97 @@ mod tests {
             assert_eq!(state, deserialized);
         }
     }
+
+    // =========================================================================
+    // H₀-FILE-81: accept attribute validation
+    // =========================================================================
+
+    #[test]
+    fn h0_file_81_parse_accept() {
+        assert_eq!(
+            parse_accept(" image/* , .CSV,,application/pdf "),
+            vec!["image/*", ".csv", "application/pdf"]
+        );
+        assert!(parse_accept("").is_empty());
+        assert!(parse_accept(" , ").is_empty());
+    }
+
+    #[test]
+    fn h0_file_82_file_input_accept_empty_allows_all() {
+        let file = FileInput::new("blob.bin", "application/octet-stream", vec![0]);
+        assert!(file.accept(""));
+    }
+
+    #[test]
+    fn h0_file_83_file_input_accept_wildcard() {
+        let png = FileInput::from_path("photo.png");
+        let csv = FileInput::from_path("data.csv");
+        assert!(png.accept("image/*"));
+        assert!(!csv.accept("image/*"));
+    }
+
+    #[test]
+    fn h0_file_84_file_input_accept_extension() {
+        let csv = FileInput::from_path("Report.CSV");
+        let txt = FileInput::from_path("notes.txt");
+        assert!(csv.accept(".csv"));
+        assert!(!txt.accept(".csv"));
+    }
+
+    #[test]
+    fn h0_file_85_file_input_accept_uses_guessed_mime() {
+        // Declared type is generic, but the name implies text/csv
+        let file = FileInput::new("data.csv", "application/octet-stream", vec![]);
+        assert!(file.accept("text/csv"));
+    }
+
+    #[test]
+    fn h0_file_86_extension_without_dot() {
+        assert_eq!(FileInput::from_path("README").extension(), None);
+        assert_eq!(
+            FileInput::from_path("a.tar.GZ").extension(),
+            Some(".gz".to_string())
+        );
+    }
+
+    #[test]
+    fn h0_file_87_set_files_rejects_unaccepted() {
+        let mut chooser = FileChooser::multiple().accept_attribute("image/*,.csv");
+        let err = chooser
+            .set_files(vec![
+                FileInput::from_path("photo.png"),
+                FileInput::from_path("evil.exe"),
+                FileInput::from_path("data.csv"),
+            ])
+            .unwrap_err();
+
+        match err {
+            ProbarError::FileRejected { name, accept, .. } => {
+                assert_eq!(name, "evil.exe");
+                assert_eq!(accept, "image/*,.csv");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+        assert!(!chooser.has_files());
+    }
+
+    #[test]
+    fn h0_file_88_set_files_accepts_mixed_forms() {
+        let mut chooser = FileChooser::multiple().accept_attribute("image/*, .csv");
+        chooser
+            .set_files(vec![
+                FileInput::from_path("photo.png"),
+                FileInput::from_path("data.csv"),
+            ])
+            .unwrap();
+        assert_eq!(chooser.file_count(), 2);
+    }
+
+    #[test]
+    fn h0_file_89_set_input_files_rejects_unaccepted() {
+        let mut chooser = FileChooser::single().accept(vec![".pdf"]);
+        assert!(chooser.set_input_files(&["notes.txt"]).is_err());
+        assert!(chooser.set_input_files(&["paper.pdf"]).is_ok());
+        assert_eq!(chooser.file_count(), 1);
+    }
 }
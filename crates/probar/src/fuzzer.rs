@@ -13,9 +13,15 @@
 //! ```
 
 use crate::event::InputEvent;
+use crate::result::ProbarResult;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
 
 /// Deterministic seed for reproducible fuzzing
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct Seed(u64);
 
 impl Seed {
@@ -217,12 +223,162 @@ impl InputFuzzer {
     pub const fn config(&self) -> &FuzzerConfig {
         &self.config
     }
+
+    /// Generate a deterministic sequence of `steps` input batches from a seed
+    ///
+    /// The same seed and configuration always produce the same sequence, so
+    /// a crasher can be regenerated from nothing but its seed.
+    #[must_use]
+    pub fn generate_sequence(seed: Seed, config: &FuzzerConfig, steps: usize) -> Vec<InputEvent> {
+        let mut fuzzer = Self::with_config(seed, config.clone());
+        (0..steps)
+            .flat_map(|_| fuzzer.generate_valid_inputs())
+            .collect()
+    }
+
+    /// Shrink a failing input sequence with delta debugging
+    ///
+    /// Repeatedly removes chunks of events while `still_fails` keeps
+    /// returning `true`, and returns the smallest sequence found. The result
+    /// is 1-minimal: removing any single remaining event makes it pass.
+    pub fn minimize<F>(failing: &Crasher, mut still_fails: F) -> Crasher
+    where
+        F: FnMut(&[InputEvent]) -> bool,
+    {
+        let mut current = failing.events.clone();
+        let mut granularity = 2;
+
+        while current.len() >= 2 {
+            let chunk = current.len().div_ceil(granularity);
+            let mut reduced = false;
+
+            for start in (0..current.len()).step_by(chunk) {
+                let end = (start + chunk).min(current.len());
+                let complement: Vec<InputEvent> = current[..start]
+                    .iter()
+                    .chain(&current[end..])
+                    .cloned()
+                    .collect();
+                if still_fails(&complement) {
+                    current = complement;
+                    granularity = (granularity - 1).max(2);
+                    reduced = true;
+                    break;
+                }
+            }
+
+            if !reduced {
+                if granularity >= current.len() {
+                    break;
+                }
+                granularity = (granularity * 2).min(current.len());
+            }
+        }
+
+        Crasher {
+            seed: failing.seed,
+            invariant_name: failing.invariant_name.clone(),
+            events: current,
+        }
+    }
+
+    /// Save crashers to a corpus directory, one JSON file per crasher
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created or a file cannot
+    /// be written.
+    pub fn save_corpus(dir: impl AsRef<Path>, crashers: &[Crasher]) -> ProbarResult<()> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        for crasher in crashers {
+            let json = serde_json::to_string_pretty(crasher)?;
+            std::fs::write(dir.join(crasher.file_name()), json)?;
+        }
+        Ok(())
+    }
+
+    /// Load all crashers from a corpus directory
+    ///
+    /// Files without a `.json` extension are ignored. Crashers are returned
+    /// sorted by file name so replay order is stable across runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read or a corpus file is
+    /// not a valid crasher.
+    pub fn load_corpus(dir: impl AsRef<Path>) -> ProbarResult<Vec<Crasher>> {
+        let mut paths: Vec<_> = std::fs::read_dir(dir)?
+            .filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        paths
+            .iter()
+            .map(|path| -> ProbarResult<Crasher> {
+                let json = std::fs::read_to_string(path)?;
+                Ok(serde_json::from_str(&json)?)
+            })
+            .collect()
+    }
+}
+
+/// A failing input sequence found by fuzzing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Crasher {
+    /// Seed that produced the original failing sequence
+    pub seed: Seed,
+    /// Name of the violated invariant
+    pub invariant_name: String,
+    /// Input events that reproduce the failure
+    pub events: Vec<InputEvent>,
+}
+
+impl Crasher {
+    /// Create a crasher from a seed and its failing events
+    #[must_use]
+    pub fn new(seed: Seed, invariant_name: impl Into<String>, events: Vec<InputEvent>) -> Self {
+        Self {
+            seed,
+            invariant_name: invariant_name.into(),
+            events,
+        }
+    }
+
+    /// Regenerate a crasher from its seed
+    #[must_use]
+    pub fn from_seed(
+        seed: Seed,
+        invariant_name: impl Into<String>,
+        config: &FuzzerConfig,
+        steps: usize,
+    ) -> Self {
+        Self::new(
+            seed,
+            invariant_name,
+            InputFuzzer::generate_sequence(seed, config, steps),
+        )
+    }
+
+    /// Corpus file name for this crasher
+    #[must_use]
+    pub fn file_name(&self) -> String {
+        format!(
+            "crash-{}-{:016x}-{}.json",
+            self.invariant_name,
+            self.seed.value(),
+            self.events.len()
+        )
+    }
 }
 
 /// Invariant checker for game state validation during fuzzing
 #[derive(Debug, Clone, Default)]
 pub struct InvariantChecker {
     checks: Vec<InvariantCheck>,
+    temporal: Vec<TemporalInvariant>,
     violations: Vec<InvariantViolation>,
 }
 
@@ -246,6 +402,65 @@ impl InvariantCheck {
     }
 }
 
+type TemporalFn = dyn Fn(&dyn Any, &dyn Any, &[InputEvent]) -> bool + Send + Sync;
+
+/// An invariant over two consecutive states
+///
+/// Receives `(prev_state, curr_state, events)`, where `events` are the inputs
+/// applied between the two frames, and returns `true` if the transition is
+/// valid. Use this for relationships such as "health never increases unless a
+/// heal event occurred".
+#[derive(Clone)]
+pub struct TemporalInvariant {
+    /// Name of the invariant
+    pub name: String,
+    /// Description of what the invariant checks
+    pub description: String,
+    check: Arc<TemporalFn>,
+}
+
+impl TemporalInvariant {
+    /// Create a temporal invariant over states of type `S`
+    ///
+    /// The invariant is only evaluated for transitions checked with the same
+    /// state type; other state types are ignored.
+    #[must_use]
+    pub fn new<S, F>(name: impl Into<String>, description: impl Into<String>, check: F) -> Self
+    where
+        S: 'static,
+        F: Fn(&S, &S, &[InputEvent]) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            check: Arc::new(
+                move |prev: &dyn Any, curr: &dyn Any, events: &[InputEvent]| match (
+                    prev.downcast_ref::<S>(),
+                    curr.downcast_ref::<S>(),
+                ) {
+                    (Some(prev), Some(curr)) => check(prev, curr, events),
+                    _ => true,
+                },
+            ),
+        }
+    }
+
+    /// Evaluate the invariant for one transition
+    #[must_use]
+    pub fn holds<S: 'static>(&self, prev: &S, curr: &S, events: &[InputEvent]) -> bool {
+        (self.check)(prev, curr, events)
+    }
+}
+
+impl fmt::Debug for TemporalInvariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TemporalInvariant")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
+}
+
 /// A violation of an invariant
 #[derive(Debug, Clone)]
 pub struct InvariantViolation {
@@ -255,6 +470,8 @@ pub struct InvariantViolation {
     pub message: String,
     /// Step at which the violation occurred
     pub step: u64,
+    /// Previous step, for violations of temporal invariants
+    pub prev_step: Option<u64>,
 }
 
 impl InvariantChecker {
@@ -269,15 +486,52 @@ impl InvariantChecker {
         self.checks.push(check);
     }
 
+    /// Add a temporal invariant checked across consecutive frames
+    pub fn add_temporal(&mut self, invariant: TemporalInvariant) {
+        self.temporal.push(invariant);
+    }
+
     /// Record a violation
     pub fn record_violation(&mut self, invariant_name: &str, message: &str, step: u64) {
         self.violations.push(InvariantViolation {
             invariant_name: invariant_name.to_string(),
             message: message.to_string(),
             step,
+            prev_step: None,
         });
     }
 
+    /// Check all temporal invariants for the transition `prev_step -> step`
+    ///
+    /// Records a violation for each failing invariant and returns `true` if
+    /// every invariant held.
+    pub fn check_transition<S: 'static>(
+        &mut self,
+        prev_step: u64,
+        step: u64,
+        prev: &S,
+        curr: &S,
+        events: &[InputEvent],
+    ) -> bool {
+        let failed: Vec<InvariantViolation> = self
+            .temporal
+            .iter()
+            .filter(|inv| !inv.holds(prev, curr, events))
+            .map(|inv| InvariantViolation {
+                invariant_name: inv.name.clone(),
+                message: format!(
+                    "{} (between step {prev_step} and step {step})",
+                    inv.description
+                ),
+                step,
+                prev_step: Some(prev_step),
+            })
+            .collect();
+        let ok = failed.is_empty();
+        self.violations.extend(failed);
+        ok
+    }
+
     /// Check if any violations occurred
     #[must_use]
     pub fn has_violations(&self) -> bool {
@@ -296,6 +550,12 @@ impl InvariantChecker {
         self.checks.len()
     }
 
+    /// Get the number of temporal invariants
+    #[must_use]
+    pub fn temporal_count(&self) -> usize {
+        self.temporal.len()
+    }
+
     /// Clear all violations
     pub fn clear_violations(&mut self) {
         self.violations.clear();
@@ -574,6 +834,78 @@ mod tests {
         }
     }
 
+    mod temporal_invariant_tests {
+        use super::*;
+
+        #[derive(Debug, Clone)]
+        struct Frame {
+            score: i32,
+        }
+
+        fn monotonic_score() -> TemporalInvariant {
+            TemporalInvariant::new(
+                "score_monotonic",
+                "Score must not decrease without a penalty event",
+                |prev: &Frame, curr: &Frame, events: &[InputEvent]| {
+                    let penalty = events
+                        .iter()
+                        .any(|e| matches!(e, InputEvent::KeyPress { key } if key == "Penalty"));
+                    curr.score >= prev.score || penalty
+                },
+            )
+        }
+
+        #[test]
+        fn test_add_temporal() {
+            let mut checker = InvariantChecker::new();
+            checker.add_temporal(monotonic_score());
+            assert_eq!(checker.temporal_count(), 1);
+            assert_eq!(checker.check_count(), 0);
+        }
+
+        #[test]
+        fn test_monotonic_score_catches_decrease() {
+            let mut checker = InvariantChecker::new();
+            checker.add_temporal(monotonic_score());
+
+            let frames = [10, 20, 15, 5, 30];
+            let events: [Vec<InputEvent>; 4] = [
+                vec![],
+                vec![InputEvent::key_press("Space")],
+                vec![InputEvent::key_press("Penalty")],
+                vec![],
+            ];
+
+            for (i, window) in frames.windows(2).enumerate() {
+                let prev = Frame { score: window[0] };
+                let curr = Frame { score: window[1] };
+                checker.check_transition(i as u64, i as u64 + 1, &prev, &curr, &events[i]);
+            }
+
+            // 20 -> 15 without penalty is a violation; 15 -> 5 had a penalty
+            let violations = checker.violations();
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].invariant_name, "score_monotonic");
+            assert_eq!(violations[0].prev_step, Some(1));
+            assert_eq!(violations[0].step, 2);
+        }
+
+        #[test]
+        fn test_other_state_types_ignored() {
+            let mut checker = InvariantChecker::new();
+            checker.add_temporal(monotonic_score());
+            assert!(checker.check_transition(0, 1, &10_i32, &5_i32, &[]));
+            assert!(!checker.has_violations());
+        }
+
+        #[test]
+        fn test_record_violation_has_no_prev_step() {
+            let mut checker = InvariantChecker::new();
+            checker.record_violation("test", "msg", 3);
+            assert_eq!(checker.violations()[0].prev_step, None);
+        }
+    }
+
     mod monte_carlo_simulation_tests {
         use super::*;
 
@@ -617,4 +949,85 @@ mod tests {
             assert!(!checker.has_violations());
         }
     }
+
+    mod corpus_tests {
+        use super::*;
+
+        /// Fails when an Escape press is followed later by a touch
+        fn escape_then_touch(events: &[InputEvent]) -> bool {
+            let escape = events
+                .iter()
+                .position(|e| matches!(e, InputEvent::KeyPress { key } if key == "Escape"));
+            escape.is_some_and(|i| {
+                events[i..]
+                    .iter()
+                    .any(|e| matches!(e, InputEvent::Touch { .. }))
+            })
+        }
+
+        fn find_crasher() -> Crasher {
+            let config = FuzzerConfig::default();
+            (0..100)
+                .map(|s| Crasher::from_seed(Seed::from_u64(s), "escape_then_touch", &config, 200))
+                .find(|c| escape_then_touch(&c.events))
+                .expect("some seed should produce a crasher")
+        }
+
+        #[test]
+        fn test_generate_sequence_deterministic() {
+            let config = FuzzerConfig::default();
+            let a = InputFuzzer::generate_sequence(Seed::from_u64(7), &config, 50);
+            let b = InputFuzzer::generate_sequence(Seed::from_u64(7), &config, 50);
+            assert_eq!(a.len(), 50);
+            assert_eq!(a, b);
+        }
+
+        #[test]
+        fn test_minimize_to_smallest_reproducer() {
+            let crasher = find_crasher();
+            assert_eq!(crasher.events.len(), 200);
+
+            let minimal = InputFuzzer::minimize(&crasher, escape_then_touch);
+            assert_eq!(minimal.events.len(), 2);
+            assert!(escape_then_touch(&minimal.events));
+            assert_eq!(minimal.seed, crasher.seed);
+        }
+
+        #[test]
+        fn test_minimize_keeps_passing_input_unchanged() {
+            let crasher =
+                Crasher::new(Seed::from_u64(1), "never", vec![InputEvent::key_press("A")]);
+            let minimal = InputFuzzer::minimize(&crasher, |_| false);
+            assert_eq!(minimal, crasher);
+        }
+
+        #[test]
+        fn test_corpus_round_trip_and_replay() {
+            let dir = tempfile::TempDir::new().unwrap();
+            let crasher = find_crasher();
+            let minimal = InputFuzzer::minimize(&crasher, escape_then_touch);
+
+            InputFuzzer::save_corpus(dir.path(), &[crasher.clone(), minimal.clone()]).unwrap();
+            std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+            let loaded = InputFuzzer::load_corpus(dir.path()).unwrap();
+            assert_eq!(loaded.len(), 2);
+            assert!(loaded.contains(&minimal));
+            assert!(loaded.iter().all(|c| escape_then_touch(&c.events)));
+
+            // The saved seed regenerates the original crasher deterministically
+            let replayed = Crasher::from_seed(
+                minimal.seed,
+                "escape_then_touch",
+                &FuzzerConfig::default(),
+                200,
+            );
+            assert_eq!(replayed, crasher);
+        }
+
+        #[test]
+        fn test_load_corpus_missing_dir_errors() {
+            assert!(InputFuzzer::load_corpus("/nonexistent/probar/corpus").is_err());
+        }
+    }
 }
@@ -45,6 +45,8 @@ pub struct GpuPixelResult {
     pub duration: Duration,
     /// Bug class if applicable
     pub bug_class: Option<PtxBugClass>,
+    /// Test was not run (e.g. no GPU backend); neither passed nor failed
+    pub skipped: bool,
 }
 
 impl GpuPixelResult {
@@ -57,6 +59,7 @@ impl GpuPixelResult {
             error: None,
             duration,
             bug_class: None,
+            skipped: false,
         }
     }
 
@@ -69,6 +72,7 @@ impl GpuPixelResult {
             error: Some(error.to_string()),
             duration,
             bug_class: None,
+            skipped: false,
         }
     }
 
@@ -81,6 +85,20 @@ impl GpuPixelResult {
             error: Some(error.to_string()),
             duration,
             bug_class: Some(bug),
+            skipped: false,
+        }
+    }
+
+    /// Create skipped result
+    #[must_use]
+    pub fn skip(name: &str, reason: &str, duration: Duration) -> Self {
+        Self {
+            name: name.to_string(),
+            passed: false,
+            error: Some(reason.to_string()),
+            duration,
+            bug_class: None,
+            skipped: true,
         }
     }
 
@@ -91,7 +109,7 @@ impl GpuPixelResult {
         if result.is_valid() {
             Self::pass("ptx_validation", start.elapsed())
         } else {
-            let first_bug = result.bugs.first();
+            let first_bug = result.errors().into_iter().next();
             let error = first_bug
                 .map(|b| format!("{}: {}", b.class, b.message))
                 .unwrap_or_else(|| "Unknown PTX error".to_string());
@@ -102,11 +120,109 @@ impl GpuPixelResult {
                 error: Some(error),
                 duration: start.elapsed(),
                 bug_class,
+                skipped: false,
             }
         }
     }
 }
 
+/// Expected kernel output for a tiny input, computed on the CPU
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputExpectation {
+    /// Kernel input buffer
+    pub input: Vec<f32>,
+    /// CPU reference output
+    pub reference: Vec<f32>,
+    /// Maximum allowed absolute per-element delta
+    pub tolerance: f32,
+}
+
+/// Per-element comparison of kernel output against a CPU reference
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputComparison {
+    /// Absolute delta per element (infinite where one side is missing)
+    pub deltas: Vec<f32>,
+    /// Largest delta
+    pub max_delta: f32,
+    /// First index whose delta exceeds the tolerance
+    pub first_divergence: Option<usize>,
+}
+
+impl OutputComparison {
+    /// Compare `actual` against `reference` element by element
+    #[must_use]
+    pub fn compare(actual: &[f32], reference: &[f32], tolerance: f32) -> Self {
+        let len = actual.len().max(reference.len());
+        let deltas: Vec<f32> = (0..len)
+            .map(|i| match (actual.get(i), reference.get(i)) {
+                (Some(a), Some(r)) => (a - r).abs(),
+                _ => f32::INFINITY,
+            })
+            .collect();
+        let first_divergence = deltas.iter().position(|d| d.is_nan() || *d > tolerance);
+        let max_delta = deltas.iter().copied().fold(0.0_f32, f32::max);
+        Self {
+            deltas,
+            max_delta,
+            first_divergence,
+        }
+    }
+
+    /// Check if every element is within tolerance
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.first_divergence.is_none()
+    }
+}
+
+/// Backend hook that launches a kernel for output comparison tests
+///
+/// Probar does not ship a PTX executor; hardware runners are provided by the
+/// caller (e.g. over a CUDA driver binding). [`FnKernelRunner`] covers CPU
+/// emulation.
+pub trait KernelRunner {
+    /// Whether kernels can be executed on this machine
+    fn is_available(&self) -> bool;
+
+    /// Launch `kernel_name` from `ptx` over `input` and return its output
+    fn run(&self, kernel_name: &str, ptx: &str, input: &[f32]) -> Result<Vec<f32>, String>;
+}
+
+/// Kernel runner backed by a plain function (CPU emulation or test double)
+pub struct FnKernelRunner<F> {
+    kernel: F,
+}
+
+impl<F> FnKernelRunner<F>
+where
+    F: Fn(&[f32]) -> Vec<f32>,
+{
+    /// Create runner from a function mapping input to output
+    #[must_use]
+    pub fn new(kernel: F) -> Self {
+        Self { kernel }
+    }
+}
+
+impl<F> std::fmt::Debug for FnKernelRunner<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnKernelRunner").finish_non_exhaustive()
+    }
+}
+
+impl<F> KernelRunner for FnKernelRunner<F>
+where
+    F: Fn(&[f32]) -> Vec<f32>,
+{
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn run(&self, _kernel_name: &str, _ptx: &str, input: &[f32]) -> Result<Vec<f32>, String> {
+        Ok((self.kernel)(input))
+    }
+}
+
 /// A single GPU pixel test
 #[derive(Debug, Clone)]
 pub struct GpuPixelTest {
@@ -116,6 +232,8 @@ pub struct GpuPixelTest {
     pub description: String,
     /// Bug class this test catches
     pub catches: PtxBugClass,
+    /// Expected output for a reference input, if this test executes the kernel
+    pub expected_output: Option<OutputExpectation>,
 }
 
 impl GpuPixelTest {
@@ -126,8 +244,20 @@ impl GpuPixelTest {
             name: name.to_string(),
             description: description.to_string(),
             catches,
+            expected_output: None,
         }
     }
+
+    /// Execute the kernel on `input` and compare against a CPU `reference`
+    #[must_use]
+    pub fn expect_output(mut self, input: Vec<f32>, reference: Vec<f32>, tolerance: f32) -> Self {
+        self.expected_output = Some(OutputExpectation {
+            input,
+            reference,
+            tolerance,
+        });
+        self
+    }
 }
 
 /// Suite of GPU pixel tests for a kernel
@@ -158,10 +288,10 @@ impl GpuPixelTestSuite {
         self.results.push(result);
     }
 
-    /// Check if all tests passed
+    /// Check if all tests passed (skipped tests do not count as failures)
     #[must_use]
     pub fn all_passed(&self) -> bool {
-        self.results.iter().all(|r| r.passed)
+        self.results.iter().all(|r| r.passed || r.skipped)
     }
 
     /// Get passed count
@@ -173,13 +303,25 @@ impl GpuPixelTestSuite {
     /// Get failed count
     #[must_use]
     pub fn failed_count(&self) -> usize {
-        self.results.iter().filter(|r| !r.passed).count()
+        self.results
+            .iter()
+            .filter(|r| !r.passed && !r.skipped)
+            .count()
+    }
+
+    /// Get skipped count
+    #[must_use]
+    pub fn skipped_count(&self) -> usize {
+        self.results.iter().filter(|r| r.skipped).count()
     }
 
     /// Get failures
     #[must_use]
     pub fn failures(&self) -> Vec<&GpuPixelResult> {
-        self.results.iter().filter(|r| !r.passed).collect()
+        self.results
+            .iter()
+            .filter(|r| !r.passed && !r.skipped)
+            .collect()
     }
 
     /// Run kernel-specific pixel tests
@@ -203,6 +345,77 @@ impl GpuPixelTestSuite {
         self.duration = start.elapsed();
     }
 
+    /// Run output comparison tests through `runner`
+    ///
+    /// Tests without an output expectation are ignored. If the runner is
+    /// unavailable every output test is reported as skipped.
+    pub fn run_output_tests(
+        &mut self,
+        ptx: &str,
+        tests: &[GpuPixelTest],
+        runner: &dyn KernelRunner,
+    ) {
+        for test in tests {
+            if let Some(expected) = &test.expected_output {
+                let result = self.pixel_output(&test.name, ptx, expected, runner);
+                self.add_result(result);
+            }
+        }
+    }
+
+    /// Pixel test: kernel output matches CPU reference
+    fn pixel_output(
+        &self,
+        name: &str,
+        ptx: &str,
+        expected: &OutputExpectation,
+        runner: &dyn KernelRunner,
+    ) -> GpuPixelResult {
+        let start = Instant::now();
+
+        if !runner.is_available() {
+            return GpuPixelResult::skip(name, "GPU backend unavailable", start.elapsed());
+        }
+
+        let actual = match runner.run(&self.kernel_name, ptx, &expected.input) {
+            Ok(actual) => actual,
+            Err(e) => {
+                return GpuPixelResult::fail(
+                    name,
+                    &format!("Kernel execution failed: {e}"),
+                    start.elapsed(),
+                )
+            }
+        };
+
+        let comparison =
+            OutputComparison::compare(&actual, &expected.reference, expected.tolerance);
+        match comparison.first_divergence {
+            None => GpuPixelResult::pass(name, start.elapsed()),
+            Some(_) if actual.len() != expected.reference.len() => GpuPixelResult::fail(
+                name,
+                &format!(
+                    "Output has {} elements, expected {}",
+                    actual.len(),
+                    expected.reference.len()
+                ),
+                start.elapsed(),
+            ),
+            Some(index) => GpuPixelResult::fail(
+                name,
+                &format!(
+                    "Output diverges at index {}: got {}, expected {} (delta {} > tolerance {})",
+                    index,
+                    actual[index],
+                    expected.reference[index],
+                    comparison.deltas[index],
+                    expected.tolerance
+                ),
+                start.elapsed(),
+            ),
+        }
+    }
+
     /// Pixel test: shared memory uses 32-bit addressing
     fn pixel_shared_mem_addressing(&self, ptx: &str) -> GpuPixelResult {
         let start = Instant::now();
@@ -281,12 +494,17 @@ impl GpuPixelTestSuite {
     #[must_use]
     pub fn summary(&self) -> String {
         let status = if self.all_passed() { "PASS" } else { "FAIL" };
+        let skipped = match self.skipped_count() {
+            0 => String::new(),
+            n => format!(", {n} skipped"),
+        };
         format!(
-            "[{}] {} - {}/{} passed ({:?})",
+            "[{}] {} - {}/{} passed{} ({:?})",
             status,
             self.kernel_name,
             self.passed_count(),
             self.results.len(),
+            skipped,
             self.duration
         )
     }
@@ -378,4 +596,104 @@ mod tests {
         assert!(summary.contains("PASS"));
         assert!(summary.contains("gemm_tiled"));
     }
+
+    mod output_comparison_tests {
+        use super::*;
+
+        /// Synthetic "kernel": y = 2x + 1
+        fn saxpy_kernel(input: &[f32]) -> Vec<f32> {
+            input.iter().map(|x| 2.0 * x + 1.0).collect()
+        }
+
+        #[test]
+        fn test_compare_within_tolerance() {
+            let comparison = OutputComparison::compare(&[1.0, 2.0005, 3.0], &[1.0, 2.0, 3.0], 1e-3);
+            assert!(comparison.passed());
+            assert!((comparison.max_delta - 0.0005).abs() < 1e-6);
+            assert_eq!(comparison.deltas.len(), 3);
+        }
+
+        #[test]
+        fn test_compare_reports_first_divergence() {
+            let comparison =
+                OutputComparison::compare(&[1.0, 2.5, 3.0, 9.0], &[1.0, 2.0, 3.0, 4.0], 0.1);
+            assert_eq!(comparison.first_divergence, Some(1));
+            assert_eq!(comparison.max_delta, 5.0);
+        }
+
+        #[test]
+        fn test_compare_length_mismatch_and_nan() {
+            let short = OutputComparison::compare(&[1.0], &[1.0, 2.0], 0.1);
+            assert_eq!(short.first_divergence, Some(1));
+            assert!(short.max_delta.is_infinite());
+
+            let nan = OutputComparison::compare(&[f32::NAN], &[1.0], 0.1);
+            assert_eq!(nan.first_divergence, Some(0));
+        }
+
+        #[test]
+        fn test_runner_hook_pass_and_fail() {
+            let tests =
+                vec![
+                    GpuPixelTest::new("saxpy_ok", "2x+1", PtxBugClass::InvalidSyntax)
+                        .expect_output(vec![0.0, 1.0, 2.0], vec![1.0, 3.0, 5.0], 1e-6),
+                    GpuPixelTest::new("saxpy_bad", "2x+1", PtxBugClass::InvalidSyntax)
+                        .expect_output(vec![0.0, 1.0, 2.0], vec![1.0, 3.0, 6.0], 1e-6),
+                    GpuPixelTest::new("static_only", "no output", PtxBugClass::InvalidSyntax),
+                ];
+            let mut suite = GpuPixelTestSuite::new("saxpy");
+            suite.run_output_tests("", &tests, &FnKernelRunner::new(saxpy_kernel));
+
+            assert_eq!(suite.results.len(), 2);
+            assert!(suite.results[0].passed);
+            assert!(!suite.results[1].passed);
+            let error = suite.results[1].error.as_deref().unwrap();
+            assert!(error.contains("index 2"));
+            assert!(error.contains("got 5"));
+            assert!(error.contains("expected 6"));
+        }
+
+        #[test]
+        fn test_runner_hook_length_mismatch() {
+            let tests = vec![GpuPixelTest::new("len", "len", PtxBugClass::InvalidSyntax)
+                .expect_output(vec![1.0], vec![3.0, 0.0], 0.0)];
+            let mut suite = GpuPixelTestSuite::new("saxpy");
+            suite.run_output_tests("", &tests, &FnKernelRunner::new(saxpy_kernel));
+            let error = suite.results[0].error.as_deref().unwrap();
+            assert!(error.contains("1 elements, expected 2"));
+        }
+
+        struct UnavailableRunner;
+
+        impl KernelRunner for UnavailableRunner {
+            fn is_available(&self) -> bool {
+                false
+            }
+
+            fn run(&self, _: &str, _: &str, _: &[f32]) -> Result<Vec<f32>, String> {
+                Err("no device".to_string())
+            }
+        }
+
+        #[test]
+        fn test_unavailable_backend_is_skipped() {
+            let tests = vec![
+                GpuPixelTest::new("saxpy", "2x+1", PtxBugClass::InvalidSyntax).expect_output(
+                    vec![1.0],
+                    vec![3.0],
+                    0.0,
+                ),
+            ];
+            let mut suite = GpuPixelTestSuite::new("saxpy");
+            suite.add_result(GpuPixelResult::pass("ptx", Duration::ZERO));
+            suite.run_output_tests("", &tests, &UnavailableRunner);
+
+            assert!(suite.results[1].skipped);
+            assert_eq!(suite.skipped_count(), 1);
+            assert_eq!(suite.failed_count(), 0);
+            assert!(suite.failures().is_empty());
+            assert!(suite.all_passed());
+            assert!(suite.summary().contains("1 skipped"));
+        }
+    }
 }
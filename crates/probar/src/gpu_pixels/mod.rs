@@ -26,9 +26,10 @@ mod ptx_analysis;
 mod regression;
 
 pub use kernel_pixels::{
-    standard_pixel_tests, GpuPixelResult, GpuPixelTest, GpuPixelTestSuite, KernelPixelConfig,
+    standard_pixel_tests, FnKernelRunner, GpuPixelResult, GpuPixelTest, GpuPixelTestSuite,
+    KernelPixelConfig, KernelRunner, OutputComparison, OutputExpectation,
 };
-pub use ptx_analysis::{PtxAnalyzer, PtxBug, PtxBugClass, PtxValidationResult};
+pub use ptx_analysis::{PtxAnalyzer, PtxBug, PtxBugClass, PtxBugSeverity, PtxValidationResult};
 pub use regression::{
     run_regression_suite, GpuRegressionSuite, RegressionConfig, RegressionResult,
 };
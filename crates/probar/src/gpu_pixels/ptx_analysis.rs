@@ -5,6 +5,7 @@
 //! - Loop branches going to END instead of START
 //! - Missing barrier synchronization
 //! - Invalid register types for operations
+//! - Shared memory strides that cause bank conflicts (warning)
 
 // Static regexes are always valid - compile-time constant patterns
 // collection_is_never_read: loop_start_labels is used for tracking/debug
@@ -15,7 +16,30 @@
 )]
 
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+/// Number of shared memory banks per warp
+const SHARED_MEM_BANKS: u64 = 32;
+/// Width of a shared memory bank in bytes
+const SHARED_MEM_BANK_WIDTH: u64 = 4;
+
+/// Severity of a detected PTX bug
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PtxBugSeverity {
+    /// Incorrect results or launch failure; fails validation
+    Error,
+    /// Correct but slow; reported without failing validation
+    Warning,
+}
+
+impl std::fmt::Display for PtxBugSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
 
 /// PTX bug classification
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -32,6 +56,19 @@ pub enum PtxBugClass {
     InvalidSyntax,
     /// Kernel entry point missing
     MissingEntryPoint,
+    /// Consecutive threads access the same shared memory bank
+    SharedMemBankConflict,
+}
+
+impl PtxBugClass {
+    /// Severity of this bug class
+    #[must_use]
+    pub const fn severity(&self) -> PtxBugSeverity {
+        match self {
+            Self::SharedMemBankConflict => PtxBugSeverity::Warning,
+            _ => PtxBugSeverity::Error,
+        }
+    }
 }
 
 impl std::fmt::Display for PtxBugClass {
@@ -43,6 +80,7 @@ impl std::fmt::Display for PtxBugClass {
             Self::NonInPlaceLoopAccumulator => write!(f, "non_inplace_accum"),
             Self::InvalidSyntax => write!(f, "invalid_syntax"),
             Self::MissingEntryPoint => write!(f, "missing_entry"),
+            Self::SharedMemBankConflict => write!(f, "shared_mem_bank_conflict"),
         }
     }
 }
@@ -73,9 +111,29 @@ pub struct PtxValidationResult {
 
 impl PtxValidationResult {
     /// Check if PTX passed all validations
+    ///
+    /// Warning-severity bugs are reported but do not fail validation.
     #[must_use]
     pub fn is_valid(&self) -> bool {
-        self.bugs.is_empty() && !self.kernel_names.is_empty()
+        self.errors().is_empty() && !self.kernel_names.is_empty()
+    }
+
+    /// Get error-severity bugs
+    #[must_use]
+    pub fn errors(&self) -> Vec<&PtxBug> {
+        self.bugs
+            .iter()
+            .filter(|b| b.class.severity() == PtxBugSeverity::Error)
+            .collect()
+    }
+
+    /// Get warning-severity bugs
+    #[must_use]
+    pub fn warnings(&self) -> Vec<&PtxBug> {
+        self.bugs
+            .iter()
+            .filter(|b| b.class.severity() == PtxBugSeverity::Warning)
+            .collect()
     }
 
     /// Get count of bugs by class
@@ -118,6 +176,18 @@ impl PtxAnalyzer {
         let loop_label = Regex::new(r"^(\w+_loop\w*):").unwrap();
         let branch_instr = Regex::new(r"bra\s+(\w+);").unwrap();
         let bar_sync = Regex::new(r"bar\.sync").unwrap();
+        let tid_x = Regex::new(r"^mov\.[us]32\s+(%\w+),\s*%tid\.x;").unwrap();
+        let scale = Regex::new(
+            r"^(mul\.lo\.[us]32|shl\.b32|mad\.lo\.[us]32)\s+(%\w+),\s*(%\w+),\s*(\d+)[,;]",
+        )
+        .unwrap();
+        let add_offset = Regex::new(r"^add\.[us]32\s+(%\w+),\s*(%\w+),\s*(%\w+|\d+);").unwrap();
+        let shared_access = Regex::new(r"^[ls][dt]\.shared\.[^\[]+\[(%\w+)(?:\+\d+)?\]").unwrap();
+
+        // Registers holding %tid.x, and registers holding %tid.x scaled by a
+        // byte stride, for bank conflict detection
+        let mut tid_regs: HashSet<String> = HashSet::new();
+        let mut strided_regs: HashMap<String, u64> = HashMap::new();
 
         // Track loop labels
         let mut loop_start_labels: HashSet<String> = HashSet::new();
@@ -154,6 +224,65 @@ impl PtxAnalyzer {
                 });
             }
 
+            // Track thread-index strides and flag shared accesses that put
+            // consecutive threads on the same bank
+            if let Some(caps) = tid_x.captures(trimmed) {
+                let dest = caps.get(1).unwrap().as_str();
+                strided_regs.remove(dest);
+                tid_regs.insert(dest.to_string());
+            } else if let Some(caps) = scale.captures(trimmed) {
+                let dest = caps.get(2).unwrap().as_str().to_string();
+                let src = caps.get(3).unwrap().as_str();
+                let imm: u64 = caps.get(4).unwrap().as_str().parse().unwrap_or(0);
+                let factor = if caps.get(1).unwrap().as_str().starts_with("shl") {
+                    1u64.checked_shl(imm as u32).unwrap_or(0)
+                } else {
+                    imm
+                };
+                let stride = if tid_regs.contains(src) {
+                    Some(factor)
+                } else {
+                    strided_regs.get(src).map(|s| s.saturating_mul(factor))
+                };
+                tid_regs.remove(&dest);
+                match stride {
+                    Some(stride) => strided_regs.insert(dest, stride),
+                    None => strided_regs.remove(&dest),
+                };
+            } else if let Some(caps) = add_offset.captures(trimmed) {
+                let dest = caps.get(1).unwrap().as_str().to_string();
+                let lhs = caps.get(2).unwrap().as_str();
+                let rhs = caps.get(3).unwrap().as_str();
+                // base + tid * stride keeps the stride; tid * a + tid * b does not
+                let stride = match (strided_regs.get(lhs), strided_regs.get(rhs)) {
+                    (Some(&s), None) if !tid_regs.contains(rhs) => Some(s),
+                    (None, Some(&s)) if !tid_regs.contains(lhs) => Some(s),
+                    _ => None,
+                };
+                tid_regs.remove(&dest);
+                match stride {
+                    Some(stride) => strided_regs.insert(dest, stride),
+                    None => strided_regs.remove(&dest),
+                };
+            } else if let Some(caps) = shared_access.captures(trimmed) {
+                let addr = caps.get(1).unwrap().as_str();
+                if let Some(&stride) = strided_regs.get(addr) {
+                    let bank_span = SHARED_MEM_BANKS * SHARED_MEM_BANK_WIDTH;
+                    if stride > 0 && stride % bank_span == 0 {
+                        bugs.push(PtxBug {
+                            class: PtxBugClass::SharedMemBankConflict,
+                            line: line_num + 1,
+                            instruction: trimmed.to_string(),
+                            message: format!(
+                                "Thread stride of {} bytes ({} words) maps every thread in a warp to the same shared memory bank. Pad the row by one word.",
+                                stride,
+                                stride / SHARED_MEM_BANK_WIDTH
+                            ),
+                        });
+                    }
+                }
+            }
+
             // Collect kernel names
             if let Some(caps) = entry_point.captures(trimmed) {
                 kernel_names.push(caps.get(1).unwrap().as_str().to_string());
@@ -484,4 +613,87 @@ loop_end:
         assert_eq!(cloned.kernel_names, result.kernel_names);
         assert_eq!(cloned.lines_analyzed, result.lines_analyzed);
     }
+
+    const BANK_CONFLICT_PTX: &str = r#"
+.visible .entry transpose_tile() {
+    .shared .align 4 .b8 tile[4096];
+    mov.u32 %r1, %tid.x;
+    mov.u32 %r2, tile;
+    mul.lo.u32 %r3, %r1, 128;
+    add.u32 %r4, %r2, %r3;
+    st.shared.f32 [%r4], %f0;
+    bar.sync 0;
+    ld.shared.f32 %f1, [%r4+4];
+    ret;
+}
+"#;
+
+    const PADDED_PTX: &str = r#"
+.visible .entry transpose_tile() {
+    .shared .align 4 .b8 tile[4224];
+    mov.u32 %r1, %tid.x;
+    mov.u32 %r2, tile;
+    mul.lo.u32 %r3, %r1, 132;
+    add.u32 %r4, %r2, %r3;
+    st.shared.f32 [%r4], %f0;
+    bar.sync 0;
+    ld.shared.f32 %f1, [%r4+4];
+    ret;
+}
+"#;
+
+    #[test]
+    fn test_bank_conflict_detected() {
+        let result = PtxAnalyzer::default().analyze(BANK_CONFLICT_PTX);
+        assert_eq!(result.bug_count(&PtxBugClass::SharedMemBankConflict), 2);
+        let warnings = result.warnings();
+        let bug = warnings[0];
+        assert_eq!(bug.line, 8);
+        assert!(bug.message.contains("128 bytes (32 words)"));
+        // Warnings surface but do not fail validation
+        assert!(result.errors().is_empty());
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn test_padded_stride_not_flagged() {
+        let result = PtxAnalyzer::strict().analyze(PADDED_PTX);
+        assert!(!result.has_bug(&PtxBugClass::SharedMemBankConflict));
+        assert!(result.bugs.is_empty());
+    }
+
+    #[test]
+    fn test_bank_conflict_via_shift() {
+        let ptx = r#"
+.visible .entry k() {
+    mov.u32 %r1, %tid.x;
+    shl.b32 %r2, %r1, 8;
+    ld.shared.f32 %f0, [%r2];
+    shl.b32 %r3, %r1, 2;
+    ld.shared.f32 %f1, [%r3];
+    ret;
+}
+"#;
+        let result = PtxAnalyzer::default().analyze(ptx);
+        // 256-byte stride conflicts; 4-byte stride is conflict-free
+        assert_eq!(result.bug_count(&PtxBugClass::SharedMemBankConflict), 1);
+        assert_eq!(result.warnings()[0].line, 5);
+    }
+
+    #[test]
+    fn test_bug_class_severity() {
+        assert_eq!(
+            PtxBugClass::SharedMemBankConflict.severity(),
+            PtxBugSeverity::Warning
+        );
+        assert_eq!(
+            PtxBugClass::SharedMemU64Addressing.severity(),
+            PtxBugSeverity::Error
+        );
+        assert_eq!(
+            format!("{}", PtxBugClass::SharedMemBankConflict),
+            "shared_mem_bank_conflict"
+        );
+        assert_eq!(format!("{}", PtxBugSeverity::Warning), "warning");
+    }
 }
@@ -493,7 +493,7 @@ pub mod file_ops;
 pub mod har;
 
 /// Playbook Testing: State Machine Verification (PROBAR-004)
-/// YAML-driven state machine testing with M1-M5 mutation classes.
+/// YAML-driven state machine testing with M1-M6 mutation classes.
 #[allow(
     clippy::missing_errors_doc,
     clippy::must_use_candidate,
@@ -573,12 +573,13 @@ pub mod llm;
 pub use accessibility::{
     AccessibilityAudit, AccessibilityConfig, AccessibilityIssue, AccessibilityValidator, Color,
     ContrastAnalysis, ContrastPair, FlashDetector, FlashResult, FocusConfig, KeyboardIssue,
-    Severity, MIN_CONTRAST_LARGE, MIN_CONTRAST_NORMAL, MIN_CONTRAST_UI,
+    Severity, MIN_CONTRAST_LARGE, MIN_CONTRAST_NORMAL, MIN_CONTRAST_UI, MIN_TARGET_SIZE,
 };
 pub use animation::{
-    sample_easing, verify_easing, verify_events, verify_timeline, AnimationEvent,
-    AnimationEventType, AnimationReport, AnimationTimeline, AnimationVerdict, EasingFunction,
-    EasingVerification, EventResult, Keyframe, ObservedEvent,
+    sample_easing, verify_easing, verify_events, verify_frame_pacing, verify_timeline,
+    AnimationEvent, AnimationEventType, AnimationReport, AnimationTimeline, AnimationVerdict,
+    EasingFunction, EasingVerification, EventResult, FramePacingReport, JankFrame, Keyframe,
+    ObservedEvent,
 };
 pub use assertion::{
     retry_contains, retry_eq, retry_none, retry_some, retry_true, Assertion, AssertionCheckResult,
@@ -589,17 +590,18 @@ pub use assertion::{
 };
 pub use audio_quality::{
     analyze_audio, analyze_samples, detect_clipping, detect_silence, AudioLevels,
-    AudioQualityConfig, AudioQualityReport, AudioVerdict, ClippingReport, SilenceRegion,
-    SilenceReport,
+    AudioQualityConfig, AudioQualityReport, AudioVerdict, ClippingReport, SilenceKind,
+    SilenceRegion, SilenceReport,
 };
 pub use av_sync::{
-    compare_edl_to_onsets, default_edl_path, detect_onsets, extract_audio, AudioOnset,
-    AudioTickPlacement, AvSyncReport, DetectionConfig, EditDecision, EditDecisionList,
-    SegmentSyncResult, SyncVerdict, TickDelta, DEFAULT_SAMPLE_RATE,
+    compare_edl_to_onsets, compare_edl_to_onsets_with_drift, default_edl_path, detect_onsets,
+    extract_audio, AudioOnset, AudioTickPlacement, AvSyncReport, DetectionConfig, EditDecision,
+    EditDecisionList, OnsetMethod, SegmentSyncResult, SyncVerdict, TickDelta,
+    DEFAULT_MAX_DRIFT_SLOPE_MS_PER_SEC, DEFAULT_SAMPLE_RATE,
 };
 pub use bridge::{
-    BridgeConnection, DiffRegion, EntitySnapshot, GameStateData, GameStateSnapshot, SnapshotCache,
-    StateBridge, VisualDiff,
+    BridgeConnection, DiffRegion, EntitySnapshot, GameStateData, GameStateSnapshot, ScrubPosition,
+    SnapshotCache, StateBridge, VisualDiff,
 };
 pub use browser::{Browser, BrowserConfig, BrowserConsoleLevel, BrowserConsoleMessage, Page};
 pub use capabilities::{
@@ -607,8 +609,9 @@ pub use capabilities::{
     WorkerMessage, WorkerState,
 };
 pub use cdp_coverage::{
-    CoverageConfig, CoverageRange, CoverageReport, CoveredFunction, FunctionCoverage, JsCoverage,
-    LineCoverage, ScriptCoverage, SourceMapEntry, WasmCoverage, WasmSourceMap,
+    CoverageConfig, CoverageRange, CoverageReport, CoverageThreshold, CoveredFunction,
+    FunctionCoverage, JsCoverage, LineCoverage, ScriptCoverage, SourceMapEntry, ThresholdCheck,
+    ThresholdViolation, WasmCoverage, WasmSourceMap,
 };
 pub use clock::{
     create_clock, Clock, ClockController, ClockError, ClockOptions, ClockState, FakeClock,
@@ -629,13 +632,14 @@ pub use driver::{
 };
 pub use event::{InputEvent, Touch, TouchAction};
 pub use file_ops::{
-    guess_mime_type, Download, DownloadManager, DownloadState, FileChooser, FileInput,
+    guess_mime_type, parse_accept, Download, DownloadManager, DownloadState, FileChooser, FileInput,
 };
 pub use fixture::{
     Fixture, FixtureBuilder, FixtureManager, FixtureScope, FixtureState, SimpleFixture,
 };
 pub use fuzzer::{
-    FuzzerConfig, InputFuzzer, InvariantCheck, InvariantChecker, InvariantViolation, Seed,
+    Crasher, FuzzerConfig, InputFuzzer, InvariantCheck, InvariantChecker, InvariantViolation, Seed,
+    TemporalInvariant,
 };
 pub use har::{
     Har, HarBrowser, HarCache, HarContent, HarCookie, HarCreator, HarEntry, HarError, HarHeader,
@@ -649,8 +653,8 @@ pub use locator::{
     DEFAULT_TIMEOUT_MS,
 };
 pub use network::{
-    CapturedRequest, HttpMethod, MockResponse, NetworkInterception, NetworkInterceptionBuilder,
-    Route, UrlPattern,
+    CapturedRequest, HttpMethod, MockResponse, NetworkActivity, NetworkInterception,
+    NetworkInterceptionBuilder, Route, UrlPattern,
 };
 pub use page_object::{
     PageObject, PageObjectBuilder, PageObjectInfo, PageRegistry, SimplePageObject, UrlMatcher,
@@ -671,11 +675,12 @@ pub use playbook::{
 };
 pub use presentar::{
     generate_falsification_playbook, parse_and_validate as parse_and_validate_presentar,
-    validate_config as validate_presentar_config, Cell as PresentarCell, Color as PresentarColor,
-    FalsificationCheck, FalsificationResult, KeybindingConfig, LayoutConfig, PanelConfig,
-    PanelConfigs, PanelType, PresentarConfig, PresentarError, TerminalAssertion, TerminalSnapshot,
-    ThemeConfig, ValidationResult as PresentarValidationResult, FALSIFICATION_COUNT,
-    SCHEMA_VERSION,
+    validate_config as validate_presentar_config, Cell as PresentarCell,
+    CellDiff as PresentarCellDiff, Color as PresentarColor, FalsificationCheck,
+    FalsificationResult, GoldenError as PresentarGoldenError, KeybindingConfig, LayoutConfig,
+    PanelConfig, PanelConfigs, PanelType, PresentarConfig, PresentarError, TerminalAssertion,
+    TerminalSnapshot, ThemeConfig, ValidationResult as PresentarValidationResult,
+    FALSIFICATION_COUNT, SCHEMA_VERSION,
 };
 pub use renacer_integration::{
     ChromeTrace, ChromeTraceEvent, TraceCollector, TraceContext, TraceSpan,
@@ -686,19 +691,20 @@ pub use replay::{
     VerificationResult, REPLAY_FORMAT_VERSION,
 };
 pub use reporter::{
-    AndonCordPulled, FailureMode, Reporter, TestResultEntry, TestStatus, TraceData,
+    AndonCordPulled, FailureMode, FlakinessReport, MergedTestResult, Reporter, SourceLocation,
+    TestResultEntry, TestStatus, TraceData,
 };
 pub use result::{ProbarError, ProbarResult};
 pub use runtime::{
-    ComponentId, EntityId, FrameResult, GameHostState, MemoryView, ProbarComponent, ProbarEntity,
-    RuntimeConfig, StateDelta, WasmRuntime,
+    ComponentChanges, ComponentId, ComponentWatcher, EntityId, FrameResult, GameHostState,
+    MemoryView, Pod, ProbarComponent, ProbarEntity, RuntimeConfig, StateDelta, WasmRuntime,
 };
 pub use shard::{ShardConfig, ShardParseError, ShardReport, ShardedRunner};
 pub use simulation::{
-    run_replay, run_simulation, RandomWalkAgent, RecordedFrame, ReplayResult, SimulatedGameState,
-    SimulationConfig, SimulationRecording,
+    run_replay, run_replay_with, run_simulation, FrameDivergence, RandomWalkAgent, RecordedFrame,
+    ReplayResult, SimulatedGameState, SimulationConfig, SimulationRecording,
 };
-pub use snapshot::{Snapshot, SnapshotConfig, SnapshotDiff};
+pub use snapshot::{PixelDiff, PixelFormat, Snapshot, SnapshotConfig, SnapshotDiff};
 pub use strict::{
     ChecklistError, ConsoleCapture, ConsoleSeverity, ConsoleValidationError, E2ETestChecklist,
     WasmStrictMode,
@@ -709,44 +715,48 @@ pub use tracing_support::{
 };
 #[cfg(feature = "tui")]
 pub use tui::{
-    expect_frame, FrameAssertion, FrameSequence, MultiValueTracker, SnapshotManager, TuiFrame,
-    TuiSnapshot, TuiTestBackend, ValueTracker,
+    expect_frame, CellColor, CellStyle, FrameAssertion, FrameSequence, MultiValueTracker,
+    SnapshotManager, StyleChange, StyledFrame, TuiFrame, TuiSnapshot, TuiTestBackend, ValueTracker,
 };
 pub use tui_load::{
-    ComponentTimings, DataGenerator, IntegrationLoadTest, SyntheticItem, TuiFrameMetrics,
-    TuiLoadAssertion, TuiLoadConfig, TuiLoadError, TuiLoadResult, TuiLoadTest,
+    ComponentTimings, DataGenerator, FrameTimeHistogram, IntegrationLoadTest, SlowFrame,
+    SyntheticItem, TuiFrameMetrics, TuiLoadAssertion, TuiLoadConfig, TuiLoadError, TuiLoadResult,
+    TuiLoadTest,
 };
 pub use ux_coverage::{
-    calculator_coverage, game_coverage, ElementCoverage, ElementId, InteractionType, StateId,
-    TrackedInteraction, UxCoverageBuilder, UxCoverageReport, UxCoverageTracker,
+    calculator_coverage, game_coverage, ElementCoverage, ElementId, FlowEdge, FlowGraph,
+    InteractionType, StateId, TrackedInteraction, UxCoverageBuilder, UxCoverageReport,
+    UxCoverageTracker,
 };
 pub use validators::{
-    CompressionAlgorithm, PartialResult, ScreenshotContent, StateTransition, StreamingMetric,
-    StreamingMetricRecord, StreamingState, StreamingUxValidator, StreamingValidationError,
-    StreamingValidationResult, TestExecutionStats, VuMeterConfig, VuMeterError, VuMeterSample,
+    Ballistics, CompressionAlgorithm, PartialResult, ScreenshotContent, StateTransition,
+    StreamingMetric, StreamingMetricRecord, StreamingState, StreamingUxValidator,
+    StreamingValidationError, StreamingValidationResult, TestExecutionStats, VuMeterConfig,
+    VuMeterError, VuMeterSample,
 };
 pub use video_quality::{
-    build_ffprobe_args, parse_ffprobe_json, probe_video, validate_video, VideoCheck,
-    VideoExpectations, VideoProbe, VideoQualityReport, VideoVerdict,
+    build_ffprobe_args, parse_ffprobe_json, probe_frame_timing, probe_video, validate_video,
+    FrameTiming, VideoCheck, VideoExpectations, VideoProbe, VideoQualityReport, VideoVerdict,
 };
 #[cfg(feature = "media")]
 pub use visual_regression::{
-    perceptual_diff, ImageDiffResult, MaskRegion, ScreenshotComparison, VisualRegressionConfig,
-    VisualRegressionTester,
+    perceptual_diff, ImageDiffResult, MaskRegion, ScreenshotComparison, SsimRegion,
+    VisualRegressionConfig, VisualRegressionTester,
 };
 pub use wait::{
-    wait_timeout, wait_until, FnCondition, LoadState, NavigationOptions, PageEvent, WaitCondition,
-    WaitOptions, WaitResult, Waiter, DEFAULT_WAIT_TIMEOUT_MS, NETWORK_IDLE_THRESHOLD_MS,
+    wait_timeout, wait_until, CombinedCondition, FnCondition, LoadState, NavigationOptions,
+    PageEvent, WaitCombinator, WaitCondition, WaitOptions, WaitResult, Waiter,
+    DEFAULT_WAIT_TIMEOUT_MS, NETWORK_IDLE_THRESHOLD_MS,
 };
 #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
 pub use watch::{
     FileChange, FileChangeKind, FileWatcher, FnWatchHandler, WatchBuilder, WatchConfig,
-    WatchHandler, WatchStats,
+    WatchHandler, WatchStats, EDITOR_TEMP_PATTERNS,
 };
 // Brick Architecture (PROBAR-SPEC-009)
 pub use brick::{
     Brick, BrickAssertion, BrickBudget, BrickError, BrickPhase, BrickResult, BrickVerification,
-    BudgetViolation,
+    BudgetViolation, PhaseTimings,
 };
 // Zero-Artifact Architecture (PROBAR-SPEC-009-P7)
 pub use brick::{
@@ -754,34 +764,38 @@ pub use brick::{
     EventBrick, EventHandler, EventType, FieldType, RingBufferConfig, WorkerBrick,
     WorkerTransition,
 };
-pub use brick_house::{BrickHouse, BrickHouseBuilder, BrickTiming, BudgetReport, JidokaAlert};
+pub use brick_house::{
+    BrickHouse, BrickHouseBuilder, BrickTiming, BudgetPolicy, BudgetReport, JidokaAlert,
+};
 pub use websocket::{
-    MessageDirection, MessageType, MockWebSocketResponse, WebSocketConnection, WebSocketMessage,
-    WebSocketMock, WebSocketMonitor, WebSocketMonitorBuilder, WebSocketState,
+    MessageDirection, MessageType, MockWebSocketResponse, ProtocolViolation, ScheduledPush,
+    WebSocketConnection, WebSocketMessage, WebSocketMock, WebSocketMonitor,
+    WebSocketMonitorBuilder, WebSocketState,
 };
 
 /// Prelude for convenient imports
 pub mod prelude {
     pub use super::accessibility::*;
     pub use super::animation::{
-        sample_easing, verify_easing, verify_events, verify_timeline, AnimationEvent,
-        AnimationEventType, AnimationReport, AnimationTimeline, AnimationVerdict, EasingFunction,
-        EasingVerification, EventResult, Keyframe, ObservedEvent,
+        sample_easing, verify_easing, verify_events, verify_frame_pacing, verify_timeline,
+        AnimationEvent, AnimationEventType, AnimationReport, AnimationTimeline, AnimationVerdict,
+        EasingFunction, EasingVerification, EventResult, FramePacingReport, JankFrame, Keyframe,
+        ObservedEvent,
     };
     pub use super::assertion::*;
     pub use super::audio_quality::{
         analyze_audio, analyze_samples, detect_clipping, detect_silence, AudioLevels,
-        AudioQualityConfig, AudioQualityReport, AudioVerdict, ClippingReport, SilenceRegion,
-        SilenceReport,
+        AudioQualityConfig, AudioQualityReport, AudioVerdict, ClippingReport, SilenceKind,
+        SilenceRegion, SilenceReport,
     };
     pub use super::av_sync::{
         compare_edl_to_onsets, default_edl_path, detect_onsets, extract_audio, AudioOnset,
         AudioTickPlacement, AvSyncReport, DetectionConfig, EditDecision, EditDecisionList,
-        SegmentSyncResult, SyncVerdict, TickDelta,
+        OnsetMethod, SegmentSyncResult, SyncVerdict, TickDelta,
     };
     pub use super::video_quality::{
-        build_ffprobe_args, parse_ffprobe_json, probe_video, validate_video, VideoCheck,
-        VideoExpectations, VideoProbe, VideoQualityReport, VideoVerdict,
+        build_ffprobe_args, parse_ffprobe_json, probe_frame_timing, probe_video, validate_video,
+        FrameTiming, VideoCheck, VideoExpectations, VideoProbe, VideoQualityReport, VideoVerdict,
     };
     // Brick Architecture (PROBAR-SPEC-009)
     pub use super::brick::*;
@@ -825,8 +839,9 @@ pub mod prelude {
     #[cfg(feature = "tui")]
     pub use super::tui::*;
     pub use super::tui_load::{
-        ComponentTimings, DataGenerator, IntegrationLoadTest, SyntheticItem, TuiFrameMetrics,
-        TuiLoadAssertion, TuiLoadConfig, TuiLoadError, TuiLoadResult, TuiLoadTest,
+        ComponentTimings, DataGenerator, FrameTimeHistogram, IntegrationLoadTest, SlowFrame,
+        SyntheticItem, TuiFrameMetrics, TuiLoadAssertion, TuiLoadConfig, TuiLoadError,
+        TuiLoadResult, TuiLoadTest,
     };
     pub use super::ux_coverage::*;
     pub use super::validators::*;
@@ -849,9 +864,9 @@ pub mod prelude {
     #[cfg(feature = "llm")]
     pub use super::llm::*;
     pub use super::wait::{
-        wait_timeout, wait_until, FnCondition, LoadState, NavigationOptions, PageEvent,
-        WaitCondition, WaitOptions, WaitResult, Waiter, DEFAULT_WAIT_TIMEOUT_MS,
-        NETWORK_IDLE_THRESHOLD_MS,
+        wait_timeout, wait_until, CombinedCondition, FnCondition, LoadState, NavigationOptions,
+        PageEvent, WaitCombinator, WaitCondition, WaitOptions, WaitResult, Waiter,
+        DEFAULT_WAIT_TIMEOUT_MS, NETWORK_IDLE_THRESHOLD_MS,
     };
     #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
     pub use super::watch::*;
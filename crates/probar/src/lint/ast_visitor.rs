@@ -1,6 +1,8 @@
 //! AST-based State Synchronization Linter (PROBAR-WASM-003)
 //!
 //! Uses `syn` crate for proper Rust AST traversal to detect Rc<RefCell<T>> patterns.
+//! `Arc` (used by multithreaded WASM builds) is treated as the same shared handle
+//! and reported under the same rule IDs.
 //! This replaces the text-based pattern matching approach that was vulnerable to:
 //! - Turbofish syntax bypass (`Alias::<T>::new()`)
 //! - Alternative constructors (`Rc::default()`, `Rc::from()`)
@@ -11,6 +13,7 @@ use std::collections::HashSet;
 use syn::visit::Visit;
 use syn::{Expr, ExprCall, ExprMethodCall, ItemFn, ItemType, Pat, ReturnType, Type};
 
+use super::state_sync::SHARED_HANDLE_TYPES;
 use super::{LintError, LintSeverity, StateSyncReport};
 
 /// Patterns that indicate Rc creation
@@ -85,15 +88,22 @@ impl<'a> AstStateSyncVisitor<'a> {
         span.start().line
     }
 
-    /// Check if a type is Rc-like
-    fn is_rc_type(ty: &Type) -> bool {
+    /// Check if a name is a shared handle type (`Rc` or `Arc`)
+    fn is_shared_handle(name: &str) -> bool {
+        SHARED_HANDLE_TYPES.contains(&name)
+    }
+
+    /// Return the handle name if a type is Rc-like (`Rc<T>`, `std::sync::Arc<T>`, ...)
+    fn rc_type_name(ty: &Type) -> Option<String> {
         if let Type::Path(type_path) = ty {
             if let Some(segment) = type_path.path.segments.last() {
                 let name = segment.ident.to_string();
-                return name == "Rc" || name == "Arc";
+                if Self::is_shared_handle(&name) {
+                    return Some(name);
+                }
             }
         }
-        false
+        None
     }
 
     /// Check if a type path refers to an Rc type alias
@@ -119,11 +129,13 @@ impl<'a> AstStateSyncVisitor<'a> {
 
             // Check for Rc::new, Rc::default, Rc::from
             if path.segments.len() >= 2 {
-                let type_name = path.segments[0].ident.to_string();
+                // Segment before the method, so `std::sync::Arc::new` and
+                // `Arc::<T>::new` both resolve to `Arc`
+                let type_name = path.segments[path.segments.len() - 2].ident.to_string();
                 let method_name = path.segments.last().map(|s| s.ident.to_string());
 
                 // Direct Rc::new() or Arc::new()
-                if (type_name == "Rc" || type_name == "Arc")
+                if Self::is_shared_handle(&type_name)
                     && method_name
                         .as_ref()
                         .map(|m| RC_CONSTRUCTORS.contains(&m.as_str()))
@@ -133,7 +145,7 @@ impl<'a> AstStateSyncVisitor<'a> {
                 }
 
                 // HOTFIX PROBAR-WASM-003: Detect Rc::from_raw - raw pointer laundering
-                if (type_name == "Rc" || type_name == "Arc" || type_name == "Weak")
+                if (Self::is_shared_handle(&type_name) || type_name == "Weak")
                     && method_name
                         .as_ref()
                         .map(|m| UNSAFE_RC_CONSTRUCTORS.contains(&m.as_str()))
@@ -192,14 +204,14 @@ impl<'a> AstStateSyncVisitor<'a> {
         }
     }
 
-    /// Check if a method call returns Rc (e.g., .to_rc(), .clone())
+    /// Check if a method call returns Rc (e.g., .to_rc(), .to_arc(), .clone())
     fn is_rc_method_call(&self, method_call: &ExprMethodCall) -> bool {
         let method_name = method_call.method.to_string();
-        // Methods that commonly return Rc
-        method_name == "to_rc"
-            || method_name == "into_rc"
-            || method_name == "as_rc"
-            || method_name == "wrap_rc"
+        // Methods that commonly return Rc or Arc
+        matches!(
+            method_name.as_str(),
+            "to_rc" | "into_rc" | "as_rc" | "wrap_rc" | "to_arc" | "into_arc" | "as_arc" | "wrap_arc"
+        )
             // clone() on an Rc variable
             || (method_name == "clone" && self.expr_is_rc(&method_call.receiver))
     }
@@ -260,15 +272,15 @@ impl<'a> AstStateSyncVisitor<'a> {
 
 impl<'ast> Visit<'ast> for AstStateSyncVisitor<'_> {
     fn visit_item_type(&mut self, node: &'ast ItemType) {
-        // Detect: type Foo = Rc<...> or type Foo<T> = Rc<RefCell<T>>
-        if Self::is_rc_type(&node.ty) {
+        // Detect: type Foo = Rc<...> or type Foo<T> = Arc<Mutex<T>>
+        if let Some(handle) = Self::rc_type_name(&node.ty) {
             let alias_name = node.ident.to_string();
             self.rc_type_aliases.insert(alias_name.clone());
 
             self.errors.push(LintError {
                 rule: "WASM-SS-006".to_string(),
                 message: format!(
-                    "Type alias `{alias_name}` wraps Rc - usage with constructors may cause state desync"
+                    "Type alias `{alias_name}` wraps {handle} - usage with constructors may cause state desync"
                 ),
                 file: self.file.clone(),
                 line: self.span_to_line(node.ident.span()),
@@ -286,14 +298,14 @@ impl<'ast> Visit<'ast> for AstStateSyncVisitor<'_> {
 
         // Check return type for Rc
         if let ReturnType::Type(_, ty) = &node.sig.output {
-            if Self::is_rc_type(ty) {
+            if let Some(handle) = Self::rc_type_name(ty) {
                 let fn_name = node.sig.ident.to_string();
                 self.rc_returning_functions.insert(fn_name.clone());
 
                 self.errors.push(LintError {
                     rule: "WASM-SS-007".to_string(),
                     message: format!(
-                        "Function `{fn_name}` returns Rc - callers may create disconnected state"
+                        "Function `{fn_name}` returns {handle} - callers may create disconnected state"
                     ),
                     file: self.file.clone(),
                     line: self.span_to_line(node.sig.ident.span()),
@@ -323,14 +335,14 @@ impl<'ast> Visit<'ast> for AstStateSyncVisitor<'_> {
     fn visit_impl_item_fn(&mut self, node: &'ast syn::ImplItemFn) {
         // Check return type for Rc
         if let ReturnType::Type(_, ty) = &node.sig.output {
-            if Self::is_rc_type(ty) {
+            if let Some(handle) = Self::rc_type_name(ty) {
                 let fn_name = node.sig.ident.to_string();
                 self.rc_returning_functions.insert(fn_name.clone());
 
                 self.errors.push(LintError {
                     rule: "WASM-SS-007".to_string(),
                     message: format!(
-                        "Method `{fn_name}` returns Rc - callers may create disconnected state"
+                        "Method `{fn_name}` returns {handle} - callers may create disconnected state"
                     ),
                     file: self.file.clone(),
                     line: self.span_to_line(node.sig.ident.span()),
@@ -386,8 +398,8 @@ impl<'ast> Visit<'ast> for AstStateSyncVisitor<'_> {
                             // HOTFIX PROBAR-WASM-003: Detect from_raw laundering
                             let rule = if self.is_unsafe_rc_reconstruction(&method) {
                                 "WASM-SS-009" // Unsafe Rc reconstruction from raw pointer
-                            } else if type_name == "Rc" || type_name == "Arc" {
-                                "WASM-SS-001" // Direct Rc::new
+                            } else if Self::is_shared_handle(&type_name) {
+                                "WASM-SS-001" // Direct Rc::new / Arc::new
                             } else if self.rc_type_aliases.contains(&type_name) {
                                 "WASM-SS-006" // Type alias
                             } else {
@@ -411,10 +423,15 @@ impl<'ast> Visit<'ast> for AstStateSyncVisitor<'_> {
 
                         if self.fn_creates_closure {
                             let method_name = method_call.method.to_string();
+                            let handle = if method_name.ends_with("arc") {
+                                "Arc"
+                            } else {
+                                "Rc"
+                            };
                             self.errors.push(LintError {
                                 rule: "WASM-SS-008".to_string(),
                                 message: format!(
-                                    "Method `.{method_name}()` returns Rc - local `{var_name}` \
+                                    "Method `.{method_name}()` returns {handle} - local `{var_name}` \
                                      may cause state desync if captured in closure"
                                 ),
                                 file: self.file.clone(),
@@ -792,6 +809,128 @@ fn spawn() {
         );
     }
 
+    /// Test Arc captured local instead of self field (multithreaded WASM)
+    #[test]
+    fn test_ast_detect_arc_captured_local_instead_of_field() {
+        let source = r#"
+impl Worker {
+    fn start(&mut self) {
+        let state = Arc::new(Mutex::new(State::Idle));
+        let worker_state = Arc::clone(&state);
+        thread::spawn(move || {
+            *worker_state.lock().unwrap() = State::Running;
+        });
+    }
+}
+"#;
+
+        let report = lint_source_ast(source, "worker.rs").expect("parse failed");
+
+        let ss001: Vec<_> = report
+            .errors
+            .iter()
+            .filter(|e| e.rule == "WASM-SS-001")
+            .collect();
+        let lines: Vec<usize> = ss001.iter().map(|e| e.line).collect();
+
+        assert_eq!(lines, vec![4, 5], "Errors: {:?}", report.errors);
+        assert!(ss001[0].message.contains("Arc::new"));
+        assert!(ss001[1].message.contains("Arc::clone"));
+        assert_eq!(ss001[0].severity, LintSeverity::Error);
+    }
+
+    /// Test Rc::clone / Arc::clone are flagged even when cloning a self field
+    #[test]
+    fn test_ast_clone_from_self_field_flagged() {
+        let source = r#"
+impl Worker {
+    fn start(&mut self) {
+        let worker_state = Arc::clone(&self.state);
+        let rc_state = Rc::clone(&self.ui_state);
+        thread::spawn(move || {
+            *worker_state.lock().unwrap() = State::Running;
+        });
+    }
+}
+"#;
+
+        let report = lint_source_ast(source, "worker.rs").expect("parse failed");
+
+        let lines: Vec<usize> = report
+            .errors
+            .iter()
+            .filter(|e| e.rule == "WASM-SS-001")
+            .map(|e| e.line)
+            .collect();
+        assert_eq!(lines, vec![4, 5], "Errors: {:?}", report.errors);
+    }
+
+    /// Test Arc type alias with turbofish and fully-qualified Arc paths
+    #[test]
+    fn test_ast_detect_arc_alias_turbofish_and_qualified_path() {
+        let source = r#"
+type Shared<T> = std::sync::Arc<Mutex<T>>;
+
+fn spawn() {
+    let state = Shared::<State>::new(Mutex::new(State {}));
+    let other = std::sync::Arc::new(Mutex::new(0));
+    let direct = Arc::<Mutex<i32>>::default();
+    let cb = move || { state.lock(); other.lock(); direct.lock(); };
+}
+"#;
+
+        let report = lint_source_ast(source, "test.rs").expect("parse failed");
+
+        let alias = report
+            .errors
+            .iter()
+            .find(|e| e.rule == "WASM-SS-006" && e.line == 2)
+            .expect("alias declaration flagged");
+        assert!(alias.message.contains("wraps Arc"));
+
+        let rules_by_line: Vec<(&str, usize)> = report
+            .errors
+            .iter()
+            .filter(|e| e.severity == LintSeverity::Error)
+            .map(|e| (e.rule.as_str(), e.line))
+            .collect();
+        assert_eq!(
+            rules_by_line,
+            vec![("WASM-SS-006", 5), ("WASM-SS-001", 6), ("WASM-SS-001", 7)]
+        );
+    }
+
+    /// Test Arc-returning function and `.to_arc()` method chain
+    #[test]
+    fn test_ast_detect_arc_returning_function_and_method_chain() {
+        let source = r#"
+fn make_state() -> Arc<Mutex<State>> {
+    Arc::new(Mutex::new(State {}))
+}
+
+fn spawn() {
+    let state = make_state();
+    let shared = value.to_arc();
+    let cb = move || { state.lock(); shared.lock(); };
+}
+"#;
+
+        let report = lint_source_ast(source, "test.rs").expect("parse failed");
+
+        assert!(report
+            .errors
+            .iter()
+            .any(|e| e.rule == "WASM-SS-007" && e.line == 2 && e.message.contains("returns Arc")));
+        assert!(report
+            .errors
+            .iter()
+            .any(|e| e.rule == "WASM-SS-007" && e.line == 7));
+        assert!(report
+            .errors
+            .iter()
+            .any(|e| e.rule == "WASM-SS-008" && e.line == 8 && e.message.contains("returns Arc")));
+    }
+
     /// Test Arc::from_raw unsafe laundering
     #[test]
     fn test_ast_detect_arc_from_raw() {
@@ -1135,21 +1274,21 @@ type Second = Rc<i32>;
     fn test_is_rc_type_negative() {
         // Test with non-Rc types
         let non_rc: syn::Type = syn::parse_str("String").expect("parse type");
-        assert!(!AstStateSyncVisitor::is_rc_type(&non_rc));
+        assert!(AstStateSyncVisitor::rc_type_name(&non_rc).is_none());
 
         let vec_type: syn::Type = syn::parse_str("Vec<i32>").expect("parse type");
-        assert!(!AstStateSyncVisitor::is_rc_type(&vec_type));
+        assert!(AstStateSyncVisitor::rc_type_name(&vec_type).is_none());
 
         // Reference type (not path)
         let ref_type: syn::Type = syn::parse_str("&str").expect("parse type");
-        assert!(!AstStateSyncVisitor::is_rc_type(&ref_type));
+        assert!(AstStateSyncVisitor::rc_type_name(&ref_type).is_none());
     }
 
     /// Test is_rc_type for Arc
     #[test]
     fn test_is_rc_type_arc() {
         let arc_type: syn::Type = syn::parse_str("Arc<Mutex<i32>>").expect("parse type");
-        assert!(AstStateSyncVisitor::is_rc_type(&arc_type));
+        assert!(AstStateSyncVisitor::rc_type_name(&arc_type).is_some());
     }
 
     /// Test function_creates_closure helper
@@ -1449,11 +1588,11 @@ fn make_vec() -> Vec<Rc<i32>> {
     fn test_is_rc_type_empty_path() {
         // A tuple type is not a path type
         let tuple_type: syn::Type = syn::parse_str("(i32, i32)").expect("parse type");
-        assert!(!AstStateSyncVisitor::is_rc_type(&tuple_type));
+        assert!(AstStateSyncVisitor::rc_type_name(&tuple_type).is_none());
 
         // A slice type
         let slice_type: syn::Type = syn::parse_str("[u8]").expect("parse type");
-        assert!(!AstStateSyncVisitor::is_rc_type(&slice_type));
+        assert!(AstStateSyncVisitor::rc_type_name(&slice_type).is_none());
     }
 
     /// Test is_rc_alias with empty path
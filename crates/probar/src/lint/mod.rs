@@ -6,7 +6,7 @@
 //!
 //! | Rule ID | Description |
 //! |---------|-------------|
-//! | WASM-SS-001 | Local `Rc::new()` in method with closure |
+//! | WASM-SS-001 | Local `Rc::new()` / `Rc::clone(&local)` in method with closure |
 //! | WASM-SS-002 | Potential state desync (self.field and local_clone both exist) |
 //! | WASM-SS-003 | Closure captures local instead of self field |
 //! | WASM-SS-004 | Duplicate state fields (RefCell + non-RefCell) |
@@ -14,6 +14,10 @@
 //! | WASM-SS-006 | Type alias for Rc (including turbofish `Alias::<T>::new()`) |
 //! | WASM-SS-007 | Function returning Rc captured in closure |
 //! | WASM-SS-008 | Method chain returning Rc (`.to_rc()`, etc.) |
+//! | WASM-SS-009 | Rc reconstructed from a raw pointer (`Rc::from_raw`) |
+//!
+//! `Arc` is a shared handle just like `Rc` (multithreaded WASM builds use it
+//! with `Mutex`), so every rule above applies to `Arc` under the same ID.
 //!
 //! ## Panic Path Rules (PROBAR-WASM-006)
 //!
@@ -26,6 +30,7 @@
 //! | WASM-PANIC-005 | `todo!()` macro | Error |
 //! | WASM-PANIC-006 | `unimplemented!()` macro | Error |
 //! | WASM-PANIC-007 | Index access without bounds check | Warning |
+//! | WASM-PANIC-008 | Unused `// probar-allow:` comment | Warning |
 //!
 //! ## AST vs Text-Based Analysis
 //!
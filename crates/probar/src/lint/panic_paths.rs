@@ -19,6 +19,18 @@
 //! | WASM-PANIC-005 | `todo!()` macro | Error |
 //! | WASM-PANIC-006 | `unimplemented!()` macro | Error |
 //! | WASM-PANIC-007 | Index access without bounds check | Warning |
+//! | WASM-PANIC-008 | Unused `probar-allow` comment | Warning |
+//!
+//! ## Suppression
+//!
+//! A finding that is genuinely infallible can be allowed with a trailing
+//! comment naming its rule on the same line. Suppressed findings stay in the
+//! report at `Info` severity so they can be counted separately; an allow
+//! comment that suppresses nothing is reported as WASM-PANIC-008.
+//!
+//! ```rust,ignore
+//! let re = Regex::new("^[a-z]+$").unwrap(); // probar-allow: WASM-PANIC-001
+//! ```
 //!
 //! ## Example
 //!
@@ -40,6 +52,44 @@ const PANIC_METHODS: &[&str] = &["unwrap", "expect"];
 /// Macros that always panic
 const PANIC_MACROS: &[&str] = &["panic", "unreachable", "todo", "unimplemented"];
 
+/// Trailing comment marker that suppresses findings on its line
+const ALLOW_MARKER: &str = "// probar-allow:";
+
+/// A `probar-allow` entry for one rule on one line
+#[derive(Debug)]
+struct AllowComment {
+    /// Line the comment is on (1-indexed)
+    line: usize,
+    /// Column of the comment marker (1-indexed)
+    column: usize,
+    /// Rule being allowed (e.g., "WASM-PANIC-001")
+    rule: String,
+    /// Whether this entry suppressed at least one finding
+    used: bool,
+}
+
+/// Collect `// probar-allow: RULE[, RULE...]` comments from source
+fn parse_allow_comments(source: &str) -> Vec<AllowComment> {
+    let mut allows = Vec::new();
+    for (idx, line) in source.lines().enumerate() {
+        let Some(pos) = line.find(ALLOW_MARKER) else {
+            continue;
+        };
+        let rules = line[pos + ALLOW_MARKER.len()..]
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|token| token.starts_with("WASM-PANIC-"));
+        for rule in rules {
+            allows.push(AllowComment {
+                line: idx + 1,
+                column: pos + 1,
+                rule: rule.to_string(),
+                used: false,
+            });
+        }
+    }
+    allows
+}
+
 /// AST visitor for detecting panic paths
 #[derive(Debug)]
 pub struct PanicPathVisitor {
@@ -53,21 +103,57 @@ pub struct PanicPathVisitor {
     in_test_module: bool,
     /// Whether we're inside an unsafe block
     in_unsafe_block: bool,
+    /// `probar-allow` comments found in the source
+    allows: Vec<AllowComment>,
 }
 
 impl PanicPathVisitor {
     /// Create a new panic path visitor
     #[must_use]
     pub fn new(file: String, source: String) -> Self {
+        let allows = parse_allow_comments(&source);
         Self {
             file,
             errors: Vec::new(),
             source,
             in_test_module: false,
             in_unsafe_block: false,
+            allows,
         }
     }
 
+    /// Record a finding, downgrading it to `Info` if a `probar-allow` covers it
+    fn push_finding(&mut self, mut error: LintError) {
+        let allow = self
+            .allows
+            .iter_mut()
+            .find(|a| a.line == error.line && a.rule == error.rule);
+        if let Some(allow) = allow {
+            allow.used = true;
+            error.severity = LintSeverity::Info;
+            error.message = format!("{} (suppressed by `probar-allow`)", error.message);
+            error.suggestion = None;
+        }
+        self.errors.push(error);
+    }
+
+    /// Warnings for allow comments that did not suppress any finding
+    fn unused_allow_errors(&self) -> Vec<LintError> {
+        self.allows
+            .iter()
+            .filter(|a| !a.used)
+            .map(|a| LintError {
+                rule: "WASM-PANIC-008".to_string(),
+                message: format!("`probar-allow: {}` does not suppress any finding", a.rule),
+                file: self.file.clone(),
+                line: a.line,
+                column: a.column,
+                severity: LintSeverity::Warning,
+                suggestion: Some("Remove the stale allow comment".to_string()),
+            })
+            .collect()
+    }
+
     /// Get the line number for a span
     fn span_to_line(&self, span: proc_macro2::Span) -> usize {
         span.start().line
@@ -146,17 +232,20 @@ impl PanicPathVisitor {
         }
     }
 
-    /// Convert to report
+    /// Convert to report, adding WASM-PANIC-008 for unused allow comments
     #[must_use]
     pub fn into_report(self, lines_analyzed: usize) -> StateSyncReport {
+        let unused_allows = self.unused_allow_errors();
+        let mut errors = self.errors;
+        errors.extend(unused_allows);
         StateSyncReport {
-            errors: self.errors,
+            errors,
             files_analyzed: 1,
             lines_analyzed,
         }
     }
 
-    /// Get errors
+    /// Get errors (excluding unused allow warnings, added by `into_report`)
     #[must_use]
     pub fn errors(&self) -> &[LintError] {
         &self.errors
@@ -219,7 +308,7 @@ impl<'ast> Visit<'ast> for PanicPathVisitor {
                     _ => "WASM-PANIC-000",
                 };
 
-                self.errors.push(LintError {
+                self.push_finding(LintError {
                     rule: rule.to_string(),
                     message: format!(
                         "`{method_name}()` can panic, which terminates WASM execution"
@@ -272,7 +361,7 @@ impl<'ast> Visit<'ast> for PanicPathVisitor {
                 _ => "WASM-PANIC-000",
             };
 
-            self.errors.push(LintError {
+            self.push_finding(LintError {
                 rule: rule.to_string(),
                 message: format!("`{macro_name}!()` panics, which terminates WASM execution"),
                 file: self.file.clone(),
@@ -297,7 +386,7 @@ impl<'ast> Visit<'ast> for PanicPathVisitor {
         let line = self.span_to_line(node.bracket_token.span.open());
         let column = self.span_to_column(node.bracket_token.span.open());
 
-        self.errors.push(LintError {
+        self.push_finding(LintError {
             rule: "WASM-PANIC-007".to_string(),
             message: "Direct indexing can panic on out-of-bounds access".to_string(),
             file: self.file.clone(),
@@ -350,6 +439,10 @@ pub struct PanicPathSummary {
     pub unimplemented_count: usize,
     /// Total index operations
     pub index_count: usize,
+    /// Findings suppressed by `probar-allow` comments (not in `total()`)
+    pub suppressed_count: usize,
+    /// `probar-allow` comments that suppressed nothing
+    pub unused_allow_count: usize,
 }
 
 impl PanicPathSummary {
@@ -359,6 +452,10 @@ impl PanicPathSummary {
         let mut summary = Self::default();
 
         for error in &report.errors {
+            if error.severity == LintSeverity::Info && error.rule.starts_with("WASM-PANIC-") {
+                summary.suppressed_count += 1;
+                continue;
+            }
             match error.rule.as_str() {
                 "WASM-PANIC-001" => summary.unwrap_count += 1,
                 "WASM-PANIC-002" => summary.expect_count += 1,
@@ -367,6 +464,7 @@ impl PanicPathSummary {
                 "WASM-PANIC-005" => summary.todo_count += 1,
                 "WASM-PANIC-006" => summary.unimplemented_count += 1,
                 "WASM-PANIC-007" => summary.index_count += 1,
+                "WASM-PANIC-008" => summary.unused_allow_count += 1,
                 _ => {}
             }
         }
@@ -567,4 +665,79 @@ mod tests {
             report.errors
         );
     }
+
+    #[test]
+    fn test_allow_comment_suppresses_unwrap() {
+        let source = r#"
+            fn example() {
+                let re = parse("^[a-z]+$").unwrap(); // probar-allow: WASM-PANIC-001
+                let v = other.unwrap();
+            }
+        "#;
+
+        let report = lint_panic_paths(source, "test.rs").expect("parse failed");
+        let suppressed = report.errors.iter().find(|e| e.line == 3).unwrap();
+        assert_eq!(suppressed.rule, "WASM-PANIC-001");
+        assert_eq!(suppressed.severity, LintSeverity::Info);
+        assert!(suppressed.message.contains("suppressed"));
+
+        let summary = PanicPathSummary::from_report(&report);
+        assert_eq!(summary.unwrap_count, 1);
+        assert_eq!(summary.suppressed_count, 1);
+        assert_eq!(summary.unused_allow_count, 0);
+        assert_eq!(summary.error_count(), 1);
+    }
+
+    #[test]
+    fn test_unused_allow_comment_warns() {
+        let source = r#"
+            fn example() -> i32 {
+                let x = 1; // probar-allow: WASM-PANIC-001
+                x
+            }
+        "#;
+
+        let report = lint_panic_paths(source, "test.rs").expect("parse failed");
+        assert_eq!(report.errors.len(), 1);
+        let unused = &report.errors[0];
+        assert_eq!(unused.rule, "WASM-PANIC-008");
+        assert_eq!(unused.severity, LintSeverity::Warning);
+        assert_eq!(unused.line, 3);
+        assert!(unused.message.contains("WASM-PANIC-001"));
+
+        let summary = PanicPathSummary::from_report(&report);
+        assert_eq!(summary.unused_allow_count, 1);
+        assert_eq!(summary.total(), 0);
+    }
+
+    #[test]
+    fn test_allow_comment_does_not_cover_nearby_panic() {
+        let source = r#"
+            fn example(x: Option<i32>) -> i32 {
+                let y = x.unwrap(); // probar-allow: WASM-PANIC-001
+                if y < 0 {
+                    panic!("negative"); // probar-allow: WASM-PANIC-001
+                }
+                y
+            }
+        "#;
+
+        let report = lint_panic_paths(source, "test.rs").expect("parse failed");
+        let panic = report
+            .errors
+            .iter()
+            .find(|e| e.rule == "WASM-PANIC-003")
+            .unwrap();
+        assert_eq!(panic.line, 5);
+        assert_eq!(panic.severity, LintSeverity::Error);
+        assert!(report
+            .errors
+            .iter()
+            .any(|e| e.rule == "WASM-PANIC-008" && e.line == 5));
+
+        let summary = PanicPathSummary::from_report(&report);
+        assert_eq!(summary.suppressed_count, 1);
+        assert_eq!(summary.panic_count, 1);
+        assert_eq!(summary.unused_allow_count, 1);
+    }
 }
@@ -131,6 +131,17 @@ impl StateSyncReport {
     }
 }
 
+/// Shared-ownership handle types checked by every state sync rule
+pub(super) const SHARED_HANDLE_TYPES: &[&str] = &["Rc", "Arc"];
+
+/// Return the handle type constructed via `Handle::new(` on this line, if any
+fn shared_handle_in(line: &str) -> Option<&'static str> {
+    SHARED_HANDLE_TYPES
+        .iter()
+        .copied()
+        .find(|h| line.contains(&format!("{h}::new(")))
+}
+
 /// State synchronization linter
 ///
 /// Detects anti-patterns that cause state desync in WASM closures.
@@ -144,6 +155,8 @@ impl StateSyncReport {
 /// | WASM-SS-005 | Missing self.*.clone() before closure | Warning |
 /// | WASM-SS-006 | Type alias for Rc<RefCell<T>> used with ::new() | Warning |
 /// | WASM-SS-007 | Function returning Rc<RefCell<T>> used in closure context | Warning |
+///
+/// `Arc` is checked the same way as `Rc` and reported under the same rules.
 #[derive(Debug)]
 pub struct StateSyncLinter {
     /// Track local Rc variables per function
@@ -274,8 +287,9 @@ impl StateSyncLinter {
                 fn_has_closure = true;
             }
 
-            // WASM-SS-001: Local Rc::new() in method with closure
+            // WASM-SS-001: Local Rc::new() / Arc::new() in method with closure
             if let Some(var_name) = self.detect_local_rc_new(line) {
+                let handle = shared_handle_in(line).unwrap_or("Rc");
                 let fn_name = current_fn
                     .clone()
                     .unwrap_or_else(|| "<unknown>".to_string());
@@ -292,7 +306,7 @@ impl StateSyncLinter {
                     report.errors.push(LintError {
                         rule: "WASM-SS-001".to_string(),
                         message: format!(
-                            "Local `{var_name}` creates new Rc - if captured by closure, \
+                            "Local `{var_name}` creates new {handle} - if captured by closure, \
                              it will be disconnected from self"
                         ),
                         file: self.current_file.clone(),
@@ -316,7 +330,7 @@ impl StateSyncLinter {
                     report.errors.push(LintError {
                         rule: "WASM-SS-006".to_string(),
                         message: format!(
-                            "Type alias `{alias_name}::new()` creates local Rc - \
+                            "Type alias `{alias_name}::new()` creates local Rc/Arc - \
                              may cause state desync if captured in closure"
                         ),
                         file: self.current_file.clone(),
@@ -340,7 +354,7 @@ impl StateSyncLinter {
                     report.errors.push(LintError {
                         rule: "WASM-SS-007".to_string(),
                         message: format!(
-                            "Function `{fn_name_called}()` returns Rc - \
+                            "Function `{fn_name_called}()` returns Rc/Arc - \
                              local assignment may cause state desync in closure"
                         ),
                         file: self.current_file.clone(),
@@ -375,14 +389,17 @@ impl StateSyncLinter {
             let line_num = line_num + 1;
             let trimmed = line.trim();
 
-            // Detect type aliases: type Foo = Rc<RefCell<...>>
-            if trimmed.starts_with("type ") && trimmed.contains("Rc<") {
+            // Detect type aliases: type Foo = Rc<RefCell<...>> or Arc<Mutex<...>>
+            let alias_handle = SHARED_HANDLE_TYPES
+                .iter()
+                .find(|h| trimmed.contains(&format!("{h}<")));
+            if let (true, Some(handle)) = (trimmed.starts_with("type "), alias_handle) {
                 if let Some(alias_name) = self.extract_type_alias_name(trimmed) {
                     self.rc_type_aliases.insert(alias_name.clone());
                     report.errors.push(LintError {
                         rule: "WASM-SS-006".to_string(),
                         message: format!(
-                            "Type alias `{alias_name}` wraps Rc - usage with ::new() may cause state desync"
+                            "Type alias `{alias_name}` wraps {handle} - usage with ::new() may cause state desync"
                         ),
                         file: self.current_file.clone(),
                         line: line_num,
@@ -393,14 +410,17 @@ impl StateSyncLinter {
                 }
             }
 
-            // Detect functions returning Rc: fn foo() -> Rc<...>
-            if trimmed.contains("fn ") && trimmed.contains("-> Rc<") {
+            // Detect functions returning Rc: fn foo() -> Rc<...> or -> Arc<...>
+            let return_handle = SHARED_HANDLE_TYPES
+                .iter()
+                .find(|h| trimmed.contains(&format!("-> {h}<")));
+            if let (true, Some(handle)) = (trimmed.contains("fn "), return_handle) {
                 if let Some(fn_name) = self.detect_function_start(trimmed) {
                     self.rc_returning_functions.insert(fn_name.clone());
                     report.errors.push(LintError {
                         rule: "WASM-SS-007".to_string(),
                         message: format!(
-                            "Function `{fn_name}` returns Rc - callers may create disconnected state"
+                            "Function `{fn_name}` returns {handle} - callers may create disconnected state"
                         ),
                         file: self.current_file.clone(),
                         line: line_num,
@@ -556,14 +576,14 @@ impl StateSyncLinter {
         result
     }
 
-    /// Detect local Rc::new() pattern
+    /// Detect local Rc::new() / Arc::new() pattern
     fn detect_local_rc_new(&self, line: &str) -> Option<String> {
         let trimmed = line.trim();
 
         // Pattern: let var_name = Rc::new(RefCell::new(
-        // Pattern: let var_name = Rc::new(
+        // Pattern: let var_name = Arc::new(Mutex::new(
         if let Some(after_let) = trimmed.strip_prefix("let ") {
-            if trimmed.contains("Rc::new(") {
+            if shared_handle_in(trimmed).is_some() {
                 // Handle: let var_name = or let mut var_name =
                 let after_mut = after_let.strip_prefix("mut ").unwrap_or(after_let);
 
@@ -616,7 +636,10 @@ impl StateSyncLinter {
 
         // Check if we have a local Rc that's not from self.*.clone()
         for line in context {
-            if line.contains("let ") && line.contains("Rc::new(") && !line.contains(".clone()") {
+            if line.contains("let ")
+                && shared_handle_in(line).is_some()
+                && !line.contains(".clone()")
+            {
                 // Already reported by WASM-SS-001, skip
                 continue;
             }
@@ -733,6 +756,33 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn test_text_based_detects_arc_patterns() {
+        let mut linter = StateSyncLinter::new();
+        let source = r#"
+type SharedState = Arc<Mutex<State>>;
+fn make_state() -> Arc<Mutex<State>> {
+}
+fn spawn_worker() {
+    let state = Arc::new(Mutex::new(State::Idle));
+}
+"#;
+        let report = linter.lint_source_text_based(source).unwrap();
+
+        let rules: Vec<(&str, usize)> = report
+            .errors
+            .iter()
+            .map(|e| (e.rule.as_str(), e.line))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![("WASM-SS-006", 2), ("WASM-SS-007", 3), ("WASM-SS-001", 6)]
+        );
+        assert!(report.errors[0].message.contains("wraps Arc"));
+        assert!(report.errors[1].message.contains("returns Arc"));
+        assert!(report.errors[2].message.contains("creates new Arc"));
+    }
+
     #[test]
     fn test_detect_function_start() {
         let linter = StateSyncLinter::new();
@@ -1,4 +1,5 @@
-//! LLM output assertions: structural validation, content checks, and latency budgets.
+//! LLM output assertions: structural validation, content checks, JSON-schema
+//! validation of structured outputs, and latency budgets.
 
 use super::client::{ChatResponse, TimedChatResponse};
 use std::time::Duration;
@@ -34,6 +35,17 @@ pub enum LlmAssertionError {
     /// Regex compilation failed.
     #[error("Invalid regex pattern: {0}")]
     InvalidRegex(String),
+    /// Content is not valid JSON.
+    #[error("Content is not JSON: {0}")]
+    NotJson(String),
+    /// JSON content does not match the schema.
+    #[error("{path}: {message}")]
+    SchemaMismatch {
+        /// JSON path of the failing value (e.g. `$.items[2].price`).
+        path: String,
+        /// What was expected at that path.
+        message: String,
+    },
 }
 
 /// Collection of assertions to run against LLM responses.
@@ -220,6 +232,29 @@ impl AssertionCheck for TokenCountCheck {
     }
 }
 
+#[derive(Debug)]
+struct JsonSchemaCheck {
+    schema: serde_json::Value,
+}
+
+impl AssertionCheck for JsonSchemaCheck {
+    fn check(&self, timed: &TimedChatResponse) -> LlmAssertionResult {
+        let content = first_content(&timed.response);
+        match validate_json_schema(&content, &self.schema) {
+            Ok(()) => LlmAssertionResult {
+                name: "matches_json_schema".to_string(),
+                passed: true,
+                detail: None,
+            },
+            Err(e) => LlmAssertionResult {
+                name: "matches_json_schema".to_string(),
+                passed: false,
+                detail: Some(e.to_string()),
+            },
+        }
+    }
+}
+
 impl LlmAssertion {
     /// Create a new empty assertion builder.
     pub fn new() -> Self {
@@ -258,6 +293,14 @@ impl LlmAssertion {
         self
     }
 
+    /// Assert the first choice's content is JSON matching the given JSON Schema.
+    ///
+    /// See [`validate_json_schema`] for the supported keywords.
+    pub fn assert_matches_json_schema(mut self, schema: serde_json::Value) -> Self {
+        self.checks.push(Box::new(JsonSchemaCheck { schema }));
+        self
+    }
+
     /// Assert total latency is under the given duration.
     pub fn assert_latency_under(mut self, budget: Duration) -> Self {
         self.checks.push(Box::new(LatencyCheck { budget }));
@@ -298,6 +341,184 @@ fn truncate(s: &str, max_len: usize) -> String {
     }
 }
 
+/// Parse `content` as JSON and validate it against a JSON Schema.
+///
+/// Supports the subset of JSON Schema used for function-calling and JSON-mode
+/// outputs: `type`, `enum`, `const`, `properties`, `required`,
+/// `additionalProperties`, `items`, `minItems`/`maxItems`,
+/// `minLength`/`maxLength`, and `minimum`/`maximum`. Unknown keywords are
+/// ignored. Reports the first failing path.
+///
+/// # Errors
+///
+/// Returns [`LlmAssertionError::NotJson`] if `content` does not parse, or
+/// [`LlmAssertionError::SchemaMismatch`] with the failing path otherwise.
+pub fn validate_json_schema(
+    content: &str,
+    schema: &serde_json::Value,
+) -> Result<(), LlmAssertionError> {
+    let value: serde_json::Value = serde_json::from_str(content.trim())
+        .map_err(|e| LlmAssertionError::NotJson(e.to_string()))?;
+    check_schema(&value, schema, "$")
+}
+
+fn check_schema(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    path: &str,
+) -> Result<(), LlmAssertionError> {
+    use serde_json::Value;
+
+    let mismatch = |message: String| LlmAssertionError::SchemaMismatch {
+        path: path.to_string(),
+        message,
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !types.is_empty() && !types.iter().any(|t| json_type_matches(value, t)) {
+            return Err(mismatch(format!(
+                "expected {}, got {}",
+                types.join(" or "),
+                json_type_name(value)
+            )));
+        }
+    }
+
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            return Err(mismatch(format!(
+                "expected one of {}",
+                Value::Array(allowed.clone())
+            )));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if value != expected {
+            return Err(mismatch(format!("expected {expected}")));
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(key) {
+                        return Err(LlmAssertionError::SchemaMismatch {
+                            path: format!("{path}.{key}"),
+                            message: "missing required property".to_string(),
+                        });
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (key, child) in map {
+                let child_path = format!("{path}.{key}");
+                match properties.and_then(|p| p.get(key)) {
+                    Some(child_schema) => check_schema(child, child_schema, &child_path)?,
+                    None => match schema.get("additionalProperties") {
+                        Some(Value::Bool(false)) => {
+                            return Err(LlmAssertionError::SchemaMismatch {
+                                path: child_path,
+                                message: "unexpected property".to_string(),
+                            });
+                        }
+                        Some(extra @ Value::Object(_)) => check_schema(child, extra, &child_path)?,
+                        _ => {}
+                    },
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if (items.len() as u64) < min {
+                    return Err(mismatch(format!(
+                        "expected at least {min} items, got {}",
+                        items.len()
+                    )));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                if items.len() as u64 > max {
+                    return Err(mismatch(format!(
+                        "expected at most {max} items, got {}",
+                        items.len()
+                    )));
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    check_schema(item, item_schema, &format!("{path}[{i}]"))?;
+                }
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                if len < min {
+                    return Err(mismatch(format!("expected length >= {min}, got {len}")));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+                if len > max {
+                    return Err(mismatch(format!("expected length <= {max}, got {len}")));
+                }
+            }
+        }
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or(0.0);
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                if n < min {
+                    return Err(mismatch(format!("expected >= {min}, got {n}")));
+                }
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                if n > max {
+                    return Err(mismatch(format!("expected <= {max}, got {n}")));
+                }
+            }
+        }
+        Value::Bool(_) | Value::Null => {}
+    }
+
+    Ok(())
+}
+
+fn json_type_matches(value: &serde_json::Value, expected: &str) -> bool {
+    use serde_json::Value;
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => match value {
+            Value::Number(n) => {
+                n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+            }
+            _ => false,
+        },
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    use serde_json::Value;
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
 /// Check determinism: given multiple responses to the same prompt (temp=0),
 /// verify they all produce the same output.
 pub fn assert_deterministic(responses: &[ChatResponse]) -> LlmAssertionResult {
@@ -363,6 +584,7 @@ mod tests {
             latency: Duration::from_millis(latency_ms),
             ttfb: Duration::from_millis(latency_ms / 2),
             brick_trace: None,
+            retries: 0,
         }
     }
 
@@ -578,6 +800,102 @@ mod tests {
         assert_eq!(first_content(&resp), "");
     }
 
+    fn order_schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "required": ["customer", "items"],
+            "properties": {
+                "customer": {"type": "string", "minLength": 1},
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["name", "price"],
+                        "properties": {
+                            "name": {"type": "string"},
+                            "price": {"type": "number", "minimum": 0}
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_json_schema_valid_object() {
+        let timed = make_timed(
+            r#"{"customer": "ada", "items": [{"name": "tea", "price": 3.5}, {"name": "cake", "price": 4}]}"#,
+            100,
+        );
+        let results = LlmAssertion::new()
+            .assert_matches_json_schema(order_schema())
+            .run(&timed);
+        assert!(results[0].passed, "{:?}", results[0].detail);
+    }
+
+    #[test]
+    fn test_json_schema_type_mismatch_reports_path() {
+        let content = r#"{"customer": "ada", "items": [
+            {"name": "tea", "price": 3.5},
+            {"name": "cake", "price": 4},
+            {"name": "jam", "price": "free"}
+        ]}"#;
+        let err = validate_json_schema(content, &order_schema()).unwrap_err();
+        match &err {
+            LlmAssertionError::SchemaMismatch { path, message } => {
+                assert_eq!(path, "$.items[2].price");
+                assert!(message.starts_with("expected number"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(err
+            .to_string()
+            .starts_with("$.items[2].price: expected number"));
+
+        let timed = make_timed(content, 100);
+        let results = LlmAssertion::new()
+            .assert_matches_json_schema(order_schema())
+            .run(&timed);
+        assert!(!results[0].passed);
+        assert!(results[0]
+            .detail
+            .as_ref()
+            .unwrap()
+            .contains("$.items[2].price"));
+    }
+
+    #[test]
+    fn test_json_schema_missing_required_field() {
+        let content = r#"{"items": []}"#;
+        let err = validate_json_schema(content, &order_schema()).unwrap_err();
+        match err {
+            LlmAssertionError::SchemaMismatch { path, message } => {
+                assert_eq!(path, "$.customer");
+                assert_eq!(message, "missing required property");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_json_schema_not_json() {
+        let err = validate_json_schema("Sure! Here is the order.", &order_schema()).unwrap_err();
+        assert!(matches!(err, LlmAssertionError::NotJson(_)));
+    }
+
+    #[test]
+    fn test_json_schema_additional_properties_and_enum() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {"unit": {"enum": ["c", "f"]}},
+            "additionalProperties": false
+        });
+        assert!(validate_json_schema(r#"{"unit": "c"}"#, &schema).is_ok());
+        assert!(validate_json_schema(r#"{"unit": "k"}"#, &schema).is_err());
+        let err = validate_json_schema(r#"{"unit": "c", "x": 1}"#, &schema).unwrap_err();
+        assert_eq!(err.to_string(), "$.x: unexpected property");
+    }
+
     #[test]
     fn test_invalid_regex_pattern() {
         let timed = make_timed("hello", 100);
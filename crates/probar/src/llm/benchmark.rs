@@ -340,6 +340,11 @@ mod tests {
             tokens_per_sec: tps,
             avg_tok_per_req: 20.0,
             itl_p50_ms: 5.0,
+            itl_mean_ms: 5.0,
+            itl_excluded_requests: 0,
+            first_try_successes: 0,
+            retried_successes: 0,
+            total_retries: 0,
             decode_tok_per_sec: 200.0,
             prefill_tok_per_sec: 0.0,
             timestamp: "2026-03-03T00:00:00Z".to_string(),
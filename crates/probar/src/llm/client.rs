@@ -48,6 +48,23 @@ pub struct StreamedChatResponse {
     pub usage: Option<Usage>,
     /// Why generation stopped (e.g., "stop", "length").
     pub finish_reason: Option<String>,
+    /// Retries (429/503) before the request succeeded; 0 on first-try success.
+    pub retries: u32,
+}
+
+/// One parsed SSE event from [`LlmClient::chat_stream`], stamped on arrival.
+#[derive(Debug, Clone, Default)]
+pub struct ChatStreamChunk {
+    /// Content delta carried by this event (empty for role-only/final events).
+    pub delta: String,
+    /// Arrival time relative to request start.
+    pub elapsed: Duration,
+    /// True for the first event with non-empty content; its `elapsed` is the TTFT.
+    pub is_first_token: bool,
+    /// Finish reason (present on final chunk).
+    pub finish_reason: Option<String>,
+    /// Token usage (only present on final chunk for some backends).
+    pub usage: Option<Usage>,
 }
 
 /// Chat message role.
@@ -167,6 +184,8 @@ pub struct TimedChatResponse {
     pub ttfb: Duration,
     /// Brick trace data extracted from response (when trace_level was set).
     pub brick_trace: Option<BrickTrace>,
+    /// Retries (429/503) before the request succeeded; 0 on first-try success.
+    pub retries: u32,
 }
 
 /// Errors from the LLM client.
@@ -184,6 +203,30 @@ pub enum LlmClientError {
         /// Response body.
         body: String,
     },
+    /// Server rejected the request with 429 Too Many Requests.
+    #[error("Rate limited (429): {body}")]
+    RateLimited {
+        /// Delay requested via the `Retry-After` header, if any.
+        retry_after: Option<Duration>,
+        /// Response body.
+        body: String,
+    },
+    /// Server is temporarily unavailable (503 Service Unavailable).
+    #[error("Server busy (503): {body}")]
+    ServerBusy {
+        /// Delay requested via the `Retry-After` header, if any.
+        retry_after: Option<Duration>,
+        /// Response body.
+        body: String,
+    },
+    /// A retryable error persisted through every retry the policy allowed.
+    #[error("{source} (after {retries} retries)")]
+    RetriesExhausted {
+        /// Retries performed before giving up.
+        retries: u32,
+        /// Error returned by the final attempt.
+        source: Box<LlmClientError>,
+    },
     /// Health check failed.
     #[error("Health check failed: {0}")]
     HealthCheckFailed(String),
@@ -192,6 +235,144 @@ pub enum LlmClientError {
     HealthCheckTimeout(Duration),
 }
 
+/// How an [`LlmClientError`] should be treated by retry logic.
+#[cfg(feature = "llm")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LlmErrorClass {
+    /// 429: retry after the server-provided delay or backoff.
+    RateLimited,
+    /// 503: transient overload, retry after delay or backoff.
+    ServerBusy,
+    /// Anything else: retrying will not help.
+    Fatal,
+}
+
+#[cfg(feature = "llm")]
+impl LlmClientError {
+    /// Classify this error for retry decisions.
+    pub fn class(&self) -> LlmErrorClass {
+        match self {
+            Self::RateLimited { .. } => LlmErrorClass::RateLimited,
+            Self::ServerBusy { .. } => LlmErrorClass::ServerBusy,
+            Self::RetriesExhausted { source, .. } => source.class(),
+            _ => LlmErrorClass::Fatal,
+        }
+    }
+
+    /// Retries performed before this error was returned (0 if none).
+    pub fn retries(&self) -> u32 {
+        match self {
+            Self::RetriesExhausted { retries, .. } => *retries,
+            _ => 0,
+        }
+    }
+
+    /// Delay requested by the server via `Retry-After`, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited { retry_after, .. } | Self::ServerBusy { retry_after, .. } => {
+                *retry_after
+            }
+            Self::RetriesExhausted { source, .. } => source.retry_after(),
+            _ => None,
+        }
+    }
+
+    /// Build an error from a non-success HTTP response, consuming its body.
+    async fn from_response(resp: reqwest::Response) -> Self {
+        let status = resp.status().as_u16();
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = resp.text().await.unwrap_or_default();
+        match status {
+            429 => Self::RateLimited { retry_after, body },
+            503 => Self::ServerBusy { retry_after, body },
+            _ => Self::ApiError { status, body },
+        }
+    }
+}
+
+/// Longest `Retry-After` delay accepted from a server.
+#[cfg(feature = "llm")]
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(3600);
+
+/// Parse a `Retry-After` header given in (possibly fractional) seconds.
+///
+/// HTTP-date values are not supported and yield `None`, falling back to backoff.
+/// Delays too large to represent are clamped to [`MAX_RETRY_AFTER`].
+#[cfg(feature = "llm")]
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let secs = value.trim().parse::<f64>().ok()?;
+    if secs.is_nan() || secs < 0.0 {
+        return None;
+    }
+    Some(Duration::try_from_secs_f64(secs).map_or(MAX_RETRY_AFTER, |d| d.min(MAX_RETRY_AFTER)))
+}
+
+/// Retry policy for rate-limited (429) and busy (503) responses.
+///
+/// Honors `Retry-After` when the server sends it, otherwise backs off
+/// exponentially from `initial_backoff`. Delays are capped at `max_backoff`.
+/// The default policy performs no retries.
+#[cfg(feature = "llm")]
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum retries after the first attempt (0 = no retry).
+    pub max_retries: u32,
+    /// Delay before the first retry when no `Retry-After` is given.
+    pub initial_backoff: Duration,
+    /// Upper bound on any single retry delay.
+    pub max_backoff: Duration,
+}
+
+#[cfg(feature = "llm")]
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+#[cfg(feature = "llm")]
+impl RetryPolicy {
+    /// Create a policy retrying up to `max_retries` times with default backoff.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    /// Set the initial backoff delay.
+    #[must_use]
+    pub fn with_initial_backoff(mut self, delay: Duration) -> Self {
+        self.initial_backoff = delay;
+        self
+    }
+
+    /// Set the maximum backoff delay.
+    #[must_use]
+    pub fn with_max_backoff(mut self, delay: Duration) -> Self {
+        self.max_backoff = delay;
+        self
+    }
+
+    /// Delay before retry number `retry` (0-based), preferring `retry_after`.
+    pub fn delay_for(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        let delay = retry_after.unwrap_or_else(|| {
+            self.initial_backoff
+                .saturating_mul(2u32.saturating_pow(retry))
+        });
+        delay.min(self.max_backoff)
+    }
+}
+
 /// OpenAI-compatible HTTP client for LLM inference.
 #[cfg(feature = "llm")]
 #[derive(Debug, Clone)]
@@ -199,6 +380,7 @@ pub struct LlmClient {
     base_url: String,
     client: reqwest::Client,
     model: String,
+    retry_policy: RetryPolicy,
 }
 
 #[cfg(feature = "llm")]
@@ -217,6 +399,7 @@ impl LlmClient {
             base_url: base_url.into().trim_end_matches('/').to_string(),
             client,
             model: model.into(),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
@@ -230,6 +413,7 @@ impl LlmClient {
             base_url: base_url.into().trim_end_matches('/').to_string(),
             client,
             model: model.into(),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
@@ -243,77 +427,77 @@ impl LlmClient {
         &self.model
     }
 
-    /// Send a chat completion request and return the response with timing.
-    pub async fn chat_completion(
-        &self,
-        messages: Vec<ChatMessage>,
-        temperature: Option<f64>,
-        max_tokens: Option<u32>,
-    ) -> Result<TimedChatResponse, LlmClientError> {
-        let request = ChatRequest {
-            model: self.model.clone(),
-            messages,
-            temperature,
-            max_tokens,
-            stream: Some(false),
-        };
+    /// Retry 429/503 responses according to `policy`.
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
 
-        let url = format!("{}/v1/chat/completions", self.base_url);
-        let start = Instant::now();
+    /// Returns the retry policy.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
 
-        let resp = self.client.post(&url).json(&request).send().await?;
-        let ttfb = start.elapsed();
+    /// POST a chat request, retrying retryable failures per the retry policy.
+    ///
+    /// Returns the successful response, the start time of the attempt that
+    /// succeeded (so latency excludes backoff waits), and the retry count.
+    async fn post_with_retry(
+        &self,
+        request: &ChatRequest,
+        trace_level: Option<&str>,
+    ) -> Result<(reqwest::Response, Instant, u32), LlmClientError> {
+        let url = format!("{}/v1/chat/completions", self.base_url);
+        let mut retries = 0;
+        loop {
+            let mut builder = self.client.post(&url);
+            if let Some(level) = trace_level {
+                builder = builder.header("X-Trace-Level", level);
+            }
+            let start = Instant::now();
+            let resp = builder.json(request).send().await?;
+            if resp.status().is_success() {
+                return Ok((resp, start, retries));
+            }
 
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(LlmClientError::ApiError {
-                status: status.as_u16(),
-                body,
-            });
+            let err = LlmClientError::from_response(resp).await;
+            if err.class() == LlmErrorClass::Fatal || retries >= self.retry_policy.max_retries {
+                return Err(if retries == 0 {
+                    err
+                } else {
+                    LlmClientError::RetriesExhausted {
+                        retries,
+                        source: Box::new(err),
+                    }
+                });
+            }
+            tokio::time::sleep(self.retry_policy.delay_for(retries, err.retry_after())).await;
+            retries += 1;
         }
-
-        let response: ChatResponse = resp.json().await?;
-        let latency = start.elapsed();
-        let brick_trace = response.brick_trace.clone();
-
-        Ok(TimedChatResponse {
-            response,
-            latency,
-            ttfb,
-            brick_trace,
-        })
     }
 
-    /// Send a raw `ChatRequest` and return the timed response.
-    pub async fn send(&self, request: &ChatRequest) -> Result<TimedChatResponse, LlmClientError> {
-        let url = format!("{}/v1/chat/completions", self.base_url);
-        let start = Instant::now();
-
-        // Use the client's model name if the request's model is empty
-        let actual_request;
-        let req = if request.model.is_empty() {
-            actual_request = ChatRequest {
+    /// Fill in the client's model name when the request leaves it empty.
+    fn with_model<'a>(&self, request: &'a ChatRequest) -> std::borrow::Cow<'a, ChatRequest> {
+        if request.model.is_empty() {
+            std::borrow::Cow::Owned(ChatRequest {
                 model: self.model.clone(),
                 ..request.clone()
-            };
-            &actual_request
+            })
         } else {
-            request
-        };
+            std::borrow::Cow::Borrowed(request)
+        }
+    }
 
-        let resp = self.client.post(&url).json(req).send().await?;
+    /// Send a non-streaming request and time it.
+    async fn send_timed(
+        &self,
+        request: &ChatRequest,
+        trace_level: Option<&str>,
+    ) -> Result<TimedChatResponse, LlmClientError> {
+        let (resp, start, retries) = self.post_with_retry(request, trace_level).await?;
         let ttfb = start.elapsed();
 
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(LlmClientError::ApiError {
-                status: status.as_u16(),
-                body,
-            });
-        }
-
         let response: ChatResponse = resp.json().await?;
         let latency = start.elapsed();
         let brick_trace = response.brick_trace.clone();
@@ -323,57 +507,41 @@ impl LlmClient {
             latency,
             ttfb,
             brick_trace,
+            retries,
         })
     }
 
+    /// Send a chat completion request and return the response with timing.
+    pub async fn chat_completion(
+        &self,
+        messages: Vec<ChatMessage>,
+        temperature: Option<f64>,
+        max_tokens: Option<u32>,
+    ) -> Result<TimedChatResponse, LlmClientError> {
+        let request = ChatRequest {
+            model: self.model.clone(),
+            messages,
+            temperature,
+            max_tokens,
+            stream: Some(false),
+        };
+        self.send_timed(&request, None).await
+    }
+
+    /// Send a raw `ChatRequest` and return the timed response.
+    pub async fn send(&self, request: &ChatRequest) -> Result<TimedChatResponse, LlmClientError> {
+        // Use the client's model name if the request's model is empty
+        self.send_timed(&self.with_model(request), None).await
+    }
+
     /// Send a raw `ChatRequest` with X-Trace-Level header.
     pub async fn send_with_trace(
         &self,
         request: &ChatRequest,
         trace_level: &str,
     ) -> Result<TimedChatResponse, LlmClientError> {
-        let url = format!("{}/v1/chat/completions", self.base_url);
-        let start = Instant::now();
-
-        let actual_request;
-        let req = if request.model.is_empty() {
-            actual_request = ChatRequest {
-                model: self.model.clone(),
-                ..request.clone()
-            };
-            &actual_request
-        } else {
-            request
-        };
-
-        let resp = self
-            .client
-            .post(&url)
-            .header("X-Trace-Level", trace_level)
-            .json(req)
-            .send()
-            .await?;
-        let ttfb = start.elapsed();
-
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(LlmClientError::ApiError {
-                status: status.as_u16(),
-                body,
-            });
-        }
-
-        let response: ChatResponse = resp.json().await?;
-        let latency = start.elapsed();
-        let brick_trace = response.brick_trace.clone();
-
-        Ok(TimedChatResponse {
-            response,
-            latency,
-            ttfb,
-            brick_trace,
-        })
+        self.send_timed(&self.with_model(request), Some(trace_level))
+            .await
     }
 
     /// Check if the server is reachable by hitting common health endpoints.
@@ -393,40 +561,37 @@ impl LlmClient {
         )))
     }
 
+    /// Send a streaming chat completion request and yield SSE events as they arrive.
+    ///
+    /// Sends `stream: true` and parses `text/event-stream` `data:` lines into
+    /// [`ChatStreamChunk`]s, each stamped with its arrival time. The first
+    /// chunk with non-empty content is flagged as the first token (TTFT).
+    /// The stream ends at `data: [DONE]` or when the server closes the body.
+    pub async fn chat_stream(
+        &self,
+        request: &ChatRequest,
+    ) -> Result<
+        impl futures::Stream<Item = Result<ChatStreamChunk, LlmClientError>> + Send + 'static,
+        LlmClientError,
+    > {
+        let (state, _retries) = self.open_stream(request).await?;
+        Ok(state.into_stream())
+    }
+
     /// Send a streaming chat completion request and collect per-token timestamps.
     ///
-    /// Sends `stream: true` and parses SSE `data: {...}` events. Records
-    /// the arrival time of each content-bearing chunk for TPOT computation.
+    /// Consumes the same chunk stream as [`chat_stream`](Self::chat_stream),
+    /// reassembling the full message and recording the arrival time of each
+    /// content-bearing chunk for TPOT computation.
     pub async fn chat_completion_stream(
         &self,
         request: &ChatRequest,
     ) -> Result<StreamedChatResponse, LlmClientError> {
-        let url = format!("{}/v1/chat/completions", self.base_url);
+        use futures::StreamExt;
 
-        // Force streaming on
-        let stream_request = ChatRequest {
-            model: if request.model.is_empty() {
-                self.model.clone()
-            } else {
-                request.model.clone()
-            },
-            messages: request.messages.clone(),
-            temperature: request.temperature,
-            max_tokens: request.max_tokens,
-            stream: Some(true),
-        };
-
-        let start = Instant::now();
-        let resp = self.client.post(&url).json(&stream_request).send().await?;
-
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(LlmClientError::ApiError {
-                status: status.as_u16(),
-                body,
-            });
-        }
+        let (state, retries) = self.open_stream(request).await?;
+        let start = state.start;
+        let mut stream = Box::pin(state.into_stream());
 
         let mut content = String::new();
         let mut token_timestamps = Vec::new();
@@ -434,54 +599,20 @@ impl LlmClient {
         let mut final_usage = None;
         let mut finish_reason = None;
 
-        // Read the response incrementally via chunk() for real per-token timestamps.
-        // Each chunk() call returns data as it arrives from the server, so timestamps
-        // reflect actual token delivery times rather than full-response download time.
-        let mut resp = resp;
-        let mut buffer = String::new();
-        let mut done = false;
-
-        while !done {
-            match resp.chunk().await? {
-                Some(chunk_bytes) => {
-                    buffer.push_str(&String::from_utf8_lossy(&chunk_bytes));
-                }
-                None => {
-                    done = true;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            if !chunk.delta.is_empty() {
+                if chunk.is_first_token {
+                    ttft = Some(chunk.elapsed);
                 }
+                token_timestamps.push(chunk.elapsed);
+                content.push_str(&chunk.delta);
             }
-
-            // Process complete lines from buffer
-            while let Some(newline_pos) = buffer.find('\n') {
-                let line: String = buffer[..newline_pos].trim().to_string();
-                buffer = buffer[newline_pos + 1..].to_string();
-
-                if line == "data: [DONE]" {
-                    done = true;
-                    break;
-                }
-                if let Some(json_str) = line.strip_prefix("data: ") {
-                    if let Ok(sse_chunk) = serde_json::from_str::<StreamChunk>(json_str) {
-                        if let Some(choice) = sse_chunk.choices.first() {
-                            if let Some(ref c) = choice.delta.content {
-                                if !c.is_empty() {
-                                    let now = start.elapsed();
-                                    if ttft.is_none() {
-                                        ttft = Some(now);
-                                    }
-                                    token_timestamps.push(now);
-                                    content.push_str(c);
-                                }
-                            }
-                            if choice.finish_reason.is_some() {
-                                finish_reason = choice.finish_reason.clone();
-                            }
-                        }
-                        if sse_chunk.usage.is_some() {
-                            final_usage = sse_chunk.usage;
-                        }
-                    }
-                }
+            if chunk.finish_reason.is_some() {
+                finish_reason = chunk.finish_reason;
+            }
+            if chunk.usage.is_some() {
+                final_usage = chunk.usage;
             }
         }
 
@@ -494,9 +625,30 @@ impl LlmClient {
             token_timestamps,
             usage: final_usage,
             finish_reason,
+            retries,
         })
     }
 
+    /// Open a streaming completion (with retries) and return the parser state.
+    async fn open_stream(&self, request: &ChatRequest) -> Result<(SseState, u32), LlmClientError> {
+        // Force streaming on
+        let stream_request = ChatRequest {
+            stream: Some(true),
+            ..self.with_model(request).into_owned()
+        };
+
+        let (resp, start, retries) = self.post_with_retry(&stream_request, None).await?;
+        let state = SseState {
+            resp,
+            start,
+            buffer: Vec::new(),
+            pending: std::collections::VecDeque::new(),
+            seen_first_token: false,
+            done: false,
+        };
+        Ok((state, retries))
+    }
+
     /// Poll the server until it becomes ready or the timeout expires.
     ///
     /// Returns the time elapsed until the server was ready.
@@ -518,6 +670,91 @@ impl LlmClient {
     }
 }
 
+/// Incremental SSE parser state backing [`LlmClient::chat_stream`].
+#[cfg(feature = "llm")]
+struct SseState {
+    resp: reqwest::Response,
+    start: Instant,
+    buffer: Vec<u8>,
+    pending: std::collections::VecDeque<ChatStreamChunk>,
+    seen_first_token: bool,
+    done: bool,
+}
+
+#[cfg(feature = "llm")]
+impl SseState {
+    /// Turn the parser into a stream of chunks.
+    ///
+    /// Reads the response incrementally via chunk() for real per-token timestamps.
+    /// Each chunk() call returns data as it arrives from the server, so timestamps
+    /// reflect actual token delivery times rather than full-response download time.
+    fn into_stream(
+        self,
+    ) -> impl futures::Stream<Item = Result<ChatStreamChunk, LlmClientError>> + Send + 'static {
+        futures::stream::unfold(self, |mut state| async move {
+            loop {
+                if let Some(chunk) = state.pending.pop_front() {
+                    return Some((Ok(chunk), state));
+                }
+                if state.done {
+                    return None;
+                }
+                match state.resp.chunk().await {
+                    Ok(Some(bytes)) => state.buffer.extend_from_slice(&bytes),
+                    Ok(None) => {
+                        // Flush a final line that lacks a trailing newline
+                        state.buffer.push(b'\n');
+                        state.done = true;
+                    }
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(e.into()), state));
+                    }
+                }
+                state.drain_lines();
+            }
+        })
+    }
+
+    /// Parse every complete line in the buffer into pending chunks.
+    fn drain_lines(&mut self) {
+        while let Some(newline_pos) = self.buffer.iter().position(|&b| b == b'\n') {
+            let raw: Vec<u8> = self.buffer.drain(..=newline_pos).collect();
+            let line = String::from_utf8_lossy(&raw);
+            let Some(data) = line.trim().strip_prefix("data:") else {
+                continue;
+            };
+            let data = data.trim_start();
+            if data == "[DONE]" {
+                self.done = true;
+                self.buffer.clear();
+                return;
+            }
+            let Ok(sse_chunk) = serde_json::from_str::<StreamChunk>(data) else {
+                continue;
+            };
+            let (delta, finish_reason) = sse_chunk.choices.into_iter().next().map_or_else(
+                || (String::new(), None),
+                |choice| {
+                    (
+                        choice.delta.content.unwrap_or_default(),
+                        choice.finish_reason,
+                    )
+                },
+            );
+            let is_first_token = !delta.is_empty() && !self.seen_first_token;
+            self.seen_first_token |= is_first_token;
+            self.pending.push_back(ChatStreamChunk {
+                delta,
+                elapsed: self.start.elapsed(),
+                is_first_token,
+                finish_reason,
+                usage: sse_chunk.usage,
+            });
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -717,4 +954,215 @@ mod tests {
         let json = serde_json::to_string(&req).unwrap();
         assert!(json.contains("\"stream\":true"));
     }
+
+    /// Read one HTTP request (headers and body) so the client is not reset mid-request.
+    #[cfg(feature = "llm")]
+    fn read_http_request(socket: &mut std::net::TcpStream) {
+        use std::io::Read;
+
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+        loop {
+            let n = socket.read(&mut buf).unwrap();
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request);
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let content_length = text[..header_end]
+                    .lines()
+                    .find_map(|l| {
+                        l.to_ascii_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|v| v.trim().parse::<usize>().unwrap())
+                    })
+                    .unwrap_or(0);
+                if request.len() >= header_end + 4 + content_length {
+                    break;
+                }
+            }
+            if n == 0 {
+                break;
+            }
+        }
+    }
+
+    /// Serve canned HTTP responses, one per connection, in order.
+    #[cfg(feature = "llm")]
+    fn spawn_mock_server(responses: Vec<String>) -> String {
+        use std::io::Write;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut socket, _) = listener.accept().unwrap();
+                read_http_request(&mut socket);
+                socket.write_all(response.as_bytes()).unwrap();
+                socket.flush().unwrap();
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    #[cfg(feature = "llm")]
+    fn http_response(status: &str, extra_headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{extra_headers}\r\n{body}",
+            body.len()
+        )
+    }
+
+    /// Serve one streaming completion over a raw socket, pausing before the first token.
+    #[cfg(feature = "llm")]
+    fn spawn_mock_sse_server(first_token_delay: Duration) -> String {
+        use std::io::Write;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+
+            read_http_request(&mut socket);
+
+            let mut send = |data: &str| {
+                socket.write_all(data.as_bytes()).unwrap();
+                socket.flush().unwrap();
+            };
+            send("HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n");
+            send("data: {\"choices\":[{\"delta\":{\"content\":\"\"},\"finish_reason\":null}]}\n\n");
+            std::thread::sleep(first_token_delay);
+            send("data: {\"choices\":[{\"delta\":{\"content\":\"Hel\"},\"finish_reason\":null}]}\n\n");
+            send(
+                "data: {\"choices\":[{\"delta\":{\"content\":\"lo\"},\"finish_reason\":null}]}\n\n",
+            );
+            // Split one event across two writes
+            send("data: {\"choices\":[{\"delta\":{\"content\":");
+            send("\" world\"},\"finish_reason\":null}]}\n\n");
+            send("data: {\"choices\":[{\"delta\":{},\"finish_reason\":\"stop\"}],\"usage\":{\"prompt_tokens\":4,\"completion_tokens\":3,\"total_tokens\":7}}\n\n");
+            send("data: [DONE]\n\n");
+        });
+        format!("http://{addr}")
+    }
+
+    #[cfg(feature = "llm")]
+    fn stream_request() -> ChatRequest {
+        ChatRequest {
+            model: String::new(),
+            messages: vec![ChatMessage {
+                role: Role::User,
+                content: "Say hello".to_string(),
+            }],
+            temperature: None,
+            max_tokens: None,
+            stream: None,
+        }
+    }
+
+    #[cfg(feature = "llm")]
+    #[tokio::test]
+    async fn test_chat_stream_captures_ttft_and_reassembles() {
+        use futures::StreamExt;
+
+        let delay = Duration::from_millis(50);
+        let client = LlmClient::new(spawn_mock_sse_server(delay), "mock");
+        let chunks: Vec<ChatStreamChunk> = client
+            .chat_stream(&stream_request())
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        let text: String = chunks.iter().map(|c| c.delta.as_str()).collect();
+        assert_eq!(text, "Hello world");
+
+        let first: Vec<&ChatStreamChunk> = chunks.iter().filter(|c| c.is_first_token).collect();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].delta, "Hel");
+        assert!(first[0].elapsed >= delay);
+
+        let last = chunks.last().unwrap();
+        assert_eq!(last.finish_reason.as_deref(), Some("stop"));
+        assert_eq!(last.usage.as_ref().unwrap().completion_tokens, 3);
+    }
+
+    #[cfg(feature = "llm")]
+    #[tokio::test]
+    async fn test_chat_completion_stream_builds_on_chat_stream() {
+        let delay = Duration::from_millis(50);
+        let client = LlmClient::new(spawn_mock_sse_server(delay), "mock");
+        let response = client
+            .chat_completion_stream(&stream_request())
+            .await
+            .unwrap();
+        assert_eq!(response.content, "Hello world");
+        assert!(response.ttft >= delay);
+        assert!(response.latency >= response.ttft);
+        assert_eq!(response.token_timestamps.len(), 3);
+        assert_eq!(response.finish_reason.as_deref(), Some("stop"));
+    }
+
+    #[cfg(feature = "llm")]
+    const COMPLETION_BODY: &str = r#"{"id":"r1","object":"chat.completion","created":0,"model":"mock","choices":[{"index":0,"message":{"role":"assistant","content":"ok"},"finish_reason":"stop"}],"usage":{"prompt_tokens":1,"completion_tokens":1,"total_tokens":2}}"#;
+
+    #[cfg(feature = "llm")]
+    #[tokio::test]
+    async fn test_retry_after_rate_limiting_then_success() {
+        let url = spawn_mock_server(vec![
+            http_response("429 Too Many Requests", "Retry-After: 0\r\n", "slow down"),
+            http_response("429 Too Many Requests", "", "slow down"),
+            http_response("200 OK", "", COMPLETION_BODY),
+        ]);
+        let client = LlmClient::new(url, "mock")
+            .with_retry_policy(RetryPolicy::new(3).with_initial_backoff(Duration::from_millis(10)));
+        let timed = client.send(&stream_request()).await.unwrap();
+        assert_eq!(timed.retries, 2);
+        assert_eq!(timed.response.choices[0].message.content, "ok");
+    }
+
+    #[cfg(feature = "llm")]
+    #[tokio::test]
+    async fn test_retry_exhausted_surfaces_rate_limited() {
+        let url = spawn_mock_server(vec![
+            http_response("429 Too Many Requests", "Retry-After: 0\r\n", "slow down"),
+            http_response("429 Too Many Requests", "Retry-After: 0\r\n", "slow down"),
+        ]);
+        let client = LlmClient::new(url, "mock").with_retry_policy(RetryPolicy::new(1));
+        let err = client.send(&stream_request()).await.unwrap_err();
+        assert_eq!(err.class(), LlmErrorClass::RateLimited);
+        assert_eq!(err.retry_after(), Some(Duration::ZERO));
+        assert_eq!(err.retries(), 1);
+    }
+
+    #[cfg(feature = "llm")]
+    #[tokio::test]
+    async fn test_fatal_error_not_retried() {
+        let url = spawn_mock_server(vec![http_response("400 Bad Request", "", "bad")]);
+        let client = LlmClient::new(url, "mock").with_retry_policy(RetryPolicy::new(3));
+        let err = client.send(&stream_request()).await.unwrap_err();
+        assert_eq!(err.class(), LlmErrorClass::Fatal);
+        assert!(matches!(err, LlmClientError::ApiError { status: 400, .. }));
+    }
+
+    #[cfg(feature = "llm")]
+    #[test]
+    fn test_retry_policy_delays() {
+        let policy = RetryPolicy::new(5)
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(500));
+        assert_eq!(policy.delay_for(0, None), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2, None), Duration::from_millis(400));
+        assert_eq!(policy.delay_for(3, None), Duration::from_millis(500));
+        assert_eq!(
+            policy.delay_for(0, Some(Duration::from_millis(300))),
+            Duration::from_millis(300)
+        );
+        assert_eq!(parse_retry_after("2"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_retry_after("0.5"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+        assert_eq!(parse_retry_after("-1"), None);
+        assert_eq!(parse_retry_after("NaN"), None);
+        assert_eq!(parse_retry_after("1e30"), Some(MAX_RETRY_AFTER));
+        assert_eq!(parse_retry_after("inf"), Some(MAX_RETRY_AFTER));
+        assert_eq!(RetryPolicy::default().max_retries, 0);
+    }
 }
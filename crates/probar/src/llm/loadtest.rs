@@ -148,6 +148,21 @@ pub struct LoadTestResult {
     /// Comparable across backends regardless of response length.
     #[serde(default)]
     pub itl_p50_ms: f64,
+    /// Mean inter-token latency in ms across requests with 2+ tokens.
+    #[serde(default)]
+    pub itl_mean_ms: f64,
+    /// Successful single-token requests excluded from ITL (tokens - 1 == 0).
+    #[serde(default)]
+    pub itl_excluded_requests: u64,
+    /// Successful requests that needed no retry.
+    #[serde(default)]
+    pub first_try_successes: u64,
+    /// Successful requests that succeeded only after retrying a 429/503.
+    #[serde(default)]
+    pub retried_successes: u64,
+    /// Total retries issued across all requests.
+    #[serde(default)]
+    pub total_retries: u64,
     /// Decode throughput: 1000 / itl_p50_ms. True generation speed (GH-23).
     #[serde(default)]
     pub decode_tok_per_sec: f64,
@@ -500,6 +515,8 @@ struct RequestRecord {
     finish_reason: Option<String>,
     /// Response content (only captured when validation requires content inspection).
     response_content: Option<String>,
+    /// Retries on rate-limited or busy responses before the final outcome.
+    retries: u32,
 }
 
 impl LoadTest {
@@ -706,9 +723,10 @@ async fn send_one_request(
                     brick_trace: None,
                     finish_reason: streamed.finish_reason,
                     response_content: content,
+                    retries: streamed.retries,
                 }
             }
-            Err(_) => failed_record(),
+            Err(err) => failed_record(err.retries()),
         }
     } else {
         let result = if let Some(tl) = trace_level {
@@ -745,14 +763,15 @@ async fn send_one_request(
                     brick_trace: timed.brick_trace,
                     finish_reason,
                     response_content: content,
+                    retries: timed.retries,
                 }
             }
-            Err(_) => failed_record(),
+            Err(err) => failed_record(err.retries()),
         }
     }
 }
 
-fn failed_record() -> RequestRecord {
+fn failed_record(retries: u32) -> RequestRecord {
     RequestRecord {
         latency: Duration::from_millis(0),
         ttfb: Duration::from_millis(0),
@@ -763,6 +782,7 @@ fn failed_record() -> RequestRecord {
         brick_trace: None,
         finish_reason: None,
         response_content: None,
+        retries,
     }
 }
 
@@ -866,6 +886,18 @@ fn aggregate_results(
     itls.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
 
     let itl_p50_ms = percentile(&itls, 0.50);
+    let itl_mean_ms = if itls.is_empty() {
+        0.0
+    } else {
+        itls.iter().sum::<f64>() / itls.len() as f64
+    };
+    let itl_excluded_requests = records.iter().filter(|r| r.success && r.tokens < 2).count() as u64;
+    let retried_successes = records
+        .iter()
+        .filter(|r| r.success && r.retries > 0)
+        .count() as u64;
+    let first_try_successes = successful - retried_successes;
+    let total_retries: u64 = records.iter().map(|r| u64::from(r.retries)).sum();
     let decode_tok_per_sec = if itl_p50_ms > 0.0 {
         1000.0 / itl_p50_ms
     } else {
@@ -975,6 +1007,11 @@ fn aggregate_results(
         tokens_per_sec,
         avg_tok_per_req,
         itl_p50_ms,
+        itl_mean_ms,
+        itl_excluded_requests,
+        first_try_successes,
+        retried_successes,
+        total_retries,
         decode_tok_per_sec,
         prefill_tok_per_sec,
         timestamp: now,
@@ -1534,6 +1571,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             })
             .collect();
         let result = aggregate_results(&records, 10.0, "realizar", 2, None, None, None, None);
@@ -1575,6 +1613,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             },
             RequestRecord {
                 latency: Duration::from_millis(0),
@@ -1586,6 +1625,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             },
         ];
         let result = aggregate_results(&records, 5.0, "ollama", 1, None, None, None, None);
@@ -1626,6 +1666,11 @@ mod tests {
             tokens_per_sec: 200.0,
             avg_tok_per_req: 15.0,
             itl_p50_ms: 5.0,
+            itl_mean_ms: 5.0,
+            itl_excluded_requests: 0,
+            first_try_successes: 0,
+            retried_successes: 0,
+            total_retries: 0,
             decode_tok_per_sec: 200.0,
             prefill_tok_per_sec: 0.0,
             timestamp: "2026-03-01T00:00:00Z".to_string(),
@@ -1720,6 +1765,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
         assert!((result.itl_p50_ms - 10.0).abs() < 0.1);
@@ -1743,6 +1789,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
         assert!((result.itl_p50_ms - 100.0).abs() < 0.1);
@@ -1763,6 +1810,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
         assert_eq!(result.itl_p50_ms, 0.0);
@@ -1782,6 +1830,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 0.0, "test", 1, None, None, None, None);
         assert_eq!(result.throughput_rps, 0.0);
@@ -1812,6 +1861,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
         assert!((result.tpot_p50_ms - 10.0).abs() < 0.1);
@@ -1830,6 +1880,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             },
             RequestRecord {
                 latency: Duration::from_millis(300),
@@ -1841,6 +1892,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             },
         ];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
@@ -1862,6 +1914,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             },
             RequestRecord {
                 latency: Duration::from_millis(100),
@@ -1873,6 +1926,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             },
         ];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
@@ -1901,6 +1955,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
         // Real TPOT from timestamps: mean of [10, 10, 10, 10] = 10ms
@@ -1930,6 +1985,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             },
             RequestRecord {
                 latency: Duration::from_millis(100),
@@ -1941,6 +1997,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: None,
                 response_content: None,
+                retries: 0,
             },
         ];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
@@ -1970,6 +2027,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
         // Both TPOT and ITL should be latency/tokens = 100ms
@@ -2010,6 +2068,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
         // Per-request mean: (300-100)/5 = 40ms
@@ -2042,6 +2101,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let result = aggregate_results(&records, 1.0, "test", 1, None, None, None, None);
         assert_eq!(result.request_details.len(), 1);
@@ -2070,6 +2130,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: Some("stop".to_string()),
                 response_content: None,
+                retries: 0,
             },
             RequestRecord {
                 latency: Duration::from_millis(120),
@@ -2081,6 +2142,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: Some("stop".to_string()),
                 response_content: None,
+                retries: 0,
             },
         ];
         let quality = compute_quality(&records, &ValidationMode::Basic);
@@ -2102,6 +2164,7 @@ mod tests {
             brick_trace: None,
             finish_reason: Some("stop".to_string()),
             response_content: None,
+            retries: 0,
         }];
         let quality = compute_quality(&records, &ValidationMode::Basic);
         assert_eq!(quality.failed, 1);
@@ -2120,6 +2183,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         let quality = compute_quality(&records, &ValidationMode::Basic);
         assert_eq!(quality.failed, 1);
@@ -2138,6 +2202,7 @@ mod tests {
             brick_trace: None,
             finish_reason: Some("stop".to_string()),
             response_content: Some("hello world".to_string()),
+            retries: 0,
         }];
         let quality = compute_quality(&records, &ValidationMode::Contains("hello".to_string()));
         assert_eq!(quality.passed, 1);
@@ -2156,6 +2221,7 @@ mod tests {
             brick_trace: None,
             finish_reason: Some("stop".to_string()),
             response_content: Some("goodbye world".to_string()),
+            retries: 0,
         }];
         let quality = compute_quality(&records, &ValidationMode::Contains("hello".to_string()));
         assert_eq!(quality.failed, 1);
@@ -2174,6 +2240,7 @@ mod tests {
             brick_trace: None,
             finish_reason: None,
             response_content: None,
+            retries: 0,
         }];
         // ValidationMode::None should still return results if called directly
         let quality = compute_quality(&records, &ValidationMode::None);
@@ -2184,7 +2251,7 @@ mod tests {
     #[test]
     fn test_quality_skips_failed_requests() {
         let records = vec![
-            failed_record(), // success: false
+            failed_record(0), // success: false
             RequestRecord {
                 latency: Duration::from_millis(100),
                 ttfb: Duration::from_millis(50),
@@ -2195,6 +2262,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: Some("stop".to_string()),
                 response_content: None,
+                retries: 0,
             },
         ];
         let quality = compute_quality(&records, &ValidationMode::Basic);
@@ -2220,6 +2288,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: Some("stop".to_string()),
                 response_content: None,
+                retries: 0,
             })
             .collect();
         let tail = compute_tail_analysis(&records, 5.0);
@@ -2244,6 +2313,7 @@ mod tests {
                 brick_trace: None,
                 finish_reason: Some("stop".to_string()),
                 response_content: None,
+                retries: 0,
             })
             .collect();
         // Add a spike (10x normal latency)
@@ -2257,6 +2327,7 @@ mod tests {
             brick_trace: None,
             finish_reason: Some("stop".to_string()),
             response_content: None,
+            retries: 0,
         });
         let tail = compute_tail_analysis(&records, 5.0);
         // The spike should be detected (its ITL is much higher than median)
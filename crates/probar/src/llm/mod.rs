@@ -24,13 +24,13 @@ pub mod report;
 pub mod score;
 pub mod training_scorecard;
 
-pub use assertion::{LlmAssertion, LlmAssertionError, LlmAssertionResult};
+pub use assertion::{validate_json_schema, LlmAssertion, LlmAssertionError, LlmAssertionResult};
 pub use client::{
-    BrickTrace, BrickTraceOp, ChatMessage, ChatRequest, ChatResponse, ChatResponseChoice, Role,
-    StreamChunk, StreamedChatResponse, TimedChatResponse, Usage,
+    BrickTrace, BrickTraceOp, ChatMessage, ChatRequest, ChatResponse, ChatResponseChoice,
+    ChatStreamChunk, Role, StreamChunk, StreamedChatResponse, TimedChatResponse, Usage,
 };
 #[cfg(feature = "llm")]
-pub use client::{LlmClient, LlmClientError};
+pub use client::{LlmClient, LlmClientError, LlmErrorClass, RetryPolicy};
 pub use experiment::{
     BudgetConfig, DataAuditResult, EarlyStoppingConfig, Experiment, ExperimentRun,
     ExperimentStatus, KillCriterion, MetricSnapshot,
@@ -2365,6 +2365,11 @@ mod tests {
             tokens_per_sec: decode * concurrency as f64,
             avg_tok_per_req: 32.0,
             itl_p50_ms: itl,
+            itl_mean_ms: itl,
+            itl_excluded_requests: 0,
+            first_try_successes: 0,
+            retried_successes: 0,
+            total_retries: 0,
             decode_tok_per_sec: decode,
             prefill_tok_per_sec: 1000.0 / ttft * 23.0,
             timestamp: "2026-03-11T00:00:00Z".into(),
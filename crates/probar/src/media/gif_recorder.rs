@@ -15,8 +15,34 @@ use crate::result::{ProbarError, ProbarResult};
 use gif::{Encoder, Frame, Repeat};
 use image::{DynamicImage, GenericImageView, ImageFormat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
+/// Palette quantization algorithm used to reduce frames to at most 256 colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Quantizer {
+    /// NeuQuant neural-network quantizer; `sample_fac` ranges from 1 (best) to 30 (fastest)
+    NeuQuant {
+        /// Pixel sampling factor (1-30)
+        sample_fac: i32,
+    },
+    /// Median-cut box splitting, limited to `max_colors` palette entries (2-256)
+    MedianCut {
+        /// Maximum palette size
+        max_colors: u16,
+    },
+}
+
+/// Whether frames share one palette or each carry their own
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaletteMode {
+    /// Quantize each frame separately (larger files, better quality)
+    #[default]
+    PerFrame,
+    /// Quantize all frames against a single global palette (smaller files)
+    Global,
+}
+
 /// Configuration for GIF recording
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GifConfig {
@@ -30,6 +56,12 @@ pub struct GifConfig {
     pub quality: u8,
     /// Loop count (0 = infinite)
     pub loop_count: u16,
+    /// Palette quantizer (`None` = NeuQuant with a sample factor derived from `quality`)
+    #[serde(default)]
+    pub quantizer: Option<Quantizer>,
+    /// Per-frame or global palette (default: per-frame)
+    #[serde(default)]
+    pub palette_mode: PaletteMode,
 }
 
 impl Default for GifConfig {
@@ -40,6 +72,8 @@ impl Default for GifConfig {
             height: 600,
             quality: 80,
             loop_count: 0, // Infinite loop
+            quantizer: None,
+            palette_mode: PaletteMode::PerFrame,
         }
     }
 }
@@ -76,6 +110,20 @@ impl GifConfig {
         self
     }
 
+    /// Set the palette quantizer, overriding the `quality`-derived default
+    #[must_use]
+    pub fn with_quantizer(mut self, quantizer: Quantizer) -> Self {
+        self.quantizer = Some(quantizer);
+        self
+    }
+
+    /// Set per-frame or global palette mode
+    #[must_use]
+    pub fn with_palette_mode(mut self, mode: PaletteMode) -> Self {
+        self.palette_mode = mode;
+        self
+    }
+
     /// Calculate frame delay in centiseconds (GIF standard)
     #[must_use]
     pub fn frame_delay_cs(&self) -> u16 {
@@ -310,12 +358,29 @@ impl GifRecorder {
         let width = self.config.width as u16;
         let height = self.config.height as u16;
 
+        let frames = self
+            .frames
+            .iter()
+            .map(|frame| self.resize_frame(frame))
+            .collect::<ProbarResult<Vec<_>>>()?;
+
+        // A global palette is built once from every frame's pixels
+        let mut global_mapper = match self.config.palette_mode {
+            PaletteMode::Global => Some(ColorMapper::new(self.quantizer(), &frames)),
+            PaletteMode::PerFrame => None,
+        };
+        let global_palette = global_mapper
+            .as_ref()
+            .map(ColorMapper::palette)
+            .unwrap_or_default();
+
         {
-            let mut encoder = Encoder::new(&mut output, width, height, &[]).map_err(|e| {
-                ProbarError::ImageProcessing {
-                    message: format!("Failed to create GIF encoder: {e}"),
-                }
-            })?;
+            let mut encoder =
+                Encoder::new(&mut output, width, height, &global_palette).map_err(|e| {
+                    ProbarError::ImageProcessing {
+                        message: format!("Failed to create GIF encoder: {e}"),
+                    }
+                })?;
 
             // Set loop behavior
             let repeat = if self.config.loop_count == 0 {
@@ -331,17 +396,25 @@ impl GifRecorder {
 
             let frame_delay = self.config.frame_delay_cs();
 
-            for gif_frame in &self.frames {
-                // Resize frame if needed
-                let rgba_data = self.resize_frame(gif_frame)?;
-
+            for rgba_data in &frames {
                 // Convert RGBA to indexed color
-                let mut frame = Frame::from_rgba_speed(
-                    width,
-                    height,
-                    &mut rgba_data.clone(),
-                    self.quality_to_speed(),
-                );
+                let mut frame = match (global_mapper.as_mut(), self.config.quantizer) {
+                    (Some(mapper), _) => {
+                        Frame::from_indexed_pixels(width, height, mapper.map(rgba_data), None)
+                    }
+                    (None, Some(quantizer)) => {
+                        let mut mapper =
+                            ColorMapper::new(quantizer, std::slice::from_ref(rgba_data));
+                        let pixels = mapper.map(rgba_data);
+                        Frame::from_palette_pixels(width, height, pixels, mapper.palette(), None)
+                    }
+                    (None, None) => Frame::from_rgba_speed(
+                        width,
+                        height,
+                        &mut rgba_data.clone(),
+                        self.quality_to_speed(),
+                    ),
+                };
                 frame.delay = frame_delay;
 
                 encoder
@@ -355,6 +428,13 @@ impl GifRecorder {
         Ok(output)
     }
 
+    /// Effective quantizer, falling back to NeuQuant driven by `quality`
+    fn quantizer(&self) -> Quantizer {
+        self.config.quantizer.unwrap_or(Quantizer::NeuQuant {
+            sample_fac: self.quality_to_speed(),
+        })
+    }
+
     /// Resize a frame to match the configured dimensions
     fn resize_frame(&self, frame: &GifFrame) -> ProbarResult<Vec<u8>> {
         if frame.width == self.config.width && frame.height == self.config.height {
@@ -390,6 +470,127 @@ impl GifRecorder {
     }
 }
 
+/// Maps RGBA pixels onto a quantized palette
+enum ColorMapper {
+    NeuQuant(color_quant::NeuQuant),
+    Palette {
+        palette: Vec<[u8; 3]>,
+        cache: HashMap<[u8; 3], u8>,
+    },
+}
+
+impl ColorMapper {
+    /// Build a palette for the RGBA pixels of `frames` using the given
+    /// quantizer (alpha is ignored)
+    fn new(quantizer: Quantizer, frames: &[Vec<u8>]) -> Self {
+        let pixels = frames.iter().flat_map(|rgba| rgba.chunks_exact(4));
+        match quantizer {
+            Quantizer::NeuQuant { sample_fac } => {
+                let opaque: Vec<u8> = pixels.flat_map(|p| [p[0], p[1], p[2], 255]).collect();
+                Self::NeuQuant(color_quant::NeuQuant::new(
+                    sample_fac.clamp(1, 30),
+                    256,
+                    &opaque,
+                ))
+            }
+            Quantizer::MedianCut { max_colors } => Self::Palette {
+                palette: median_cut(
+                    pixels.map(|p| [p[0], p[1], p[2]]).collect(),
+                    usize::from(max_colors.clamp(2, 256)),
+                ),
+                cache: HashMap::new(),
+            },
+        }
+    }
+
+    /// Palette as packed `[r, g, b, ...]` bytes
+    fn palette(&self) -> Vec<u8> {
+        match self {
+            Self::NeuQuant(nq) => nq.color_map_rgb(),
+            Self::Palette { palette, .. } => palette.iter().flatten().copied().collect(),
+        }
+    }
+
+    /// Map RGBA pixels to palette indices
+    fn map(&mut self, rgba: &[u8]) -> Vec<u8> {
+        rgba.chunks_exact(4)
+            .map(|p| self.index_of([p[0], p[1], p[2]]))
+            .collect()
+    }
+
+    fn index_of(&mut self, rgb: [u8; 3]) -> u8 {
+        match self {
+            Self::NeuQuant(nq) => nq.index_of(&[rgb[0], rgb[1], rgb[2], 255]) as u8,
+            Self::Palette { palette, cache } => *cache.entry(rgb).or_insert_with(|| {
+                palette
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, entry)| color_distance(**entry, rgb))
+                    .map_or(0, |(i, _)| i as u8)
+            }),
+        }
+    }
+}
+
+fn color_distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(&x, &y)| u32::from(x.abs_diff(y)).pow(2))
+        .sum()
+}
+
+/// Median-cut quantization: repeatedly split the box with the widest channel
+/// range at its median until `max_colors` boxes exist, then average each box
+fn median_cut(pixels: Vec<[u8; 3]>, max_colors: usize) -> Vec<[u8; 3]> {
+    fn widest_channel(pixels: &[[u8; 3]]) -> (usize, u8) {
+        (0..3)
+            .map(|c| {
+                let (min, max) = pixels.iter().fold((u8::MAX, u8::MIN), |(lo, hi), p| {
+                    (lo.min(p[c]), hi.max(p[c]))
+                });
+                (c, max.saturating_sub(min))
+            })
+            .max_by_key(|&(_, range)| range)
+            .unwrap_or((0, 0))
+    }
+
+    if pixels.is_empty() {
+        return vec![[0, 0, 0]];
+    }
+
+    let mut boxes = vec![(widest_channel(&pixels), pixels)];
+    while boxes.len() < max_colors {
+        let Some(idx) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, ((_, range), _))| *range > 0)
+            .max_by_key(|(_, ((_, range), _))| *range)
+            .map(|(i, _)| i)
+        else {
+            break;
+        };
+        let ((channel, _), mut lower) = boxes.swap_remove(idx);
+        lower.sort_unstable_by_key(|p| p[channel]);
+        let upper = lower.split_off(lower.len() / 2);
+        boxes.push((widest_channel(&lower), lower));
+        boxes.push((widest_channel(&upper), upper));
+    }
+
+    boxes
+        .iter()
+        .map(|(_, pixels)| {
+            let mut sum = [0u64; 3];
+            for p in pixels {
+                for (total, &value) in sum.iter_mut().zip(p) {
+                    *total += u64::from(value);
+                }
+            }
+            let n = pixels.len() as u64;
+            [(sum[0] / n) as u8, (sum[1] / n) as u8, (sum[2] / n) as u8]
+        })
+        .collect()
+}
+
 // ============================================================================
 // EXTREME TDD: Tests written FIRST per spec
 // ============================================================================
@@ -457,6 +658,22 @@ mod tests {
             assert_eq!(config.loop_count, 3);
         }
 
+        #[test]
+        fn test_quantizer_and_palette_defaults() {
+            let config = GifConfig::default();
+            assert_eq!(config.quantizer, None);
+            assert_eq!(config.palette_mode, PaletteMode::PerFrame);
+
+            let config = GifConfig::default()
+                .with_quantizer(Quantizer::MedianCut { max_colors: 64 })
+                .with_palette_mode(PaletteMode::Global);
+            assert_eq!(
+                config.quantizer,
+                Some(Quantizer::MedianCut { max_colors: 64 })
+            );
+            assert_eq!(config.palette_mode, PaletteMode::Global);
+        }
+
         #[test]
         fn test_frame_delay_calculation() {
             let config = GifConfig::default().with_fps(10);
@@ -685,6 +902,126 @@ mod tests {
         }
     }
 
+    mod quantizer_tests {
+        use super::*;
+
+        const SIZE: u32 = 64;
+
+        fn gradient_frame() -> GifFrame {
+            let mut data = Vec::with_capacity((SIZE * SIZE * 4) as usize);
+            for y in 0..SIZE {
+                for x in 0..SIZE {
+                    data.extend_from_slice(&[
+                        (x * 4) as u8,
+                        (y * 4) as u8,
+                        ((x + y) * 2) as u8,
+                        255,
+                    ]);
+                }
+            }
+            GifFrame::new(data, SIZE, SIZE, 0)
+        }
+
+        fn encode(config: GifConfig, frames: Vec<GifFrame>) -> Vec<u8> {
+            let mut recorder = GifRecorder::new(config);
+            recorder.start().unwrap();
+            for frame in frames {
+                recorder.add_frame(frame).unwrap();
+            }
+            recorder.stop().unwrap()
+        }
+
+        fn decode_first_frame_rgba(data: &[u8]) -> Vec<u8> {
+            let mut options = gif::DecodeOptions::new();
+            options.set_color_output(gif::ColorOutput::RGBA);
+            let mut decoder = options.read_info(Cursor::new(data)).unwrap();
+            decoder.read_next_frame().unwrap().unwrap().buffer.to_vec()
+        }
+
+        /// Mean weighted squared error (luma-weighted RGB) between two RGBA buffers
+        fn perceptual_error(a: &[u8], b: &[u8]) -> f64 {
+            let total: f64 = a
+                .chunks_exact(4)
+                .zip(b.chunks_exact(4))
+                .map(|(p, q)| {
+                    let d = |c: usize| f64::from(p[c]) - f64::from(q[c]);
+                    0.299 * d(0).powi(2) + 0.587 * d(1).powi(2) + 0.114 * d(2).powi(2)
+                })
+                .sum();
+            total / f64::from(SIZE * SIZE)
+        }
+
+        #[test]
+        fn test_neuquant_beats_coarse_median_cut_on_gradient() {
+            let source = gradient_frame();
+
+            let neuquant = encode(
+                GifConfig::new(SIZE, SIZE).with_quantizer(Quantizer::NeuQuant { sample_fac: 1 }),
+                vec![source.clone()],
+            );
+            let median_cut = encode(
+                GifConfig::new(SIZE, SIZE).with_quantizer(Quantizer::MedianCut { max_colors: 16 }),
+                vec![source.clone()],
+            );
+
+            let neuquant_error =
+                perceptual_error(&source.data, &decode_first_frame_rgba(&neuquant));
+            let median_cut_error =
+                perceptual_error(&source.data, &decode_first_frame_rgba(&median_cut));
+
+            assert!(
+                neuquant_error < median_cut_error,
+                "NeuQuant error {neuquant_error} should be below median-cut error {median_cut_error}"
+            );
+        }
+
+        #[test]
+        fn test_median_cut_is_lossless_for_few_colors() {
+            let mut data = Vec::new();
+            for i in 0..(SIZE * SIZE) {
+                let color: [u8; 4] = if i % 2 == 0 {
+                    [10, 200, 30, 255]
+                } else {
+                    [250, 5, 120, 255]
+                };
+                data.extend_from_slice(&color);
+            }
+            let source = GifFrame::new(data, SIZE, SIZE, 0);
+            let gif_data = encode(
+                GifConfig::new(SIZE, SIZE).with_quantizer(Quantizer::MedianCut { max_colors: 16 }),
+                vec![source.clone()],
+            );
+
+            assert_eq!(decode_first_frame_rgba(&gif_data), source.data);
+        }
+
+        #[test]
+        fn test_global_palette_omits_local_palettes() {
+            let frames = vec![gradient_frame(), gradient_frame()];
+            let global = encode(
+                GifConfig::new(SIZE, SIZE).with_palette_mode(PaletteMode::Global),
+                frames.clone(),
+            );
+            let per_frame = encode(GifConfig::new(SIZE, SIZE), frames);
+
+            let mut decoder = gif::DecodeOptions::new()
+                .read_info(Cursor::new(&global))
+                .unwrap();
+            assert!(decoder.global_palette().is_some_and(|p| p.len() == 256 * 3));
+            while let Some(frame) = decoder.read_next_frame().unwrap() {
+                assert!(frame.palette.is_none());
+            }
+
+            let mut decoder = gif::DecodeOptions::new()
+                .read_info(Cursor::new(&per_frame))
+                .unwrap();
+            while let Some(frame) = decoder.read_next_frame().unwrap() {
+                assert!(frame.palette.is_some());
+            }
+            assert!(global.len() < per_frame.len());
+        }
+    }
+
     mod property_tests {
         use super::*;
         use proptest::prelude::*;
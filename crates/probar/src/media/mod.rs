@@ -13,7 +13,9 @@ mod png_exporter;
 mod svg_exporter;
 mod video_recorder;
 
-pub use gif_recorder::{GifConfig, GifFrame, GifRecorder};
-pub use png_exporter::{Annotation, CompressionLevel, PngExporter, PngMetadata};
+pub use gif_recorder::{GifConfig, GifFrame, GifRecorder, PaletteMode, Quantizer};
+pub use png_exporter::{Annotation, AnnotationKind, CompressionLevel, PngExporter, PngMetadata};
 pub use svg_exporter::{SvgCompression, SvgConfig, SvgExporter, SvgShape};
-pub use video_recorder::{EncodedFrame, RecordingState, VideoCodec, VideoConfig, VideoRecorder};
+pub use video_recorder::{
+    EncodedFrame, RateControl, RecordingState, VideoCodec, VideoConfig, VideoRecorder,
+};
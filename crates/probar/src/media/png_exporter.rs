@@ -5,6 +5,7 @@
 //! ## EXTREME TDD: Tests written FIRST per spec
 
 use crate::driver::Screenshot;
+use crate::pixel_coverage::BitmapFont;
 use crate::result::{ProbarError, ProbarResult};
 use image::{DynamicImage, GenericImageView, Rgba, RgbaImage};
 use serde::{Deserialize, Serialize};
@@ -122,10 +123,20 @@ pub enum AnnotationKind {
     FilledRectangle,
     /// Circle/ellipse outline
     Circle,
-    /// Arrow pointing to location
+    /// Arrow from (`x`, `y`) to (`x + width`, `y + height`)
     Arrow,
     /// Highlight (semi-transparent overlay)
     Highlight,
+    /// Arrow from (`x`, `y`) to an arbitrary target point, arrowhead at the target
+    ArrowTo {
+        /// Target point the arrowhead touches
+        to: (u32, u32),
+    },
+    /// Dimension line from (`x`, `y`) to `to` with end ticks and the label at its midpoint
+    Measurement {
+        /// End point of the dimension line
+        to: (u32, u32),
+    },
 }
 
 impl Annotation {
@@ -199,6 +210,34 @@ impl Annotation {
         }
     }
 
+    /// Create an arrow annotation between two points (arrowhead at `to`)
+    #[must_use]
+    pub fn arrow_between(from: (u32, u32), to: (u32, u32)) -> Self {
+        Self {
+            x: from.0,
+            y: from.1,
+            width: from.0.abs_diff(to.0),
+            height: from.1.abs_diff(to.1),
+            color: [0, 0, 255, 255], // Blue by default
+            kind: AnnotationKind::ArrowTo { to },
+            label: None,
+        }
+    }
+
+    /// Create a labeled measurement (dimension line) between two points
+    #[must_use]
+    pub fn measurement(from: (u32, u32), to: (u32, u32), label: impl Into<String>) -> Self {
+        Self {
+            x: from.0,
+            y: from.1,
+            width: from.0.abs_diff(to.0),
+            height: from.1.abs_diff(to.1),
+            color: [255, 0, 255, 255], // Magenta by default
+            kind: AnnotationKind::Measurement { to },
+            label: Some(label.into()),
+        }
+    }
+
     /// Set the annotation color
     #[must_use]
     pub fn with_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
@@ -387,10 +426,86 @@ impl PngExporter {
             AnnotationKind::Highlight => {
                 Self::draw_highlight(img, annotation);
             }
-            AnnotationKind::Circle | AnnotationKind::Arrow => {
+            AnnotationKind::Circle => {
                 // Simplified: draw as rectangle for now
                 Self::draw_rectangle_outline(img, annotation, color);
             }
+            AnnotationKind::Arrow => {
+                let to = (
+                    annotation.x.saturating_add(annotation.width),
+                    annotation.y.saturating_add(annotation.height),
+                );
+                Self::draw_arrow(img, annotation, to, color);
+            }
+            AnnotationKind::ArrowTo { to } => {
+                Self::draw_arrow(img, annotation, to, color);
+            }
+            AnnotationKind::Measurement { to } => {
+                Self::draw_measurement(img, annotation, to, color);
+            }
+        }
+    }
+
+    /// Draw an arrow with a filled head at `to`; the label sits behind the tail
+    fn draw_arrow(img: &mut RgbaImage, ann: &Annotation, to: (u32, u32), color: Rgba<u8>) {
+        let from = (f64::from(ann.x), f64::from(ann.y));
+        let tip = (f64::from(to.0), f64::from(to.1));
+        let Some((ux, uy)) = unit_vector(from, tip) else {
+            put_clipped(img, from.0, from.1, color);
+            return;
+        };
+
+        let length = (tip.0 - from.0).hypot(tip.1 - from.1);
+        let head_len = ARROW_HEAD_LENGTH.min(length);
+        let base = (tip.0 - ux * head_len, tip.1 - uy * head_len);
+        let half_width = head_len / 2.0;
+        let left = (base.0 - uy * half_width, base.1 + ux * half_width);
+        let right = (base.0 + uy * half_width, base.1 - ux * half_width);
+
+        draw_line(img, from, base, color);
+        fill_triangle(img, tip, left, right, color);
+
+        if let Some(label) = &ann.label {
+            let font = BitmapFont::default();
+            let offset = f64::from(font.text_width(label).max(font.char_height())) / 2.0 + 3.0;
+            draw_text(
+                img,
+                &font,
+                label,
+                (from.0 - ux * offset, from.1 - uy * offset),
+                color,
+            );
+        }
+    }
+
+    /// Draw a dimension line with perpendicular end ticks and a centered label
+    fn draw_measurement(img: &mut RgbaImage, ann: &Annotation, to: (u32, u32), color: Rgba<u8>) {
+        let from = (f64::from(ann.x), f64::from(ann.y));
+        let end = (f64::from(to.0), f64::from(to.1));
+        let (ux, uy) = unit_vector(from, end).unwrap_or((1.0, 0.0));
+        let (nx, ny) = (-uy, ux);
+
+        draw_line(img, from, end, color);
+        for (px, py) in [from, end] {
+            draw_line(
+                img,
+                (px - nx * MEASUREMENT_TICK, py - ny * MEASUREMENT_TICK),
+                (px + nx * MEASUREMENT_TICK, py + ny * MEASUREMENT_TICK),
+                color,
+            );
+        }
+
+        if let Some(label) = &ann.label {
+            let font = BitmapFont::default();
+            let offset = f64::from(font.char_height()) / 2.0 + MEASUREMENT_TICK;
+            let mid = ((from.0 + end.0) / 2.0, (from.1 + end.1) / 2.0);
+            draw_text(
+                img,
+                &font,
+                label,
+                (mid.0 - nx * offset, mid.1 - ny * offset),
+                color,
+            );
         }
     }
 
@@ -457,6 +572,161 @@ impl PngExporter {
     }
 }
 
+/// Length of arrowheads in pixels (shortened for arrows shorter than this)
+const ARROW_HEAD_LENGTH: f64 = 10.0;
+
+/// Half-length of the end ticks on measurement lines
+const MEASUREMENT_TICK: f64 = 4.0;
+
+/// Unit direction from `from` to `to`, or `None` if the points coincide
+fn unit_vector(from: (f64, f64), to: (f64, f64)) -> Option<(f64, f64)> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length = dx.hypot(dy);
+    if length > 0.0 {
+        Some((dx / length, dy / length))
+    } else {
+        None
+    }
+}
+
+/// Set a pixel if it lies inside the image
+fn put_clipped(img: &mut RgbaImage, x: f64, y: f64, color: Rgba<u8>) {
+    let (x, y) = (x.round(), y.round());
+    if x >= 0.0 && y >= 0.0 && x < f64::from(img.width()) && y < f64::from(img.height()) {
+        img.put_pixel(x as u32, y as u32, color);
+    }
+}
+
+/// Clip a segment to the image rectangle (Liang-Barsky)
+fn clip_line(
+    img: &RgbaImage,
+    from: (f64, f64),
+    to: (f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    let max_x = f64::from(img.width()) - 1.0;
+    let max_y = f64::from(img.height()) - 1.0;
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+
+    for (p, q) in [
+        (-dx, from.0),
+        (dx, max_x - from.0),
+        (-dy, from.1),
+        (dy, max_y - from.1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+
+    if t0 > t1 {
+        return None;
+    }
+    Some((
+        (from.0 + t0 * dx, from.1 + t0 * dy),
+        (from.0 + t1 * dx, from.1 + t1 * dy),
+    ))
+}
+
+/// Draw a one-pixel line, clipped to the image bounds
+fn draw_line(img: &mut RgbaImage, from: (f64, f64), to: (f64, f64), color: Rgba<u8>) {
+    let Some((from, to)) = clip_line(img, from, to) else {
+        return;
+    };
+    let steps = (to.0 - from.0)
+        .abs()
+        .max((to.1 - from.1).abs())
+        .ceil()
+        .max(1.0);
+    let count = steps as u32;
+    for i in 0..=count {
+        let t = f64::from(i) / steps;
+        put_clipped(
+            img,
+            from.0 + (to.0 - from.0) * t,
+            from.1 + (to.1 - from.1) * t,
+            color,
+        );
+    }
+}
+
+/// Fill a triangle, clipped to the image bounds
+fn fill_triangle(
+    img: &mut RgbaImage,
+    a: (f64, f64),
+    b: (f64, f64),
+    c: (f64, f64),
+    color: Rgba<u8>,
+) {
+    let edge = |p: (f64, f64), q: (f64, f64), r: (f64, f64)| {
+        (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+    };
+    let area = edge(a, b, c);
+    if area == 0.0 {
+        draw_line(img, a, b, color);
+        draw_line(img, b, c, color);
+        return;
+    }
+
+    let max_x = f64::from(img.width()) - 1.0;
+    let max_y = f64::from(img.height()) - 1.0;
+    let x0 = a.0.min(b.0).min(c.0).floor().max(0.0);
+    let x1 = a.0.max(b.0).max(c.0).ceil().min(max_x);
+    let y0 = a.1.min(b.1).min(c.1).floor().max(0.0);
+    let y1 = a.1.max(b.1).max(c.1).ceil().min(max_y);
+    if x0 > x1 || y0 > y1 {
+        return;
+    }
+
+    for y in (y0 as u32)..=(y1 as u32) {
+        for x in (x0 as u32)..=(x1 as u32) {
+            let p = (f64::from(x), f64::from(y));
+            let w0 = edge(b, c, p) / area;
+            let w1 = edge(c, a, p) / area;
+            let w2 = edge(a, b, p) / area;
+            if w0 >= 0.0 && w1 >= 0.0 && w2 >= 0.0 {
+                img.put_pixel(x, y, color);
+            }
+        }
+    }
+    // Make sure the tip itself is always painted
+    put_clipped(img, a.0, a.1, color);
+}
+
+/// Draw text centered on `center` with the bitmap font, clipped to the image bounds
+fn draw_text(
+    img: &mut RgbaImage,
+    font: &BitmapFont,
+    text: &str,
+    center: (f64, f64),
+    color: Rgba<u8>,
+) {
+    let left = (center.0 - f64::from(font.text_width(text)) / 2.0).round();
+    let top = (center.1 - f64::from(font.char_height()) / 2.0).round();
+    let width = font.char_width() as usize;
+
+    for (i, c) in text.chars().enumerate() {
+        let glyph_x = left + (i as f64) * f64::from(font.char_width() + font.spacing());
+        for (bit, _) in font.glyph(c).iter().enumerate().filter(|(_, on)| **on) {
+            put_clipped(
+                img,
+                glyph_x + (bit % width) as f64,
+                top + (bit / width) as f64,
+                color,
+            );
+        }
+    }
+}
+
 /// Blend two color channels with alpha
 #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
 fn blend_channel(base: u8, overlay: u8, alpha: f32) -> u8 {
@@ -770,6 +1040,92 @@ mod tests {
         }
     }
 
+    mod arrow_and_measurement_tests {
+        use super::*;
+
+        const WHITE: [u8; 4] = [255, 255, 255, 255];
+        const RED: [u8; 4] = [255, 0, 0, 255];
+
+        fn export_decoded(width: u32, height: u32, annotations: &[Annotation]) -> RgbaImage {
+            let exporter = PngExporter::new();
+            let screenshot = create_test_screenshot(width, height, WHITE);
+            let png = exporter
+                .export_with_annotations(&screenshot, annotations)
+                .unwrap();
+            image::load_from_memory(&png).unwrap().to_rgba8()
+        }
+
+        #[test]
+        fn test_arrowhead_lands_at_target() {
+            let arrow = Annotation::arrow_between((10, 50), (80, 50)).with_color(255, 0, 0, 255);
+            let img = export_decoded(100, 100, &[arrow]);
+
+            // Tip is painted exactly at the target and not beyond it
+            assert_eq!(img.get_pixel(80, 50).0, RED);
+            assert_eq!(img.get_pixel(82, 50).0, WHITE);
+            // Head is wider than the shaft just behind the tip
+            assert_eq!(img.get_pixel(74, 47).0, RED);
+            assert_eq!(img.get_pixel(74, 53).0, RED);
+            assert_eq!(img.get_pixel(40, 47).0, WHITE);
+            // Shaft runs back to the tail
+            assert_eq!(img.get_pixel(10, 50).0, RED);
+            assert_eq!(img.get_pixel(40, 50).0, RED);
+        }
+
+        #[test]
+        fn test_arrow_pointing_up_left() {
+            let arrow = Annotation::arrow_between((90, 90), (20, 20)).with_color(255, 0, 0, 255);
+            let img = export_decoded(100, 100, &[arrow]);
+
+            assert_eq!(img.get_pixel(20, 20).0, RED);
+            assert_eq!(img.get_pixel(18, 18).0, WHITE);
+            assert_eq!(img.get_pixel(55, 55).0, RED);
+        }
+
+        #[test]
+        fn test_arrow_clipped_to_image_bounds() {
+            let arrow = Annotation::arrow_between((50, 50), (5000, 50))
+                .with_color(255, 0, 0, 255)
+                .with_label("off-screen");
+            let img = export_decoded(100, 100, &[arrow]);
+
+            assert_eq!(img.get_pixel(99, 50).0, RED);
+            assert_eq!(img.get_pixel(50, 50).0, RED);
+        }
+
+        #[test]
+        fn test_measurement_draws_ticks_and_label() {
+            let measurement =
+                Annotation::measurement((20, 60), (80, 60), "60PX").with_color(255, 0, 0, 255);
+            assert!(matches!(
+                measurement.kind,
+                AnnotationKind::Measurement { to: (80, 60) }
+            ));
+            let img = export_decoded(100, 100, &[measurement]);
+
+            // Dimension line and both end ticks
+            assert_eq!(img.get_pixel(50, 60).0, RED);
+            assert_eq!(img.get_pixel(20, 56).0, RED);
+            assert_eq!(img.get_pixel(80, 64).0, RED);
+            // Label rendered above the line's midpoint
+            let label_pixels = (40..60)
+                .flat_map(|x| (45..56).map(move |y| (x, y)))
+                .filter(|&(x, y)| img.get_pixel(x, y).0 == RED)
+                .count();
+            assert!(
+                label_pixels > 10,
+                "expected label pixels, got {label_pixels}"
+            );
+        }
+
+        #[test]
+        fn test_measurement_outside_image_is_ignored() {
+            let measurement = Annotation::measurement((500, 500), (600, 500), "X");
+            let img = export_decoded(50, 50, &[measurement]);
+            assert!(img.pixels().all(|p| p.0 == WHITE));
+        }
+    }
+
     mod exporter_edge_cases {
         use super::*;
 
@@ -10,6 +10,7 @@
 
 use crate::driver::Screenshot;
 use crate::media::png_exporter::{Annotation, AnnotationKind};
+use crate::pixel_coverage::{ColorPalette, CoverageCell};
 use crate::result::{ProbarError, ProbarResult};
 use std::fmt::Write as FmtWrite;
 use std::fs;
@@ -43,6 +44,8 @@ pub struct SvgConfig {
     pub title: Option<String>,
     /// Description for accessibility
     pub description: Option<String>,
+    /// Embed source data as JSON `<metadata>` and `data-*` attributes
+    pub embed_metadata: bool,
 }
 
 impl Default for SvgConfig {
@@ -55,6 +58,7 @@ impl Default for SvgConfig {
             include_xml_declaration: true,
             title: None,
             description: None,
+            embed_metadata: false,
         }
     }
 }
@@ -71,6 +75,7 @@ impl SvgConfig {
             include_xml_declaration: true,
             title: None,
             description: None,
+            embed_metadata: false,
         }
     }
 
@@ -102,6 +107,13 @@ impl SvgConfig {
         self
     }
 
+    /// Set metadata embedding for data-backed exports
+    #[must_use]
+    pub const fn with_embedded_metadata(mut self, embed: bool) -> Self {
+        self.embed_metadata = embed;
+        self
+    }
+
     /// Set title
     #[must_use]
     pub fn with_title(mut self, title: impl Into<String>) -> Self {
@@ -330,6 +342,115 @@ impl SvgExporter {
         Ok(svg)
     }
 
+    /// Export a coverage grid as colored cells
+    ///
+    /// With `embed_metadata` enabled, the cell values are also written as JSON
+    /// inside `<metadata id="coverage-data">` and as `data-*` attributes on each
+    /// cell, so dashboards can show exact numbers without a separate data file.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if rendering or JSON serialization fails
+    pub fn from_coverage_cells(&self, cells: &[Vec<CoverageCell>]) -> ProbarResult<String> {
+        macro_rules! w {
+            ($($arg:tt)*) => {
+                write!($($arg)*).map_err(|e| ProbarError::ImageProcessing { message: e.to_string() })
+            };
+        }
+
+        let mut svg = String::with_capacity(4096);
+        let newline = self.newline();
+        let indent = self.indent();
+
+        if self.config.include_xml_declaration {
+            svg.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+            svg.push_str(newline);
+        }
+
+        let (width, height) = self.config.viewbox;
+        let preserve_aspect = if self.config.preserve_aspect_ratio {
+            "xMidYMid meet"
+        } else {
+            "none"
+        };
+        w!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+             width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" \
+             preserveAspectRatio=\"{preserve_aspect}\">"
+        )?;
+        svg.push_str(newline);
+
+        if let Some(ref title) = self.config.title {
+            w!(svg, "{indent}<title>{}</title>", escape_xml(title))?;
+            svg.push_str(newline);
+        }
+        if let Some(ref desc) = self.config.description {
+            w!(svg, "{indent}<desc>{}</desc>", escape_xml(desc))?;
+            svg.push_str(newline);
+        }
+
+        let rows = cells.len();
+        let cols = cells.iter().map(Vec::len).max().unwrap_or(0);
+
+        if self.config.embed_metadata {
+            let json = serde_json::json!({
+                "rows": rows,
+                "cols": cols,
+                "cells": cells,
+            });
+            w!(
+                svg,
+                "{indent}<metadata id=\"coverage-data\">{}</metadata>",
+                escape_xml(&json.to_string())
+            )?;
+            svg.push_str(newline);
+        }
+
+        if rows > 0 && cols > 0 {
+            let cell_width = f64::from(width) / cols as f64;
+            let cell_height = f64::from(height) / rows as f64;
+            let palette = ColorPalette::default();
+
+            w!(svg, "{indent}<g id=\"coverage-cells\">")?;
+            svg.push_str(newline);
+            for (row, row_cells) in cells.iter().enumerate() {
+                for (col, cell) in row_cells.iter().enumerate() {
+                    let color = palette.color_for_coverage(cell.coverage);
+                    w!(
+                        svg,
+                        "{indent}{indent}<rect x=\"{}\" y=\"{}\" width=\"{cell_width}\" \
+                         height=\"{cell_height}\" fill=\"rgb({},{},{})\"",
+                        col as f64 * cell_width,
+                        row as f64 * cell_height,
+                        color.r,
+                        color.g,
+                        color.b
+                    )?;
+                    if self.config.embed_metadata {
+                        w!(
+                            svg,
+                            " data-row=\"{row}\" data-col=\"{col}\" \
+                             data-coverage=\"{}\" data-hits=\"{}\"",
+                            cell.coverage,
+                            cell.hit_count
+                        )?;
+                    }
+                    w!(svg, "/>")?;
+                    svg.push_str(newline);
+                }
+            }
+            w!(svg, "{indent}</g>")?;
+            svg.push_str(newline);
+        }
+
+        svg.push_str("</svg>");
+        svg.push_str(newline);
+
+        Ok(svg)
+    }
+
     /// Save SVG to file
     ///
     /// # Errors
@@ -403,12 +524,17 @@ impl SvgExporter {
                     message: e.to_string(),
                 })?;
             }
-            AnnotationKind::Arrow => {
-                // Arrow from (x, y) to (x + width, y + height)
+            AnnotationKind::Arrow | AnnotationKind::ArrowTo { .. } => {
+                // Arrow from (x, y) to (x + width, y + height) or to the explicit target
                 let x1 = annotation.x;
                 let y1 = annotation.y;
-                let x2 = annotation.x + annotation.width;
-                let y2 = annotation.y + annotation.height;
+                let (x2, y2) = match annotation.kind {
+                    AnnotationKind::ArrowTo { to } => to,
+                    _ => (
+                        annotation.x + annotation.width,
+                        annotation.y + annotation.height,
+                    ),
+                };
                 write!(
                     svg,
                     "{indent}<defs>{newline}\
@@ -425,6 +551,35 @@ impl SvgExporter {
                     message: e.to_string(),
                 })?;
             }
+            AnnotationKind::Measurement { to: (x2, y2) } => {
+                // Dimension line with perpendicular end ticks
+                let (x1, y1) = (f64::from(annotation.x), f64::from(annotation.y));
+                let (x2, y2) = (f64::from(x2), f64::from(y2));
+                let length = (x2 - x1).hypot(y2 - y1).max(f64::EPSILON);
+                let (nx, ny) = (-(y2 - y1) / length * 4.0, (x2 - x1) / length * 4.0);
+                write!(
+                    svg,
+                    "{indent}<line x1=\"{x1}\" y1=\"{y1}\" x2=\"{x2}\" y2=\"{y2}\" \
+                     stroke=\"{color}\" stroke-width=\"1\"/>"
+                )
+                .map_err(|e| ProbarError::ImageProcessing {
+                    message: e.to_string(),
+                })?;
+                for (px, py) in [(x1, y1), (x2, y2)] {
+                    write!(
+                        svg,
+                        "{newline}{indent}<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" \
+                         stroke=\"{color}\" stroke-width=\"1\"/>",
+                        px - nx,
+                        py - ny,
+                        px + nx,
+                        py + ny
+                    )
+                    .map_err(|e| ProbarError::ImageProcessing {
+                        message: e.to_string(),
+                    })?;
+                }
+            }
             AnnotationKind::Highlight => {
                 // Semi-transparent highlight
                 write!(
@@ -1849,6 +2004,26 @@ mod tests {
             assert!(svg.contains("Arrow Label"));
         }
 
+        #[test]
+        fn test_annotation_arrow_to_and_measurement() {
+            let screenshot = test_screenshot();
+            let annotations = vec![
+                Annotation::arrow_between((90, 90), (20, 30)),
+                Annotation::measurement((10, 50), (70, 50), "60px"),
+            ];
+
+            let exporter = SvgExporter::with_config(SvgConfig::new(100, 100));
+            let svg = exporter
+                .from_screenshot_with_annotations(&screenshot, &annotations)
+                .unwrap();
+
+            assert!(svg.contains("x1=\"90\" y1=\"90\" x2=\"20\" y2=\"30\""));
+            assert!(svg.contains("x1=\"10\" y1=\"50\" x2=\"70\" y2=\"50\""));
+            // End ticks of the dimension line
+            assert!(svg.contains("x1=\"10\" y1=\"46\" x2=\"10\" y2=\"54\""));
+            assert!(svg.contains("60px"));
+        }
+
         #[test]
         fn test_annotation_highlight_with_label() {
             let screenshot = test_screenshot();
@@ -2403,4 +2578,108 @@ mod tests {
             assert!(debug.contains("Minified"));
         }
     }
+
+    mod coverage_metadata_tests {
+        use super::*;
+
+        fn sample_cells() -> Vec<Vec<CoverageCell>> {
+            vec![
+                vec![
+                    CoverageCell {
+                        hit_count: 0,
+                        coverage: 0.0,
+                    },
+                    CoverageCell {
+                        hit_count: 3,
+                        coverage: 0.375,
+                    },
+                ],
+                vec![
+                    CoverageCell {
+                        hit_count: 12,
+                        coverage: 1.0,
+                    },
+                    CoverageCell {
+                        hit_count: 1,
+                        coverage: 0.1,
+                    },
+                ],
+            ]
+        }
+
+        fn unescape_xml(s: &str) -> String {
+            s.replace("&quot;", "\"")
+                .replace("&apos;", "'")
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&amp;", "&")
+        }
+
+        #[test]
+        fn test_metadata_matches_input_cells() {
+            let cells = sample_cells();
+            let exporter = SvgExporter::with_config(
+                SvgConfig::new(200, 100)
+                    .with_embedded_metadata(true)
+                    .with_title("Coverage <dashboard> & \"friends\""),
+            );
+            let svg = exporter.from_coverage_cells(&cells).unwrap();
+
+            let open = "<metadata id=\"coverage-data\">";
+            let start = svg.find(open).unwrap() + open.len();
+            let end = svg[start..].find("</metadata>").unwrap() + start;
+            let body = &svg[start..end];
+            assert!(!body.contains('<') && !body.contains('"'));
+
+            let json: serde_json::Value = serde_json::from_str(&unescape_xml(body)).unwrap();
+            assert_eq!(json["rows"], 2);
+            assert_eq!(json["cols"], 2);
+            let parsed: Vec<Vec<CoverageCell>> =
+                serde_json::from_value(json["cells"].clone()).unwrap();
+            for (row, expected) in parsed.iter().zip(&cells) {
+                for (cell, want) in row.iter().zip(expected) {
+                    assert_eq!(cell.hit_count, want.hit_count);
+                    assert_eq!(cell.coverage, want.coverage);
+                }
+            }
+
+            assert!(
+                svg.contains("<title>Coverage &lt;dashboard&gt; &amp; &quot;friends&quot;</title>")
+            );
+            assert!(
+                svg.contains("data-row=\"1\" data-col=\"0\" data-coverage=\"1\" data-hits=\"12\"")
+            );
+            assert!(svg.contains("data-coverage=\"0.375\" data-hits=\"3\""));
+            assert_eq!(svg.matches("<rect").count(), 4);
+            assert!(svg.trim_end().ends_with("</svg>"));
+        }
+
+        #[test]
+        fn test_metadata_disabled_by_default() {
+            let svg = SvgExporter::new()
+                .from_coverage_cells(&sample_cells())
+                .unwrap();
+
+            assert!(!svg.contains("<metadata"));
+            assert!(!svg.contains("data-coverage"));
+            assert_eq!(svg.matches("<rect").count(), 4);
+        }
+
+        #[test]
+        fn test_empty_cells_still_emit_metadata() {
+            let exporter =
+                SvgExporter::with_config(SvgConfig::default().with_embedded_metadata(true));
+            let svg = exporter.from_coverage_cells(&[]).unwrap();
+
+            let body = svg
+                .split("<metadata id=\"coverage-data\">")
+                .nth(1)
+                .and_then(|rest| rest.split("</metadata>").next())
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_str(&unescape_xml(body)).unwrap();
+            assert_eq!(json["rows"], 0);
+            assert_eq!(json["cells"], serde_json::json!([]));
+            assert!(!svg.contains("<rect"));
+        }
+    }
 }
@@ -34,6 +34,16 @@ impl Default for VideoCodec {
     }
 }
 
+/// Rate control strategy for lossy codecs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RateControl {
+    /// Target the configured `bitrate` with the fixed `jpeg_quality`
+    #[default]
+    Bitrate,
+    /// Constant quality (CRF-like): 0 = best, 51 = worst; frame size follows content
+    ConstantQuality(u8),
+}
+
 /// Video recording state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RecordingState {
@@ -62,6 +72,9 @@ pub struct VideoConfig {
     pub max_duration_secs: u32,
     /// JPEG quality for MJPEG codec (1-100)
     pub jpeg_quality: u8,
+    /// Rate control mode (default: bitrate)
+    #[serde(default)]
+    pub rate_control: RateControl,
 }
 
 impl Default for VideoConfig {
@@ -74,6 +87,7 @@ impl Default for VideoConfig {
             codec: VideoCodec::Mjpeg,
             max_duration_secs: 300, // 5 minutes max
             jpeg_quality: 85,
+            rate_control: RateControl::Bitrate,
         }
     }
 }
@@ -124,6 +138,33 @@ impl VideoConfig {
         self
     }
 
+    /// Set rate control mode
+    #[must_use]
+    pub fn with_rate_control(mut self, rate_control: RateControl) -> Self {
+        self.rate_control = rate_control;
+        self
+    }
+
+    /// Use constant-quality rate control (CRF clamped to 0-51)
+    #[must_use]
+    pub fn with_constant_quality(self, crf: u8) -> Self {
+        self.with_rate_control(RateControl::ConstantQuality(crf.min(51)))
+    }
+
+    /// JPEG quality the MJPEG encoder uses under the current rate control
+    ///
+    /// Constant quality maps CRF 0-51 linearly onto JPEG quality 100-1.
+    #[must_use]
+    pub fn effective_jpeg_quality(&self) -> u8 {
+        match self.rate_control {
+            RateControl::Bitrate => self.jpeg_quality,
+            RateControl::ConstantQuality(crf) => {
+                let crf = u32::from(crf.min(51));
+                (100 - crf * 99 / 51) as u8
+            }
+        }
+    }
+
     /// Calculate frame duration
     #[must_use]
     pub fn frame_duration(&self) -> Duration {
@@ -204,6 +245,18 @@ impl VideoRecorder {
         self.frames.len()
     }
 
+    /// Get the encoded frames captured so far
+    #[must_use]
+    pub fn frames(&self) -> &[EncodedFrame] {
+        &self.frames
+    }
+
+    /// Total encoded bytes across all captured frames
+    #[must_use]
+    pub fn total_encoded_bytes(&self) -> usize {
+        self.frames.iter().map(|frame| frame.data.len()).sum()
+    }
+
     /// Get the recording configuration
     #[must_use]
     pub fn config(&self) -> &VideoConfig {
@@ -415,7 +468,7 @@ impl VideoRecorder {
                 let mut buffer = Cursor::new(Vec::new());
                 let mut encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(
                     &mut buffer,
-                    self.config.jpeg_quality,
+                    self.config.effective_jpeg_quality(),
                 );
                 encoder
                     .encode(
@@ -2520,4 +2573,103 @@ mod tests {
             assert_eq!(recorder.frame_count(), 1);
         }
     }
+
+    mod rate_control_tests {
+        use super::*;
+
+        const SIZE: u32 = 64;
+
+        fn record_cq(crf: u8, frames: &[Vec<u8>]) -> VideoRecorder {
+            let config = VideoConfig::new(SIZE, SIZE)
+                .with_fps(60)
+                .with_constant_quality(crf);
+            let mut recorder = VideoRecorder::new(config);
+            recorder.start().unwrap();
+            for frame in frames {
+                recorder.capture_raw_frame(frame, SIZE, SIZE).unwrap();
+                std::thread::sleep(std::time::Duration::from_millis(20));
+            }
+            recorder
+        }
+
+        /// Deterministic pseudo-random noise (xorshift) for a busy scene
+        fn noise_frame(seed: u32) -> Vec<u8> {
+            let mut state = seed.max(1);
+            (0..SIZE * SIZE)
+                .flat_map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    let [r, g, b, _] = state.to_le_bytes();
+                    [r, g, b, 255]
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_default_rate_control_is_bitrate() {
+            let config = VideoConfig::default();
+            assert_eq!(config.rate_control, RateControl::Bitrate);
+            assert_eq!(config.effective_jpeg_quality(), config.jpeg_quality);
+        }
+
+        #[test]
+        fn test_crf_maps_to_jpeg_quality() {
+            let quality = |crf| {
+                VideoConfig::default()
+                    .with_constant_quality(crf)
+                    .effective_jpeg_quality()
+            };
+            assert_eq!(quality(0), 100);
+            assert_eq!(quality(51), 1);
+            assert_eq!(quality(200), 1);
+            assert!(quality(18) > quality(28));
+        }
+
+        #[test]
+        fn test_rate_control_serialization_defaults() {
+            let json = r#"{"fps":30,"width":10,"height":10,"bitrate":5000,"codec":"Mjpeg","max_duration_secs":0,"jpeg_quality":85}"#;
+            let config: VideoConfig = serde_json::from_str(json).unwrap();
+            assert_eq!(config.rate_control, RateControl::Bitrate);
+
+            let config = VideoConfig::default().with_constant_quality(30);
+            let back: VideoConfig =
+                serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+            assert_eq!(back.rate_control, RateControl::ConstantQuality(30));
+        }
+
+        #[test]
+        fn test_cq_static_scene_smaller_than_busy_scene() {
+            let static_frame = vec![90, 120, 200, 255].repeat((SIZE * SIZE) as usize);
+            let static_scene = record_cq(
+                23,
+                &[static_frame.clone(), static_frame.clone(), static_frame],
+            );
+            let busy_scene = record_cq(23, &[noise_frame(1), noise_frame(2), noise_frame(3)]);
+
+            assert_eq!(static_scene.frame_count(), 3);
+            assert_eq!(busy_scene.frame_count(), 3);
+            assert!(
+                static_scene.total_encoded_bytes() < busy_scene.total_encoded_bytes(),
+                "static {} bytes should be below busy {} bytes",
+                static_scene.total_encoded_bytes(),
+                busy_scene.total_encoded_bytes()
+            );
+        }
+
+        #[test]
+        fn test_lowest_quality_frames_are_smaller() {
+            let frames = [noise_frame(7)];
+            let best = record_cq(0, &frames);
+            let worst = record_cq(51, &frames);
+
+            let (best, worst) = (&best.frames()[0], &worst.frames()[0]);
+            assert!(
+                worst.data.len() < best.data.len(),
+                "crf 51 frame {} bytes should be below crf 0 frame {} bytes",
+                worst.data.len(),
+                best.data.len()
+            );
+        }
+    }
 }
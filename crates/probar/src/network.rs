@@ -10,10 +10,12 @@
 //! - **Jidoka**: Immediate feedback on unexpected requests
 //! - **Muda**: Only intercept relevant requests
 
+use crate::clock::Clock;
 use crate::result::{ProbarError, ProbarResult};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 // =============================================================================
 // PMAT-006: Network Features (Playwright Parity)
@@ -432,6 +434,117 @@ impl Route {
     }
 }
 
+/// Shared tracker of in-flight network requests
+///
+/// Clones share the same state, so the handle returned by
+/// [`NetworkInterception::network_activity`] can be given to a
+/// [`crate::wait::Waiter`] to resolve `networkidle` waits.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkActivity {
+    state: Arc<Mutex<ActivityState>>,
+}
+
+#[derive(Debug)]
+struct ActivityState {
+    in_flight: usize,
+    total_started: u64,
+    /// Time of the last start or finish, as measured by [`Self::now`]
+    last_activity: Option<Duration>,
+    /// Injected clock (real time since `created` is used when absent)
+    clock: Option<Clock>,
+    created: Instant,
+}
+
+impl Default for ActivityState {
+    fn default() -> Self {
+        Self {
+            in_flight: 0,
+            total_started: 0,
+            last_activity: None,
+            clock: None,
+            created: Instant::now(),
+        }
+    }
+}
+
+impl ActivityState {
+    fn now(&self) -> Duration {
+        match &self.clock {
+            Some(clock) => clock.now(),
+            None => self.created.elapsed(),
+        }
+    }
+}
+
+impl NetworkActivity {
+    /// Create a new tracker with no requests
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Measure the idle window with an injected clock instead of real time
+    ///
+    /// Clones share the clock, so this also applies to the tracker held by
+    /// a [`NetworkInterception`].
+    #[must_use]
+    pub fn with_clock(self, clock: Clock) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            // Earlier timestamps were taken on the old time base
+            state.last_activity = None;
+            state.clock = Some(clock);
+        }
+        self
+    }
+
+    /// Record that a request started
+    pub fn request_started(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.in_flight += 1;
+            state.total_started += 1;
+            state.last_activity = Some(state.now());
+        }
+    }
+
+    /// Record that a request finished (or failed)
+    pub fn request_finished(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.in_flight = state.in_flight.saturating_sub(1);
+            state.last_activity = Some(state.now());
+        }
+    }
+
+    /// Number of requests currently in flight
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.state.lock().map(|s| s.in_flight).unwrap_or(0)
+    }
+
+    /// Total number of requests started
+    #[must_use]
+    pub fn total_started(&self) -> u64 {
+        self.state.lock().map(|s| s.total_started).unwrap_or(0)
+    }
+
+    /// Check if no request has been in flight for at least `threshold`
+    ///
+    /// The window restarts whenever a request starts or finishes, so a
+    /// request that starts and completes inside the window still delays idle.
+    #[must_use]
+    pub fn is_idle(&self, threshold: Duration) -> bool {
+        let Ok(state) = self.state.lock() else {
+            return false;
+        };
+        if state.in_flight > 0 {
+            return false;
+        }
+        match state.last_activity {
+            Some(last) => state.now().saturating_sub(last) >= threshold,
+            None => true,
+        }
+    }
+}
+
 /// Network interception handler
 #[derive(Debug)]
 pub struct NetworkInterception {
@@ -447,6 +560,8 @@ pub struct NetworkInterception {
     start_time: std::time::Instant,
     /// Block unmatched requests
     block_unmatched: bool,
+    /// In-flight request tracking
+    activity: NetworkActivity,
 }
 
 impl Default for NetworkInterception {
@@ -466,6 +581,7 @@ impl NetworkInterception {
             active: false,
             start_time: std::time::Instant::now(),
             block_unmatched: false,
+            activity: NetworkActivity::new(),
         }
     }
 
@@ -555,6 +671,21 @@ impl NetworkInterception {
 
         let timestamp_ms = self.start_time.elapsed().as_millis() as u64;
 
+        // Mocked requests complete synchronously but still count as activity
+        self.activity.request_started();
+        let response = self.route_request(url, method, headers, body, timestamp_ms);
+        self.activity.request_finished();
+        response
+    }
+
+    fn route_request(
+        &mut self,
+        url: &str,
+        method: HttpMethod,
+        headers: HashMap<String, String>,
+        body: Option<Vec<u8>>,
+        timestamp_ms: u64,
+    ) -> Option<MockResponse> {
         // Capture the request
         if self.capture_all {
             let mut request = CapturedRequest::new(url, method, timestamp_ms);
@@ -592,6 +723,22 @@ impl NetworkInterception {
         }
     }
 
+    /// Record that a pass-through request started
+    pub fn request_started(&self) {
+        self.activity.request_started();
+    }
+
+    /// Record that a pass-through request finished
+    pub fn request_finished(&self) {
+        self.activity.request_finished();
+    }
+
+    /// Get a shared handle to the in-flight request tracker
+    #[must_use]
+    pub fn network_activity(&self) -> NetworkActivity {
+        self.activity.clone()
+    }
+
     /// Get all captured requests
     #[must_use]
     pub fn captured_requests(&self) -> Vec<CapturedRequest> {
@@ -1243,6 +1390,50 @@ mod tests {
         }
     }
 
+    mod network_activity_tests {
+        use super::*;
+
+        #[test]
+        fn test_activity_counts_in_flight() {
+            let activity = NetworkActivity::new();
+            assert!(activity.is_idle(Duration::from_secs(60)));
+
+            activity.request_started();
+            activity.request_started();
+            assert_eq!(activity.in_flight(), 2);
+            assert!(!activity.is_idle(Duration::ZERO));
+
+            activity.request_finished();
+            activity.request_finished();
+            activity.request_finished();
+            assert_eq!(activity.in_flight(), 0);
+            assert_eq!(activity.total_started(), 2);
+            assert!(activity.is_idle(Duration::ZERO));
+            // The last finish restarted the quiet window.
+            assert!(!activity.is_idle(Duration::from_secs(60)));
+        }
+
+        #[test]
+        fn test_handle_request_records_activity() {
+            let mut interception = NetworkInterception::new();
+            interception.get("/api", MockResponse::text("ok"));
+            let activity = interception.network_activity();
+
+            // Inactive interception does not see requests
+            interception.handle_request("https://x.com/api", HttpMethod::Get, HashMap::new(), None);
+            assert_eq!(activity.total_started(), 0);
+
+            interception.start();
+            interception.handle_request("https://x.com/api", HttpMethod::Get, HashMap::new(), None);
+            interception.request_started();
+            assert_eq!(activity.total_started(), 2);
+            assert_eq!(activity.in_flight(), 1);
+
+            interception.request_finished();
+            assert_eq!(activity.in_flight(), 0);
+        }
+    }
+
     mod network_interception_builder_tests {
         use super::*;
 
@@ -265,6 +265,60 @@ pub trait HeatmapRenderer {
     fn render(&self, cells: &[Vec<CoverageCell>]) -> String;
 }
 
+/// Screenshot the PNG heatmap is alpha-blended over
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeatmapBackground {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Row-major pixel data
+    pub pixels: Vec<Rgb>,
+}
+
+impl HeatmapBackground {
+    /// Create a background from row-major pixel data
+    #[must_use]
+    pub fn new(width: u32, height: u32, pixels: Vec<Rgb>) -> Self {
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Create a background filled with a single color
+    #[must_use]
+    pub fn solid(width: u32, height: u32, color: Rgb) -> Self {
+        Self::new(width, height, vec![color; (width * height) as usize])
+    }
+
+    /// Create a background from a decoded screenshot
+    #[cfg(feature = "media")]
+    #[must_use]
+    pub fn from_image(img: &image::RgbImage) -> Self {
+        let pixels = img.pixels().map(|p| Rgb::new(p[0], p[1], p[2])).collect();
+        Self::new(img.width(), img.height(), pixels)
+    }
+
+    /// Decode a background from PNG (or any format `image` understands)
+    #[cfg(feature = "media")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, std::io::Error> {
+        let img = image::load_from_memory(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(Self::from_image(&img.to_rgb8()))
+    }
+
+    /// Get the pixel at (x, y)
+    #[must_use]
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Rgb> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.pixels.get((y * self.width + x) as usize).copied()
+    }
+}
+
 /// PNG heatmap export with trueno-viz style output
 #[derive(Debug, Clone)]
 pub struct PngHeatmap {
@@ -292,6 +346,10 @@ pub struct PngHeatmap {
     background: Rgb,
     /// Stats panel for combined coverage display
     pub stats_panel: Option<StatsPanel>,
+    /// Screenshot to composite the heatmap over
+    background_image: Option<HeatmapBackground>,
+    /// Heatmap opacity when composited over a background image
+    overlay_opacity: f32,
 }
 
 impl Default for PngHeatmap {
@@ -317,6 +375,8 @@ impl PngHeatmap {
             margin: 40,
             background: Rgb::new(255, 255, 255),
             stats_panel: None,
+            background_image: None,
+            overlay_opacity: 0.5,
         }
     }
 
@@ -334,6 +394,25 @@ impl PngHeatmap {
         self
     }
 
+    /// Composite the heatmap over a screenshot instead of a solid color
+    ///
+    /// The background must have the same dimensions as the heatmap. In
+    /// overlay mode the grid spans the whole image so cells line up with the
+    /// UI underneath; margins, title, legend and stats panel are not drawn.
+    #[must_use]
+    pub fn with_background_image(mut self, background: HeatmapBackground) -> Self {
+        self.background_image = Some(background);
+        self
+    }
+
+    /// Set heatmap opacity used when compositing over a background image
+    /// (0.0 = background only, 1.0 = heatmap only)
+    #[must_use]
+    pub fn with_overlay_opacity(mut self, opacity: f32) -> Self {
+        self.overlay_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
     /// Set border color
     #[must_use]
     pub fn with_border_color(mut self, color: Rgb) -> Self {
@@ -403,12 +482,99 @@ impl PngHeatmap {
         self
     }
 
+    /// Alpha-blend the heatmap over the background image
+    ///
+    /// Returns row-major pixels with the background's dimensions. Fails if no
+    /// background image is set or its dimensions do not match the heatmap.
+    pub fn composite(&self, cells: &[Vec<CoverageCell>]) -> Result<Vec<Rgb>, std::io::Error> {
+        let Some(background) = &self.background_image else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "no background image set",
+            ));
+        };
+        if background.width != self.width || background.height != self.height {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "background image is {}x{} but heatmap is {}x{}",
+                    background.width, background.height, self.width, self.height
+                ),
+            ));
+        }
+        let expected = (self.width * self.height) as usize;
+        if background.pixels.len() != expected {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "background image has {} pixels, expected {} for {}x{}",
+                    background.pixels.len(),
+                    expected,
+                    self.width,
+                    self.height
+                ),
+            ));
+        }
+
+        let rows = cells.len();
+        let cols = cells.first().map_or(0, Vec::len);
+        if rows == 0 || cols == 0 {
+            return Ok(background.pixels.clone());
+        }
+
+        let gap_color = Rgb::new(255, 0, 0);
+        let mut out = Vec::with_capacity(expected);
+        for y in 0..self.height {
+            let row = (y as usize * rows) / self.height as usize;
+            let y_start = (row * self.height as usize).div_ceil(rows) as u32;
+            let y_end = ((row + 1) * self.height as usize).div_ceil(rows) as u32;
+            for x in 0..self.width {
+                let col = (x as usize * cols) / self.width as usize;
+                let bg = background.pixels[(y * self.width + x) as usize];
+                let Some(cell) = cells[row].get(col) else {
+                    out.push(bg);
+                    continue;
+                };
+
+                if self.highlight_gaps && cell.coverage <= 0.0 {
+                    // Gap outlines stay opaque so they are visible on busy UIs
+                    let x_start = (col * self.width as usize).div_ceil(cols) as u32;
+                    let x_end = ((col + 1) * self.width as usize).div_ceil(cols) as u32;
+                    let on_edge =
+                        x < x_start + 3 || x + 3 >= x_end || y < y_start + 3 || y + 3 >= y_end;
+                    if on_edge {
+                        out.push(gap_color);
+                        continue;
+                    }
+                }
+
+                let color = self.palette.interpolate(cell.coverage);
+                out.push(Rgb::lerp(bg, color, self.overlay_opacity));
+            }
+        }
+        Ok(out)
+    }
+
     /// Export to PNG bytes (trueno-viz style with margins)
+    ///
+    /// If a background image is set, exports the composited overlay instead.
     #[cfg(feature = "media")]
     pub fn export(&self, cells: &[Vec<CoverageCell>]) -> Result<Vec<u8>, std::io::Error> {
         use image::{ImageBuffer, Rgb as ImageRgb, RgbImage};
         use std::io::Cursor;
 
+        if self.background_image.is_some() {
+            let pixels = self.composite(cells)?;
+            let mut img: RgbImage = ImageBuffer::new(self.width, self.height);
+            for (pixel, color) in img.pixels_mut().zip(pixels) {
+                *pixel = ImageRgb([color.r, color.g, color.b]);
+            }
+            let mut buffer = Cursor::new(Vec::new());
+            img.write_to(&mut buffer, image::ImageFormat::Png)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+            return Ok(buffer.into_inner());
+        }
+
         let rows = cells.len();
         let cols = cells.first().map_or(0, Vec::len);
 
@@ -2652,4 +2818,122 @@ mod tests {
             assert_eq!(line.chars().count(), 3);
         }
     }
+
+    // =========================================================================
+    // Background Overlay Tests (H₀-OVL-XX)
+    // =========================================================================
+
+    fn split_cells() -> Vec<Vec<CoverageCell>> {
+        vec![vec![
+            CoverageCell {
+                coverage: 0.0,
+                hit_count: 0,
+            },
+            CoverageCell {
+                coverage: 1.0,
+                hit_count: 4,
+            },
+        ]]
+    }
+
+    #[test]
+    fn h0_ovl_01_blends_over_solid_background() {
+        let heatmap = PngHeatmap::new(4, 2)
+            .with_palette(ColorPalette::heat())
+            .with_background_image(HeatmapBackground::solid(4, 2, Rgb::new(100, 100, 100)))
+            .with_overlay_opacity(0.5);
+
+        let pixels = heatmap.composite(&split_cells()).unwrap();
+        assert_eq!(pixels.len(), 8);
+        // Left half: black (0% coverage) at 50% over gray => 100 * 0.5
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(pixels[y * 4 + x], Rgb::new(50, 50, 50));
+            }
+        }
+        // Right half: white (100% coverage) => 100 + 155 * 0.5
+        for y in 0..2 {
+            for x in 2..4 {
+                assert_eq!(pixels[y * 4 + x], Rgb::new(177, 177, 177));
+            }
+        }
+    }
+
+    #[test]
+    fn h0_ovl_02_opacity_extremes() {
+        let background = HeatmapBackground::solid(4, 2, Rgb::new(10, 20, 30));
+        let transparent = PngHeatmap::new(4, 2)
+            .with_palette(ColorPalette::heat())
+            .with_background_image(background.clone())
+            .with_overlay_opacity(0.0)
+            .composite(&split_cells())
+            .unwrap();
+        assert!(transparent.iter().all(|p| *p == Rgb::new(10, 20, 30)));
+
+        let opaque = PngHeatmap::new(4, 2)
+            .with_palette(ColorPalette::heat())
+            .with_background_image(background)
+            .with_overlay_opacity(2.0)
+            .composite(&split_cells())
+            .unwrap();
+        assert_eq!(opaque[0], Rgb::new(0, 0, 0));
+        assert_eq!(opaque[3], Rgb::new(255, 255, 255));
+    }
+
+    #[test]
+    fn h0_ovl_03_dimension_mismatch() {
+        let err = PngHeatmap::new(4, 2)
+            .with_background_image(HeatmapBackground::solid(8, 2, Rgb::new(0, 0, 0)))
+            .composite(&split_cells())
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert!(err.to_string().contains("8x2"));
+        assert!(err.to_string().contains("4x2"));
+
+        let short = HeatmapBackground::new(4, 2, vec![Rgb::new(0, 0, 0); 3]);
+        let err = PngHeatmap::new(4, 2)
+            .with_background_image(short)
+            .export(&split_cells())
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn h0_ovl_04_requires_background() {
+        let err = PngHeatmap::new(4, 2).composite(&split_cells()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn h0_ovl_05_export_roundtrip() {
+        let png = PngHeatmap::new(4, 2)
+            .with_palette(ColorPalette::heat())
+            .with_background_image(HeatmapBackground::solid(4, 2, Rgb::new(100, 100, 100)))
+            .export(&split_cells())
+            .unwrap();
+        let decoded = HeatmapBackground::from_bytes(&png).unwrap();
+        assert_eq!(decoded.width, 4);
+        assert_eq!(decoded.height, 2);
+        assert_eq!(decoded.pixel(0, 0), Some(Rgb::new(50, 50, 50)));
+        assert_eq!(decoded.pixel(3, 1), Some(Rgb::new(177, 177, 177)));
+        assert_eq!(decoded.pixel(4, 0), None);
+    }
+
+    #[test]
+    fn h0_ovl_06_gap_outline_is_opaque() {
+        let cells = vec![vec![CoverageCell {
+            coverage: 0.0,
+            hit_count: 0,
+        }]];
+        let pixels = PngHeatmap::new(10, 10)
+            .with_palette(ColorPalette::heat())
+            .with_gap_highlighting()
+            .with_background_image(HeatmapBackground::solid(10, 10, Rgb::new(100, 100, 100)))
+            .composite(&cells)
+            .unwrap();
+        assert_eq!(pixels[0], Rgb::new(255, 0, 0));
+        assert_eq!(pixels[9 * 10 + 9], Rgb::new(255, 0, 0));
+        // Interior is blended, not outlined
+        assert_eq!(pixels[5 * 10 + 5], Rgb::new(50, 50, 50));
+    }
 }
@@ -107,6 +107,75 @@ impl SsimMetric {
         }
     }
 
+    /// Compute local SSIM over non-overlapping `window_size` windows
+    ///
+    /// Windows on the right and bottom edges may be smaller. Each score is
+    /// the mean of the per-channel SSIM for that window, so low-scoring
+    /// windows locate the regions that drag the global score down.
+    #[must_use]
+    pub fn ssim_map(
+        &self,
+        reference: &[Rgb],
+        generated: &[Rgb],
+        width: u32,
+        height: u32,
+    ) -> SsimMap {
+        let window = self.window_size.max(1);
+        let columns = width.div_ceil(window);
+        let rows = height.div_ceil(window);
+        let expected_len = (width * height) as usize;
+        let valid = reference.len() == expected_len && generated.len() == expected_len;
+
+        let mut scores = Vec::with_capacity((columns * rows) as usize);
+        for row in 0..rows {
+            for column in 0..columns {
+                if !valid {
+                    scores.push(0.0);
+                    continue;
+                }
+                let x0 = column * window;
+                let y0 = row * window;
+                let x1 = (x0 + window).min(width);
+                let y1 = (y0 + window).min(height);
+                let indices: Vec<usize> = (y0..y1)
+                    .flat_map(|y| (x0..x1).map(move |x| (y * width + x) as usize))
+                    .collect();
+
+                let channel = |image: &[Rgb], pick: fn(&Rgb) -> u8| -> Vec<f32> {
+                    indices.iter().map(|&i| pick(&image[i]) as f32).collect()
+                };
+                let r = self.calculate_channel_ssim(
+                    &channel(reference, |p| p.r),
+                    &channel(generated, |p| p.r),
+                    x1 - x0,
+                    y1 - y0,
+                );
+                let g = self.calculate_channel_ssim(
+                    &channel(reference, |p| p.g),
+                    &channel(generated, |p| p.g),
+                    x1 - x0,
+                    y1 - y0,
+                );
+                let b = self.calculate_channel_ssim(
+                    &channel(reference, |p| p.b),
+                    &channel(generated, |p| p.b),
+                    x1 - x0,
+                    y1 - y0,
+                );
+                scores.push((r + g + b) / 3.0);
+            }
+        }
+
+        SsimMap {
+            window_size: window,
+            width,
+            height,
+            columns,
+            rows,
+            scores,
+        }
+    }
+
     /// Calculate SSIM for a single channel
     fn calculate_channel_ssim(
         &self,
@@ -160,6 +229,52 @@ impl SsimMetric {
     }
 }
 
+/// Local SSIM scores on a grid of windows
+#[derive(Debug, Clone)]
+pub struct SsimMap {
+    /// Window size in pixels
+    pub window_size: u32,
+    /// Image width in pixels
+    pub width: u32,
+    /// Image height in pixels
+    pub height: u32,
+    /// Number of windows per row
+    pub columns: u32,
+    /// Number of window rows
+    pub rows: u32,
+    /// Mean-channel SSIM per window, row-major
+    pub scores: Vec<f32>,
+}
+
+impl SsimMap {
+    /// Get the score of the window at (`column`, `row`)
+    #[must_use]
+    pub fn get(&self, column: u32, row: u32) -> Option<f32> {
+        if column >= self.columns || row >= self.rows {
+            return None;
+        }
+        self.scores
+            .get((row * self.columns + column) as usize)
+            .copied()
+    }
+
+    /// Pixel bounds `(x, y, width, height)` of the window at (`column`, `row`)
+    #[must_use]
+    pub fn window_bounds(&self, column: u32, row: u32) -> (u32, u32, u32, u32) {
+        let x = column * self.window_size;
+        let y = row * self.window_size;
+        let w = self.window_size.min(self.width.saturating_sub(x));
+        let h = self.window_size.min(self.height.saturating_sub(y));
+        (x, y, w, h)
+    }
+
+    /// Lowest window score (1.0 for an empty map)
+    #[must_use]
+    pub fn min_score(&self) -> f32 {
+        self.scores.iter().copied().fold(1.0, f32::min)
+    }
+}
+
 // ============================================================================
 // PSNR - Peak Signal-to-Noise Ratio
 // ============================================================================
@@ -829,6 +944,39 @@ mod tests {
         assert!(result.is_acceptable);
     }
 
+    #[test]
+    fn h0_ssim_map_locates_changed_window() {
+        let reference = test_image_gray(16 * 16);
+        let mut generated = reference.clone();
+        // Checkerboard patch inside the window at column 1, row 0
+        for y in 2..6 {
+            for x in 10..14 {
+                if (x + y) % 2 == 0 {
+                    generated[y * 16 + x] = Rgb::new(0, 0, 0);
+                }
+            }
+        }
+
+        let map = SsimMetric::new(8).ssim_map(&reference, &generated, 16, 16);
+        assert_eq!((map.columns, map.rows), (2, 2));
+        assert!(map.get(0, 0).unwrap() >= 0.99);
+        assert!(map.get(1, 0).unwrap() < 0.5);
+        assert!(map.get(0, 1).unwrap() >= 0.99);
+        assert!(map.get(1, 1).unwrap() >= 0.99);
+        assert!(map.get(2, 0).is_none());
+        assert_eq!(map.window_bounds(1, 0), (8, 0, 8, 8));
+        assert!(map.min_score() < 0.5);
+    }
+
+    #[test]
+    fn h0_ssim_map_partial_edge_windows() {
+        let img = test_image_white(10 * 5);
+        let map = SsimMetric::new(4).ssim_map(&img, &img, 10, 5);
+        assert_eq!((map.columns, map.rows), (3, 2));
+        assert_eq!(map.window_bounds(2, 1), (8, 4, 2, 1));
+        assert!(map.scores.iter().all(|&s| s >= 0.99));
+    }
+
     #[test]
     fn h0_ssim_04_mismatched_lengths() {
         let img1 = test_image_white(100);
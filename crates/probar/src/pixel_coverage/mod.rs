@@ -22,11 +22,12 @@ pub use falsification::{
     FalsificationCondition, FalsificationLayer, GateResult,
 };
 pub use heatmap::{
-    BitmapFont, ColorPalette, HeatmapRenderer, PngHeatmap, Rgb, StatsPanel, TerminalHeatmap,
+    BitmapFont, ColorPalette, HeatmapBackground, HeatmapRenderer, PngHeatmap, Rgb, StatsPanel,
+    TerminalHeatmap,
 };
 pub use metrics::{
     CieDe2000Metric, DeltaEClassification, DeltaEResult, Lab, PerceptualHash, PhashAlgorithm,
-    PixelVerificationResult, PixelVerificationSuite, PsnrMetric, PsnrQuality, PsnrResult,
+    PixelVerificationResult, PixelVerificationSuite, PsnrMetric, PsnrQuality, PsnrResult, SsimMap,
     SsimMetric, SsimResult,
 };
 pub use parallel::{
@@ -38,7 +39,7 @@ pub use terminal::{
 };
 pub use tracker::{
     CombinedCoverageReport, CoverageCell, GridConfig, LineCoverageReport, PixelCoverageReport,
-    PixelCoverageTracker, Point as PixelPoint, Region as PixelRegion,
+    PixelCoverageTracker, Point as PixelPoint, Region as PixelRegion, WeightedGap,
 };
 pub use wasm_demo::{
     wilson_confidence_interval, ConfigError as WasmDemoConfigError,
@@ -125,6 +125,21 @@ impl CoverageCell {
     }
 }
 
+/// Under-covered cell from weighted gap analysis
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WeightedGap {
+    /// Grid column
+    pub col: u32,
+    /// Grid row
+    pub row: u32,
+    /// Screen region covered by the cell
+    pub region: Region,
+    /// Accumulated interaction weight
+    pub weight: u64,
+    /// Weighted coverage (0.0 - 1.0)
+    pub coverage: f32,
+}
+
 /// Pixel coverage tracker for grid-based UI coverage
 #[derive(Debug, Clone)]
 pub struct PixelCoverageTracker {
@@ -132,6 +147,10 @@ pub struct PixelCoverageTracker {
     cells: Vec<Vec<CoverageCell>>,
     threshold: f32,
     total_interactions: u64,
+    /// Weight at which a cell counts as fully covered
+    target_weight: u64,
+    /// Whether any weighted interaction has been recorded
+    weighted: bool,
 }
 
 impl PixelCoverageTracker {
@@ -154,6 +173,8 @@ impl PixelCoverageTracker {
             cells,
             threshold: 0.8,
             total_interactions: 0,
+            target_weight: 1,
+            weighted: false,
         }
     }
 
@@ -187,20 +208,55 @@ impl PixelCoverageTracker {
         &self.config
     }
 
+    /// Get the weight at which a cell counts as fully covered
+    #[must_use]
+    pub fn target_weight(&self) -> u64 {
+        self.target_weight
+    }
+
     /// Record an interaction at a point
     pub fn record_interaction(&mut self, point: Point) {
+        self.add_interaction(point, 1);
+    }
+
+    /// Record an interaction at a point weighted by dwell time (or any
+    /// other importance measure)
+    ///
+    /// The weight accumulates into the cell's `hit_count`; the cell's
+    /// coverage is `hit_count / target_weight`, capped at 1.0.
+    pub fn record_interaction_weighted(&mut self, point: Point, weight: u64) {
+        self.weighted = true;
+        self.add_interaction(point, weight);
+    }
+
+    fn add_interaction(&mut self, point: Point, weight: u64) {
         let (col, row) = self.config.point_to_cell(point);
+        let target = self.target_weight;
         if let Some(row_cells) = self.cells.get_mut(row as usize) {
             if let Some(cell) = row_cells.get_mut(col as usize) {
-                cell.hit_count += 1;
-                cell.coverage = 1.0;
+                Self::add_weight(cell, weight, target);
                 self.total_interactions += 1;
             }
         }
     }
 
+    fn add_weight(cell: &mut CoverageCell, weight: u64, target: u64) {
+        cell.hit_count = cell.hit_count.saturating_add(weight);
+        cell.coverage = (cell.hit_count as f32 / target.max(1) as f32).min(1.0);
+    }
+
     /// Record coverage for a region
     pub fn record_region(&mut self, region: Region) {
+        self.add_region(region, 1);
+    }
+
+    /// Record coverage for a region weighted by dwell time
+    pub fn record_region_weighted(&mut self, region: Region, weight: u64) {
+        self.weighted = true;
+        self.add_region(region, weight);
+    }
+
+    fn add_region(&mut self, region: Region, weight: u64) {
         let start_col = region.x / self.config.cell_width();
         let start_row = region.y / self.config.cell_height();
         let end_col =
@@ -212,8 +268,7 @@ impl PixelCoverageTracker {
             for col in start_col..=end_col {
                 if let Some(row_cells) = self.cells.get_mut(row as usize) {
                     if let Some(cell) = row_cells.get_mut(col as usize) {
-                        cell.hit_count += 1;
-                        cell.coverage = 1.0;
+                        Self::add_weight(cell, weight, self.target_weight);
                     }
                 }
             }
@@ -272,7 +327,55 @@ impl PixelCoverageTracker {
             total_interactions: self.total_interactions,
             meets_threshold: overall_coverage >= self.threshold,
             uncovered_regions: self.find_uncovered_regions(),
+            weighted_coverage: self.weighted.then(|| self.weighted_coverage()),
+        }
+    }
+
+    /// Mean of per-cell coverage `min(hit_count / target_weight, 1.0)`
+    /// over all cells
+    ///
+    /// Every cell counts equally, so piling weight onto one cell cannot
+    /// raise the score past that cell's share of the grid.
+    #[must_use]
+    pub fn weighted_coverage(&self) -> f32 {
+        let (sum, cells) = self
+            .cells
+            .iter()
+            .flat_map(|row| row.iter())
+            .fold((0.0_f64, 0_usize), |(sum, n), c| {
+                (sum + f64::from(c.coverage), n + 1)
+            });
+        if cells == 0 {
+            return 0.0;
         }
+        (sum / cells as f64) as f32
+    }
+
+    /// Cells below full weighted coverage, weakest first
+    ///
+    /// Ties are broken by row, then column.
+    #[must_use]
+    pub fn weighted_gaps(&self) -> Vec<WeightedGap> {
+        let mut gaps: Vec<WeightedGap> = self
+            .cells
+            .iter()
+            .enumerate()
+            .flat_map(|(row_idx, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(move |(col_idx, cell)| (row_idx, col_idx, cell))
+            })
+            .filter(|(_, _, cell)| cell.coverage < 1.0)
+            .map(|(row_idx, col_idx, cell)| WeightedGap {
+                col: col_idx as u32,
+                row: row_idx as u32,
+                region: self.config.cell_to_region(col_idx as u32, row_idx as u32),
+                weight: cell.hit_count,
+                coverage: cell.coverage,
+            })
+            .collect();
+        gaps.sort_by(|a, b| a.coverage.total_cmp(&b.coverage));
+        gaps
     }
 
     /// Get list of uncovered regions
@@ -343,6 +446,7 @@ pub struct PixelCoverageTrackerBuilder {
     grid_cols: u32,
     grid_rows: u32,
     threshold: f32,
+    target_weight: u64,
 }
 
 impl Default for PixelCoverageTrackerBuilder {
@@ -353,6 +457,7 @@ impl Default for PixelCoverageTrackerBuilder {
             grid_cols: 64,
             grid_rows: 36,
             threshold: 0.8,
+            target_weight: 1,
         }
     }
 }
@@ -381,12 +486,21 @@ impl PixelCoverageTrackerBuilder {
         self
     }
 
+    /// Set the interaction weight at which a cell counts as fully covered
+    /// (e.g. 2000 for a 2-second hover when weights are milliseconds)
+    #[must_use]
+    pub fn target_weight(mut self, weight: u64) -> Self {
+        self.target_weight = weight.max(1);
+        self
+    }
+
     /// Build the tracker
     #[must_use]
     pub fn build(self) -> PixelCoverageTracker {
         let mut tracker =
             PixelCoverageTracker::new(self.width, self.height, self.grid_cols, self.grid_rows);
         tracker.threshold = self.threshold;
+        tracker.target_weight = self.target_weight;
         tracker
     }
 }
@@ -414,6 +528,9 @@ pub struct PixelCoverageReport {
     pub meets_threshold: bool,
     /// List of uncovered regions
     pub uncovered_regions: Vec<Region>,
+    /// Mean weighted coverage (present when weighted interactions were recorded)
+    #[serde(default)]
+    pub weighted_coverage: Option<f32>,
 }
 
 impl Default for PixelCoverageReport {
@@ -429,6 +546,7 @@ impl Default for PixelCoverageReport {
             total_interactions: 0,
             meets_threshold: false,
             uncovered_regions: Vec::new(),
+            weighted_coverage: None,
         }
     }
 }
@@ -654,6 +772,7 @@ mod tests {
             total_interactions: 100,
             meets_threshold: false,
             uncovered_regions: vec![],
+            weighted_coverage: None,
         };
 
         assert!((report.percent() - 75.0).abs() < f32::EPSILON);
@@ -672,6 +791,7 @@ mod tests {
             total_interactions: 100,
             meets_threshold: true,
             uncovered_regions: vec![],
+            weighted_coverage: None,
         };
 
         assert!(complete.is_complete());
@@ -811,4 +931,84 @@ mod tests {
         let png = heatmap.export(tracker.cells()).unwrap();
         assert!(!png.is_empty());
     }
+
+    // =========================================================================
+    // Weighted interaction tests
+    // =========================================================================
+
+    #[test]
+    fn h0_weighted_01_dwell_outranks_glance() {
+        let mut tracker = PixelCoverageTracker::builder()
+            .resolution(300, 100)
+            .grid_size(3, 1)
+            .target_weight(2000)
+            .build();
+        // 1.5s hover on the first cell, fleeting 200ms mouse-over on the second
+        tracker.record_interaction_weighted(Point::new(10, 10), 1500);
+        tracker.record_interaction_weighted(Point::new(150, 10), 200);
+
+        assert_eq!(tracker.cells()[0][0].hit_count, 1500);
+        assert_eq!(tracker.cells()[0][1].hit_count, 200);
+        assert!((tracker.cells()[0][0].coverage - 0.75).abs() < 1e-6);
+        assert!((tracker.cells()[0][1].coverage - 0.1).abs() < 1e-6);
+
+        let gaps = tracker.weighted_gaps();
+        let order: Vec<u32> = gaps.iter().map(|g| g.col).collect();
+        // Untouched cell is the worst gap, the glance next, the dwell last
+        assert_eq!(order, vec![2, 1, 0]);
+        assert_eq!(gaps[1].weight, 200);
+        assert_eq!(gaps[1].region, Region::new(100, 0, 100, 100));
+
+        let report = tracker.generate_report();
+        assert_eq!(report.covered_cells, 2);
+        assert_eq!(report.total_interactions, 2);
+        assert!((report.overall_coverage - 2.0 / 3.0).abs() < 1e-6);
+        let weighted = report.weighted_coverage.unwrap();
+        let expected = (0.75 + 0.1 + 0.0) / 3.0;
+        assert!((weighted - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn h0_weighted_02_region_and_saturation() {
+        let mut tracker = PixelCoverageTracker::builder()
+            .resolution(200, 100)
+            .grid_size(2, 1)
+            .target_weight(100)
+            .build();
+        tracker.record_region_weighted(Region::new(0, 0, 99, 99), 80);
+        tracker.record_region_weighted(Region::new(0, 0, 99, 99), 80);
+
+        assert_eq!(tracker.cells()[0][0].hit_count, 160);
+        assert_eq!(tracker.cells()[0][0].coverage, 1.0);
+        assert_eq!(tracker.cells()[0][1].hit_count, 0);
+        assert_eq!(tracker.weighted_gaps().len(), 1);
+        let weighted = tracker.generate_report().weighted_coverage.unwrap();
+        // Saturated cell counts once, untouched cell counts zero
+        assert!((weighted - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn h0_weighted_03_unweighted_report_unchanged() {
+        let mut tracker = PixelCoverageTracker::new(100, 100, 10, 10);
+        tracker.record_interaction(Point::new(5, 5));
+        tracker.record_interaction(Point::new(5, 5));
+
+        assert_eq!(tracker.target_weight(), 1);
+        assert_eq!(tracker.cells()[0][0].hit_count, 2);
+        assert_eq!(tracker.cells()[0][0].coverage, 1.0);
+        assert!(tracker.generate_report().weighted_coverage.is_none());
+    }
+
+    #[test]
+    fn h0_weighted_04_heavy_cell_does_not_dominate() {
+        let mut tracker = PixelCoverageTracker::builder()
+            .resolution(1000, 1000)
+            .grid_size(10, 10)
+            .target_weight(2000)
+            .build();
+        tracker.record_interaction_weighted(Point::new(5, 5), 1500);
+
+        let weighted = tracker.generate_report().weighted_coverage.unwrap();
+        assert!((weighted - 0.75 / 100.0).abs() < 1e-6);
+    }
 }
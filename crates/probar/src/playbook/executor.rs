@@ -4,7 +4,9 @@
 //! Tracks timing for complexity analysis.
 
 use super::complexity::{check_complexity_violation, ComplexityResult};
-use super::schema::{Action, Assertion, Playbook, Transition, WaitCondition};
+use super::schema::{Action, Assertion, Playbook, State, Transition, WaitCondition};
+use crate::clock::Clock;
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 /// Result of executing a playbook.
@@ -14,6 +16,9 @@ pub struct ExecutionResult {
     pub success: bool,
     /// Current state after execution
     pub final_state: String,
+    /// All active states after execution (the current state followed by the
+    /// active state of each region when inside a parallel state)
+    pub active_states: Vec<String>,
     /// Executed transitions
     pub transitions_executed: Vec<TransitionResult>,
     /// Total execution time
@@ -143,19 +148,98 @@ pub struct PlaybookExecutor<E: ActionExecutor> {
     playbook: Playbook,
     executor: E,
     current_state: String,
+    active_regions: Vec<String>,
     transition_count: usize,
+    clock: Option<Clock>,
+    started: Instant,
+    entered_at_ms: HashMap<String, u64>,
 }
 
 impl<E: ActionExecutor> PlaybookExecutor<E> {
     /// Create a new executor for the given playbook.
     pub fn new(playbook: Playbook, executor: E) -> Self {
         let initial = playbook.machine.initial.clone();
-        Self {
+        let mut runner = Self {
             playbook,
             executor,
-            current_state: initial,
+            current_state: String::new(),
+            active_regions: Vec::new(),
             transition_count: 0,
+            clock: None,
+            started: Instant::now(),
+            entered_at_ms: HashMap::new(),
+        };
+        let entered = runner.enter_state(&initial);
+        runner.mark_entered(&entered, 0);
+        runner
+    }
+
+    /// Drive timed (`after_ms`) transitions from the given clock instead of
+    /// wall-clock time, making them deterministic under a paused `FakeClock`.
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        let now = clock.now_ms();
+        self.clock = Some(clock);
+        for entered in self.entered_at_ms.values_mut() {
+            *entered = now;
+        }
+        self
+    }
+
+    /// Current time in milliseconds on the executor's clock.
+    fn now_ms(&self) -> u64 {
+        self.clock.as_ref().map_or_else(
+            || self.started.elapsed().as_millis() as u64,
+            |clock| clock.now_ms(),
+        )
+    }
+
+    /// Earliest deadline of a timed transition leaving an active state.
+    pub fn next_deadline_ms(&self) -> Option<u64> {
+        self.due_timer(u64::MAX).map(|(deadline, _, _)| deadline)
+    }
+
+    /// Fire every timed transition whose deadline has passed on the clock.
+    ///
+    /// Timers fire in deadline order, and a state entered by a timed
+    /// transition counts as entered at that deadline, so chained timers are
+    /// resolved deterministically in a single call.
+    pub fn fire_due_timers(&mut self) -> Result<Vec<TransitionResult>, ExecutorError> {
+        let now = self.now_ms();
+        let mut results = Vec::new();
+        while let Some((deadline, transition, region)) = self.due_timer(now) {
+            results.push(self.fire_transition(&transition, region, deadline)?);
+        }
+        Ok(results)
+    }
+
+    /// The earliest timed transition due at or before `now`, with its deadline
+    /// and the region it belongs to (`None` for the current state).
+    fn due_timer(&self, now: u64) -> Option<(u64, Transition, Option<usize>)> {
+        let active = std::iter::once((None, &self.current_state)).chain(
+            self.active_regions
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (Some(i), s)),
+        );
+
+        let mut earliest: Option<(u64, &Transition, Option<usize>)> = None;
+        for (region, state_id) in active {
+            let entered = self.entered_at_ms.get(state_id).copied().unwrap_or(0);
+            for transition in &self.playbook.machine.transitions {
+                let Some(after_ms) = transition.after_ms else {
+                    continue;
+                };
+                // A zero-delay self-loop would fire forever
+                if transition.from != *state_id || (after_ms == 0 && transition.to == *state_id) {
+                    continue;
+                }
+                let deadline = entered.saturating_add(after_ms);
+                if deadline <= now && earliest.map_or(true, |(best, _, _)| deadline < best) {
+                    earliest = Some((deadline, transition, region));
+                }
+            }
         }
+        earliest.map(|(deadline, transition, region)| (deadline, transition.clone(), region))
     }
 
     /// Execute the playbook by following the given event sequence.
@@ -166,34 +250,45 @@ impl<E: ActionExecutor> PlaybookExecutor<E> {
         let mut metrics = ExecutionMetrics::default();
         let mut success = true;
 
-        for event in events {
-            match self.trigger_event(event) {
-                Ok(result) => {
-                    // Track timing for complexity analysis
-                    metrics.transition_times.push((
-                        self.transition_count,
-                        result.duration.as_secs_f64() * 1000.0,
-                    ));
-
-                    // Check for assertion failures
-                    if !result.assertions_passed {
-                        for ar in &result.assertion_results {
-                            if !ar.passed {
-                                assertion_failures.push(AssertionFailure {
-                                    transition_id: result.transition_id.clone(),
-                                    assertion_description: ar.description.clone(),
-                                    error: ar.error.clone().unwrap_or_default(),
-                                });
+        // A final pass with no event fires timers that came due after the last event
+        let steps = events.iter().map(|e| Some(*e)).chain(std::iter::once(None));
+        for event in steps {
+            let outcome = self.fire_due_timers().and_then(|mut results| {
+                if let Some(event) = event {
+                    results.extend(self.trigger_event(event)?);
+                }
+                Ok(results)
+            });
+            match outcome {
+                Ok(results) => {
+                    for result in results {
+                        // Track timing for complexity analysis
+                        metrics.transition_times.push((
+                            self.transition_count,
+                            result.duration.as_secs_f64() * 1000.0,
+                        ));
+
+                        // Check for assertion failures
+                        if !result.assertions_passed {
+                            for ar in &result.assertion_results {
+                                if !ar.passed {
+                                    assertion_failures.push(AssertionFailure {
+                                        transition_id: result.transition_id.clone(),
+                                        assertion_description: ar.description.clone(),
+                                        error: ar.error.clone().unwrap_or_default(),
+                                    });
+                                }
                             }
+                            success = false;
                         }
-                        success = false;
-                    }
 
-                    transitions_executed.push(result);
+                        transitions_executed.push(result);
+                    }
                 }
                 Err(e) => {
                     assertion_failures.push(AssertionFailure {
-                        transition_id: format!("event:{}", event),
+                        transition_id: event
+                            .map_or_else(|| "timer".to_string(), |e| format!("event:{}", e)),
                         assertion_description: "Transition execution".to_string(),
                         error: e.to_string(),
                     });
@@ -221,6 +316,7 @@ impl<E: ActionExecutor> PlaybookExecutor<E> {
         ExecutionResult {
             success,
             final_state: self.current_state.clone(),
+            active_states: self.active_states().into_iter().map(String::from).collect(),
             transitions_executed,
             total_time: start.elapsed(),
             metrics,
@@ -229,78 +325,144 @@ impl<E: ActionExecutor> PlaybookExecutor<E> {
         }
     }
 
-    /// Trigger an event and execute the corresponding transition.
-    fn trigger_event(&mut self, event: &str) -> Result<TransitionResult, ExecutorError> {
-        // Find matching transition and clone necessary data to avoid borrow issues
-        let transition = self.find_transition(event)?;
-        let transition_id = transition.id.clone();
-        let from_state = transition.from.clone();
-        let to_state = transition.to.clone();
-        let transition_actions = transition.actions.clone();
-        let transition_assertions = transition.assertions.clone();
+    /// Trigger an event and execute the corresponding transitions.
+    ///
+    /// Inside a parallel state each active region fires its own matching
+    /// transition independently. If no region handles the event, the
+    /// transition from the current (parent) state is taken instead.
+    fn trigger_event(&mut self, event: &str) -> Result<Vec<TransitionResult>, ExecutorError> {
+        let now = self.now_ms();
+        let mut results = Vec::new();
+        let regions = self.active_regions.clone();
+        for (region, active) in regions.iter().enumerate() {
+            let transition = self
+                .playbook
+                .machine
+                .transitions
+                .iter()
+                .find(|t| t.from == *active && t.event == event && t.after_ms.is_none())
+                .cloned();
+            if let Some(transition) = transition {
+                results.push(self.fire_transition(&transition, Some(region), now)?);
+            }
+        }
+        if !results.is_empty() {
+            return Ok(results);
+        }
+
+        let transition = self.find_transition(event)?.clone();
+        Ok(vec![self.fire_transition(&transition, None, now)?])
+    }
 
+    /// Execute a transition, either within the given region or at the top level,
+    /// as of `at_ms` on the executor's clock.
+    fn fire_transition(
+        &mut self,
+        transition: &Transition,
+        region: Option<usize>,
+        at_ms: u64,
+    ) -> Result<TransitionResult, ExecutorError> {
         let start = Instant::now();
 
-        // Clone exit actions to avoid borrow issues
-        let exit_actions = self
-            .playbook
-            .machine
-            .states
-            .get(&self.current_state)
-            .map(|s| s.on_exit.clone())
-            .unwrap_or_default();
+        // Leaving the parent state exits its active regions first
+        let mut exiting = match region {
+            Some(index) => vec![self.active_regions[index].clone()],
+            None => self.active_regions.clone(),
+        };
+        if region.is_none() {
+            exiting.push(self.current_state.clone());
+        }
 
-        // Execute exit actions for current state
-        for action in &exit_actions {
-            self.execute_action(action)?;
+        // Execute exit actions for the states being left
+        for state_id in &exiting {
+            let exit_actions = self.state_actions(state_id, |s| &s.on_exit);
+            for action in &exit_actions {
+                self.execute_action(action)?;
+            }
         }
 
         // Execute transition actions
-        for action in &transition_actions {
+        for action in &transition.actions {
             self.execute_action(action)?;
         }
 
         // Update current state
-        self.current_state = to_state.clone();
+        let entering = match region {
+            Some(index) => {
+                self.active_regions[index] = transition.to.clone();
+                vec![transition.to.clone()]
+            }
+            None => self.enter_state(&transition.to),
+        };
+        self.mark_entered(&entering, at_ms);
         self.transition_count += 1;
 
-        // Clone entry actions to avoid borrow issues
-        let entry_actions = self
-            .playbook
-            .machine
-            .states
-            .get(&self.current_state)
-            .map(|s| s.on_entry.clone())
-            .unwrap_or_default();
-
-        // Execute entry actions for new state
-        for action in &entry_actions {
-            self.execute_action(action)?;
+        // Execute entry actions for the states being entered
+        for state_id in &entering {
+            let entry_actions = self.state_actions(state_id, |s| &s.on_entry);
+            for action in &entry_actions {
+                self.execute_action(action)?;
+            }
         }
 
         // Check assertions
-        let assertion_results = self.check_assertions(&transition_assertions);
+        let assertion_results = self.check_assertions(&transition.assertions);
         let assertions_passed = assertion_results.iter().all(|r| r.passed);
 
         let duration = start.elapsed();
 
         Ok(TransitionResult {
-            transition_id,
-            from_state,
-            to_state,
+            transition_id: transition.id.clone(),
+            from_state: transition.from.clone(),
+            to_state: transition.to.clone(),
             duration,
             assertions_passed,
             assertion_results,
         })
     }
 
+    /// Make `state_id` the current state, activating its regions if it is
+    /// parallel. Returns the states entered, parent first.
+    fn enter_state(&mut self, state_id: &str) -> Vec<String> {
+        self.current_state = state_id.to_string();
+        self.active_regions = self
+            .playbook
+            .machine
+            .states
+            .get(state_id)
+            .filter(|s| s.parallel)
+            .map(|s| s.regions.clone())
+            .unwrap_or_default();
+
+        let mut entered = vec![self.current_state.clone()];
+        entered.extend(self.active_regions.iter().cloned());
+        entered
+    }
+
+    /// Record when states were entered, for scheduling their timed transitions.
+    fn mark_entered(&mut self, states: &[String], at_ms: u64) {
+        for state_id in states {
+            self.entered_at_ms.insert(state_id.clone(), at_ms);
+        }
+    }
+
+    /// Clone the entry or exit actions of a state to avoid borrow issues.
+    fn state_actions(&self, state_id: &str, select: fn(&State) -> &[Action]) -> Vec<Action> {
+        self.playbook
+            .machine
+            .states
+            .get(state_id)
+            .map(|s| select(s).to_vec())
+            .unwrap_or_default()
+    }
+
     /// Find a transition matching the current state and event.
     fn find_transition(&self, event: &str) -> Result<&Transition, ExecutorError> {
         self.playbook
             .machine
             .transitions
             .iter()
-            .find(|t| t.from == self.current_state && t.event == event)
+            .find(|t| t.from == self.current_state && t.event == event && t.after_ms.is_none())
             .ok_or_else(|| ExecutorError::InvalidTransition {
                 state: self.current_state.clone(),
                 event: event.to_string(),
@@ -451,9 +613,20 @@ impl<E: ActionExecutor> PlaybookExecutor<E> {
         &self.current_state
     }
 
+    /// Get all active states: the current state followed by the active
+    /// state of each region when inside a parallel state.
+    pub fn active_states(&self) -> Vec<&str> {
+        std::iter::once(self.current_state.as_str())
+            .chain(self.active_regions.iter().map(String::as_str))
+            .collect()
+    }
+
     /// Reset to initial state.
     pub fn reset(&mut self) {
-        self.current_state = self.playbook.machine.initial.clone();
+        let initial = self.playbook.machine.initial.clone();
+        let entered = self.enter_state(&initial);
+        let now = self.now_ms();
+        self.mark_entered(&entered, now);
         self.transition_count = 0;
     }
 }
@@ -1055,6 +1228,180 @@ machine:
         assert_eq!(result.metrics.transition_count, 2);
     }
 
+    #[test]
+    fn test_timed_transition_fires_on_fake_clock() {
+        use crate::clock::{create_clock, ClockOptions};
+
+        let yaml = r#"
+version: "1.0"
+machine:
+  id: "loader"
+  initial: "loading"
+  states:
+    loading:
+      id: "loading"
+    ready:
+      id: "ready"
+    done:
+      id: "done"
+      final_state: true
+  transitions:
+    - id: "loaded"
+      from: "loading"
+      to: "ready"
+      after_ms: 500
+    - id: "finish"
+      from: "ready"
+      to: "done"
+      event: "finish"
+"#;
+        let playbook = Playbook::from_yaml(yaml).expect("parse");
+        let clock = create_clock();
+        clock.install(ClockOptions::fixed(1_000)).expect("install");
+        let mut runner =
+            PlaybookExecutor::new(playbook, MockExecutor::new()).with_clock(clock.clone());
+        assert_eq!(runner.next_deadline_ms(), Some(1_500));
+
+        clock.fast_forward_ms(499);
+        let result = runner.execute(&[]);
+        assert!(result.success);
+        assert_eq!(result.final_state, "loading");
+        assert!(result.transitions_executed.is_empty());
+
+        // The event is not accepted until the timer has fired
+        let result = runner.execute(&["finish"]);
+        assert!(!result.success);
+        assert_eq!(runner.current_state(), "loading");
+
+        clock.fast_forward_ms(1);
+        let result = runner.execute(&["finish"]);
+        assert!(result.success, "failures: {:?}", result.assertion_failures);
+        assert_eq!(result.final_state, "done");
+        let fired: Vec<_> = result
+            .transitions_executed
+            .iter()
+            .map(|t| t.transition_id.as_str())
+            .collect();
+        assert_eq!(fired, vec!["loaded", "finish"]);
+    }
+
+    #[test]
+    fn test_chained_timed_transitions_use_deadlines() {
+        use crate::clock::{create_clock, ClockOptions};
+
+        let yaml = r#"
+version: "1.0"
+machine:
+  id: "splash"
+  initial: "splash"
+  states:
+    splash:
+      id: "splash"
+    loading:
+      id: "loading"
+    ready:
+      id: "ready"
+      final_state: true
+  transitions:
+    - id: "t1"
+      from: "splash"
+      to: "loading"
+      after_ms: 300
+    - id: "t2"
+      from: "loading"
+      to: "ready"
+      after_ms: 500
+"#;
+        let playbook = Playbook::from_yaml(yaml).expect("parse");
+        let clock = create_clock();
+        clock.install(ClockOptions::fixed(0)).expect("install");
+        let mut runner =
+            PlaybookExecutor::new(playbook, MockExecutor::new()).with_clock(clock.clone());
+
+        clock.fast_forward_ms(700);
+        let fired = runner.fire_due_timers().expect("timers");
+        assert_eq!(fired.len(), 1);
+        assert_eq!(runner.current_state(), "loading");
+        assert_eq!(runner.next_deadline_ms(), Some(800));
+
+        // loading was entered at 300ms, so ready is due at 800ms
+        clock.fast_forward_ms(100);
+        let fired = runner.fire_due_timers().expect("timers");
+        assert_eq!(fired.len(), 1);
+        assert_eq!(runner.current_state(), "ready");
+    }
+
+    #[test]
+    fn test_parallel_regions_transition_independently() {
+        let yaml = r#"
+version: "1.0"
+machine:
+  id: "app"
+  initial: "app"
+  states:
+    app:
+      id: "app"
+      parallel: true
+      regions: ["online", "list"]
+    online:
+      id: "online"
+    offline:
+      id: "offline"
+    list:
+      id: "list"
+    detail:
+      id: "detail"
+    closed:
+      id: "closed"
+      final_state: true
+  transitions:
+    - id: "disconnect"
+      from: "online"
+      to: "offline"
+      event: "disconnect"
+    - id: "connect"
+      from: "offline"
+      to: "online"
+      event: "connect"
+    - id: "open"
+      from: "list"
+      to: "detail"
+      event: "open"
+    - id: "back"
+      from: "detail"
+      to: "list"
+      event: "back"
+    - id: "quit"
+      from: "app"
+      to: "closed"
+      event: "quit"
+"#;
+        let playbook = Playbook::from_yaml(yaml).expect("parse");
+        let executor = MockExecutor::new();
+        let mut runner = PlaybookExecutor::new(playbook, executor);
+        assert_eq!(runner.active_states(), vec!["app", "online", "list"]);
+
+        let result = runner.execute(&["open"]);
+        assert!(result.success);
+        assert_eq!(result.active_states, vec!["app", "online", "detail"]);
+
+        let result = runner.execute(&["disconnect"]);
+        assert!(result.success);
+        assert_eq!(result.active_states, vec!["app", "offline", "detail"]);
+
+        let result = runner.execute(&["back", "connect"]);
+        assert!(result.success);
+        assert_eq!(result.active_states, vec!["app", "online", "list"]);
+
+        let result = runner.execute(&["quit"]);
+        assert!(result.success);
+        assert_eq!(result.final_state, "closed");
+        assert_eq!(runner.active_states(), vec!["closed"]);
+
+        runner.reset();
+        assert_eq!(runner.active_states(), vec!["app", "online", "list"]);
+    }
+
     #[test]
     fn test_text_assertion_element_not_found() {
         let yaml = r##"
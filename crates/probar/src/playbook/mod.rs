@@ -4,7 +4,7 @@
 //! - SCXML-inspired state definitions
 //! - Transition-based assertions
 //! - O(n) complexity verification via curve fitting
-//! - M1-M5 mutation testing for falsification
+//! - M1-M6 mutation testing for falsification
 //!
 //! # References
 //! - W3C SCXML: <https://www.w3.org/TR/scxml/>
@@ -1,6 +1,6 @@
 //! Mutation testing support for playbook validation.
 //!
-//! Implements M1-M5 mutation classes for falsification protocol.
+//! Implements M1-M6 mutation classes for falsification protocol.
 //! Reference: Fabbri et al., "Mutation Testing Applied to Validate
 //! Specifications Based on Statecharts" (ISSRE 1999)
 
@@ -20,6 +20,8 @@ pub enum MutationClass {
     TargetSwap,
     /// M5: Guard negation - negate guard conditions
     GuardNegation,
+    /// M6: Guard boundary - shift numeric comparisons in guards by one
+    GuardBoundary,
 }
 
 impl MutationClass {
@@ -31,10 +33,11 @@ impl MutationClass {
             MutationClass::EventSwap,
             MutationClass::TargetSwap,
             MutationClass::GuardNegation,
+            MutationClass::GuardBoundary,
         ]
     }
 
-    /// Get the mutation class identifier (M1-M5).
+    /// Get the mutation class identifier (M1-M6).
     pub fn id(&self) -> &'static str {
         match self {
             MutationClass::StateRemoval => "M1",
@@ -42,6 +45,7 @@ impl MutationClass {
             MutationClass::EventSwap => "M3",
             MutationClass::TargetSwap => "M4",
             MutationClass::GuardNegation => "M5",
+            MutationClass::GuardBoundary => "M6",
         }
     }
 
@@ -53,6 +57,7 @@ impl MutationClass {
             MutationClass::EventSwap => "Swap event triggers between two transitions",
             MutationClass::TargetSwap => "Change a transition's target to a different state",
             MutationClass::GuardNegation => "Negate a transition's guard condition",
+            MutationClass::GuardBoundary => "Shift a numeric comparison boundary in a guard",
         }
     }
 }
@@ -125,6 +130,7 @@ impl<'a> MutationGenerator<'a> {
         mutants.extend(self.generate_event_swaps());
         mutants.extend(self.generate_target_swaps());
         mutants.extend(self.generate_guard_negations());
+        mutants.extend(self.generate_guard_boundaries());
         mutants
     }
 
@@ -136,6 +142,7 @@ impl<'a> MutationGenerator<'a> {
             MutationClass::EventSwap => self.generate_event_swaps(),
             MutationClass::TargetSwap => self.generate_target_swaps(),
             MutationClass::GuardNegation => self.generate_guard_negations(),
+            MutationClass::GuardBoundary => self.generate_guard_boundaries(),
         }
     }
 
@@ -280,6 +287,99 @@ impl<'a> MutationGenerator<'a> {
 
         mutants
     }
+
+    /// M6: Generate guard boundary mutants.
+    fn generate_guard_boundaries(&self) -> Vec<Mutant> {
+        let mut mutants = Vec::new();
+
+        for (idx, transition) in self.playbook.machine.transitions.iter().enumerate() {
+            if let Some(guard) = &transition.guard {
+                for (variant, mutated_guard) in boundary_variants(guard).into_iter().enumerate() {
+                    let mut mutated = self.playbook.clone();
+                    mutated.machine.transitions[idx].guard = Some(mutated_guard.clone());
+
+                    mutants.push(Mutant {
+                        id: format!("M6_{}_{}", transition.id, variant),
+                        class: MutationClass::GuardBoundary,
+                        description: format!(
+                            "Shift guard boundary of '{}': '{}' → '{}'",
+                            transition.id, guard, mutated_guard
+                        ),
+                        playbook: mutated,
+                    });
+                }
+            }
+        }
+
+        mutants
+    }
+}
+
+/// Enumerate off-by-one variants of every relational comparison in a guard.
+///
+/// Each `<`, `<=`, `>` or `>=` yields its inclusive/exclusive counterpart
+/// (`count > 3` → `count >= 3`). When the right-hand operand is a numeric
+/// literal, the literal is also shifted by one in each direction
+/// (`count > 3` → `count > 2`, `count > 4`).
+fn boundary_variants(guard: &str) -> Vec<String> {
+    let bytes = guard.as_bytes();
+    let mut variants = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let prev = if i > 0 { bytes[i - 1] } else { b' ' };
+        let next = bytes.get(i + 1).copied().unwrap_or(b' ');
+
+        // Skip arrows (=>, ->) and shifts (<<, >>)
+        let is_relational =
+            (c == b'<' || c == b'>') && prev != b'=' && prev != b'-' && prev != c && next != c;
+        if !is_relational {
+            i += 1;
+            continue;
+        }
+
+        let inclusive = next == b'=';
+        let op_end = if inclusive { i + 2 } else { i + 1 };
+        let flipped = match (c, inclusive) {
+            (b'<', false) => "<=",
+            (b'<', true) => "<",
+            (_, false) => ">=",
+            (_, true) => ">",
+        };
+        variants.push(format!("{}{}{}", &guard[..i], flipped, &guard[op_end..]));
+
+        // Shift a numeric literal right-hand operand by one
+        let literal_start = op_end + guard[op_end..].len() - guard[op_end..].trim_start().len();
+        let literal_len = guard[literal_start..]
+            .char_indices()
+            .take_while(|&(pos, ch)| ch.is_ascii_digit() || ch == '.' || (pos == 0 && ch == '-'))
+            .count();
+        let literal = &guard[literal_start..literal_start + literal_len];
+        let shifted = if literal.contains('.') {
+            literal
+                .parse::<f64>()
+                .ok()
+                .map(|value| [(value - 1.0).to_string(), (value + 1.0).to_string()])
+        } else {
+            literal
+                .parse::<i64>()
+                .ok()
+                .map(|value| [(value - 1).to_string(), (value + 1).to_string()])
+        };
+        for value in shifted.into_iter().flatten() {
+            variants.push(format!(
+                "{}{}{}",
+                &guard[..literal_start],
+                value,
+                &guard[literal_start + literal_len..]
+            ));
+        }
+
+        i = op_end;
+    }
+
+    variants
 }
 
 /// Calculate mutation score from results.
@@ -484,5 +584,72 @@ machine:
         assert_eq!(MutationClass::EventSwap.id(), "M3");
         assert_eq!(MutationClass::TargetSwap.id(), "M4");
         assert_eq!(MutationClass::GuardNegation.id(), "M5");
+        assert_eq!(MutationClass::GuardBoundary.id(), "M6");
+    }
+
+    #[test]
+    fn test_generate_guard_boundaries() {
+        let yaml = r#"
+version: "1.0"
+machine:
+  id: "counter"
+  initial: "counting"
+  states:
+    counting:
+      id: "counting"
+    done:
+      id: "done"
+      final_state: true
+  transitions:
+    - id: "increment"
+      from: "counting"
+      to: "counting"
+      event: "click"
+      guard: "count <= 3"
+    - id: "finish"
+      from: "counting"
+      to: "done"
+      event: "click"
+      guard: "count > 3"
+"#;
+        let playbook = Playbook::from_yaml(yaml).expect("parse");
+        let generator = MutationGenerator::new(&playbook);
+        let mutants = generator.generate(MutationClass::GuardBoundary);
+
+        let finish_guards: Vec<_> = mutants
+            .iter()
+            .filter(|m| m.id.starts_with("M6_finish_"))
+            .map(|m| m.playbook.machine.transitions[1].guard.clone().unwrap())
+            .collect();
+        assert_eq!(finish_guards, vec!["count >= 3", "count > 2", "count > 4"]);
+        assert_eq!(mutants.len(), 6);
+        assert!(mutants
+            .iter()
+            .all(|m| m.class == MutationClass::GuardBoundary));
+
+        // M6 mutants are included in the overall and per-class score
+        let results: Vec<_> = mutants
+            .iter()
+            .map(|m| MutantResult {
+                mutant_id: m.id.clone(),
+                class: m.class,
+                killed: m.playbook.machine.transitions[1].guard.as_deref() != Some("count > 4"),
+                kill_reason: None,
+            })
+            .collect();
+        let score = calculate_mutation_score(&results);
+        assert_eq!(score.total_mutants, 6);
+        assert_eq!(score.by_class[&MutationClass::GuardBoundary].total, 6);
+        assert_eq!(score.by_class[&MutationClass::GuardBoundary].killed, 5);
+    }
+
+    #[test]
+    fn test_boundary_variants_skip_non_relational_operators() {
+        assert!(boundary_variants("a => b && x << 2").is_empty());
+        assert_eq!(
+            boundary_variants("ratio < 0.5"),
+            vec!["ratio <= 0.5", "ratio < -0.5", "ratio < 1.5"]
+        );
+        assert_eq!(boundary_variants("x >= y"), vec!["x > y"]);
     }
 }
@@ -55,6 +55,31 @@ pub struct StateMachine {
     pub performance: Option<PerformanceBudget>,
 }
 
+impl StateMachine {
+    /// States belonging to the orthogonal region that starts at `region_initial`.
+    ///
+    /// A region contains its initial state and every state reachable from it
+    /// through transitions, without passing through a parallel state.
+    pub fn region_states(&self, region_initial: &str) -> Vec<String> {
+        let mut members = vec![region_initial.to_string()];
+        let mut index = 0;
+        while index < members.len() {
+            let current = members[index].clone();
+            for transition in &self.transitions {
+                let enters_parallel = self.states.get(&transition.to).is_some_and(|s| s.parallel);
+                if transition.from == current
+                    && !enters_parallel
+                    && !members.contains(&transition.to)
+                {
+                    members.push(transition.to.clone());
+                }
+            }
+            index += 1;
+        }
+        members
+    }
+}
+
 /// Forbidden transition that must never occur.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForbiddenTransition {
@@ -87,6 +112,13 @@ pub struct State {
     /// Whether this is a final (accepting) state
     #[serde(default)]
     pub final_state: bool,
+    /// Whether this is a parallel (orthogonal) state whose regions are all
+    /// active at once while the machine is in this state
+    #[serde(default)]
+    pub parallel: bool,
+    /// Initial state ID of each orthogonal region (parallel states only)
+    #[serde(default)]
+    pub regions: Vec<String>,
 }
 
 /// State transition definition.
@@ -98,11 +130,16 @@ pub struct Transition {
     pub from: String,
     /// Target state ID
     pub to: String,
-    /// Event that triggers this transition
+    /// Event that triggers this transition (may be empty for timed transitions)
+    #[serde(default)]
     pub event: String,
     /// Guard condition (optional)
     #[serde(default)]
     pub guard: Option<String>,
+    /// Fire automatically this many milliseconds after entering the source
+    /// state instead of waiting for `event`
+    #[serde(default)]
+    pub after_ms: Option<u64>,
     /// Actions to execute during transition
     #[serde(default)]
     pub actions: Vec<Action>,
@@ -433,6 +470,24 @@ impl Playbook {
                     state_id: transition.to.clone(),
                 });
             }
+            if transition.event.is_empty() && transition.after_ms.is_none() {
+                return Err(PlaybookError::MissingTrigger(transition.id.clone()));
+            }
+        }
+
+        // Validate parallel states declare existing region initial states
+        for (state_id, state) in &self.machine.states {
+            if state.parallel && state.regions.is_empty() {
+                return Err(PlaybookError::EmptyParallelRegions(state_id.clone()));
+            }
+            for region in &state.regions {
+                if !self.machine.states.contains_key(region) {
+                    return Err(PlaybookError::InvalidRegion {
+                        state_id: state_id.clone(),
+                        region: region.clone(),
+                    });
+                }
+            }
         }
 
         // Check for duplicate state IDs (HashMap handles this, but explicit check)
@@ -496,6 +551,15 @@ pub enum PlaybookError {
 
     #[error("Transitions cannot be empty")]
     EmptyTransitions,
+
+    #[error("Transition '{0}' must declare an event or after_ms")]
+    MissingTrigger(String),
+
+    #[error("Parallel state '{0}' must declare at least one region")]
+    EmptyParallelRegions(String),
+
+    #[error("State '{state_id}' references non-existent region initial state '{region}'")]
+    InvalidRegion { state_id: String, region: String },
 }
 
 #[cfg(test)]
@@ -612,6 +676,31 @@ machine:
         assert!(matches!(result, Err(PlaybookError::EmptyTransitions)));
     }
 
+    #[test]
+    fn test_reject_transition_without_trigger() {
+        let yaml = r#"
+version: "1.0"
+machine:
+  id: "test"
+  initial: "loading"
+  states:
+    loading:
+      id: "loading"
+    ready:
+      id: "ready"
+  transitions:
+    - id: "t1"
+      from: "loading"
+      to: "ready"
+"#;
+        let result = Playbook::from_yaml(yaml);
+        assert!(matches!(result, Err(PlaybookError::MissingTrigger(id)) if id == "t1"));
+
+        let timed = yaml.replace("to: \"ready\"", "to: \"ready\"\n      after_ms: 500");
+        let playbook = Playbook::from_yaml(&timed).expect("parse");
+        assert_eq!(playbook.machine.transitions[0].after_ms, Some(500));
+    }
+
     // === Additional tests for improved coverage ===
 
     #[test]
@@ -1445,6 +1534,8 @@ machine:
             on_exit: vec![],
             invariants: vec![],
             final_state: false,
+            parallel: false,
+            regions: vec![],
         };
         let _ = state;
 
@@ -1454,6 +1545,7 @@ machine:
             to: "b".to_string(),
             event: "go".to_string(),
             guard: None,
+            after_ms: None,
             actions: vec![],
             assertions: vec![],
         };
@@ -1665,6 +1757,8 @@ machine:
             on_exit: vec![],
             invariants: vec![],
             final_state: false,
+            parallel: false,
+            regions: vec![],
         };
         let _ = format!("{:?}", state);
 
@@ -31,6 +31,9 @@ pub struct ReachabilityInfo {
     pub reachable_final_states: HashSet<String>,
     /// Whether at least one final state is reachable
     pub can_reach_final: bool,
+    /// Reachable configurations of each reachable parallel state: the cross
+    /// product of the states reachable in each of its orthogonal regions
+    pub parallel_configurations: HashMap<String, Vec<Vec<String>>>,
 }
 
 /// Information about state machine determinism.
@@ -145,14 +148,48 @@ impl<'a> StateMachineValidator<'a> {
 
         // BFS traversal
         while let Some(current) = queue.pop_front() {
-            for transition in &self.playbook.machine.transitions {
-                if transition.from == current && !reachable.contains(&transition.to) {
-                    reachable.insert(transition.to.clone());
-                    queue.push_back(transition.to.clone());
+            let mut successors: Vec<&String> = self
+                .playbook
+                .machine
+                .transitions
+                .iter()
+                .filter(|t| t.from == current)
+                .map(|t| &t.to)
+                .collect();
+
+            // Entering a parallel state activates every region's initial state
+            if let Some(state) = self.playbook.machine.states.get(&current) {
+                if state.parallel {
+                    successors.extend(state.regions.iter());
+                }
+            }
+
+            for next in successors {
+                if !reachable.contains(next) {
+                    reachable.insert(next.clone());
+                    queue.push_back(next.clone());
                 }
             }
         }
 
+        // Regions transition independently, so a parallel state can be in any
+        // combination of its regions' states
+        let parallel_configurations = self
+            .playbook
+            .machine
+            .states
+            .iter()
+            .filter(|(id, state)| state.parallel && reachable.contains(*id))
+            .map(|(id, state)| {
+                let region_states: Vec<Vec<String>> = state
+                    .regions
+                    .iter()
+                    .map(|region| self.playbook.machine.region_states(region))
+                    .collect();
+                (id.clone(), cross_product(&region_states))
+            })
+            .collect();
+
         // Find orphaned states
         let all_states: HashSet<_> = self.playbook.machine.states.keys().cloned().collect();
         let orphaned: HashSet<_> = all_states.difference(&reachable).cloned().collect();
@@ -175,6 +212,7 @@ impl<'a> StateMachineValidator<'a> {
             orphaned_states: orphaned,
             can_reach_final: !final_states.is_empty(),
             reachable_final_states: final_states,
+            parallel_configurations,
         }
     }
 
@@ -192,7 +230,9 @@ impl<'a> StateMachineValidator<'a> {
         // Check each reachable non-final state
         for state_id in &reachability.reachable_states {
             if let Some(state) = self.playbook.machine.states.get(state_id) {
-                if !state.final_state && !outgoing.contains_key(state_id.as_str()) {
+                // A parallel state stays alive through its regions' transitions
+                let has_regions = state.parallel && !state.regions.is_empty();
+                if !state.final_state && !has_regions && !outgoing.contains_key(state_id.as_str()) {
                     issues.push(ValidationIssue::DeadEndState {
                         state_id: state_id.clone(),
                     });
@@ -274,6 +314,21 @@ impl<'a> StateMachineValidator<'a> {
                     changed = true;
                 }
             }
+
+            // Leaving a parallel state exits all of its regions, so region
+            // states share the parallel state's path to a final state
+            for (state_id, state) in &self.playbook.machine.states {
+                if !state.parallel || !can_reach_final.contains(state_id) {
+                    continue;
+                }
+                for region in &state.regions {
+                    for member in self.playbook.machine.region_states(region) {
+                        if can_reach_final.insert(member) {
+                            changed = true;
+                        }
+                    }
+                }
+            }
         }
 
         // Report states that cannot reach final
@@ -287,8 +342,39 @@ impl<'a> StateMachineValidator<'a> {
     }
 }
 
+/// Every combination that picks one state from each region.
+fn cross_product(regions: &[Vec<String>]) -> Vec<Vec<String>> {
+    regions
+        .iter()
+        .fold(vec![Vec::new()], |combinations, region| {
+            combinations
+                .iter()
+                .flat_map(|prefix| {
+                    region.iter().map(move |state| {
+                        let mut combination = prefix.clone();
+                        combination.push(state.clone());
+                        combination
+                    })
+                })
+                .collect()
+        })
+}
+
 /// Generate a state diagram in DOT format for visualization.
+///
+/// Edges are labelled with the transition's event and guard. Nodes flagged by
+/// validation are filled: unreachable states in red, dead-end states in orange.
 pub fn to_dot(playbook: &Playbook) -> String {
+    let validation = StateMachineValidator::new(playbook).validate();
+    let dead_ends: HashSet<&str> = validation
+        .issues
+        .iter()
+        .filter_map(|issue| match issue {
+            ValidationIssue::DeadEndState { state_id } => Some(state_id.as_str()),
+            _ => None,
+        })
+        .collect();
+
     let mut dot = String::new();
     dot.push_str("digraph StateMachine {\n");
     dot.push_str("  rankdir=LR;\n");
@@ -307,19 +393,32 @@ pub fn to_dot(playbook: &Playbook) -> String {
         } else {
             "ellipse"
         };
-        dot.push_str(&format!("  \"{}\" [shape={}];\n", id, shape));
+        let fill = if validation.reachability.orphaned_states.contains(id) {
+            ", style=filled, fillcolor=red"
+        } else if dead_ends.contains(id.as_str()) {
+            ", style=filled, fillcolor=orange"
+        } else {
+            ""
+        };
+        dot.push_str(&format!("  \"{}\" [shape={}{}];\n", id, shape, fill));
     }
 
     // Add transitions
     for transition in &playbook.machine.transitions {
+        let trigger = match transition.after_ms {
+            Some(ms) => format!("after {}ms", ms),
+            None => transition.event.clone(),
+        };
         let label = if let Some(guard) = &transition.guard {
-            format!("{} [{}]", transition.event, guard)
+            format!("{} [{}]", trigger, guard)
         } else {
-            transition.event.clone()
+            trigger
         };
         dot.push_str(&format!(
             "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
-            transition.from, transition.to, label
+            transition.from,
+            transition.to,
+            label.replace('"', "\\\"")
         ));
     }
 
@@ -464,6 +563,75 @@ machine:
         ));
     }
 
+    const PARALLEL_PLAYBOOK: &str = r#"
+version: "1.0"
+machine:
+  id: "app"
+  initial: "app"
+  states:
+    app:
+      id: "app"
+      parallel: true
+      regions: ["online", "list"]
+    online:
+      id: "online"
+    offline:
+      id: "offline"
+    list:
+      id: "list"
+    detail:
+      id: "detail"
+    closed:
+      id: "closed"
+      final_state: true
+  transitions:
+    - id: "disconnect"
+      from: "online"
+      to: "offline"
+      event: "disconnect"
+    - id: "connect"
+      from: "offline"
+      to: "online"
+      event: "connect"
+    - id: "open"
+      from: "list"
+      to: "detail"
+      event: "open"
+    - id: "back"
+      from: "detail"
+      to: "list"
+      event: "back"
+    - id: "quit"
+      from: "app"
+      to: "closed"
+      event: "quit"
+"#;
+
+    #[test]
+    fn test_parallel_regions_reachability() {
+        let playbook = Playbook::from_yaml(PARALLEL_PLAYBOOK).expect("parse");
+        let result = StateMachineValidator::new(&playbook).validate();
+
+        assert!(result.is_valid, "issues: {:?}", result.issues);
+        assert!(result.reachability.orphaned_states.is_empty());
+        assert!(!result
+            .issues
+            .iter()
+            .any(|i| matches!(i, ValidationIssue::NoPathToFinal { .. })));
+
+        let mut configurations = result.reachability.parallel_configurations["app"].clone();
+        configurations.sort();
+        assert_eq!(
+            configurations,
+            vec![
+                vec!["offline".to_string(), "detail".to_string()],
+                vec!["offline".to_string(), "list".to_string()],
+                vec!["online".to_string(), "detail".to_string()],
+                vec!["online".to_string(), "list".to_string()],
+            ]
+        );
+    }
+
     #[test]
     fn test_dot_generation() {
         let playbook = Playbook::from_yaml(VALID_PLAYBOOK).expect("parse");
@@ -473,5 +641,48 @@ machine:
         assert!(dot.contains("__start"));
         assert!(dot.contains("doublecircle")); // final state
         assert!(dot.contains("\"start\" -> \"middle\""));
+        assert!(!dot.contains("fillcolor"));
+    }
+
+    #[test]
+    fn test_dot_guard_labels_and_highlighting() {
+        let yaml = r#"
+version: "1.0"
+machine:
+  id: "test"
+  initial: "start"
+  states:
+    start:
+      id: "start"
+    stuck:
+      id: "stuck"
+    orphan:
+      id: "orphan"
+    end:
+      id: "end"
+      final_state: true
+  transitions:
+    - id: "t1"
+      from: "start"
+      to: "end"
+      event: "submit"
+      guard: "form.name == \"ok\""
+    - id: "t2"
+      from: "start"
+      to: "stuck"
+      event: "cancel"
+    - id: "t3"
+      from: "orphan"
+      to: "end"
+      event: "finish"
+"#;
+        let playbook = Playbook::from_yaml(yaml).expect("parse");
+        let dot = to_dot(&playbook);
+
+        assert!(dot.contains(r#""start" -> "end" [label="submit [form.name == \"ok\"]"];"#));
+        assert!(dot.contains(r#""start" -> "stuck" [label="cancel"];"#));
+        assert!(dot.contains(r#""orphan" [shape=ellipse, style=filled, fillcolor=red];"#));
+        assert!(dot.contains(r#""stuck" [shape=ellipse, style=filled, fillcolor=orange];"#));
+        assert!(dot.contains(r#""end" [shape=doublecircle];"#));
     }
 }
@@ -795,6 +795,8 @@ pub fn generate_falsification_playbook(config: &PresentarConfig) -> Playbook {
             on_exit: vec![],
             invariants: vec![],
             final_state: false,
+            parallel: false,
+            regions: vec![],
         },
     );
     states.insert(
@@ -806,6 +808,8 @@ pub fn generate_falsification_playbook(config: &PresentarConfig) -> Playbook {
             on_exit: vec![],
             invariants: vec![],
             final_state: true,
+            parallel: false,
+            regions: vec![],
         },
     );
 
@@ -33,7 +33,9 @@ pub use schema::{
     KeybindingConfig, LayoutConfig, PanelConfig, PanelConfigs, PanelType, PresentarConfig,
     ThemeConfig,
 };
-pub use terminal::{Cell, Color, TerminalAssertion, TerminalSnapshot};
+pub use terminal::{
+    Cell, CellDiff, Color, GoldenError, TerminalAssertion, TerminalSnapshot, UPDATE_SNAPSHOTS_ENV,
+};
 pub use validator::{parse_and_validate, validate_config, PresentarError, ValidationResult};
 
 /// Presentar schema version supported by this module.
@@ -2,10 +2,16 @@
 //!
 //! Provides cell-based terminal output capture and assertion capabilities.
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that, when set to `1`, overwrites golden snapshots
+/// instead of comparing against them.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "UPDATE_SNAPSHOTS";
 
 /// RGB color representation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct Color {
     /// Red component (0-255).
     pub r: u8,
@@ -63,7 +69,7 @@ impl fmt::Display for Color {
 }
 
 /// A single terminal cell.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Cell {
     /// Character in the cell.
     pub ch: char,
@@ -112,7 +118,7 @@ impl Cell {
 }
 
 /// Terminal snapshot for testing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TerminalSnapshot {
     cells: Vec<Cell>,
     width: u16,
@@ -237,6 +243,175 @@ impl TerminalSnapshot {
         }
         result
     }
+
+    /// Compare against an expected snapshot cell-by-cell (character and
+    /// foreground/background color).
+    ///
+    /// Positions that exist in only one snapshot are reported with `None`
+    /// on the missing side.
+    pub fn diff(&self, expected: &Self) -> Vec<CellDiff> {
+        let width = self.width.max(expected.width);
+        let height = self.height.max(expected.height);
+        let mut diffs = Vec::new();
+        for row in 0..height {
+            for col in 0..width {
+                let want = expected.get(col, row);
+                let got = self.get(col, row);
+                let same = match (want, got) {
+                    (Some(w), Some(g)) => w.ch == g.ch && w.fg == g.fg && w.bg == g.bg,
+                    (None, None) => true,
+                    _ => false,
+                };
+                if !same {
+                    diffs.push(CellDiff {
+                        row,
+                        col,
+                        expected: want.cloned(),
+                        actual: got.cloned(),
+                    });
+                }
+            }
+        }
+        diffs
+    }
+
+    /// Load a snapshot previously written with [`Self::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, GoldenError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| GoldenError::Io {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        serde_json::from_str(&json).map_err(|e| GoldenError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Write this snapshot to `path` as JSON, creating parent directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), GoldenError> {
+        let path = path.as_ref();
+        let io_error = |e: std::io::Error| GoldenError::Io {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| GoldenError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        std::fs::write(path, json).map_err(io_error)
+    }
+
+    /// Assert this snapshot matches the golden snapshot stored at `path`.
+    ///
+    /// On mismatch the actual snapshot is written next to the golden with an
+    /// `.actual` suffix and the differing cells are returned. Setting
+    /// `UPDATE_SNAPSHOTS=1` overwrites the golden with this snapshot instead.
+    pub fn assert_matches_golden(&self, path: impl AsRef<Path>) -> Result<(), GoldenError> {
+        let update = std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|v| v == "1");
+        self.check_golden(path.as_ref(), update)
+    }
+
+    fn check_golden(&self, path: &Path, update: bool) -> Result<(), GoldenError> {
+        let actual_path = actual_path(path);
+        if update {
+            self.save(path)?;
+            let _ = std::fs::remove_file(&actual_path);
+            return Ok(());
+        }
+
+        let golden = Self::load(path)?;
+        let diffs = self.diff(&golden);
+        if diffs.is_empty() {
+            let _ = std::fs::remove_file(&actual_path);
+            return Ok(());
+        }
+
+        self.save(&actual_path)?;
+        Err(GoldenError::Mismatch {
+            golden: path.to_path_buf(),
+            actual: actual_path,
+            diffs,
+        })
+    }
+}
+
+/// Path of the `.actual` file written next to a golden snapshot on mismatch.
+fn actual_path(golden: &Path) -> PathBuf {
+    let mut path = golden.as_os_str().to_owned();
+    path.push(".actual");
+    PathBuf::from(path)
+}
+
+/// A cell that differs between a snapshot and its golden.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellDiff {
+    /// Row (y) of the cell.
+    pub row: u16,
+    /// Column (x) of the cell.
+    pub col: u16,
+    /// Cell in the golden snapshot, if within its bounds.
+    pub expected: Option<Cell>,
+    /// Cell in the actual snapshot, if within its bounds.
+    pub actual: Option<Cell>,
+}
+
+impl fmt::Display for CellDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |cell: &Option<Cell>| match cell {
+            Some(c) => format!("'{}' fg={} bg={}", c.ch, c.fg, c.bg),
+            None => "<none>".to_string(),
+        };
+        write!(
+            f,
+            "({}, {}): expected {}, got {}",
+            self.row,
+            self.col,
+            describe(&self.expected),
+            describe(&self.actual)
+        )
+    }
+}
+
+/// Errors from golden snapshot comparison.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GoldenError {
+    /// Golden file could not be read or written.
+    #[error("Golden snapshot I/O error at {}: {message}", path.display())]
+    Io {
+        /// File path.
+        path: PathBuf,
+        /// Underlying error.
+        message: String,
+    },
+
+    /// Golden file is not a valid snapshot.
+    #[error("Invalid golden snapshot at {}: {message}", path.display())]
+    Parse {
+        /// File path.
+        path: PathBuf,
+        /// Underlying error.
+        message: String,
+    },
+
+    /// Snapshot differs from the golden.
+    #[error(
+        "Snapshot differs from {} in {} cell(s); actual written to {}",
+        golden.display(),
+        diffs.len(),
+        actual.display()
+    )]
+    Mismatch {
+        /// Golden snapshot path.
+        golden: PathBuf,
+        /// Path the actual snapshot was written to.
+        actual: PathBuf,
+        /// Differing cells as (row, col, expected, actual).
+        diffs: Vec<CellDiff>,
+    },
 }
 
 impl fmt::Display for TerminalSnapshot {
@@ -511,6 +686,73 @@ mod tests {
         assert_eq!(format!("{}", color), "#64C8FF");
     }
 
+    #[test]
+    fn test_golden_snapshot_matches() {
+        let dir = tempfile::tempdir().unwrap();
+        let golden = dir.path().join("golden").join("dashboard.snap");
+        let mut snapshot = TerminalSnapshot::from_string("CPU 45%", 10, 2);
+        snapshot.set(0, 1, Cell::new('#').with_fg(Color::GREEN));
+
+        snapshot.save(&golden).unwrap();
+        assert!(snapshot.assert_matches_golden(&golden).is_ok());
+        assert!(!actual_path(&golden).exists());
+    }
+
+    #[test]
+    fn test_golden_snapshot_color_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let golden = dir.path().join("dashboard.snap");
+        let mut expected = TerminalSnapshot::from_string("OK", 4, 1);
+        expected.set(0, 0, Cell::new('O').with_fg(Color::GREEN));
+        expected.save(&golden).unwrap();
+
+        let mut actual = expected.clone();
+        actual.set(0, 0, Cell::new('O').with_fg(Color::RED));
+
+        let (actual_file, diffs) = match actual.check_golden(&golden, false) {
+            Err(GoldenError::Mismatch { actual, diffs, .. }) => (actual, diffs),
+            other => panic!("expected mismatch, got {other:?}"),
+        };
+        assert_eq!(diffs.len(), 1);
+        assert_eq!((diffs[0].row, diffs[0].col), (0, 0));
+        assert_eq!(diffs[0].expected.as_ref().unwrap().fg, Color::GREEN);
+        assert_eq!(diffs[0].actual.as_ref().unwrap().fg, Color::RED);
+        assert_eq!(diffs[0].expected.as_ref().unwrap().ch, 'O');
+
+        assert_eq!(actual_file, dir.path().join("dashboard.snap.actual"));
+        let written = TerminalSnapshot::load(&actual_file).unwrap();
+        assert_eq!(written.fg_color_at(0, 0), Some(Color::RED));
+    }
+
+    #[test]
+    fn test_golden_snapshot_update() {
+        let dir = tempfile::tempdir().unwrap();
+        let golden = dir.path().join("dashboard.snap");
+        TerminalSnapshot::from_string("old", 5, 1)
+            .save(&golden)
+            .unwrap();
+
+        let snapshot = TerminalSnapshot::from_string("new", 5, 1);
+        assert!(snapshot.check_golden(&golden, false).is_err());
+        assert!(actual_path(&golden).exists());
+
+        snapshot.check_golden(&golden, true).unwrap();
+        assert!(!actual_path(&golden).exists());
+        assert!(TerminalSnapshot::load(&golden).unwrap().contains("new"));
+        assert!(snapshot.check_golden(&golden, false).is_ok());
+    }
+
+    #[test]
+    fn test_golden_snapshot_dimension_mismatch() {
+        let wide = TerminalSnapshot::from_string("AB", 2, 1);
+        let narrow = TerminalSnapshot::from_string("A", 1, 1);
+        let diffs = wide.diff(&narrow);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].col, 1);
+        assert!(diffs[0].expected.is_none());
+        assert_eq!(diffs[0].actual.as_ref().unwrap().ch, 'B');
+    }
+
     #[test]
     fn test_snapshot_display() {
         let snapshot = TerminalSnapshot::from_string("Test", 10, 1);
@@ -29,6 +29,7 @@
 //! std::fs::write("trace.json", trace)?;
 //! ```
 
+use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Tracing configuration for renacer integration
@@ -422,6 +423,71 @@ impl ChromeTrace {
         self.metadata = Some(metadata);
     }
 
+    /// Merge traces from several workers into one timeline
+    ///
+    /// Every distinct pid of every source is remapped to a fresh pid starting
+    /// at `pid_offset` (tids are kept), and a `process_name` metadata event
+    /// labels each one. Timestamps are shifted so the earliest event across
+    /// all sources is at 0, and events are ordered by timestamp, keeping the
+    /// source order for ties.
+    #[must_use]
+    pub fn merge(traces: &[ChromeTrace], pid_offset: u32) -> Self {
+        let epoch = traces
+            .iter()
+            .flat_map(|t| t.trace_events.iter().map(|e| e.ts))
+            .min()
+            .unwrap_or(0);
+
+        let mut merged = Self::new();
+        let mut events = Vec::new();
+        let mut next_pid = pid_offset;
+
+        for (source, trace) in traces.iter().enumerate() {
+            let label = trace
+                .metadata
+                .as_ref()
+                .and_then(|m| m.get("service_name"))
+                .and_then(serde_json::Value::as_str)
+                .map_or_else(|| format!("trace {source}"), ToString::to_string);
+            let mut pid_map: HashMap<u32, u32> = HashMap::new();
+
+            for event in &trace.trace_events {
+                let pid = *pid_map.entry(event.pid).or_insert_with(|| {
+                    let pid = next_pid;
+                    next_pid += 1;
+                    merged.trace_events.push(ChromeTraceEvent {
+                        name: "process_name".to_string(),
+                        cat: "__metadata".to_string(),
+                        ph: "M".to_string(),
+                        ts: 0,
+                        dur: None,
+                        pid,
+                        tid: 0,
+                        args: Some(serde_json::json!({
+                            "name": format!("{label} (pid {})", event.pid)
+                        })),
+                    });
+                    pid
+                });
+
+                let mut event = event.clone();
+                event.pid = pid;
+                event.ts -= epoch;
+                events.push(event);
+            }
+        }
+
+        // Stable sort keeps per-source order for equal timestamps
+        events.sort_by_key(|e| e.ts);
+        merged.trace_events.extend(events);
+        merged.set_metadata(serde_json::json!({
+            "merged_sources": traces.len(),
+            "epoch_us": epoch,
+            "sources": traces.iter().map(|t| t.metadata.clone()).collect::<Vec<_>>(),
+        }));
+        merged
+    }
+
     /// Export as JSON string
     ///
     /// # Errors
@@ -739,6 +805,70 @@ mod tests {
             assert_eq!(trace.trace_events[0].name, "test-span");
         }
 
+        #[test]
+        fn test_merge_traces() {
+            // Both workers report pid 1; worker B started 500us after worker A
+            let mut worker_a = ChromeTrace::new();
+            worker_a.add_instant("a-start", "test", 1_000, 1, 0);
+            worker_a.add_instant("a-step", "test", 1_700, 1, 1);
+            worker_a.add_instant("a-end", "test", 2_000, 1, 0);
+            worker_a.set_metadata(serde_json::json!({ "service_name": "worker-a" }));
+
+            let mut worker_b = ChromeTrace::new();
+            worker_b.add_instant("b-start", "test", 1_500, 1, 0);
+            worker_b.add_instant("b-end", "test", 1_700, 1, 0);
+
+            let merged = ChromeTrace::merge(&[worker_a, worker_b], 10);
+
+            let process_names: Vec<_> =
+                merged.trace_events.iter().filter(|e| e.ph == "M").collect();
+            assert_eq!(process_names.len(), 2);
+            assert_eq!(process_names[0].pid, 10);
+            assert_eq!(process_names[1].pid, 11);
+            assert_eq!(
+                process_names[0].args.as_ref().unwrap()["name"],
+                "worker-a (pid 1)"
+            );
+
+            let events: Vec<_> = merged
+                .trace_events
+                .iter()
+                .filter(|e| e.ph != "M")
+                .map(|e| (e.name.as_str(), e.ts, e.pid))
+                .collect();
+            assert_eq!(
+                events,
+                vec![
+                    ("a-start", 0, 10),
+                    ("b-start", 500, 11),
+                    ("a-step", 700, 10),
+                    ("b-end", 700, 11),
+                    ("a-end", 1_000, 10),
+                ]
+            );
+
+            let metadata = merged.metadata.unwrap();
+            assert_eq!(metadata["merged_sources"], 2);
+            assert_eq!(metadata["epoch_us"], 1_000);
+        }
+
+        #[test]
+        fn test_merge_distinct_source_pids() {
+            let mut worker = ChromeTrace::new();
+            worker.add_instant("main", "test", 5, 1, 0);
+            worker.add_instant("gpu", "test", 6, 2, 0);
+
+            let merged = ChromeTrace::merge(&[worker, ChromeTrace::new()], 0);
+            let pids: Vec<u32> = merged
+                .trace_events
+                .iter()
+                .filter(|e| e.ph != "M")
+                .map(|e| e.pid)
+                .collect();
+            assert_eq!(pids, vec![0, 1]);
+            assert!(ChromeTrace::merge(&[], 0).trace_events.is_empty());
+        }
+
         #[test]
         fn test_add_instant() {
             let mut trace = ChromeTrace::new();
@@ -15,6 +15,7 @@
 
 use crate::event::InputEvent;
 use crate::result::{ProbarError, ProbarResult};
+use crate::runtime::StateDelta;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
@@ -24,6 +25,9 @@ use std::path::Path;
 /// Version of the replay format
 pub const REPLAY_FORMAT_VERSION: u32 = 1;
 
+/// Mask applied to the recorded seed when probing whether a game consumes it
+const SEED_PROBE_MASK: u64 = 0x9E37_79B9_7F4A_7C15;
+
 /// Replay file header
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplayHeader {
@@ -315,6 +319,13 @@ impl ReplayRecorder {
         self
     }
 
+    /// Capture the RNG seed the game was initialized with
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.replay.header.seed = seed;
+        self
+    }
+
     /// Record an input event
     pub fn record_input(&mut self, event: InputEvent) {
         if self.recording {
@@ -535,6 +546,116 @@ impl ReplayPlayer {
     pub fn replay(&self) -> &Replay {
         &self.replay
     }
+
+    /// Get the recorded seed to re-inject into the game before playback
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.replay.header.seed
+    }
+
+    /// Replay every frame against a live game and verify all checkpoints
+    ///
+    /// `init` receives the recorded seed and builds the initial game state;
+    /// `step` applies one frame of inputs and returns the resulting state hash.
+    /// Playback restarts from frame 0.
+    ///
+    /// When checkpoints match, the replay is run a second time with a
+    /// different seed. If the checkpoints still match, the game never consumed
+    /// the seed and `VerificationResult::seed_unused` is set.
+    pub fn verify<S, I, F>(&mut self, init: I, mut step: F) -> VerificationResult
+    where
+        I: FnMut(u64) -> S,
+        F: FnMut(&mut S, &[InputEvent]) -> String,
+    {
+        self.verify_with_data(init, move |state, inputs| {
+            (step(state, inputs), HashMap::new())
+        })
+    }
+
+    /// Like [`Self::verify`], but `step` also returns the live state fields
+    ///
+    /// On divergence, the fields are compared against the recorded checkpoint's
+    /// `state_data` and the differing ones are reported in
+    /// `VerificationResult::state_delta`. Nested JSON objects are flattened to
+    /// dotted paths such as `player.position.x`.
+    pub fn verify_with_data<S, I, F>(&mut self, mut init: I, mut step: F) -> VerificationResult
+    where
+        I: FnMut(u64) -> S,
+        F: FnMut(&mut S, &[InputEvent]) -> (String, HashMap<String, serde_json::Value>),
+    {
+        let frames = self.play_frames(self.seed(), &mut init, &mut step);
+        let mut checkpoints = self.replay.checkpoints.clone();
+        checkpoints.sort_by_key(|c| c.frame);
+
+        // Checkpoints are in frame order, so the first mismatch is the
+        // earliest divergence.
+        for checkpoint in &checkpoints {
+            let Some((hash, data)) = frame_at(&frames, checkpoint.frame) else {
+                return VerificationResult::failure(
+                    checkpoint.frame,
+                    &format!(
+                        "Checkpoint at frame {} is beyond the end of playback",
+                        checkpoint.frame
+                    ),
+                );
+            };
+            if *hash == checkpoint.state_hash {
+                continue;
+            }
+
+            let delta = checkpoint.state_data.as_ref().map(|expected| {
+                StateDelta::compute_fields(expected, data, checkpoint.frame, checkpoint.frame)
+            });
+            let location = delta
+                .as_ref()
+                .and_then(|d| d.fields.first())
+                .map(|(field, _)| format!(" on {field}"))
+                .unwrap_or_default();
+            return VerificationResult::failure(
+                checkpoint.frame,
+                &format!(
+                    "Replay diverged at frame {}{location}: expected hash '{}', got '{}'",
+                    checkpoint.frame, checkpoint.state_hash, hash
+                ),
+            )
+            .with_state_delta(delta);
+        }
+
+        let mut result = VerificationResult::success(frames.len() as u64, checkpoints.len());
+        if !checkpoints.is_empty() {
+            let probe = self.play_frames(self.seed() ^ SEED_PROBE_MASK, &mut init, &mut step);
+            result.seed_unused = checkpoints
+                .iter()
+                .all(|c| frame_at(&probe, c.frame).is_some_and(|(hash, _)| *hash == c.state_hash));
+        }
+        result
+    }
+
+    /// Play from frame 0 with the given seed, returning the state after each frame
+    fn play_frames<S, I, F>(
+        &mut self,
+        seed: u64,
+        init: &mut I,
+        step: &mut F,
+    ) -> Vec<(String, HashMap<String, serde_json::Value>)>
+    where
+        I: FnMut(u64) -> S,
+        F: FnMut(&mut S, &[InputEvent]) -> (String, HashMap<String, serde_json::Value>),
+    {
+        self.seek(0);
+        let mut state = init(seed);
+        let mut frames = Vec::new();
+        while self.playing {
+            let inputs = self.get_frame_inputs();
+            frames.push(step(&mut state, &inputs));
+        }
+        frames
+    }
+}
+
+/// Look up the observed state for a frame
+fn frame_at<T>(frames: &[T], frame: u64) -> Option<&T> {
+    usize::try_from(frame).ok().and_then(|i| frames.get(i))
 }
 
 /// Result of replay verification
@@ -550,6 +671,11 @@ pub struct VerificationResult {
     pub divergence_frame: Option<u64>,
     /// Divergence details
     pub divergence_details: Option<String>,
+    /// Checkpoints matched even with a different seed, so the game did not
+    /// consume the recorded seed
+    pub seed_unused: bool,
+    /// Fields that differ at the divergence frame (`None` if no state data)
+    pub state_delta: Option<StateDelta>,
 }
 
 impl VerificationResult {
@@ -562,6 +688,8 @@ impl VerificationResult {
             checkpoints_verified,
             divergence_frame: None,
             divergence_details: None,
+            seed_unused: false,
+            state_delta: None,
         }
     }
 
@@ -574,8 +702,17 @@ impl VerificationResult {
             checkpoints_verified: 0,
             divergence_frame: Some(frame),
             divergence_details: Some(details.to_string()),
+            seed_unused: false,
+            state_delta: None,
         }
     }
+
+    /// Attach the differing fields at the divergence frame
+    #[must_use]
+    pub fn with_state_delta(mut self, delta: Option<StateDelta>) -> Self {
+        self.state_delta = delta;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -1422,4 +1559,210 @@ mod tests {
             assert!(inputs.is_empty());
         }
     }
+
+    mod seed_verification_tests {
+        use super::*;
+
+        /// Minimal RNG-driven game: every input adds a random amount to the score
+        struct RngGame {
+            rng: u64,
+            score: u64,
+        }
+
+        impl RngGame {
+            fn new(seed: u64) -> Self {
+                Self {
+                    rng: seed.max(1),
+                    score: 0,
+                }
+            }
+
+            fn step(&mut self, inputs: &[InputEvent]) -> String {
+                for _ in inputs {
+                    self.rng ^= self.rng << 13;
+                    self.rng ^= self.rng >> 7;
+                    self.rng ^= self.rng << 17;
+                    self.score += self.rng % 100;
+                }
+                format!("{:016x}", self.score)
+            }
+        }
+
+        fn record(seed: u64, frames: u64) -> Replay {
+            let mut recorder = ReplayRecorder::new("rng_game", "1.0", 0).with_seed(seed);
+            let mut game = RngGame::new(seed);
+            for frame in 0..frames {
+                let inputs = if frame % 3 == 0 {
+                    vec![InputEvent::key_press("Space")]
+                } else {
+                    vec![]
+                };
+                recorder.record_inputs(&inputs);
+                let hash = game.step(&inputs);
+                recorder.checkpoint(&hash);
+                recorder.next_frame(None);
+            }
+            recorder.finalize()
+        }
+
+        #[test]
+        fn test_with_seed_sets_header() {
+            let replay = ReplayRecorder::new("game", "1.0", 1)
+                .with_seed(99)
+                .finalize();
+            assert_eq!(replay.header.seed, 99);
+            assert_eq!(ReplayPlayer::new(replay).seed(), 99);
+        }
+
+        #[test]
+        fn test_rng_replay_checkpoints_match_exactly() {
+            let replay = record(0xDEAD_BEEF, 30);
+            assert_eq!(replay.checkpoints.len(), 30);
+            let mut player = ReplayPlayer::new(replay);
+
+            let result = player.verify(RngGame::new, |game, inputs| game.step(inputs));
+
+            assert!(result.passed, "{:?}", result.divergence_details);
+            assert_eq!(result.checkpoints_verified, 30);
+            assert_eq!(result.frames_verified, 30);
+            assert!(!result.seed_unused);
+        }
+
+        #[test]
+        fn test_missing_seed_injection_diverges() {
+            let replay = record(0xDEAD_BEEF, 30);
+            let mut player = ReplayPlayer::new(replay);
+
+            // Game ignores the injected seed and uses its own
+            let result = player.verify(|_| RngGame::new(7), |game, inputs| game.step(inputs));
+
+            assert!(!result.passed);
+            assert_eq!(result.divergence_frame, Some(0));
+        }
+
+        #[test]
+        fn test_seed_unused_flagged() {
+            let mut recorder = ReplayRecorder::new("static_game", "1.0", 0).with_seed(42);
+            for frame in 0..10 {
+                recorder.record_input(InputEvent::key_press("A"));
+                recorder.checkpoint(&format!("frame-{frame}"));
+                recorder.next_frame(None);
+            }
+            let mut player = ReplayPlayer::new(recorder.finalize());
+
+            let result = player.verify(
+                |_seed| 0_u64,
+                |frame, _inputs| {
+                    let hash = format!("frame-{frame}");
+                    *frame += 1;
+                    hash
+                },
+            );
+
+            assert!(result.passed);
+            assert!(result.seed_unused);
+        }
+    }
+
+    mod divergence_tests {
+        use super::*;
+        use serde_json::json;
+
+        fn player_state(x: f64, y: f64, score: u64) -> HashMap<String, serde_json::Value> {
+            let mut data = HashMap::new();
+            data.insert(
+                "player".to_string(),
+                json!({ "position": { "x": x, "y": y } }),
+            );
+            data.insert("score".to_string(), json!(score));
+            data
+        }
+
+        fn hash_state(data: &HashMap<String, serde_json::Value>) -> String {
+            let mut keys: Vec<_> = data.keys().collect();
+            keys.sort();
+            let canonical: Vec<String> = keys.iter().map(|k| format!("{k}={}", data[*k])).collect();
+            format!("{:x}", Sha256::digest(canonical.join(";").as_bytes()))
+        }
+
+        fn record(frames: u64) -> Replay {
+            let mut recorder = ReplayRecorder::new("game", "1.0", 1);
+            for frame in 0..frames {
+                recorder.record_input(InputEvent::key_press("ArrowRight"));
+                let data = player_state(frame as f64, 0.0, frame * 10);
+                recorder.checkpoint_with_data(&hash_state(&data), data);
+                recorder.next_frame(None);
+            }
+            recorder.finalize()
+        }
+
+        /// Replays the game, nudging the player's x position on one frame
+        fn replay_with_perturbation(frames: u64, perturbed: Option<u64>) -> VerificationResult {
+            let mut player = ReplayPlayer::new(record(frames));
+            player.verify_with_data(
+                |_seed| 0_u64,
+                |frame, _inputs| {
+                    let nudge = if Some(*frame) == perturbed { 0.5 } else { 0.0 };
+                    let data = player_state(*frame as f64 + nudge, 0.0, *frame * 10);
+                    *frame += 1;
+                    (hash_state(&data), data)
+                },
+            )
+        }
+
+        #[test]
+        fn test_clean_replay_has_no_delta() {
+            let result = replay_with_perturbation(20, None);
+            assert!(result.passed);
+            assert!(result.state_delta.is_none());
+        }
+
+        #[test]
+        fn test_reports_first_divergent_frame_and_field() {
+            let result = replay_with_perturbation(20, Some(13));
+
+            assert!(!result.passed);
+            assert_eq!(result.divergence_frame, Some(13));
+            let delta = result.state_delta.unwrap();
+            assert_eq!(delta.target_frame, 13);
+            assert_eq!(
+                delta.fields,
+                vec![("player.position.x".to_string(), Some(json!(13.5)))]
+            );
+            let details = result.divergence_details.unwrap();
+            assert!(
+                details.contains("frame 13 on player.position.x"),
+                "{details}"
+            );
+        }
+
+        #[test]
+        fn test_state_delta_missing_fields() {
+            let expected = player_state(1.0, 2.0, 3);
+            let mut actual = expected.clone();
+            actual.remove("score");
+            actual.insert("lives".to_string(), json!(3));
+
+            let delta = StateDelta::compute_fields(&expected, &actual, 0, 0);
+            assert_eq!(
+                delta.fields,
+                vec![
+                    ("lives".to_string(), Some(json!(3))),
+                    ("score".to_string(), None),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_divergence_without_state_data() {
+            let mut recorder = ReplayRecorder::new("game", "1.0", 1);
+            recorder.record_input(InputEvent::key_press("A"));
+            recorder.checkpoint("expected");
+            let mut player = ReplayPlayer::new(recorder.finalize());
+
+            let result = player.verify(|_| (), |(), _| "actual".to_string());
+            assert_eq!(result.divergence_frame, Some(0));
+            assert!(result.state_delta.is_none());
+        }
+    }
 }
@@ -31,6 +31,7 @@
 
 use crate::bridge::VisualDiff;
 use crate::driver::Screenshot;
+use crate::harness::SuiteResults;
 use crate::result::{ProbarError, ProbarResult};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -60,6 +61,8 @@ pub enum TestStatus {
     Skipped,
     /// Test is pending
     Pending,
+    /// Test both passed and failed across repeated runs
+    Flaky,
 }
 
 impl TestStatus {
@@ -74,6 +77,12 @@ impl TestStatus {
     pub const fn is_failed(&self) -> bool {
         matches!(self, Self::Failed)
     }
+
+    /// Check if status is flaky
+    #[must_use]
+    pub const fn is_flaky(&self) -> bool {
+        matches!(self, Self::Flaky)
+    }
 }
 
 /// Individual test result
@@ -92,10 +101,44 @@ pub struct TestResultEntry {
     pub failure_screenshot: Option<Screenshot>,
     /// Stack trace if available
     pub stack_trace: Option<String>,
+    /// Source location of the failure, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
     /// Timestamp when test completed
     pub timestamp: SystemTime,
 }
 
+/// Source file and line a failure points at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// File path (relative to the repository root for CI annotations)
+    pub file: String,
+    /// 1-based line number
+    pub line: u32,
+}
+
+impl SourceLocation {
+    /// Create a new source location
+    #[must_use]
+    pub fn new(file: impl Into<String>, line: u32) -> Self {
+        Self {
+            file: file.into(),
+            line,
+        }
+    }
+
+    /// Find the first `path.rs:LINE` frame in a stack trace
+    #[must_use]
+    pub fn from_stack_trace(trace: &str) -> Option<Self> {
+        trace.split_whitespace().find_map(|token| {
+            let token = token.trim_matches(|c: char| matches!(c, '(' | ')' | ',' | '\''));
+            let (file, rest) = token.split_once(".rs:")?;
+            let line = rest.split(':').next()?.parse().ok()?;
+            Some(Self::new(format!("{file}.rs"), line))
+        })
+    }
+}
+
 impl TestResultEntry {
     /// Create a passing test result
     #[must_use]
@@ -107,6 +150,7 @@ impl TestResultEntry {
             error: None,
             failure_screenshot: None,
             stack_trace: None,
+            location: None,
             timestamp: SystemTime::now(),
         }
     }
@@ -121,6 +165,7 @@ impl TestResultEntry {
             error: Some(error.into()),
             failure_screenshot: None,
             stack_trace: None,
+            location: None,
             timestamp: SystemTime::now(),
         }
     }
@@ -135,6 +180,7 @@ impl TestResultEntry {
             error: None,
             failure_screenshot: None,
             stack_trace: None,
+            location: None,
             timestamp: SystemTime::now(),
         }
     }
@@ -152,6 +198,23 @@ impl TestResultEntry {
         self.stack_trace = Some(trace.into());
         self
     }
+
+    /// Add the source location of the failure
+    #[must_use]
+    pub fn with_location(mut self, file: impl Into<String>, line: u32) -> Self {
+        self.location = Some(SourceLocation::new(file, line));
+        self
+    }
+
+    /// Get the failure location, falling back to the stack trace
+    #[must_use]
+    pub fn source_location(&self) -> Option<SourceLocation> {
+        self.location.clone().or_else(|| {
+            self.stack_trace
+                .as_deref()
+                .and_then(SourceLocation::from_stack_trace)
+        })
+    }
 }
 
 /// Trace data for performance analysis
@@ -223,6 +286,63 @@ pub struct AndonCordPulled {
     pub screenshot: Option<Screenshot>,
 }
 
+impl AndonCordPulled {
+    /// Human-readable reason the line was stopped
+    #[must_use]
+    pub fn reason(&self) -> String {
+        format!(
+            "ANDON CORD PULLED: Test '{}' failed: {}",
+            self.test_name, self.failure
+        )
+    }
+}
+
+/// A single test's outcome aggregated across repeated runs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergedTestResult {
+    /// Test name
+    pub name: String,
+    /// `Passed` or `Failed` if stable across runs, `Flaky` if mixed
+    pub status: TestStatus,
+    /// Number of runs the test appeared in
+    pub runs: usize,
+    /// Number of failing runs
+    pub failures: usize,
+    /// Fraction of runs that failed for flaky tests (0.0 for stable tests)
+    pub flakiness_rate: f64,
+    /// Most recent error message, if any run failed
+    pub last_error: Option<String>,
+}
+
+/// Flakiness report produced by [`Reporter::merge_runs`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FlakinessReport {
+    /// Number of suite runs merged
+    pub runs: usize,
+    /// Per-test results, in order of first appearance
+    pub tests: Vec<MergedTestResult>,
+}
+
+impl FlakinessReport {
+    /// Get flaky tests
+    #[must_use]
+    pub fn flaky(&self) -> Vec<&MergedTestResult> {
+        self.tests.iter().filter(|t| t.status.is_flaky()).collect()
+    }
+
+    /// Get tests that failed in every run
+    #[must_use]
+    pub fn stable_failures(&self) -> Vec<&MergedTestResult> {
+        self.tests.iter().filter(|t| t.status.is_failed()).collect()
+    }
+
+    /// Check if every test passed in every run
+    #[must_use]
+    pub fn all_stable_passing(&self) -> bool {
+        self.tests.iter().all(|t| t.status.is_passed())
+    }
+}
+
 /// Test reporter with Andon Cord support
 ///
 /// The reporter collects test results and can generate various output formats.
@@ -253,6 +373,8 @@ pub struct Reporter {
     suite_name: String,
     /// Start time
     start_time: Option<SystemTime>,
+    /// Set once the Andon Cord has been pulled
+    andon_cord: Option<AndonCordPulled>,
 }
 
 impl Reporter {
@@ -305,10 +427,11 @@ impl Reporter {
     pub fn record(&mut self, result: TestResultEntry) -> ProbarResult<()> {
         let failed = result.status.is_failed();
         let failure_info = if failed {
-            Some((
-                result.name.clone(),
-                result.error.clone().unwrap_or_default(),
-            ))
+            Some(AndonCordPulled {
+                test_name: result.name.clone(),
+                failure: result.error.clone().unwrap_or_default(),
+                screenshot: result.failure_screenshot.clone(),
+            })
         } else {
             None
         };
@@ -316,17 +439,68 @@ impl Reporter {
         self.results.push(result);
 
         if self.failure_mode == FailureMode::AndonCord {
-            if let Some((test_name, failure)) = failure_info {
+            if let Some(pulled) = failure_info {
                 // ANDON CORD PULLED: Stop immediately
-                return Err(ProbarError::AssertionFailed {
-                    message: format!("ANDON CORD PULLED: Test '{test_name}' failed: {failure}"),
-                });
+                let message = pulled.reason();
+                self.andon_cord = Some(pulled);
+                return Err(ProbarError::AssertionFailed { message });
             }
         }
 
         Ok(())
     }
 
+    /// Merge repeated runs of the same suite to detect flaky tests
+    ///
+    /// Each test is classified as stable-pass, stable-fail, or flaky (passed
+    /// in some runs and failed in others).
+    #[must_use]
+    pub fn merge_runs(runs: &[SuiteResults]) -> FlakinessReport {
+        let mut tests: Vec<MergedTestResult> = Vec::new();
+
+        for result in runs.iter().flat_map(|run| &run.results) {
+            let index = match tests.iter().position(|t| t.name == result.name) {
+                Some(index) => index,
+                None => {
+                    tests.push(MergedTestResult {
+                        name: result.name.clone(),
+                        status: TestStatus::Passed,
+                        runs: 0,
+                        failures: 0,
+                        flakiness_rate: 0.0,
+                        last_error: None,
+                    });
+                    tests.len() - 1
+                }
+            };
+            let merged = &mut tests[index];
+            merged.runs += 1;
+            if !result.passed {
+                merged.failures += 1;
+                merged.last_error.clone_from(&result.error);
+            }
+        }
+
+        for merged in &mut tests {
+            (merged.status, merged.flakiness_rate) = match merged.failures {
+                0 => (TestStatus::Passed, 0.0),
+                f if f == merged.runs => (TestStatus::Failed, 0.0),
+                f => (TestStatus::Flaky, f as f64 / merged.runs as f64),
+            };
+        }
+
+        FlakinessReport {
+            runs: runs.len(),
+            tests,
+        }
+    }
+
+    /// Get the Andon Cord failure, if the line was stopped
+    #[must_use]
+    pub const fn andon_cord(&self) -> Option<&AndonCordPulled> {
+        self.andon_cord.as_ref()
+    }
+
     /// Add a screenshot
     pub fn add_screenshot(&mut self, name: impl Into<String>, screenshot: Screenshot) {
         self.screenshots.push((name.into(), screenshot));
@@ -439,6 +613,7 @@ impl Reporter {
         .test.pass { background: #e8f5e9; border-left: 4px solid #4caf50; }
         .test.fail { background: #ffebee; border-left: 4px solid #f44336; }
         .test.skip { background: #fff3e0; border-left: 4px solid #ff9800; }
+        .test.flaky { background: #f3e5f5; border-left: 4px solid #9c27b0; }
         .error { color: #d32f2f; font-family: monospace; white-space: pre-wrap; }
         .visual-diff { display: flex; gap: 10px; margin: 10px 0; }
         .visual-diff img { max-width: 300px; border: 1px solid #ddd; }
@@ -473,6 +648,7 @@ impl Reporter {
                 TestStatus::Passed => "pass",
                 TestStatus::Failed => "fail",
                 TestStatus::Skipped | TestStatus::Pending => "skip",
+                TestStatus::Flaky => "flaky",
             };
 
             html.push_str(&format!(
@@ -577,6 +753,55 @@ impl Reporter {
         xml.push_str("</testsuite>\n");
         xml
     }
+
+    /// Render GitHub Actions workflow commands for failing tests
+    ///
+    /// Emits one `::error` line per failure, with `file=` and `line=` when a
+    /// location is known so the failure shows inline on the PR diff. If the
+    /// Andon Cord was pulled, a final `::error::` line carries its reason.
+    #[must_use]
+    pub fn to_github_annotations(&self) -> String {
+        let mut out = String::new();
+
+        for result in self.failures() {
+            let message = escape_annotation_data(&format!(
+                "{}: {}",
+                result.name,
+                result.error.as_deref().unwrap_or("test failed")
+            ));
+            match result.source_location() {
+                Some(location) => out.push_str(&format!(
+                    "::error file={},line={}::{message}\n",
+                    escape_annotation_property(&location.file),
+                    location.line
+                )),
+                None => out.push_str(&format!("::error::{message}\n")),
+            }
+        }
+
+        if let Some(pulled) = &self.andon_cord {
+            out.push_str(&format!(
+                "::error::{}\n",
+                escape_annotation_data(&pulled.reason())
+            ));
+        }
+
+        out
+    }
+}
+
+/// Escape a GitHub Actions workflow command message
+fn escape_annotation_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a GitHub Actions workflow command property value
+fn escape_annotation_property(s: &str) -> String {
+    escape_annotation_data(s)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
 }
 
 /// Escape XML special characters
@@ -1004,4 +1229,161 @@ mod tests {
             assert!(html.contains("85.0%")); // 0.85 * 100
         }
     }
+
+    mod github_annotation_tests {
+        use super::*;
+
+        #[test]
+        fn test_annotation_with_location() {
+            let mut reporter = Reporter::collect_all();
+            reporter
+                .record(
+                    TestResultEntry::failed("test_jump", Duration::ZERO, "expected 10, got 7")
+                        .with_location("tests/game.rs", 42),
+                )
+                .unwrap();
+
+            assert_eq!(
+                reporter.to_github_annotations(),
+                "::error file=tests/game.rs,line=42::test_jump: expected 10, got 7\n"
+            );
+        }
+
+        #[test]
+        fn test_annotation_without_location() {
+            let mut reporter = Reporter::collect_all();
+            reporter
+                .record(TestResultEntry::passed("ok", Duration::ZERO))
+                .unwrap();
+            reporter
+                .record(TestResultEntry::failed(
+                    "test_fall",
+                    Duration::ZERO,
+                    "line1\nline2",
+                ))
+                .unwrap();
+
+            assert_eq!(
+                reporter.to_github_annotations(),
+                "::error::test_fall: line1%0Aline2\n"
+            );
+        }
+
+        #[test]
+        fn test_annotation_location_from_stack_trace() {
+            let mut reporter = Reporter::collect_all();
+            reporter
+                .record(
+                    TestResultEntry::failed("t", Duration::ZERO, "boom")
+                        .with_stack_trace("panicked at src/lib.rs:17:5:\nboom"),
+                )
+                .unwrap();
+
+            assert!(reporter
+                .to_github_annotations()
+                .starts_with("::error file=src/lib.rs,line=17::"));
+        }
+
+        #[test]
+        fn test_annotation_andon_cord() {
+            let mut reporter = Reporter::andon();
+            let _ = reporter.record(TestResultEntry::failed("t1", Duration::ZERO, "bad"));
+
+            assert_eq!(
+                reporter.andon_cord().map(|a| a.test_name.as_str()),
+                Some("t1")
+            );
+            assert_eq!(
+                reporter.to_github_annotations(),
+                "::error::t1: bad\n::error::ANDON CORD PULLED: Test 't1' failed: bad\n"
+            );
+        }
+
+        #[test]
+        fn test_annotation_escapes_properties() {
+            assert_eq!(escape_annotation_property("a:b,c%"), "a%3Ab%2Cc%25");
+        }
+
+        #[test]
+        fn test_no_annotations_when_passing() {
+            let mut reporter = Reporter::andon();
+            reporter
+                .record(TestResultEntry::passed("ok", Duration::ZERO))
+                .unwrap();
+            assert!(reporter.to_github_annotations().is_empty());
+            assert!(reporter.andon_cord().is_none());
+        }
+    }
+
+    mod flaky_detection_tests {
+        use super::*;
+        use crate::harness::TestResult;
+
+        fn run(results: Vec<TestResult>) -> SuiteResults {
+            SuiteResults {
+                suite_name: "nightly".to_string(),
+                results,
+                duration: Duration::ZERO,
+            }
+        }
+
+        #[test]
+        fn test_merge_runs_classifies_flaky() {
+            let runs = [
+                run(vec![TestResult::pass("stable"), TestResult::pass("jump")]),
+                run(vec![
+                    TestResult::pass("stable"),
+                    TestResult::fail("jump", "timed out"),
+                ]),
+                run(vec![TestResult::pass("stable"), TestResult::pass("jump")]),
+            ];
+
+            let report = Reporter::merge_runs(&runs);
+            assert_eq!(report.runs, 3);
+
+            let flaky = report.flaky();
+            assert_eq!(flaky.len(), 1);
+            assert_eq!(flaky[0].name, "jump");
+            assert_eq!(flaky[0].status, TestStatus::Flaky);
+            assert_eq!(flaky[0].failures, 1);
+            assert!((flaky[0].flakiness_rate - 0.33).abs() < 0.01);
+            assert_eq!(flaky[0].last_error.as_deref(), Some("timed out"));
+
+            assert_eq!(report.tests[0].status, TestStatus::Passed);
+            assert!(!report.all_stable_passing());
+        }
+
+        #[test]
+        fn test_merge_runs_stable_failure() {
+            let runs = [
+                run(vec![TestResult::fail("broken", "e1")]),
+                run(vec![TestResult::fail("broken", "e2")]),
+            ];
+
+            let report = Reporter::merge_runs(&runs);
+            assert!(report.flaky().is_empty());
+            let failures = report.stable_failures();
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].flakiness_rate, 0.0);
+            assert_eq!(failures[0].last_error.as_deref(), Some("e2"));
+        }
+
+        #[test]
+        fn test_merge_runs_empty() {
+            let report = Reporter::merge_runs(&[]);
+            assert_eq!(report.runs, 0);
+            assert!(report.all_stable_passing());
+        }
+
+        #[test]
+        fn test_flaky_status_rendered_in_html() {
+            let mut reporter = Reporter::collect_all();
+            let mut entry = TestResultEntry::passed("jump", Duration::ZERO);
+            entry.status = TestStatus::Flaky;
+            reporter.record(entry).unwrap();
+
+            assert!(TestStatus::Flaky.is_flaky());
+            assert!(reporter.render_html().contains(r#"class="test flaky""#));
+        }
+    }
 }
@@ -221,4 +221,29 @@ pub enum ProbarError {
         /// Error message
         message: String,
     },
+
+    /// Memory access outside bounds or with bad alignment
+    #[error("Out of bounds: {message}")]
+    OutOfBounds {
+        /// Error message
+        message: String,
+    },
+
+    /// File rejected by a file chooser's `accept` list
+    #[error("File {name} ({mime_type}) not accepted by \"{accept}\"")]
+    FileRejected {
+        /// Rejected file name
+        name: String,
+        /// MIME type guessed from the file name
+        mime_type: String,
+        /// Accept list the file was checked against
+        accept: String,
+    },
+
+    /// Operation not supported by this driver or backend
+    #[error("Unsupported operation: {operation}")]
+    UnsupportedOperation {
+        /// Name of the unsupported operation
+        operation: String,
+    },
 }
@@ -30,9 +30,11 @@
 
 use crate::event::InputEvent;
 use crate::result::{ProbarError, ProbarResult};
+use crate::wait::WaitCondition;
 use serde::{Deserialize, Serialize};
-use std::collections::{hash_map::DefaultHasher, VecDeque};
+use std::collections::{hash_map::DefaultHasher, BTreeMap, HashMap, VecDeque};
 use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, PoisonError};
 
 #[cfg(feature = "runtime")]
 use wasmtime::{Caller, Engine, Instance, Linker, Module, Store};
@@ -136,6 +138,8 @@ pub struct StateDelta {
     pub target_frame: u64,
     /// Changed memory regions (offset, data)
     pub changes: Vec<(usize, Vec<u8>)>,
+    /// Changed named fields (dotted path, new value or `None` if removed)
+    pub fields: Vec<(String, Option<serde_json::Value>)>,
     /// Checksum of resulting state
     pub checksum: u64,
 }
@@ -148,6 +152,7 @@ impl StateDelta {
             base_frame: frame,
             target_frame: frame,
             changes: Vec::new(),
+            fields: Vec::new(),
             checksum: 0,
         }
     }
@@ -184,6 +189,42 @@ impl StateDelta {
             base_frame,
             target_frame,
             changes,
+            fields: Vec::new(),
+            checksum,
+        }
+    }
+
+    /// Compute delta between two named-field states
+    ///
+    /// Nested JSON objects are flattened to dotted paths such as
+    /// `player.position.x`; differing fields are sorted by path.
+    #[must_use]
+    pub fn compute_fields(
+        base: &HashMap<String, serde_json::Value>,
+        current: &HashMap<String, serde_json::Value>,
+        base_frame: u64,
+        target_frame: u64,
+    ) -> Self {
+        let base = flatten_fields(base);
+        let current = flatten_fields(current);
+
+        let mut paths: Vec<&String> = base.keys().chain(current.keys()).collect();
+        paths.sort();
+        paths.dedup();
+
+        let fields = paths
+            .into_iter()
+            .filter(|path| base.get(*path) != current.get(*path))
+            .map(|path| (path.clone(), current.get(path).cloned()))
+            .collect();
+
+        let checksum = Self::compute_checksum(&serde_json::to_vec(&current).unwrap_or_default());
+
+        Self {
+            base_frame,
+            target_frame,
+            changes: Vec::new(),
+            fields,
             checksum,
         }
     }
@@ -215,6 +256,34 @@ impl StateDelta {
     }
 }
 
+/// Flatten nested JSON objects into dotted-path leaves
+fn flatten_fields(
+    state: &HashMap<String, serde_json::Value>,
+) -> BTreeMap<String, serde_json::Value> {
+    fn walk(
+        path: String,
+        value: &serde_json::Value,
+        out: &mut BTreeMap<String, serde_json::Value>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) if !map.is_empty() => {
+                for (key, child) in map {
+                    walk(format!("{path}.{key}"), child, out);
+                }
+            }
+            _ => {
+                out.insert(path, value.clone());
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    for (key, value) in state {
+        walk(key.clone(), value, &mut out);
+    }
+    out
+}
+
 /// Host state accessible to WASM guest
 ///
 /// This struct holds the state that the WASM module can interact with
@@ -274,6 +343,8 @@ pub struct MemoryView {
     entity_table_offset: usize,
     /// Offset to component arrays
     component_arrays_offset: usize,
+    /// Per-component array offsets (component ID -> byte offset)
+    component_offsets: HashMap<ComponentId, usize>,
     /// Entity count
     entity_count: usize,
 }
@@ -286,6 +357,7 @@ impl MemoryView {
             size,
             entity_table_offset: 0,
             component_arrays_offset: 0,
+            component_offsets: HashMap::new(),
             entity_count: 0,
         }
     }
@@ -305,6 +377,28 @@ impl MemoryView {
         self
     }
 
+    /// Configure the array location for a specific component type
+    #[must_use]
+    pub fn with_component_array<T: ProbarComponent>(mut self, offset: usize) -> Self {
+        self.component_offsets.insert(T::component_id(), offset);
+        self
+    }
+
+    /// Byte offset of `entity`'s `T` component
+    ///
+    /// Components are laid out as one dense array per type, indexed by
+    /// entity ID. Types without a registered array use
+    /// `component_arrays_offset`.
+    #[must_use]
+    pub fn component_offset<T: ProbarComponent>(&self, entity: EntityId) -> usize {
+        let base = self
+            .component_offsets
+            .get(&T::component_id())
+            .copied()
+            .unwrap_or(self.component_arrays_offset);
+        base + entity.raw() as usize * T::layout().size()
+    }
+
     /// Get the memory size
     #[must_use]
     pub const fn size(&self) -> usize {
@@ -353,29 +447,217 @@ impl MemoryView {
         Ok(core::ptr::read_unaligned(ptr))
     }
 
-    /// Read a slice from memory
+    /// Read a typed slice of `len` elements from memory
     ///
-    /// # Safety
+    /// Zero-copy and safe: the range `offset..offset + len * size_of::<T>()`
+    /// must lie within `memory` and `offset` must satisfy `T`'s alignment.
     ///
-    /// Caller must ensure the memory region is valid
+    /// # Errors
+    ///
+    /// Returns `ProbarError::OutOfBounds` if the range exceeds the memory or
+    /// the start address is misaligned for `T`.
     #[inline]
-    pub fn read_slice<'a>(
+    pub fn read_slice<'a, T: Pod>(
         &self,
         memory: &'a [u8],
         offset: usize,
         len: usize,
-    ) -> ProbarResult<&'a [u8]> {
-        if offset + len > memory.len() {
-            return Err(ProbarError::WasmError {
+    ) -> ProbarResult<&'a [T]> {
+        let size = core::mem::size_of::<T>();
+        let end = len
+            .checked_mul(size)
+            .and_then(|bytes| bytes.checked_add(offset))
+            .filter(|end| *end <= memory.len())
+            .ok_or_else(|| ProbarError::OutOfBounds {
                 message: format!(
-                    "Slice out of bounds: offset {} + len {} > memory {}",
+                    "Slice out of bounds: offset {} + {} x {} bytes > memory {}",
                     offset,
                     len,
+                    size,
                     memory.len()
                 ),
+            })?;
+
+        let bytes = &memory[offset..end];
+        let align = core::mem::align_of::<T>();
+        if (bytes.as_ptr() as usize) % align != 0 {
+            return Err(ProbarError::OutOfBounds {
+                message: format!("Misaligned read: offset {offset} is not {align}-byte aligned"),
             });
         }
-        Ok(&memory[offset..offset + len])
+
+        // SAFETY: the range is in bounds and aligned for `T`, and `T: Pod`
+        // guarantees every bit pattern is a valid value.
+        Ok(unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast::<T>(), len) })
+    }
+}
+
+/// Plain-old-data types that can be viewed directly from WASM memory
+///
+/// # Safety
+///
+/// Implementors must be `Copy`, contain no padding, and be valid for every
+/// possible bit pattern.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($t:ty),*) => {
+        $(
+            // SAFETY: primitive numeric types have no padding and no invalid
+            // bit patterns.
+            unsafe impl Pod for $t {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+/// Changes of one watched component, in frame order
+///
+/// Returned by [`ComponentWatcher::watch`] and
+/// [`WasmRuntime::watch_component`]. Iterating yields the values queued so
+/// far without blocking; as a [`WaitCondition`] it is satisfied once a
+/// change is pending, so [`Waiter::wait_for`](crate::wait::Waiter::wait_for)
+/// wakes on the change itself.
+#[derive(Debug)]
+pub struct ComponentChanges<T> {
+    queue: Arc<Mutex<VecDeque<T>>>,
+}
+
+impl<T> Clone for ComponentChanges<T> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: Arc::clone(&self.queue),
+        }
+    }
+}
+
+impl<T> ComponentChanges<T> {
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<T>> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Check whether a change is waiting to be taken
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        !self.lock().is_empty()
+    }
+
+    /// Take every queued value
+    pub fn drain(&self) -> Vec<T> {
+        self.lock().drain(..).collect()
+    }
+}
+
+impl<T> Iterator for ComponentChanges<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.lock().pop_front()
+    }
+}
+
+impl<T: Send> WaitCondition for ComponentChanges<T> {
+    fn check(&self) -> bool {
+        self.has_changes()
+    }
+
+    fn description(&self) -> String {
+        format!("change of {}", std::any::type_name::<T>())
+    }
+}
+
+/// Watched byte range and the callback fired when it changes
+struct WatchSlot {
+    offset: usize,
+    size: usize,
+    last: Option<Vec<u8>>,
+    notify: Box<dyn FnMut(&[u8]) + Send>,
+}
+
+impl std::fmt::Debug for WatchSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatchSlot")
+            .field("offset", &self.offset)
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Component change subscriptions backed by per-frame memory diffing
+///
+/// Each watch remembers the bytes of one component; [`Self::observe`]
+/// compares them against a fresh memory snapshot and fires the watch's
+/// callback with the new value whenever they differ.
+#[derive(Debug, Default)]
+pub struct ComponentWatcher {
+    slots: Vec<WatchSlot>,
+}
+
+impl ComponentWatcher {
+    /// Create an empty watcher
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `callback` with each new value of the component at `offset`
+    ///
+    /// `memory` is the baseline; the current value is not reported, only
+    /// later changes are. Values whose byte length does not match
+    /// `size_of::<T>()` are skipped.
+    pub fn on_change<T, F>(&mut self, offset: usize, memory: &[u8], mut callback: F)
+    where
+        T: ProbarComponent + Pod,
+        F: FnMut(T) + Send + 'static,
+    {
+        let size = T::layout().size();
+        self.slots.push(WatchSlot {
+            offset,
+            size,
+            last: memory.get(offset..offset + size).map(<[u8]>::to_vec),
+            notify: Box::new(move |bytes| {
+                if bytes.len() == core::mem::size_of::<T>() {
+                    // SAFETY: `bytes` is exactly `size_of::<T>()` long, and
+                    // `T: Pod` guarantees every bit pattern is a valid value.
+                    callback(unsafe { core::ptr::read_unaligned(bytes.as_ptr().cast::<T>()) });
+                }
+            }),
+        });
+    }
+
+    /// Queue each new value of the component at `offset`
+    ///
+    /// See [`Self::on_change`] for how changes are detected.
+    pub fn watch<T>(&mut self, offset: usize, memory: &[u8]) -> ComponentChanges<T>
+    where
+        T: ProbarComponent + Pod + Send,
+    {
+        let changes = ComponentChanges {
+            queue: Arc::new(Mutex::new(VecDeque::new())),
+        };
+        let sink = changes.clone();
+        self.on_change(offset, memory, move |value: T| sink.lock().push_back(value));
+        changes
+    }
+
+    /// Diff all watched components against a new memory snapshot
+    pub fn observe(&mut self, memory: &[u8]) {
+        for slot in &mut self.slots {
+            let current = memory.get(slot.offset..slot.offset + slot.size);
+            if current != slot.last.as_deref() {
+                if let Some(bytes) = current {
+                    (slot.notify)(bytes);
+                }
+                slot.last = current.map(<[u8]>::to_vec);
+            }
+        }
+    }
+
+    /// Number of active watches
+    #[must_use]
+    pub fn watch_count(&self) -> usize {
+        self.slots.len()
     }
 }
 
@@ -447,6 +729,7 @@ pub struct WasmRuntime {
     store: Store<GameHostState>,
     instance: Instance,
     memory_view: MemoryView,
+    watcher: ComponentWatcher,
 }
 
 #[cfg(feature = "runtime")]
@@ -533,6 +816,7 @@ impl WasmRuntime {
             store,
             instance,
             memory_view,
+            watcher: ComponentWatcher::new(),
         })
     }
 
@@ -634,6 +918,14 @@ impl WasmRuntime {
         let execution_time = start.elapsed();
         let state_hash = self.compute_state_hash();
 
+        if self.watcher.watch_count() > 0 {
+            let memory = self
+                .instance
+                .get_memory(&mut self.store, "memory")
+                .expect("memory export required");
+            self.watcher.observe(memory.data(&self.store));
+        }
+
         #[allow(clippy::cast_possible_truncation)]
         let execution_time_ns = execution_time.as_nanos() as u64;
 
@@ -674,6 +966,44 @@ impl WasmRuntime {
         &self.memory_view
     }
 
+    /// Replace the memory view layout used for component access
+    pub fn set_memory_view(&mut self, view: MemoryView) {
+        self.memory_view = view;
+    }
+
+    /// Subscribe to changes of `entity`'s `T` component
+    ///
+    /// After every [`Self::step`], the component's bytes are diffed against
+    /// the previous frame and each new value is pushed to the returned
+    /// [`ComponentChanges`], which can be iterated or waited on.
+    pub fn watch_component<T>(&mut self, entity: EntityId) -> ComponentChanges<T>
+    where
+        T: ProbarComponent + Pod + Send,
+    {
+        let offset = self.memory_view.component_offset::<T>(entity);
+        let memory = self
+            .instance
+            .get_memory(&mut self.store, "memory")
+            .expect("memory export required");
+        self.watcher.watch(offset, memory.data(&self.store))
+    }
+
+    /// Call `callback` from [`Self::step`] whenever `entity`'s `T`
+    /// component changes
+    pub fn on_component_change<T, F>(&mut self, entity: EntityId, callback: F)
+    where
+        T: ProbarComponent + Pod,
+        F: FnMut(T) + Send + 'static,
+    {
+        let offset = self.memory_view.component_offset::<T>(entity);
+        let memory = self
+            .instance
+            .get_memory(&mut self.store, "memory")
+            .expect("memory export required");
+        self.watcher
+            .on_change(offset, memory.data(&self.store), callback);
+    }
+
     /// Record a snapshot of current state (delta-encoded)
     pub fn record_snapshot(&mut self) {
         let memory = self.get_memory().to_vec();
@@ -937,7 +1267,7 @@ mod tests {
         fn test_memory_view_read_slice() {
             let view = MemoryView::new(1024);
             let memory = vec![1, 2, 3, 4, 5, 6, 7, 8];
-            let slice = view.read_slice(&memory, 2, 4).unwrap();
+            let slice = view.read_slice::<u8>(&memory, 2, 4).unwrap();
             assert_eq!(slice, &[3, 4, 5, 6]);
         }
 
@@ -945,9 +1275,218 @@ mod tests {
         fn test_memory_view_read_slice_out_of_bounds() {
             let view = MemoryView::new(1024);
             let memory = vec![1, 2, 3, 4];
-            let result = view.read_slice(&memory, 2, 10);
+            let result = view.read_slice::<u8>(&memory, 2, 10);
             assert!(result.is_err());
         }
+
+        /// First offset into `memory` whose address is `align`-aligned
+        fn aligned_offset(memory: &[u8], align: usize) -> usize {
+            (0..align)
+                .find(|off| (memory.as_ptr() as usize + off) % align == 0)
+                .unwrap()
+        }
+
+        #[test]
+        fn test_memory_view_read_slice_typed() {
+            let view = MemoryView::new(64);
+            let mut memory = vec![0u8; 64];
+            let base = aligned_offset(&memory, 4);
+            for (i, value) in [10u32, 20, 30].iter().enumerate() {
+                let at = base + i * 4;
+                memory[at..at + 4].copy_from_slice(&value.to_ne_bytes());
+            }
+
+            let values = view.read_slice::<u32>(&memory, base, 3).unwrap();
+            assert_eq!(values, &[10, 20, 30]);
+        }
+
+        #[test]
+        fn test_memory_view_read_slice_misaligned() {
+            let view = MemoryView::new(64);
+            let memory = vec![0u8; 64];
+            let misaligned = aligned_offset(&memory, 4) + 1;
+
+            let result = view.read_slice::<u32>(&memory, misaligned, 2);
+            assert!(matches!(result, Err(ProbarError::OutOfBounds { .. })));
+        }
+
+        #[test]
+        fn test_memory_view_read_slice_typed_out_of_bounds() {
+            let view = MemoryView::new(64);
+            let memory = vec![0u8; 64];
+            let base = aligned_offset(&memory, 8);
+
+            // 8 f64s would need 64 bytes starting at base
+            let result = view.read_slice::<f64>(&memory, base + 8, 8);
+            assert!(matches!(result, Err(ProbarError::OutOfBounds { .. })));
+            assert!(view.read_slice::<f64>(&memory, 0, usize::MAX).is_err());
+        }
+
+        #[test]
+        fn test_memory_view_read_slice_empty() {
+            let view = MemoryView::new(64);
+            let memory = vec![0u8; 8];
+            assert!(view.read_slice::<u8>(&memory, 8, 0).unwrap().is_empty());
+        }
+    }
+
+    mod component_watch_tests {
+        use super::*;
+
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Position {
+            x: f32,
+            y: f32,
+        }
+
+        // SAFETY: two `f32`s with `repr(C)` have no padding and no invalid
+        // bit patterns.
+        unsafe impl Pod for Position {}
+
+        impl ProbarComponent for Position {
+            fn component_id() -> ComponentId {
+                ComponentId::of::<Self>()
+            }
+
+            fn layout() -> std::alloc::Layout {
+                std::alloc::Layout::new::<Self>()
+            }
+        }
+
+        fn write_position(memory: &mut [u8], offset: usize, x: f32, y: f32) {
+            memory[offset..offset + 4].copy_from_slice(&x.to_le_bytes());
+            memory[offset + 4..offset + 8].copy_from_slice(&y.to_le_bytes());
+        }
+
+        #[test]
+        fn test_component_offset() {
+            let view = MemoryView::new(1024)
+                .with_component_arrays(16)
+                .with_component_array::<Position>(64);
+            assert_eq!(view.component_offset::<Position>(EntityId::new(0)), 64);
+            assert_eq!(
+                view.component_offset::<Position>(EntityId::new(3)),
+                64 + 3 * 8
+            );
+        }
+
+        #[test]
+        fn test_component_offset_falls_back_to_arrays_offset() {
+            let view = MemoryView::new(1024).with_component_arrays(16);
+            assert_eq!(view.component_offset::<Position>(EntityId::new(2)), 32);
+        }
+
+        #[test]
+        fn test_watch_yields_exactly_changed_values() {
+            let view = MemoryView::new(128).with_component_array::<Position>(32);
+            let player = EntityId::new(1);
+            let offset = view.component_offset::<Position>(player);
+
+            let mut memory = vec![0u8; 128];
+            write_position(&mut memory, offset, 1.0, 1.0);
+
+            let mut watcher = ComponentWatcher::new();
+            let changes = watcher.watch::<Position>(offset, &memory);
+
+            // Frame 1: moves, frame 2: unchanged, frame 3: another entity moves,
+            // frame 4: moves again
+            write_position(&mut memory, offset, 2.0, 1.0);
+            watcher.observe(&memory);
+            watcher.observe(&memory);
+            write_position(&mut memory, offset + 8, 9.0, 9.0);
+            watcher.observe(&memory);
+            write_position(&mut memory, offset, 2.0, 5.0);
+            watcher.observe(&memory);
+
+            assert!(changes.has_changes());
+            assert_eq!(
+                changes.clone().collect::<Vec<_>>(),
+                vec![Position { x: 2.0, y: 1.0 }, Position { x: 2.0, y: 5.0 }]
+            );
+            assert!(!changes.has_changes());
+            assert!(changes.drain().is_empty());
+        }
+
+        #[test]
+        fn test_on_change_fires_callback() {
+            let seen = Arc::new(Mutex::new(Vec::new()));
+            let sink = Arc::clone(&seen);
+            let mut memory = vec![0u8; 8];
+
+            let mut watcher = ComponentWatcher::new();
+            watcher.on_change(0, &memory, move |p: Position| {
+                sink.lock().unwrap().push(p.x);
+            });
+            write_position(&mut memory, 0, 4.0, 0.0);
+            watcher.observe(&memory);
+            watcher.observe(&memory);
+
+            assert_eq!(*seen.lock().unwrap(), vec![4.0]);
+        }
+
+        #[test]
+        fn test_wait_for_wakes_on_component_change() {
+            use crate::wait::{WaitOptions, Waiter};
+
+            let mut watcher = ComponentWatcher::new();
+            let changes = watcher.watch::<Position>(0, &[0u8; 8]);
+            let options = WaitOptions::new().with_timeout(50).with_poll_interval(5);
+            assert!(Waiter::new().wait_for(&changes, &options).is_err());
+
+            let stepper = std::thread::spawn(move || {
+                let mut memory = vec![0u8; 8];
+                write_position(&mut memory, 0, 7.0, 8.0);
+                watcher.observe(&memory);
+            });
+            let options = WaitOptions::new().with_timeout(5000).with_poll_interval(5);
+            let result = Waiter::new().wait_for(&changes, &options).unwrap();
+            stepper.join().unwrap();
+
+            assert!(result.waited_for.contains("Position"));
+            assert_eq!(changes.drain(), vec![Position { x: 7.0, y: 8.0 }]);
+        }
+
+        #[test]
+        fn test_watch_out_of_bounds_until_memory_grows() {
+            let mut watcher = ComponentWatcher::new();
+            let changes = watcher.watch::<Position>(16, &[0u8; 8]);
+            watcher.observe(&[0u8; 8]);
+            assert!(!changes.has_changes());
+
+            let mut grown = vec![0u8; 32];
+            write_position(&mut grown, 16, 3.0, 4.0);
+            watcher.observe(&grown);
+            assert_eq!(changes.drain(), vec![Position { x: 3.0, y: 4.0 }]);
+        }
+
+        #[test]
+        fn test_changes_skip_values_with_wrong_size() {
+            /// Declares a layout smaller than the type itself
+            #[repr(C)]
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            struct Truncated(u32, u32);
+
+            // SAFETY: two `u32`s with `repr(C)` have no padding and no
+            // invalid bit patterns.
+            unsafe impl Pod for Truncated {}
+
+            impl ProbarComponent for Truncated {
+                fn component_id() -> ComponentId {
+                    ComponentId::of::<Self>()
+                }
+
+                fn layout() -> std::alloc::Layout {
+                    std::alloc::Layout::new::<u32>()
+                }
+            }
+
+            let mut watcher = ComponentWatcher::new();
+            let changes = watcher.watch::<Truncated>(0, &[0u8; 8]);
+            watcher.observe(&[1u8; 8]);
+            assert_eq!(watcher.watch_count(), 1);
+            assert!(!changes.has_changes());
+        }
     }
 
     mod runtime_config_tests {
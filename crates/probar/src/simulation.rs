@@ -18,7 +18,9 @@
 use crate::event::InputEvent;
 use crate::fuzzer::Seed;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
 use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
 
 /// Configuration for simulation runs
 #[derive(Debug, Clone, Copy)]
@@ -33,6 +35,8 @@ pub struct SimulationConfig {
     pub max_entities: usize,
     /// Whether to record full state history
     pub record_states: bool,
+    /// Whether to record a state hash per frame for replay verification
+    pub record_hashes: bool,
 }
 
 impl Default for SimulationConfig {
@@ -43,6 +47,7 @@ impl Default for SimulationConfig {
             fps: 60,
             max_entities: 2000,
             record_states: false,
+            record_hashes: true,
         }
     }
 }
@@ -57,6 +62,7 @@ impl SimulationConfig {
             fps: 60,
             max_entities: 2000,
             record_states: false,
+            record_hashes: true,
         }
     }
 
@@ -81,6 +87,13 @@ impl SimulationConfig {
         self
     }
 
+    /// Enable per-frame state hash recording
+    #[must_use]
+    pub const fn with_hash_recording(mut self, enabled: bool) -> Self {
+        self.record_hashes = enabled;
+        self
+    }
+
     /// Get the seed as a Seed type
     #[must_use]
     pub const fn as_seed(&self) -> Seed {
@@ -96,6 +109,9 @@ pub struct RecordedFrame {
     /// Input events for this frame
     pub inputs: Vec<InputEvent>,
     /// Hash of game state after this frame (for verification)
+    ///
+    /// Only meaningful when the recording's [`SimulationConfig::record_hashes`]
+    /// is set; otherwise `run_simulation` leaves it at 0.
     pub state_hash: u64,
 }
 
@@ -164,6 +180,17 @@ impl SimulationRecording {
     }
 }
 
+/// First frame at which a replay's state hash differed from the recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameDivergence {
+    /// Frame number
+    pub frame: u64,
+    /// Hash recorded in the original run
+    pub expected_hash: u64,
+    /// Hash produced by the replay
+    pub actual_hash: u64,
+}
+
 /// Result of replaying a simulation
 #[derive(Debug, Clone)]
 pub struct ReplayResult {
@@ -175,6 +202,8 @@ pub struct ReplayResult {
     pub determinism_verified: bool,
     /// Frame where divergence occurred (if any)
     pub divergence_frame: Option<u64>,
+    /// Expected vs. actual hash at the first divergent frame (if any)
+    pub divergence: Option<FrameDivergence>,
     /// Error message if replay failed
     pub error: Option<String>,
 }
@@ -188,6 +217,7 @@ impl ReplayResult {
             frames_replayed,
             determinism_verified: true,
             divergence_frame: None,
+            divergence: None,
             error: None,
         }
     }
@@ -200,6 +230,11 @@ impl ReplayResult {
             frames_replayed: divergence_frame,
             determinism_verified: false,
             divergence_frame: Some(divergence_frame),
+            divergence: Some(FrameDivergence {
+                frame: divergence_frame,
+                expected_hash,
+                actual_hash,
+            }),
             error: Some(format!(
                 "State diverged at frame {divergence_frame}: expected hash {expected_hash}, got {actual_hash}"
             )),
@@ -304,6 +339,20 @@ impl SimulatedGameState {
         hasher.finish()
     }
 
+    /// Hash of the action-controlled state (position, health, score)
+    ///
+    /// Unlike [`compute_hash`](Self::compute_hash) this ignores the frame
+    /// counter and RNG, so revisiting a position yields the same hash.
+    #[must_use]
+    pub fn coverage_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.player_x.to_bits().hash(&mut hasher);
+        self.player_y.to_bits().hash(&mut hasher);
+        self.health.hash(&mut hasher);
+        self.score.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Check if state is valid (invariants hold)
     #[must_use]
     pub const fn is_valid(&self) -> bool {
@@ -353,17 +402,26 @@ where
         let recorded_frame = RecordedFrame {
             frame,
             inputs,
-            state_hash: state.compute_hash(),
+            state_hash: if config.record_hashes {
+                state.compute_hash()
+            } else {
+                0
+            },
         };
         recording.add_frame(recorded_frame);
     }
 
+    recording.final_state_hash = state.compute_hash();
     recording.mark_completed();
     recording
 }
 
 /// Replay a simulation recording and verify determinism
 ///
+/// When the recording has per-frame hashes ([`SimulationConfig::record_hashes`]),
+/// each frame is compared as it is replayed, so the first nondeterministic
+/// frame is reported. Otherwise only the final state can be compared.
+///
 /// # Arguments
 /// * `recording` - The original recording to replay
 ///
@@ -371,58 +429,219 @@ where
 /// Result of the replay including whether determinism was verified
 #[must_use]
 pub fn run_replay(recording: &SimulationRecording) -> ReplayResult {
+    run_replay_with(recording, |_, _| {})
+}
+
+/// Replay a simulation recording, calling `after_tick` after each frame update
+///
+/// The hook sees the frame number and the replayed state before its hash
+/// is checked, which allows injecting faults when testing divergence
+/// detection.
+#[must_use]
+pub fn run_replay_with<F>(recording: &SimulationRecording, mut after_tick: F) -> ReplayResult
+where
+    F: FnMut(u64, &mut SimulatedGameState),
+{
     let mut state = SimulatedGameState::new(recording.config.seed);
 
     for recorded_frame in &recording.frames {
         // Apply the same inputs
         state.update(&recorded_frame.inputs);
+        after_tick(recorded_frame.frame, &mut state);
 
         // Verify state hash matches
-        let current_hash = state.compute_hash();
-        if current_hash != recorded_frame.state_hash {
-            return ReplayResult::diverged(
-                recorded_frame.frame,
-                recorded_frame.state_hash,
-                current_hash,
-            );
+        if recording.config.record_hashes {
+            let current_hash = state.compute_hash();
+            if current_hash != recorded_frame.state_hash {
+                return ReplayResult::diverged(
+                    recorded_frame.frame,
+                    recorded_frame.state_hash,
+                    current_hash,
+                );
+            }
         }
     }
 
-    ReplayResult::success(state.compute_hash(), recording.total_frames)
+    // Completed recordings always carry the final hash, even without per-frame hashes
+    let final_hash = state.compute_hash();
+    if recording.completed && final_hash != recording.final_state_hash {
+        return ReplayResult::diverged(
+            recording.total_frames.saturating_sub(1),
+            recording.final_state_hash,
+            final_hash,
+        );
+    }
+
+    ReplayResult::success(final_hash, recording.total_frames)
 }
 
 /// A random walk agent for testing
+///
+/// Picks one action per frame from a weighted action table. In
+/// epsilon-greedy mode ([`next_inputs_for`](Self::next_inputs_for)) it
+/// prefers actions that lead to game states it has not visited yet.
 #[derive(Debug, Clone)]
 pub struct RandomWalkAgent {
     state: u64,
+    /// Candidate actions with their relative weights (None = arrow keys and Space)
+    actions: Option<Vec<(InputEvent, u32)>>,
+    /// Probability of a purely random step in epsilon-greedy mode
+    epsilon: f64,
+    /// Hashes of states reached so far
+    visited: BTreeSet<u64>,
+}
+
+/// Arrow keys and Space with equal weights
+fn default_actions() -> &'static [(InputEvent, u32)] {
+    static DEFAULT: OnceLock<Vec<(InputEvent, u32)>> = OnceLock::new();
+    DEFAULT.get_or_init(|| {
+        ["ArrowUp", "ArrowDown", "ArrowLeft", "ArrowRight", "Space"]
+            .into_iter()
+            .map(|key| (InputEvent::key_press(key), 1))
+            .collect()
+    })
 }
 
 impl RandomWalkAgent {
     /// Create a new random walk agent with a seed
+    ///
+    /// Uses the arrow keys and Space with equal weights.
     #[must_use]
     pub const fn new(seed: Seed) -> Self {
         Self {
             state: seed.value(),
+            actions: None,
+            epsilon: 1.0,
+            visited: BTreeSet::new(),
+        }
+    }
+
+    /// Create an agent choosing from `actions` in proportion to their weights
+    ///
+    /// Zero-weight actions are never chosen by a random step.
+    #[must_use]
+    pub fn weighted(seed: Seed, actions: Vec<(InputEvent, u32)>) -> Self {
+        Self {
+            state: seed.value(),
+            actions: Some(actions),
+            epsilon: 1.0,
+            visited: BTreeSet::new(),
+        }
+    }
+
+    /// Prefer unseen states, exploring randomly with probability `epsilon`
+    #[must_use]
+    pub fn with_epsilon_greedy(mut self, epsilon: f64) -> Self {
+        self.epsilon = epsilon.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Get the action table
+    #[must_use]
+    pub fn actions(&self) -> &[(InputEvent, u32)] {
+        match &self.actions {
+            Some(actions) => actions,
+            None => default_actions(),
         }
     }
 
+    /// Number of distinct states visited in epsilon-greedy mode
+    #[must_use]
+    pub fn visited_states(&self) -> usize {
+        self.visited.len()
+    }
+
     /// Generate inputs for the next frame
     pub fn next_inputs(&mut self) -> Vec<InputEvent> {
+        let candidates: Vec<usize> = (0..self.actions().len()).collect();
+        self.pick_weighted(&candidates)
+            .map(|i| vec![self.actions()[i].0.clone()])
+            .unwrap_or_default()
+    }
+
+    /// Generate inputs for the next frame given the current game state
+    ///
+    /// With probability `1 - epsilon` the agent restricts its choice to
+    /// actions whose resulting state has not been visited (falling back to
+    /// all actions when every outcome is known).
+    pub fn next_inputs_for(&mut self, state: &SimulatedGameState) -> Vec<InputEvent> {
+        self.visited.insert(state.coverage_hash());
+
+        let outcomes: Vec<u64> = self
+            .actions()
+            .iter()
+            .map(|(action, _)| {
+                let mut next = state.clone();
+                next.update(std::slice::from_ref(action));
+                next.coverage_hash()
+            })
+            .collect();
+
+        let all: Vec<usize> = (0..self.actions().len()).collect();
+        let explore = self.next_unit() < self.epsilon;
+        let unseen: Vec<usize> = all
+            .iter()
+            .copied()
+            .filter(|&i| !self.visited.contains(&outcomes[i]))
+            .collect();
+
+        let choice = if explore || unseen.is_empty() {
+            self.pick_weighted(&all)
+        } else {
+            self.pick_weighted(&unseen)
+                .or_else(|| self.pick_uniform(&unseen))
+        };
+
+        choice.map_or_else(Vec::new, |i| {
+            self.visited.insert(outcomes[i]);
+            vec![self.actions()[i].0.clone()]
+        })
+    }
+
+    /// Advance the xorshift generator
+    fn next_u64(&mut self) -> u64 {
         // Simple xorshift for determinism
         self.state ^= self.state << 13;
         self.state ^= self.state >> 7;
         self.state ^= self.state << 17;
+        self.state
+    }
 
-        let direction = self.state % 5;
-        let key = match direction {
-            0 => "ArrowUp",
-            1 => "ArrowDown",
-            2 => "ArrowLeft",
-            3 => "ArrowRight",
-            _ => "Space",
-        };
+    /// Uniform value in [0, 1)
+    #[allow(clippy::cast_precision_loss)]
+    fn next_unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Pick one of `candidates` (action indices) by weight
+    fn pick_weighted(&mut self, candidates: &[usize]) -> Option<usize> {
+        let total: u64 = candidates
+            .iter()
+            .map(|&i| u64::from(self.actions()[i].1))
+            .sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut roll = self.next_u64() % total;
+        for &i in candidates {
+            let weight = u64::from(self.actions()[i].1);
+            if roll < weight {
+                return Some(i);
+            }
+            roll -= weight;
+        }
+        None
+    }
 
-        vec![InputEvent::key_press(key)]
+    /// Pick one of `candidates` ignoring weights
+    #[allow(clippy::cast_possible_truncation)]
+    fn pick_uniform(&mut self, candidates: &[usize]) -> Option<usize> {
+        if candidates.is_empty() {
+            return None;
+        }
+        let index = (self.next_u64() % candidates.len() as u64) as usize;
+        Some(candidates[index])
     }
 }
 
@@ -644,6 +863,67 @@ mod tests {
                 "Full session replay should be deterministic"
             );
         }
+
+        fn space_every_tenth(frame: u64) -> Vec<InputEvent> {
+            if frame % 10 == 0 {
+                vec![InputEvent::key_press("Space")]
+            } else {
+                vec![]
+            }
+        }
+
+        #[test]
+        fn test_replay_records_hash_per_frame() {
+            let recording = run_simulation(SimulationConfig::new(7, 50), space_every_tenth);
+
+            assert!(recording.config.record_hashes);
+            assert!(recording.frames.iter().all(|f| f.state_hash != 0));
+
+            let replay = run_replay(&recording);
+            assert!(replay.determinism_verified);
+            assert!(replay.divergence.is_none());
+            assert_eq!(replay.frames_replayed, 50);
+        }
+
+        #[test]
+        fn test_replay_detects_nondeterministic_tick() {
+            let recording = run_simulation(SimulationConfig::new(7, 100), space_every_tenth);
+
+            // Inject a nondeterministic score bump at frame 37
+            let replay = run_replay_with(&recording, |frame, state| {
+                if frame == 37 {
+                    state.score += 1;
+                }
+            });
+
+            assert!(!replay.determinism_verified);
+            assert_eq!(replay.divergence_frame, Some(37));
+            let divergence = replay.divergence.unwrap();
+            assert_eq!(divergence.frame, 37);
+            assert_eq!(divergence.expected_hash, recording.frames[37].state_hash);
+            assert_ne!(divergence.expected_hash, divergence.actual_hash);
+            assert_eq!(replay.final_state_hash, divergence.actual_hash);
+        }
+
+        #[test]
+        fn test_replay_without_hashes_checks_final_state() {
+            let config = SimulationConfig::new(7, 100).with_hash_recording(false);
+            let recording = run_simulation(config, space_every_tenth);
+
+            assert!(recording.frames.iter().all(|f| f.state_hash == 0));
+            assert!(run_replay(&recording).determinism_verified);
+
+            // Without per-frame hashes divergence surfaces at the last frame
+            let replay = run_replay_with(&recording, |frame, state| {
+                if frame == 37 {
+                    state.score += 1;
+                }
+            });
+            assert!(!replay.determinism_verified);
+            let divergence = replay.divergence.unwrap();
+            assert_eq!(divergence.frame, 99);
+            assert_eq!(divergence.expected_hash, recording.final_state_hash);
+        }
     }
 
     mod agent_tests {
@@ -693,6 +973,96 @@ mod tests {
                 "Replay with same agent should match"
             );
         }
+
+        #[test]
+        fn test_default_actions_are_uniform_keys() {
+            let agent = RandomWalkAgent::new(Seed::from_u64(1));
+
+            assert_eq!(agent.actions().len(), 5);
+            assert!(agent.actions().iter().all(|(_, weight)| *weight == 1));
+        }
+
+        #[test]
+        fn test_weighted_action_frequencies() {
+            let click = InputEvent::mouse_click(100.0, 100.0);
+            let key = InputEvent::key_press("Space");
+            let mut agent = RandomWalkAgent::weighted(
+                Seed::from_u64(42),
+                vec![(click.clone(), 3), (key.clone(), 1)],
+            );
+
+            let steps = 10_000;
+            let mut clicks = 0;
+            for _ in 0..steps {
+                let inputs = agent.next_inputs();
+                assert_eq!(inputs.len(), 1);
+                if inputs[0] == click {
+                    clicks += 1;
+                } else {
+                    assert_eq!(inputs[0], key);
+                }
+            }
+
+            // 3:1 weighting => ~75% clicks
+            let ratio = f64::from(clicks) / f64::from(steps);
+            assert!((ratio - 0.75).abs() < 0.02, "click ratio {ratio}");
+        }
+
+        #[test]
+        fn test_zero_weight_action_never_chosen() {
+            let mut agent = RandomWalkAgent::weighted(
+                Seed::from_u64(9),
+                vec![
+                    (InputEvent::key_press("ArrowUp"), 1),
+                    (InputEvent::key_press("Escape"), 0),
+                ],
+            );
+
+            for _ in 0..500 {
+                assert_eq!(agent.next_inputs(), vec![InputEvent::key_press("ArrowUp")]);
+            }
+        }
+
+        #[test]
+        fn test_no_actions_yields_no_inputs() {
+            let mut agent = RandomWalkAgent::weighted(Seed::from_u64(3), vec![]);
+            assert!(agent.next_inputs().is_empty());
+            assert!(agent
+                .next_inputs_for(&SimulatedGameState::new(3))
+                .is_empty());
+        }
+
+        #[test]
+        fn test_epsilon_greedy_prefers_unseen_states() {
+            let seed = Seed::from_u64(2024);
+            let explore = |agent: &mut RandomWalkAgent| {
+                let mut state = SimulatedGameState::new(seed.value());
+                let mut positions = std::collections::HashSet::new();
+                for _ in 0..200 {
+                    let inputs = agent.next_inputs_for(&state);
+                    state.update(&inputs);
+                    positions.insert((state.player_x.to_bits(), state.player_y.to_bits()));
+                }
+                positions.len()
+            };
+
+            // Arrow keys only: a uniform walk keeps revisiting positions
+            let arrows: Vec<_> = ["ArrowUp", "ArrowDown", "ArrowLeft", "ArrowRight"]
+                .into_iter()
+                .map(|k| (InputEvent::key_press(k), 1))
+                .collect();
+            let mut random = RandomWalkAgent::weighted(seed, arrows.clone());
+            let mut greedy = RandomWalkAgent::weighted(seed, arrows).with_epsilon_greedy(0.1);
+
+            let random_positions = explore(&mut random);
+            let greedy_positions = explore(&mut greedy);
+
+            assert!(greedy.visited_states() > 0);
+            assert!(
+                greedy_positions > random_positions,
+                "greedy {greedy_positions} vs random {random_positions}"
+            );
+        }
     }
 
     mod additional_coverage_tests {
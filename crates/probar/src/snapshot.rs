@@ -2,6 +2,27 @@
 //!
 //! Per spec Section 6.2: Visual Regression Testing
 
+/// How snapshot data is interpreted when diffing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// Opaque bytes, compared one byte at a time
+    #[default]
+    Raw,
+    /// 8-bit RGBA pixels (4 bytes per pixel), compared per pixel
+    Rgba8,
+}
+
+impl PixelFormat {
+    /// Bytes per comparison unit
+    #[must_use]
+    pub const fn bytes_per_unit(self) -> usize {
+        match self {
+            Self::Raw => 1,
+            Self::Rgba8 => 4,
+        }
+    }
+}
+
 /// Configuration for snapshot testing
 #[derive(Debug, Clone)]
 pub struct SnapshotConfig {
@@ -11,6 +32,12 @@ pub struct SnapshotConfig {
     pub threshold: f64,
     /// Directory to store snapshots
     pub snapshot_dir: String,
+    /// How snapshot data is interpreted when diffing
+    pub pixel_format: PixelFormat,
+    /// Maximum per-channel difference still treated as equal (RGBA only)
+    pub channel_tolerance: u8,
+    /// Skip pixels that are fully transparent in both snapshots (RGBA only)
+    pub ignore_transparent: bool,
 }
 
 impl Default for SnapshotConfig {
@@ -19,6 +46,9 @@ impl Default for SnapshotConfig {
             update_snapshots: false,
             threshold: 0.01, // 1% difference allowed
             snapshot_dir: String::from("__snapshots__"),
+            pixel_format: PixelFormat::Raw,
+            channel_tolerance: 0,
+            ignore_transparent: false,
         }
     }
 }
@@ -44,6 +74,27 @@ impl SnapshotConfig {
         self.snapshot_dir = dir.into();
         self
     }
+
+    /// Set how snapshot data is interpreted when diffing
+    #[must_use]
+    pub const fn pixel_format(mut self, format: PixelFormat) -> Self {
+        self.pixel_format = format;
+        self
+    }
+
+    /// Set per-channel tolerance for RGBA comparison
+    #[must_use]
+    pub const fn with_channel_tolerance(mut self, tolerance: u8) -> Self {
+        self.channel_tolerance = tolerance;
+        self
+    }
+
+    /// Skip pixels that are fully transparent in both snapshots
+    #[must_use]
+    pub const fn with_ignore_transparent(mut self, ignore: bool) -> Self {
+        self.ignore_transparent = ignore;
+        self
+    }
 }
 
 /// A visual snapshot
@@ -92,6 +143,7 @@ impl Snapshot {
                 difference_count: 0,
                 difference_percent: 0.0,
                 diff_data: Vec::new(),
+                pixel_diffs: Vec::new(),
             };
         }
 
@@ -111,6 +163,83 @@ impl Snapshot {
             difference_count,
             difference_percent,
             diff_data: Vec::new(), // Would contain visual diff in full impl
+            pixel_diffs: Vec::new(),
+        }
+    }
+
+    /// Compare this snapshot to another using the config's pixel format
+    ///
+    /// With [`PixelFormat::Rgba8`], data is read as 4-byte pixels and a pixel
+    /// differs when any channel differs by more than `channel_tolerance`.
+    /// Missing trailing bytes count as transparent black. `difference_count`
+    /// and `difference_percent` are then per pixel, and `diff_data` is an RGBA
+    /// mask with differing pixels in opaque red.
+    #[must_use]
+    pub fn diff_with(&self, other: &Self, config: &SnapshotConfig) -> SnapshotDiff {
+        match config.pixel_format {
+            PixelFormat::Raw => self.diff(other),
+            PixelFormat::Rgba8 => self.diff_rgba(other, config),
+        }
+    }
+
+    fn diff_rgba(&self, other: &Self, config: &SnapshotConfig) -> SnapshotDiff {
+        const HIGHLIGHT: [u8; 4] = [255, 0, 0, 255];
+
+        let pixel = |data: &[u8], index: usize| -> [u8; 4] {
+            let mut px = [0u8; 4];
+            for (channel, value) in px.iter_mut().enumerate() {
+                *value = data.get(index * 4 + channel).copied().unwrap_or(0);
+            }
+            px
+        };
+
+        let pixel_count = self.data.len().max(other.data.len()).div_ceil(4);
+        let mut compared = 0usize;
+        let mut diff_data = vec![0u8; pixel_count * 4];
+        let mut pixel_diffs = Vec::new();
+
+        for index in 0..pixel_count {
+            let a = pixel(&self.data, index);
+            let b = pixel(&other.data, index);
+            if config.ignore_transparent && a[3] == 0 && b[3] == 0 {
+                continue;
+            }
+            compared += 1;
+
+            let max_channel_delta = a
+                .iter()
+                .zip(&b)
+                .map(|(x, y)| x.abs_diff(*y))
+                .max()
+                .unwrap_or(0);
+            if max_channel_delta > config.channel_tolerance {
+                diff_data[index * 4..index * 4 + 4].copy_from_slice(&HIGHLIGHT);
+                let (x, y) = match self.width.max(other.width) as usize {
+                    0 => (index, 0),
+                    width => (index % width, index / width),
+                };
+                pixel_diffs.push(PixelDiff {
+                    x: x as u32,
+                    y: y as u32,
+                    max_channel_delta,
+                });
+            }
+        }
+
+        let difference_count = pixel_diffs.len();
+        #[allow(clippy::cast_precision_loss)] // Acceptable for percentage calculation
+        let difference_percent = if compared == 0 {
+            0.0
+        } else {
+            (difference_count as f64 / compared as f64) * 100.0
+        };
+
+        SnapshotDiff {
+            identical: difference_count == 0,
+            difference_count,
+            difference_percent,
+            diff_data,
+            pixel_diffs,
         }
     }
 
@@ -132,6 +261,19 @@ pub struct SnapshotDiff {
     pub difference_percent: f64,
     /// Visual diff data (highlighted differences)
     pub diff_data: Vec<u8>,
+    /// Differing pixels (RGBA comparison only)
+    pub pixel_diffs: Vec<PixelDiff>,
+}
+
+/// A single differing pixel in an RGBA comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelDiff {
+    /// Column (pixel index when the width is unknown)
+    pub x: u32,
+    /// Row
+    pub y: u32,
+    /// Largest absolute difference across the four channels
+    pub max_channel_delta: u8,
 }
 
 impl SnapshotDiff {
@@ -543,4 +685,85 @@ mod tests {
             assert_eq!(diff.difference_count, 3);
         }
     }
+
+    mod rgba_diff_tests {
+        use super::*;
+
+        fn image(width: u32, height: u32) -> Snapshot {
+            let data = (0..width * height)
+                .flat_map(|i| [(i * 10) as u8, 128, 64, 255])
+                .collect();
+            Snapshot::new("img", data).with_dimensions(width, height)
+        }
+
+        fn rgba(tolerance: u8) -> SnapshotConfig {
+            SnapshotConfig::default()
+                .pixel_format(PixelFormat::Rgba8)
+                .with_channel_tolerance(tolerance)
+        }
+
+        #[test]
+        fn test_one_channel_perturbation_tight_tolerance() {
+            let baseline = image(4, 4);
+            let mut current = baseline.clone();
+            // Green channel of pixel (1, 2)
+            current.data[(2 * 4 + 1) * 4 + 1] += 3;
+
+            let diff = baseline.diff_with(&current, &rgba(0));
+            assert!(!diff.is_identical());
+            assert_eq!(diff.difference_count, 1);
+            assert!((diff.difference_percent - 6.25).abs() < f64::EPSILON);
+            assert_eq!(
+                diff.pixel_diffs,
+                vec![PixelDiff {
+                    x: 1,
+                    y: 2,
+                    max_channel_delta: 3
+                }]
+            );
+            assert_eq!(diff.diff_data.len(), 64);
+            assert_eq!(&diff.diff_data[36..40], &[255, 0, 0, 255]);
+
+            // Raw mode still counts bytes
+            let raw = baseline.diff(&current);
+            assert!((raw.difference_percent - 1.5625).abs() < f64::EPSILON);
+        }
+
+        #[test]
+        fn test_one_channel_perturbation_loose_tolerance() {
+            let baseline = image(4, 4);
+            let mut current = baseline.clone();
+            current.data[(2 * 4 + 1) * 4 + 1] += 3;
+
+            let diff = baseline.diff_with(&current, &rgba(5));
+            assert!(diff.is_identical());
+            assert!(diff.pixel_diffs.is_empty());
+            assert!(diff.within_threshold(0.0));
+        }
+
+        #[test]
+        fn test_ignore_transparent_pixels() {
+            let a = Snapshot::new("a", vec![10, 20, 30, 0, 1, 2, 3, 255]).with_dimensions(2, 1);
+            let b = Snapshot::new("b", vec![90, 80, 70, 0, 1, 2, 3, 255]).with_dimensions(2, 1);
+
+            let strict = a.diff_with(&b, &rgba(0));
+            assert_eq!(strict.difference_count, 1);
+            assert!((strict.difference_percent - 50.0).abs() < f64::EPSILON);
+
+            let ignoring = a.diff_with(&b, &rgba(0).with_ignore_transparent(true));
+            assert!(ignoring.is_identical());
+            assert!((ignoring.difference_percent - 0.0).abs() < f64::EPSILON);
+        }
+
+        #[test]
+        fn test_rgba_length_mismatch_counts_missing_pixels() {
+            let a = Snapshot::new("a", vec![1, 2, 3, 255, 4, 5, 6, 255]);
+            let b = Snapshot::new("b", vec![1, 2, 3, 255]);
+
+            let diff = a.diff_with(&b, &rgba(0));
+            assert_eq!(diff.difference_count, 1);
+            assert_eq!(diff.pixel_diffs[0].x, 1);
+            assert_eq!(diff.pixel_diffs[0].max_channel_delta, 255);
+        }
+    }
 }
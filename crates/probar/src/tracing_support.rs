@@ -12,7 +12,7 @@
 
 use crate::result::ProbarResult;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::path::Path;
 use std::time::{Instant, SystemTime};
@@ -33,6 +33,16 @@ pub struct TracingConfig {
     pub max_events: usize,
     /// Include timestamps
     pub include_timestamps: bool,
+    /// Keep one in every N events (1 = keep all)
+    #[serde(default = "default_sample_rate")]
+    pub sample_rate: u32,
+    /// Evict the oldest event instead of dropping new ones once `max_events` is reached
+    #[serde(default)]
+    pub ring_buffer: bool,
+}
+
+const fn default_sample_rate() -> u32 {
+    1
 }
 
 impl Default for TracingConfig {
@@ -44,6 +54,8 @@ impl Default for TracingConfig {
             capture_performance: true,
             max_events: 10000,
             include_timestamps: true,
+            sample_rate: default_sample_rate(),
+            ring_buffer: false,
         }
     }
 }
@@ -81,6 +93,20 @@ impl TracingConfig {
         self.max_events = max;
         self
     }
+
+    /// Keep only one in every `one_in_n` events to bound tracing overhead
+    #[must_use]
+    pub fn sample_rate(mut self, one_in_n: u32) -> Self {
+        self.sample_rate = one_in_n.max(1);
+        self
+    }
+
+    /// Keep the most recent `max_events` events instead of the first ones
+    #[must_use]
+    pub const fn with_ring_buffer(mut self, enabled: bool) -> Self {
+        self.ring_buffer = enabled;
+        self
+    }
 }
 
 /// A traced span (a named section of execution)
@@ -357,6 +383,15 @@ pub struct TraceMetadata {
     pub span_count: usize,
     /// Number of events
     pub event_count: usize,
+    /// Sampling rate the events were recorded with (one in N)
+    #[serde(default = "default_sample_rate")]
+    pub sample_rate: u32,
+    /// Events skipped by sampling
+    #[serde(default)]
+    pub events_sampled_out: usize,
+    /// Sampled events discarded because `max_events` was reached
+    #[serde(default)]
+    pub events_dropped: usize,
     /// Probar version
     pub probar_version: String,
 }
@@ -373,6 +408,9 @@ impl TraceMetadata {
             duration_ms: None,
             span_count: 0,
             event_count: 0,
+            sample_rate: default_sample_rate(),
+            events_sampled_out: 0,
+            events_dropped: 0,
             probar_version: env!("CARGO_PKG_VERSION").to_string(),
         }
     }
@@ -463,7 +501,8 @@ pub struct ExecutionTracer {
     start_time: Instant,
     metadata: TraceMetadata,
     spans: Vec<TracedSpan>,
-    events: Vec<TracedEvent>,
+    events: VecDeque<TracedEvent>,
+    events_seen: u64,
     network_events: Vec<NetworkEvent>,
     console_messages: Vec<ConsoleMessage>,
     current_span_id: Option<String>,
@@ -479,7 +518,8 @@ impl ExecutionTracer {
             start_time: Instant::now(),
             metadata: TraceMetadata::new(test_name),
             spans: Vec::new(),
-            events: Vec::new(),
+            events: VecDeque::new(),
+            events_seen: 0,
             network_events: Vec::new(),
             console_messages: Vec::new(),
             current_span_id: None,
@@ -502,6 +542,7 @@ impl ExecutionTracer {
         self.metadata.duration_ms = Some(self.elapsed_ms());
         self.metadata.span_count = self.spans.len();
         self.metadata.event_count = self.events.len();
+        self.metadata.sample_rate = self.config.sample_rate;
 
         // Close any open spans
         let end_ms = self.elapsed_ms();
@@ -515,7 +556,7 @@ impl ExecutionTracer {
         TraceArchive {
             metadata: self.metadata.clone(),
             spans: self.spans.clone(),
-            events: self.events.clone(),
+            events: self.events.iter().cloned().collect(),
             network_events: self.network_events.clone(),
             console_messages: self.console_messages.clone(),
         }
@@ -563,9 +604,26 @@ impl ExecutionTracer {
     }
 
     /// Record an event
+    ///
+    /// Only one in every `sample_rate` events is kept. Once `max_events` is
+    /// reached, new events are dropped, or the oldest is evicted when
+    /// `ring_buffer` is enabled. Both are counted in [`TraceMetadata`].
     pub fn record_event(&mut self, event: TracedEvent) {
+        let seen = self.events_seen;
+        self.events_seen += 1;
+        if seen % u64::from(self.config.sample_rate.max(1)) != 0 {
+            self.metadata.events_sampled_out += 1;
+            return;
+        }
+
         if self.events.len() < self.config.max_events {
-            self.events.push(event);
+            self.events.push_back(event);
+        } else if self.config.ring_buffer && self.config.max_events > 0 {
+            self.events.pop_front();
+            self.events.push_back(event);
+            self.metadata.events_dropped += 1;
+        } else {
+            self.metadata.events_dropped += 1;
         }
     }
 
@@ -984,6 +1042,58 @@ mod tests {
 
             let archive = tracer.stop();
             assert_eq!(archive.events.len(), 3);
+            assert_eq!(archive.metadata.events_dropped, 7);
+            assert_eq!(archive.events[0].message, "Event 0");
+        }
+
+        #[test]
+        fn test_sample_rate_bounds_events() {
+            let config = TracingConfig::default().sample_rate(100);
+            let mut tracer = ExecutionTracer::new("hot_loop", config);
+            tracer.start();
+
+            for i in 0..100_000 {
+                tracer.record_event(TracedEvent::new(
+                    &format!("tick_{i}"),
+                    EventCategory::Custom,
+                    0,
+                ));
+            }
+
+            let archive = tracer.stop();
+            assert_eq!(archive.events.len(), 1_000);
+            assert_eq!(archive.metadata.event_count, 1_000);
+            assert_eq!(archive.metadata.sample_rate, 100);
+            assert_eq!(archive.metadata.events_sampled_out, 99_000);
+            assert_eq!(archive.metadata.events_dropped, 0);
+            assert_eq!(archive.events[1].name, "tick_100");
+        }
+
+        #[test]
+        fn test_ring_buffer_keeps_latest_events() {
+            let config = TracingConfig::default()
+                .sample_rate(10)
+                .with_max_events(50)
+                .with_ring_buffer(true);
+            let mut tracer = ExecutionTracer::new("ring", config);
+            tracer.start();
+
+            for i in 0..1_000 {
+                tracer.info("tick", &format!("Event {i}"));
+            }
+
+            let archive = tracer.stop();
+            assert_eq!(archive.events.len(), 50);
+            assert_eq!(archive.metadata.events_sampled_out, 900);
+            assert_eq!(archive.metadata.events_dropped, 50);
+            assert_eq!(archive.events[0].message, "Event 500");
+            assert_eq!(archive.events[49].message, "Event 990");
+        }
+
+        #[test]
+        fn test_sample_rate_zero_keeps_all() {
+            let config = TracingConfig::default().sample_rate(0);
+            assert_eq!(config.sample_rate, 1);
         }
     }
 
@@ -33,6 +33,7 @@ mod assertions;
 mod backend;
 mod buffer;
 mod snapshot;
+mod styled;
 mod tty;
 
 // Brick and ComputeBlock testing (optional, requires presentar-terminal)
@@ -44,7 +45,8 @@ mod compute_block;
 pub use assertions::{expect_frame, FrameAssertion, MultiValueTracker, ValueTracker};
 pub use backend::{FrameDiff, LineDiff, TuiFrame, TuiTestBackend};
 pub use buffer::TextGrid;
-pub use snapshot::{FrameSequence, SnapshotManager, TuiSnapshot};
+pub use snapshot::{FrameSequence, SnapshotManager, StyleChange, TuiSnapshot};
+pub use styled::{CellColor, CellStyle, StyledFrame};
 pub use tty::{AnsiCommand, ClearMode, MockTty};
 
 // Re-export Brick testing utilities
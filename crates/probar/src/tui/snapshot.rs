@@ -2,6 +2,10 @@
 //!
 //! Provides snapshot testing for TUI frames with YAML serialization.
 //!
+//! Snapshots taken from a [`StyledFrame`] also store each line with inline
+//! ANSI SGR sequences, so identical text rendered in a different color fails
+//! the comparison with a diff listing only the changed cell styles.
+//!
 //! ## EXTREME TDD: Tests written FIRST per spec
 //!
 //! ## Toyota Way Application
@@ -11,6 +15,7 @@
 //! - **Genchi Genbutsu**: Snapshot files are source of truth
 
 use super::backend::TuiFrame;
+use super::styled::{CellStyle, StyledFrame};
 use crate::result::{ProbarError, ProbarResult};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -33,6 +38,34 @@ pub struct TuiSnapshot {
     /// Optional metadata
     #[serde(default)]
     pub metadata: std::collections::HashMap<String, String>,
+    /// Frame content with inline ANSI styling (empty for text-only snapshots)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub styled: Vec<String>,
+}
+
+/// A cell whose text matches but whose styling differs between snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleChange {
+    /// Column
+    pub x: u16,
+    /// Row
+    pub y: u16,
+    /// Character in the cell
+    pub ch: char,
+    /// Style in the expected snapshot
+    pub expected: CellStyle,
+    /// Style in the actual snapshot
+    pub actual: CellStyle,
+}
+
+impl std::fmt::Display for StyleChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {}) {:?}: {} -> {}",
+            self.x, self.y, self.ch, self.expected, self.actual
+        )
+    }
 }
 
 impl TuiSnapshot {
@@ -40,7 +73,34 @@ impl TuiSnapshot {
     #[must_use]
     pub fn from_frame(name: &str, frame: &TuiFrame) -> Self {
         let content: Vec<String> = frame.lines().iter().map(ToString::to_string).collect();
-        let hash = Self::compute_hash(&content);
+        let hash = Self::compute_hash(&content, &[]);
+
+        Self {
+            name: name.to_string(),
+            hash,
+            width: frame.width(),
+            height: frame.height(),
+            content,
+            metadata: std::collections::HashMap::new(),
+            styled: Vec::new(),
+        }
+    }
+
+    /// Create a snapshot that records cell colors and modifiers
+    #[must_use]
+    pub fn from_styled_frame(name: &str, frame: &StyledFrame) -> Self {
+        let content: Vec<String> = frame
+            .to_frame()
+            .lines()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let mut styled = frame.to_ansi_lines();
+        if styled == content {
+            // No styling: identical to a text-only snapshot
+            styled.clear();
+        }
+        let hash = Self::compute_hash(&content, &styled);
 
         Self {
             name: name.to_string(),
@@ -49,6 +109,7 @@ impl TuiSnapshot {
             height: frame.height(),
             content,
             metadata: std::collections::HashMap::new(),
+            styled,
         }
     }
 
@@ -56,7 +117,7 @@ impl TuiSnapshot {
     #[must_use]
     pub fn from_lines(name: &str, lines: &[&str]) -> Self {
         let content: Vec<String> = lines.iter().map(|s| (*s).to_string()).collect();
-        let hash = Self::compute_hash(&content);
+        let hash = Self::compute_hash(&content, &[]);
         let width = content.iter().map(|l| l.len()).max().unwrap_or(0) as u16;
         let height = content.len() as u16;
 
@@ -67,6 +128,7 @@ impl TuiSnapshot {
             height,
             content,
             metadata: std::collections::HashMap::new(),
+            styled: Vec::new(),
         }
     }
 
@@ -77,13 +139,20 @@ impl TuiSnapshot {
         self
     }
 
-    /// Compute content hash
-    fn compute_hash(content: &[String]) -> String {
+    /// Compute content hash (styled lines only contribute when present)
+    fn compute_hash(content: &[String], styled: &[String]) -> String {
         let mut hasher = Sha256::new();
         for line in content {
             hasher.update(line.as_bytes());
             hasher.update(b"\n");
         }
+        if !styled.is_empty() {
+            hasher.update(b"\x1bstyled\n");
+            for line in styled {
+                hasher.update(line.as_bytes());
+                hasher.update(b"\n");
+            }
+        }
         let result = hasher.finalize();
         format!("{result:x}")
     }
@@ -101,6 +170,44 @@ impl TuiSnapshot {
         TuiFrame::from_lines(&lines)
     }
 
+    /// Convert to a styled frame (unstyled if the snapshot has no styling)
+    #[must_use]
+    pub fn to_styled_frame(&self) -> StyledFrame {
+        if self.styled.is_empty() {
+            StyledFrame::from_frame(&self.to_frame())
+        } else {
+            let lines: Vec<&str> = self.styled.iter().map(String::as_str).collect();
+            StyledFrame::from_ansi_lines(&lines)
+        }
+    }
+
+    /// Cells with the same character but different styling than `expected`
+    #[must_use]
+    pub fn style_diff(&self, expected: &TuiSnapshot) -> Vec<StyleChange> {
+        let actual = self.to_styled_frame();
+        let expected = expected.to_styled_frame();
+        let blank = (' ', CellStyle::default());
+        let mut changes = Vec::new();
+
+        for y in 0..actual.height().max(expected.height()) {
+            for x in 0..actual.width().max(expected.width()) {
+                let (ch, actual_style) = actual.get(x, y).unwrap_or(blank);
+                let (expected_ch, expected_style) = expected.get(x, y).unwrap_or(blank);
+                if ch == expected_ch && actual_style != expected_style {
+                    changes.push(StyleChange {
+                        x,
+                        y,
+                        ch,
+                        expected: expected_style,
+                        actual: actual_style,
+                    });
+                }
+            }
+        }
+
+        changes
+    }
+
     /// Save snapshot to a YAML file
     pub fn save(&self, path: &Path) -> ProbarResult<()> {
         let yaml = serde_yaml_ng::to_string(self).map_err(|e| {
@@ -137,6 +244,21 @@ impl TuiSnapshot {
             let expected_frame = expected.to_frame();
             let diff = actual_frame.diff(&expected_frame);
 
+            if diff.is_identical {
+                let changes: Vec<String> = self
+                    .style_diff(expected)
+                    .iter()
+                    .map(|change| format!("  {change}"))
+                    .collect();
+                return Err(ProbarError::AssertionFailed {
+                    message: format!(
+                        "Snapshot '{}' text matches but styling differs:\n{}",
+                        self.name,
+                        changes.join("\n")
+                    ),
+                });
+            }
+
             Err(ProbarError::AssertionFailed {
                 message: format!(
                     "Snapshot '{}' does not match expected:\n{}",
@@ -205,8 +327,16 @@ impl SnapshotManager {
 
     /// Assert a frame matches a snapshot (or create if missing)
     pub fn assert_snapshot(&self, name: &str, frame: &TuiFrame) -> ProbarResult<()> {
-        let actual = TuiSnapshot::from_frame(name, frame);
-        let path = self.snapshot_path(name);
+        self.assert_snapshot_matches(&TuiSnapshot::from_frame(name, frame))
+    }
+
+    /// Assert a styled frame matches a snapshot, including colors and modifiers
+    pub fn assert_styled_snapshot(&self, name: &str, frame: &StyledFrame) -> ProbarResult<()> {
+        self.assert_snapshot_matches(&TuiSnapshot::from_styled_frame(name, frame))
+    }
+
+    fn assert_snapshot_matches(&self, actual: &TuiSnapshot) -> ProbarResult<()> {
+        let path = self.snapshot_path(&actual.name);
 
         if path.exists() {
             let expected = TuiSnapshot::load(&path)?;
@@ -389,6 +519,83 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    mod styled_snapshot_tests {
+        use super::*;
+        use crate::tui::CellColor;
+
+        fn status_frame(color: CellColor) -> StyledFrame {
+            let mut frame = StyledFrame::new(20, 2);
+            frame.write_styled(0, 0, "Status:", CellStyle::new().bold());
+            frame.write_styled(8, 0, "OK", CellStyle::new().fg(color));
+            frame.write_styled(0, 1, "Ready", CellStyle::new());
+            frame
+        }
+
+        #[test]
+        fn test_same_text_different_colors_fails() {
+            let expected =
+                TuiSnapshot::from_styled_frame("status", &status_frame(CellColor::Indexed(2)));
+            let actual =
+                TuiSnapshot::from_styled_frame("status", &status_frame(CellColor::Indexed(1)));
+
+            assert_eq!(actual.content, expected.content);
+            assert!(!actual.matches(&expected));
+
+            let changes = actual.style_diff(&expected);
+            assert_eq!(changes.len(), 2);
+            assert_eq!((changes[0].x, changes[0].y, changes[0].ch), (8, 0, 'O'));
+            assert_eq!(changes[0].expected.fg, Some(CellColor::Indexed(2)));
+            assert_eq!(changes[0].actual.fg, Some(CellColor::Indexed(1)));
+
+            let err = actual.assert_matches(&expected).unwrap_err().to_string();
+            assert!(err.contains("styling differs"));
+            assert!(err.contains("(8, 0) 'O': fg=2 bg=default -> fg=1 bg=default"));
+            assert!(!err.contains("'S'"), "unchanged cells are not listed");
+        }
+
+        #[test]
+        fn test_same_styling_matches() {
+            let a = TuiSnapshot::from_styled_frame("status", &status_frame(CellColor::Indexed(2)));
+            let b = TuiSnapshot::from_styled_frame("status", &status_frame(CellColor::Indexed(2)));
+            assert!(a.matches(&b));
+            assert!(a.style_diff(&b).is_empty());
+            assert!(a.styled[0].contains("\x1b[0;32mOK"));
+        }
+
+        #[test]
+        fn test_unstyled_frame_matches_text_snapshot() {
+            let frame = TuiFrame::from_lines(&["Hello", "World"]);
+            let styled = TuiSnapshot::from_styled_frame("plain", &StyledFrame::from_frame(&frame));
+            let plain = TuiSnapshot::from_frame("plain", &frame);
+
+            assert!(styled.styled.is_empty());
+            assert!(styled.matches(&plain));
+        }
+
+        #[test]
+        fn test_manager_detects_color_regression() {
+            let temp_dir = TempDir::new().unwrap();
+            let manager = SnapshotManager::new(temp_dir.path());
+
+            manager
+                .assert_styled_snapshot("status", &status_frame(CellColor::Indexed(2)))
+                .unwrap();
+            let loaded = manager.load("status").unwrap();
+            assert_eq!(
+                loaded.to_styled_frame(),
+                TuiSnapshot::from_styled_frame("status", &status_frame(CellColor::Indexed(2)))
+                    .to_styled_frame()
+            );
+
+            assert!(manager
+                .assert_styled_snapshot("status", &status_frame(CellColor::Indexed(2)))
+                .is_ok());
+            assert!(manager
+                .assert_styled_snapshot("status", &status_frame(CellColor::Rgb(255, 0, 0)))
+                .is_err());
+        }
+    }
+
     mod tui_snapshot_tests {
         use super::*;
 
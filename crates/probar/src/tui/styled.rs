@@ -0,0 +1,428 @@
+//! Styled cells for color-aware TUI snapshots.
+//!
+//! [`StyledFrame`] keeps each cell's foreground, background and modifiers
+//! next to its character. Lines are encoded with compact ANSI SGR escape
+//! sequences, emitted only where the style changes, so a snapshot of the
+//! encoded lines catches color regressions that plain text comparison misses.
+
+use super::backend::TuiFrame;
+use std::fmt;
+
+/// Terminal color of a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellColor {
+    /// 256-color palette index (0-15 are the standard ANSI colors)
+    Indexed(u8),
+    /// 24-bit true color
+    Rgb(u8, u8, u8),
+}
+
+impl CellColor {
+    /// Append SGR parameters selecting this color as foreground or background
+    fn push_sgr(self, params: &mut Vec<String>, background: bool) {
+        let base = if background { 40 } else { 30 };
+        match self {
+            Self::Indexed(n) if n < 8 => params.push((base + u16::from(n)).to_string()),
+            Self::Indexed(n) if n < 16 => params.push((base + 60 + u16::from(n - 8)).to_string()),
+            Self::Indexed(n) => params.push(format!("{};5;{n}", base + 8)),
+            Self::Rgb(r, g, b) => params.push(format!("{};2;{r};{g};{b}", base + 8)),
+        }
+    }
+}
+
+impl fmt::Display for CellColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Indexed(n) => write!(f, "{n}"),
+            Self::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+        }
+    }
+}
+
+/// Foreground, background and modifiers of a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CellStyle {
+    /// Foreground color (`None` = terminal default)
+    pub fg: Option<CellColor>,
+    /// Background color (`None` = terminal default)
+    pub bg: Option<CellColor>,
+    /// Bold
+    pub bold: bool,
+    /// Italic
+    pub italic: bool,
+    /// Underline
+    pub underline: bool,
+    /// Reverse video
+    pub reversed: bool,
+}
+
+impl CellStyle {
+    /// Create the default (unstyled) style
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the foreground color
+    #[must_use]
+    pub fn fg(mut self, color: CellColor) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Set the background color
+    #[must_use]
+    pub fn bg(mut self, color: CellColor) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Enable bold
+    #[must_use]
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Enable italic
+    #[must_use]
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Enable underline
+    #[must_use]
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Enable reverse video
+    #[must_use]
+    pub fn reversed(mut self) -> Self {
+        self.reversed = true;
+        self
+    }
+
+    /// Check if this is the terminal default style
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Encode as a self-contained SGR sequence (resets, then sets attributes)
+    #[must_use]
+    pub fn to_sgr(&self) -> String {
+        let mut params = vec!["0".to_string()];
+        if self.bold {
+            params.push("1".to_string());
+        }
+        if self.italic {
+            params.push("3".to_string());
+        }
+        if self.underline {
+            params.push("4".to_string());
+        }
+        if self.reversed {
+            params.push("7".to_string());
+        }
+        if let Some(fg) = self.fg {
+            fg.push_sgr(&mut params, false);
+        }
+        if let Some(bg) = self.bg {
+            bg.push_sgr(&mut params, true);
+        }
+        format!("\x1b[{}m", params.join(";"))
+    }
+
+    /// Apply SGR parameters to this style
+    fn apply_sgr(&mut self, params: &[u16]) {
+        if params.is_empty() {
+            *self = Self::default();
+            return;
+        }
+
+        let mut iter = params.iter().copied();
+        while let Some(param) = iter.next() {
+            match param {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                7 => self.reversed = true,
+                22 => self.bold = false,
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.reversed = false,
+                30..=37 => self.fg = Some(CellColor::Indexed((param - 30) as u8)),
+                90..=97 => self.fg = Some(CellColor::Indexed((param - 90 + 8) as u8)),
+                40..=47 => self.bg = Some(CellColor::Indexed((param - 40) as u8)),
+                100..=107 => self.bg = Some(CellColor::Indexed((param - 100 + 8) as u8)),
+                39 => self.fg = None,
+                49 => self.bg = None,
+                38 | 48 => {
+                    let color = match iter.next() {
+                        Some(5) => iter.next().map(|n| CellColor::Indexed(n as u8)),
+                        Some(2) => match (iter.next(), iter.next(), iter.next()) {
+                            (Some(r), Some(g), Some(b)) => {
+                                Some(CellColor::Rgb(r as u8, g as u8, b as u8))
+                            }
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    if param == 38 {
+                        self.fg = color;
+                    } else {
+                        self.bg = color;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl fmt::Display for CellStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let color =
+            |c: Option<CellColor>| c.map_or_else(|| "default".to_string(), |c| c.to_string());
+        write!(f, "fg={} bg={}", color(self.fg), color(self.bg))?;
+        for (enabled, name) in [
+            (self.bold, "bold"),
+            (self.italic, "italic"),
+            (self.underline, "underline"),
+            (self.reversed, "reversed"),
+        ] {
+            if enabled {
+                write!(f, " {name}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A frame whose cells carry style as well as text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyledFrame {
+    cells: Vec<(char, CellStyle)>,
+    width: u16,
+    height: u16,
+}
+
+impl StyledFrame {
+    /// Create a blank frame filled with unstyled spaces
+    #[must_use]
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            cells: vec![(' ', CellStyle::default()); width as usize * height as usize],
+            width,
+            height,
+        }
+    }
+
+    /// Create an unstyled frame from a text frame
+    #[must_use]
+    pub fn from_frame(frame: &TuiFrame) -> Self {
+        let mut styled = Self::new(frame.width(), frame.height());
+        for (y, line) in frame.lines().iter().enumerate() {
+            styled.write_styled(0, y as u16, line, CellStyle::default());
+        }
+        styled
+    }
+
+    /// Decode lines produced by [`Self::to_ansi_lines`]
+    #[must_use]
+    pub fn from_ansi_lines(lines: &[&str]) -> Self {
+        let decoded: Vec<Vec<(char, CellStyle)>> =
+            lines.iter().map(|line| decode_ansi_line(line)).collect();
+        let width = decoded.iter().map(Vec::len).max().unwrap_or(0) as u16;
+
+        let mut frame = Self::new(width, decoded.len() as u16);
+        for (y, row) in decoded.iter().enumerate() {
+            for (x, &(ch, style)) in row.iter().enumerate() {
+                frame.set(x as u16, y as u16, ch, style);
+            }
+        }
+        frame
+    }
+
+    /// Get the frame width
+    #[must_use]
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Get the frame height
+    #[must_use]
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some((y as usize) * (self.width as usize) + (x as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Get the character and style at (x, y)
+    #[must_use]
+    pub fn get(&self, x: u16, y: u16) -> Option<(char, CellStyle)> {
+        self.index(x, y).map(|idx| self.cells[idx])
+    }
+
+    /// Set the character and style at (x, y)
+    pub fn set(&mut self, x: u16, y: u16, ch: char, style: CellStyle) {
+        if let Some(idx) = self.index(x, y) {
+            self.cells[idx] = (ch, style);
+        }
+    }
+
+    /// Write styled text starting at (x, y), truncated at the frame width
+    pub fn write_styled(&mut self, x: u16, y: u16, text: &str, style: CellStyle) {
+        for (offset, ch) in text.chars().enumerate() {
+            let Some(col) = x.checked_add(offset as u16).filter(|&c| c < self.width) else {
+                break;
+            };
+            self.set(col, y, ch, style);
+        }
+    }
+
+    fn row(&self, y: u16) -> &[(char, CellStyle)] {
+        let start = (y as usize) * (self.width as usize);
+        &self.cells[start..start + self.width as usize]
+    }
+
+    /// Convert to a plain text frame (styles dropped, trailing spaces trimmed)
+    #[must_use]
+    pub fn to_frame(&self) -> TuiFrame {
+        let lines: Vec<String> = (0..self.height)
+            .map(|y| {
+                let line: String = self.row(y).iter().map(|&(ch, _)| ch).collect();
+                line.trim_end().to_string()
+            })
+            .collect();
+        let refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+        TuiFrame::from_lines(&refs)
+    }
+
+    /// Encode each row as text with inline ANSI SGR sequences
+    ///
+    /// A sequence is emitted only where the style changes, and a styled row
+    /// ends with a reset. Trailing unstyled spaces are trimmed.
+    #[must_use]
+    pub fn to_ansi_lines(&self) -> Vec<String> {
+        (0..self.height)
+            .map(|y| {
+                let row = self.row(y);
+                let end = row
+                    .iter()
+                    .rposition(|&(ch, style)| ch != ' ' || !style.is_default())
+                    .map_or(0, |i| i + 1);
+
+                let mut line = String::new();
+                let mut current = CellStyle::default();
+                for &(ch, style) in &row[..end] {
+                    if style != current {
+                        line.push_str(&style.to_sgr());
+                        current = style;
+                    }
+                    line.push(ch);
+                }
+                if !current.is_default() {
+                    line.push_str("\x1b[0m");
+                }
+                line
+            })
+            .collect()
+    }
+}
+
+/// Decode one line of text with inline SGR sequences into styled cells
+fn decode_ansi_line(line: &str) -> Vec<(char, CellStyle)> {
+    let mut cells = Vec::new();
+    let mut style = CellStyle::default();
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' && chars.peek() == Some(&'[') {
+            chars.next();
+            let mut body = String::new();
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    if c == 'm' {
+                        let params: Vec<u16> = body
+                            .split(';')
+                            .filter(|p| !p.is_empty())
+                            .filter_map(|p| p.parse().ok())
+                            .collect();
+                        style.apply_sgr(&params);
+                    }
+                    break;
+                }
+                body.push(c);
+            }
+        } else {
+            cells.push((ch, style));
+        }
+    }
+
+    cells
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_roundtrip() {
+        let mut frame = StyledFrame::new(12, 2);
+        frame.write_styled(
+            0,
+            0,
+            "OK",
+            CellStyle::new().fg(CellColor::Indexed(2)).bold(),
+        );
+        frame.write_styled(3, 0, "warn", CellStyle::new().fg(CellColor::Indexed(11)));
+        frame.write_styled(
+            0,
+            1,
+            "  ",
+            CellStyle::new()
+                .bg(CellColor::Rgb(0x20, 0x40, 0x60))
+                .underline(),
+        );
+        frame.write_styled(2, 1, "x", CellStyle::new().fg(CellColor::Indexed(200)));
+
+        let lines = frame.to_ansi_lines();
+        assert_eq!(lines[0], "\x1b[0;1;32mOK\x1b[0m \x1b[0;93mwarn\x1b[0m");
+
+        let refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+        let decoded = StyledFrame::from_ansi_lines(&refs);
+        assert_eq!(decoded.to_ansi_lines(), lines);
+        assert_eq!(decoded.get(0, 1), frame.get(0, 1));
+        assert_eq!(decoded.get(2, 1), frame.get(2, 1));
+    }
+
+    #[test]
+    fn test_unstyled_lines_have_no_escapes() {
+        let frame = StyledFrame::from_frame(&TuiFrame::from_lines(&["Hello", "World  "]));
+        assert_eq!(frame.to_ansi_lines(), vec!["Hello", "World"]);
+        assert_eq!(frame.to_frame().lines(), &["Hello", "World"]);
+    }
+
+    #[test]
+    fn test_style_display() {
+        let style = CellStyle::new()
+            .fg(CellColor::Indexed(1))
+            .bg(CellColor::Rgb(255, 0, 16))
+            .bold()
+            .reversed();
+        assert_eq!(style.to_string(), "fg=1 bg=#ff0010 bold reversed");
+        assert_eq!(CellStyle::new().to_string(), "fg=default bg=default");
+    }
+}
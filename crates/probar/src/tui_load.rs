@@ -122,6 +122,56 @@ pub struct TuiFrameMetrics {
     pub max_frame_us: u64,
     /// Frame times for percentile calculation
     pub frame_times_us: Vec<u64>,
+    /// Per-component timings for each frame (parallel to `frame_times_us`)
+    pub frame_components: Vec<ComponentTimings>,
+    /// Seed of the synthetic dataset rendered, for reproducing the run
+    pub data_seed: Option<u64>,
+}
+
+/// Frame-time distribution in fixed-width buckets
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameTimeHistogram {
+    /// Width of each bucket in milliseconds
+    pub bucket_width_ms: f64,
+    /// Frame count per bucket; bucket `i` covers `[i * width, (i + 1) * width)`
+    pub counts: Vec<usize>,
+}
+
+impl FrameTimeHistogram {
+    /// Lower bound of a bucket in milliseconds
+    #[must_use]
+    pub fn bucket_start_ms(&self, bucket: usize) -> f64 {
+        bucket as f64 * self.bucket_width_ms
+    }
+
+    /// Total number of frames in the histogram
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+}
+
+/// A single frame with its component breakdown
+#[derive(Debug, Clone)]
+pub struct SlowFrame {
+    /// Frame number (0-based, in recording order)
+    pub frame: usize,
+    /// Frame time in milliseconds
+    pub frame_ms: f64,
+    /// Component timings recorded for this frame
+    pub components: ComponentTimings,
+}
+
+impl SlowFrame {
+    /// Component that took the longest in this frame
+    #[must_use]
+    pub fn dominant_component(&self) -> Option<(&str, f64)> {
+        self.components
+            .0
+            .iter()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(name, &ms)| (name.as_str(), ms))
+    }
 }
 
 impl TuiFrameMetrics {
@@ -136,11 +186,80 @@ impl TuiFrameMetrics {
 
     /// Record a frame time
     pub fn record(&mut self, frame_time_us: u64) {
+        self.record_with_components(frame_time_us, ComponentTimings::new());
+    }
+
+    /// Record a frame time together with its component breakdown
+    pub fn record_with_components(&mut self, frame_time_us: u64, components: ComponentTimings) {
         self.frame_count += 1;
         self.total_time_us += frame_time_us;
         self.min_frame_us = self.min_frame_us.min(frame_time_us);
         self.max_frame_us = self.max_frame_us.max(frame_time_us);
         self.frame_times_us.push(frame_time_us);
+        self.frame_components.push(components);
+    }
+
+    /// Build a frame-time histogram with the given bucket width
+    #[must_use]
+    pub fn histogram(&self, bucket_width_ms: f64) -> FrameTimeHistogram {
+        let width = if bucket_width_ms > 0.0 {
+            bucket_width_ms
+        } else {
+            1.0
+        };
+        let mut counts = Vec::new();
+
+        for &us in &self.frame_times_us {
+            let bucket = (us as f64 / 1000.0 / width) as usize;
+            if bucket >= counts.len() {
+                counts.resize(bucket + 1, 0);
+            }
+            counts[bucket] += 1;
+        }
+
+        FrameTimeHistogram {
+            bucket_width_ms: width,
+            counts,
+        }
+    }
+
+    /// Get the `n` slowest frames, slowest first (ties in recording order)
+    #[must_use]
+    pub fn worst_frames(&self, n: usize) -> Vec<SlowFrame> {
+        let mut order: Vec<usize> = (0..self.frame_times_us.len()).collect();
+        order.sort_by(|&a, &b| {
+            self.frame_times_us[b]
+                .cmp(&self.frame_times_us[a])
+                .then(a.cmp(&b))
+        });
+
+        order
+            .into_iter()
+            .take(n)
+            .map(|frame| SlowFrame {
+                frame,
+                frame_ms: self.frame_times_us[frame] as f64 / 1000.0,
+                components: self
+                    .frame_components
+                    .get(frame)
+                    .cloned()
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// Fraction of frames (0.0-1.0) slower than `threshold_ms`
+    #[must_use]
+    pub fn fraction_over_ms(&self, threshold_ms: f64) -> f64 {
+        if self.frame_times_us.is_empty() {
+            return 0.0;
+        }
+        let over = self
+            .frame_times_us
+            .iter()
+            .filter(|&&us| us as f64 / 1000.0 > threshold_ms)
+            .count();
+        over as f64 / self.frame_times_us.len() as f64
     }
 
     /// Get average frame time in milliseconds
@@ -258,6 +377,10 @@ pub struct DataGenerator {
     item_count: usize,
     /// Average description length
     avg_description_len: usize,
+    /// Exact description length range (inclusive), overrides the average
+    description_len_range: Option<(usize, usize)>,
+    /// Range for `value1`/`value2`
+    value_range: (f32, f32),
 }
 
 impl DataGenerator {
@@ -268,16 +391,40 @@ impl DataGenerator {
             seed: 42,
             item_count,
             avg_description_len: 100,
+            description_len_range: None,
+            value_range: (0.0, 100.0),
         }
     }
 
     /// Set random seed
+    ///
+    /// Generators with the same seed and settings produce identical items.
     #[must_use]
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = seed;
         self
     }
 
+    /// Get the random seed
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Draw each description length uniformly from `min..=max` characters
+    #[must_use]
+    pub fn with_description_len_range(mut self, min: usize, max: usize) -> Self {
+        self.description_len_range = Some((min.min(max), min.max(max)));
+        self
+    }
+
+    /// Set the range of the numeric values (`value1`, `value2`)
+    #[must_use]
+    pub fn with_value_range(mut self, min: f32, max: f32) -> Self {
+        self.value_range = (min.min(max), min.max(max));
+        self
+    }
+
     /// Set average description length
     #[must_use]
     pub fn with_description_len(mut self, len: usize) -> Self {
@@ -349,14 +496,14 @@ impl DataGenerator {
             // Generate a realistic command line
             let base_name = names[name_idx];
             let pid = 1000 + i as u32;
-            let description = self.generate_cmdline(base_name, r1);
+            let description = self.generate_cmdline(base_name, r1, r2);
 
             items.push(SyntheticItem {
                 id: pid,
                 name: format!("{}-{}", base_name, i % 100),
                 description,
-                value1: ((r1 % 10000) as f32) / 100.0, // CPU 0-100%
-                value2: ((r2 % 10000) as f32) / 100.0, // Mem 0-100%
+                value1: self.scale_value(r1), // e.g. CPU %
+                value2: self.scale_value(r2), // e.g. Mem %
                 state: states[state_idx].to_string(),
                 owner: users[user_idx].to_string(),
                 count: ((r3 % 64) + 1) as u32, // threads 1-64
@@ -366,7 +513,13 @@ impl DataGenerator {
         items
     }
 
-    fn generate_cmdline(&self, base_name: &str, seed: u64) -> String {
+    /// Map a random draw onto `value_range` in steps of 1/10000
+    fn scale_value(&self, r: u64) -> f32 {
+        let (min, max) = self.value_range;
+        min + ((r % 10000) as f32 / 10000.0) * (max - min)
+    }
+
+    fn generate_cmdline(&self, base_name: &str, seed: u64, len_seed: u64) -> String {
         let args = [
             "--config",
             "/etc/config.yaml",
@@ -398,10 +551,19 @@ impl DataGenerator {
             cmdline.push_str(args[arg_idx]);
         }
 
+        let target_len = match self.description_len_range {
+            Some((min, max)) => min + (len_seed % (max - min + 1) as u64) as usize,
+            None => self.avg_description_len,
+        };
+
         // Pad to approximate target length
-        while cmdline.len() < self.avg_description_len {
+        while cmdline.len() < target_len {
             cmdline.push_str(" --extra-arg");
         }
+        if self.description_len_range.is_some() {
+            // Command lines are ASCII, so any byte index is a char boundary
+            cmdline.truncate(target_len);
+        }
 
         cmdline
     }
@@ -428,6 +590,8 @@ pub struct TuiLoadConfig {
     pub filters: Vec<String>,
     /// Fail on budget exceeded (vs just warn)
     pub strict_budget: bool,
+    /// Seed for the synthetic dataset
+    pub seed: u64,
 }
 
 impl Default for TuiLoadConfig {
@@ -445,6 +609,7 @@ impl Default for TuiLoadConfig {
                 "nonexistent_filter_that_matches_nothing".to_string(),
             ],
             strict_budget: false,
+            seed: 42,
         }
     }
 }
@@ -471,7 +636,28 @@ impl TuiLoadTest {
     #[must_use]
     pub fn with_item_count(mut self, count: usize) -> Self {
         self.config.item_count = count;
-        self.data = DataGenerator::new(count).generate();
+        self.data = DataGenerator::new(count)
+            .with_seed(self.config.seed)
+            .generate();
+        self
+    }
+
+    /// Regenerate the dataset from a seed (reported as `data_seed` in metrics)
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.config.seed = seed;
+        self.data = DataGenerator::new(self.config.item_count)
+            .with_seed(seed)
+            .generate();
+        self
+    }
+
+    /// Use a custom data generator; its seed is reported in metrics
+    #[must_use]
+    pub fn with_data_generator(mut self, generator: &DataGenerator) -> Self {
+        self.data = generator.generate();
+        self.config.item_count = self.data.len();
+        self.config.seed = generator.seed();
         self
     }
 
@@ -541,6 +727,7 @@ impl TuiLoadTest {
         F: FnMut(&[SyntheticItem], &str) -> Option<u64>,
     {
         let mut metrics = TuiFrameMetrics::new();
+        metrics.data_seed = Some(self.config.seed);
         let timeout = Duration::from_millis(self.config.timeout_ms);
         let mut frame_num = 0;
 
@@ -622,6 +809,7 @@ impl TuiLoadTest {
 
         for filter in stress_filters {
             let mut metrics = TuiFrameMetrics::new();
+            metrics.data_seed = Some(self.config.seed);
 
             for frame in 0..self.config.frames_per_filter {
                 let start = Instant::now();
@@ -674,6 +862,32 @@ impl TuiLoadAssertion {
         );
     }
 
+    /// Assert that at most `max_fraction` of frames exceed `threshold_ms`
+    ///
+    /// E.g. `assert_frames_over_budget(&metrics, 16.0, 0.01)` allows no more
+    /// than 1% of frames slower than 16ms.
+    pub fn assert_frames_over_budget(
+        metrics: &TuiFrameMetrics,
+        threshold_ms: f64,
+        max_fraction: f64,
+    ) {
+        let fraction = metrics.fraction_over_ms(threshold_ms);
+        let worst = metrics
+            .worst_frames(1)
+            .first()
+            .map_or_else(String::new, |f| {
+                format!(" (worst: frame {} at {:.2}ms)", f.frame, f.frame_ms)
+            });
+        assert!(
+            fraction <= max_fraction,
+            "{:.1}% of frames exceed {:.2}ms (max allowed: {:.1}%){}",
+            fraction * 100.0,
+            threshold_ms,
+            max_fraction * 100.0,
+            worst
+        );
+    }
+
     /// Assert that no frame exceeded timeout
     pub fn assert_no_hang(result: &TuiLoadResult<TuiFrameMetrics>) {
         assert!(
@@ -833,7 +1047,7 @@ impl IntegrationLoadTest {
                 }
             }
 
-            metrics.record(elapsed.as_micros() as u64);
+            metrics.record_with_components(elapsed.as_micros() as u64, timings);
         }
 
         Ok(metrics)
@@ -893,6 +1107,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_data_generator_same_seed_byte_identical() {
+        let make = |seed| {
+            DataGenerator::new(200)
+                .with_seed(seed)
+                .with_description_len_range(40, 160)
+                .with_value_range(10.0, 20.0)
+        };
+        let items1 = format!("{:?}", make(7).generate());
+        let items2 = format!("{:?}", make(7).generate());
+        assert_eq!(items1.as_bytes(), items2.as_bytes());
+
+        let other = format!("{:?}", make(8).generate());
+        assert_ne!(items1, other);
+    }
+
+    #[test]
+    fn test_data_generator_distribution_controls() {
+        let items = DataGenerator::new(200)
+            .with_description_len_range(40, 160)
+            .with_value_range(10.0, 20.0)
+            .generate();
+
+        for item in &items {
+            assert!((40..=160).contains(&item.description.len()));
+            assert!((10.0..=20.0).contains(&item.value1));
+            assert!((10.0..=20.0).contains(&item.value2));
+        }
+        let min_len = items.iter().map(|i| i.description.len()).min().unwrap();
+        let max_len = items.iter().map(|i| i.description.len()).max().unwrap();
+        assert!(
+            max_len - min_len > 60,
+            "lengths should spread across the range"
+        );
+    }
+
+    #[test]
+    fn test_load_test_reports_data_seed() {
+        let test = TuiLoadTest::new()
+            .with_item_count(20)
+            .with_seed(99)
+            .with_frames_per_filter(1);
+        assert_eq!(test.config().seed, 99);
+        assert_eq!(
+            format!("{:?}", test.data()),
+            format!("{:?}", DataGenerator::new(20).with_seed(99).generate())
+        );
+
+        let metrics = test.run(|_, _| Some(1_000)).unwrap();
+        assert_eq!(metrics.data_seed, Some(99));
+    }
+
     #[test]
     fn test_synthetic_item_filter_empty() {
         let item = SyntheticItem {
@@ -1184,6 +1450,7 @@ mod tests {
             frames_per_filter: 10,
             filters: vec!["sys".to_string(), "usr".to_string()],
             strict_budget: true,
+            seed: 7,
         };
         assert_eq!(config.item_count, 500);
         assert!((config.frame_budget_ms - 32.0).abs() < f64::EPSILON);
@@ -1211,5 +1478,85 @@ mod tests {
         assert_eq!(metrics.frame_count, 0);
         assert_eq!(metrics.p50_frame_ms(), 0.0);
         assert_eq!(metrics.p95_frame_ms(), 0.0);
+        assert!(metrics.worst_frames(3).is_empty());
+        assert_eq!(metrics.histogram(4.0).total(), 0);
+    }
+
+    /// 200 frames at 5-9ms, with three spikes dominated by different components
+    fn synthetic_frame_stream() -> TuiFrameMetrics {
+        let mut metrics = TuiFrameMetrics::new();
+        for frame in 0..200u64 {
+            let mut timings = ComponentTimings::new();
+            let frame_us = match frame {
+                40 => {
+                    timings.record("collector", 30.0);
+                    timings.record("render", 4.0);
+                    34_000
+                }
+                120 => {
+                    timings.record("collector", 2.0);
+                    timings.record("render", 18.0);
+                    20_000
+                }
+                180 => {
+                    timings.record("layout", 25.0);
+                    25_000
+                }
+                _ => {
+                    timings.record("render", 5.0);
+                    5_000 + (frame % 5) * 1_000
+                }
+            };
+            metrics.record_with_components(frame_us, timings);
+        }
+        metrics
+    }
+
+    #[test]
+    fn test_worst_frames_ordering() {
+        let metrics = synthetic_frame_stream();
+        let worst = metrics.worst_frames(3);
+
+        let frames: Vec<usize> = worst.iter().map(|f| f.frame).collect();
+        assert_eq!(frames, vec![40, 180, 120]);
+        assert!((worst[0].frame_ms - 34.0).abs() < f64::EPSILON);
+        assert_eq!(worst[0].dominant_component(), Some(("collector", 30.0)));
+        assert_eq!(worst[1].dominant_component(), Some(("layout", 25.0)));
+        assert_eq!(worst[2].dominant_component(), Some(("render", 18.0)));
+
+        // Ties keep recording order
+        let next = metrics.worst_frames(5);
+        assert_eq!(next[3].frame, 4);
+        assert_eq!(next[4].frame, 9);
+    }
+
+    #[test]
+    fn test_frame_time_histogram() {
+        let metrics = synthetic_frame_stream();
+        let histogram = metrics.histogram(4.0);
+
+        assert_eq!(histogram.total(), 200);
+        // 5-7ms in [4, 8), 8-9ms in [8, 12)
+        assert_eq!(histogram.counts[1], 117);
+        assert_eq!(histogram.counts[2], 80);
+        assert_eq!(histogram.counts[5], 1); // 20ms
+        assert_eq!(histogram.counts[6], 1); // 25ms
+        assert_eq!(histogram.counts[8], 1); // 34ms
+        assert!((histogram.bucket_start_ms(5) - 20.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_assert_frames_over_budget() {
+        let metrics = synthetic_frame_stream();
+        // 3 of 200 frames exceed 16ms
+        assert!((metrics.fraction_over_ms(16.0) - 0.015).abs() < f64::EPSILON);
+        TuiLoadAssertion::assert_frames_over_budget(&metrics, 16.0, 0.02);
+    }
+
+    #[test]
+    #[should_panic(expected = "of frames exceed 16.00ms")]
+    fn test_assert_frames_over_budget_fails() {
+        let metrics = synthetic_frame_stream();
+        TuiLoadAssertion::assert_frames_over_budget(&metrics, 16.0, 0.01);
     }
 }
@@ -435,6 +435,34 @@ impl UxCoverageTracker {
         &self.journeys
     }
 
+    /// Build the interaction-flow graph of state transitions
+    ///
+    /// Each edge is weighted by the number of journeys (tests) that traversed
+    /// it at least once; the journey currently being recorded is included.
+    /// Consecutive visits to the same state are not transitions.
+    #[must_use]
+    pub fn flow_graph(&self) -> FlowGraph {
+        let mut graph = FlowGraph::default();
+        let journeys = self
+            .journeys
+            .iter()
+            .chain(std::iter::once(&self.current_journey));
+
+        for journey in journeys {
+            let mut traversed: HashSet<(&StateId, &StateId)> = HashSet::new();
+            for state in journey {
+                graph.add_state(state);
+            }
+            for pair in journey.windows(2) {
+                if pair[0] != pair[1] && traversed.insert((&pair[0], &pair[1])) {
+                    graph.add_traversal(&pair[0], &pair[1]);
+                }
+            }
+        }
+
+        graph
+    }
+
     /// Generate a coverage report
     #[must_use]
     pub fn generate_report(&self) -> UxCoverageReport {
@@ -636,6 +664,90 @@ impl fmt::Display for UxCoverageReport {
     }
 }
 
+/// A state-to-state transition observed during testing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowEdge {
+    /// Source state
+    pub from: StateId,
+    /// Destination state
+    pub to: StateId,
+    /// Number of journeys that traversed this transition
+    pub weight: usize,
+}
+
+/// Interaction-flow graph built from recorded journeys
+///
+/// Transitions that no test exercised are absent, so a pair of covered states
+/// without an edge between them points at an untested path.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FlowGraph {
+    /// States in first-visit order
+    pub states: Vec<StateId>,
+    /// Traversed transitions in first-traversal order
+    pub edges: Vec<FlowEdge>,
+}
+
+impl FlowGraph {
+    fn add_state(&mut self, state: &StateId) {
+        if !self.states.contains(state) {
+            self.states.push(state.clone());
+        }
+    }
+
+    fn add_traversal(&mut self, from: &StateId, to: &StateId) {
+        if let Some(edge) = self
+            .edges
+            .iter_mut()
+            .find(|e| &e.from == from && &e.to == to)
+        {
+            edge.weight += 1;
+        } else {
+            self.edges.push(FlowEdge {
+                from: from.clone(),
+                to: to.clone(),
+                weight: 1,
+            });
+        }
+    }
+
+    /// Number of journeys that traversed `from -> to` (0 if never traversed)
+    #[must_use]
+    pub fn weight(&self, from: &StateId, to: &StateId) -> usize {
+        self.edges
+            .iter()
+            .find(|e| &e.from == from && &e.to == to)
+            .map_or(0, |e| e.weight)
+    }
+
+    /// Whether any journey traversed `from -> to`
+    #[must_use]
+    pub fn contains_edge(&self, from: &StateId, to: &StateId) -> bool {
+        self.weight(from, to) > 0
+    }
+
+    /// Render the graph in DOT format, labelling edges with their weight
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph UxFlow {\n");
+        dot.push_str("  rankdir=LR;\n");
+        dot.push_str("  node [shape=box];\n");
+
+        for state in &self.states {
+            dot.push_str(&format!("  \"{state}\";\n"));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "  \"{}\" -> \"{}\" [label=\"{}\", penwidth={}];\n",
+                edge.from, edge.to, edge.weight, edge.weight
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
 /// Builder for defining UX coverage requirements
 #[derive(Debug, Default)]
 pub struct UxCoverageBuilder {
@@ -1005,6 +1117,53 @@ mod tests {
             assert_eq!(tracker.journeys().len(), 2);
         }
 
+        #[test]
+        fn test_flow_graph_edge_weights() {
+            let mut tracker = UxCoverageTracker::new();
+            let home = StateId::new("screen", "home");
+            let settings = StateId::new("screen", "settings");
+            let profile = StateId::new("screen", "profile");
+
+            // Path A twice, path B once
+            for _ in 0..2 {
+                tracker.record_state(home.clone());
+                tracker.record_state(settings.clone());
+                tracker.end_journey();
+            }
+            tracker.record_state(home.clone());
+            tracker.record_state(profile.clone());
+            tracker.record_state(home.clone());
+            tracker.record_state(profile.clone());
+            tracker.end_journey();
+
+            let graph = tracker.flow_graph();
+            assert_eq!(
+                graph.states,
+                vec![home.clone(), settings.clone(), profile.clone()]
+            );
+            assert_eq!(graph.edges.len(), 3);
+            assert_eq!(graph.weight(&home, &settings), 2);
+            // Traversed twice within one journey still counts one test
+            assert_eq!(graph.weight(&home, &profile), 1);
+            assert_eq!(graph.weight(&profile, &home), 1);
+            // Both states are covered but no test went settings -> profile
+            assert!(!graph.contains_edge(&settings, &profile));
+            assert_eq!(graph.weight(&settings, &profile), 0);
+
+            let dot = graph.to_dot();
+            assert!(dot.starts_with("digraph UxFlow {"));
+            assert!(dot.contains("\"screen:home\" -> \"screen:settings\" [label=\"2\""));
+            assert!(!dot.contains("\"screen:settings\" -> \"screen:profile\""));
+        }
+
+        #[test]
+        fn test_flow_graph_empty() {
+            let tracker = UxCoverageTracker::new();
+            let graph = tracker.flow_graph();
+            assert!(graph.states.is_empty());
+            assert!(graph.edges.is_empty());
+        }
+
         #[test]
         fn test_assert_coverage_pass() {
             let mut tracker = UxCoverageTracker::new();
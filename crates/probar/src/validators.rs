@@ -20,15 +20,71 @@ use std::time::{Duration, Instant};
 // VU Meter Configuration (Section 2.4)
 // =============================================================================
 
+/// Meter ballistics: how the displayed level follows the signal
+///
+/// - **VU** (IEC 60268-17): symmetric averaging, reaching 99% of a step in
+///   300 ms in both directions, so short transients read low.
+/// - **PPM** (IEC 60268-10 Type II): 10 ms integration (80% of a step) with a
+///   slow fall-back of 24 dB in 2.8 s, so transients are caught and held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ballistics {
+    /// Volume unit meter (average)
+    #[default]
+    Vu,
+    /// Peak program meter (quasi-peak)
+    Ppm,
+}
+
+impl Ballistics {
+    /// VU rise/fall time to 99% of a step (ms)
+    pub const VU_INTEGRATION_MS: f64 = 300.0;
+    /// PPM integration time to 80% of a step (ms)
+    pub const PPM_INTEGRATION_MS: f64 = 10.0;
+    /// PPM fall-back rate (24 dB in 2.8 s)
+    pub const PPM_DECAY_DB_PER_SEC: f64 = 24.0 / 2.8;
+
+    /// First-order attack time constant (ms)
+    #[must_use]
+    pub fn attack_time_constant_ms(self) -> f64 {
+        match self {
+            Self::Vu => Self::VU_INTEGRATION_MS / 100.0_f64.ln(),
+            Self::Ppm => Self::PPM_INTEGRATION_MS / 5.0_f64.ln(),
+        }
+    }
+
+    /// Advance the displayed level by `dt_ms` towards `input`
+    #[must_use]
+    pub fn step(self, displayed: f32, input: f32, dt_ms: f64) -> f32 {
+        let displayed = f64::from(displayed);
+        let input = f64::from(input);
+        let follow =
+            |tau_ms: f64| displayed + (input - displayed) * (1.0 - (-dt_ms / tau_ms).exp());
+
+        let next = if input >= displayed {
+            follow(self.attack_time_constant_ms())
+        } else {
+            match self {
+                Self::Vu => follow(self.attack_time_constant_ms()),
+                Self::Ppm => {
+                    let decay_db = Self::PPM_DECAY_DB_PER_SEC * dt_ms / 1000.0;
+                    (displayed * 10.0_f64.powf(-decay_db / 20.0)).max(input)
+                }
+            }
+        };
+        next as f32
+    }
+}
+
 /// VU meter validation parameters
 ///
 /// # Example
 /// ```
-/// use jugar_probar::validators::VuMeterConfig;
+/// use jugar_probar::validators::{Ballistics, VuMeterConfig};
 ///
 /// let config = VuMeterConfig::default()
 ///     .with_min_level(0.1)
-///     .with_update_rate_hz(30.0);
+///     .with_update_rate_hz(30.0)
+///     .ballistics(Ballistics::Ppm);
 /// ```
 #[derive(Debug, Clone)]
 pub struct VuMeterConfig {
@@ -42,6 +98,8 @@ pub struct VuMeterConfig {
     pub smoothing_tolerance: f32,
     /// Maximum time without updates (staleness)
     pub max_stale_ms: u64,
+    /// Ballistics used to compute the displayed level
+    pub ballistics: Ballistics,
 }
 
 impl Default for VuMeterConfig {
@@ -52,6 +110,7 @@ impl Default for VuMeterConfig {
             update_rate_hz: 30.0,
             smoothing_tolerance: 0.1,
             max_stale_ms: 100,
+            ballistics: Ballistics::Vu,
         }
     }
 }
@@ -85,6 +144,41 @@ impl VuMeterConfig {
         self
     }
 
+    /// Set meter ballistics (VU or PPM)
+    #[must_use]
+    pub fn ballistics(mut self, ballistics: Ballistics) -> Self {
+        self.ballistics = ballistics;
+        self
+    }
+
+    /// Compute the displayed level for each sample of a signal stream
+    ///
+    /// The meter starts at rest (0.0) and follows the configured ballistics
+    /// between sample timestamps.
+    #[must_use]
+    pub fn displayed_levels(&self, samples: &[VuMeterSample]) -> Vec<f32> {
+        let mut displayed = 0.0_f32;
+        let mut last_ms = samples.first().map_or(0.0, |s| s.timestamp_ms);
+
+        samples
+            .iter()
+            .map(|sample| {
+                let dt_ms = (sample.timestamp_ms - last_ms).max(0.0);
+                displayed = self.ballistics.step(displayed, sample.level, dt_ms);
+                last_ms = sample.timestamp_ms;
+                displayed
+            })
+            .collect()
+    }
+
+    /// Highest displayed level over a signal stream
+    #[must_use]
+    pub fn peak_displayed(&self, samples: &[VuMeterSample]) -> f32 {
+        self.displayed_levels(samples)
+            .into_iter()
+            .fold(0.0, f32::max)
+    }
+
     /// Validate a VU meter sample
     pub fn validate_sample(&self, level: f32) -> Result<(), VuMeterError> {
         if level < 0.0 {
@@ -1885,6 +1979,56 @@ mod tests {
         assert!(final_result.is_final);
     }
 
+    /// 1 ms samples: 100 ms silence, 10 ms full-scale burst, 200 ms silence
+    fn transient_spike() -> Vec<VuMeterSample> {
+        (0..310)
+            .map(|ms| VuMeterSample {
+                timestamp_ms: f64::from(ms),
+                level: if (100..110).contains(&ms) { 1.0 } else { 0.0 },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_vu_meter_ballistics_transient() {
+        let samples = transient_spike();
+        let vu = VuMeterConfig::default();
+        let ppm = VuMeterConfig::default().ballistics(Ballistics::Ppm);
+        assert_eq!(vu.ballistics, Ballistics::Vu);
+
+        // PPM reaches ~80% within its 10 ms integration time
+        let ppm_peak = ppm.peak_displayed(&samples);
+        assert!(ppm_peak > 0.75, "PPM peak {ppm_peak}");
+
+        // VU averages the burst down
+        let vu_peak = vu.peak_displayed(&samples);
+        assert!(vu_peak < 0.2, "VU peak {vu_peak}");
+
+        // PPM falls back slowly (~1.7 dB over 200 ms); VU returns to rest
+        let ppm_levels = ppm.displayed_levels(&samples);
+        let vu_levels = vu.displayed_levels(&samples);
+        assert!(ppm_levels[309] > 0.6);
+        assert!(vu_levels[309] < 0.01);
+    }
+
+    #[test]
+    fn test_vu_meter_ballistics_steady_tone() {
+        let samples: Vec<_> = (0..1000)
+            .map(|ms| VuMeterSample {
+                timestamp_ms: f64::from(ms),
+                level: 0.5,
+            })
+            .collect();
+
+        // Both meters settle on a steady signal
+        for ballistics in [Ballistics::Vu, Ballistics::Ppm] {
+            let levels = VuMeterConfig::default()
+                .ballistics(ballistics)
+                .displayed_levels(&samples);
+            assert!((levels[999] - 0.5).abs() < 0.01);
+        }
+    }
+
     #[test]
     fn f048_vu_meter_sample_tracking() {
         // Falsification: VU meter samples should track level over time
@@ -3095,6 +3239,7 @@ mod tests {
             update_rate_hz: 30.0,
             smoothing_tolerance: 0.2,
             max_stale_ms: 100,
+            ballistics: Ballistics::Vu,
         };
 
         // Level at max + tolerance should pass
@@ -1,4 +1,4 @@
-//! Video Quality Verification: codec, resolution, FPS, duration validation.
+//! Video Quality Verification: codec, resolution, FPS, duration, and frame pacing validation.
 //!
 //! Probes rendered video files with ffprobe and validates metadata
 //! against expected properties.
@@ -17,6 +17,12 @@ pub mod probe;
 pub mod types;
 pub mod validation;
 
-pub use probe::{build_ffprobe_args, parse_ffprobe_json, probe_video};
-pub use types::{VideoCheck, VideoExpectations, VideoProbe, VideoQualityReport, VideoVerdict};
+pub use probe::{
+    analyze_frame_timing, build_ffprobe_args, build_frame_probe_args, build_framemd5_args,
+    parse_ffprobe_json, parse_frame_timestamps_json, parse_framemd5, probe_frame_timing,
+    probe_video,
+};
+pub use types::{
+    FrameTiming, VideoCheck, VideoExpectations, VideoProbe, VideoQualityReport, VideoVerdict,
+};
 pub use validation::validate_video;
@@ -1,9 +1,11 @@
 //! Video probing via ffprobe.
 //!
 //! Extracts video metadata (codec, resolution, fps, duration) by
-//! shelling out to ffprobe with JSON output.
+//! shelling out to ffprobe with JSON output. Frame pacing (VFR and
+//! duplicated frames) is probed separately from per-frame timestamps
+//! and ffmpeg's `framemd5` hashes, since it requires decoding the stream.
 
-use super::types::VideoProbe;
+use super::types::{FrameTiming, VideoProbe};
 use crate::result::ProbarError;
 use std::path::Path;
 
@@ -153,9 +155,158 @@ pub fn parse_ffprobe_json(json: &str) -> Result<VideoProbe, ProbarError> {
         audio_codec,
         audio_sample_rate,
         audio_channels,
+        frame_timing: None,
     })
 }
 
+/// Build ffprobe arguments that list per-frame timestamps of the first video stream.
+#[must_use]
+pub fn build_frame_probe_args(video_path: &Path) -> Vec<String> {
+    vec![
+        "-v".to_string(),
+        "quiet".to_string(),
+        "-select_streams".to_string(),
+        "v:0".to_string(),
+        "-show_entries".to_string(),
+        "frame=pts_time,best_effort_timestamp_time".to_string(),
+        "-print_format".to_string(),
+        "json".to_string(),
+        video_path.to_string_lossy().to_string(),
+    ]
+}
+
+/// Build ffmpeg arguments that emit one MD5 hash per decoded video frame.
+#[must_use]
+pub fn build_framemd5_args(video_path: &Path) -> Vec<String> {
+    vec![
+        "-v".to_string(),
+        "quiet".to_string(),
+        "-i".to_string(),
+        video_path.to_string_lossy().to_string(),
+        "-map".to_string(),
+        "0:v:0".to_string(),
+        "-f".to_string(),
+        "framemd5".to_string(),
+        "-".to_string(),
+    ]
+}
+
+/// Probe frame pacing of a video file.
+///
+/// Decodes the first video stream twice: once with ffprobe for frame
+/// timestamps and once with ffmpeg for per-frame hashes. The result can be
+/// attached to a [`VideoProbe`] as `frame_timing` before validation.
+///
+/// # Errors
+///
+/// Returns `ProbarError::FfmpegError` if ffprobe/ffmpeg is not found or fails.
+pub fn probe_frame_timing(video_path: &Path) -> Result<FrameTiming, ProbarError> {
+    let frames_json = run_tool("ffprobe", &build_frame_probe_args(video_path))?;
+    let timestamps = parse_frame_timestamps_json(&frames_json)?;
+    let framemd5 = run_tool("ffmpeg", &build_framemd5_args(video_path))?;
+    let hashes = parse_framemd5(&framemd5);
+    Ok(analyze_frame_timing(&timestamps, &hashes))
+}
+
+/// Parse ffprobe `-show_entries frame=...` JSON into frame timestamps (seconds).
+///
+/// Uses `pts_time`, falling back to `best_effort_timestamp_time`. Frames
+/// without a usable timestamp are skipped. The result is sorted.
+pub fn parse_frame_timestamps_json(json: &str) -> Result<Vec<f64>, ProbarError> {
+    let parsed: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| ProbarError::FfmpegError {
+            message: format!("Failed to parse ffprobe JSON: {e}"),
+        })?;
+
+    let frames = parsed
+        .get("frames")
+        .and_then(|f| f.as_array())
+        .ok_or_else(|| ProbarError::FfmpegError {
+            message: "ffprobe output missing 'frames' array".to_string(),
+        })?;
+
+    let mut timestamps: Vec<f64> = frames
+        .iter()
+        .filter_map(|frame| {
+            ["pts_time", "best_effort_timestamp_time"]
+                .iter()
+                .find_map(|key| frame.get(*key).and_then(|v| v.as_str()))
+                .and_then(|s| s.parse::<f64>().ok())
+        })
+        .collect();
+    timestamps.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(timestamps)
+}
+
+/// Parse ffmpeg `framemd5` output into per-frame hashes, in frame order.
+///
+/// Comment lines (starting with `#`) are skipped; the hash is the last
+/// comma-separated field of each packet line.
+#[must_use]
+pub fn parse_framemd5(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.rsplit(',').next())
+        .map(|hash| hash.trim().to_string())
+        .collect()
+}
+
+/// Compute frame pacing from sorted frame timestamps and per-frame hashes.
+#[must_use]
+pub fn analyze_frame_timing(timestamps: &[f64], hashes: &[String]) -> FrameTiming {
+    let intervals: Vec<f64> = timestamps.windows(2).map(|w| w[1] - w[0]).collect();
+    let (min_interval_secs, max_interval_secs, mean_interval_secs) = if intervals.is_empty() {
+        (0.0, 0.0, 0.0)
+    } else {
+        let min = intervals.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = intervals.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mean = intervals.iter().sum::<f64>() / intervals.len() as f64;
+        (min, max, mean)
+    };
+
+    let mut longest_duplicate_run = 0;
+    let mut run = 0;
+    for pair in hashes.windows(2) {
+        if pair[0] == pair[1] {
+            run += 1;
+            longest_duplicate_run = longest_duplicate_run.max(run);
+        } else {
+            run = 0;
+        }
+    }
+
+    FrameTiming {
+        frame_count: timestamps.len().max(hashes.len()),
+        min_interval_secs,
+        max_interval_secs,
+        mean_interval_secs,
+        longest_duplicate_run,
+    }
+}
+
+/// Run an ffmpeg-family tool and capture stdout.
+fn run_tool(program: &str, args: &[String]) -> Result<String, ProbarError> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .map_err(|e| ProbarError::FfmpegError {
+            message: format!("Failed to execute {program}: {e}"),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(ProbarError::FfmpegError {
+            message: format!("{program} exited with {}: {stderr}", output.status),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 /// Parse an FPS fraction string like "24/1" or "30000/1001" into a float.
 fn parse_fps_fraction(fraction: &str) -> f64 {
     let parts: Vec<&str> = fraction.split('/').collect();
@@ -334,4 +485,116 @@ mod tests {
         let result = probe_video(Path::new("/nonexistent/video.mp4"));
         assert!(result.is_err());
     }
+
+    const CFR_FRAMES_JSON: &str = r#"{
+        "frames": [
+            {"pts_time": "0.000000", "best_effort_timestamp_time": "0.000000"},
+            {"pts_time": "0.033333", "best_effort_timestamp_time": "0.033333"},
+            {"pts_time": "0.066667", "best_effort_timestamp_time": "0.066667"},
+            {"pts_time": "0.100000", "best_effort_timestamp_time": "0.100000"},
+            {"pts_time": "0.133333", "best_effort_timestamp_time": "0.133333"}
+        ]
+    }"#;
+
+    const VFR_FRAMES_JSON: &str = r#"{
+        "frames": [
+            {"pts_time": "0.000000"},
+            {"pts_time": "0.033333"},
+            {"best_effort_timestamp_time": "0.066667"},
+            {"pts_time": "0.150000"},
+            {"pts_time": "0.166667"},
+            {"pts_time": "0.250000"}
+        ]
+    }"#;
+
+    #[test]
+    fn test_build_frame_probe_args() {
+        let args = build_frame_probe_args(Path::new("/tmp/video.mp4"));
+        assert!(args.contains(&"-show_entries".to_string()));
+        assert!(args.contains(&"v:0".to_string()));
+        assert_eq!(args.last().unwrap(), "/tmp/video.mp4");
+    }
+
+    #[test]
+    fn test_build_framemd5_args() {
+        let args = build_framemd5_args(Path::new("/tmp/video.mp4"));
+        assert!(args.contains(&"framemd5".to_string()));
+        assert_eq!(args.last().unwrap(), "-");
+    }
+
+    #[test]
+    fn test_parse_frame_timestamps_cfr() {
+        let timestamps = parse_frame_timestamps_json(CFR_FRAMES_JSON).unwrap();
+        assert_eq!(timestamps.len(), 5);
+        let timing = analyze_frame_timing(&timestamps, &[]);
+        assert_eq!(timing.frame_count, 5);
+        assert!((timing.mean_interval_secs - 1.0 / 30.0).abs() < 1e-4);
+        assert!(timing.interval_jitter_secs() < 0.001);
+    }
+
+    #[test]
+    fn test_parse_frame_timestamps_vfr() {
+        let timestamps = parse_frame_timestamps_json(VFR_FRAMES_JSON).unwrap();
+        assert_eq!(timestamps.len(), 6);
+        let timing = analyze_frame_timing(&timestamps, &[]);
+        assert!((timing.min_interval_secs - 0.016667).abs() < 1e-6);
+        assert!((timing.max_interval_secs - 0.083333).abs() < 1e-6);
+        assert!(timing.interval_jitter_secs() > 0.001);
+    }
+
+    #[test]
+    fn test_parse_frame_timestamps_missing_frames() {
+        assert!(parse_frame_timestamps_json(r#"{"streams": []}"#).is_err());
+        assert!(parse_frame_timestamps_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_framemd5_and_duplicate_run() {
+        let output = "\
+#format: frame checksums
+#version: 2
+#hash: MD5
+#stream#, dts,        pts, duration,     size, hash
+0,          0,          0,        1,  6220800, aaaa
+0,          1,          1,        1,  6220800, bbbb
+0,          2,          2,        1,  6220800, bbbb
+0,          3,          3,        1,  6220800, bbbb
+0,          4,          4,        1,  6220800, cccc
+0,          5,          5,        1,  6220800, cccc
+";
+        let hashes = parse_framemd5(output);
+        assert_eq!(hashes, vec!["aaaa", "bbbb", "bbbb", "bbbb", "cccc", "cccc"]);
+        let timing = analyze_frame_timing(&[], &hashes);
+        assert_eq!(timing.longest_duplicate_run, 2);
+        assert_eq!(timing.frame_count, 6);
+    }
+
+    #[test]
+    fn test_validate_vfr_and_cfr_streams() {
+        use crate::video_quality::{validate_video, VideoExpectations, VideoVerdict};
+
+        let exp = VideoExpectations::default().require_constant_frame_rate(true);
+        let stream = r#"{
+            "streams": [
+                {"codec_type": "video", "codec_name": "h264", "width": 640,
+                 "height": 480, "r_frame_rate": "30/1", "pix_fmt": "yuv420p"}
+            ],
+            "format": {"duration": "0.2"}
+        }"#;
+
+        let mut probe = parse_ffprobe_json(stream).unwrap();
+        let cfr = parse_frame_timestamps_json(CFR_FRAMES_JSON).unwrap();
+        probe.frame_timing = Some(analyze_frame_timing(&cfr, &[]));
+        assert_eq!(
+            validate_video(&probe, &exp, "cfr.mp4").verdict,
+            VideoVerdict::Pass
+        );
+
+        let vfr = parse_frame_timestamps_json(VFR_FRAMES_JSON).unwrap();
+        probe.frame_timing = Some(analyze_frame_timing(&vfr, &[]));
+        assert_eq!(
+            validate_video(&probe, &exp, "vfr.mp4").verdict,
+            VideoVerdict::Fail
+        );
+    }
 }
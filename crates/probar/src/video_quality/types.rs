@@ -30,6 +30,34 @@ pub struct VideoProbe {
     pub audio_sample_rate: Option<u32>,
     /// Audio channels (None if no audio stream)
     pub audio_channels: Option<u32>,
+    /// Per-frame timing analysis (None unless frames were probed)
+    #[serde(default)]
+    pub frame_timing: Option<FrameTiming>,
+}
+
+/// Per-frame timing derived from frame timestamps and frame hashes.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrameTiming {
+    /// Number of video frames analyzed
+    pub frame_count: usize,
+    /// Shortest interval between consecutive frames in seconds
+    pub min_interval_secs: f64,
+    /// Longest interval between consecutive frames in seconds
+    pub max_interval_secs: f64,
+    /// Mean interval between consecutive frames in seconds
+    pub mean_interval_secs: f64,
+    /// Longest run of consecutive frames identical to their predecessor
+    pub longest_duplicate_run: usize,
+}
+
+impl FrameTiming {
+    /// Spread between the longest and shortest frame interval.
+    ///
+    /// Zero (within timestamp precision) for a constant frame rate stream.
+    #[must_use]
+    pub fn interval_jitter_secs(&self) -> f64 {
+        self.max_interval_secs - self.min_interval_secs
+    }
 }
 
 /// Expected video properties for validation.
@@ -51,6 +79,12 @@ pub struct VideoExpectations {
     pub require_audio: bool,
     /// FPS tolerance for comparison (default: 0.01)
     pub fps_tolerance: f64,
+    /// Whether frame intervals must be constant (no VFR)
+    pub constant_frame_rate: bool,
+    /// Allowed frame interval jitter in seconds before a stream counts as VFR (default: 0.001)
+    pub frame_interval_tolerance_secs: f64,
+    /// Maximum run of duplicated frames (None = skip check)
+    pub max_duplicate_frame_run: Option<usize>,
 }
 
 impl Default for VideoExpectations {
@@ -64,6 +98,9 @@ impl Default for VideoExpectations {
             max_duration_secs: None,
             require_audio: false,
             fps_tolerance: 0.01,
+            constant_frame_rate: false,
+            frame_interval_tolerance_secs: 0.001,
+            max_duplicate_frame_run: None,
         }
     }
 }
@@ -111,6 +148,26 @@ impl VideoExpectations {
         self.require_audio = require;
         self
     }
+
+    /// Require a constant frame rate (fails on variable frame rate output).
+    #[must_use]
+    pub const fn require_constant_frame_rate(mut self, require: bool) -> Self {
+        self.constant_frame_rate = require;
+        self
+    }
+
+    /// Set maximum run of consecutive duplicated frames.
+    #[must_use]
+    pub const fn with_max_duplicate_frame_run(mut self, frames: usize) -> Self {
+        self.max_duplicate_frame_run = Some(frames);
+        self
+    }
+
+    /// Whether any expectation needs per-frame timing data.
+    #[must_use]
+    pub const fn needs_frame_timing(&self) -> bool {
+        self.constant_frame_rate || self.max_duplicate_frame_run.is_some()
+    }
 }
 
 /// Video quality verification report.
@@ -182,6 +239,7 @@ mod tests {
             audio_codec: Some("aac".to_string()),
             audio_sample_rate: Some(48000),
             audio_channels: Some(2),
+            frame_timing: None,
         }
     }
 
@@ -205,6 +263,9 @@ mod tests {
         assert!(exp.height.is_none());
         assert!(exp.fps.is_none());
         assert!(!exp.require_audio);
+        assert!(!exp.constant_frame_rate);
+        assert!(exp.max_duplicate_frame_run.is_none());
+        assert!(!exp.needs_frame_timing());
     }
 
     #[test]
@@ -215,7 +276,9 @@ mod tests {
             .with_codec("h264")
             .with_min_duration(10.0)
             .with_max_duration(300.0)
-            .with_require_audio(true);
+            .with_require_audio(true)
+            .require_constant_frame_rate(true)
+            .with_max_duplicate_frame_run(2);
         assert_eq!(exp.width, Some(1920));
         assert_eq!(exp.height, Some(1080));
         assert!((exp.fps.unwrap() - 24.0).abs() < f64::EPSILON);
@@ -223,6 +286,21 @@ mod tests {
         assert!((exp.min_duration_secs.unwrap() - 10.0).abs() < f64::EPSILON);
         assert!((exp.max_duration_secs.unwrap() - 300.0).abs() < f64::EPSILON);
         assert!(exp.require_audio);
+        assert!(exp.constant_frame_rate);
+        assert_eq!(exp.max_duplicate_frame_run, Some(2));
+        assert!(exp.needs_frame_timing());
+    }
+
+    #[test]
+    fn test_frame_timing_jitter() {
+        let timing = FrameTiming {
+            frame_count: 5,
+            min_interval_secs: 0.033,
+            max_interval_secs: 0.050,
+            mean_interval_secs: 0.040,
+            longest_duplicate_run: 0,
+        };
+        assert!((timing.interval_jitter_secs() - 0.017).abs() < 1e-9);
     }
 
     #[test]
@@ -251,6 +329,7 @@ mod tests {
         let probe: VideoProbe = serde_json::from_str(json).unwrap();
         assert_eq!(probe.codec, "h264");
         assert_eq!(probe.width, 1920);
+        assert!(probe.frame_timing.is_none());
     }
 
     #[test]
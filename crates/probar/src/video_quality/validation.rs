@@ -84,6 +84,10 @@ pub fn validate_video(
         });
     }
 
+    if expectations.needs_frame_timing() {
+        checks.push(check_frame_timing(probe, expectations));
+    }
+
     let passed_count = checks.iter().filter(|c| c.passed).count();
     let total_count = checks.len();
     let verdict = if passed_count == total_count {
@@ -102,10 +106,55 @@ pub fn validate_video(
     }
 }
 
+/// Check frame pacing: constant frame rate and duplicated-frame runs.
+///
+/// Fails when no frame timing was probed, since the expectation cannot
+/// be verified from stream metadata alone.
+fn check_frame_timing(probe: &VideoProbe, expectations: &VideoExpectations) -> VideoCheck {
+    let mut expected = Vec::new();
+    if expectations.constant_frame_rate {
+        expected.push(format!(
+            "constant frame rate (jitter <= {:.1}ms)",
+            expectations.frame_interval_tolerance_secs * 1000.0
+        ));
+    }
+    if let Some(max_run) = expectations.max_duplicate_frame_run {
+        expected.push(format!("duplicate run <= {max_run}"));
+    }
+
+    let (actual, passed) = match probe.frame_timing {
+        Some(ref timing) => {
+            let cfr_ok = !expectations.constant_frame_rate
+                || timing.interval_jitter_secs() <= expectations.frame_interval_tolerance_secs;
+            let dup_ok = expectations
+                .max_duplicate_frame_run
+                .map_or(true, |max_run| timing.longest_duplicate_run <= max_run);
+            (
+                format!(
+                    "interval {:.1}-{:.1}ms, duplicate run {}",
+                    timing.min_interval_secs * 1000.0,
+                    timing.max_interval_secs * 1000.0,
+                    timing.longest_duplicate_run
+                ),
+                cfr_ok && dup_ok,
+            )
+        }
+        None => ("not probed".to_string(), false),
+    };
+
+    VideoCheck {
+        name: "frame_timing".to_string(),
+        expected: expected.join(", "),
+        actual,
+        passed,
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::video_quality::types::FrameTiming;
 
     fn sample_probe() -> VideoProbe {
         VideoProbe {
@@ -120,6 +169,7 @@ mod tests {
             audio_codec: Some("aac".to_string()),
             audio_sample_rate: Some(48000),
             audio_channels: Some(2),
+            frame_timing: None,
         }
     }
 
@@ -238,4 +288,71 @@ mod tests {
         assert_eq!(report.passed_count, 2); // width + height pass
         assert_eq!(report.total_count, 3); // width + height + fps
     }
+
+    fn cfr_timing() -> FrameTiming {
+        FrameTiming {
+            frame_count: 240,
+            min_interval_secs: 1.0 / 24.0,
+            max_interval_secs: 1.0 / 24.0,
+            mean_interval_secs: 1.0 / 24.0,
+            longest_duplicate_run: 0,
+        }
+    }
+
+    #[test]
+    fn test_validate_frame_timing_cfr_pass() {
+        let mut probe = sample_probe();
+        probe.frame_timing = Some(cfr_timing());
+        let exp = VideoExpectations::default()
+            .require_constant_frame_rate(true)
+            .with_max_duplicate_frame_run(2);
+        let report = validate_video(&probe, &exp, "test.mp4");
+        assert_eq!(report.verdict, VideoVerdict::Pass);
+        let check = report
+            .checks
+            .iter()
+            .find(|c| c.name == "frame_timing")
+            .unwrap();
+        assert!(check.passed);
+    }
+
+    #[test]
+    fn test_validate_frame_timing_vfr_fail() {
+        let mut probe = sample_probe();
+        probe.frame_timing = Some(FrameTiming {
+            max_interval_secs: 0.1,
+            ..cfr_timing()
+        });
+        let exp = VideoExpectations::default().require_constant_frame_rate(true);
+        let report = validate_video(&probe, &exp, "test.mp4");
+        assert_eq!(report.verdict, VideoVerdict::Fail);
+    }
+
+    #[test]
+    fn test_validate_frame_timing_duplicate_run_fail() {
+        let mut probe = sample_probe();
+        probe.frame_timing = Some(FrameTiming {
+            longest_duplicate_run: 12,
+            ..cfr_timing()
+        });
+        let exp = VideoExpectations::default().with_max_duplicate_frame_run(2);
+        let report = validate_video(&probe, &exp, "test.mp4");
+        assert_eq!(report.verdict, VideoVerdict::Fail);
+        // VFR not requested, so jitter is irrelevant
+        probe.frame_timing = Some(FrameTiming {
+            max_interval_secs: 0.1,
+            ..cfr_timing()
+        });
+        let report = validate_video(&probe, &exp, "test.mp4");
+        assert_eq!(report.verdict, VideoVerdict::Pass);
+    }
+
+    #[test]
+    fn test_validate_frame_timing_not_probed() {
+        let probe = sample_probe();
+        let exp = VideoExpectations::default().require_constant_frame_rate(true);
+        let report = validate_video(&probe, &exp, "test.mp4");
+        assert_eq!(report.verdict, VideoVerdict::Fail);
+        assert_eq!(report.checks[0].actual, "not probed");
+    }
 }
@@ -2,8 +2,9 @@
 //!
 //! Per spec Section 6.2: Visual Regression Testing using pure Rust image comparison.
 
+use crate::pixel_coverage::{Rgb, SsimMetric};
 use crate::result::{ProbarError, ProbarResult};
-use image::{DynamicImage, GenericImageView, ImageEncoder, Rgba};
+use image::{DynamicImage, GenericImageView, ImageEncoder, Rgba, RgbaImage};
 use std::path::Path;
 
 /// Configuration for visual regression testing
@@ -19,6 +20,10 @@ pub struct VisualRegressionConfig {
     pub diff_dir: String,
     /// Whether to update baselines automatically
     pub update_baselines: bool,
+    /// Local SSIM below which a window is reported as a regression region
+    pub ssim_threshold: f32,
+    /// Regions excluded from comparison (clocks, ads, other dynamic content)
+    pub ignore_regions: Vec<MaskRegion>,
 }
 
 impl Default for VisualRegressionConfig {
@@ -29,6 +34,8 @@ impl Default for VisualRegressionConfig {
             baseline_dir: String::from("__baselines__"),
             diff_dir: String::from("__diffs__"),
             update_baselines: false,
+            ssim_threshold: 0.95,
+            ignore_regions: Vec::new(),
         }
     }
 }
@@ -61,6 +68,26 @@ impl VisualRegressionConfig {
         self.update_baselines = update;
         self
     }
+
+    /// Set the local SSIM threshold for reported regions
+    #[must_use]
+    pub const fn with_ssim_threshold(mut self, threshold: f32) -> Self {
+        self.ssim_threshold = threshold;
+        self
+    }
+
+    /// Exclude a region from comparison
+    #[must_use]
+    pub fn with_ignore_region(mut self, region: MaskRegion) -> Self {
+        self.ignore_regions.push(region);
+        self
+    }
+
+    /// Check if a pixel lies in an ignored region
+    #[must_use]
+    pub fn is_ignored(&self, x: u32, y: u32) -> bool {
+        self.ignore_regions.iter().any(|r| r.contains(x, y))
+    }
 }
 
 /// Result of comparing two images
@@ -80,6 +107,19 @@ pub struct ImageDiffResult {
     pub avg_color_diff: f64,
     /// Diff image data (PNG encoded, highlights differences in red)
     pub diff_image: Option<Vec<u8>>,
+    /// Regions whose local SSIM fell below the configured threshold
+    pub low_ssim_regions: Vec<SsimRegion>,
+}
+
+/// A connected area of low local SSIM
+#[derive(Debug, Clone, PartialEq)]
+pub struct SsimRegion {
+    /// Bounding box of the changed pixels in the low-SSIM windows
+    pub bounds: MaskRegion,
+    /// Lowest window SSIM in the region
+    pub min_ssim: f32,
+    /// Whether the region lies entirely inside an ignore region
+    pub ignored: bool,
 }
 
 impl ImageDiffResult {
@@ -94,6 +134,15 @@ impl ImageDiffResult {
     pub fn within_threshold(&self, threshold: f64) -> bool {
         self.diff_percentage <= threshold * 100.0
     }
+
+    /// Low-SSIM regions outside every ignore region
+    #[must_use]
+    pub fn regressions(&self) -> Vec<&SsimRegion> {
+        self.low_ssim_regions
+            .iter()
+            .filter(|r| !r.ignored)
+            .collect()
+    }
 }
 
 /// Visual regression tester
@@ -167,6 +216,7 @@ impl VisualRegressionTester {
         let actual_rgba = actual.to_rgba8();
         let expected_rgba = expected.to_rgba8();
 
+        let mut ignored_pixels = 0usize;
         for y in 0..height {
             for x in 0..width {
                 let actual_pixel = actual_rgba.get_pixel(x, y);
@@ -174,7 +224,11 @@ impl VisualRegressionTester {
 
                 let color_diff = pixel_diff(*actual_pixel, *expected_pixel);
 
-                if color_diff > u32::from(self.config.color_threshold) {
+                if self.config.is_ignored(x, y) {
+                    ignored_pixels += 1;
+                    let Rgba([r, g, b, _]) = *actual_pixel;
+                    diff_img.put_pixel(x, y, Rgba([r / 2, g / 2, b / 2, 128]));
+                } else if color_diff > u32::from(self.config.color_threshold) {
                     diff_pixel_count += 1;
                     total_color_diff += u64::from(color_diff);
                     max_color_diff = max_color_diff.max(color_diff);
@@ -189,6 +243,9 @@ impl VisualRegressionTester {
             }
         }
 
+        let total_pixels = total_pixels - ignored_pixels;
+        let low_ssim_regions = self.low_ssim_regions(&actual_rgba, &expected_rgba);
+
         #[allow(clippy::cast_precision_loss)]
         let diff_percentage = if total_pixels > 0 {
             (diff_pixel_count as f64 / total_pixels as f64) * 100.0
@@ -232,9 +289,79 @@ impl VisualRegressionTester {
             max_color_diff,
             avg_color_diff,
             diff_image,
+            low_ssim_regions,
         })
     }
 
+    /// Group low-SSIM windows into connected regions
+    ///
+    /// Each region is bounded by the pixels that actually changed within its
+    /// windows (falling back to the window bounds), so a region caused by a
+    /// masked clock stays inside the mask even if the windows overlap it.
+    fn low_ssim_regions(&self, actual: &RgbaImage, expected: &RgbaImage) -> Vec<SsimRegion> {
+        let (width, height) = actual.dimensions();
+        let to_rgb = |img: &RgbaImage| -> Vec<Rgb> {
+            img.pixels()
+                .map(|&Rgba([r, g, b, _])| Rgb::new(r, g, b))
+                .collect()
+        };
+        let map = SsimMetric::default().ssim_map(&to_rgb(expected), &to_rgb(actual), width, height);
+
+        let is_low = |column: u32, row: u32| {
+            map.get(column, row)
+                .is_some_and(|score| score < self.config.ssim_threshold)
+        };
+        let mut visited = vec![false; map.scores.len()];
+        let mut regions = Vec::new();
+
+        for start_row in 0..map.rows {
+            for start_column in 0..map.columns {
+                let start = (start_row * map.columns + start_column) as usize;
+                if visited[start] || !is_low(start_column, start_row) {
+                    continue;
+                }
+
+                // Flood-fill 4-connected low windows
+                visited[start] = true;
+                let mut stack = vec![(start_column, start_row)];
+                let mut windows = Vec::new();
+                while let Some((column, row)) = stack.pop() {
+                    windows.push((column, row));
+                    let neighbours = [
+                        (column.wrapping_sub(1), row),
+                        (column + 1, row),
+                        (column, row.wrapping_sub(1)),
+                        (column, row + 1),
+                    ];
+                    for (c, r) in neighbours {
+                        if is_low(c, r) && !visited[(r * map.columns + c) as usize] {
+                            visited[(r * map.columns + c) as usize] = true;
+                            stack.push((c, r));
+                        }
+                    }
+                }
+
+                let min_ssim = windows
+                    .iter()
+                    .filter_map(|&(c, r)| map.get(c, r))
+                    .fold(1.0, f32::min);
+                let bounds = changed_bounds(&map, &windows, actual, expected);
+                let ignored = self
+                    .config
+                    .ignore_regions
+                    .iter()
+                    .any(|mask| mask.contains_region(&bounds));
+                regions.push(SsimRegion {
+                    bounds,
+                    min_ssim,
+                    ignored,
+                });
+            }
+        }
+
+        regions
+    }
+
     /// Compare screenshot against baseline file
     ///
     /// # Errors
@@ -260,6 +387,7 @@ impl VisualRegressionTester {
                     max_color_diff: 0,
                     avg_color_diff: 0.0,
                     diff_image: None,
+                    low_ssim_regions: Vec::new(),
                 });
             }
             return Err(ProbarError::ImageComparisonError {
@@ -294,6 +422,38 @@ impl VisualRegressionTester {
     }
 }
 
+/// Bounding box of changed pixels within a set of SSIM windows
+fn changed_bounds(
+    map: &crate::pixel_coverage::SsimMap,
+    windows: &[(u32, u32)],
+    actual: &RgbaImage,
+    expected: &RgbaImage,
+) -> MaskRegion {
+    let mut changed: Option<(u32, u32, u32, u32)> = None;
+    let mut window_box: Option<(u32, u32, u32, u32)> = None;
+    let extend = |acc: Option<(u32, u32, u32, u32)>, x0: u32, y0: u32, x1: u32, y1: u32| {
+        Some(match acc {
+            Some((ax0, ay0, ax1, ay1)) => (ax0.min(x0), ay0.min(y0), ax1.max(x1), ay1.max(y1)),
+            None => (x0, y0, x1, y1),
+        })
+    };
+
+    for &(column, row) in windows {
+        let (wx, wy, ww, wh) = map.window_bounds(column, row);
+        window_box = extend(window_box, wx, wy, wx + ww, wy + wh);
+        for y in wy..wy + wh {
+            for x in wx..wx + ww {
+                if actual.get_pixel(x, y) != expected.get_pixel(x, y) {
+                    changed = extend(changed, x, y, x + 1, y + 1);
+                }
+            }
+        }
+    }
+
+    let (x0, y0, x1, y1) = changed.or(window_box).unwrap_or_default();
+    MaskRegion::new(x0, y0, x1 - x0, y1 - y0)
+}
+
 /// Calculate pixel difference (sum of RGB channel differences)
 fn pixel_diff(a: Rgba<u8>, b: Rgba<u8>) -> u32 {
     let Rgba([r1, g1, b1, _]) = a;
@@ -336,6 +496,15 @@ impl MaskRegion {
     pub const fn contains(&self, px: u32, py: u32) -> bool {
         px >= self.x && px < self.x + self.width && py >= self.y && py < self.y + self.height
     }
+
+    /// Check if another region lies entirely within this one
+    #[must_use]
+    pub const fn contains_region(&self, other: &Self) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.x + other.width <= self.x + self.width
+            && other.y + other.height <= self.y + self.height
+    }
 }
 
 /// Screenshot comparison configuration (Playwright API parity)
@@ -485,6 +654,7 @@ mod tests {
             max_color_diff: 0,
             avg_color_diff: 0.0,
             diff_image: None,
+            low_ssim_regions: Vec::new(),
         };
         assert!(result.is_identical());
 
@@ -496,6 +666,7 @@ mod tests {
             max_color_diff: 100,
             avg_color_diff: 50.0,
             diff_image: None,
+            low_ssim_regions: Vec::new(),
         };
         assert!(!result2.is_identical());
     }
@@ -567,6 +738,7 @@ mod tests {
             max_color_diff: 50,
             avg_color_diff: 25.0,
             diff_image: None,
+            low_ssim_regions: Vec::new(),
         };
 
         assert!(result.within_threshold(0.01)); // 1% threshold
@@ -965,6 +1137,7 @@ mod tests {
                 max_color_diff: 0,
                 avg_color_diff: 0.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert!(result.is_identical());
         }
@@ -979,6 +1152,7 @@ mod tests {
                 max_color_diff: 50,
                 avg_color_diff: 50.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert!(!result.is_identical());
         }
@@ -993,6 +1167,7 @@ mod tests {
                 max_color_diff: 10,
                 avg_color_diff: 10.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert!(result.within_threshold(0.02)); // 2% threshold
         }
@@ -1007,6 +1182,7 @@ mod tests {
                 max_color_diff: 100,
                 avg_color_diff: 80.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert!(!result.within_threshold(0.05)); // 5% threshold
         }
@@ -1021,6 +1197,7 @@ mod tests {
                 max_color_diff: 255,
                 avg_color_diff: 128.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert!((result.diff_percentage - 50.0).abs() < f64::EPSILON);
         }
@@ -1035,6 +1212,7 @@ mod tests {
                 max_color_diff: 200,
                 avg_color_diff: 150.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert_eq!(result.max_color_diff, 200);
         }
@@ -1049,6 +1227,7 @@ mod tests {
                 max_color_diff: 200,
                 avg_color_diff: 125.5,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert!((result.avg_color_diff - 125.5).abs() < f64::EPSILON);
         }
@@ -1063,6 +1242,7 @@ mod tests {
                 max_color_diff: 255,
                 avg_color_diff: 200.0,
                 diff_image: Some(vec![1, 2, 3, 4]),
+                low_ssim_regions: Vec::new(),
             };
             assert!(result.diff_image.is_some());
         }
@@ -1077,6 +1257,7 @@ mod tests {
                 max_color_diff: 0,
                 avg_color_diff: 0.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert!(result.matches);
         }
@@ -1091,6 +1272,7 @@ mod tests {
                 max_color_diff: 0,
                 avg_color_diff: 0.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             assert_eq!(result.total_pixels, 1920 * 1080);
         }
@@ -1342,6 +1524,7 @@ mod tests {
                 max_color_diff: 0,
                 avg_color_diff: 0.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             let cloned = result;
             assert!(cloned.matches);
@@ -1357,6 +1540,7 @@ mod tests {
                 max_color_diff: 0,
                 avg_color_diff: 0.0,
                 diff_image: None,
+                low_ssim_regions: Vec::new(),
             };
             let debug = format!("{:?}", result);
             assert!(debug.contains("ImageDiffResult"));
@@ -1403,4 +1587,106 @@ mod tests {
             assert!(debug.contains("MaskRegion"));
         }
     }
+
+    mod ssim_region_tests {
+        use super::*;
+
+        const CLOCK: MaskRegion = MaskRegion::new(38, 0, 26, 12);
+
+        fn page() -> RgbaImage {
+            RgbaImage::from_pixel(64, 32, Rgba([255, 255, 255, 255]))
+        }
+
+        fn draw_clock(img: &mut RgbaImage) {
+            for y in 2..10 {
+                for x in 40..60 {
+                    if (x + y) % 3 == 0 {
+                        img.put_pixel(x, y, Rgba([0, 0, 0, 255]));
+                    }
+                }
+            }
+        }
+
+        fn tester() -> VisualRegressionTester {
+            VisualRegressionTester::new(
+                VisualRegressionConfig::default()
+                    .with_threshold(0.0)
+                    .with_ignore_region(CLOCK),
+            )
+        }
+
+        #[test]
+        fn test_masked_timestamp_passes() {
+            let expected = page();
+            let mut actual = page();
+            draw_clock(&mut actual);
+
+            let result = tester()
+                .compare_dynamic_images(
+                    &DynamicImage::ImageRgba8(actual),
+                    &DynamicImage::ImageRgba8(expected),
+                )
+                .unwrap();
+
+            assert!(!result.low_ssim_regions.is_empty());
+            for region in &result.low_ssim_regions {
+                assert!(region.ignored);
+                assert!(CLOCK.contains_region(&region.bounds));
+                assert!(region.min_ssim < 0.95);
+            }
+            assert!(result.regressions().is_empty());
+            assert!(result.matches);
+            assert_eq!(result.diff_pixel_count, 0);
+            assert_eq!(result.total_pixels, 64 * 32 - 26 * 12);
+        }
+
+        #[test]
+        fn test_regression_outside_mask_fails() {
+            let expected = page();
+            let mut actual = page();
+            draw_clock(&mut actual);
+            for y in 20..28 {
+                for x in 4..12 {
+                    actual.put_pixel(x, y, Rgba([0, 0, 0, 255]));
+                }
+            }
+
+            let result = tester()
+                .compare_dynamic_images(
+                    &DynamicImage::ImageRgba8(actual),
+                    &DynamicImage::ImageRgba8(expected),
+                )
+                .unwrap();
+
+            assert!(!result.matches);
+            assert_eq!(result.diff_pixel_count, 64);
+            let regressions = result.regressions();
+            assert_eq!(regressions.len(), 1);
+            assert_eq!(regressions[0].bounds, MaskRegion::new(4, 20, 8, 8));
+            assert!(result
+                .low_ssim_regions
+                .iter()
+                .any(|r| r.ignored && CLOCK.contains_region(&r.bounds)));
+        }
+
+        #[test]
+        fn test_identical_images_have_no_regions() {
+            let result = VisualRegressionTester::default()
+                .compare_dynamic_images(
+                    &DynamicImage::ImageRgba8(page()),
+                    &DynamicImage::ImageRgba8(page()),
+                )
+                .unwrap();
+            assert!(result.low_ssim_regions.is_empty());
+        }
+
+        #[test]
+        fn test_mask_contains_region() {
+            let outer = MaskRegion::new(10, 10, 20, 20);
+            assert!(outer.contains_region(&MaskRegion::new(10, 10, 20, 20)));
+            assert!(outer.contains_region(&MaskRegion::new(15, 12, 5, 5)));
+            assert!(!outer.contains_region(&MaskRegion::new(25, 25, 10, 2)));
+            assert!(!outer.contains_region(&MaskRegion::new(5, 12, 10, 2)));
+        }
+    }
 }
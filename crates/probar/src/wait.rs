@@ -10,7 +10,7 @@
 //! - **Poka-Yoke**: Type-safe wait conditions prevent invalid waits
 //! - **Muda**: Efficient polling reduces wasted CPU cycles
 
-use crate::network::UrlPattern;
+use crate::network::{NetworkActivity, UrlPattern};
 use crate::result::{ProbarError, ProbarResult};
 use std::time::{Duration, Instant};
 
@@ -288,6 +288,14 @@ pub trait WaitCondition: Send + Sync {
 
     /// Get description for error messages
     fn description(&self) -> String;
+
+    /// Check the condition, returning the descriptions of what satisfied it
+    ///
+    /// Plain conditions report their own description; combinators report
+    /// the child conditions that satisfied them.
+    fn poll(&self) -> Option<Vec<String>> {
+        self.check().then(|| vec![self.description()])
+    }
 }
 
 /// A function-based wait condition
@@ -324,6 +332,101 @@ impl<F: Fn() -> bool + Send + Sync> WaitCondition for FnCondition<F> {
     }
 }
 
+/// How a [`CombinedCondition`] resolves from its children
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitCombinator {
+    /// Every child must be satisfied on the same poll
+    All,
+    /// Any single child satisfies the combination
+    Any,
+}
+
+/// A wait condition combining several child conditions
+///
+/// Children are polled together on every tick. An empty `all` is satisfied
+/// immediately; an empty `any` never is.
+pub struct CombinedCondition {
+    combinator: WaitCombinator,
+    conditions: Vec<Box<dyn WaitCondition>>,
+}
+
+impl std::fmt::Debug for CombinedCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CombinedCondition")
+            .field("combinator", &self.combinator)
+            .field("description", &self.description())
+            .finish_non_exhaustive()
+    }
+}
+
+impl CombinedCondition {
+    /// Wait until every condition is satisfied
+    #[must_use]
+    pub fn all(conditions: Vec<Box<dyn WaitCondition>>) -> Self {
+        Self {
+            combinator: WaitCombinator::All,
+            conditions,
+        }
+    }
+
+    /// Wait until any condition is satisfied
+    #[must_use]
+    pub fn any(conditions: Vec<Box<dyn WaitCondition>>) -> Self {
+        Self {
+            combinator: WaitCombinator::Any,
+            conditions,
+        }
+    }
+
+    /// Get the combinator
+    #[must_use]
+    pub const fn combinator(&self) -> WaitCombinator {
+        self.combinator
+    }
+
+    /// Get the number of child conditions
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.conditions.len()
+    }
+
+    /// Check if there are no child conditions
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl WaitCondition for CombinedCondition {
+    fn check(&self) -> bool {
+        self.poll().is_some()
+    }
+
+    fn description(&self) -> String {
+        let children: Vec<String> = self.conditions.iter().map(|c| c.description()).collect();
+        let name = match self.combinator {
+            WaitCombinator::All => "all of",
+            WaitCombinator::Any => "any of",
+        };
+        format!("{} [{}]", name, children.join(", "))
+    }
+
+    fn poll(&self) -> Option<Vec<String>> {
+        let mut satisfied = Vec::new();
+        for condition in &self.conditions {
+            match (condition.poll(), self.combinator) {
+                (Some(children), _) => satisfied.extend(children),
+                (None, WaitCombinator::All) => return None,
+                (None, WaitCombinator::Any) => {}
+            }
+        }
+        match self.combinator {
+            WaitCombinator::Any if satisfied.is_empty() => None,
+            _ => Some(satisfied),
+        }
+    }
+}
+
 // =============================================================================
 // WAIT RESULT
 // =============================================================================
@@ -337,6 +440,8 @@ pub struct WaitResult {
     pub elapsed: Duration,
     /// Description of what was waited for
     pub waited_for: String,
+    /// Descriptions of the conditions that satisfied the wait
+    pub satisfied: Vec<String>,
 }
 
 impl WaitResult {
@@ -347,6 +452,7 @@ impl WaitResult {
             success: true,
             elapsed,
             waited_for: waited_for.into(),
+            satisfied: Vec::new(),
         }
     }
 
@@ -357,8 +463,16 @@ impl WaitResult {
             success: false,
             elapsed,
             waited_for: waited_for.into(),
+            satisfied: Vec::new(),
         }
     }
+
+    /// Set the conditions that satisfied the wait
+    #[must_use]
+    pub fn with_satisfied(mut self, satisfied: Vec<String>) -> Self {
+        self.satisfied = satisfied;
+        self
+    }
 }
 
 // =============================================================================
@@ -379,6 +493,10 @@ pub struct Waiter {
     pending_requests: usize,
     /// Time of last network activity
     last_network_activity: Option<Instant>,
+    /// Shared in-flight request tracker (overrides `pending_requests`)
+    network: Option<NetworkActivity>,
+    /// Idle window for `networkidle` (defaults to `NETWORK_IDLE_THRESHOLD_MS`)
+    network_idle_threshold_ms: Option<u64>,
     /// Events that have occurred
     events: Vec<PageEvent>,
 }
@@ -399,6 +517,25 @@ impl Waiter {
         }
     }
 
+    /// Track in-flight requests from a shared tracker
+    ///
+    /// Typically the handle from
+    /// [`NetworkInterception::network_activity`](crate::network::NetworkInterception::network_activity).
+    /// Once attached, `networkidle` is decided by the tracker instead of
+    /// [`Self::set_pending_requests`].
+    #[must_use]
+    pub fn with_network_activity(mut self, activity: NetworkActivity) -> Self {
+        self.network = Some(activity);
+        self
+    }
+
+    /// Set the quiet window required for `networkidle`
+    #[must_use]
+    pub const fn with_network_idle_threshold(mut self, threshold_ms: u64) -> Self {
+        self.network_idle_threshold_ms = Some(threshold_ms);
+        self
+    }
+
     /// Set current URL (for testing)
     pub fn set_url(&mut self, url: impl Into<String>) {
         self.current_url = Some(url.into());
@@ -438,11 +575,11 @@ impl Waiter {
         let poll_interval = Duration::from_millis(options.poll_interval_ms);
 
         while start.elapsed() < timeout {
-            if condition.check() {
-                return Ok(WaitResult::success(
-                    start.elapsed(),
-                    condition.description(),
-                ));
+            if let Some(satisfied) = condition.poll() {
+                return Ok(
+                    WaitResult::success(start.elapsed(), condition.description())
+                        .with_satisfied(satisfied),
+                );
             }
             std::thread::sleep(poll_interval);
         }
@@ -515,12 +652,20 @@ impl Waiter {
 
     /// Check if network is idle
     fn is_network_idle(&self) -> bool {
+        let threshold = Duration::from_millis(
+            self.network_idle_threshold_ms
+                .unwrap_or(NETWORK_IDLE_THRESHOLD_MS),
+        );
+        if let Some(ref network) = self.network {
+            return network.is_idle(threshold);
+        }
+
         if self.pending_requests > 0 {
             return false;
         }
 
         match self.last_network_activity {
-            Some(last) => last.elapsed() >= Duration::from_millis(NETWORK_IDLE_THRESHOLD_MS),
+            Some(last) => last.elapsed() >= threshold,
             None => true, // No network activity = idle
         }
     }
@@ -1091,6 +1236,77 @@ mod tests {
         }
     }
 
+    mod combined_condition_tests {
+        use super::*;
+
+        fn after_ms(start: Instant, ms: u64, name: &str) -> Box<dyn WaitCondition> {
+            Box::new(FnCondition::new(
+                move || start.elapsed() >= Duration::from_millis(ms),
+                name,
+            ))
+        }
+
+        #[test]
+        fn test_all_waits_for_every_condition() {
+            let start = Instant::now();
+            let condition =
+                CombinedCondition::all(vec![after_ms(start, 20, "a"), after_ms(start, 60, "b")]);
+            let options = WaitOptions::new().with_timeout(2000).with_poll_interval(5);
+
+            let result = Waiter::new().wait_for(&condition, &options).unwrap();
+            assert!(result.success);
+            assert!(start.elapsed() >= Duration::from_millis(60));
+            assert_eq!(result.satisfied, vec!["a", "b"]);
+            assert_eq!(result.waited_for, "all of [a, b]");
+        }
+
+        #[test]
+        fn test_all_times_out_when_one_condition_never_holds() {
+            let start = Instant::now();
+            let condition = CombinedCondition::all(vec![
+                after_ms(start, 0, "ready"),
+                Box::new(FnCondition::new(|| false, "never")),
+            ]);
+            let options = WaitOptions::new().with_timeout(50).with_poll_interval(5);
+
+            let result = Waiter::new().wait_for(&condition, &options);
+            assert!(matches!(result, Err(ProbarError::Timeout { ms: 50 })));
+        }
+
+        #[test]
+        fn test_any_resolves_on_first() {
+            let start = Instant::now();
+            let condition = CombinedCondition::any(vec![
+                Box::new(FnCondition::new(|| false, "never")),
+                after_ms(start, 20, "fast"),
+                after_ms(start, 1000, "slow"),
+            ]);
+            let options = WaitOptions::new().with_timeout(5000).with_poll_interval(5);
+
+            let result = Waiter::new().wait_for(&condition, &options).unwrap();
+            assert!(result.elapsed < Duration::from_secs(1));
+            assert_eq!(result.satisfied, vec!["fast"]);
+        }
+
+        #[test]
+        fn test_nested_and_empty_combinators() {
+            let nested = CombinedCondition::all(vec![
+                Box::new(FnCondition::new(|| true, "x")),
+                Box::new(CombinedCondition::any(vec![
+                    Box::new(FnCondition::new(|| false, "y")),
+                    Box::new(FnCondition::new(|| true, "z")),
+                ])),
+            ]);
+            assert_eq!(nested.poll(), Some(vec!["x".to_string(), "z".to_string()]));
+            assert_eq!(nested.description(), "all of [x, any of [y, z]]");
+
+            assert!(CombinedCondition::all(Vec::new()).check());
+            assert!(!CombinedCondition::any(Vec::new()).check());
+            assert_eq!(nested.combinator(), WaitCombinator::All);
+            assert_eq!(nested.len(), 2);
+        }
+    }
+
     // =========================================================================
     // Integration Tests
     // =========================================================================
@@ -1117,6 +1333,72 @@ mod tests {
             assert!(result.is_ok());
         }
 
+        #[test]
+        fn test_network_idle_tracks_in_flight_requests() {
+            use crate::clock::{create_clock, ClockOptions};
+
+            let clock = create_clock();
+            clock.install(ClockOptions::fixed(0)).unwrap();
+            let interception = crate::network::NetworkInterception::new();
+            let activity = interception
+                .network_activity()
+                .with_clock(Arc::clone(&clock));
+            let waiter = Waiter::new()
+                .with_network_activity(interception.network_activity())
+                .with_network_idle_threshold(100);
+            let options = WaitOptions::new().with_timeout(20).with_poll_interval(5);
+            let idle = || {
+                waiter
+                    .wait_for_load_state(LoadState::NetworkIdle, &options)
+                    .is_ok()
+            };
+
+            // First request starts before the wait so the page is busy.
+            activity.request_started();
+            assert!(!idle());
+            clock.fast_forward_ms(30);
+            activity.request_finished();
+
+            // Starts inside the 100ms quiet window, which must reset it.
+            clock.fast_forward_ms(50);
+            assert!(!idle());
+            activity.request_started();
+            clock.fast_forward_ms(40);
+            activity.request_finished();
+
+            // A full 100ms window after the last finish is required.
+            clock.fast_forward_ms(99);
+            assert!(!idle());
+            clock.fast_forward_ms(1);
+            assert!(idle());
+            assert_eq!(interception.network_activity().total_started(), 2);
+            assert_eq!(interception.network_activity().in_flight(), 0);
+        }
+
+        #[test]
+        fn test_dom_content_loaded_does_not_wait_for_network() {
+            let activity = crate::network::NetworkActivity::new();
+            activity.request_started();
+            let mut waiter = Waiter::new()
+                .with_network_activity(activity.clone())
+                .with_network_idle_threshold(10);
+            waiter.set_load_state(LoadState::DomContentLoaded);
+
+            let options = WaitOptions::new().with_timeout(50).with_poll_interval(5);
+            assert!(waiter
+                .wait_for_load_state(LoadState::DomContentLoaded, &options)
+                .is_ok());
+            assert!(waiter
+                .wait_for_load_state(LoadState::NetworkIdle, &options)
+                .is_err());
+
+            activity.request_finished();
+            let options = WaitOptions::new().with_timeout(500).with_poll_interval(5);
+            assert!(waiter
+                .wait_for_load_state(LoadState::NetworkIdle, &options)
+                .is_ok());
+        }
+
         #[test]
         fn test_multiple_wait_operations() {
             let mut waiter = Waiter::new();
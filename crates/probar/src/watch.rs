@@ -11,13 +11,19 @@
 //! - **Muda**: Only re-run affected tests (smart filtering)
 
 use crate::result::{ProbarError, ProbarResult};
+use notify::event::{ModifyKind, RenameMode};
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::time::{Duration, Instant};
 
+/// Ignore patterns for files editors create while saving
+///
+/// Backups (`*~`), Emacs lock files (`.#*`), Vim swap files (`*.swp`) and
+/// Vim's write-permission probe (`4913`).
+pub const EDITOR_TEMP_PATTERNS: &[&str] = &["**/*~", "**/.#*", "**/*.swp", "**/4913"];
+
 /// Configuration for watch mode
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WatchConfig {
@@ -39,11 +45,11 @@ impl Default for WatchConfig {
     fn default() -> Self {
         Self {
             patterns: vec!["**/*.rs".to_string(), "**/*.toml".to_string()],
-            ignore_patterns: vec![
-                "**/target/**".to_string(),
-                "**/.git/**".to_string(),
-                "**/node_modules/**".to_string(),
-            ],
+            ignore_patterns: ["**/target/**", "**/.git/**", "**/node_modules/**"]
+                .iter()
+                .chain(EDITOR_TEMP_PATTERNS)
+                .map(|p| (*p).to_string())
+                .collect(),
             debounce_ms: 300,
             clear_screen: true,
             run_on_start: true,
@@ -94,18 +100,25 @@ impl WatchConfig {
         self
     }
 
-    /// Check if a path matches watch patterns
+    /// Check if a path matches any ignore pattern
     #[must_use]
-    pub fn matches_pattern(&self, path: &Path) -> bool {
+    pub fn is_ignored(&self, path: &Path) -> bool {
         let path_str = path.to_string_lossy();
+        self.ignore_patterns
+            .iter()
+            .any(|pattern| Self::glob_matches(pattern, &path_str))
+    }
 
+    /// Check if a path matches watch patterns
+    #[must_use]
+    pub fn matches_pattern(&self, path: &Path) -> bool {
         // Check ignore patterns first
-        for pattern in &self.ignore_patterns {
-            if Self::glob_matches(pattern, &path_str) {
-                return false;
-            }
+        if self.is_ignored(path) {
+            return false;
         }
 
+        let path_str = path.to_string_lossy();
+
         // Check watch patterns
         for pattern in &self.patterns {
             if Self::glob_matches(pattern, &path_str) {
@@ -296,8 +309,9 @@ pub struct FileWatcher {
     config: WatchConfig,
     watcher: Option<RecommendedWatcher>,
     receiver: Option<Receiver<Result<Event, notify::Error>>>,
-    last_trigger: Option<Instant>,
+    last_event: Option<Instant>,
     pending_changes: Vec<FileChange>,
+    stats: WatchStats,
 }
 
 impl FileWatcher {
@@ -307,8 +321,9 @@ impl FileWatcher {
             config,
             watcher: None,
             receiver: None,
-            last_trigger: None,
+            last_event: None,
             pending_changes: Vec::new(),
+            stats: WatchStats::new(),
         })
     }
 
@@ -361,52 +376,77 @@ impl FileWatcher {
     /// Check for changes (non-blocking)
     pub fn check_changes(&mut self) -> Option<Vec<FileChange>> {
         let receiver = self.receiver.as_ref()?;
+        let events: Vec<Event> = receiver.try_iter().filter_map(Result::ok).collect();
+
         let now = Instant::now();
+        for event in &events {
+            self.record_event(event, now);
+        }
+        self.poll_changes(now)
+    }
 
-        // Collect all pending events
-        while let Ok(result) = receiver.try_recv() {
-            if let Ok(event) = result {
-                for path in event.paths {
-                    if self.config.matches_pattern(&path) {
-                        self.pending_changes.push(FileChange {
-                            path,
-                            kind: event.kind.into(),
-                            timestamp: now,
-                        });
-                    }
-                }
+    /// Record a raw file system event observed at `now`
+    ///
+    /// Events for the same path are coalesced into one pending change that
+    /// keeps the latest kind. A rename is keyed by its final path, so an
+    /// editor's write-temp-then-rename save counts as a change to the target.
+    /// Paths matching an ignore pattern are counted in
+    /// [`WatchStats::ignored_count`] and do not restart the debounce window.
+    pub fn record_event(&mut self, event: &Event, now: Instant) {
+        let paths = match event.kind {
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => event.paths.last(),
+            _ => None,
+        }
+        .map_or(event.paths.as_slice(), std::slice::from_ref);
+
+        for path in paths {
+            if self.config.is_ignored(path) {
+                self.stats.ignored_count += 1;
+                continue;
+            }
+            if !self.config.matches_pattern(path) {
+                continue;
+            }
+
+            self.last_event = Some(now);
+            let kind = event.kind.into();
+            if let Some(pending) = self.pending_changes.iter_mut().find(|c| c.path == *path) {
+                pending.kind = kind;
+                pending.timestamp = now;
+                self.stats.coalesced_count += 1;
+            } else {
+                self.pending_changes.push(FileChange {
+                    path: path.clone(),
+                    kind,
+                    timestamp: now,
+                });
             }
         }
+    }
 
-        // Check if we should trigger (debounce)
-        if self.pending_changes.is_empty() {
+    /// Take the pending changes once the debounce window has passed
+    ///
+    /// Changes are released only after `debounce_ms` without a new relevant
+    /// event, so a burst of saves triggers a single rebuild.
+    pub fn poll_changes(&mut self, now: Instant) -> Option<Vec<FileChange>> {
+        let last_event = self.last_event?;
+        if self.pending_changes.is_empty()
+            || now.saturating_duration_since(last_event)
+                < Duration::from_millis(self.config.debounce_ms)
+        {
             return None;
         }
 
-        let should_trigger = match self.last_trigger {
-            Some(last) => now.duration_since(last).as_millis() >= self.config.debounce_ms as u128,
-            None => true,
-        };
-
-        if should_trigger {
-            self.last_trigger = Some(now);
-            let changes = std::mem::take(&mut self.pending_changes);
-
-            // Deduplicate by path
-            let unique_paths: HashSet<_> = changes.iter().map(|c| c.path.clone()).collect();
-            let deduped: Vec<FileChange> = unique_paths
-                .into_iter()
-                .filter_map(|path| changes.iter().find(|c| c.path == path).cloned())
-                .collect();
+        self.last_event = None;
+        let changes = std::mem::take(&mut self.pending_changes);
+        self.stats.record_trigger(changes.len());
+        Some(changes)
+    }
 
-            if deduped.is_empty() {
-                None
-            } else {
-                Some(deduped)
-            }
-        } else {
-            None
-        }
+    /// Get statistics for this watcher
+    #[must_use]
+    pub fn stats(&self) -> &WatchStats {
+        &self.stats
     }
 
     /// Get the configuration
@@ -443,6 +483,10 @@ pub struct WatchStats {
     pub total_runtime: Duration,
     /// Time of last trigger
     pub last_trigger: Option<Instant>,
+    /// Events merged into an already pending change for the same path
+    pub coalesced_count: u64,
+    /// Events dropped because their path matched an ignore pattern
+    pub ignored_count: u64,
 }
 
 impl WatchStats {
@@ -525,6 +569,15 @@ impl WatchBuilder {
         self
     }
 
+    /// Ignore editor temp, swap and backup files
+    #[must_use]
+    pub fn ignore_editor_temp_files(mut self) -> Self {
+        self.config
+            .ignore_patterns
+            .extend(EDITOR_TEMP_PATTERNS.iter().map(|p| (*p).to_string()));
+        self
+    }
+
     /// Set debounce duration
     #[must_use]
     pub const fn debounce(mut self, ms: u64) -> Self {
@@ -762,6 +815,85 @@ mod tests {
         }
     }
 
+    mod coalescing_tests {
+        use super::*;
+        use notify::event::DataChange;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        fn modify(path: &str) -> Event {
+            Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+                .add_path(PathBuf::from(path))
+        }
+
+        #[test]
+        fn test_burst_coalesces_into_single_rebuild() {
+            let config = WatchConfig::new().with_debounce(100);
+            let mut watcher = FileWatcher::new(config).unwrap();
+            let rebuilds = Arc::new(AtomicUsize::new(0));
+            let counter = Arc::clone(&rebuilds);
+            let handler = FnWatchHandler::new(move |changes: &[FileChange]| {
+                assert_eq!(changes.len(), 1);
+                assert_eq!(changes[0].path, PathBuf::from("src/lib.rs"));
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            });
+
+            let t0 = Instant::now();
+            for i in 0..5 {
+                watcher.record_event(&modify("src/lib.rs"), t0 + Duration::from_millis(i * 10));
+            }
+            watcher.record_event(&modify("src/.lib.rs.swp"), t0 + Duration::from_millis(45));
+
+            for ms in [0, 50, 139, 140, 200, 400] {
+                if let Some(changes) = watcher.poll_changes(t0 + Duration::from_millis(ms)) {
+                    handler.on_change(&changes).unwrap();
+                }
+            }
+
+            assert_eq!(rebuilds.load(Ordering::SeqCst), 1);
+            let stats = watcher.stats();
+            assert_eq!(stats.trigger_count, 1);
+            assert_eq!(stats.change_count, 1);
+            assert_eq!(stats.coalesced_count, 4);
+            assert_eq!(stats.ignored_count, 1);
+        }
+
+        #[test]
+        fn test_rename_keyed_by_final_path() {
+            let config = WatchConfig::new().with_debounce(0);
+            let mut watcher = FileWatcher::new(config).unwrap();
+            let now = Instant::now();
+
+            let rename = Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+                .add_path(PathBuf::from("src/main.rs.tmp"))
+                .add_path(PathBuf::from("src/main.rs"));
+            watcher.record_event(&modify("src/main.rs"), now);
+            watcher.record_event(&rename, now);
+
+            let changes = watcher.poll_changes(now).unwrap();
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].path, PathBuf::from("src/main.rs"));
+            assert_eq!(watcher.stats().coalesced_count, 1);
+        }
+
+        #[test]
+        fn test_default_ignores_editor_temp_files() {
+            let config = WatchConfig::default();
+            for path in ["src/lib.rs~", "src/.#lib.rs", "src/.lib.rs.swp", "src/4913"] {
+                assert!(config.is_ignored(Path::new(path)), "{path}");
+            }
+            assert!(!config.is_ignored(Path::new("src/lib.rs")));
+
+            let built = WatchBuilder::new()
+                .rust_files()
+                .ignore_editor_temp_files()
+                .build();
+            assert!(!built.matches_pattern(Path::new("src/.#lib.rs")));
+            assert!(built.matches_pattern(Path::new("src/lib.rs")));
+        }
+    }
+
     mod watch_stats_tests {
         use super::*;
 
@@ -1718,6 +1850,8 @@ mod tests {
                 change_count: 25,
                 total_runtime: Duration::from_secs(60),
                 last_trigger: Some(Instant::now()),
+                coalesced_count: 0,
+                ignored_count: 0,
             };
 
             assert_eq!(stats.trigger_count, 10);
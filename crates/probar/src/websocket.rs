@@ -10,11 +10,12 @@
 //! - **Jidoka**: Fail-fast on unexpected messages
 //! - **Kaizen**: Continuous improvement through message inspection
 
+use crate::clock::Clock;
 use crate::result::{ProbarError, ProbarResult};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// WebSocket connection state
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -29,6 +30,36 @@ pub enum WebSocketState {
     Closed,
 }
 
+impl WebSocketState {
+    /// Check whether moving from this state to `next` is a legal transition
+    ///
+    /// Follows the RFC 6455 lifecycle: a connection opens (or fails) once,
+    /// may start a closing handshake while open, and ends closed.
+    #[must_use]
+    pub const fn can_transition_to(self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Connecting, Self::Open | Self::Closed)
+                | (Self::Open, Self::Closing | Self::Closed)
+                | (Self::Closing, Self::Closed)
+        )
+    }
+
+    /// Check whether a data frame in `direction` is legal in this state
+    ///
+    /// Neither peer may send data before the opening handshake completes or
+    /// after the connection is closed. While closing, the server may still
+    /// deliver frames that were in flight, but the client must stop sending.
+    #[must_use]
+    pub const fn allows_data(self, direction: MessageDirection) -> bool {
+        match self {
+            Self::Connecting | Self::Closed => false,
+            Self::Open => true,
+            Self::Closing => matches!(direction, MessageDirection::Received),
+        }
+    }
+}
+
 /// WebSocket message type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MessageType {
@@ -181,6 +212,29 @@ impl WebSocketMessage {
     }
 }
 
+/// A WebSocket protocol violation detected on a connection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtocolViolation {
+    /// Connection ID the violation occurred on
+    pub connection_id: String,
+    /// Connection state when the violation occurred
+    pub state: WebSocketState,
+    /// Offending message, if the violation was caused by a frame
+    pub message: Option<WebSocketMessage>,
+    /// Human-readable description
+    pub description: String,
+}
+
+impl std::fmt::Display for ProtocolViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] {} (state: {:?})",
+            self.connection_id, self.description, self.state
+        )
+    }
+}
+
 /// A tracked WebSocket connection
 #[derive(Debug)]
 pub struct WebSocketConnection {
@@ -198,6 +252,14 @@ pub struct WebSocketConnection {
     pub close_code: Option<u16>,
     /// Close reason (if closed)
     pub close_reason: Option<String>,
+    /// Protocol violations observed on this connection
+    violations: Arc<Mutex<Vec<ProtocolViolation>>>,
+    /// Injected clock (real time is used when absent)
+    clock: Option<Clock>,
+    /// Clock time when the connection was created
+    clock_start_ms: u64,
+    /// Server-initiated messages waiting for their due time, ordered by due time
+    scheduled: VecDeque<ScheduledPush>,
 }
 
 impl WebSocketConnection {
@@ -212,30 +274,137 @@ impl WebSocketConnection {
             start_time: Instant::now(),
             close_code: None,
             close_reason: None,
+            violations: Arc::new(Mutex::new(Vec::new())),
+            clock: None,
+            clock_start_ms: 0,
+            scheduled: VecDeque::new(),
         }
     }
 
-    /// Open the connection
+    /// Measure elapsed time with an injected clock instead of real time
+    #[must_use]
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock_start_ms = clock.now_ms();
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Open the connection (opening handshake completed)
     pub fn open(&mut self) {
-        self.state = WebSocketState::Open;
+        self.transition(WebSocketState::Open);
+    }
+
+    /// Start the closing handshake
+    pub fn begin_close(&mut self) {
+        self.transition(WebSocketState::Closing);
     }
 
     /// Close the connection
     pub fn close(&mut self, code: u16, reason: &str) {
-        self.state = WebSocketState::Closed;
+        self.transition(WebSocketState::Closed);
         self.close_code = Some(code);
         self.close_reason = Some(reason.to_string());
     }
 
+    /// Move to `next`, recording a violation if the transition is illegal
+    fn transition(&mut self, next: WebSocketState) {
+        if !self.state.can_transition_to(next) {
+            self.record_violation(
+                None,
+                format!("invalid state transition {:?} -> {:?}", self.state, next),
+            );
+        }
+        self.state = next;
+    }
+
+    fn record_violation(&self, message: Option<WebSocketMessage>, description: String) {
+        if let Ok(mut violations) = self.violations.lock() {
+            violations.push(ProtocolViolation {
+                connection_id: self.id.clone(),
+                state: self.state,
+                message,
+                description,
+            });
+        }
+    }
+
+    /// Get protocol violations observed on this connection
+    #[must_use]
+    pub fn violations(&self) -> Vec<ProtocolViolation> {
+        self.violations
+            .lock()
+            .map(|v| v.clone())
+            .unwrap_or_default()
+    }
+
     /// Get elapsed time in milliseconds
     #[must_use]
     pub fn elapsed_ms(&self) -> u64 {
-        self.start_time.elapsed().as_millis() as u64
+        match &self.clock {
+            Some(clock) => clock.now_ms().saturating_sub(self.clock_start_ms),
+            None => self.start_time.elapsed().as_millis() as u64,
+        }
+    }
+
+    /// Schedule a server-initiated message `delay_ms` after connection start
+    ///
+    /// Pushes with the same due time are delivered in scheduling order.
+    pub fn schedule_push(&mut self, push: ScheduledPush) {
+        let index = self
+            .scheduled
+            .partition_point(|p| p.delay_ms <= push.delay_ms);
+        self.scheduled.insert(index, push);
+    }
+
+    /// Number of scheduled pushes not yet delivered
+    #[must_use]
+    pub fn pending_push_count(&self) -> usize {
+        self.scheduled.len()
+    }
+
+    /// Deliver every scheduled push that is due, in order
+    ///
+    /// Delivered messages are timestamped with their due time rather than the
+    /// time of delivery, so timelines stay deterministic when the clock jumps.
+    /// Nothing is delivered unless the connection is open.
+    pub fn deliver_due(&mut self) -> usize {
+        if !self.is_open() {
+            return 0;
+        }
+        let now = self.elapsed_ms();
+        let mut delivered = 0;
+        while self.scheduled.front().is_some_and(|p| p.delay_ms <= now) {
+            if let Some(push) = self.scheduled.pop_front() {
+                let mut message = push.message;
+                message.timestamp_ms = push.delay_ms;
+                self.record_message(message);
+                delivered += 1;
+            }
+        }
+        delivered
     }
 
     /// Record a message
+    ///
+    /// Data frames that arrive in a state that does not allow them (e.g. a
+    /// server frame before the handshake completes) are still recorded, but
+    /// also flagged as protocol violations.
     pub fn record_message(&self, mut message: WebSocketMessage) {
         message.connection_id = self.id.clone();
+        if (message.is_text() || message.is_binary()) && !self.state.allows_data(message.direction)
+        {
+            let description = match (self.state, message.direction) {
+                (WebSocketState::Connecting, MessageDirection::Received) => {
+                    "server data frame before handshake completed"
+                }
+                (WebSocketState::Connecting, MessageDirection::Sent) => {
+                    "client data frame before handshake completed"
+                }
+                (WebSocketState::Closing, _) => "client data frame after close started",
+                _ => "data frame after connection closed",
+            };
+            self.record_violation(Some(message.clone()), description.to_string());
+        }
         if let Ok(mut messages) = self.messages.lock() {
             messages.push(message);
         }
@@ -360,6 +529,26 @@ impl Default for MockWebSocketResponse {
     }
 }
 
+/// A server-initiated message scheduled relative to connection start
+#[derive(Debug, Clone)]
+pub struct ScheduledPush {
+    /// Delay after the connection opens (ms)
+    pub delay_ms: u64,
+    /// Message to deliver to the client
+    pub message: WebSocketMessage,
+}
+
+impl ScheduledPush {
+    /// Create a scheduled text push
+    #[must_use]
+    pub fn text(delay_ms: u64, data: &str) -> Self {
+        Self {
+            delay_ms,
+            message: WebSocketMessage::text(data, MessageDirection::Received, delay_ms),
+        }
+    }
+}
+
 /// A WebSocket mock rule
 #[derive(Debug, Clone)]
 pub struct WebSocketMock {
@@ -373,6 +562,8 @@ pub struct WebSocketMock {
     pub once: bool,
     /// Whether this mock has been used
     pub used: bool,
+    /// Server-initiated messages pushed after the connection opens
+    pub pushes: Vec<ScheduledPush>,
 }
 
 impl WebSocketMock {
@@ -385,9 +576,28 @@ impl WebSocketMock {
             response: MockWebSocketResponse::new(),
             once: false,
             used: false,
+            pushes: Vec::new(),
         }
     }
 
+    /// Push a message to the client as soon as the connection opens
+    #[must_use]
+    pub fn push_on_connect(self, message: &str) -> Self {
+        self.push_after(Duration::ZERO, message)
+    }
+
+    /// Push a message to the client `delay` after the connection opens
+    ///
+    /// The delay is measured with the monitor's clock, so pushes are
+    /// deterministic when a [`Clock`] is injected via
+    /// [`WebSocketMonitor::with_clock`].
+    #[must_use]
+    pub fn push_after(mut self, delay: Duration, message: &str) -> Self {
+        self.pushes
+            .push(ScheduledPush::text(delay.as_millis() as u64, message));
+        self
+    }
+
     /// Set response for when connection opens
     #[must_use]
     pub fn on_open(mut self, response: MockWebSocketResponse) -> Self {
@@ -449,6 +659,8 @@ pub struct WebSocketMonitor {
     active: bool,
     /// Connection counter
     connection_counter: u64,
+    /// Injected clock for connection timing
+    clock: Option<Clock>,
 }
 
 impl Default for WebSocketMonitor {
@@ -467,9 +679,17 @@ impl WebSocketMonitor {
             pending_responses: VecDeque::new(),
             active: false,
             connection_counter: 0,
+            clock: None,
         }
     }
 
+    /// Use an injected clock for connection timing and scheduled pushes
+    #[must_use]
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
     /// Start monitoring
     pub fn start(&mut self) {
         self.active = true;
@@ -493,26 +713,76 @@ impl WebSocketMonitor {
 
     /// Create a new connection
     pub fn connect(&mut self, url: &str) -> String {
+        let id = self.connect_pending(url);
+        self.complete_handshake(&id);
+        id
+    }
+
+    /// Create a connection whose opening handshake has not completed yet
+    ///
+    /// The connection stays in [`WebSocketState::Connecting`] until
+    /// [`Self::complete_handshake`] is called, so traffic recorded in between
+    /// is checked against the handshake state.
+    pub fn connect_pending(&mut self, url: &str) -> String {
         self.connection_counter += 1;
         let id = format!("ws_{}", self.connection_counter);
 
         let mut connection = WebSocketConnection::new(&id, url);
-        connection.open();
+        if let Some(clock) = &self.clock {
+            connection = connection.with_clock(Arc::clone(clock));
+        }
+        if let Ok(mut connections) = self.connections.lock() {
+            connections.push(connection);
+        }
+
+        id
+    }
+
+    /// Complete the opening handshake of a pending connection
+    ///
+    /// Scheduled pushes from matching mocks are attached to the connection,
+    /// and those due immediately (see [`WebSocketMock::push_on_connect`]) are
+    /// delivered.
+    pub fn complete_handshake(&mut self, connection_id: &str) {
+        let Ok(mut connections) = self.connections.lock() else {
+            return;
+        };
+        let Some(conn) = connections.iter_mut().find(|c| c.id == connection_id) else {
+            return;
+        };
+        conn.open();
 
         // Check for on_open mocks
         for mock in &mut self.mocks {
-            if mock.matches_url(url) && mock.message_pattern.is_none() {
+            if !mock.matches_url(&conn.url) {
+                continue;
+            }
+            for push in &mock.pushes {
+                conn.schedule_push(push.clone());
+            }
+            if mock.message_pattern.is_none() {
                 self.pending_responses
-                    .push_back((id.clone(), mock.response.clone()));
+                    .push_back((connection_id.to_string(), mock.response.clone()));
                 mock.mark_used();
             }
         }
+        conn.deliver_due();
+    }
 
-        if let Ok(mut connections) = self.connections.lock() {
-            connections.push(connection);
-        }
-
-        id
+    /// Deliver scheduled server pushes that are due on every connection
+    ///
+    /// Call after advancing the injected clock. Returns the number of
+    /// messages delivered.
+    pub fn deliver_scheduled(&self) -> usize {
+        self.connections
+            .lock()
+            .map(|mut connections| {
+                connections
+                    .iter_mut()
+                    .map(WebSocketConnection::deliver_due)
+                    .sum()
+            })
+            .unwrap_or(0)
     }
 
     /// Close a connection
@@ -632,6 +902,68 @@ impl WebSocketMonitor {
         Ok(())
     }
 
+    /// Get protocol violations across all connections
+    #[must_use]
+    pub fn protocol_violations(&self) -> Vec<ProtocolViolation> {
+        self.connections
+            .lock()
+            .map(|connections| connections.iter().flat_map(|c| c.violations()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Assert no protocol violations were observed
+    pub fn assert_no_protocol_violations(&self) -> ProbarResult<()> {
+        let violations = self.protocol_violations();
+        if let Some(first) = violations.first() {
+            return Err(ProbarError::AssertionError {
+                message: format!(
+                    "Expected no WebSocket protocol violations, found {}: {}",
+                    violations.len(),
+                    first
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Assert messages matching `predicates` occur in the given relative order
+    ///
+    /// Messages from all connections are ordered by timestamp (ties keep
+    /// recording order). Each predicate must match a message that comes after
+    /// the message matched by the previous predicate; unrelated messages in
+    /// between are ignored.
+    pub fn assert_order(
+        &self,
+        predicates: &[&dyn Fn(&WebSocketMessage) -> bool],
+    ) -> ProbarResult<()> {
+        let mut messages = self.all_messages();
+        messages.sort_by_key(|m| m.timestamp_ms);
+
+        let mut next = 0;
+        for (index, predicate) in predicates.iter().enumerate() {
+            let Some(offset) = messages[next..].iter().position(*predicate) else {
+                let detail = messages[..next].iter().position(*predicate).map_or_else(
+                    || "no matching message was recorded".to_string(),
+                    |pos| {
+                        format!(
+                            "the only match ('{}') occurs before the match for predicate {}",
+                            messages[pos].data,
+                            index.saturating_sub(1)
+                        )
+                    },
+                );
+                return Err(ProbarError::AssertionError {
+                    message: format!(
+                        "Expected message order violated at predicate {}: {}",
+                        index, detail
+                    ),
+                });
+            };
+            next += offset + 1;
+        }
+        Ok(())
+    }
+
     /// Assert connection was made to URL
     pub fn assert_connected(&self, url_pattern: &str) -> ProbarResult<()> {
         let found = self
@@ -1059,6 +1391,203 @@ mod tests {
         }
     }
 
+    mod protocol_order_tests {
+        use super::*;
+
+        fn is_subscribe(m: &WebSocketMessage) -> bool {
+            m.is_sent() && m.contains("subscribe")
+        }
+
+        fn is_data(m: &WebSocketMessage) -> bool {
+            m.is_received() && m.contains("\"data\"")
+        }
+
+        #[test]
+        fn test_state_transitions() {
+            assert!(WebSocketState::Connecting.can_transition_to(WebSocketState::Open));
+            assert!(WebSocketState::Connecting.can_transition_to(WebSocketState::Closed));
+            assert!(WebSocketState::Open.can_transition_to(WebSocketState::Closing));
+            assert!(WebSocketState::Closing.can_transition_to(WebSocketState::Closed));
+            assert!(!WebSocketState::Closed.can_transition_to(WebSocketState::Open));
+            assert!(!WebSocketState::Open.can_transition_to(WebSocketState::Connecting));
+            assert!(!WebSocketState::Connecting.allows_data(MessageDirection::Received));
+            assert!(WebSocketState::Closing.allows_data(MessageDirection::Received));
+            assert!(!WebSocketState::Closing.allows_data(MessageDirection::Sent));
+        }
+
+        #[test]
+        fn test_subscribe_then_data_passes() {
+            let mut monitor = WebSocketMonitor::new();
+            let id = monitor.connect_pending("ws://feed.example.com");
+            monitor.complete_handshake(&id);
+            monitor.send(&id, r#"{"type":"subscribe","channel":"prices"}"#);
+            monitor.receive(&id, r#"{"type":"ack"}"#);
+            monitor.receive(&id, r#"{"type":"data","price":42}"#);
+
+            assert!(monitor.assert_order(&[&is_subscribe, &is_data]).is_ok());
+            assert!(monitor.protocol_violations().is_empty());
+            assert!(monitor.assert_no_protocol_violations().is_ok());
+        }
+
+        #[test]
+        fn test_early_data_is_flagged() {
+            let mut monitor = WebSocketMonitor::new();
+            let id = monitor.connect_pending("ws://feed.example.com");
+            monitor.receive(&id, r#"{"type":"data","price":41}"#);
+            monitor.complete_handshake(&id);
+            monitor.send(&id, r#"{"type":"subscribe","channel":"prices"}"#);
+
+            let err = monitor
+                .assert_order(&[&is_subscribe, &is_data])
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("predicate 1"));
+            assert!(err.contains("occurs before"));
+
+            let violations = monitor.protocol_violations();
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].state, WebSocketState::Connecting);
+            assert_eq!(violations[0].connection_id, id);
+            assert!(violations[0].description.contains("before handshake"));
+            assert!(violations[0]
+                .message
+                .as_ref()
+                .is_some_and(|m| m.is_received()));
+            assert!(monitor.assert_no_protocol_violations().is_err());
+        }
+
+        #[test]
+        fn test_assert_order_missing_match() {
+            let mut monitor = WebSocketMonitor::new();
+            let id = monitor.connect("ws://feed.example.com");
+            monitor.send(&id, "subscribe");
+
+            let err = monitor
+                .assert_order(&[&is_subscribe, &is_data])
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("no matching message"));
+            assert!(monitor.assert_order(&[]).is_ok());
+        }
+
+        #[test]
+        fn test_invalid_transition_recorded() {
+            let mut conn = WebSocketConnection::new("conn_1", "ws://example.com");
+            conn.open();
+            conn.close(1000, "done");
+            assert!(conn.violations().is_empty());
+            conn.open();
+            let violations = conn.violations();
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].state, WebSocketState::Closed);
+            assert!(violations[0].message.is_none());
+        }
+    }
+
+    mod scheduled_push_tests {
+        use super::*;
+        use crate::clock::{create_clock, ClockOptions};
+
+        #[test]
+        fn test_heartbeats_delivered_in_sequence() {
+            let clock = create_clock();
+            clock.install(ClockOptions::fixed(0)).unwrap();
+
+            let mut monitor = WebSocketMonitor::new().with_clock(Arc::clone(&clock));
+            monitor.mock(
+                WebSocketMock::new("ws://live")
+                    .push_after(Duration::from_secs(3), "heartbeat 3")
+                    .push_after(Duration::from_secs(1), "heartbeat 1")
+                    .push_after(Duration::from_secs(2), "heartbeat 2")
+                    .push_on_connect("welcome"),
+            );
+            let id = monitor.connect("ws://live/feed");
+
+            let received = |m: &WebSocketMonitor| -> Vec<(String, u64)> {
+                m.get_connection(&id)
+                    .unwrap()
+                    .into_iter()
+                    .filter(WebSocketMessage::is_received)
+                    .map(|msg| (msg.data, msg.timestamp_ms))
+                    .collect()
+            };
+            assert_eq!(received(&monitor), vec![("welcome".to_string(), 0)]);
+
+            assert_eq!(monitor.deliver_scheduled(), 0);
+            clock.fast_forward(Duration::from_millis(999));
+            assert_eq!(monitor.deliver_scheduled(), 0);
+
+            clock.fast_forward(Duration::from_millis(1));
+            assert_eq!(monitor.deliver_scheduled(), 1);
+
+            // Jumping past both remaining due times still delivers in order.
+            clock.fast_forward(Duration::from_millis(2500));
+            assert_eq!(monitor.deliver_scheduled(), 2);
+
+            assert_eq!(
+                received(&monitor),
+                vec![
+                    ("welcome".to_string(), 0),
+                    ("heartbeat 1".to_string(), 1000),
+                    ("heartbeat 2".to_string(), 2000),
+                    ("heartbeat 3".to_string(), 3000),
+                ]
+            );
+            assert!(monitor
+                .assert_order(&[
+                    &|m: &WebSocketMessage| m.data == "heartbeat 1",
+                    &|m: &WebSocketMessage| m.data == "heartbeat 2",
+                    &|m: &WebSocketMessage| m.data == "heartbeat 3",
+                ])
+                .is_ok());
+            assert!(monitor.protocol_violations().is_empty());
+        }
+
+        #[test]
+        fn test_equal_delays_keep_scheduling_order() {
+            let mut conn = WebSocketConnection::new("conn_1", "ws://example.com");
+            conn.schedule_push(ScheduledPush::text(10, "a"));
+            conn.schedule_push(ScheduledPush::text(5, "b"));
+            conn.schedule_push(ScheduledPush::text(10, "c"));
+            assert_eq!(conn.pending_push_count(), 3);
+
+            let order: Vec<_> = conn
+                .scheduled
+                .iter()
+                .map(|p| p.message.data.clone())
+                .collect();
+            assert_eq!(order, vec!["b", "a", "c"]);
+        }
+
+        #[test]
+        fn test_pushes_wait_for_open_connection() {
+            let clock = create_clock();
+            clock.install(ClockOptions::fixed(0)).unwrap();
+
+            let mut conn = WebSocketConnection::new("conn_1", "ws://example.com").with_clock(clock);
+            conn.schedule_push(ScheduledPush::text(0, "early"));
+            assert_eq!(conn.deliver_due(), 0);
+
+            conn.open();
+            assert_eq!(conn.deliver_due(), 1);
+            assert_eq!(conn.pending_push_count(), 0);
+            assert!(conn.violations().is_empty());
+        }
+
+        #[test]
+        fn test_push_on_connect_without_clock() {
+            let mut monitor = WebSocketMonitor::new();
+            monitor.mock(WebSocketMock::new("ws://chat").push_on_connect("hello"));
+            let id = monitor.connect("ws://chat/room");
+
+            let messages = monitor.get_connection(&id).unwrap();
+            assert_eq!(messages.len(), 1);
+            assert!(messages[0].is_received());
+            assert_eq!(messages[0].connection_id, id);
+            assert!(monitor.assert_received("hello").is_ok());
+        }
+    }
+
     mod additional_coverage_tests {
         use super::*;
 
@@ -15,13 +15,17 @@
 //! 4. Ring buffer overflow/underflow
 //! 5. Worker error recovery failures
 //! 6. Memory leak in long-running workers
+//! 7. Transferred `ArrayBuffer`s still used by the sender (neutering bugs)
 //!
 //! ## References:
 //! - [7] Herlihy & Shavit (2012) "The Art of Multiprocessor Programming"
 //! - [8] Lamport (1978) "Time, Clocks, and the Ordering of Events"
 //! - whisper.apr: SharedRingBuffer implementation
 
+use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::fmt;
+use std::rc::Rc;
 use std::time::Duration;
 
 /// Worker lifecycle states for testing
@@ -852,6 +856,184 @@ impl WorkerTestHarness {
     }
 }
 
+/// Simulated `ArrayBuffer` handle for worker message tests
+///
+/// Clones share the same backing store, like JS references. Transferring the
+/// buffer in a [`WorkerMessageChannel::post_message`] transfer list detaches
+/// it: every handle on the sender side then reports a zero length and reads
+/// fail with [`WorkerTestError::DetachedBuffer`].
+#[derive(Debug, Clone)]
+pub struct ArrayBuffer {
+    data: Rc<RefCell<Option<Vec<u8>>>>,
+}
+
+impl ArrayBuffer {
+    /// Create a buffer owning `bytes`
+    #[must_use]
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            data: Rc::new(RefCell::new(Some(bytes))),
+        }
+    }
+
+    /// Byte length (0 once detached, as in JS)
+    #[must_use]
+    pub fn byte_length(&self) -> usize {
+        self.data.borrow().as_ref().map_or(0, Vec::len)
+    }
+
+    /// Whether the buffer has been transferred away
+    #[must_use]
+    pub fn is_detached(&self) -> bool {
+        self.data.borrow().is_none()
+    }
+
+    /// Read the buffer contents
+    ///
+    /// # Errors
+    /// Returns [`WorkerTestError::DetachedBuffer`] if the buffer was transferred
+    pub fn read(&self) -> Result<Vec<u8>, WorkerTestError> {
+        self.data
+            .borrow()
+            .clone()
+            .ok_or(WorkerTestError::DetachedBuffer)
+    }
+
+    /// Overwrite the buffer contents
+    ///
+    /// # Errors
+    /// Returns [`WorkerTestError::DetachedBuffer`] if the buffer was transferred
+    pub fn write(&self, bytes: &[u8]) -> Result<(), WorkerTestError> {
+        match self.data.borrow_mut().as_mut() {
+            Some(data) => {
+                data.clear();
+                data.extend_from_slice(bytes);
+                Ok(())
+            }
+            None => Err(WorkerTestError::DetachedBuffer),
+        }
+    }
+
+    fn same_buffer(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+    }
+
+    fn detach(&self) -> Option<Vec<u8>> {
+        self.data.borrow_mut().take()
+    }
+}
+
+/// Message posted between the main thread and a worker
+#[derive(Debug, Clone)]
+pub struct PostedMessage {
+    /// Message payload (structured-cloned)
+    pub payload: String,
+    /// Buffers carried by the message
+    pub buffers: Vec<ArrayBuffer>,
+}
+
+impl PostedMessage {
+    /// Create a message with a payload and no buffers
+    #[must_use]
+    pub fn new(payload: impl Into<String>) -> Self {
+        Self {
+            payload: payload.into(),
+            buffers: Vec::new(),
+        }
+    }
+
+    /// Attach a buffer to the message
+    #[must_use]
+    pub fn with_buffer(mut self, buffer: ArrayBuffer) -> Self {
+        self.buffers.push(buffer);
+        self
+    }
+}
+
+/// One-directional `postMessage` channel with transfer-list semantics
+///
+/// Buffers named in the transfer list move to the receiver and are detached
+/// on the sender; all other buffers are copied (structured clone).
+#[derive(Debug, Default)]
+pub struct WorkerMessageChannel {
+    queue: VecDeque<PostedMessage>,
+}
+
+impl WorkerMessageChannel {
+    /// Create an empty channel
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Post a message, transferring the buffers in `transfer`
+    ///
+    /// # Errors
+    /// Returns [`WorkerTestError::DataCloneError`] if the transfer list names
+    /// a buffer twice or an already-detached buffer, and
+    /// [`WorkerTestError::DetachedBuffer`] if a copied buffer is detached.
+    /// Nothing is detached when an error is returned.
+    pub fn post_message(
+        &mut self,
+        message: PostedMessage,
+        transfer: &[ArrayBuffer],
+    ) -> Result<(), WorkerTestError> {
+        for (i, buffer) in transfer.iter().enumerate() {
+            if buffer.is_detached() {
+                return Err(WorkerTestError::DataCloneError(format!(
+                    "transfer list entry {i} is already detached"
+                )));
+            }
+            if transfer[..i].iter().any(|b| b.same_buffer(buffer)) {
+                return Err(WorkerTestError::DataCloneError(format!(
+                    "transfer list entry {i} is a duplicate"
+                )));
+            }
+        }
+        let is_transferred = |buffer: &ArrayBuffer| transfer.iter().any(|t| t.same_buffer(buffer));
+        for buffer in &message.buffers {
+            if !is_transferred(buffer) && buffer.is_detached() {
+                return Err(WorkerTestError::DetachedBuffer);
+            }
+        }
+
+        // Receiver-side handles are fresh: transferred data moves, the rest is copied
+        let mut received: Vec<Option<ArrayBuffer>> = vec![None; message.buffers.len()];
+        for t in transfer {
+            let data = t.detach().unwrap_or_default();
+            for (slot, buffer) in received.iter_mut().zip(&message.buffers) {
+                if slot.is_none() && buffer.same_buffer(t) {
+                    *slot = Some(ArrayBuffer::new(data.clone()));
+                }
+            }
+        }
+        let buffers = received
+            .into_iter()
+            .zip(&message.buffers)
+            .map(|(slot, buffer)| {
+                slot.unwrap_or_else(|| ArrayBuffer::new(buffer.read().unwrap_or_default()))
+            })
+            .collect();
+
+        self.queue.push_back(PostedMessage {
+            payload: message.payload,
+            buffers,
+        });
+        Ok(())
+    }
+
+    /// Receive the next message, if any
+    pub fn recv(&mut self) -> Option<PostedMessage> {
+        self.queue.pop_front()
+    }
+
+    /// Number of undelivered messages
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+}
+
 /// Error type for worker testing
 #[derive(Debug, Clone)]
 pub enum WorkerTestError {
@@ -863,6 +1045,10 @@ pub enum WorkerTestError {
     ProtocolError(String),
     /// CDP error
     CdpError(String),
+    /// Access to an `ArrayBuffer` that was transferred away
+    DetachedBuffer,
+    /// `postMessage` could not clone or transfer the message
+    DataCloneError(String),
 }
 
 impl fmt::Display for WorkerTestError {
@@ -872,6 +1058,8 @@ impl fmt::Display for WorkerTestError {
             Self::Timeout(msg) => write!(f, "Timeout: {msg}"),
             Self::ProtocolError(msg) => write!(f, "Protocol error: {msg}"),
             Self::CdpError(msg) => write!(f, "CDP error: {msg}"),
+            Self::DetachedBuffer => write!(f, "Cannot access a detached ArrayBuffer"),
+            Self::DataCloneError(msg) => write!(f, "DataCloneError: {msg}"),
         }
     }
 }
@@ -1351,4 +1539,81 @@ mod tests {
         assert!(config.test_wait_notify);
         assert!(config.test_concurrent_writes);
     }
+
+    // =========================================================================
+    // H0-WH-40: Transferable ArrayBuffer semantics
+    // =========================================================================
+
+    #[test]
+    fn h0_wh_40_transfer_detaches_sender_buffer() {
+        let mut channel = WorkerMessageChannel::new();
+        let buffer = ArrayBuffer::new(vec![1, 2, 3, 4]);
+        let alias = buffer.clone();
+
+        channel
+            .post_message(
+                PostedMessage::new("audio-chunk").with_buffer(buffer.clone()),
+                std::slice::from_ref(&buffer),
+            )
+            .unwrap();
+
+        let received = channel.recv().unwrap();
+        assert_eq!(received.payload, "audio-chunk");
+        assert_eq!(received.buffers[0].read().unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(received.buffers[0].byte_length(), 4);
+
+        // Every sender-side handle is neutered
+        assert!(buffer.is_detached());
+        assert_eq!(alias.byte_length(), 0);
+        assert!(matches!(
+            buffer.read(),
+            Err(WorkerTestError::DetachedBuffer)
+        ));
+        assert!(matches!(
+            alias.write(&[9]),
+            Err(WorkerTestError::DetachedBuffer)
+        ));
+    }
+
+    #[test]
+    fn h0_wh_41_post_without_transfer_copies() {
+        let mut channel = WorkerMessageChannel::new();
+        let buffer = ArrayBuffer::new(vec![7, 8]);
+
+        channel
+            .post_message(PostedMessage::new("copy").with_buffer(buffer.clone()), &[])
+            .unwrap();
+        buffer.write(&[0, 0]).unwrap();
+
+        let received = channel.recv().unwrap();
+        assert!(!buffer.is_detached());
+        assert_eq!(received.buffers[0].read().unwrap(), vec![7, 8]);
+        assert_eq!(channel.pending(), 0);
+    }
+
+    #[test]
+    fn h0_wh_42_transfer_errors() {
+        let mut channel = WorkerMessageChannel::new();
+        let buffer = ArrayBuffer::new(vec![1]);
+
+        let dup = channel.post_message(
+            PostedMessage::new("dup").with_buffer(buffer.clone()),
+            &[buffer.clone(), buffer.clone()],
+        );
+        assert!(matches!(dup, Err(WorkerTestError::DataCloneError(_))));
+        assert!(!buffer.is_detached());
+
+        channel
+            .post_message(PostedMessage::new("first"), std::slice::from_ref(&buffer))
+            .unwrap();
+        let again = channel.post_message(PostedMessage::new("again"), std::slice::from_ref(&buffer));
+        assert!(matches!(again, Err(WorkerTestError::DataCloneError(_))));
+
+        let copy = channel.post_message(PostedMessage::new("copy").with_buffer(buffer), &[]);
+        assert!(matches!(copy, Err(WorkerTestError::DetachedBuffer)));
+        assert_eq!(channel.pending(), 1);
+        assert!(WorkerTestError::DetachedBuffer
+            .to_string()
+            .contains("detached"));
+    }
 }
@@ -14,11 +14,15 @@
 //! 3. External CSS files not generated by builders
 //! 4. HTML files without WASM generation markers
 //!
+//! Known-good generated scripts (wasm-bindgen glue, service workers) can be
+//! allowlisted by path glob and/or SHA-256 via [`ScriptAllowlistEntry`].
+//!
 //! ## References:
 //! - whisper.apr CLAUDE.md: "ABSOLUTE ZERO JAVASCRIPT"
 //! - DO-178C Section 6.3: Configuration management
 //! - OWASP: Third-party JavaScript injection prevention
 
+use sha2::{Digest, Sha256};
 use std::fmt;
 use std::path::{Path, PathBuf};
 
@@ -100,6 +104,10 @@ pub struct ZeroJsValidationResult {
     pub dangerous_patterns: Vec<DangerousPatternViolation>,
     /// Files that passed manifest verification
     pub verified_js_files: Vec<PathBuf>,
+    /// Scripts allowlisted by SHA-256 of their contents
+    pub allowlisted_by_hash: Vec<PathBuf>,
+    /// Scripts allowlisted by path glob only
+    pub allowlisted_by_path: Vec<PathBuf>,
 }
 
 impl ZeroJsValidationResult {
@@ -168,10 +176,118 @@ impl fmt::Display for ZeroJsValidationResult {
                 }
             }
         }
+        self.fmt_allowlisted(f)
+    }
+}
+
+impl ZeroJsValidationResult {
+    fn fmt_allowlisted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.allowlisted_by_hash.is_empty() {
+            writeln!(f, "\n  Allowlisted by hash:")?;
+            for path in &self.allowlisted_by_hash {
+                writeln!(f, "    - {}", path.display())?;
+            }
+        }
+        if !self.allowlisted_by_path.is_empty() {
+            writeln!(f, "\n  Allowlisted by path:")?;
+            for path in &self.allowlisted_by_path {
+                writeln!(f, "    - {}", path.display())?;
+            }
+        }
         Ok(())
     }
 }
 
+/// Allowlist entry for a known-good generated script
+///
+/// An entry matches when every criterion it specifies matches: a pinned
+/// entry (glob + hash) rejects a file at the right path whose contents changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptAllowlistEntry {
+    /// Path glob relative to the validated directory (`*`, `**`, `?`)
+    pub path_glob: Option<String>,
+    /// Lowercase hex SHA-256 of the file contents
+    pub sha256: Option<String>,
+}
+
+impl ScriptAllowlistEntry {
+    /// Allow any script matching a path glob
+    #[must_use]
+    pub fn path(glob: impl Into<String>) -> Self {
+        Self {
+            path_glob: Some(glob.into()),
+            sha256: None,
+        }
+    }
+
+    /// Allow any script with the given contents hash
+    #[must_use]
+    pub fn hash(sha256: impl Into<String>) -> Self {
+        Self {
+            path_glob: None,
+            sha256: Some(sha256.into().to_lowercase()),
+        }
+    }
+
+    /// Allow a script only at a path glob and with the given contents hash
+    #[must_use]
+    pub fn pinned(glob: impl Into<String>, sha256: impl Into<String>) -> Self {
+        Self {
+            path_glob: Some(glob.into()),
+            sha256: Some(sha256.into().to_lowercase()),
+        }
+    }
+
+    /// Whether the entry matches a script at `relative_path` with `content_hash`
+    #[must_use]
+    pub fn matches(&self, relative_path: &Path, content_hash: &str) -> bool {
+        if self.path_glob.is_none() && self.sha256.is_none() {
+            return false;
+        }
+        let path_ok = self
+            .path_glob
+            .as_deref()
+            .map_or(true, |glob| glob_matches(glob, relative_path));
+        let hash_ok = self
+            .sha256
+            .as_deref()
+            .map_or(true, |hash| hash == content_hash);
+        path_ok && hash_ok
+    }
+}
+
+/// Lowercase hex SHA-256 of script contents, as used by [`ScriptAllowlistEntry`]
+#[must_use]
+pub fn script_sha256(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// Match a `/`-separated path against a glob (`**` crosses directories)
+fn glob_matches(glob: &str, path: &Path) -> bool {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut pattern = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // `**/` also matches zero directories
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    pattern.push_str("(?:.*/)?");
+                } else {
+                    pattern.push_str(".*");
+                }
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    regex::Regex::new(&pattern).is_ok_and(|re| re.is_match(&path))
+}
+
 /// Inline script violation details
 #[derive(Debug, Clone)]
 pub struct InlineScriptViolation {
@@ -240,6 +356,8 @@ pub struct ZeroJsConfig {
     pub wasm_marker: String,
     /// Whether inline scripts are allowed if WASM-generated
     pub allow_wasm_inline_scripts: bool,
+    /// Known-good scripts that pass without a manifest
+    pub script_allowlist: Vec<ScriptAllowlistEntry>,
 }
 
 impl Default for ZeroJsConfig {
@@ -252,6 +370,7 @@ impl Default for ZeroJsConfig {
             check_dangerous_patterns: true,
             wasm_marker: "__PROBAR_WASM_GENERATED__".to_string(),
             allow_wasm_inline_scripts: true,
+            script_allowlist: vec![],
         }
     }
 }
@@ -268,6 +387,7 @@ impl ZeroJsConfig {
             check_dangerous_patterns: true,
             wasm_marker: "__PROBAR_WASM_GENERATED__".to_string(),
             allow_wasm_inline_scripts: false,
+            script_allowlist: vec![],
         }
     }
 
@@ -282,6 +402,7 @@ impl ZeroJsConfig {
             check_dangerous_patterns: false,
             wasm_marker: String::new(),
             allow_wasm_inline_scripts: true,
+            script_allowlist: vec![],
         }
     }
 
@@ -298,6 +419,13 @@ impl ZeroJsConfig {
         self.skip_paths.push(path.into());
         self
     }
+
+    /// Add a known-good script to the allowlist
+    #[must_use]
+    pub fn with_allowlisted_script(mut self, entry: ScriptAllowlistEntry) -> Self {
+        self.script_allowlist.push(entry);
+        self
+    }
 }
 
 /// Zero-JavaScript validator for WASM-first applications
@@ -337,7 +465,7 @@ impl ZeroJsValidator {
             ..Default::default()
         };
 
-        self.scan_directory(path, &mut result)?;
+        self.scan_directory(path, path, &mut result)?;
 
         // Update valid flag based on findings
         result.valid = result.is_valid();
@@ -487,6 +615,7 @@ impl ZeroJsValidator {
     /// Recursively scan directory
     fn scan_directory(
         &self,
+        root: &Path,
         dir: &Path,
         result: &mut ZeroJsValidationResult,
     ) -> std::io::Result<()> {
@@ -511,17 +640,60 @@ impl ZeroJsValidator {
             }
 
             if path.is_dir() {
-                self.scan_directory(&path, result)?;
+                self.scan_directory(root, &path, result)?;
             } else if path.is_file() {
-                self.check_file(&path, result)?;
+                self.check_file(root, &path, result)?;
             }
         }
 
         Ok(())
     }
 
+    /// Check a script against the allowlist, recording how it was allowed
+    ///
+    /// Hash-matched scripts are trusted as-is; path-only matches are still
+    /// checked for dangerous patterns.
+    fn check_allowlist(
+        &self,
+        root: &Path,
+        path: &Path,
+        result: &mut ZeroJsValidationResult,
+    ) -> bool {
+        if self.config.script_allowlist.is_empty() {
+            return false;
+        }
+        let Ok(content) = std::fs::read(path) else {
+            return false;
+        };
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        let hash = script_sha256(&content);
+
+        let Some(entry) = self
+            .config
+            .script_allowlist
+            .iter()
+            .find(|entry| entry.matches(relative, &hash))
+        else {
+            return false;
+        };
+
+        if entry.sha256.is_some() {
+            result.allowlisted_by_hash.push(path.to_path_buf());
+        } else {
+            result.allowlisted_by_path.push(path.to_path_buf());
+            let violations = self.validate_js_content(&String::from_utf8_lossy(&content), path);
+            result.dangerous_patterns.extend(violations);
+        }
+        true
+    }
+
     /// Check a single file for violations
-    fn check_file(&self, path: &Path, result: &mut ZeroJsValidationResult) -> std::io::Result<()> {
+    fn check_file(
+        &self,
+        root: &Path,
+        path: &Path,
+        result: &mut ZeroJsValidationResult,
+    ) -> std::io::Result<()> {
         let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
         let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
@@ -536,6 +708,10 @@ impl ZeroJsValidator {
         let is_forbidden_ext = FORBIDDEN_EXTENSIONS.contains(&ext_with_dot.as_str());
 
         if is_forbidden_ext {
+            if self.check_allowlist(root, path, result) {
+                return Ok(());
+            }
+
             // Check if it's allowed by pattern
             if self.matches_allowed_pattern(path, &self.config.allowed_js_patterns) {
                 // Allowed pattern - check manifest if required
@@ -1357,6 +1533,8 @@ mod tests {
                 context: "eval(input)".into(),
             }],
             verified_js_files: vec![],
+            allowlisted_by_hash: vec![PathBuf::from("pkg/app.js")],
+            allowlisted_by_path: vec![],
         };
 
         let display = format!("{result}");
@@ -1365,6 +1543,8 @@ mod tests {
         assert!(display.contains("node_modules"));
         assert!(display.contains("alert"));
         assert!(display.contains("eval"));
+        assert!(display.contains("Allowlisted by hash"));
+        assert!(display.contains("pkg/app.js"));
     }
 
     #[test]
@@ -1726,6 +1906,89 @@ mod tests {
         assert_eq!(violations.len(), 3);
     }
 
+    const GLUE: &str = "let wasm;\nexport default async function init() {}\n";
+
+    #[test]
+    fn test_allowlisted_glue_by_hash_passes() {
+        let temp = TempDir::new().unwrap();
+        let pkg = temp.path().join("pkg");
+        std::fs::create_dir(&pkg).unwrap();
+        std::fs::write(pkg.join("app.js"), GLUE).unwrap();
+        std::fs::write(
+            temp.path().join("sw.js"),
+            "self.addEventListener('fetch', () => {});",
+        )
+        .unwrap();
+
+        let config = ZeroJsConfig::default()
+            .with_allowlisted_script(ScriptAllowlistEntry::pinned(
+                "pkg/*.js",
+                script_sha256(GLUE.as_bytes()),
+            ))
+            .with_allowlisted_script(ScriptAllowlistEntry::path("sw.js"));
+        let result = ZeroJsValidator::with_config(config)
+            .validate_directory(temp.path())
+            .unwrap();
+
+        assert!(result.is_valid(), "{result}");
+        assert_eq!(result.allowlisted_by_hash, vec![pkg.join("app.js")]);
+        assert_eq!(result.allowlisted_by_path, vec![temp.path().join("sw.js")]);
+        let report = result.to_string();
+        assert!(report.contains("Allowlisted by hash:"));
+        assert!(report.contains("Allowlisted by path:"));
+    }
+
+    #[test]
+    fn test_allowlisted_glue_hash_mismatch_fails() {
+        let temp = TempDir::new().unwrap();
+        let pkg = temp.path().join("pkg");
+        std::fs::create_dir(&pkg).unwrap();
+        std::fs::write(pkg.join("app.js"), format!("{GLUE}fetch('//evil');\n")).unwrap();
+
+        let config = ZeroJsConfig::default().with_allowlisted_script(ScriptAllowlistEntry::pinned(
+            "pkg/*.js",
+            script_sha256(GLUE.as_bytes()),
+        ));
+        let result = ZeroJsValidator::with_config(config)
+            .validate_directory(temp.path())
+            .unwrap();
+
+        assert!(!result.is_valid());
+        assert_eq!(result.unauthorized_js_files, vec![pkg.join("app.js")]);
+        assert!(result.allowlisted_by_hash.is_empty());
+    }
+
+    #[test]
+    fn test_allowlist_does_not_cover_inline_scripts() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("glue.js"), GLUE).unwrap();
+        std::fs::write(
+            temp.path().join("index.html"),
+            "<html>\n<script>\nalert('hi');\n</script>\n</html>",
+        )
+        .unwrap();
+
+        let config = ZeroJsConfig::default()
+            .with_allowlisted_script(ScriptAllowlistEntry::hash(script_sha256(GLUE.as_bytes())));
+        let result = ZeroJsValidator::with_config(config)
+            .validate_directory(temp.path())
+            .unwrap();
+
+        assert!(!result.is_valid());
+        assert_eq!(result.allowlisted_by_hash.len(), 1);
+        assert_eq!(result.inline_scripts_detected.len(), 1);
+    }
+
+    #[test]
+    fn test_script_allowlist_glob_matching() {
+        let entry = ScriptAllowlistEntry::path("**/*_bg.js");
+        assert!(entry.matches(Path::new("app_bg.js"), "x"));
+        assert!(entry.matches(Path::new("pkg/deep/app_bg.js"), "x"));
+        assert!(!entry.matches(Path::new("pkg/app.js"), "x"));
+        assert!(!ScriptAllowlistEntry::path("pkg/*.js").matches(Path::new("pkg/a/b.js"), "x"));
+        assert!(!ScriptAllowlistEntry::default().matches(Path::new("a.js"), "x"));
+    }
+
     #[test]
     fn test_html_script_not_closed() {
         let html = r#"